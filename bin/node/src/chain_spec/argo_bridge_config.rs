@@ -6,6 +6,7 @@ pub fn production_config() -> ArgoBridgeConfig {
         mint_allowance: 0,
         bridging_fee: DefaultBridgingFee::get(),
         thawn_duration: 1,
+        attestation_ttl: 600,
     }
 }
 
@@ -15,5 +16,6 @@ pub fn testing_config() -> ArgoBridgeConfig {
         mint_allowance: 0,
         bridging_fee: DefaultBridgingFee::get(),
         thawn_duration: 1,
+        attestation_ttl: 600,
     }
 }
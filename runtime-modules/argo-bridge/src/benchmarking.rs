@@ -9,6 +9,9 @@ use core::convert::{TryFrom, TryInto};
 use frame_benchmarking::v1::{account, benchmarks};
 use frame_system::Pallet as System;
 use frame_system::{EventRecord, RawOrigin};
+use membership::{BuyMembershipParameters, Module as Members};
+use project_token::types::TokenAllocation;
+use sp_runtime::Permill;
 
 use crate::{BridgeConstraints, BridgeStatus, RemoteAccount, RemoteTransfer};
 
@@ -78,12 +81,123 @@ where
     ArgoBridge::<T>::request_outbound_transfer(
         RawOrigin::Signed(sender).into(),
         remote_account,
+        BridgedAsset::Joy,
         amount + fee,
         fee,
     )
     .unwrap();
 }
 
+fn max_rate_limits<T: Config>(
+) -> BoundedVec<(RateLimitKeyOf<T>, RateLimitOf<T>), ConstU32<MAX_RATE_LIMITS>> {
+    let rate_limit = RateLimit {
+        max_amount: 1_000_000u32.into(),
+        window: 100u32.into(),
+    };
+    let mut rate_limits = Vec::new();
+    for chain_id in sp_std::iter::once(None).chain((0..MAX_REMOTE_CHAINS).map(Some)) {
+        for direction in [TransferDirection::Inbound, TransferDirection::Outbound] {
+            rate_limits.push((
+                RateLimitKey {
+                    direction,
+                    chain_id,
+                    token_id: None,
+                },
+                rate_limit,
+            ));
+        }
+    }
+    BoundedVec::try_from(rate_limits).unwrap()
+}
+
+// Member controlled by the account `1000`, matching the mock memberships
+fn create_member<T: Config>() -> (T::MemberId, T::AccountId)
+where
+    T::AccountId: CreateAccountId,
+{
+    let controller_account = T::AccountId::create_account_id(1000u32);
+    let member_id = Members::<T>::members_created();
+    let _ = Balances::<T>::deposit_creating(
+        &controller_account,
+        T::ExistentialDeposit::get() + Members::<T>::membership_price(),
+    );
+    Members::<T>::buy_membership(
+        RawOrigin::Signed(controller_account.clone()).into(),
+        BuyMembershipParameters {
+            root_account: controller_account.clone(),
+            controller_account: controller_account.clone(),
+            handle: Some(b"member".to_vec()),
+            metadata: Vec::new(),
+            referrer_id: None,
+        },
+    )
+    .unwrap();
+    (member_id, controller_account)
+}
+
+fn issue_creator_token<T: Config>(member_id: T::MemberId) -> T::TokenId
+where
+    T::AccountId: CreateAccountId,
+{
+    let issuer_account = T::AccountId::create_account_id(1u32);
+    let _ = Balances::<T>::deposit_creating(
+        &issuer_account,
+        T::ExistentialDeposit::get() + project_token::Module::<T>::bloat_bond(),
+    );
+    T::ProjectToken::issue_token(
+        issuer_account.clone(),
+        TokenIssuanceParametersOf::<T> {
+            initial_allocation: [(
+                member_id,
+                TokenAllocation {
+                    amount: 1000u32.into(),
+                    vesting_schedule_params: None,
+                },
+            )]
+            .iter()
+            .cloned()
+            .collect(),
+            ..Default::default()
+        },
+        UploadContextOf::<T> {
+            uploader_account: issuer_account,
+            bag_id: Default::default(),
+        },
+    )
+    .unwrap()
+}
+
+fn max_creator_token_mint_allowances<T: Config>(
+) -> BoundedVec<(T::TokenId, BalanceOf<T>), ConstU32<MAX_CREATOR_TOKEN_MINT_ALLOWANCES>>
+where
+    T::AccountId: CreateAccountId,
+{
+    let (member_id, _) = create_member::<T>();
+    let mint_allowances: Vec<_> = (0..MAX_CREATOR_TOKEN_MINT_ALLOWANCES)
+        .map(|_| (issue_creator_token::<T>(member_id), 1000u32.into()))
+        .collect();
+    BoundedVec::try_from(mint_allowances).unwrap()
+}
+
+fn max_remote_chain_fees<T: Config>(
+) -> BoundedVec<(ChainId, Option<FeeScheduleOf<T>>), ConstU32<MAX_REMOTE_CHAINS>> {
+    let fee_schedule = FeeSchedule {
+        flat_fee: 5u32.into(),
+        percentage_fee: Permill::from_percent(5),
+    };
+    let remote_chain_fees: Vec<_> = (0..MAX_REMOTE_CHAINS)
+        .map(|chain_id| (chain_id, Some(fee_schedule)))
+        .collect();
+    BoundedVec::try_from(remote_chain_fees).unwrap()
+}
+
+fn fee_distribution() -> FeeDistribution {
+    FeeDistribution {
+        council_budget_share: Permill::from_percent(30),
+        operator_reward_share: Permill::from_percent(30),
+    }
+}
+
 benchmarks! {
     where_clause {
         where
@@ -93,6 +207,8 @@ benchmarks! {
     // Worst case scenario:
     // - max number of remote chains being use
     // - using the last chain
+    // - global and remote chain rate limits enabled
+    // - remote chain fee schedule set and the fee split between burning, council and operator
     request_outbound_transfer{
         let fee: BalanceOf<T> = 10u32.into();
         let remote_chains: Vec<u32> = (0..MAX_REMOTE_CHAINS).collect();
@@ -103,7 +219,15 @@ benchmarks! {
             pauser_accounts: Some(vec![pauser_acount.clone()]),
             bridging_fee: Some(fee),
             thawn_duration: Some(1u32.into()),
-            remote_chains: Some(BoundedVec::try_from(remote_chains).unwrap())
+            remote_chains: Some(BoundedVec::try_from(remote_chains).unwrap()),
+            attester_accounts: None,
+            attestation_threshold: None,
+            attestation_ttl: None,
+            rate_limits: Some(max_rate_limits::<T>()),
+            remote_chain_fees: Some(max_remote_chain_fees::<T>()),
+            fee_distribution: Some(fee_distribution()),
+            operator_reward_account: Some(T::AccountId::create_account_id(2u32)),
+            creator_token_mint_allowances: None
         };
 
         ArgoBridge::<T>::update_bridge_constrains(
@@ -112,6 +236,7 @@ benchmarks! {
         ).unwrap();
         activate_bridge::<T>(&pauser_acount, &operator_account);
 
+        // fee schedule of the chain: 5 + 5% of the transfer amount
         let initial_balance: u32 = 1030u32;
         let sender = T::AccountId::create_account_id(1u32);
         let _ = Balances::<T>::deposit_creating(&sender, T::ExistentialDeposit::get() + initial_balance.into());
@@ -123,11 +248,11 @@ benchmarks! {
         let origin = RawOrigin::Signed(sender);
         let transfer_id = ArgoBridge::<T>::next_transfer_id();
         let transfer_amount = 100u32.into();
-    }: _(origin, dest_account, transfer_amount, fee)
+    }: _(origin, dest_account, BridgedAsset::Joy, transfer_amount, fee)
     verify {
         let sender = T::AccountId::create_account_id(1u32);
         assert_last_event::<T>(
-            RawEvent::OutboundTransferRequested(transfer_id, sender, dest_account, transfer_amount, fee).into());
+            RawEvent::OutboundTransferRequested(transfer_id, sender, dest_account, BridgedAsset::Joy, transfer_amount, fee).into());
     }
 
     // Worse case scenario
@@ -142,7 +267,15 @@ benchmarks! {
             pauser_accounts: Some(vec![pauser_acount.clone()]),
             bridging_fee: Some(fee),
             thawn_duration: Some(1u32.into()),
-            remote_chains: Some(BoundedVec::try_from(remote_chains).unwrap())
+            remote_chains: Some(BoundedVec::try_from(remote_chains).unwrap()),
+            attester_accounts: None,
+            attestation_threshold: None,
+            attestation_ttl: None,
+            rate_limits: None,
+            remote_chain_fees: None,
+            fee_distribution: None,
+            operator_reward_account: None,
+            creator_token_mint_allowances: None
         };
 
         ArgoBridge::<T>::update_bridge_constrains(
@@ -151,33 +284,123 @@ benchmarks! {
         ).unwrap();
         activate_bridge::<T>(&pauser_acount, &operator_account);
 
-        let revert_amount: u32 = 1030u32;
-        set_bridge_mint_allowance::<T>(revert_amount.into(), fee);
+        let transfer_id = ArgoBridge::<T>::next_transfer_id();
+        set_bridge_mint_allowance::<T>(1030u32.into(), fee);
 
-        let transfer_id = 1u64;
         let rationale = vec![0u8; (MAX_BYTES_RATIONALE) as usize];
-        let revert_account = T::AccountId::create_account_id(2u32);
-    }: _(RawOrigin::Signed(operator_account), transfer_id, revert_account.clone(), revert_amount.into(), rationale.clone().try_into().unwrap())
+        let transfer = ArgoBridge::<T>::outbound_transfers(transfer_id).unwrap();
+    }: _(RawOrigin::Signed(operator_account), transfer_id, rationale.clone().try_into().unwrap())
     verify {
         assert_last_event::<T>(
-            RawEvent::OutboundTransferReverted(transfer_id, revert_account, revert_amount.into(), rationale.try_into().unwrap()).into());
+            RawEvent::OutboundTransferReverted(transfer_id, transfer.sender, transfer.amount, rationale.try_into().unwrap()).into());
     }
 
+    complete_outbound_transfer{
+        let fee: BalanceOf<T> = 10u32.into();
+        let pauser_acount = T::AccountId::create_account_id(1u32);
+        let operator_account = T::AccountId::create_account_id(1u32);
+        let remote_chains: Vec<u32> = (0..MAX_REMOTE_CHAINS).collect();
+        let parameters = BridgeConstraints {
+            operator_account: Some(operator_account.clone()),
+            pauser_accounts: Some(vec![pauser_acount.clone()]),
+            bridging_fee: Some(fee),
+            thawn_duration: Some(1u32.into()),
+            remote_chains: Some(BoundedVec::try_from(remote_chains).unwrap()),
+            attester_accounts: None,
+            attestation_threshold: None,
+            attestation_ttl: None,
+            rate_limits: None,
+            remote_chain_fees: None,
+            fee_distribution: None,
+            operator_reward_account: None,
+            creator_token_mint_allowances: None
+        };
+
+        ArgoBridge::<T>::update_bridge_constrains(
+            RawOrigin::Root.into(),
+            parameters
+        ).unwrap();
+        activate_bridge::<T>(&pauser_acount, &operator_account);
+
+        let transfer_id = ArgoBridge::<T>::next_transfer_id();
+        set_bridge_mint_allowance::<T>(1030u32.into(), fee);
+    }: _(RawOrigin::Signed(operator_account), transfer_id)
+    verify {
+        assert_eq!(
+            ArgoBridge::<T>::outbound_transfers(transfer_id).unwrap().status,
+            OutboundTransferStatus::Completed
+        );
+        assert_last_event::<T>(RawEvent::OutboundTransferCompleted(transfer_id).into());
+    }
+
+    prune_outbound_transfer{
+        let fee: BalanceOf<T> = 10u32.into();
+        let pauser_acount = T::AccountId::create_account_id(1u32);
+        let operator_account = T::AccountId::create_account_id(1u32);
+        let remote_chains: Vec<u32> = (0..MAX_REMOTE_CHAINS).collect();
+        let parameters = BridgeConstraints {
+            operator_account: Some(operator_account.clone()),
+            pauser_accounts: Some(vec![pauser_acount.clone()]),
+            bridging_fee: Some(fee),
+            thawn_duration: Some(1u32.into()),
+            remote_chains: Some(BoundedVec::try_from(remote_chains).unwrap()),
+            attester_accounts: None,
+            attestation_threshold: None,
+            attestation_ttl: None,
+            rate_limits: None,
+            remote_chain_fees: None,
+            fee_distribution: None,
+            operator_reward_account: None,
+            creator_token_mint_allowances: None
+        };
+
+        ArgoBridge::<T>::update_bridge_constrains(
+            RawOrigin::Root.into(),
+            parameters
+        ).unwrap();
+        activate_bridge::<T>(&pauser_acount, &operator_account);
+
+        let transfer_id = ArgoBridge::<T>::next_transfer_id();
+        set_bridge_mint_allowance::<T>(1030u32.into(), fee);
+        ArgoBridge::<T>::complete_outbound_transfer(
+            RawOrigin::Signed(operator_account.clone()).into(),
+            transfer_id
+        ).unwrap();
+    }: _(RawOrigin::Signed(operator_account), transfer_id)
+    verify {
+        assert!(ArgoBridge::<T>::outbound_transfers(transfer_id).is_none());
+        assert_last_event::<T>(RawEvent::OutboundTransferPruned(transfer_id).into());
+    }
 
     // Worst case scenario:
     // - max number of remote chains being use
     // - using the last chain
+    // - all the attesters needed to reach the threshold, the last attestation reaching it
+    // - global and remote chain rate limits enabled
     finalize_inbound_transfer{
+        let a in 1 .. T::MaxAttesterAccounts::get();
+
         let fee: BalanceOf<T> = 10u32.into();
         let remote_chains: Vec<u32> = (0..MAX_REMOTE_CHAINS).collect();
         let pauser_acount = T::AccountId::create_account_id(1);
         let operator_account = T::AccountId::create_account_id(1u32);
+        let attester_accounts: Vec<T::AccountId> = (0..a)
+        .map(T::AccountId::create_account_id)
+        .collect();
         let parameters = BridgeConstraints {
             operator_account: Some(operator_account.clone()),
             pauser_accounts: Some(vec![pauser_acount.clone()]),
             bridging_fee: Some(fee),
             thawn_duration: Some(1u32.into()),
-            remote_chains: Some(BoundedVec::try_from(remote_chains).unwrap())
+            remote_chains: Some(BoundedVec::try_from(remote_chains).unwrap()),
+            attester_accounts: Some(attester_accounts.clone()),
+            attestation_threshold: Some(a),
+            attestation_ttl: Some(10u32.into()),
+            rate_limits: Some(max_rate_limits::<T>()),
+            remote_chain_fees: None,
+            fee_distribution: None,
+            operator_reward_account: None,
+            creator_token_mint_allowances: None
         };
         ArgoBridge::<T>::update_bridge_constrains(
             RawOrigin::Root.into(),
@@ -188,13 +411,22 @@ benchmarks! {
         let transfer_amount = 100u32.into();
         set_bridge_mint_allowance::<T>(1030u32.into(), fee);
 
-        let operator = T::AccountId::create_account_id(1u32);
         let dest_account = T::AccountId::create_account_id(1u32);
         let remote_transfer = RemoteTransfer { id: 0, chain_id: MAX_REMOTE_CHAINS - 1 };
-    }: _(RawOrigin::Signed(operator), remote_transfer.clone(), dest_account.clone(), transfer_amount)
+        let (last_attester, other_attesters) = attester_accounts.split_last().unwrap();
+        for attester in other_attesters {
+            ArgoBridge::<T>::finalize_inbound_transfer(
+                RawOrigin::Signed(attester.clone()).into(),
+                remote_transfer.clone(),
+                dest_account.clone(),
+                BridgedAsset::Joy,
+                transfer_amount
+            ).unwrap();
+        }
+    }: _(RawOrigin::Signed(last_attester.clone()), remote_transfer.clone(), dest_account.clone(), BridgedAsset::Joy, transfer_amount)
     verify {
         assert_last_event::<T>(
-            RawEvent::InboundTransferFinalized(remote_transfer, dest_account, transfer_amount).into());
+            RawEvent::InboundTransferFinalized(remote_transfer, dest_account, BridgedAsset::Joy, transfer_amount).into());
     }
 
     // Worst case scenario:
@@ -210,7 +442,15 @@ benchmarks! {
             pauser_accounts: Some(pauser_accounts.clone()),
             bridging_fee: None,
             thawn_duration: Some(1u32.into()),
-            remote_chains: None
+            remote_chains: None,
+            attester_accounts: None,
+            attestation_threshold: None,
+            attestation_ttl: None,
+            rate_limits: None,
+            remote_chain_fees: None,
+            fee_distribution: None,
+            operator_reward_account: None,
+            creator_token_mint_allowances: None
         };
         ArgoBridge::<T>::update_bridge_constrains(
             RawOrigin::Root.into(),
@@ -239,7 +479,15 @@ benchmarks! {
             pauser_accounts: Some(pauser_accounts.clone()),
             bridging_fee: None,
             thawn_duration: Some(1u32.into()),
-            remote_chains: None
+            remote_chains: None,
+            attester_accounts: None,
+            attestation_threshold: None,
+            attestation_ttl: None,
+            rate_limits: None,
+            remote_chain_fees: None,
+            fee_distribution: None,
+            operator_reward_account: None,
+            creator_token_mint_allowances: None
         };
         ArgoBridge::<T>::update_bridge_constrains(
             RawOrigin::Root.into(),
@@ -269,7 +517,15 @@ benchmarks! {
             pauser_accounts: Some(pauser_accounts.clone()),
             bridging_fee: None,
             thawn_duration: Some(1u32.into()),
-            remote_chains: None
+            remote_chains: None,
+            attester_accounts: None,
+            attestation_threshold: None,
+            attestation_ttl: None,
+            rate_limits: None,
+            remote_chain_fees: None,
+            fee_distribution: None,
+            operator_reward_account: None,
+            creator_token_mint_allowances: None
         };
         ArgoBridge::<T>::update_bridge_constrains(
             RawOrigin::Root.into(),
@@ -291,6 +547,7 @@ benchmarks! {
 
     // Worst case scenario:
     // - update all parameters
+    // - max number of creator token mint allowances set
     update_bridge_constrains{
         let fee: BalanceOf<T> = 10u32.into();
         let pauser_accounts: Vec<T::AccountId> = (0..T::MaxPauserAccounts::get())
@@ -304,7 +561,15 @@ benchmarks! {
             pauser_accounts: Some(pauser_accounts),
             bridging_fee: Some(fee),
             thawn_duration: Some(1u32.into()),
-            remote_chains: Some(BoundedVec::try_from(remote_chains).unwrap())
+            remote_chains: Some(BoundedVec::try_from(remote_chains).unwrap()),
+            attester_accounts: None,
+            attestation_threshold: None,
+            attestation_ttl: None,
+            rate_limits: Some(max_rate_limits::<T>()),
+            remote_chain_fees: Some(max_remote_chain_fees::<T>()),
+            fee_distribution: Some(fee_distribution()),
+            operator_reward_account: Some(T::AccountId::create_account_id(2u32)),
+            creator_token_mint_allowances: Some(max_creator_token_mint_allowances::<T>())
         };
 
     }: _(RawOrigin::Root, parameters.clone())
@@ -312,6 +577,81 @@ benchmarks! {
         assert_last_event::<T>(
             RawEvent::BridgeConfigUpdated(parameters).into());
     }
+
+    // Worst case scenario:
+    // - max number of transfers being pruned
+    // - all of the pruned transfers present in the registry
+    prune_processed_inbound_transfers{
+        let operator_account = T::AccountId::create_account_id(1u32);
+        let parameters = BridgeConstraints {
+            operator_account: Some(operator_account.clone()),
+            pauser_accounts: None,
+            bridging_fee: None,
+            thawn_duration: None,
+            remote_chains: None,
+            attester_accounts: None,
+            attestation_threshold: None,
+            attestation_ttl: None,
+            rate_limits: None,
+            remote_chain_fees: None,
+            fee_distribution: None,
+            operator_reward_account: None,
+            creator_token_mint_allowances: None
+        };
+        ArgoBridge::<T>::update_bridge_constrains(
+            RawOrigin::Root.into(),
+            parameters
+        ).unwrap();
+
+        let chain_id = MAX_REMOTE_CHAINS - 1;
+        let new_floor: TransferId = MAX_PRUNED_INBOUND_TRANSFERS.into();
+        for transfer_id in 0..new_floor {
+            ProcessedInboundTransfers::<T>::insert(chain_id, transfer_id, System::<T>::block_number());
+        }
+    }: _(RawOrigin::Signed(operator_account), chain_id, new_floor)
+    verify {
+        assert_eq!(ArgoBridge::<T>::processed_inbound_transfers_floor(chain_id), new_floor);
+        assert_last_event::<T>(
+            RawEvent::ProcessedInboundTransfersPruned(chain_id, new_floor).into());
+    }
+
+    // Worst case scenario:
+    // - all the attestations made by the former attesters
+    prune_inbound_transfer_attestations{
+        let a in 1 .. T::MaxAttesterAccounts::get();
+
+        let caller = T::AccountId::create_account_id(1u32);
+        let remote_transfer = RemoteTransfer { id: 0, chain_id: MAX_REMOTE_CHAINS - 1 };
+        for attester_id in 0..a {
+            InboundTransferAttestations::<T>::insert(
+                &remote_transfer,
+                T::AccountId::create_account_id(attester_id),
+                InboundTransferAttestation {
+                    dest_account: caller.clone(),
+                    asset: BridgedAsset::Joy,
+                    amount: 100u32.into(),
+                    attested_at: System::<T>::block_number(),
+                }
+            );
+        }
+    }: _(RawOrigin::Signed(caller), remote_transfer.clone())
+    verify {
+        assert_eq!(InboundTransferAttestations::<T>::iter_prefix(&remote_transfer).count(), 0);
+        assert_last_event::<T>(
+            RawEvent::InboundTransferAttestationsPruned(remote_transfer, a).into());
+    }
+
+    claim_creator_token_refund{
+        let (member_id, controller_account) = create_member::<T>();
+        let token_id = issue_creator_token::<T>(member_id);
+        let amount: BalanceOf<T> = 100u32.into();
+        PendingCreatorTokenRefunds::<T>::insert(token_id, member_id, amount);
+    }: _(RawOrigin::Signed(controller_account), token_id, member_id)
+    verify {
+        assert!(!PendingCreatorTokenRefunds::<T>::contains_key(token_id, member_id));
+        assert_last_event::<T>(
+            RawEvent::CreatorTokenRefundClaimed(token_id, member_id, amount).into());
+    }
 }
 
 #[cfg(test)]
@@ -335,6 +675,20 @@ mod tests {
         });
     }
 
+    #[test]
+    fn test_complete_outbound_transfer() {
+        with_test_externalities(|| {
+            assert_ok!(ArgoBridge::test_benchmark_complete_outbound_transfer());
+        });
+    }
+
+    #[test]
+    fn test_prune_outbound_transfer() {
+        with_test_externalities(|| {
+            assert_ok!(ArgoBridge::test_benchmark_prune_outbound_transfer());
+        });
+    }
+
     #[test]
     fn test_finalize_inbound_transfer() {
         with_test_externalities(|| {
@@ -369,4 +723,25 @@ mod tests {
             assert_ok!(ArgoBridge::test_benchmark_update_bridge_constrains());
         });
     }
+
+    #[test]
+    fn test_prune_processed_inbound_transfers() {
+        with_test_externalities(|| {
+            assert_ok!(ArgoBridge::test_benchmark_prune_processed_inbound_transfers());
+        });
+    }
+
+    #[test]
+    fn test_prune_inbound_transfer_attestations() {
+        with_test_externalities(|| {
+            assert_ok!(ArgoBridge::test_benchmark_prune_inbound_transfer_attestations());
+        });
+    }
+
+    #[test]
+    fn test_claim_creator_token_refund() {
+        with_test_externalities(|| {
+            assert_ok!(ArgoBridge::test_benchmark_claim_creator_token_refund());
+        });
+    }
 }
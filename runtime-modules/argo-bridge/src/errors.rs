@@ -34,10 +34,52 @@ decl_error! {
         /// Number of pauser accounts over the maximum allowed
         InvalidNumberOfPauserAccounts,
 
+        /// Attester account required
+        NotAttesterAccount,
+
+        /// Number of attester accounts over the maximum allowed
+        InvalidNumberOfAttesterAccounts,
+
+        /// Attestation threshold must be non-zero and not greater than the number of attesters
+        InvalidAttestationThreshold,
+
         /// Current block is lower than thawn_ends_at
         ThawnNotFinished,
 
         /// ChainId is not on the list of the supported chains
-        NotSupportedRemoteChainId
+        NotSupportedRemoteChainId,
+
+        /// Inbound transfer was already finalized
+        InboundTransferAlreadyProcessed,
+
+        /// Inbound transfer has no attestations that can be pruned
+        NoInboundTransferAttestationsToPrune,
+
+        /// New processed inbound transfers floor must be greater than the current one
+        InvalidProcessedInboundTransfersFloor,
+
+        /// Number of inbound transfers to prune over the maximum allowed
+        TooManyInboundTransfersToPrune,
+
+        /// Outbound transfer with the provided id does not exist
+        OutboundTransferNotFound,
+
+        /// Outbound transfer is not in pending state
+        OutboundTransferNotPending,
+
+        /// Outbound transfer is still in pending state
+        OutboundTransferStillPending,
+
+        /// Account is not the controller account of the creator token holder member
+        NotMemberControllerAccount,
+
+        /// Council budget and operator reward shares of the fee exceed 100%
+        InvalidFeeDistribution,
+
+        /// Creator token with the provided id does not exist
+        CreatorTokenNotFound,
+
+        /// No reverted creator token transfer is waiting to be refunded to the member
+        NoPendingCreatorTokenRefund
     }
 }
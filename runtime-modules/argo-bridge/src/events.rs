@@ -19,18 +19,44 @@ decl_event!(
         Balance = BalanceOf<T>,
         BridgeConstraints = BridgeConstraintsOf<T>,
         BlockNumber = <T as frame_system::Config>::BlockNumber,
+        BridgedAsset = BridgedAssetOf<T>,
+        RateLimitKey = RateLimitKeyOf<T>,
+        TokenId = <T as project_token::Config>::TokenId,
+        MemberId = <T as common::membership::MembershipTypes>::MemberId,
     {
-        OutboundTransferRequested(TransferId, AccountId, RemoteAccount, Balance, Balance),
-        InboundTransferFinalized(RemoteTransfer, AccountId, Balance),
+        OutboundTransferRequested(
+            TransferId,
+            AccountId,
+            RemoteAccount,
+            BridgedAsset,
+            Balance,
+            Balance,
+        ),
+        InboundTransferAttested(RemoteTransfer, AccountId, AccountId, BridgedAsset, Balance),
+        ConflictingInboundTransferAttestation(
+            RemoteTransfer,
+            AccountId,
+            AccountId,
+            BridgedAsset,
+            Balance,
+        ),
+        InboundTransferFinalized(RemoteTransfer, AccountId, BridgedAsset, Balance),
+        InboundTransferAttestationsPruned(RemoteTransfer, u32),
         OutboundTransferReverted(
             TransferId,
             AccountId,
             Balance,
             BoundedVec<u8, ConstU32<MAX_BYTES_RATIONALE>>,
         ),
+        CreatorTokenRefundDeferred(TransferId, TokenId, MemberId, Balance),
+        CreatorTokenRefundClaimed(TokenId, MemberId, Balance),
         BridgePaused(AccountId),
+        BridgePausedByRateLimit(RateLimitKey),
         BridgeThawnStarted(AccountId, BlockNumber),
         BridgeThawnFinished(),
+        OutboundTransferCompleted(TransferId),
+        OutboundTransferPruned(TransferId),
         BridgeConfigUpdated(BridgeConstraints),
+        ProcessedInboundTransfersPruned(ChainId, TransferId),
     }
 );
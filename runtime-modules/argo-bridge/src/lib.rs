@@ -17,18 +17,27 @@ use frame_support::{
     dispatch::{marker::Copy, DispatchResult},
     ensure,
     storage::bounded_vec::BoundedVec,
-    traits::{ConstU32, Currency, Get},
+    traits::{ConstU32, Currency, Get, StorageVersion},
+    IterableStorageDoubleMap,
 };
 use frame_system::{ensure_root, ensure_signed};
 use sp_runtime::DispatchError;
-use sp_runtime::{traits::CheckedAdd, SaturatedConversion};
+use sp_runtime::{
+    traits::{CheckedAdd, Saturating, Zero},
+    SaturatedConversion,
+};
 
-use sp_std::vec;
+use sp_std::{
+    convert::{TryFrom, TryInto},
+    vec,
+    vec::Vec,
+};
 
 // crate modules
 mod benchmarking;
 mod errors;
 mod events;
+pub mod migrations;
 mod tests;
 pub mod types;
 pub mod weights;
@@ -36,23 +45,53 @@ pub use weights::WeightInfo;
 
 // crate imports
 use common::costs::{burn_from_usable, has_sufficient_balance_for_payment};
+use common::council::CouncilBudgetManager;
+use common::membership::MemberOriginValidator;
 pub use errors::Error;
 pub use events::{Event, RawEvent};
+use project_token::traits::PalletToken;
+use project_token::types::{
+    AmmParamsOf, TokenIssuanceParametersOf, TokenSaleParamsOf, TransferWithVestingOutputsOf,
+    UploadContextOf,
+};
 use types::*;
 type WeightInfoArgo<T> = <T as Config>::WeightInfo;
 
-pub trait Config: frame_system::Config + balances::Config {
+const CURRENT_STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
+pub trait Config: frame_system::Config + balances::Config + project_token::Config {
     // /// Events
     type RuntimeEvent: From<Event<Self>> + Into<<Self as frame_system::Config>::RuntimeEvent>;
 
     /// Max number of accounts allow to pause the bridge
     type MaxPauserAccounts: Get<u32>;
 
+    /// Max number of accounts allowed to attest inbound transfers
+    type MaxAttesterAccounts: Get<u32>;
+
     /// Weight information for extrinsics in this pallet.
     type WeightInfo: WeightInfo;
 
     /// Defines the default bridging fee.
     type DefaultBridgingFee: Get<BalanceOf<Self>>;
+
+    /// Interface for Creator Tokens functionality
+    type ProjectToken: PalletToken<
+        Self::TokenId,
+        Self::MemberId,
+        Self::AccountId,
+        BalanceOf<Self>,
+        TokenIssuanceParametersOf<Self>,
+        Self::BlockNumber,
+        TokenSaleParamsOf<Self>,
+        UploadContextOf<Self>,
+        TransferWithVestingOutputsOf<Self>,
+        AmmParamsOf<Self>,
+        TokenBalanceOf<Self>,
+    >;
+
+    /// Council budget manager, receives the council share of the bridging fees
+    type CouncilBudgetManager: CouncilBudgetManager<Self::AccountId, BalanceOf<Self>>;
 }
 
 decl_storage! { generate_storage_info
@@ -65,18 +104,71 @@ decl_storage! { generate_storage_info
         /// List of account IDs with permission to pause the bridge operations
         pub PauserAccounts get(fn pauser_accounts): BoundedVec<T::AccountId, T::MaxPauserAccounts>;
 
+        /// List of account IDs with permission to attest inbound transfers
+        pub AttesterAccounts get(fn attester_accounts): BoundedVec<T::AccountId, T::MaxAttesterAccounts>;
+
+        /// Number of matching attestations needed before an inbound transfer is finalized
+        pub AttestationThreshold get(fn attestation_threshold): u32;
+
+        /// Number of blocks after which a pending attestation is considered stale
+        pub AttestationTtl get(fn attestation_ttl) config(): T::BlockNumber = DEFAULT_ATTESTATION_TTL.into();
+
+        /// Pending attestations of the inbound transfers: remote_transfer => attester => attestation
+        pub InboundTransferAttestations get(fn inbound_transfer_attestations):
+            double_map hasher(blake2_128_concat) RemoteTransfer, hasher(blake2_128_concat) T::AccountId => Option<InboundTransferAttestationOf<T>>;
+
         /// Number of tokens that the bridge pallet is able to mint
         pub MintAllowance get(fn mint_allowance) config(): BalanceOf<T> = 0u32.into();
 
-        /// Amount of JOY burned as a fee for each transfer
+        /// Number of creator tokens that the bridge pallet is able to mint, per token
+        pub CreatorTokenMintAllowance get(fn creator_token_mint_allowance):
+            map hasher(blake2_128_concat) T::TokenId => BalanceOf<T>;
+
+        /// Creator tokens of the reverted outbound transfers that could not be minted back yet:
+        /// token_id => member_id => amount
+        pub PendingCreatorTokenRefunds get(fn pending_creator_token_refunds):
+            double_map hasher(blake2_128_concat) T::TokenId, hasher(blake2_128_concat) T::MemberId => BalanceOf<T>;
+
+        /// Amount of JOY charged as a fee for each transfer to a remote chain without a fee schedule
         pub BridgingFee get(fn bridging_fee) config(): BalanceOf<T>;
 
+        /// Per remote chain fee schedules of the outbound transfers
+        pub RemoteChainFees get(fn remote_chain_fees):
+            map hasher(blake2_128_concat) ChainId => Option<FeeScheduleOf<T>>;
+
+        /// Split of the collected fees between the council budget and the operator reward, the rest is burned
+        pub BridgingFeeDistribution get(fn bridging_fee_distribution): FeeDistribution;
+
+        /// Account receiving the operator share of the collected fees
+        pub OperatorRewardAccount get(fn operator_reward_account): Option<T::AccountId>;
+
         /// Number of blocks needed before bridge unpause can be finalised
         pub ThawnDuration get(fn thawn_duration) config(): T::BlockNumber;
 
         pub NextTransferId get(fn next_transfer_id): TransferId;
 
+        /// Outbound transfers requested on this chain, kept until completed or reverted and pruned
+        pub OutboundTransfers get(fn outbound_transfers):
+            map hasher(blake2_128_concat) TransferId => Option<OutboundTransferOf<T>>;
+
         pub RemoteChains get(fn remote_chains): BoundedVec<ChainId, ConstU32<MAX_REMOTE_CHAINS>>;
+
+        /// Global and per remote chain limits of the amount minted / burned within a rolling window of blocks
+        pub RateLimits get(fn rate_limits):
+            map hasher(blake2_128_concat) RateLimitKeyOf<T> => RateLimitOf<T>;
+
+        /// Amount minted / burned within the rolling window of each rate limit
+        pub RateLimitsUsage get(fn rate_limits_usage):
+            map hasher(blake2_128_concat) RateLimitKeyOf<T> => RateLimitUsageOf<T>;
+
+        /// Registry of the already finalized inbound transfers: (chain_id, transfer_id) => finalization block
+        pub ProcessedInboundTransfers get(fn processed_inbound_transfers):
+            double_map hasher(blake2_128_concat) ChainId, hasher(blake2_128_concat) TransferId => Option<T::BlockNumber>;
+
+        /// Per remote chain lowest transfer id still tracked by the registry,
+        /// all the transfers with a lower id are considered processed (and pruned)
+        pub ProcessedInboundTransfersFloor get(fn processed_inbound_transfers_floor):
+            map hasher(blake2_128_concat) ChainId => TransferId;
     }
 }
 
@@ -89,76 +181,473 @@ decl_module! {
         /// Default deposit_event() handler
         fn deposit_event() = default;
 
+        /// Request a transfer of JOY or creator tokens to a remote chain
+        /// Preconditions:
+        /// - bridge is active and the remote chain is supported
+        /// - `expected_fee` equals the fee computed for the remote chain and amount
+        /// - for creator tokens: origin is signed by the controller account of the
+        ///   token holder member and the token issuer opted in for bridging
+        /// PostConditions:
+        /// - `amount` of the asset is burned
+        /// - JOY fee split between the council budget, the operator reward account and burning
+        /// - mint allowance of the asset increased by `amount`
+        /// - outbound transfer recorded as pending
+        /// - if a burn rate limit would be exceeded the bridge is paused instead
+        ///   and nothing is burned
+        /// <weight>
+        ///
+        /// ## Weight
+        /// `O (1)`
+        /// # </weight>
         #[weight = WeightInfoArgo::<T>::request_outbound_transfer()]
-        pub fn request_outbound_transfer(origin, dest_account: RemoteAccount, amount: BalanceOf<T>, expected_fee: BalanceOf<T>) -> DispatchResult {
+        pub fn request_outbound_transfer(
+            origin,
+            dest_account: RemoteAccount,
+            asset: BridgedAssetOf<T>,
+            amount: BalanceOf<T>,
+            expected_fee: BalanceOf<T>,
+        ) -> DispatchResult {
             Self::ensure_bridge_active()?;
 
             Self::ensure_chain_supported(dest_account.chain_id)?;
 
-            let fee = Self::bridging_fee();
+            let fee = Self::outbound_transfer_fee(&asset, dest_account.chain_id, amount);
             ensure!(fee == expected_fee, Error::<T>::FeeDifferentThanExpected);
 
-            let amount_with_fees = amount.checked_add(&fee).ok_or(Error::<T>::ArithmeticError)?;
             let sender = ensure_signed(origin)?;
-            ensure!(has_sufficient_balance_for_payment::<T>(&sender, amount_with_fees), Error::<T>::InsufficientJoyBalance);
+            let joy_amount = match asset {
+                BridgedAsset::Joy => amount.checked_add(&fee).ok_or(Error::<T>::ArithmeticError)?,
+                BridgedAsset::CreatorToken(_, member_id) => {
+                    Self::ensure_member_controller_account(&member_id, &sender)?;
+                    fee
+                }
+            };
+            ensure!(has_sufficient_balance_for_payment::<T>(&sender, joy_amount), Error::<T>::InsufficientJoyBalance);
+            // JOY is burned from the usable balance after the creator tokens are burned
+            ensure!(
+                balances::Pallet::<T>::usable_balance(&sender) >= joy_amount,
+                Error::<T>::InsufficientJoyBalance
+            );
             let transfer_id = NextTransferId::get();
             let next_transfer_id = transfer_id.checked_add(1).ok_or(Error::<T>::ArithmeticError)?;
+            let rate_limits_usage = Self::rate_limits_usage_after_transfer(
+                &asset,
+                TransferDirection::Outbound,
+                dest_account.chain_id,
+                amount,
+            );
+
+            // Call to ProjectToken - should be the first call before MUTATION SAFE!
+            if let (BridgedAsset::CreatorToken(token_id, member_id), Ok(_)) = (asset, &rate_limits_usage) {
+                T::ProjectToken::burn_bridged_tokens(token_id, member_id, Self::to_token_balance(amount)?)?;
+            }
 
             //
             // == MUTATION SAFE ==
             //
 
-            burn_from_usable::<T>(&sender, amount_with_fees)?;
-            <MintAllowance<T>>::put(Self::mint_allowance() + amount);
-
-            Self::deposit_event(RawEvent::OutboundTransferRequested(transfer_id, sender, dest_account, amount, fee));
+            // Burn rate limit exceeded: pause the bridge without requesting the transfer
+            let rate_limits_usage = match rate_limits_usage {
+                Ok(rate_limits_usage) => rate_limits_usage,
+                Err(exceeded_rate_limit) => {
+                    Self::pause_bridge_by_rate_limit(exceeded_rate_limit);
+                    return Ok(());
+                }
+            };
+
+            burn_from_usable::<T>(&sender, joy_amount)?;
+            Self::distribute_fee(fee);
+            Self::increase_mint_allowance(&asset, amount);
+            Self::update_rate_limits_usage(rate_limits_usage);
+
+            <OutboundTransfers<T>>::insert(transfer_id, OutboundTransfer {
+                sender: sender.clone(),
+                dest_account,
+                asset,
+                amount,
+                fee,
+                status: OutboundTransferStatus::Pending,
+            });
+
+            Self::deposit_event(RawEvent::OutboundTransferRequested(transfer_id, sender, dest_account, asset, amount, fee));
             NextTransferId::put(next_transfer_id);
 
             Ok(())
         }
 
-        #[weight = WeightInfoArgo::<T>::finalize_inbound_transfer()]
-        pub fn finalize_inbound_transfer(origin, remote_transfer: RemoteTransfer, dest_account: T::AccountId, amount: BalanceOf<T>) -> DispatchResult {
+        /// Attest an inbound transfer, finalizing it once enough matching attestations are collected
+        /// Preconditions:
+        /// - origin is signed by one of the attester accounts
+        /// - remote transfer was not processed yet
+        /// - for creator tokens: `dest_account` is the controller account of the
+        ///   token holder member
+        /// PostConditions:
+        /// - if the attestation threshold is reached for the identical
+        ///   `(remote_transfer, dest_account, asset, amount)` the tokens are minted and the
+        ///   transfer is recorded as processed
+        /// - otherwise the attestation is stored until it goes stale
+        /// - if a mint rate limit would be exceeded the attestation is stored and the bridge
+        ///   is paused instead of finalizing the transfer
+        /// <weight>
+        ///
+        /// ## Weight
+        /// `O (A)` where:
+        /// - `A` is the max number of attester accounts
+        /// # </weight>
+        #[weight = WeightInfoArgo::<T>::finalize_inbound_transfer(T::MaxAttesterAccounts::get())]
+        pub fn finalize_inbound_transfer(
+            origin,
+            remote_transfer: RemoteTransfer,
+            dest_account: T::AccountId,
+            asset: BridgedAssetOf<T>,
+            amount: BalanceOf<T>,
+        ) -> DispatchResult {
             Self::ensure_bridge_active()?;
 
-            Self::ensure_operator_origin(origin)?;
+            let attester = Self::ensure_attester_origin(origin)?;
 
             Self::ensure_chain_supported(remote_transfer.chain_id)?;
 
-            Self::ensure_mint_allowance(amount)?;
+            Self::ensure_inbound_transfer_not_processed(&remote_transfer)?;
+
+            if let BridgedAsset::CreatorToken(_, member_id) = asset {
+                Self::ensure_member_controller_account(&member_id, &dest_account)?;
+            }
+
+            let current_block = <frame_system::Pallet<T>>::block_number();
+            let attesters = Self::attester_accounts();
+            let mut matching_attestations = 1u32;
+            let mut has_conflicts = false;
+            let mut stale_attesters = vec![];
+            for (account, attestation) in <InboundTransferAttestations<T>>::iter_prefix(&remote_transfer) {
+                if account == attester {
+                    continue;
+                }
+                if !attesters.contains(&account) || Self::is_attestation_stale(&attestation, current_block) {
+                    stale_attesters.push(account);
+                } else if attestation.dest_account == dest_account
+                    && attestation.asset == asset
+                    && attestation.amount == amount
+                {
+                    matching_attestations = matching_attestations.saturating_add(1);
+                } else {
+                    has_conflicts = true;
+                }
+            }
+
+            let threshold_reached = matching_attestations >= Self::attestation_threshold();
+            let rate_limits_usage = if threshold_reached {
+                Self::ensure_mint_allowance(&asset, amount)?;
+                Some(Self::rate_limits_usage_after_transfer(
+                    &asset,
+                    TransferDirection::Inbound,
+                    remote_transfer.chain_id,
+                    amount,
+                ))
+            } else {
+                None
+            };
+
+            // Call to ProjectToken - should be the first call before MUTATION SAFE!
+            if let (BridgedAsset::CreatorToken(token_id, member_id), Some(Ok(_))) = (asset, &rate_limits_usage) {
+                T::ProjectToken::mint_bridged_tokens(token_id, member_id, Self::to_token_balance(amount)?)?;
+            }
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            for account in stale_attesters {
+                <InboundTransferAttestations<T>>::remove(&remote_transfer, account);
+            }
+
+            if has_conflicts {
+                Self::deposit_event(RawEvent::ConflictingInboundTransferAttestation(
+                    remote_transfer.clone(),
+                    attester.clone(),
+                    dest_account.clone(),
+                    asset,
+                    amount,
+                ));
+            }
+
+            if let Some(Ok(rate_limits_usage)) = rate_limits_usage {
+                // Attestations left by the former attesters are pruned separately
+                let _ = <InboundTransferAttestations<T>>::clear_prefix(
+                    &remote_transfer,
+                    T::MaxAttesterAccounts::get(),
+                    None,
+                );
+
+                Self::update_rate_limits_usage(rate_limits_usage);
+
+                Self::mint_tokens(&asset, &dest_account, amount);
+
+                <ProcessedInboundTransfers<T>>::insert(remote_transfer.chain_id, remote_transfer.id, current_block);
+
+                Self::deposit_event(RawEvent::InboundTransferFinalized(remote_transfer, dest_account, asset, amount));
+            } else {
+                <InboundTransferAttestations<T>>::insert(&remote_transfer, &attester, InboundTransferAttestation {
+                    dest_account: dest_account.clone(),
+                    asset,
+                    amount,
+                    attested_at: current_block,
+                });
+
+                Self::deposit_event(RawEvent::InboundTransferAttested(remote_transfer, attester, dest_account, asset, amount));
+
+                // Mint rate limit exceeded: keep the attestation and pause the bridge
+                // without finalizing the transfer
+                if let Some(Err(exceeded_rate_limit)) = rate_limits_usage {
+                    Self::pause_bridge_by_rate_limit(exceeded_rate_limit);
+                }
+            }
+
+            Ok(())
+        }
+
+        /// Remove the attestations of an inbound transfer that can no longer count
+        /// towards its finalization
+        /// Preconditions:
+        /// - origin is signed
+        /// - at least one attestation of the remote transfer is stale, made by an account
+        ///   that is no longer an attester, or the remote transfer is already processed
+        /// PostConditions:
+        /// - such attestations are removed, up to the max number of attester accounts
+        /// <weight>
+        ///
+        /// ## Weight
+        /// `O (A)` where:
+        /// - `A` is the max number of attester accounts
+        /// # </weight>
+        #[weight = WeightInfoArgo::<T>::prune_inbound_transfer_attestations(T::MaxAttesterAccounts::get())]
+        pub fn prune_inbound_transfer_attestations(origin, remote_transfer: RemoteTransfer) -> DispatchResult {
+            ensure_signed(origin)?;
+
+            let current_block = <frame_system::Pallet<T>>::block_number();
+            let is_processed = Self::is_inbound_transfer_processed(&remote_transfer);
+            let attesters = Self::attester_accounts();
+            let pruned_attesters = <InboundTransferAttestations<T>>::iter_prefix(&remote_transfer)
+                .filter(|(account, attestation)| {
+                    is_processed
+                        || !attesters.contains(account)
+                        || Self::is_attestation_stale(attestation, current_block)
+                })
+                .map(|(account, _)| account)
+                .take(T::MaxAttesterAccounts::get().saturated_into())
+                .collect::<Vec<_>>();
+            ensure!(!pruned_attesters.is_empty(), Error::<T>::NoInboundTransferAttestationsToPrune);
 
             //
             // == MUTATION SAFE ==
             //
 
-            Self::mint_tokens(&dest_account, amount);
+            let pruned_count = pruned_attesters.len().saturated_into::<u32>();
+            for account in pruned_attesters {
+                <InboundTransferAttestations<T>>::remove(&remote_transfer, account);
+            }
 
-            Self::deposit_event(RawEvent::InboundTransferFinalized(remote_transfer, dest_account, amount));
+            Self::deposit_event(RawEvent::InboundTransferAttestationsPruned(remote_transfer, pruned_count));
 
             Ok(())
         }
 
+        /// Revert a pending outbound transfer, returning the transferred asset to its sender
+        /// Preconditions:
+        /// - origin is signed by the operator account
+        /// - outbound transfer exists and is pending
+        /// PostConditions:
+        /// - recorded amount minted back to the recorded sender (or token holder member)
+        /// - if the creator tokens cannot be minted back at the moment (ie. the token supply
+        ///   cannot be modified) the amount is recorded as a pending refund claimable by the member
+        /// - outbound transfer status set to `Reverted`
+        /// <weight>
+        ///
+        /// ## Weight
+        /// `O (1)`
+        /// # </weight>
         #[weight = WeightInfoArgo::<T>::revert_outbound_transfer()]
         pub fn revert_outbound_transfer(
             origin,
             transfer_id: TransferId,
-            revert_account: T::AccountId,
-            revert_amount: BalanceOf<T>,
             rationale: BoundedVec<u8, ConstU32<MAX_BYTES_RATIONALE>>,
         ) -> DispatchResult {
             Self::ensure_bridge_active()?;
 
             Self::ensure_operator_origin(origin)?;
 
-            Self::ensure_mint_allowance(revert_amount)?;
+            let transfer = Self::ensure_pending_outbound_transfer(transfer_id)?;
+
+            Self::ensure_mint_allowance(&transfer.asset, transfer.amount)?;
+
+            // Call to ProjectToken - should be the first call before MUTATION SAFE!
+            // A failed mint doesn't block the revert, the tokens are refunded later instead
+            let creator_tokens_minted = match transfer.asset {
+                BridgedAsset::CreatorToken(token_id, member_id) => Self::to_token_balance(transfer.amount)
+                    .and_then(|amount| T::ProjectToken::mint_bridged_tokens(token_id, member_id, amount))
+                    .is_ok(),
+                BridgedAsset::Joy => false,
+            };
 
             //
             // == MUTATION SAFE ==
             //
 
-            Self::mint_tokens(&revert_account, revert_amount);
+            Self::mint_tokens(&transfer.asset, &transfer.sender, transfer.amount);
+
+            if let (BridgedAsset::CreatorToken(token_id, member_id), false) = (transfer.asset, creator_tokens_minted) {
+                <PendingCreatorTokenRefunds<T>>::mutate(token_id, member_id, |refund| {
+                    *refund = refund.saturating_add(transfer.amount)
+                });
+
+                Self::deposit_event(RawEvent::CreatorTokenRefundDeferred(transfer_id, token_id, member_id, transfer.amount));
+            }
+
+            <OutboundTransfers<T>>::insert(transfer_id, OutboundTransfer {
+                status: OutboundTransferStatus::Reverted,
+                ..transfer.clone()
+            });
 
-            Self::deposit_event(RawEvent::OutboundTransferReverted(transfer_id, revert_account, revert_amount, rationale));
+            Self::deposit_event(RawEvent::OutboundTransferReverted(transfer_id, transfer.sender, transfer.amount, rationale));
+
+            Ok(())
+        }
+
+        /// Mint back the creator tokens of the reverted outbound transfers that could not be
+        /// refunded at the time of the revert
+        /// Preconditions:
+        /// - origin is signed by the controller account of the token holder member
+        /// - a refund of the creator token is pending for the member
+        /// - the creator tokens can be minted (ie. the token supply can be modified)
+        /// PostConditions:
+        /// - pending refund amount minted to the token holder member
+        /// - pending refund removed
+        /// <weight>
+        ///
+        /// ## Weight
+        /// `O (1)`
+        /// # </weight>
+        #[weight = WeightInfoArgo::<T>::claim_creator_token_refund()]
+        pub fn claim_creator_token_refund(origin, token_id: T::TokenId, member_id: T::MemberId) -> DispatchResult {
+            let sender = ensure_signed(origin)?;
+
+            Self::ensure_member_controller_account(&member_id, &sender)?;
+
+            let amount = Self::pending_creator_token_refunds(token_id, member_id);
+            ensure!(!amount.is_zero(), Error::<T>::NoPendingCreatorTokenRefund);
+
+            // Call to ProjectToken - should be the first call before MUTATION SAFE!
+            T::ProjectToken::mint_bridged_tokens(token_id, member_id, Self::to_token_balance(amount)?)?;
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            <PendingCreatorTokenRefunds<T>>::remove(token_id, member_id);
+
+            Self::deposit_event(RawEvent::CreatorTokenRefundClaimed(token_id, member_id, amount));
+
+            Ok(())
+        }
+
+        /// Acknowledge a pending outbound transfer as completed on the remote chain
+        /// Preconditions:
+        /// - origin is signed by the operator account
+        /// - outbound transfer exists and is pending
+        /// PostConditions:
+        /// - outbound transfer status set to `Completed`
+        /// <weight>
+        ///
+        /// ## Weight
+        /// `O (1)`
+        /// # </weight>
+        #[weight = WeightInfoArgo::<T>::complete_outbound_transfer()]
+        pub fn complete_outbound_transfer(origin, transfer_id: TransferId) -> DispatchResult {
+            Self::ensure_operator_origin(origin)?;
+
+            let transfer = Self::ensure_pending_outbound_transfer(transfer_id)?;
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            <OutboundTransfers<T>>::insert(transfer_id, OutboundTransfer {
+                status: OutboundTransferStatus::Completed,
+                ..transfer
+            });
+
+            Self::deposit_event(RawEvent::OutboundTransferCompleted(transfer_id));
+
+            Ok(())
+        }
+
+        /// Remove a completed or reverted outbound transfer from storage
+        /// Preconditions:
+        /// - origin is signed by the operator account
+        /// - outbound transfer exists and is no longer pending
+        /// PostConditions:
+        /// - outbound transfer removed from storage
+        /// <weight>
+        ///
+        /// ## Weight
+        /// `O (1)`
+        /// # </weight>
+        #[weight = WeightInfoArgo::<T>::prune_outbound_transfer()]
+        pub fn prune_outbound_transfer(origin, transfer_id: TransferId) -> DispatchResult {
+            Self::ensure_operator_origin(origin)?;
+
+            let transfer = Self::ensure_outbound_transfer_exists(transfer_id)?;
+            ensure!(!transfer.is_pending(), Error::<T>::OutboundTransferStillPending);
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            <OutboundTransfers<T>>::remove(transfer_id);
+
+            Self::deposit_event(RawEvent::OutboundTransferPruned(transfer_id));
+
+            Ok(())
+        }
+
+        /// Prune the processed inbound transfers registry for a remote chain
+        /// Preconditions:
+        /// - origin is signed by the operator account
+        /// - `new_floor` is greater than the current floor for `chain_id`
+        /// - at most `MAX_PRUNED_INBOUND_TRANSFERS` ids are pruned at once
+        /// PostConditions:
+        /// - existing registry entries with ids in `[current_floor, new_floor)` are removed
+        /// - all transfers with ids lower than `new_floor` are considered processed and
+        ///   rejected if attested again
+        /// <weight>
+        ///
+        /// ## Weight
+        /// `O (MAX_PRUNED_INBOUND_TRANSFERS)`
+        /// # </weight>
+        #[weight = WeightInfoArgo::<T>::prune_processed_inbound_transfers()]
+        pub fn prune_processed_inbound_transfers(origin, chain_id: ChainId, new_floor: TransferId) -> DispatchResult {
+            Self::ensure_operator_origin(origin)?;
+
+            let current_floor = Self::processed_inbound_transfers_floor(chain_id);
+            ensure!(new_floor > current_floor, Error::<T>::InvalidProcessedInboundTransfersFloor);
+            let pruned_count = new_floor.checked_sub(current_floor).ok_or(Error::<T>::ArithmeticError)?;
+            ensure!(
+                pruned_count <= MAX_PRUNED_INBOUND_TRANSFERS.into(),
+                Error::<T>::TooManyInboundTransfersToPrune
+            );
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            for transfer_id in current_floor..new_floor {
+                if <ProcessedInboundTransfers<T>>::contains_key(chain_id, transfer_id) {
+                    <ProcessedInboundTransfers<T>>::remove(chain_id, transfer_id);
+                }
+            }
+            ProcessedInboundTransfersFloor::insert(chain_id, new_floor);
+
+            Self::deposit_event(RawEvent::ProcessedInboundTransfersPruned(chain_id, new_floor));
 
             Ok(())
         }
@@ -228,6 +717,7 @@ decl_module! {
         /// Allow Governance to Set constraints
         /// Preconditions:
         /// - origin is signed by `root`
+        /// - creator tokens of the provided mint allowances exist
         /// PostConditions:
         /// - governance parameters storage value set to the provided values
         /// <weight>
@@ -239,6 +729,26 @@ decl_module! {
         pub fn update_bridge_constrains(origin, parameters: BridgeConstraintsOf<T>) -> DispatchResult {
             ensure_root(origin)?;
 
+            let attesters_count = match parameters.attester_accounts {
+                // converts into range [0, u32::MAX], no risk as we might assume that the number of attesters is less than 100
+                Some(ref new_attester_accounts) => new_attester_accounts.len().saturated_into::<u32>(),
+                None => Self::attester_accounts().len().saturated_into::<u32>(),
+            };
+            ensure!(attesters_count <= T::MaxAttesterAccounts::get(), Error::<T>::InvalidNumberOfAttesterAccounts);
+            let attestation_threshold = parameters.attestation_threshold.unwrap_or_else(Self::attestation_threshold);
+            ensure!(
+                attestation_threshold <= attesters_count && (attestation_threshold > 0 || attesters_count == 0),
+                Error::<T>::InvalidAttestationThreshold
+            );
+            if let Some(ref new_fee_distribution) = parameters.fee_distribution {
+                ensure!(new_fee_distribution.is_valid(), Error::<T>::InvalidFeeDistribution);
+            }
+            if let Some(ref new_mint_allowances) = parameters.creator_token_mint_allowances {
+                for (token_id, _) in new_mint_allowances.iter() {
+                    ensure!(T::ProjectToken::token_exists(*token_id), Error::<T>::CreatorTokenNotFound);
+                }
+            }
+
             if let Some(ref new_operator_account) = parameters.operator_account {
                 <OperatorAccount<T>>::put(new_operator_account);
             }
@@ -261,11 +771,57 @@ decl_module! {
                 RemoteChains::put(new_remote_chains);
              }
 
+            if let Some(ref new_attester_accounts) = parameters.attester_accounts {
+                <AttesterAccounts<T>>::put(BoundedVec::truncate_from(new_attester_accounts.to_vec()));
+            }
+
+            AttestationThreshold::put(attestation_threshold);
+
+            if let Some(new_attestation_ttl) = parameters.attestation_ttl {
+                <AttestationTtl<T>>::put(new_attestation_ttl);
+            }
+
+            if let Some(ref new_rate_limits) = parameters.rate_limits {
+                for (key, rate_limit) in new_rate_limits.iter() {
+                    if rate_limit.is_enabled() {
+                        <RateLimits<T>>::insert(key, rate_limit);
+                    } else {
+                        <RateLimits<T>>::remove(key);
+                        <RateLimitsUsage<T>>::remove(key);
+                    }
+                }
+            }
+
+            if let Some(ref new_remote_chain_fees) = parameters.remote_chain_fees {
+                for (chain_id, fee_schedule) in new_remote_chain_fees.iter() {
+                    match fee_schedule {
+                        Some(fee_schedule) => <RemoteChainFees<T>>::insert(chain_id, fee_schedule),
+                        None => <RemoteChainFees<T>>::remove(chain_id),
+                    }
+                }
+            }
+
+            if let Some(new_fee_distribution) = parameters.fee_distribution {
+                BridgingFeeDistribution::put(new_fee_distribution);
+            }
+
+            if let Some(ref new_operator_reward_account) = parameters.operator_reward_account {
+                <OperatorRewardAccount<T>>::put(new_operator_reward_account);
+            }
+
+            if let Some(ref new_mint_allowances) = parameters.creator_token_mint_allowances {
+                for (token_id, mint_allowance) in new_mint_allowances.iter() {
+                    <CreatorTokenMintAllowance<T>>::insert(token_id, mint_allowance);
+                }
+            }
+
              Self::deposit_event(RawEvent::BridgeConfigUpdated(parameters));
 
             Ok(())
         }
 
+        type StorageVersion = CURRENT_STORAGE_VERSION;
+
     }
 }
 
@@ -289,6 +845,13 @@ impl<T: Config> Module<T> {
         }
     }
 
+    pub fn ensure_attester_origin(origin: T::RuntimeOrigin) -> Result<T::AccountId, DispatchError> {
+        let caller = ensure_signed(origin)?;
+        let accounts = Self::attester_accounts();
+        ensure!(accounts.contains(&caller), Error::<T>::NotAttesterAccount);
+        Ok(caller)
+    }
+
     pub fn ensure_pauser_origin(origin: T::RuntimeOrigin) -> Result<T::AccountId, DispatchError> {
         let caller = ensure_signed(origin)?;
         let accounts = Self::pauser_accounts();
@@ -296,14 +859,78 @@ impl<T: Config> Module<T> {
         Ok(caller)
     }
 
-    pub fn ensure_mint_allowance(amount: BalanceOf<T>) -> DispatchResult {
+    pub fn ensure_member_controller_account(
+        member_id: &T::MemberId,
+        account_id: &T::AccountId,
+    ) -> DispatchResult {
         ensure!(
-            amount <= Self::mint_allowance(),
+            T::MemberOriginValidator::is_member_controller_account(member_id, account_id),
+            Error::<T>::NotMemberControllerAccount
+        );
+        Ok(())
+    }
+
+    /// Number of tokens of the asset that the bridge pallet is able to mint
+    pub fn asset_mint_allowance(asset: &BridgedAssetOf<T>) -> BalanceOf<T> {
+        match asset {
+            BridgedAsset::Joy => Self::mint_allowance(),
+            BridgedAsset::CreatorToken(token_id, _) => Self::creator_token_mint_allowance(token_id),
+        }
+    }
+
+    pub fn ensure_mint_allowance(
+        asset: &BridgedAssetOf<T>,
+        amount: BalanceOf<T>,
+    ) -> DispatchResult {
+        ensure!(
+            amount <= Self::asset_mint_allowance(asset),
             Error::<T>::InsufficientBridgeMintAllowance
         );
         Ok(())
     }
 
+    /// Fee of an outbound transfer: the remote chain fee schedule if set, the flat
+    /// `BridgingFee` otherwise. The percentage part only applies to JOY transfers.
+    pub fn outbound_transfer_fee(
+        asset: &BridgedAssetOf<T>,
+        chain_id: ChainId,
+        amount: BalanceOf<T>,
+    ) -> BalanceOf<T> {
+        match Self::remote_chain_fees(chain_id) {
+            Some(fee_schedule) => {
+                let percentage_part = match asset {
+                    BridgedAsset::Joy => fee_schedule.percentage_fee.mul_floor(amount),
+                    BridgedAsset::CreatorToken(..) => Zero::zero(),
+                };
+                fee_schedule.flat_fee.saturating_add(percentage_part)
+            }
+            None => Self::bridging_fee(),
+        }
+    }
+
+    /// Credit the council budget and the operator reward account with their shares of the
+    /// already burned fee, the operator share stays burned if no reward account is set
+    pub fn distribute_fee(fee: BalanceOf<T>) {
+        let distribution = Self::bridging_fee_distribution();
+
+        let council_share = distribution.council_budget_share.mul_floor(fee);
+        if !council_share.is_zero() {
+            T::CouncilBudgetManager::increase_budget(council_share);
+        }
+
+        if let Some(reward_account) = Self::operator_reward_account() {
+            let operator_share = distribution.operator_reward_share.mul_floor(fee);
+            if !operator_share.is_zero() {
+                let _ = balances::Pallet::<T>::deposit_creating(&reward_account, operator_share);
+            }
+        }
+    }
+
+    pub fn to_token_balance(amount: BalanceOf<T>) -> Result<TokenBalanceOf<T>, DispatchError> {
+        let amount: u128 = amount.try_into().map_err(|_| Error::<T>::ArithmeticError)?;
+        TokenBalanceOf::<T>::try_from(amount).map_err(|_| Error::<T>::ArithmeticError.into())
+    }
+
     pub fn ensure_chain_supported(chain_id: ChainId) -> DispatchResult {
         ensure!(
             RemoteChains::get().contains(&chain_id),
@@ -312,9 +939,137 @@ impl<T: Config> Module<T> {
         Ok(())
     }
 
-    pub fn mint_tokens(dest_account: &T::AccountId, amount: BalanceOf<T>) {
-        <MintAllowance<T>>::put(Self::mint_allowance() - amount);
-        let _ = balances::Pallet::<T>::deposit_creating(dest_account, amount);
+    pub fn ensure_outbound_transfer_exists(
+        transfer_id: TransferId,
+    ) -> Result<OutboundTransferOf<T>, DispatchError> {
+        Self::outbound_transfers(transfer_id)
+            .ok_or_else(|| Error::<T>::OutboundTransferNotFound.into())
+    }
+
+    pub fn ensure_pending_outbound_transfer(
+        transfer_id: TransferId,
+    ) -> Result<OutboundTransferOf<T>, DispatchError> {
+        let transfer = Self::ensure_outbound_transfer_exists(transfer_id)?;
+        ensure!(
+            transfer.is_pending(),
+            Error::<T>::OutboundTransferNotPending
+        );
+        Ok(transfer)
+    }
+
+    pub fn ensure_inbound_transfer_not_processed(
+        remote_transfer: &RemoteTransfer,
+    ) -> DispatchResult {
+        ensure!(
+            !Self::is_inbound_transfer_processed(remote_transfer),
+            Error::<T>::InboundTransferAlreadyProcessed
+        );
+        Ok(())
+    }
+
+    /// Whether the remote transfer was already finalized (or pruned from the registry)
+    pub fn is_inbound_transfer_processed(remote_transfer: &RemoteTransfer) -> bool {
+        remote_transfer.id < Self::processed_inbound_transfers_floor(remote_transfer.chain_id)
+            || <ProcessedInboundTransfers<T>>::contains_key(
+                remote_transfer.chain_id,
+                remote_transfer.id,
+            )
+    }
+
+    /// Whether the attestation was made more than `AttestationTtl` blocks ago
+    pub fn is_attestation_stale(
+        attestation: &InboundTransferAttestationOf<T>,
+        current_block: T::BlockNumber,
+    ) -> bool {
+        attestation
+            .attested_at
+            .saturating_add(Self::attestation_ttl())
+            < current_block
+    }
+
+    /// Global and remote chain rate limits usage of the asset after the transfer,
+    /// or the key of the first rate limit that would be exceeded.
+    /// Rate limits of a creator token are denominated in the token.
+    pub fn rate_limits_usage_after_transfer(
+        asset: &BridgedAssetOf<T>,
+        direction: TransferDirection,
+        chain_id: ChainId,
+        amount: BalanceOf<T>,
+    ) -> Result<Vec<(RateLimitKeyOf<T>, RateLimitUsageOf<T>)>, RateLimitKeyOf<T>> {
+        let token_id = match asset {
+            BridgedAsset::Joy => None,
+            BridgedAsset::CreatorToken(token_id, _) => Some(*token_id),
+        };
+        let current_block = <frame_system::Pallet<T>>::block_number();
+        let keys = [
+            RateLimitKey {
+                direction,
+                chain_id: None,
+                token_id,
+            },
+            RateLimitKey {
+                direction,
+                chain_id: Some(chain_id),
+                token_id,
+            },
+        ];
+
+        let mut usage = Vec::new();
+        for key in keys {
+            let rate_limit = Self::rate_limits(key);
+            if !rate_limit.is_enabled() {
+                continue;
+            }
+            let new_usage = Self::rate_limits_usage(key)
+                .with_transfer(&rate_limit, amount, current_block)
+                .ok_or(key)?;
+            usage.push((key, new_usage));
+        }
+        Ok(usage)
+    }
+
+    pub fn pause_bridge_by_rate_limit(exceeded_rate_limit: RateLimitKeyOf<T>) {
+        <Status<T>>::put(BridgeStatus::Paused);
+        Self::deposit_event(RawEvent::BridgePausedByRateLimit(exceeded_rate_limit));
+    }
+
+    pub fn update_rate_limits_usage(usage: Vec<(RateLimitKeyOf<T>, RateLimitUsageOf<T>)>) {
+        for (key, new_usage) in usage {
+            <RateLimitsUsage<T>>::insert(key, new_usage);
+        }
+    }
+
+    pub fn increase_mint_allowance(asset: &BridgedAssetOf<T>, amount: BalanceOf<T>) {
+        match asset {
+            BridgedAsset::Joy => {
+                <MintAllowance<T>>::put(Self::mint_allowance().saturating_add(amount))
+            }
+            BridgedAsset::CreatorToken(token_id, _) => {
+                <CreatorTokenMintAllowance<T>>::mutate(token_id, |allowance| {
+                    *allowance = allowance.saturating_add(amount)
+                })
+            }
+        }
+    }
+
+    /// Decrease the mint allowance of the asset and, for JOY, deposit the amount
+    /// (creator tokens are minted through `ProjectToken` before any mutation)
+    pub fn mint_tokens(
+        asset: &BridgedAssetOf<T>,
+        dest_account: &T::AccountId,
+        amount: BalanceOf<T>,
+    ) {
+        match asset {
+            BridgedAsset::Joy => {
+                <MintAllowance<T>>::put(Self::mint_allowance().saturating_sub(amount));
+                let _ = balances::Pallet::<T>::deposit_creating(dest_account, amount);
+            }
+            BridgedAsset::CreatorToken(token_id, _) => {
+                <CreatorTokenMintAllowance<T>>::mutate(token_id, |allowance| {
+                    *allowance = allowance.saturating_sub(amount)
+                })
+            }
+        }
     }
 }
 
@@ -0,0 +1,44 @@
+// Migrations for Argo Bridge Pallet
+
+use super::*;
+use frame_support::traits::{GetStorageVersion, OnRuntimeUpgrade};
+use frame_support::weights::Weight;
+use sp_std::marker::PhantomData;
+
+pub mod v1 {
+    use super::*;
+
+    /// Seeds the inbound transfer attestation parameters, so the operator account that used to
+    /// finalize the inbound transfers alone keeps doing so as the only attester until
+    /// the governance sets up the attesters.
+    pub struct MigrateToV1<T>(PhantomData<T>);
+
+    impl<T: Config> OnRuntimeUpgrade for MigrateToV1<T> {
+        fn on_runtime_upgrade() -> Weight {
+            let onchain = Pallet::<T>::on_chain_storage_version();
+            let current = Pallet::<T>::current_storage_version();
+
+            if onchain > 0 {
+                return T::DbWeight::get().reads(1);
+            }
+
+            if !<AttestationTtl<T>>::exists() {
+                <AttestationTtl<T>>::put(T::BlockNumber::from(DEFAULT_ATTESTATION_TTL));
+            }
+
+            if Pallet::<T>::attester_accounts().is_empty() {
+                let attester_accounts = BoundedVec::<_, T::MaxAttesterAccounts>::truncate_from(
+                    Pallet::<T>::operator_account().into_iter().collect(),
+                );
+                if !attester_accounts.is_empty() {
+                    <AttesterAccounts<T>>::put(attester_accounts);
+                    AttestationThreshold::put(1);
+                }
+            }
+
+            current.put::<Pallet<T>>();
+
+            T::DbWeight::get().reads_writes(4, 4)
+        }
+    }
+}
@@ -8,10 +8,16 @@ use frame_support::{
     traits::{OnFinalize, OnInitialize},
 };
 
-use frame_support::traits::{ConstU16, ConstU32, ConstU64};
+use common::locks::{BoundStakingAccountLockId, InvitedMemberLockId};
+use common::membership::{MemberOriginValidator, MembershipInfoProvider};
+use frame_support::traits::{ConstU16, ConstU32, ConstU64, Currency, LockIdentifier};
+use frame_support::{ensure, PalletId};
+use frame_system::ensure_signed;
 use sp_runtime::testing::{Header, H256};
 use sp_runtime::traits::{BlakeTwo256, IdentityLookup};
+use sp_runtime::{DispatchError, DispatchResult};
 use sp_std::convert::{TryFrom, TryInto};
+use staking_handler::LockComparator;
 
 // Crate aliases
 type BalanceOf<T> = <T as balances::Config>::Balance;
@@ -20,6 +26,8 @@ type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Test>;
 type Block = frame_system::mocking::MockBlock<Test>;
 pub type AccountId = <Test as frame_system::Config>::AccountId;
 pub type BlockNumber = <Test as frame_system::Config>::BlockNumber;
+pub type MemberId = <Test as common::membership::MembershipTypes>::MemberId;
+pub type TokenId = <Test as project_token::Config>::TokenId;
 
 parameter_types! {
     pub const BlockHashCount: u64 = 250;
@@ -28,11 +36,36 @@ parameter_types! {
     pub const DefaultMembershipPrice: u64 = 100;
     pub const DefaultInitialInvitationBalance: u64 = 100;
     pub const DefaultMemberInvitesCount: u32 = 2;
+    pub const HandleReclaimCooldown: u64 = 1000;
+    pub const MaxRecoveryGuardians: u32 = 5;
+    pub const RecoveryDelay: u64 = 100;
+    pub const RecoveryCooldown: u64 = 50;
+    pub const ReferralCutMaximumPercent: u8 = 50;
+    pub const CandidateStake: u64 = 100;
+    // constants for storage::Config
+    pub const MaxDistributionBucketFamilyNumber: u64 = 4;
+    pub const StorageModuleId: PalletId = PalletId(*b"mstorage");
+    pub const BlacklistSizeLimit: u64 = 1;
+    pub const MaxNumberOfPendingInvitationsPerDistributionBucket: u32 = 1;
+    pub const DefaultMemberDynamicBagNumberOfStorageBuckets: u32 = 3;
+    pub const DefaultChannelDynamicBagNumberOfStorageBuckets: u32 = 4;
+    pub const MaxDataObjectSize: u64 = 1_000_000_000;
+    pub const MinStorageBucketsPerBag: u32 = 3;
+    pub const MaxStorageBucketsPerBag: u32 = 10;
+    pub const MinDistributionBucketsPerBag: u32 = 3;
+    pub const MaxDistributionBucketsPerBag: u32 = 10;
+    pub const MaxNumberOfOperatorsPerDistributionBucket: u32 = 5;
+    // constants for project_token::Config
+    pub const TokenModuleId: PalletId = PalletId(*b"m__Token");
+    pub const MaxVestingSchedulesPerAccountPerToken: u32 = 3;
+    pub const BlocksPerYear: u32 = 5259487;
+    pub const MaxOutputs: u32 = 256;
 }
 
 // Config constants
 parameter_types! {
     pub const MaxPauserAccounts: u32 = 10;
+    pub const MaxAttesterAccounts: u32 = 10;
     pub const DefaultBridgingFee: Balance = 1;
 }
 
@@ -55,6 +88,9 @@ frame_support::construct_runtime!(
         System: frame_system,
         Balances: balances,
         Timestamp: pallet_timestamp::{Pallet, Call, Storage, Inherent},
+        Membership: membership::{Pallet, Call, Storage, Event<T>},
+        Storage: storage::{Pallet, Call, Storage, Event<T>},
+        Token: project_token::{Pallet, Call, Storage, Event<T>},
         ArgoBridge: argo_bridge::{Pallet, Call, Storage, Event<T>},
     }
 );
@@ -105,11 +141,225 @@ impl balances::Config for Test {
     type WeightInfo = ();
 }
 
+impl common::MembershipTypes for Test {
+    type MemberId = u64;
+    type ActorId = u64;
+}
+
+impl membership::Config for Test {
+    type RuntimeEvent = RuntimeEvent;
+    type DefaultMembershipPrice = DefaultMembershipPrice;
+    type ReferralCutMaximumPercent = ReferralCutMaximumPercent;
+    type WorkingGroup = Wg;
+    type DefaultInitialInvitationBalance = DefaultInitialInvitationBalance;
+    type InvitedMemberStakingHandler = staking_handler::StakingManager<Self, InvitedMemberLockId>;
+    type StakingCandidateStakingHandler =
+        staking_handler::StakingManager<Self, BoundStakingAccountLockId>;
+    type CandidateStake = CandidateStake;
+    type WeightInfo = ();
+    type DefaultMemberInvitesCount = DefaultMemberInvitesCount;
+    type HandleReclaimCooldown = HandleReclaimCooldown;
+    type MaxRecoveryGuardians = MaxRecoveryGuardians;
+    type RecoveryDelay = RecoveryDelay;
+    type RecoveryCooldown = RecoveryCooldown;
+}
+
+impl LockComparator<u64> for Test {
+    fn are_locks_conflicting(new_lock: &LockIdentifier, existing_locks: &[LockIdentifier]) -> bool {
+        if *new_lock == InvitedMemberLockId::get() {
+            existing_locks.contains(new_lock)
+        } else {
+            false
+        }
+    }
+}
+
+impl storage::Config for Test {
+    type RuntimeEvent = RuntimeEvent;
+    type DataObjectId = u64;
+    type StorageBucketId = u64;
+    type DistributionBucketIndex = u64;
+    type DistributionBucketFamilyId = u64;
+    type DistributionBucketOperatorId = u64;
+    type ChannelId = u64;
+    type BlacklistSizeLimit = BlacklistSizeLimit;
+    type ModuleId = StorageModuleId;
+    type MinStorageBucketsPerBag = MinStorageBucketsPerBag;
+    type MaxStorageBucketsPerBag = MaxStorageBucketsPerBag;
+    type MinDistributionBucketsPerBag = MinDistributionBucketsPerBag;
+    type MaxDistributionBucketsPerBag = MaxDistributionBucketsPerBag;
+    type DefaultMemberDynamicBagNumberOfStorageBuckets =
+        DefaultMemberDynamicBagNumberOfStorageBuckets;
+    type DefaultChannelDynamicBagNumberOfStorageBuckets =
+        DefaultChannelDynamicBagNumberOfStorageBuckets;
+    type MaxDistributionBucketFamilyNumber = MaxDistributionBucketFamilyNumber;
+    type MaxNumberOfPendingInvitationsPerDistributionBucket =
+        MaxNumberOfPendingInvitationsPerDistributionBucket;
+    type MaxNumberOfOperatorsPerDistributionBucket = MaxNumberOfOperatorsPerDistributionBucket;
+    type ContentId = u64;
+    type MaxDataObjectSize = MaxDataObjectSize;
+    type StorageWorkingGroup = Wg;
+    type DistributionWorkingGroup = Wg;
+    type ModuleAccountInitialBalance = ExistentialDeposit;
+    type WeightInfo = ();
+}
+
+impl project_token::Config for Test {
+    type RuntimeEvent = RuntimeEvent;
+    type Balance = u64;
+    type TokenId = u64;
+    type DataObjectStorage = storage::Module<Self>;
+    type ModuleId = TokenModuleId;
+    type JoyExistentialDeposit = ExistentialDeposit;
+    type MaxVestingSchedulesPerAccountPerToken = MaxVestingSchedulesPerAccountPerToken;
+    type BlocksPerYear = BlocksPerYear;
+    type WeightInfo = ();
+    type MemberOriginValidator = TestMemberships;
+    type MembershipInfoProvider = TestMemberships;
+    type MaxOutputs = MaxOutputs;
+}
+
 impl Config for Test {
     type RuntimeEvent = RuntimeEvent;
     type MaxPauserAccounts = MaxPauserAccounts;
+    type MaxAttesterAccounts = MaxAttesterAccounts;
     type WeightInfo = argo_bridge::weights::SubstrateWeight<Test>;
     type DefaultBridgingFee = DefaultBridgingFee;
+    type ProjectToken = project_token::Module<Test>;
+    type CouncilBudgetManager = CouncilBudgetManager;
+}
+
+pub const COUNCIL_BUDGET_ACCOUNT_ID: u64 = 90000000;
+pub struct CouncilBudgetManager;
+impl common::council::CouncilBudgetManager<u64, u64> for CouncilBudgetManager {
+    fn get_budget() -> u64 {
+        balances::Pallet::<Test>::usable_balance(&COUNCIL_BUDGET_ACCOUNT_ID)
+    }
+
+    fn set_budget(budget: u64) {
+        let old_budget = Self::get_budget();
+
+        if budget > old_budget {
+            let _ = balances::Pallet::<Test>::deposit_creating(
+                &COUNCIL_BUDGET_ACCOUNT_ID,
+                budget - old_budget,
+            );
+        }
+
+        if budget < old_budget {
+            let _ =
+                balances::Pallet::<Test>::slash(&COUNCIL_BUDGET_ACCOUNT_ID, old_budget - budget);
+        }
+    }
+
+    fn try_withdraw(account_id: &u64, amount: u64) -> DispatchResult {
+        let _ = balances::Pallet::<Test>::deposit_creating(account_id, amount);
+        Self::decrease_budget(amount);
+
+        Ok(())
+    }
+}
+
+pub struct Wg;
+impl common::working_group::WorkingGroupBudgetHandler<u64, u64> for Wg {
+    fn get_budget() -> u64 {
+        unimplemented!()
+    }
+
+    fn set_budget(_new_value: u64) {
+        unimplemented!()
+    }
+
+    fn try_withdraw(_account_id: &u64, _amount: u64) -> DispatchResult {
+        unimplemented!()
+    }
+}
+
+impl common::working_group::WorkingGroupAuthenticator<Test> for Wg {
+    fn ensure_worker_origin(
+        _origin: <Test as frame_system::Config>::RuntimeOrigin,
+        _worker_id: &<Test as common::membership::MembershipTypes>::ActorId,
+    ) -> DispatchResult {
+        unimplemented!()
+    }
+
+    fn ensure_leader_origin(
+        _origin: <Test as frame_system::Config>::RuntimeOrigin,
+    ) -> DispatchResult {
+        unimplemented!()
+    }
+
+    fn get_leader_member_id() -> Option<<Test as common::membership::MembershipTypes>::MemberId> {
+        unimplemented!()
+    }
+
+    fn get_worker_member_id(
+        _: &<Test as common::membership::MembershipTypes>::ActorId,
+    ) -> Option<<Test as common::membership::MembershipTypes>::MemberId> {
+        unimplemented!()
+    }
+
+    fn is_leader_account_id(_account_id: &<Test as frame_system::Config>::AccountId) -> bool {
+        unimplemented!()
+    }
+
+    fn is_worker_account_id(
+        _account_id: &<Test as frame_system::Config>::AccountId,
+        _worker_id: &<Test as common::membership::MembershipTypes>::ActorId,
+    ) -> bool {
+        unimplemented!()
+    }
+
+    fn worker_exists(_worker_id: &<Test as common::membership::MembershipTypes>::ActorId) -> bool {
+        unimplemented!();
+    }
+
+    fn ensure_worker_exists(
+        _worker_id: &<Test as common::membership::MembershipTypes>::ActorId,
+    ) -> DispatchResult {
+        unimplemented!();
+    }
+}
+
+pub struct TestMemberships {}
+
+// Mock MembershipInfoProvider impl: member `id < 1000` is controlled by account `id + 1000`
+impl MembershipInfoProvider<Test> for TestMemberships {
+    fn controller_account_id(
+        member_id: common::MemberId<Test>,
+    ) -> Result<AccountId, DispatchError> {
+        if member_id < 1000 {
+            return Ok(member_id + 1000);
+        }
+
+        Err(DispatchError::Other("no account found"))
+    }
+
+    fn has_valid_attestation(
+        _member_id: common::MemberId<Test>,
+        _kind: common::membership::AttestationKind,
+    ) -> bool {
+        false
+    }
+}
+
+// Mock MemberOriginValidator impl
+impl MemberOriginValidator<RuntimeOrigin, u64, u64> for TestMemberships {
+    fn ensure_member_controller_account_origin(
+        origin: RuntimeOrigin,
+        member_id: u64,
+    ) -> Result<u64, DispatchError> {
+        let sender = ensure_signed(origin)?;
+        ensure!(
+            Self::is_member_controller_account(&member_id, &sender),
+            DispatchError::Other("origin signer not a member controller account"),
+        );
+        Ok(sender)
+    }
+
+    fn is_member_controller_account(member_id: &u64, account_id: &u64) -> bool {
+        *member_id < 1000 && *account_id == 1000 + *member_id
+    }
 }
 
 pub fn default_genesis_config() -> argo_bridge::GenesisConfig<Test> {
@@ -118,6 +368,7 @@ pub fn default_genesis_config() -> argo_bridge::GenesisConfig<Test> {
         mint_allowance: 0,
         bridging_fee: DefaultBridgingFee::get(),
         thawn_duration: 1,
+        attestation_ttl: 10,
     }
 }
 
@@ -190,3 +441,13 @@ macro_rules! account {
         AccountId::from($acc as u64)
     };
 }
+
+#[macro_export]
+macro_rules! member {
+    ($id:expr) => {
+        (
+            MemberId::from($id as u64),
+            AccountId::from($id as u64 + 1000),
+        )
+    };
+}
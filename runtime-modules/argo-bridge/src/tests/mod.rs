@@ -4,12 +4,23 @@ use core::convert::{TryFrom, TryInto};
 
 use crate::tests::mock::*;
 use frame_support::dispatch::DispatchResult;
-use frame_support::{assert_err, assert_ok};
-use sp_runtime::BoundedVec;
+use frame_support::traits::{
+    GetStorageVersion, LockableCurrency, OnRuntimeUpgrade, StorageVersion, WithdrawReasons,
+};
+use frame_support::{assert_err, assert_ok, StorageValue};
+use project_token::traits::PalletToken;
+use project_token::types::{TokenAllocation, TokenIssuanceParametersOf, UploadContextOf};
+use sp_runtime::{BoundedVec, DispatchError, Permill};
+use sp_std::collections::btree_map::BTreeMap;
 
 use crate::{
-    account, joy, last_event_eq,
+    account, joy, last_event_eq, member,
     tests::mock::{increase_block_number_by, AccountId, Balance, Balances, BlockNumber, Test},
+    types::{
+        BridgedAsset, FeeDistribution, FeeSchedule, OutboundTransfer, OutboundTransferStatus,
+        RateLimit, RateLimitKey, RateLimitUsage, TransferDirection, TransferId,
+        DEFAULT_ATTESTATION_TTL, MAX_PRUNED_INBOUND_TRANSFERS,
+    },
     BridgeConstraints, BridgeStatus, RawEvent, RemoteAccount, RemoteTransfer,
 };
 
@@ -17,6 +28,7 @@ use self::mock::{
     with_test_externalities, with_test_externalities_custom_mint_allowance, ArgoBridge,
     RuntimeOrigin,
 };
+use crate::migrations::v1::MigrateToV1;
 use crate::Error;
 
 pub mod mock;
@@ -32,6 +44,14 @@ fn request_outbound_transfer_success() {
             bridging_fee: Some(fee),
             thawn_duration: None,
             remote_chains: Some(remote_chains),
+            attester_accounts: None,
+            attestation_threshold: None,
+            attestation_ttl: None,
+            rate_limits: None,
+            remote_chain_fees: None,
+            fee_distribution: None,
+            operator_reward_account: None,
+            creator_token_mint_allowances: None,
         };
         assert_ok!(ArgoBridge::update_bridge_constrains(
             RuntimeOrigin::root(),
@@ -53,6 +73,7 @@ fn request_outbound_transfer_success() {
         let result = ArgoBridge::request_outbound_transfer(
             RuntimeOrigin::signed(sender),
             remote_account,
+            BridgedAsset::Joy,
             transfer_amount,
             fee,
         );
@@ -66,9 +87,21 @@ fn request_outbound_transfer_success() {
             transfer_id,
             sender,
             remote_account,
+            BridgedAsset::Joy,
             transfer_amount,
             fee
         ));
+        assert_eq!(
+            ArgoBridge::outbound_transfers(transfer_id),
+            Some(OutboundTransfer {
+                sender,
+                dest_account: remote_account,
+                asset: BridgedAsset::Joy,
+                amount: transfer_amount,
+                fee,
+                status: OutboundTransferStatus::Pending,
+            })
+        );
     });
 }
 
@@ -83,6 +116,14 @@ fn request_outbound_transfer_with_bridge_paused() {
             bridging_fee: Some(fee),
             thawn_duration: None,
             remote_chains: Some(remote_chains),
+            attester_accounts: None,
+            attestation_threshold: None,
+            attestation_ttl: None,
+            rate_limits: None,
+            remote_chain_fees: None,
+            fee_distribution: None,
+            operator_reward_account: None,
+            creator_token_mint_allowances: None,
         };
         ArgoBridge::update_bridge_constrains(RuntimeOrigin::root(), parameters).unwrap();
 
@@ -97,6 +138,7 @@ fn request_outbound_transfer_with_bridge_paused() {
         let result = ArgoBridge::request_outbound_transfer(
             RuntimeOrigin::signed(sender),
             remote_account,
+            BridgedAsset::Joy,
             transfer_amount,
             fee,
         );
@@ -115,6 +157,14 @@ fn request_outbound_transfer_with_insufficient_balance() {
             bridging_fee: Some(fee),
             thawn_duration: None,
             remote_chains: Some(remote_chains),
+            attester_accounts: None,
+            attestation_threshold: None,
+            attestation_ttl: None,
+            rate_limits: None,
+            remote_chain_fees: None,
+            fee_distribution: None,
+            operator_reward_account: None,
+            creator_token_mint_allowances: None,
         };
         assert_ok!(ArgoBridge::update_bridge_constrains(
             RuntimeOrigin::root(),
@@ -137,6 +187,7 @@ fn request_outbound_transfer_with_insufficient_balance() {
         let result = ArgoBridge::request_outbound_transfer(
             RuntimeOrigin::signed(account!(1)),
             remote_account,
+            BridgedAsset::Joy,
             transfer_amount,
             fee,
         );
@@ -154,6 +205,14 @@ fn request_outbound_transfer_with_unexpected_fee() {
             bridging_fee: Some(joy!(20)),
             thawn_duration: None,
             remote_chains: Some(remote_chains),
+            attester_accounts: None,
+            attestation_threshold: None,
+            attestation_ttl: None,
+            rate_limits: None,
+            remote_chain_fees: None,
+            fee_distribution: None,
+            operator_reward_account: None,
+            creator_token_mint_allowances: None,
         };
         assert_ok!(ArgoBridge::update_bridge_constrains(
             RuntimeOrigin::root(),
@@ -176,6 +235,7 @@ fn request_outbound_transfer_with_unexpected_fee() {
         let result = ArgoBridge::request_outbound_transfer(
             RuntimeOrigin::signed(account!(1)),
             remote_account,
+            BridgedAsset::Joy,
             transfer_amount,
             joy!(10),
         );
@@ -192,6 +252,14 @@ fn request_outbound_transfer_with_not_supported_remote_chain() {
             bridging_fee: Some(joy!(20)),
             thawn_duration: None,
             remote_chains: Some(BoundedVec::try_from(vec![1u32]).unwrap()),
+            attester_accounts: None,
+            attestation_threshold: None,
+            attestation_ttl: None,
+            rate_limits: None,
+            remote_chain_fees: None,
+            fee_distribution: None,
+            operator_reward_account: None,
+            creator_token_mint_allowances: None,
         };
         assert_ok!(ArgoBridge::update_bridge_constrains(
             RuntimeOrigin::root(),
@@ -214,6 +282,7 @@ fn request_outbound_transfer_with_not_supported_remote_chain() {
         let result = ArgoBridge::request_outbound_transfer(
             RuntimeOrigin::signed(account!(1)),
             remote_account,
+            BridgedAsset::Joy,
             transfer_amount,
             joy!(10),
         );
@@ -232,6 +301,14 @@ fn request_outbound_transfer_with_overflow() {
             bridging_fee: Some(fee),
             thawn_duration: None,
             remote_chains: Some(remote_chains),
+            attester_accounts: None,
+            attestation_threshold: None,
+            attestation_ttl: None,
+            rate_limits: None,
+            remote_chain_fees: None,
+            fee_distribution: None,
+            operator_reward_account: None,
+            creator_token_mint_allowances: None,
         };
         assert_ok!(ArgoBridge::update_bridge_constrains(
             RuntimeOrigin::root(),
@@ -249,6 +326,7 @@ fn request_outbound_transfer_with_overflow() {
         let result = ArgoBridge::request_outbound_transfer(
             RuntimeOrigin::signed(sender),
             remote_account,
+            BridgedAsset::Joy,
             transfer_amount,
             fee,
         );
@@ -266,6 +344,14 @@ fn finalize_inbound_transfer_success() {
             bridging_fee: None,
             thawn_duration: None,
             remote_chains: Some(remote_chains),
+            attester_accounts: Some(vec![account!(1)]),
+            attestation_threshold: Some(1),
+            attestation_ttl: None,
+            rate_limits: None,
+            remote_chain_fees: None,
+            fee_distribution: None,
+            operator_reward_account: None,
+            creator_token_mint_allowances: None,
         };
         ArgoBridge::update_bridge_constrains(RuntimeOrigin::root(), parameters).unwrap();
         assert_ok!(activate_bridge(account!(2), account!(1)));
@@ -276,6 +362,7 @@ fn finalize_inbound_transfer_success() {
             RuntimeOrigin::signed(account!(1)),
             RemoteTransfer { id: 0, chain_id: 1 },
             dest_account,
+            BridgedAsset::Joy,
             transfer_amount,
         );
         assert_ok!(result);
@@ -283,6 +370,7 @@ fn finalize_inbound_transfer_success() {
         last_event_eq!(RawEvent::InboundTransferFinalized(
             RemoteTransfer { id: 0, chain_id: 1 },
             dest_account,
+            BridgedAsset::Joy,
             transfer_amount
         ));
     });
@@ -298,6 +386,14 @@ fn finalize_inbound_transfer_with_unauthorized_account() {
             bridging_fee: None,
             thawn_duration: None,
             remote_chains: Some(remote_chains),
+            attester_accounts: Some(vec![account!(1)]),
+            attestation_threshold: Some(1),
+            attestation_ttl: None,
+            rate_limits: None,
+            remote_chain_fees: None,
+            fee_distribution: None,
+            operator_reward_account: None,
+            creator_token_mint_allowances: None,
         };
         assert_ok!(ArgoBridge::update_bridge_constrains(
             RuntimeOrigin::root(),
@@ -310,9 +406,10 @@ fn finalize_inbound_transfer_with_unauthorized_account() {
             RuntimeOrigin::signed(account!(2)),
             remote_transfer,
             account!(2),
+            BridgedAsset::Joy,
             1000,
         );
-        assert_err!(result, Error::<Test>::NotOperatorAccount);
+        assert_err!(result, Error::<Test>::NotAttesterAccount);
     });
 }
 
@@ -326,6 +423,14 @@ fn finalize_inbound_transfer_with_insufficient_bridge_mint() {
             bridging_fee: None,
             thawn_duration: None,
             remote_chains: Some(remote_chains),
+            attester_accounts: Some(vec![account!(1)]),
+            attestation_threshold: Some(1),
+            attestation_ttl: None,
+            rate_limits: None,
+            remote_chain_fees: None,
+            fee_distribution: None,
+            operator_reward_account: None,
+            creator_token_mint_allowances: None,
         };
         assert_ok!(ArgoBridge::update_bridge_constrains(
             RuntimeOrigin::root(),
@@ -338,6 +443,7 @@ fn finalize_inbound_transfer_with_insufficient_bridge_mint() {
             RuntimeOrigin::signed(account!(1)),
             remote_transfer,
             account!(2),
+            BridgedAsset::Joy,
             1000,
         );
         assert_err!(result, Error::<Test>::InsufficientBridgeMintAllowance);
@@ -354,6 +460,14 @@ fn finalize_inbound_transfer_with_bridge_paused() {
             bridging_fee: None,
             thawn_duration: None,
             remote_chains: Some(remote_chains),
+            attester_accounts: Some(vec![account!(1)]),
+            attestation_threshold: Some(1),
+            attestation_ttl: None,
+            rate_limits: None,
+            remote_chain_fees: None,
+            fee_distribution: None,
+            operator_reward_account: None,
+            creator_token_mint_allowances: None,
         };
         ArgoBridge::update_bridge_constrains(RuntimeOrigin::root(), parameters).unwrap();
 
@@ -362,6 +476,7 @@ fn finalize_inbound_transfer_with_bridge_paused() {
             RuntimeOrigin::signed(account!(1)),
             remote_transfer,
             account!(2),
+            BridgedAsset::Joy,
             1000,
         );
         assert_err!(result, Error::<Test>::BridgeNotActive);
@@ -369,7 +484,7 @@ fn finalize_inbound_transfer_with_bridge_paused() {
 }
 
 #[test]
-fn revert_outbound_transfer_success() {
+fn finalize_inbound_transfer_already_processed() {
     with_test_externalities_custom_mint_allowance(joy!(1000), || {
         let remote_chains = BoundedVec::try_from(vec![1u32]).unwrap();
         let parameters = BridgeConstraints {
@@ -378,35 +493,44 @@ fn revert_outbound_transfer_success() {
             bridging_fee: None,
             thawn_duration: None,
             remote_chains: Some(remote_chains),
+            attester_accounts: Some(vec![account!(1)]),
+            attestation_threshold: Some(1),
+            attestation_ttl: None,
+            rate_limits: None,
+            remote_chain_fees: None,
+            fee_distribution: None,
+            operator_reward_account: None,
+            creator_token_mint_allowances: None,
         };
         ArgoBridge::update_bridge_constrains(RuntimeOrigin::root(), parameters).unwrap();
         assert_ok!(activate_bridge(account!(2), account!(1)));
 
-        let transfer_id = 1u64;
-        let revert_amount = joy!(123);
-        let revert_account = account!(2);
-        let rationale = "test".as_bytes().to_vec();
-        let result = ArgoBridge::revert_outbound_transfer(
+        let remote_transfer = RemoteTransfer { id: 0, chain_id: 1 };
+        assert!(!ArgoBridge::is_inbound_transfer_processed(&remote_transfer));
+        assert_ok!(ArgoBridge::finalize_inbound_transfer(
             RuntimeOrigin::signed(account!(1)),
-            transfer_id,
-            revert_account,
-            revert_amount,
-            rationale.clone().try_into().unwrap(),
-        );
-        assert_ok!(result);
-        assert_eq!(Balances::free_balance(revert_account), revert_amount);
-        last_event_eq!(RawEvent::OutboundTransferReverted(
-            transfer_id,
-            revert_account,
-            revert_amount,
-            rationale.try_into().unwrap(),
+            remote_transfer.clone(),
+            account!(2),
+            BridgedAsset::Joy,
+            joy!(100),
         ));
+        assert!(ArgoBridge::is_inbound_transfer_processed(&remote_transfer));
+
+        let result = ArgoBridge::finalize_inbound_transfer(
+            RuntimeOrigin::signed(account!(1)),
+            remote_transfer,
+            account!(2),
+            BridgedAsset::Joy,
+            joy!(100),
+        );
+        assert_err!(result, Error::<Test>::InboundTransferAlreadyProcessed);
+        assert_eq!(Balances::free_balance(account!(2)), joy!(100));
     });
 }
 
 #[test]
-fn revert_outbound_transfer_with_unauthorized_account() {
-    with_test_externalities(|| {
+fn finalize_inbound_transfer_with_attestation_threshold() {
+    with_test_externalities_custom_mint_allowance(joy!(1000), || {
         let remote_chains = BoundedVec::try_from(vec![1u32]).unwrap();
         let parameters = BridgeConstraints {
             operator_account: Some(account!(1)),
@@ -414,27 +538,63 @@ fn revert_outbound_transfer_with_unauthorized_account() {
             bridging_fee: None,
             thawn_duration: None,
             remote_chains: Some(remote_chains),
+            attester_accounts: Some(vec![account!(4), account!(5), account!(6)]),
+            attestation_threshold: Some(2),
+            attestation_ttl: None,
+            rate_limits: None,
+            remote_chain_fees: None,
+            fee_distribution: None,
+            operator_reward_account: None,
+            creator_token_mint_allowances: None,
         };
-        assert_ok!(ArgoBridge::update_bridge_constrains(
-            RuntimeOrigin::root(),
-            parameters
-        ));
+        ArgoBridge::update_bridge_constrains(RuntimeOrigin::root(), parameters).unwrap();
         assert_ok!(activate_bridge(account!(2), account!(1)));
 
-        let result = ArgoBridge::revert_outbound_transfer(
-            RuntimeOrigin::signed(account!(2)),
-            1u64,
-            account!(2),
-            joy!(123),
-            vec![].try_into().unwrap(),
+        let remote_transfer = RemoteTransfer { id: 0, chain_id: 1 };
+        let dest_account = account!(3);
+        let transfer_amount = joy!(100);
+        assert_ok!(ArgoBridge::finalize_inbound_transfer(
+            RuntimeOrigin::signed(account!(4)),
+            remote_transfer.clone(),
+            dest_account,
+            BridgedAsset::Joy,
+            transfer_amount,
+        ));
+        assert_eq!(Balances::free_balance(dest_account), joy!(0));
+        assert!(!ArgoBridge::is_inbound_transfer_processed(&remote_transfer));
+        last_event_eq!(RawEvent::InboundTransferAttested(
+            remote_transfer.clone(),
+            account!(4),
+            dest_account,
+            BridgedAsset::Joy,
+            transfer_amount
+        ));
+
+        assert_ok!(ArgoBridge::finalize_inbound_transfer(
+            RuntimeOrigin::signed(account!(5)),
+            remote_transfer.clone(),
+            dest_account,
+            BridgedAsset::Joy,
+            transfer_amount,
+        ));
+        assert_eq!(Balances::free_balance(dest_account), transfer_amount);
+        assert!(ArgoBridge::is_inbound_transfer_processed(&remote_transfer));
+        assert_eq!(
+            ArgoBridge::inbound_transfer_attestations(&remote_transfer, account!(4)),
+            None
         );
-        assert_err!(result, Error::<Test>::NotOperatorAccount);
+        last_event_eq!(RawEvent::InboundTransferFinalized(
+            remote_transfer,
+            dest_account,
+            BridgedAsset::Joy,
+            transfer_amount
+        ));
     });
 }
 
 #[test]
-fn revert_outbound_transfer_with_insufficient_bridge_mint() {
-    with_test_externalities(|| {
+fn finalize_inbound_transfer_with_conflicting_attestations() {
+    with_test_externalities_custom_mint_allowance(joy!(1000), || {
         let remote_chains = BoundedVec::try_from(vec![1u32]).unwrap();
         let parameters = BridgeConstraints {
             operator_account: Some(account!(1)),
@@ -442,27 +602,50 @@ fn revert_outbound_transfer_with_insufficient_bridge_mint() {
             bridging_fee: None,
             thawn_duration: None,
             remote_chains: Some(remote_chains),
+            attester_accounts: Some(vec![account!(4), account!(5), account!(6)]),
+            attestation_threshold: Some(2),
+            attestation_ttl: None,
+            rate_limits: None,
+            remote_chain_fees: None,
+            fee_distribution: None,
+            operator_reward_account: None,
+            creator_token_mint_allowances: None,
         };
-        assert_ok!(ArgoBridge::update_bridge_constrains(
-            RuntimeOrigin::root(),
-            parameters
-        ));
+        ArgoBridge::update_bridge_constrains(RuntimeOrigin::root(), parameters).unwrap();
         assert_ok!(activate_bridge(account!(2), account!(1)));
 
-        let result = ArgoBridge::revert_outbound_transfer(
-            RuntimeOrigin::signed(account!(1)),
-            1u64,
-            account!(2),
+        let remote_transfer = RemoteTransfer { id: 0, chain_id: 1 };
+        assert_ok!(ArgoBridge::finalize_inbound_transfer(
+            RuntimeOrigin::signed(account!(4)),
+            remote_transfer.clone(),
+            account!(3),
+            BridgedAsset::Joy,
             joy!(100),
-            vec![].try_into().unwrap(),
-        );
-        assert_err!(result, Error::<Test>::InsufficientBridgeMintAllowance);
+        ));
+        assert_ok!(ArgoBridge::finalize_inbound_transfer(
+            RuntimeOrigin::signed(account!(5)),
+            remote_transfer.clone(),
+            account!(3),
+            BridgedAsset::Joy,
+            joy!(200),
+        ));
+
+        assert!(System::events().iter().any(|record| record.event
+            == RuntimeEvent::ArgoBridge(RawEvent::ConflictingInboundTransferAttestation(
+                remote_transfer.clone(),
+                account!(5),
+                account!(3),
+                BridgedAsset::Joy,
+                joy!(200)
+            ))));
+        assert_eq!(Balances::free_balance(account!(3)), joy!(0));
+        assert!(!ArgoBridge::is_inbound_transfer_processed(&remote_transfer));
     });
 }
 
 #[test]
-fn revert_outbound_transfer_with_bridge_paused() {
-    with_test_externalities(|| {
+fn finalize_inbound_transfer_with_stale_attestation() {
+    with_test_externalities_custom_mint_allowance(joy!(1000), || {
         let remote_chains = BoundedVec::try_from(vec![1u32]).unwrap();
         let parameters = BridgeConstraints {
             operator_account: Some(account!(1)),
@@ -470,186 +653,2040 @@ fn revert_outbound_transfer_with_bridge_paused() {
             bridging_fee: None,
             thawn_duration: None,
             remote_chains: Some(remote_chains),
+            attester_accounts: Some(vec![account!(4), account!(5), account!(6)]),
+            attestation_threshold: Some(2),
+            attestation_ttl: Some(2),
+            rate_limits: None,
+            remote_chain_fees: None,
+            fee_distribution: None,
+            operator_reward_account: None,
+            creator_token_mint_allowances: None,
         };
         ArgoBridge::update_bridge_constrains(RuntimeOrigin::root(), parameters).unwrap();
+        assert_ok!(activate_bridge(account!(2), account!(1)));
 
-        let result = ArgoBridge::revert_outbound_transfer(
-            RuntimeOrigin::signed(account!(1)),
-            1u64,
-            account!(2),
+        let remote_transfer = RemoteTransfer { id: 0, chain_id: 1 };
+        assert_ok!(ArgoBridge::finalize_inbound_transfer(
+            RuntimeOrigin::signed(account!(4)),
+            remote_transfer.clone(),
+            account!(3),
+            BridgedAsset::Joy,
             joy!(100),
-            vec![].try_into().unwrap(),
+        ));
+
+        increase_block_number_by(3);
+
+        assert_ok!(ArgoBridge::finalize_inbound_transfer(
+            RuntimeOrigin::signed(account!(5)),
+            remote_transfer.clone(),
+            account!(3),
+            BridgedAsset::Joy,
+            joy!(100),
+        ));
+        assert_eq!(Balances::free_balance(account!(3)), joy!(0));
+        assert_eq!(
+            ArgoBridge::inbound_transfer_attestations(&remote_transfer, account!(4)),
+            None
         );
-        assert_err!(result, Error::<Test>::BridgeNotActive);
+        assert!(!ArgoBridge::is_inbound_transfer_processed(&remote_transfer));
     });
 }
 
+fn setup_attesters_and_attest(attester_accounts: Vec<AccountId>) -> RemoteTransfer {
+    let remote_chains = BoundedVec::try_from(vec![1u32]).unwrap();
+    let parameters = BridgeConstraints {
+        operator_account: Some(account!(1)),
+        pauser_accounts: Some(vec![account!(2)]),
+        bridging_fee: None,
+        thawn_duration: None,
+        remote_chains: Some(remote_chains),
+        attester_accounts: Some(attester_accounts),
+        attestation_threshold: Some(2),
+        attestation_ttl: Some(2),
+        rate_limits: None,
+        remote_chain_fees: None,
+        fee_distribution: None,
+        operator_reward_account: None,
+        creator_token_mint_allowances: None,
+    };
+    ArgoBridge::update_bridge_constrains(RuntimeOrigin::root(), parameters).unwrap();
+    assert_ok!(activate_bridge(account!(2), account!(1)));
+
+    let remote_transfer = RemoteTransfer { id: 0, chain_id: 1 };
+    assert_ok!(ArgoBridge::finalize_inbound_transfer(
+        RuntimeOrigin::signed(account!(4)),
+        remote_transfer.clone(),
+        account!(3),
+        BridgedAsset::Joy,
+        joy!(100),
+    ));
+    remote_transfer
+}
+
 #[test]
-fn pause_bridge_success() {
-    with_test_externalities(|| {
-        let pauser_account = account!(2);
-        let parameters = BridgeConstraints {
-            operator_account: Some(account!(1)),
-            pauser_accounts: Some(vec![pauser_account]),
-            bridging_fee: None,
-            thawn_duration: None,
-            remote_chains: None,
-        };
-        assert_ok!(ArgoBridge::update_bridge_constrains(
-            RuntimeOrigin::root(),
-            parameters
-        ));
-        assert_ok!(activate_bridge(pauser_account, account!(1)));
-        assert_eq!(ArgoBridge::status(), BridgeStatus::Active);
+fn prune_inbound_transfer_attestations_with_stale_attestation() {
+    with_test_externalities_custom_mint_allowance(joy!(1000), || {
+        let remote_transfer =
+            setup_attesters_and_attest(vec![account!(4), account!(5), account!(6)]);
 
-        assert_ok!(ArgoBridge::pause_bridge(RuntimeOrigin::signed(
-            pauser_account
-        )));
-        assert_eq!(ArgoBridge::status(), BridgeStatus::Paused);
-        last_event_eq!(RawEvent::BridgePaused(pauser_account));
+        increase_block_number_by(3);
+
+        assert_ok!(ArgoBridge::prune_inbound_transfer_attestations(
+            RuntimeOrigin::signed(account!(7)),
+            remote_transfer.clone(),
+        ));
+        assert_eq!(
+            ArgoBridge::inbound_transfer_attestations(&remote_transfer, account!(4)),
+            None
+        );
+        last_event_eq!(RawEvent::InboundTransferAttestationsPruned(
+            remote_transfer,
+            1
+        ));
     });
 }
 
 #[test]
-fn pause_bridge_with_unauthorized_account() {
-    with_test_externalities(|| {
+fn prune_inbound_transfer_attestations_of_former_attester() {
+    with_test_externalities_custom_mint_allowance(joy!(1000), || {
+        let remote_transfer =
+            setup_attesters_and_attest(vec![account!(4), account!(5), account!(6)]);
+
         let parameters = BridgeConstraints {
             operator_account: None,
-            pauser_accounts: Some(vec![account!(2)]),
+            pauser_accounts: None,
             bridging_fee: None,
             thawn_duration: None,
             remote_chains: None,
+            attester_accounts: Some(vec![account!(5), account!(6)]),
+            attestation_threshold: None,
+            attestation_ttl: None,
+            rate_limits: None,
+            remote_chain_fees: None,
+            fee_distribution: None,
+            operator_reward_account: None,
+            creator_token_mint_allowances: None,
         };
-        assert_ok!(ArgoBridge::update_bridge_constrains(
-            RuntimeOrigin::root(),
-            parameters
+        ArgoBridge::update_bridge_constrains(RuntimeOrigin::root(), parameters).unwrap();
+
+        assert_ok!(ArgoBridge::prune_inbound_transfer_attestations(
+            RuntimeOrigin::signed(account!(7)),
+            remote_transfer.clone(),
         ));
+        assert_eq!(
+            ArgoBridge::inbound_transfer_attestations(&remote_transfer, account!(4)),
+            None
+        );
+    });
+}
 
-        let result = ArgoBridge::pause_bridge(RuntimeOrigin::signed(account!(1)));
-        assert_err!(result, Error::<Test>::NotPauserAccount);
+#[test]
+fn prune_inbound_transfer_attestations_with_valid_attestation() {
+    with_test_externalities_custom_mint_allowance(joy!(1000), || {
+        let remote_transfer =
+            setup_attesters_and_attest(vec![account!(4), account!(5), account!(6)]);
+
+        assert_err!(
+            ArgoBridge::prune_inbound_transfer_attestations(
+                RuntimeOrigin::signed(account!(7)),
+                remote_transfer.clone(),
+            ),
+            Error::<Test>::NoInboundTransferAttestationsToPrune
+        );
+        assert!(ArgoBridge::inbound_transfer_attestations(&remote_transfer, account!(4)).is_some());
     });
 }
 
 #[test]
-fn unpause_bridge_success() {
+fn migration_seeds_operator_account_as_attester() {
     with_test_externalities(|| {
         let parameters = BridgeConstraints {
             operator_account: Some(account!(1)),
-            pauser_accounts: Some(vec![account!(2)]),
+            pauser_accounts: None,
             bridging_fee: None,
-            thawn_duration: Some(1),
+            thawn_duration: None,
             remote_chains: None,
+            attester_accounts: None,
+            attestation_threshold: None,
+            attestation_ttl: None,
+            rate_limits: None,
+            remote_chain_fees: None,
+            fee_distribution: None,
+            operator_reward_account: None,
+            creator_token_mint_allowances: None,
         };
         ArgoBridge::update_bridge_constrains(RuntimeOrigin::root(), parameters).unwrap();
 
-        ArgoBridge::init_unpause_bridge(RuntimeOrigin::signed(account!(2))).unwrap();
-        let current_block = <frame_system::Pallet<Test>>::block_number();
+        // Simulate the storage before the attestations were introduced.
+        crate::AttestationTtl::<Test>::kill();
+        StorageVersion::new(0).put::<ArgoBridge>();
+
+        MigrateToV1::<Test>::on_runtime_upgrade();
+
+        assert_eq!(ArgoBridge::attester_accounts().to_vec(), vec![account!(1)]);
+        assert_eq!(ArgoBridge::attestation_threshold(), 1);
         assert_eq!(
-            ArgoBridge::status(),
-            BridgeStatus::Thawn {
-                thawn_ends_at: current_block + 1
-            }
+            ArgoBridge::attestation_ttl(),
+            BlockNumber::from(DEFAULT_ATTESTATION_TTL)
+        );
+        assert_eq!(
+            ArgoBridge::on_chain_storage_version(),
+            StorageVersion::new(1)
         );
-        increase_block_number_by(2);
-        assert_ok!(ArgoBridge::finish_unpause_bridge(RuntimeOrigin::signed(
-            account!(1)
-        )));
-        last_event_eq!(RawEvent::BridgeThawnFinished());
     });
 }
 
 #[test]
-fn init_unpause_bridge_active() {
+fn update_bridge_constrains_with_invalid_attestation_threshold() {
     with_test_externalities(|| {
+        let parameters = BridgeConstraints {
+            operator_account: None,
+            pauser_accounts: None,
+            bridging_fee: None,
+            thawn_duration: None,
+            remote_chains: None,
+            attester_accounts: Some(vec![account!(4)]),
+            attestation_threshold: Some(2),
+            attestation_ttl: None,
+            rate_limits: None,
+            remote_chain_fees: None,
+            fee_distribution: None,
+            operator_reward_account: None,
+            creator_token_mint_allowances: None,
+        };
+        let result = ArgoBridge::update_bridge_constrains(RuntimeOrigin::root(), parameters);
+        assert_err!(result, Error::<Test>::InvalidAttestationThreshold);
+
+        let parameters = BridgeConstraints {
+            operator_account: None,
+            pauser_accounts: None,
+            bridging_fee: None,
+            thawn_duration: None,
+            remote_chains: None,
+            attester_accounts: Some(vec![account!(4)]),
+            attestation_threshold: Some(0),
+            attestation_ttl: None,
+            rate_limits: None,
+            remote_chain_fees: None,
+            fee_distribution: None,
+            operator_reward_account: None,
+            creator_token_mint_allowances: None,
+        };
+        let result = ArgoBridge::update_bridge_constrains(RuntimeOrigin::root(), parameters);
+        assert_err!(result, Error::<Test>::InvalidAttestationThreshold);
+    });
+}
+
+#[test]
+fn prune_processed_inbound_transfers_success() {
+    with_test_externalities_custom_mint_allowance(joy!(1000), || {
+        let remote_chains = BoundedVec::try_from(vec![1u32]).unwrap();
         let parameters = BridgeConstraints {
             operator_account: Some(account!(1)),
             pauser_accounts: Some(vec![account!(2)]),
             bridging_fee: None,
             thawn_duration: None,
-            remote_chains: None,
+            remote_chains: Some(remote_chains),
+            attester_accounts: Some(vec![account!(1)]),
+            attestation_threshold: Some(1),
+            attestation_ttl: None,
+            rate_limits: None,
+            remote_chain_fees: None,
+            fee_distribution: None,
+            operator_reward_account: None,
+            creator_token_mint_allowances: None,
         };
         ArgoBridge::update_bridge_constrains(RuntimeOrigin::root(), parameters).unwrap();
         assert_ok!(activate_bridge(account!(2), account!(1)));
 
-        let result = ArgoBridge::init_unpause_bridge(RuntimeOrigin::signed(account!(2)));
-        assert_err!(result, Error::<Test>::BridgeNotPaused);
+        for id in 0..3 {
+            assert_ok!(ArgoBridge::finalize_inbound_transfer(
+                RuntimeOrigin::signed(account!(1)),
+                RemoteTransfer { id, chain_id: 1 },
+                account!(2),
+                BridgedAsset::Joy,
+                joy!(100),
+            ));
+        }
+
+        assert_ok!(ArgoBridge::prune_processed_inbound_transfers(
+            RuntimeOrigin::signed(account!(1)),
+            1,
+            2
+        ));
+        assert_eq!(ArgoBridge::processed_inbound_transfers_floor(1), 2);
+        assert_eq!(ArgoBridge::processed_inbound_transfers(1, 0), None);
+        assert_eq!(ArgoBridge::processed_inbound_transfers(1, 1), None);
+        assert!(ArgoBridge::processed_inbound_transfers(1, 2).is_some());
+        last_event_eq!(RawEvent::ProcessedInboundTransfersPruned(1, 2));
+
+        // pruned transfers are still considered processed
+        let result = ArgoBridge::finalize_inbound_transfer(
+            RuntimeOrigin::signed(account!(1)),
+            RemoteTransfer { id: 0, chain_id: 1 },
+            account!(2),
+            BridgedAsset::Joy,
+            joy!(100),
+        );
+        assert_err!(result, Error::<Test>::InboundTransferAlreadyProcessed);
     });
 }
 
 #[test]
-fn unpause_bridge_during_thawn() {
+fn prune_processed_inbound_transfers_with_invalid_floor() {
     with_test_externalities(|| {
-        let thawn_duration: BlockNumber = 2;
-        let operator = account!(1);
         let parameters = BridgeConstraints {
-            operator_account: Some(operator),
-            pauser_accounts: Some(vec![account!(2)]),
+            operator_account: Some(account!(1)),
+            pauser_accounts: None,
             bridging_fee: None,
-            thawn_duration: Some(thawn_duration),
+            thawn_duration: None,
             remote_chains: None,
+            attester_accounts: None,
+            attestation_threshold: None,
+            attestation_ttl: None,
+            rate_limits: None,
+            remote_chain_fees: None,
+            fee_distribution: None,
+            operator_reward_account: None,
+            creator_token_mint_allowances: None,
         };
         ArgoBridge::update_bridge_constrains(RuntimeOrigin::root(), parameters).unwrap();
 
-        ArgoBridge::pause_bridge(RuntimeOrigin::signed(account!(2))).unwrap();
+        let result =
+            ArgoBridge::prune_processed_inbound_transfers(RuntimeOrigin::signed(account!(1)), 1, 0);
+        assert_err!(result, Error::<Test>::InvalidProcessedInboundTransfersFloor);
 
-        ArgoBridge::init_unpause_bridge(RuntimeOrigin::signed(account!(2))).unwrap();
-        let current_block = <frame_system::Pallet<Test>>::block_number();
-        assert_eq!(
-            ArgoBridge::status(),
-            BridgeStatus::Thawn {
-                thawn_ends_at: current_block + thawn_duration
-            }
+        let result = ArgoBridge::prune_processed_inbound_transfers(
+            RuntimeOrigin::signed(account!(1)),
+            1,
+            (MAX_PRUNED_INBOUND_TRANSFERS + 1).into(),
         );
-        increase_block_number_by(1);
-        let result = ArgoBridge::finish_unpause_bridge(RuntimeOrigin::signed(operator));
-        assert_err!(result, Error::<Test>::ThawnNotFinished);
+        assert_err!(result, Error::<Test>::TooManyInboundTransfersToPrune);
     });
 }
 
 #[test]
-fn unpause_bridge_without_operator_account() {
-    with_test_externalities(|| {
-        let thawn_duration: BlockNumber = 1;
+fn prune_processed_inbound_transfers_with_missing_transfer_ids() {
+    with_test_externalities_custom_mint_allowance(joy!(1000), || {
+        let remote_chains = BoundedVec::try_from(vec![1u32]).unwrap();
         let parameters = BridgeConstraints {
-            operator_account: None,
+            operator_account: Some(account!(1)),
             pauser_accounts: Some(vec![account!(2)]),
             bridging_fee: None,
-            thawn_duration: Some(thawn_duration),
-            remote_chains: None,
+            thawn_duration: None,
+            remote_chains: Some(remote_chains),
+            attester_accounts: Some(vec![account!(1)]),
+            attestation_threshold: Some(1),
+            attestation_ttl: None,
+            rate_limits: None,
+            remote_chain_fees: None,
+            fee_distribution: None,
+            operator_reward_account: None,
+            creator_token_mint_allowances: None,
         };
         ArgoBridge::update_bridge_constrains(RuntimeOrigin::root(), parameters).unwrap();
-        ArgoBridge::init_unpause_bridge(RuntimeOrigin::signed(account!(2))).unwrap();
+        assert_ok!(activate_bridge(account!(2), account!(1)));
 
-        increase_block_number_by(1);
+        // transfer 1 is not in the registry
+        for id in [0, 2] {
+            assert_ok!(ArgoBridge::finalize_inbound_transfer(
+                RuntimeOrigin::signed(account!(1)),
+                RemoteTransfer { id, chain_id: 1 },
+                account!(2),
+                BridgedAsset::Joy,
+                joy!(100),
+            ));
+        }
 
-        let result = ArgoBridge::finish_unpause_bridge(RuntimeOrigin::signed(account!(1)));
-        assert_err!(result, Error::<Test>::OperatorAccountNotSet);
+        assert_ok!(ArgoBridge::prune_processed_inbound_transfers(
+            RuntimeOrigin::signed(account!(1)),
+            1,
+            3
+        ));
+        assert_eq!(ArgoBridge::processed_inbound_transfers_floor(1), 3);
+        for id in [0, 2] {
+            assert_eq!(ArgoBridge::processed_inbound_transfers(1, id), None);
+        }
+
+        for id in 0..3 {
+            let result = ArgoBridge::finalize_inbound_transfer(
+                RuntimeOrigin::signed(account!(1)),
+                RemoteTransfer { id, chain_id: 1 },
+                account!(2),
+                BridgedAsset::Joy,
+                joy!(100),
+            );
+            assert_err!(result, Error::<Test>::InboundTransferAlreadyProcessed);
+        }
     });
 }
 
 #[test]
-fn init_unpause_bridge_with_unauthorized_account() {
+fn prune_processed_inbound_transfers_with_unauthorized_account() {
     with_test_externalities(|| {
         let parameters = BridgeConstraints {
             operator_account: Some(account!(1)),
-            pauser_accounts: Some(vec![account!(2)]),
+            pauser_accounts: None,
             bridging_fee: None,
             thawn_duration: None,
             remote_chains: None,
+            attester_accounts: None,
+            attestation_threshold: None,
+            attestation_ttl: None,
+            rate_limits: None,
+            remote_chain_fees: None,
+            fee_distribution: None,
+            operator_reward_account: None,
+            creator_token_mint_allowances: None,
         };
         ArgoBridge::update_bridge_constrains(RuntimeOrigin::root(), parameters).unwrap();
 
-        ArgoBridge::pause_bridge(RuntimeOrigin::signed(account!(2))).unwrap();
-
-        let result = ArgoBridge::init_unpause_bridge(RuntimeOrigin::signed(account!(1)));
-        assert_err!(result, Error::<Test>::NotPauserAccount);
+        let result =
+            ArgoBridge::prune_processed_inbound_transfers(RuntimeOrigin::signed(account!(2)), 1, 1);
+        assert_err!(result, Error::<Test>::NotOperatorAccount);
     });
 }
 
-pub fn activate_bridge(pauser_account_id: u64, operator_account_id: u64) -> DispatchResult {
-    let pauser_origin = RuntimeOrigin::signed(pauser_account_id);
-    ArgoBridge::init_unpause_bridge(pauser_origin)?;
-    System::set_block_number(3u32.into());
-    ArgoBridge::finish_unpause_bridge(RuntimeOrigin::signed(operator_account_id))?;
-    Ok(())
+#[test]
+fn revert_outbound_transfer_success() {
+    with_test_externalities(|| {
+        let remote_chains = BoundedVec::try_from(vec![1u32]).unwrap();
+        let parameters = BridgeConstraints {
+            operator_account: Some(account!(1)),
+            pauser_accounts: Some(vec![account!(2)]),
+            bridging_fee: Some(joy!(10)),
+            thawn_duration: None,
+            remote_chains: Some(remote_chains),
+            attester_accounts: None,
+            attestation_threshold: None,
+            attestation_ttl: None,
+            rate_limits: None,
+            remote_chain_fees: None,
+            fee_distribution: None,
+            operator_reward_account: None,
+            creator_token_mint_allowances: None,
+        };
+        ArgoBridge::update_bridge_constrains(RuntimeOrigin::root(), parameters).unwrap();
+        assert_ok!(activate_bridge(account!(2), account!(1)));
+
+        let sender = account!(3);
+        let transfer_amount = joy!(123);
+        let transfer_id = request_outbound_transfer(sender, transfer_amount, joy!(10));
+        assert_eq!(Balances::free_balance(sender), ExistentialDeposit::get());
+
+        let rationale = "test".as_bytes().to_vec();
+        let result = ArgoBridge::revert_outbound_transfer(
+            RuntimeOrigin::signed(account!(1)),
+            transfer_id,
+            rationale.clone().try_into().unwrap(),
+        );
+        assert_ok!(result);
+        assert_eq!(
+            Balances::free_balance(sender),
+            ExistentialDeposit::get() + transfer_amount
+        );
+        assert_eq!(ArgoBridge::mint_allowance(), joy!(0));
+        assert_eq!(
+            ArgoBridge::outbound_transfers(transfer_id).unwrap().status,
+            OutboundTransferStatus::Reverted
+        );
+        last_event_eq!(RawEvent::OutboundTransferReverted(
+            transfer_id,
+            sender,
+            transfer_amount,
+            rationale.try_into().unwrap(),
+        ));
+    });
+}
+
+#[test]
+fn revert_outbound_transfer_twice() {
+    with_test_externalities(|| {
+        let remote_chains = BoundedVec::try_from(vec![1u32]).unwrap();
+        let parameters = BridgeConstraints {
+            operator_account: Some(account!(1)),
+            pauser_accounts: Some(vec![account!(2)]),
+            bridging_fee: Some(joy!(10)),
+            thawn_duration: None,
+            remote_chains: Some(remote_chains),
+            attester_accounts: None,
+            attestation_threshold: None,
+            attestation_ttl: None,
+            rate_limits: None,
+            remote_chain_fees: None,
+            fee_distribution: None,
+            operator_reward_account: None,
+            creator_token_mint_allowances: None,
+        };
+        ArgoBridge::update_bridge_constrains(RuntimeOrigin::root(), parameters).unwrap();
+        assert_ok!(activate_bridge(account!(2), account!(1)));
+
+        let transfer_id = request_outbound_transfer(account!(3), joy!(123), joy!(10));
+        assert_ok!(ArgoBridge::revert_outbound_transfer(
+            RuntimeOrigin::signed(account!(1)),
+            transfer_id,
+            vec![].try_into().unwrap(),
+        ));
+
+        let result = ArgoBridge::revert_outbound_transfer(
+            RuntimeOrigin::signed(account!(1)),
+            transfer_id,
+            vec![].try_into().unwrap(),
+        );
+        assert_err!(result, Error::<Test>::OutboundTransferNotPending);
+    });
+}
+
+#[test]
+fn revert_outbound_transfer_not_found() {
+    with_test_externalities(|| {
+        let parameters = BridgeConstraints {
+            operator_account: Some(account!(1)),
+            pauser_accounts: Some(vec![account!(2)]),
+            bridging_fee: None,
+            thawn_duration: None,
+            remote_chains: None,
+            attester_accounts: None,
+            attestation_threshold: None,
+            attestation_ttl: None,
+            rate_limits: None,
+            remote_chain_fees: None,
+            fee_distribution: None,
+            operator_reward_account: None,
+            creator_token_mint_allowances: None,
+        };
+        ArgoBridge::update_bridge_constrains(RuntimeOrigin::root(), parameters).unwrap();
+        assert_ok!(activate_bridge(account!(2), account!(1)));
+
+        let result = ArgoBridge::revert_outbound_transfer(
+            RuntimeOrigin::signed(account!(1)),
+            1u64,
+            vec![].try_into().unwrap(),
+        );
+        assert_err!(result, Error::<Test>::OutboundTransferNotFound);
+    });
+}
+
+#[test]
+fn revert_outbound_transfer_with_unauthorized_account() {
+    with_test_externalities(|| {
+        let remote_chains = BoundedVec::try_from(vec![1u32]).unwrap();
+        let parameters = BridgeConstraints {
+            operator_account: Some(account!(1)),
+            pauser_accounts: Some(vec![account!(2)]),
+            bridging_fee: None,
+            thawn_duration: None,
+            remote_chains: Some(remote_chains),
+            attester_accounts: None,
+            attestation_threshold: None,
+            attestation_ttl: None,
+            rate_limits: None,
+            remote_chain_fees: None,
+            fee_distribution: None,
+            operator_reward_account: None,
+            creator_token_mint_allowances: None,
+        };
+        assert_ok!(ArgoBridge::update_bridge_constrains(
+            RuntimeOrigin::root(),
+            parameters
+        ));
+        assert_ok!(activate_bridge(account!(2), account!(1)));
+
+        let result = ArgoBridge::revert_outbound_transfer(
+            RuntimeOrigin::signed(account!(2)),
+            1u64,
+            vec![].try_into().unwrap(),
+        );
+        assert_err!(result, Error::<Test>::NotOperatorAccount);
+    });
+}
+
+#[test]
+fn revert_outbound_transfer_with_insufficient_bridge_mint() {
+    with_test_externalities(|| {
+        let remote_chains = BoundedVec::try_from(vec![1u32]).unwrap();
+        let parameters = BridgeConstraints {
+            operator_account: Some(account!(1)),
+            pauser_accounts: Some(vec![account!(2)]),
+            bridging_fee: Some(joy!(10)),
+            thawn_duration: None,
+            remote_chains: Some(remote_chains),
+            attester_accounts: Some(vec![account!(1)]),
+            attestation_threshold: Some(1),
+            attestation_ttl: None,
+            rate_limits: None,
+            remote_chain_fees: None,
+            fee_distribution: None,
+            operator_reward_account: None,
+            creator_token_mint_allowances: None,
+        };
+        assert_ok!(ArgoBridge::update_bridge_constrains(
+            RuntimeOrigin::root(),
+            parameters
+        ));
+        assert_ok!(activate_bridge(account!(2), account!(1)));
+
+        let transfer_id = request_outbound_transfer(account!(3), joy!(100), joy!(10));
+        assert_ok!(ArgoBridge::finalize_inbound_transfer(
+            RuntimeOrigin::signed(account!(1)),
+            RemoteTransfer { id: 0, chain_id: 1 },
+            account!(4),
+            BridgedAsset::Joy,
+            joy!(50),
+        ));
+
+        let result = ArgoBridge::revert_outbound_transfer(
+            RuntimeOrigin::signed(account!(1)),
+            transfer_id,
+            vec![].try_into().unwrap(),
+        );
+        assert_err!(result, Error::<Test>::InsufficientBridgeMintAllowance);
+    });
+}
+
+#[test]
+fn revert_outbound_transfer_with_bridge_paused() {
+    with_test_externalities(|| {
+        let remote_chains = BoundedVec::try_from(vec![1u32]).unwrap();
+        let parameters = BridgeConstraints {
+            operator_account: Some(account!(1)),
+            pauser_accounts: Some(vec![account!(2)]),
+            bridging_fee: None,
+            thawn_duration: None,
+            remote_chains: Some(remote_chains),
+            attester_accounts: None,
+            attestation_threshold: None,
+            attestation_ttl: None,
+            rate_limits: None,
+            remote_chain_fees: None,
+            fee_distribution: None,
+            operator_reward_account: None,
+            creator_token_mint_allowances: None,
+        };
+        ArgoBridge::update_bridge_constrains(RuntimeOrigin::root(), parameters).unwrap();
+
+        let result = ArgoBridge::revert_outbound_transfer(
+            RuntimeOrigin::signed(account!(1)),
+            1u64,
+            vec![].try_into().unwrap(),
+        );
+        assert_err!(result, Error::<Test>::BridgeNotActive);
+    });
+}
+
+#[test]
+fn complete_outbound_transfer_success() {
+    with_test_externalities(|| {
+        let remote_chains = BoundedVec::try_from(vec![1u32]).unwrap();
+        let parameters = BridgeConstraints {
+            operator_account: Some(account!(1)),
+            pauser_accounts: Some(vec![account!(2)]),
+            bridging_fee: Some(joy!(10)),
+            thawn_duration: None,
+            remote_chains: Some(remote_chains),
+            attester_accounts: None,
+            attestation_threshold: None,
+            attestation_ttl: None,
+            rate_limits: None,
+            remote_chain_fees: None,
+            fee_distribution: None,
+            operator_reward_account: None,
+            creator_token_mint_allowances: None,
+        };
+        ArgoBridge::update_bridge_constrains(RuntimeOrigin::root(), parameters).unwrap();
+        assert_ok!(activate_bridge(account!(2), account!(1)));
+
+        let transfer_id = request_outbound_transfer(account!(3), joy!(100), joy!(10));
+        assert_ok!(ArgoBridge::complete_outbound_transfer(
+            RuntimeOrigin::signed(account!(1)),
+            transfer_id
+        ));
+        assert_eq!(
+            ArgoBridge::outbound_transfers(transfer_id).unwrap().status,
+            OutboundTransferStatus::Completed
+        );
+        last_event_eq!(RawEvent::OutboundTransferCompleted(transfer_id));
+
+        // completed transfer can no longer be reverted
+        let result = ArgoBridge::revert_outbound_transfer(
+            RuntimeOrigin::signed(account!(1)),
+            transfer_id,
+            vec![].try_into().unwrap(),
+        );
+        assert_err!(result, Error::<Test>::OutboundTransferNotPending);
+    });
+}
+
+#[test]
+fn complete_outbound_transfer_with_unauthorized_account() {
+    with_test_externalities(|| {
+        let remote_chains = BoundedVec::try_from(vec![1u32]).unwrap();
+        let parameters = BridgeConstraints {
+            operator_account: Some(account!(1)),
+            pauser_accounts: Some(vec![account!(2)]),
+            bridging_fee: Some(joy!(10)),
+            thawn_duration: None,
+            remote_chains: Some(remote_chains),
+            attester_accounts: None,
+            attestation_threshold: None,
+            attestation_ttl: None,
+            rate_limits: None,
+            remote_chain_fees: None,
+            fee_distribution: None,
+            operator_reward_account: None,
+            creator_token_mint_allowances: None,
+        };
+        ArgoBridge::update_bridge_constrains(RuntimeOrigin::root(), parameters).unwrap();
+        assert_ok!(activate_bridge(account!(2), account!(1)));
+
+        let transfer_id = request_outbound_transfer(account!(3), joy!(100), joy!(10));
+        let result =
+            ArgoBridge::complete_outbound_transfer(RuntimeOrigin::signed(account!(3)), transfer_id);
+        assert_err!(result, Error::<Test>::NotOperatorAccount);
+    });
+}
+
+#[test]
+fn prune_outbound_transfer_success() {
+    with_test_externalities(|| {
+        let remote_chains = BoundedVec::try_from(vec![1u32]).unwrap();
+        let parameters = BridgeConstraints {
+            operator_account: Some(account!(1)),
+            pauser_accounts: Some(vec![account!(2)]),
+            bridging_fee: Some(joy!(10)),
+            thawn_duration: None,
+            remote_chains: Some(remote_chains),
+            attester_accounts: None,
+            attestation_threshold: None,
+            attestation_ttl: None,
+            rate_limits: None,
+            remote_chain_fees: None,
+            fee_distribution: None,
+            operator_reward_account: None,
+            creator_token_mint_allowances: None,
+        };
+        ArgoBridge::update_bridge_constrains(RuntimeOrigin::root(), parameters).unwrap();
+        assert_ok!(activate_bridge(account!(2), account!(1)));
+
+        let transfer_id = request_outbound_transfer(account!(3), joy!(100), joy!(10));
+        assert_ok!(ArgoBridge::complete_outbound_transfer(
+            RuntimeOrigin::signed(account!(1)),
+            transfer_id
+        ));
+        assert_ok!(ArgoBridge::prune_outbound_transfer(
+            RuntimeOrigin::signed(account!(1)),
+            transfer_id
+        ));
+        assert!(ArgoBridge::outbound_transfers(transfer_id).is_none());
+        last_event_eq!(RawEvent::OutboundTransferPruned(transfer_id));
+    });
+}
+
+#[test]
+fn prune_outbound_transfer_still_pending() {
+    with_test_externalities(|| {
+        let remote_chains = BoundedVec::try_from(vec![1u32]).unwrap();
+        let parameters = BridgeConstraints {
+            operator_account: Some(account!(1)),
+            pauser_accounts: Some(vec![account!(2)]),
+            bridging_fee: Some(joy!(10)),
+            thawn_duration: None,
+            remote_chains: Some(remote_chains),
+            attester_accounts: None,
+            attestation_threshold: None,
+            attestation_ttl: None,
+            rate_limits: None,
+            remote_chain_fees: None,
+            fee_distribution: None,
+            operator_reward_account: None,
+            creator_token_mint_allowances: None,
+        };
+        ArgoBridge::update_bridge_constrains(RuntimeOrigin::root(), parameters).unwrap();
+        assert_ok!(activate_bridge(account!(2), account!(1)));
+
+        let transfer_id = request_outbound_transfer(account!(3), joy!(100), joy!(10));
+        let result =
+            ArgoBridge::prune_outbound_transfer(RuntimeOrigin::signed(account!(1)), transfer_id);
+        assert_err!(result, Error::<Test>::OutboundTransferStillPending);
+    });
+}
+
+#[test]
+fn request_outbound_transfer_with_rate_limit_exceeded_pauses_bridge() {
+    with_test_externalities(|| {
+        let remote_chains = BoundedVec::try_from(vec![1u32]).unwrap();
+        let rate_limit_key = RateLimitKey {
+            direction: TransferDirection::Outbound,
+            chain_id: None,
+            token_id: None,
+        };
+        let rate_limit = RateLimit {
+            max_amount: joy!(150),
+            window: 10,
+        };
+        let parameters = BridgeConstraints {
+            operator_account: Some(account!(1)),
+            pauser_accounts: Some(vec![account!(2)]),
+            bridging_fee: Some(joy!(10)),
+            thawn_duration: None,
+            remote_chains: Some(remote_chains),
+            attester_accounts: None,
+            attestation_threshold: None,
+            attestation_ttl: None,
+            rate_limits: Some(BoundedVec::try_from(vec![(rate_limit_key, rate_limit)]).unwrap()),
+            remote_chain_fees: None,
+            fee_distribution: None,
+            operator_reward_account: None,
+            creator_token_mint_allowances: None,
+        };
+        ArgoBridge::update_bridge_constrains(RuntimeOrigin::root(), parameters).unwrap();
+        assert_ok!(activate_bridge(account!(2), account!(1)));
+
+        request_outbound_transfer(account!(3), joy!(100), joy!(10));
+        assert_eq!(
+            ArgoBridge::rate_limits_usage(rate_limit_key).used_amount,
+            joy!(100)
+        );
+
+        Balances::set_balance(RuntimeOrigin::root(), account!(4), joy!(1000), joy!(0)).unwrap();
+        let transfer_id = ArgoBridge::next_transfer_id();
+        assert_ok!(ArgoBridge::request_outbound_transfer(
+            RuntimeOrigin::signed(account!(4)),
+            RemoteAccount {
+                account: [0; 32],
+                chain_id: 1,
+            },
+            BridgedAsset::Joy,
+            joy!(100),
+            joy!(10),
+        ));
+        assert_eq!(ArgoBridge::status(), BridgeStatus::Paused);
+        assert_eq!(Balances::free_balance(account!(4)), joy!(1000));
+        assert!(ArgoBridge::outbound_transfers(transfer_id).is_none());
+        assert_eq!(ArgoBridge::next_transfer_id(), transfer_id);
+        assert_eq!(
+            ArgoBridge::rate_limits_usage(rate_limit_key).used_amount,
+            joy!(100)
+        );
+        last_event_eq!(RawEvent::BridgePausedByRateLimit(rate_limit_key));
+    });
+}
+
+#[test]
+fn rate_limit_usage_released_over_rolling_window() {
+    with_test_externalities(|| {
+        let remote_chains = BoundedVec::try_from(vec![1u32]).unwrap();
+        let rate_limit_key = RateLimitKey {
+            direction: TransferDirection::Outbound,
+            chain_id: Some(1),
+            token_id: None,
+        };
+        let rate_limit = RateLimit {
+            max_amount: joy!(150),
+            window: 10,
+        };
+        let parameters = BridgeConstraints {
+            operator_account: Some(account!(1)),
+            pauser_accounts: Some(vec![account!(2)]),
+            bridging_fee: Some(joy!(10)),
+            thawn_duration: None,
+            remote_chains: Some(remote_chains),
+            attester_accounts: None,
+            attestation_threshold: None,
+            attestation_ttl: None,
+            rate_limits: Some(BoundedVec::try_from(vec![(rate_limit_key, rate_limit)]).unwrap()),
+            remote_chain_fees: None,
+            fee_distribution: None,
+            operator_reward_account: None,
+            creator_token_mint_allowances: None,
+        };
+        ArgoBridge::update_bridge_constrains(RuntimeOrigin::root(), parameters).unwrap();
+        assert_ok!(activate_bridge(account!(2), account!(1)));
+
+        request_outbound_transfer(account!(3), joy!(100), joy!(10));
+
+        // 4/10 of the max amount released
+        increase_block_number_by(4);
+        let usage = ArgoBridge::rate_limits_usage(rate_limit_key);
+        assert_eq!(
+            usage.used_amount_at(&rate_limit, System::block_number()),
+            joy!(40)
+        );
+
+        request_outbound_transfer(account!(4), joy!(100), joy!(10));
+        assert_eq!(
+            ArgoBridge::rate_limits_usage(rate_limit_key),
+            RateLimitUsage {
+                updated_at: System::block_number(),
+                used_amount: joy!(140),
+            }
+        );
+        assert_eq!(ArgoBridge::status(), BridgeStatus::Active);
+
+        // whole usage released after the window
+        increase_block_number_by(10);
+        let usage = ArgoBridge::rate_limits_usage(rate_limit_key);
+        assert_eq!(
+            usage.used_amount_at(&rate_limit, System::block_number()),
+            joy!(0)
+        );
+    });
+}
+
+#[test]
+fn finalize_inbound_transfer_with_rate_limit_exceeded_pauses_bridge() {
+    with_test_externalities_custom_mint_allowance(joy!(1000), || {
+        let remote_chains = BoundedVec::try_from(vec![1u32]).unwrap();
+        let rate_limit_key = RateLimitKey {
+            direction: TransferDirection::Inbound,
+            chain_id: Some(1),
+            token_id: None,
+        };
+        let rate_limit = RateLimit {
+            max_amount: joy!(150),
+            window: 10,
+        };
+        let parameters = BridgeConstraints {
+            operator_account: Some(account!(1)),
+            pauser_accounts: Some(vec![account!(2)]),
+            bridging_fee: None,
+            thawn_duration: None,
+            remote_chains: Some(remote_chains),
+            attester_accounts: Some(vec![account!(1)]),
+            attestation_threshold: Some(1),
+            attestation_ttl: None,
+            rate_limits: Some(BoundedVec::try_from(vec![(rate_limit_key, rate_limit)]).unwrap()),
+            remote_chain_fees: None,
+            fee_distribution: None,
+            operator_reward_account: None,
+            creator_token_mint_allowances: None,
+        };
+        ArgoBridge::update_bridge_constrains(RuntimeOrigin::root(), parameters).unwrap();
+        assert_ok!(activate_bridge(account!(2), account!(1)));
+
+        assert_ok!(ArgoBridge::finalize_inbound_transfer(
+            RuntimeOrigin::signed(account!(1)),
+            RemoteTransfer { id: 0, chain_id: 1 },
+            account!(3),
+            BridgedAsset::Joy,
+            joy!(100),
+        ));
+
+        let remote_transfer = RemoteTransfer { id: 1, chain_id: 1 };
+        assert_ok!(ArgoBridge::finalize_inbound_transfer(
+            RuntimeOrigin::signed(account!(1)),
+            remote_transfer.clone(),
+            account!(3),
+            BridgedAsset::Joy,
+            joy!(100),
+        ));
+        assert_eq!(ArgoBridge::status(), BridgeStatus::Paused);
+        assert_eq!(Balances::free_balance(account!(3)), joy!(100));
+        assert!(!ArgoBridge::is_inbound_transfer_processed(&remote_transfer));
+        assert!(ArgoBridge::inbound_transfer_attestations(&remote_transfer, account!(1)).is_some());
+        last_event_eq!(RawEvent::BridgePausedByRateLimit(rate_limit_key));
+    });
+}
+
+#[test]
+fn pause_bridge_success() {
+    with_test_externalities(|| {
+        let pauser_account = account!(2);
+        let parameters = BridgeConstraints {
+            operator_account: Some(account!(1)),
+            pauser_accounts: Some(vec![pauser_account]),
+            bridging_fee: None,
+            thawn_duration: None,
+            remote_chains: None,
+            attester_accounts: None,
+            attestation_threshold: None,
+            attestation_ttl: None,
+            rate_limits: None,
+            remote_chain_fees: None,
+            fee_distribution: None,
+            operator_reward_account: None,
+            creator_token_mint_allowances: None,
+        };
+        assert_ok!(ArgoBridge::update_bridge_constrains(
+            RuntimeOrigin::root(),
+            parameters
+        ));
+        assert_ok!(activate_bridge(pauser_account, account!(1)));
+        assert_eq!(ArgoBridge::status(), BridgeStatus::Active);
+
+        assert_ok!(ArgoBridge::pause_bridge(RuntimeOrigin::signed(
+            pauser_account
+        )));
+        assert_eq!(ArgoBridge::status(), BridgeStatus::Paused);
+        last_event_eq!(RawEvent::BridgePaused(pauser_account));
+    });
+}
+
+#[test]
+fn pause_bridge_with_unauthorized_account() {
+    with_test_externalities(|| {
+        let parameters = BridgeConstraints {
+            operator_account: None,
+            pauser_accounts: Some(vec![account!(2)]),
+            bridging_fee: None,
+            thawn_duration: None,
+            remote_chains: None,
+            attester_accounts: None,
+            attestation_threshold: None,
+            attestation_ttl: None,
+            rate_limits: None,
+            remote_chain_fees: None,
+            fee_distribution: None,
+            operator_reward_account: None,
+            creator_token_mint_allowances: None,
+        };
+        assert_ok!(ArgoBridge::update_bridge_constrains(
+            RuntimeOrigin::root(),
+            parameters
+        ));
+
+        let result = ArgoBridge::pause_bridge(RuntimeOrigin::signed(account!(1)));
+        assert_err!(result, Error::<Test>::NotPauserAccount);
+    });
+}
+
+#[test]
+fn unpause_bridge_success() {
+    with_test_externalities(|| {
+        let parameters = BridgeConstraints {
+            operator_account: Some(account!(1)),
+            pauser_accounts: Some(vec![account!(2)]),
+            bridging_fee: None,
+            thawn_duration: Some(1),
+            remote_chains: None,
+            attester_accounts: None,
+            attestation_threshold: None,
+            attestation_ttl: None,
+            rate_limits: None,
+            remote_chain_fees: None,
+            fee_distribution: None,
+            operator_reward_account: None,
+            creator_token_mint_allowances: None,
+        };
+        ArgoBridge::update_bridge_constrains(RuntimeOrigin::root(), parameters).unwrap();
+
+        ArgoBridge::init_unpause_bridge(RuntimeOrigin::signed(account!(2))).unwrap();
+        let current_block = <frame_system::Pallet<Test>>::block_number();
+        assert_eq!(
+            ArgoBridge::status(),
+            BridgeStatus::Thawn {
+                thawn_ends_at: current_block + 1
+            }
+        );
+        increase_block_number_by(2);
+        assert_ok!(ArgoBridge::finish_unpause_bridge(RuntimeOrigin::signed(
+            account!(1)
+        )));
+        last_event_eq!(RawEvent::BridgeThawnFinished());
+    });
+}
+
+#[test]
+fn init_unpause_bridge_active() {
+    with_test_externalities(|| {
+        let parameters = BridgeConstraints {
+            operator_account: Some(account!(1)),
+            pauser_accounts: Some(vec![account!(2)]),
+            bridging_fee: None,
+            thawn_duration: None,
+            remote_chains: None,
+            attester_accounts: None,
+            attestation_threshold: None,
+            attestation_ttl: None,
+            rate_limits: None,
+            remote_chain_fees: None,
+            fee_distribution: None,
+            operator_reward_account: None,
+            creator_token_mint_allowances: None,
+        };
+        ArgoBridge::update_bridge_constrains(RuntimeOrigin::root(), parameters).unwrap();
+        assert_ok!(activate_bridge(account!(2), account!(1)));
+
+        let result = ArgoBridge::init_unpause_bridge(RuntimeOrigin::signed(account!(2)));
+        assert_err!(result, Error::<Test>::BridgeNotPaused);
+    });
+}
+
+#[test]
+fn unpause_bridge_during_thawn() {
+    with_test_externalities(|| {
+        let thawn_duration: BlockNumber = 2;
+        let operator = account!(1);
+        let parameters = BridgeConstraints {
+            operator_account: Some(operator),
+            pauser_accounts: Some(vec![account!(2)]),
+            bridging_fee: None,
+            thawn_duration: Some(thawn_duration),
+            remote_chains: None,
+            attester_accounts: None,
+            attestation_threshold: None,
+            attestation_ttl: None,
+            rate_limits: None,
+            remote_chain_fees: None,
+            fee_distribution: None,
+            operator_reward_account: None,
+            creator_token_mint_allowances: None,
+        };
+        ArgoBridge::update_bridge_constrains(RuntimeOrigin::root(), parameters).unwrap();
+
+        ArgoBridge::pause_bridge(RuntimeOrigin::signed(account!(2))).unwrap();
+
+        ArgoBridge::init_unpause_bridge(RuntimeOrigin::signed(account!(2))).unwrap();
+        let current_block = <frame_system::Pallet<Test>>::block_number();
+        assert_eq!(
+            ArgoBridge::status(),
+            BridgeStatus::Thawn {
+                thawn_ends_at: current_block + thawn_duration
+            }
+        );
+        increase_block_number_by(1);
+        let result = ArgoBridge::finish_unpause_bridge(RuntimeOrigin::signed(operator));
+        assert_err!(result, Error::<Test>::ThawnNotFinished);
+    });
+}
+
+#[test]
+fn unpause_bridge_without_operator_account() {
+    with_test_externalities(|| {
+        let thawn_duration: BlockNumber = 1;
+        let parameters = BridgeConstraints {
+            operator_account: None,
+            pauser_accounts: Some(vec![account!(2)]),
+            bridging_fee: None,
+            thawn_duration: Some(thawn_duration),
+            remote_chains: None,
+            attester_accounts: None,
+            attestation_threshold: None,
+            attestation_ttl: None,
+            rate_limits: None,
+            remote_chain_fees: None,
+            fee_distribution: None,
+            operator_reward_account: None,
+            creator_token_mint_allowances: None,
+        };
+        ArgoBridge::update_bridge_constrains(RuntimeOrigin::root(), parameters).unwrap();
+        ArgoBridge::init_unpause_bridge(RuntimeOrigin::signed(account!(2))).unwrap();
+
+        increase_block_number_by(1);
+
+        let result = ArgoBridge::finish_unpause_bridge(RuntimeOrigin::signed(account!(1)));
+        assert_err!(result, Error::<Test>::OperatorAccountNotSet);
+    });
+}
+
+#[test]
+fn init_unpause_bridge_with_unauthorized_account() {
+    with_test_externalities(|| {
+        let parameters = BridgeConstraints {
+            operator_account: Some(account!(1)),
+            pauser_accounts: Some(vec![account!(2)]),
+            bridging_fee: None,
+            thawn_duration: None,
+            remote_chains: None,
+            attester_accounts: None,
+            attestation_threshold: None,
+            attestation_ttl: None,
+            rate_limits: None,
+            remote_chain_fees: None,
+            fee_distribution: None,
+            operator_reward_account: None,
+            creator_token_mint_allowances: None,
+        };
+        ArgoBridge::update_bridge_constrains(RuntimeOrigin::root(), parameters).unwrap();
+
+        ArgoBridge::pause_bridge(RuntimeOrigin::signed(account!(2))).unwrap();
+
+        let result = ArgoBridge::init_unpause_bridge(RuntimeOrigin::signed(account!(1)));
+        assert_err!(result, Error::<Test>::NotPauserAccount);
+    });
+}
+
+#[test]
+fn request_outbound_transfer_with_creator_token_success() {
+    with_test_externalities(|| {
+        let remote_chains = BoundedVec::try_from(vec![1u32]).unwrap();
+        let parameters = BridgeConstraints {
+            operator_account: Some(account!(1)),
+            pauser_accounts: Some(vec![account!(2)]),
+            bridging_fee: Some(joy!(10)),
+            thawn_duration: None,
+            remote_chains: Some(remote_chains),
+            attester_accounts: None,
+            attestation_threshold: None,
+            attestation_ttl: None,
+            rate_limits: None,
+            remote_chain_fees: None,
+            fee_distribution: None,
+            operator_reward_account: None,
+            creator_token_mint_allowances: None,
+        };
+        ArgoBridge::update_bridge_constrains(RuntimeOrigin::root(), parameters).unwrap();
+        assert_ok!(activate_bridge(account!(2), account!(1)));
+
+        let (member_id, sender) = member!(1);
+        let token_id = issue_creator_token(member_id, 1000);
+        let transfer_amount = joy!(400);
+        let transfer_id =
+            request_creator_token_outbound_transfer(member_id, token_id, transfer_amount, joy!(10));
+
+        assert_eq!(Balances::free_balance(sender), ExistentialDeposit::get());
+        assert_eq!(
+            Token::account_info_by_token_and_member(token_id, member_id).amount,
+            600
+        );
+        assert_eq!(Token::token_info_by_id(token_id).total_supply, 600);
+        assert_eq!(
+            ArgoBridge::creator_token_mint_allowance(token_id),
+            transfer_amount
+        );
+        assert_eq!(ArgoBridge::mint_allowance(), joy!(0));
+        assert_eq!(
+            ArgoBridge::outbound_transfers(transfer_id).unwrap().asset,
+            BridgedAsset::CreatorToken(token_id, member_id)
+        );
+    });
+}
+
+#[test]
+fn request_outbound_transfer_with_creator_token_by_non_controller_account() {
+    with_test_externalities(|| {
+        let remote_chains = BoundedVec::try_from(vec![1u32]).unwrap();
+        let parameters = BridgeConstraints {
+            operator_account: Some(account!(1)),
+            pauser_accounts: Some(vec![account!(2)]),
+            bridging_fee: Some(joy!(10)),
+            thawn_duration: None,
+            remote_chains: Some(remote_chains),
+            attester_accounts: None,
+            attestation_threshold: None,
+            attestation_ttl: None,
+            rate_limits: None,
+            remote_chain_fees: None,
+            fee_distribution: None,
+            operator_reward_account: None,
+            creator_token_mint_allowances: None,
+        };
+        ArgoBridge::update_bridge_constrains(RuntimeOrigin::root(), parameters).unwrap();
+        assert_ok!(activate_bridge(account!(2), account!(1)));
+
+        let member_id = member!(1).0;
+        let token_id = issue_creator_token(member_id, 1000);
+        let sender = member!(2).1;
+        Balances::set_balance(RuntimeOrigin::root(), sender, joy!(100), joy!(0)).unwrap();
+
+        let result = ArgoBridge::request_outbound_transfer(
+            RuntimeOrigin::signed(sender),
+            RemoteAccount {
+                account: [0; 32],
+                chain_id: 1,
+            },
+            BridgedAsset::CreatorToken(token_id, member_id),
+            joy!(400),
+            joy!(10),
+        );
+        assert_err!(result, Error::<Test>::NotMemberControllerAccount);
+    });
+}
+
+#[test]
+fn request_outbound_transfer_with_creator_token_bridging_disabled() {
+    with_test_externalities(|| {
+        let remote_chains = BoundedVec::try_from(vec![1u32]).unwrap();
+        let parameters = BridgeConstraints {
+            operator_account: Some(account!(1)),
+            pauser_accounts: Some(vec![account!(2)]),
+            bridging_fee: Some(joy!(10)),
+            thawn_duration: None,
+            remote_chains: Some(remote_chains),
+            attester_accounts: None,
+            attestation_threshold: None,
+            attestation_ttl: None,
+            rate_limits: None,
+            remote_chain_fees: None,
+            fee_distribution: None,
+            operator_reward_account: None,
+            creator_token_mint_allowances: None,
+        };
+        ArgoBridge::update_bridge_constrains(RuntimeOrigin::root(), parameters).unwrap();
+        assert_ok!(activate_bridge(account!(2), account!(1)));
+
+        let (member_id, sender) = member!(1);
+        let token_id = issue_creator_token(member_id, 1000);
+        assert_ok!(Token::set_bridging_status(token_id, false));
+        Balances::set_balance(RuntimeOrigin::root(), sender, joy!(100), joy!(0)).unwrap();
+
+        let result = ArgoBridge::request_outbound_transfer(
+            RuntimeOrigin::signed(sender),
+            RemoteAccount {
+                account: [0; 32],
+                chain_id: 1,
+            },
+            BridgedAsset::CreatorToken(token_id, member_id),
+            joy!(400),
+            joy!(10),
+        );
+        assert_err!(
+            result,
+            project_token::Error::<Test>::BridgingNotEnabledForToken
+        );
+    });
+}
+
+#[test]
+fn finalize_inbound_transfer_with_creator_token_success() {
+    with_test_externalities(|| {
+        let remote_chains = BoundedVec::try_from(vec![1u32]).unwrap();
+        let parameters = BridgeConstraints {
+            operator_account: Some(account!(1)),
+            pauser_accounts: Some(vec![account!(2)]),
+            bridging_fee: Some(joy!(10)),
+            thawn_duration: None,
+            remote_chains: Some(remote_chains),
+            attester_accounts: Some(vec![account!(1)]),
+            attestation_threshold: Some(1),
+            attestation_ttl: None,
+            rate_limits: None,
+            remote_chain_fees: None,
+            fee_distribution: None,
+            operator_reward_account: None,
+            creator_token_mint_allowances: None,
+        };
+        ArgoBridge::update_bridge_constrains(RuntimeOrigin::root(), parameters).unwrap();
+        assert_ok!(activate_bridge(account!(2), account!(1)));
+
+        let (member_id, member_account) = member!(1);
+        let token_id = issue_creator_token(member_id, 1000);
+        request_creator_token_outbound_transfer(member_id, token_id, joy!(400), joy!(10));
+
+        let asset = BridgedAsset::CreatorToken(token_id, member_id);
+        let remote_transfer = RemoteTransfer { id: 0, chain_id: 1 };
+        let result = ArgoBridge::finalize_inbound_transfer(
+            RuntimeOrigin::signed(account!(1)),
+            remote_transfer.clone(),
+            member_account,
+            asset,
+            joy!(300),
+        );
+        assert_ok!(result);
+        assert_eq!(
+            Token::account_info_by_token_and_member(token_id, member_id).amount,
+            900
+        );
+        assert_eq!(Token::token_info_by_id(token_id).total_supply, 900);
+        assert_eq!(
+            ArgoBridge::creator_token_mint_allowance(token_id),
+            joy!(100)
+        );
+        assert_eq!(
+            Balances::free_balance(member_account),
+            ExistentialDeposit::get()
+        );
+        last_event_eq!(RawEvent::InboundTransferFinalized(
+            remote_transfer,
+            member_account,
+            asset,
+            joy!(300)
+        ));
+    });
+}
+
+#[test]
+fn finalize_inbound_transfer_with_creator_token_insufficient_mint_allowance() {
+    with_test_externalities_custom_mint_allowance(joy!(1000), || {
+        let remote_chains = BoundedVec::try_from(vec![1u32]).unwrap();
+        let parameters = BridgeConstraints {
+            operator_account: Some(account!(1)),
+            pauser_accounts: Some(vec![account!(2)]),
+            bridging_fee: None,
+            thawn_duration: None,
+            remote_chains: Some(remote_chains),
+            attester_accounts: Some(vec![account!(1)]),
+            attestation_threshold: Some(1),
+            attestation_ttl: None,
+            rate_limits: None,
+            remote_chain_fees: None,
+            fee_distribution: None,
+            operator_reward_account: None,
+            creator_token_mint_allowances: None,
+        };
+        ArgoBridge::update_bridge_constrains(RuntimeOrigin::root(), parameters).unwrap();
+        assert_ok!(activate_bridge(account!(2), account!(1)));
+
+        let (member_id, member_account) = member!(1);
+        let token_id = issue_creator_token(member_id, 1000);
+
+        let result = ArgoBridge::finalize_inbound_transfer(
+            RuntimeOrigin::signed(account!(1)),
+            RemoteTransfer { id: 0, chain_id: 1 },
+            member_account,
+            BridgedAsset::CreatorToken(token_id, member_id),
+            joy!(100),
+        );
+        assert_err!(result, Error::<Test>::InsufficientBridgeMintAllowance);
+    });
+}
+
+#[test]
+fn finalize_inbound_transfer_with_creator_token_to_non_controller_account() {
+    with_test_externalities(|| {
+        let remote_chains = BoundedVec::try_from(vec![1u32]).unwrap();
+        let parameters = BridgeConstraints {
+            operator_account: Some(account!(1)),
+            pauser_accounts: Some(vec![account!(2)]),
+            bridging_fee: None,
+            thawn_duration: None,
+            remote_chains: Some(remote_chains),
+            attester_accounts: Some(vec![account!(1)]),
+            attestation_threshold: Some(1),
+            attestation_ttl: None,
+            rate_limits: None,
+            remote_chain_fees: None,
+            fee_distribution: None,
+            operator_reward_account: None,
+            creator_token_mint_allowances: None,
+        };
+        ArgoBridge::update_bridge_constrains(RuntimeOrigin::root(), parameters).unwrap();
+        assert_ok!(activate_bridge(account!(2), account!(1)));
+
+        let member_id = member!(1).0;
+        let token_id = issue_creator_token(member_id, 1000);
+
+        let result = ArgoBridge::finalize_inbound_transfer(
+            RuntimeOrigin::signed(account!(1)),
+            RemoteTransfer { id: 0, chain_id: 1 },
+            member!(2).1,
+            BridgedAsset::CreatorToken(token_id, member_id),
+            joy!(100),
+        );
+        assert_err!(result, Error::<Test>::NotMemberControllerAccount);
+    });
+}
+
+#[test]
+fn revert_outbound_transfer_with_creator_token_success() {
+    with_test_externalities(|| {
+        let remote_chains = BoundedVec::try_from(vec![1u32]).unwrap();
+        let parameters = BridgeConstraints {
+            operator_account: Some(account!(1)),
+            pauser_accounts: Some(vec![account!(2)]),
+            bridging_fee: Some(joy!(10)),
+            thawn_duration: None,
+            remote_chains: Some(remote_chains),
+            attester_accounts: None,
+            attestation_threshold: None,
+            attestation_ttl: None,
+            rate_limits: None,
+            remote_chain_fees: None,
+            fee_distribution: None,
+            operator_reward_account: None,
+            creator_token_mint_allowances: None,
+        };
+        ArgoBridge::update_bridge_constrains(RuntimeOrigin::root(), parameters).unwrap();
+        assert_ok!(activate_bridge(account!(2), account!(1)));
+
+        let (member_id, sender) = member!(1);
+        let token_id = issue_creator_token(member_id, 1000);
+        let transfer_id =
+            request_creator_token_outbound_transfer(member_id, token_id, joy!(400), joy!(10));
+
+        let result = ArgoBridge::revert_outbound_transfer(
+            RuntimeOrigin::signed(account!(1)),
+            transfer_id,
+            "test".as_bytes().to_vec().try_into().unwrap(),
+        );
+        assert_ok!(result);
+        assert_eq!(
+            Token::account_info_by_token_and_member(token_id, member_id).amount,
+            1000
+        );
+        assert_eq!(ArgoBridge::creator_token_mint_allowance(token_id), joy!(0));
+        assert_eq!(Balances::free_balance(sender), ExistentialDeposit::get());
+    });
+}
+
+#[test]
+fn revert_outbound_transfer_with_creator_token_defers_refund() {
+    with_test_externalities(|| {
+        let remote_chains = BoundedVec::try_from(vec![1u32]).unwrap();
+        let parameters = BridgeConstraints {
+            operator_account: Some(account!(1)),
+            pauser_accounts: Some(vec![account!(2)]),
+            bridging_fee: Some(joy!(10)),
+            thawn_duration: None,
+            remote_chains: Some(remote_chains),
+            attester_accounts: None,
+            attestation_threshold: None,
+            attestation_ttl: None,
+            rate_limits: None,
+            remote_chain_fees: None,
+            fee_distribution: None,
+            operator_reward_account: None,
+            creator_token_mint_allowances: None,
+        };
+        ArgoBridge::update_bridge_constrains(RuntimeOrigin::root(), parameters).unwrap();
+        assert_ok!(activate_bridge(account!(2), account!(1)));
+
+        let (member_id, member_account) = member!(1);
+        let token_id = issue_creator_token(member_id, 1000);
+        let transfer_id =
+            request_creator_token_outbound_transfer(member_id, token_id, joy!(400), joy!(10));
+
+        // creator tokens cannot be minted while the token pallet is frozen
+        assert_ok!(Token::set_frozen_status(RuntimeOrigin::root(), true));
+
+        let result = ArgoBridge::revert_outbound_transfer(
+            RuntimeOrigin::signed(account!(1)),
+            transfer_id,
+            "test".as_bytes().to_vec().try_into().unwrap(),
+        );
+        assert_ok!(result);
+        assert_eq!(
+            ArgoBridge::outbound_transfers(transfer_id).unwrap().status,
+            OutboundTransferStatus::Reverted
+        );
+        assert_eq!(
+            Token::account_info_by_token_and_member(token_id, member_id).amount,
+            600
+        );
+        assert_eq!(
+            ArgoBridge::pending_creator_token_refunds(token_id, member_id),
+            joy!(400)
+        );
+        assert_eq!(ArgoBridge::creator_token_mint_allowance(token_id), joy!(0));
+
+        let result = ArgoBridge::claim_creator_token_refund(
+            RuntimeOrigin::signed(member_account),
+            token_id,
+            member_id,
+        );
+        assert_err!(result, project_token::Error::<Test>::PalletFrozen);
+
+        assert_ok!(Token::set_frozen_status(RuntimeOrigin::root(), false));
+
+        let result = ArgoBridge::claim_creator_token_refund(
+            RuntimeOrigin::signed(member!(2).1),
+            token_id,
+            member_id,
+        );
+        assert_err!(result, Error::<Test>::NotMemberControllerAccount);
+
+        let result = ArgoBridge::claim_creator_token_refund(
+            RuntimeOrigin::signed(member_account),
+            token_id,
+            member_id,
+        );
+        assert_ok!(result);
+        assert_eq!(
+            Token::account_info_by_token_and_member(token_id, member_id).amount,
+            1000
+        );
+        assert_eq!(
+            ArgoBridge::pending_creator_token_refunds(token_id, member_id),
+            joy!(0)
+        );
+        last_event_eq!(RawEvent::CreatorTokenRefundClaimed(
+            token_id,
+            member_id,
+            joy!(400)
+        ));
+
+        let result = ArgoBridge::claim_creator_token_refund(
+            RuntimeOrigin::signed(member_account),
+            token_id,
+            member_id,
+        );
+        assert_err!(result, Error::<Test>::NoPendingCreatorTokenRefund);
+    });
+}
+
+#[test]
+fn request_outbound_transfer_with_creator_token_rate_limit_exceeded_pauses_bridge() {
+    with_test_externalities(|| {
+        let (member_id, sender) = member!(1);
+        let token_id = issue_creator_token(member_id, 1000);
+
+        let remote_chains = BoundedVec::try_from(vec![1u32]).unwrap();
+        let rate_limit_key = RateLimitKey {
+            direction: TransferDirection::Outbound,
+            chain_id: None,
+            token_id: Some(token_id),
+        };
+        let rate_limit = RateLimit {
+            max_amount: joy!(500),
+            window: 10,
+        };
+        let parameters = BridgeConstraints {
+            operator_account: Some(account!(1)),
+            pauser_accounts: Some(vec![account!(2)]),
+            bridging_fee: Some(joy!(10)),
+            thawn_duration: None,
+            remote_chains: Some(remote_chains),
+            attester_accounts: None,
+            attestation_threshold: None,
+            attestation_ttl: None,
+            rate_limits: Some(BoundedVec::try_from(vec![(rate_limit_key, rate_limit)]).unwrap()),
+            remote_chain_fees: None,
+            fee_distribution: None,
+            operator_reward_account: None,
+            creator_token_mint_allowances: None,
+        };
+        ArgoBridge::update_bridge_constrains(RuntimeOrigin::root(), parameters).unwrap();
+        assert_ok!(activate_bridge(account!(2), account!(1)));
+
+        request_creator_token_outbound_transfer(member_id, token_id, joy!(400), joy!(10));
+        assert_eq!(
+            ArgoBridge::rate_limits_usage(rate_limit_key).used_amount,
+            joy!(400)
+        );
+
+        Balances::set_balance(RuntimeOrigin::root(), sender, joy!(100), joy!(0)).unwrap();
+        let transfer_id = ArgoBridge::next_transfer_id();
+        assert_ok!(ArgoBridge::request_outbound_transfer(
+            RuntimeOrigin::signed(sender),
+            RemoteAccount {
+                account: [0; 32],
+                chain_id: 1,
+            },
+            BridgedAsset::CreatorToken(token_id, member_id),
+            joy!(200),
+            joy!(10),
+        ));
+        assert_eq!(ArgoBridge::status(), BridgeStatus::Paused);
+        assert_eq!(
+            Token::account_info_by_token_and_member(token_id, member_id).amount,
+            600
+        );
+        assert_eq!(Balances::free_balance(sender), joy!(100));
+        assert!(ArgoBridge::outbound_transfers(transfer_id).is_none());
+        last_event_eq!(RawEvent::BridgePausedByRateLimit(rate_limit_key));
+    });
+}
+
+#[test]
+fn request_creator_token_outbound_transfer_with_locked_joy_balance() {
+    with_test_externalities(|| {
+        let (member_id, sender) = member!(1);
+        let token_id = issue_creator_token(member_id, 1000);
+
+        let remote_chains = BoundedVec::try_from(vec![1u32]).unwrap();
+        let parameters = BridgeConstraints {
+            operator_account: Some(account!(1)),
+            pauser_accounts: Some(vec![account!(2)]),
+            bridging_fee: Some(joy!(10)),
+            thawn_duration: None,
+            remote_chains: Some(remote_chains),
+            attester_accounts: None,
+            attestation_threshold: None,
+            attestation_ttl: None,
+            rate_limits: None,
+            remote_chain_fees: None,
+            fee_distribution: None,
+            operator_reward_account: None,
+            creator_token_mint_allowances: None,
+        };
+        ArgoBridge::update_bridge_constrains(RuntimeOrigin::root(), parameters).unwrap();
+        assert_ok!(activate_bridge(account!(2), account!(1)));
+
+        Balances::set_balance(RuntimeOrigin::root(), sender, joy!(100), joy!(0)).unwrap();
+        Balances::set_lock(*b"testlock", &sender, joy!(95), WithdrawReasons::all());
+
+        let result = ArgoBridge::request_outbound_transfer(
+            RuntimeOrigin::signed(sender),
+            RemoteAccount {
+                account: [0; 32],
+                chain_id: 1,
+            },
+            BridgedAsset::CreatorToken(token_id, member_id),
+            joy!(200),
+            joy!(10),
+        );
+        assert_err!(result, Error::<Test>::InsufficientJoyBalance);
+        assert_eq!(
+            Token::account_info_by_token_and_member(token_id, member_id).amount,
+            1000
+        );
+        assert_eq!(Balances::free_balance(sender), joy!(100));
+    });
+}
+
+#[test]
+fn update_bridge_constrains_sets_creator_token_mint_allowances() {
+    with_test_externalities(|| {
+        let member_id = member!(1).0;
+        let token_id = issue_creator_token(member_id, 1000);
+        let parameters = BridgeConstraints {
+            operator_account: None,
+            pauser_accounts: None,
+            bridging_fee: None,
+            thawn_duration: None,
+            remote_chains: None,
+            attester_accounts: None,
+            attestation_threshold: None,
+            attestation_ttl: None,
+            rate_limits: None,
+            remote_chain_fees: None,
+            fee_distribution: None,
+            operator_reward_account: None,
+            creator_token_mint_allowances: Some(
+                BoundedVec::try_from(vec![(token_id, joy!(500))]).unwrap(),
+            ),
+        };
+
+        assert_ok!(ArgoBridge::update_bridge_constrains(
+            RuntimeOrigin::root(),
+            parameters.clone()
+        ));
+        assert_eq!(
+            ArgoBridge::creator_token_mint_allowance(token_id),
+            joy!(500)
+        );
+        last_event_eq!(RawEvent::BridgeConfigUpdated(parameters));
+    });
+}
+
+#[test]
+fn update_bridge_constrains_with_invalid_creator_token_mint_allowances() {
+    with_test_externalities(|| {
+        let member_id = member!(1).0;
+        let token_id = issue_creator_token(member_id, 1000);
+        let parameters = BridgeConstraints {
+            operator_account: None,
+            pauser_accounts: None,
+            bridging_fee: None,
+            thawn_duration: None,
+            remote_chains: None,
+            attester_accounts: None,
+            attestation_threshold: None,
+            attestation_ttl: None,
+            rate_limits: None,
+            remote_chain_fees: None,
+            fee_distribution: None,
+            operator_reward_account: None,
+            creator_token_mint_allowances: Some(
+                BoundedVec::try_from(vec![(token_id, joy!(500)), (token_id + 1, joy!(500))])
+                    .unwrap(),
+            ),
+        };
+
+        let result = ArgoBridge::update_bridge_constrains(
+            RuntimeOrigin::signed(account!(1)),
+            parameters.clone(),
+        );
+        assert_err!(result, DispatchError::BadOrigin);
+
+        let result = ArgoBridge::update_bridge_constrains(RuntimeOrigin::root(), parameters);
+        assert_err!(result, Error::<Test>::CreatorTokenNotFound);
+        assert_eq!(ArgoBridge::creator_token_mint_allowance(token_id), 0);
+    });
+}
+
+#[test]
+fn request_outbound_transfer_with_remote_chain_fee_schedule() {
+    with_test_externalities(|| {
+        let remote_chains = BoundedVec::try_from(vec![1u32, 2u32]).unwrap();
+        let fee_schedule = FeeSchedule {
+            flat_fee: joy!(5),
+            percentage_fee: Permill::from_percent(10),
+        };
+        let parameters = BridgeConstraints {
+            operator_account: Some(account!(1)),
+            pauser_accounts: Some(vec![account!(2)]),
+            bridging_fee: Some(joy!(20)),
+            thawn_duration: None,
+            remote_chains: Some(remote_chains),
+            attester_accounts: None,
+            attestation_threshold: None,
+            attestation_ttl: None,
+            rate_limits: None,
+            remote_chain_fees: Some(
+                BoundedVec::try_from(vec![(1u32, Some(fee_schedule))]).unwrap(),
+            ),
+            fee_distribution: None,
+            operator_reward_account: None,
+            creator_token_mint_allowances: None,
+        };
+        assert_ok!(ArgoBridge::update_bridge_constrains(
+            RuntimeOrigin::root(),
+            parameters
+        ));
+        assert_ok!(activate_bridge(account!(2), account!(1)));
+
+        // chain with a fee schedule: 5 + 10% of 100
+        assert_eq!(
+            ArgoBridge::outbound_transfer_fee(&BridgedAsset::Joy, 1, joy!(100)),
+            joy!(15)
+        );
+        // creator tokens are only charged the flat part
+        assert_eq!(
+            ArgoBridge::outbound_transfer_fee(&BridgedAsset::CreatorToken(1, 1), 1, joy!(100)),
+            joy!(5)
+        );
+        // chain without a fee schedule falls back to the global fee
+        assert_eq!(
+            ArgoBridge::outbound_transfer_fee(&BridgedAsset::Joy, 2, joy!(100)),
+            joy!(20)
+        );
+
+        let sender = account!(3);
+        let transfer_id = request_outbound_transfer(sender, joy!(100), joy!(15));
+        assert_eq!(Balances::free_balance(sender), ExistentialDeposit::get());
+        assert_eq!(
+            ArgoBridge::outbound_transfers(transfer_id).unwrap().fee,
+            joy!(15)
+        );
+
+        let result = ArgoBridge::request_outbound_transfer(
+            RuntimeOrigin::signed(sender),
+            RemoteAccount {
+                account: [0; 32],
+                chain_id: 1,
+            },
+            BridgedAsset::Joy,
+            joy!(100),
+            joy!(20),
+        );
+        assert_err!(result, Error::<Test>::FeeDifferentThanExpected);
+    });
+}
+
+#[test]
+fn remove_remote_chain_fee_schedule() {
+    with_test_externalities(|| {
+        let fee_schedule = FeeSchedule {
+            flat_fee: joy!(5),
+            percentage_fee: Permill::from_percent(10),
+        };
+        let mut parameters = BridgeConstraints {
+            operator_account: None,
+            pauser_accounts: None,
+            bridging_fee: Some(joy!(20)),
+            thawn_duration: None,
+            remote_chains: None,
+            attester_accounts: None,
+            attestation_threshold: None,
+            attestation_ttl: None,
+            rate_limits: None,
+            remote_chain_fees: Some(
+                BoundedVec::try_from(vec![(1u32, Some(fee_schedule))]).unwrap(),
+            ),
+            fee_distribution: None,
+            operator_reward_account: None,
+            creator_token_mint_allowances: None,
+        };
+        assert_ok!(ArgoBridge::update_bridge_constrains(
+            RuntimeOrigin::root(),
+            parameters.clone()
+        ));
+        assert_eq!(ArgoBridge::remote_chain_fees(1), Some(fee_schedule));
+
+        parameters.remote_chain_fees = Some(BoundedVec::try_from(vec![(1u32, None)]).unwrap());
+        assert_ok!(ArgoBridge::update_bridge_constrains(
+            RuntimeOrigin::root(),
+            parameters
+        ));
+        assert_eq!(ArgoBridge::remote_chain_fees(1), None);
+        assert_eq!(
+            ArgoBridge::outbound_transfer_fee(&BridgedAsset::Joy, 1, joy!(100)),
+            joy!(20)
+        );
+    });
+}
+
+#[test]
+fn request_outbound_transfer_distributes_fee() {
+    with_test_externalities(|| {
+        let remote_chains = BoundedVec::try_from(vec![1u32]).unwrap();
+        let reward_account = account!(4);
+        let parameters = BridgeConstraints {
+            operator_account: Some(account!(1)),
+            pauser_accounts: Some(vec![account!(2)]),
+            bridging_fee: Some(joy!(100)),
+            thawn_duration: None,
+            remote_chains: Some(remote_chains),
+            attester_accounts: None,
+            attestation_threshold: None,
+            attestation_ttl: None,
+            rate_limits: None,
+            remote_chain_fees: None,
+            fee_distribution: Some(FeeDistribution {
+                council_budget_share: Permill::from_percent(20),
+                operator_reward_share: Permill::from_percent(50),
+            }),
+            operator_reward_account: Some(reward_account),
+            creator_token_mint_allowances: None,
+        };
+        assert_ok!(ArgoBridge::update_bridge_constrains(
+            RuntimeOrigin::root(),
+            parameters
+        ));
+        assert_ok!(activate_bridge(account!(2), account!(1)));
+
+        let total_issuance = Balances::total_issuance();
+        request_outbound_transfer(account!(3), joy!(1000), joy!(100));
+
+        assert_eq!(
+            <CouncilBudgetManager as common::council::CouncilBudgetManager<u64, u64>>::get_budget(),
+            joy!(20)
+        );
+        assert_eq!(Balances::free_balance(reward_account), joy!(50));
+        // transferred amount and the remaining 30% of the fee are burned
+        assert_eq!(
+            Balances::total_issuance(),
+            total_issuance + ExistentialDeposit::get() + joy!(20) + joy!(50)
+        );
+    });
+}
+
+#[test]
+fn request_outbound_transfer_burns_operator_share_without_reward_account() {
+    with_test_externalities(|| {
+        let remote_chains = BoundedVec::try_from(vec![1u32]).unwrap();
+        let parameters = BridgeConstraints {
+            operator_account: Some(account!(1)),
+            pauser_accounts: Some(vec![account!(2)]),
+            bridging_fee: Some(joy!(100)),
+            thawn_duration: None,
+            remote_chains: Some(remote_chains),
+            attester_accounts: None,
+            attestation_threshold: None,
+            attestation_ttl: None,
+            rate_limits: None,
+            remote_chain_fees: None,
+            fee_distribution: Some(FeeDistribution {
+                council_budget_share: Permill::from_percent(20),
+                operator_reward_share: Permill::from_percent(50),
+            }),
+            operator_reward_account: None,
+            creator_token_mint_allowances: None,
+        };
+        assert_ok!(ArgoBridge::update_bridge_constrains(
+            RuntimeOrigin::root(),
+            parameters
+        ));
+        assert_ok!(activate_bridge(account!(2), account!(1)));
+
+        let total_issuance = Balances::total_issuance();
+        request_outbound_transfer(account!(3), joy!(1000), joy!(100));
+
+        assert_eq!(
+            Balances::total_issuance(),
+            total_issuance + ExistentialDeposit::get() + joy!(20)
+        );
+    });
+}
+
+#[test]
+fn update_bridge_constrains_with_invalid_fee_distribution() {
+    with_test_externalities(|| {
+        let parameters = BridgeConstraints {
+            operator_account: None,
+            pauser_accounts: None,
+            bridging_fee: None,
+            thawn_duration: None,
+            remote_chains: None,
+            attester_accounts: None,
+            attestation_threshold: None,
+            attestation_ttl: None,
+            rate_limits: None,
+            remote_chain_fees: None,
+            fee_distribution: Some(FeeDistribution {
+                council_budget_share: Permill::from_percent(60),
+                operator_reward_share: Permill::from_percent(50),
+            }),
+            operator_reward_account: None,
+            creator_token_mint_allowances: None,
+        };
+        let result = ArgoBridge::update_bridge_constrains(RuntimeOrigin::root(), parameters);
+        assert_err!(result, Error::<Test>::InvalidFeeDistribution);
+    });
+}
+
+pub fn activate_bridge(pauser_account_id: u64, operator_account_id: u64) -> DispatchResult {
+    let pauser_origin = RuntimeOrigin::signed(pauser_account_id);
+    ArgoBridge::init_unpause_bridge(pauser_origin)?;
+    System::set_block_number(3u32.into());
+    ArgoBridge::finish_unpause_bridge(RuntimeOrigin::signed(operator_account_id))?;
+    Ok(())
+}
+
+pub fn request_outbound_transfer(sender: u64, amount: Balance, fee: Balance) -> TransferId {
+    Balances::set_balance(
+        RuntimeOrigin::root(),
+        sender,
+        amount + fee + ExistentialDeposit::get(),
+        joy!(0),
+    )
+    .unwrap();
+    let transfer_id = ArgoBridge::next_transfer_id();
+    ArgoBridge::request_outbound_transfer(
+        RuntimeOrigin::signed(sender),
+        RemoteAccount {
+            account: [0; 32],
+            chain_id: 1,
+        },
+        BridgedAsset::Joy,
+        amount,
+        fee,
+    )
+    .unwrap();
+    transfer_id
+}
+
+pub fn issue_creator_token(member_id: MemberId, amount: u64) -> TokenId {
+    let mut initial_allocation = BTreeMap::new();
+    initial_allocation.insert(
+        member_id,
+        TokenAllocation {
+            amount,
+            vesting_schedule_params: None,
+        },
+    );
+    let token_id = Token::issue_token(
+        account!(1),
+        TokenIssuanceParametersOf::<Test> {
+            initial_allocation,
+            ..Default::default()
+        },
+        UploadContextOf::<Test> {
+            uploader_account: account!(1),
+            bag_id: Default::default(),
+        },
+    )
+    .unwrap();
+    assert_ok!(Token::set_bridging_status(token_id, true));
+    token_id
+}
+
+pub fn request_creator_token_outbound_transfer(
+    member_id: MemberId,
+    token_id: TokenId,
+    amount: Balance,
+    fee: Balance,
+) -> TransferId {
+    let sender = member!(member_id).1;
+    Balances::set_balance(
+        RuntimeOrigin::root(),
+        sender,
+        fee + ExistentialDeposit::get(),
+        joy!(0),
+    )
+    .unwrap();
+    let transfer_id = ArgoBridge::next_transfer_id();
+    ArgoBridge::request_outbound_transfer(
+        RuntimeOrigin::signed(sender),
+        RemoteAccount {
+            account: [0; 32],
+            chain_id: 1,
+        },
+        BridgedAsset::CreatorToken(token_id, member_id),
+        amount,
+        fee,
+    )
+    .unwrap();
+    transfer_id
 }
@@ -3,12 +3,17 @@ use frame_support::{storage::bounded_vec::BoundedVec, traits::ConstU32};
 use scale_info::TypeInfo;
 #[cfg(feature = "std")]
 use serde::{Deserialize, Serialize};
+use sp_runtime::traits::{AtLeast32BitUnsigned, CheckedAdd, Saturating, UniqueSaturatedInto, Zero};
+use sp_runtime::{Perbill, Permill};
 
 use sp_std::vec::Vec;
 
 // Balance type alias
 pub type BalanceOf<T> = <T as balances::Config>::Balance;
 
+// Creator token balance type alias
+pub type TokenBalanceOf<T> = <T as project_token::Config>::Balance;
+
 pub type ChainId = u32;
 pub type TransferId = u64;
 
@@ -16,22 +21,78 @@ pub const MAX_REMOTE_CHAINS: u32 = 10;
 
 pub const MAX_BYTES_RATIONALE: u32 = 200;
 
+pub const MAX_PRUNED_INBOUND_TRANSFERS: u32 = 100;
+
+/// Default number of blocks after which a pending attestation is considered stale
+pub const DEFAULT_ATTESTATION_TTL: u32 = 600;
+
+// Max number of rate limits set at once: one global and one per remote chain limit
+// for each transfer direction of an asset, 2 * (MAX_REMOTE_CHAINS + 1)
+pub const MAX_RATE_LIMITS: u32 = 22;
+
+// Max number of creator token mint allowances set at once
+pub const MAX_CREATOR_TOKEN_MINT_ALLOWANCES: u32 = 10;
+
 #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 #[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
-pub struct BridgeConstraints<AccountId, Balance, BlockNumber> {
+pub struct BridgeConstraints<AccountId, Balance, BlockNumber, TokenId> {
     pub operator_account: Option<AccountId>,
     pub pauser_accounts: Option<Vec<AccountId>>,
     pub bridging_fee: Option<Balance>,
     pub thawn_duration: Option<BlockNumber>,
     pub remote_chains: Option<BoundedVec<ChainId, ConstU32<MAX_REMOTE_CHAINS>>>,
+    pub attester_accounts: Option<Vec<AccountId>>,
+    pub attestation_threshold: Option<u32>,
+    pub attestation_ttl: Option<BlockNumber>,
+    pub rate_limits: Option<
+        BoundedVec<
+            (RateLimitKey<TokenId>, RateLimit<Balance, BlockNumber>),
+            ConstU32<MAX_RATE_LIMITS>,
+        >,
+    >,
+    pub remote_chain_fees:
+        Option<BoundedVec<(ChainId, Option<FeeSchedule<Balance>>), ConstU32<MAX_REMOTE_CHAINS>>>,
+    pub fee_distribution: Option<FeeDistribution>,
+    pub operator_reward_account: Option<AccountId>,
+    pub creator_token_mint_allowances:
+        Option<BoundedVec<(TokenId, Balance), ConstU32<MAX_CREATOR_TOKEN_MINT_ALLOWANCES>>>,
 }
 
 pub type BridgeConstraintsOf<T> = BridgeConstraints<
     <T as frame_system::Config>::AccountId,
     BalanceOf<T>,
     <T as frame_system::Config>::BlockNumber,
+    <T as project_token::Config>::TokenId,
 >;
 
+/// Fee charged for the outbound transfers to a remote chain: a flat part plus
+/// a percentage of the transferred JOY amount
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Default, Copy, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
+pub struct FeeSchedule<Balance> {
+    pub flat_fee: Balance,
+    pub percentage_fee: Permill,
+}
+
+pub type FeeScheduleOf<T> = FeeSchedule<BalanceOf<T>>;
+
+/// Split of the collected bridging fees, the remainder is burned
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Default, Copy, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
+pub struct FeeDistribution {
+    pub council_budget_share: Permill,
+    pub operator_reward_share: Permill,
+}
+
+impl FeeDistribution {
+    pub fn is_valid(&self) -> bool {
+        self.council_budget_share
+            .deconstruct()
+            .saturating_add(self.operator_reward_share.deconstruct())
+            <= Permill::one().deconstruct()
+    }
+}
+
 #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 #[derive(Encode, Decode, Copy, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
 pub struct RemoteAccount {
@@ -48,6 +109,155 @@ pub struct RemoteTransfer {
     pub chain_id: ChainId,
 }
 
+/// Asset moved by a bridge transfer
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Copy, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
+pub enum BridgedAsset<TokenId, MemberId> {
+    // Native JOY, burned from / minted to the transfer account
+    Joy,
+    // Creator token, burned from / minted to the token account of the member
+    CreatorToken(TokenId, MemberId),
+}
+
+pub type BridgedAssetOf<T> = BridgedAsset<
+    <T as project_token::Config>::TokenId,
+    <T as common::membership::MembershipTypes>::MemberId,
+>;
+
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Copy, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
+pub enum TransferDirection {
+    // Tokens minted by the bridge
+    Inbound,
+    // Tokens burned by the bridge
+    Outbound,
+}
+
+/// Scope of a rate limit: the transfer direction, either a single remote chain
+/// or all the remote chains (`chain_id: None`) and either a creator token or JOY
+/// (`token_id: None`), the limit is denominated in the bridged asset
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Copy, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
+pub struct RateLimitKey<TokenId> {
+    pub direction: TransferDirection,
+    pub chain_id: Option<ChainId>,
+    pub token_id: Option<TokenId>,
+}
+
+pub type RateLimitKeyOf<T> = RateLimitKey<<T as project_token::Config>::TokenId>;
+
+/// At most `max_amount` can be transferred at once, the transferred amount is released
+/// linearly over the `window` blocks (rolling window), a zero `window` disables the limit
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Default, Copy, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
+pub struct RateLimit<Balance, BlockNumber> {
+    pub max_amount: Balance,
+    pub window: BlockNumber,
+}
+
+impl<Balance, BlockNumber: Zero> RateLimit<Balance, BlockNumber> {
+    pub fn is_enabled(&self) -> bool {
+        !self.window.is_zero()
+    }
+}
+
+pub type RateLimitOf<T> = RateLimit<BalanceOf<T>, <T as frame_system::Config>::BlockNumber>;
+
+/// Amount transferred within the rolling window of a rate limit as of `updated_at`
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Default, Copy, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
+pub struct RateLimitUsage<Balance, BlockNumber> {
+    pub updated_at: BlockNumber,
+    pub used_amount: Balance,
+}
+
+impl<Balance, BlockNumber> RateLimitUsage<Balance, BlockNumber>
+where
+    Balance: AtLeast32BitUnsigned + Copy,
+    BlockNumber: AtLeast32BitUnsigned + Copy,
+{
+    /// Amount still used at `now`, `max_amount / window` is released per block
+    pub fn used_amount_at(
+        &self,
+        limit: &RateLimit<Balance, BlockNumber>,
+        now: BlockNumber,
+    ) -> Balance {
+        let elapsed = now.saturating_sub(self.updated_at);
+        if elapsed >= limit.window {
+            return Balance::zero();
+        }
+        let released = Perbill::from_rational(
+            UniqueSaturatedInto::<u64>::unique_saturated_into(elapsed),
+            UniqueSaturatedInto::<u64>::unique_saturated_into(limit.window),
+        ) * limit.max_amount;
+        self.used_amount.saturating_sub(released)
+    }
+
+    /// Usage after transferring `amount` at `now`, `None` if the limit would be exceeded
+    pub fn with_transfer(
+        &self,
+        limit: &RateLimit<Balance, BlockNumber>,
+        amount: Balance,
+        now: BlockNumber,
+    ) -> Option<Self> {
+        let used_amount = self.used_amount_at(limit, now).checked_add(&amount)?;
+        if used_amount > limit.max_amount {
+            return None;
+        }
+        Some(Self {
+            updated_at: now,
+            used_amount,
+        })
+    }
+}
+
+pub type RateLimitUsageOf<T> =
+    RateLimitUsage<BalanceOf<T>, <T as frame_system::Config>::BlockNumber>;
+
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
+pub struct InboundTransferAttestation<AccountId, Balance, BlockNumber, BridgedAsset> {
+    pub dest_account: AccountId,
+    pub asset: BridgedAsset,
+    pub amount: Balance,
+    pub attested_at: BlockNumber,
+}
+
+pub type InboundTransferAttestationOf<T> = InboundTransferAttestation<
+    <T as frame_system::Config>::AccountId,
+    BalanceOf<T>,
+    <T as frame_system::Config>::BlockNumber,
+    BridgedAssetOf<T>,
+>;
+
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Copy, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
+pub enum OutboundTransferStatus {
+    Pending,
+    Completed,
+    Reverted,
+}
+
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
+pub struct OutboundTransfer<AccountId, Balance, BridgedAsset> {
+    pub sender: AccountId,
+    pub dest_account: RemoteAccount,
+    pub asset: BridgedAsset,
+    pub amount: Balance,
+    pub fee: Balance,
+    pub status: OutboundTransferStatus,
+}
+
+pub type OutboundTransferOf<T> =
+    OutboundTransfer<<T as frame_system::Config>::AccountId, BalanceOf<T>, BridgedAssetOf<T>>;
+
+impl<AccountId, Balance, BridgedAsset> OutboundTransfer<AccountId, Balance, BridgedAsset> {
+    pub fn is_pending(&self) -> bool {
+        self.status == OutboundTransferStatus::Pending
+    }
+}
+
 #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 #[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
 pub enum BridgeStatus<BlockNumber> {
@@ -20,6 +20,9 @@
 //! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 4.0.0-dev
 //! DATE: 2024-06-06, STEPS: `50`, REPEAT: 20, LOW RANGE: `[]`, HIGH RANGE: `[]`
 //! EXECUTION: Some(Wasm), WASM-EXECUTION: Compiled, CHAIN: Some("prod-test"), DB CACHE: 1024
+//!
+//! NOTE: the weight functions marked as PLACEHOLDER are hand-written estimates, not the
+//! benchmark output. They must be regenerated with the benchmark CLI before merging.
 
 // Executed Command:
 // ./scripts/../target/release/joystream-node
@@ -46,75 +49,129 @@ use sp_std::marker::PhantomData;
 pub trait WeightInfo {
 	fn request_outbound_transfer() -> Weight;
 	fn revert_outbound_transfer() -> Weight;
-	fn finalize_inbound_transfer() -> Weight;
+	fn finalize_inbound_transfer(a: u32, ) -> Weight;
+	fn prune_inbound_transfer_attestations(a: u32, ) -> Weight;
 	fn pause_bridge() -> Weight;
 	fn init_unpause_bridge() -> Weight;
 	fn finish_unpause_bridge() -> Weight;
 	fn update_bridge_constrains() -> Weight;
+	fn prune_processed_inbound_transfers() -> Weight;
+	fn complete_outbound_transfer() -> Weight;
+	fn prune_outbound_transfer() -> Weight;
+	fn claim_creator_token_refund() -> Weight;
 }
 
 /// Weights for argo_bridge using the Substrate node and recommended hardware.
 pub struct SubstrateWeight<T>(PhantomData<T>);
 impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	// PLACEHOLDER: hand-written estimate, not the benchmark output.
+	// Must be regenerated with the benchmark CLI before merging.
 	// Storage: ArgoBridge Status (r:1 w:0)
 	// Proof: ArgoBridge Status (max_values: Some(1), max_size: Some(5), added: 500, mode: MaxEncodedLen)
 	// Storage: ArgoBridge RemoteChains (r:1 w:0)
 	// Proof: ArgoBridge RemoteChains (max_values: Some(1), max_size: Some(41), added: 536, mode: MaxEncodedLen)
-	// Storage: ArgoBridge BridgingFee (r:1 w:0)
-	// Proof: ArgoBridge BridgingFee (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
-	// Storage: System Account (r:1 w:1)
+	// Storage: ArgoBridge RemoteChainFees (r:1 w:0)
+	// Proof: ArgoBridge RemoteChainFees (max_values: None, max_size: Some(40), added: 2515, mode: MaxEncodedLen)
+	// Storage: System Account (r:2 w:2)
 	// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
 	// Storage: ArgoBridge NextTransferId (r:1 w:1)
 	// Proof: ArgoBridge NextTransferId (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
 	// Storage: ArgoBridge MintAllowance (r:1 w:1)
 	// Proof: ArgoBridge MintAllowance (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	// Storage: ArgoBridge RateLimits (r:2 w:0)
+	// Proof: ArgoBridge RateLimits (max_values: None, max_size: Some(67), added: 2542, mode: MaxEncodedLen)
+	// Storage: ArgoBridge RateLimitsUsage (r:2 w:2)
+	// Proof: ArgoBridge RateLimitsUsage (max_values: None, max_size: Some(51), added: 2526, mode: MaxEncodedLen)
+	// Storage: ArgoBridge BridgingFeeDistribution (r:1 w:0)
+	// Proof: ArgoBridge BridgingFeeDistribution (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	// Storage: ArgoBridge OperatorRewardAccount (r:1 w:0)
+	// Proof: ArgoBridge OperatorRewardAccount (max_values: Some(1), max_size: Some(32), added: 527, mode: MaxEncodedLen)
+	// Storage: Council Budget (r:1 w:1)
+	// Proof: Council Budget (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	// Storage: ArgoBridge OutboundTransfers (r:0 w:1)
+	// Proof: ArgoBridge OutboundTransfers (max_values: None, max_size: Some(137), added: 2612, mode: MaxEncodedLen)
 	fn request_outbound_transfer() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `430`
-		//  Estimated: `11104`
-		// Minimum execution time: 44_006 nanoseconds.
-		Weight::from_parts(45_771_000, 0u64)
-			.saturating_add(Weight::from_parts(0, 11104))
-			.saturating_add(T::DbWeight::get().reads(6_u64))
-			.saturating_add(T::DbWeight::get().writes(3_u64))
+		Weight::from_parts(68_935_000, 0u64)
+			.saturating_add(Weight::from_parts(0, 27383))
+			.saturating_add(T::DbWeight::get().reads(14_u64))
+			.saturating_add(T::DbWeight::get().writes(8_u64))
 	}
+	// PLACEHOLDER: hand-written estimate, not the benchmark output.
+	// Must be regenerated with the benchmark CLI before merging.
 	// Storage: ArgoBridge Status (r:1 w:0)
 	// Proof: ArgoBridge Status (max_values: Some(1), max_size: Some(5), added: 500, mode: MaxEncodedLen)
 	// Storage: ArgoBridge OperatorAccount (r:1 w:0)
 	// Proof: ArgoBridge OperatorAccount (max_values: Some(1), max_size: Some(32), added: 527, mode: MaxEncodedLen)
+	// Storage: ArgoBridge OutboundTransfers (r:1 w:1)
+	// Proof: ArgoBridge OutboundTransfers (max_values: None, max_size: Some(137), added: 2612, mode: MaxEncodedLen)
 	// Storage: ArgoBridge MintAllowance (r:1 w:1)
 	// Proof: ArgoBridge MintAllowance (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
-	// Storage: System Account (r:1 w:0)
+	// Storage: System Account (r:1 w:1)
 	// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	// Storage: ArgoBridge PendingCreatorTokenRefunds (r:1 w:1)
+	// Proof: ArgoBridge PendingCreatorTokenRefunds (max_values: None, max_size: Some(64), added: 2539, mode: MaxEncodedLen)
 	fn revert_outbound_transfer() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `321`
-		//  Estimated: `8101`
-		// Minimum execution time: 26_913 nanoseconds.
-		Weight::from_parts(28_236_000, 0u64)
-			.saturating_add(Weight::from_parts(0, 8101))
-			.saturating_add(T::DbWeight::get().reads(4_u64))
-			.saturating_add(T::DbWeight::get().writes(1_u64))
+		Weight::from_parts(45_602_000, 0u64)
+			.saturating_add(Weight::from_parts(0, 13252))
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
 	}
+	// PLACEHOLDER: hand-written estimate, not the benchmark output.
+	// Must be regenerated with the benchmark CLI before merging.
 	// Storage: ArgoBridge Status (r:1 w:0)
 	// Proof: ArgoBridge Status (max_values: Some(1), max_size: Some(5), added: 500, mode: MaxEncodedLen)
-	// Storage: ArgoBridge OperatorAccount (r:1 w:0)
-	// Proof: ArgoBridge OperatorAccount (max_values: Some(1), max_size: Some(32), added: 527, mode: MaxEncodedLen)
+	// Storage: ArgoBridge AttesterAccounts (r:1 w:0)
+	// Proof: ArgoBridge AttesterAccounts (max_values: Some(1), max_size: Some(321), added: 816, mode: MaxEncodedLen)
 	// Storage: ArgoBridge RemoteChains (r:1 w:0)
 	// Proof: ArgoBridge RemoteChains (max_values: Some(1), max_size: Some(41), added: 536, mode: MaxEncodedLen)
+	// Storage: ArgoBridge ProcessedInboundTransfersFloor (r:1 w:0)
+	// Proof: ArgoBridge ProcessedInboundTransfersFloor (max_values: None, max_size: Some(28), added: 2503, mode: MaxEncodedLen)
+	// Storage: ArgoBridge ProcessedInboundTransfers (r:1 w:1)
+	// Proof: ArgoBridge ProcessedInboundTransfers (max_values: None, max_size: Some(60), added: 2535, mode: MaxEncodedLen)
+	// Storage: ArgoBridge InboundTransferAttestations (r:10 w:9)
+	// Proof: ArgoBridge InboundTransferAttestations (max_values: None, max_size: Some(120), added: 2595, mode: MaxEncodedLen)
+	// Storage: ArgoBridge AttestationTtl (r:1 w:0)
+	// Proof: ArgoBridge AttestationTtl (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	// Storage: ArgoBridge AttestationThreshold (r:1 w:0)
+	// Proof: ArgoBridge AttestationThreshold (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
 	// Storage: ArgoBridge MintAllowance (r:1 w:1)
 	// Proof: ArgoBridge MintAllowance (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	// Storage: ArgoBridge RateLimits (r:2 w:0)
+	// Proof: ArgoBridge RateLimits (max_values: None, max_size: Some(67), added: 2542, mode: MaxEncodedLen)
+	// Storage: ArgoBridge RateLimitsUsage (r:2 w:2)
+	// Proof: ArgoBridge RateLimitsUsage (max_values: None, max_size: Some(51), added: 2526, mode: MaxEncodedLen)
 	// Storage: System Account (r:1 w:1)
 	// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
-	fn finalize_inbound_transfer() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `473`
-		//  Estimated: `9627`
-		// Minimum execution time: 37_314 nanoseconds.
-		Weight::from_parts(38_704_000, 0u64)
-			.saturating_add(Weight::from_parts(0, 9627))
-			.saturating_add(T::DbWeight::get().reads(5_u64))
-			.saturating_add(T::DbWeight::get().writes(2_u64))
+	/// The range of component `a` is `[1, 10]`.
+	fn finalize_inbound_transfer(a: u32, ) -> Weight {
+		Weight::from_parts(96_884_000, 0u64)
+			.saturating_add(Weight::from_parts(0, 28394))
+			.saturating_add(Weight::from_parts(3_632_000, 0u64).saturating_mul(a.into()))
+			.saturating_add(T::DbWeight::get().reads(13_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(a.into())))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(a.into())))
+			.saturating_add(Weight::from_parts(0, 2595).saturating_mul(a.into()))
+	}
+	// PLACEHOLDER: hand-written estimate, not the benchmark output.
+	// Must be regenerated with the benchmark CLI before merging.
+	// Storage: ArgoBridge ProcessedInboundTransfers (r:1 w:0)
+	// Proof: ArgoBridge ProcessedInboundTransfers (max_values: None, max_size: Some(60), added: 2535, mode: MaxEncodedLen)
+	// Storage: ArgoBridge AttesterAccounts (r:1 w:0)
+	// Proof: ArgoBridge AttesterAccounts (max_values: Some(1), max_size: Some(321), added: 816, mode: MaxEncodedLen)
+	// Storage: ArgoBridge InboundTransferAttestations (r:10 w:10)
+	// Proof: ArgoBridge InboundTransferAttestations (max_values: None, max_size: Some(120), added: 2595, mode: MaxEncodedLen)
+	// Storage: ArgoBridge AttestationTtl (r:1 w:0)
+	// Proof: ArgoBridge AttestationTtl (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// The range of component `a` is `[1, 10]`.
+	fn prune_inbound_transfer_attestations(a: u32, ) -> Weight {
+		Weight::from_parts(25_917_000, 0u64)
+			.saturating_add(Weight::from_parts(0, 5820))
+			.saturating_add(Weight::from_parts(3_417_000, 0u64).saturating_mul(a.into()))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(a.into())))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(a.into())))
+			.saturating_add(Weight::from_parts(0, 2595).saturating_mul(a.into()))
 	}
 	// Storage: ArgoBridge PauserAccounts (r:1 w:0)
 	// Proof: ArgoBridge PauserAccounts (max_values: Some(1), max_size: Some(321), added: 816, mode: MaxEncodedLen)
@@ -160,6 +217,18 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(2_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
+	// PLACEHOLDER: hand-written estimate, not the benchmark output.
+	// Must be regenerated with the benchmark CLI before merging.
+	// Storage: ArgoBridge RateLimits (r:0 w:22)
+	// Proof: ArgoBridge RateLimits (max_values: None, max_size: Some(67), added: 2542, mode: MaxEncodedLen)
+	// Storage: ArgoBridge RateLimitsUsage (r:0 w:22)
+	// Proof: ArgoBridge RateLimitsUsage (max_values: None, max_size: Some(51), added: 2526, mode: MaxEncodedLen)
+	// Storage: ArgoBridge AttestationThreshold (r:0 w:1)
+	// Proof: ArgoBridge AttestationThreshold (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	// Storage: ArgoBridge AttesterAccounts (r:0 w:1)
+	// Proof: ArgoBridge AttesterAccounts (max_values: Some(1), max_size: Some(321), added: 816, mode: MaxEncodedLen)
+	// Storage: ArgoBridge AttestationTtl (r:0 w:1)
+	// Proof: ArgoBridge AttestationTtl (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
 	// Storage: ArgoBridge RemoteChains (r:0 w:1)
 	// Proof: ArgoBridge RemoteChains (max_values: Some(1), max_size: Some(41), added: 536, mode: MaxEncodedLen)
 	// Storage: ArgoBridge PauserAccounts (r:0 w:1)
@@ -170,14 +239,77 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	// Proof: ArgoBridge BridgingFee (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
 	// Storage: ArgoBridge ThawnDuration (r:0 w:1)
 	// Proof: ArgoBridge ThawnDuration (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	// Storage: ArgoBridge RemoteChainFees (r:0 w:10)
+	// Proof: ArgoBridge RemoteChainFees (max_values: None, max_size: Some(40), added: 2515, mode: MaxEncodedLen)
+	// Storage: ArgoBridge BridgingFeeDistribution (r:0 w:1)
+	// Proof: ArgoBridge BridgingFeeDistribution (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	// Storage: ArgoBridge OperatorRewardAccount (r:0 w:1)
+	// Proof: ArgoBridge OperatorRewardAccount (max_values: Some(1), max_size: Some(32), added: 527, mode: MaxEncodedLen)
+	// Storage: Token TokenInfoById (r:10 w:0)
+	// Proof: Token TokenInfoById (max_values: None, max_size: Some(352), added: 2827, mode: MaxEncodedLen)
+	// Storage: ArgoBridge CreatorTokenMintAllowance (r:0 w:10)
+	// Proof: ArgoBridge CreatorTokenMintAllowance (max_values: None, max_size: Some(40), added: 2515, mode: MaxEncodedLen)
 	fn update_bridge_constrains() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `0`
-		//  Estimated: `0`
-		// Minimum execution time: 17_157 nanoseconds.
-		Weight::from_parts(17_598_000, 0u64)
-			.saturating_add(Weight::from_parts(0, 0))
-			.saturating_add(T::DbWeight::get().writes(5_u64))
+		Weight::from_parts(155_247_000, 0u64)
+			.saturating_add(Weight::from_parts(0, 28270))
+			.saturating_add(T::DbWeight::get().reads(10_u64))
+			.saturating_add(T::DbWeight::get().writes(74_u64))
+	}
+	// PLACEHOLDER: hand-written estimate, not the benchmark output.
+	// Must be regenerated with the benchmark CLI before merging.
+	// Storage: ArgoBridge OperatorAccount (r:1 w:0)
+	// Proof: ArgoBridge OperatorAccount (max_values: Some(1), max_size: Some(32), added: 527, mode: MaxEncodedLen)
+	// Storage: ArgoBridge ProcessedInboundTransfersFloor (r:1 w:1)
+	// Proof: ArgoBridge ProcessedInboundTransfersFloor (max_values: None, max_size: Some(28), added: 2503, mode: MaxEncodedLen)
+	// Storage: ArgoBridge ProcessedInboundTransfers (r:100 w:100)
+	// Proof: ArgoBridge ProcessedInboundTransfers (max_values: None, max_size: Some(60), added: 2535, mode: MaxEncodedLen)
+	fn prune_processed_inbound_transfers() -> Weight {
+		Weight::from_parts(421_873_000, 0u64)
+			.saturating_add(Weight::from_parts(0, 258530))
+			.saturating_add(T::DbWeight::get().reads(102_u64))
+			.saturating_add(T::DbWeight::get().writes(101_u64))
+	}
+	// PLACEHOLDER: hand-written estimate, not the benchmark output.
+	// Must be regenerated with the benchmark CLI before merging.
+	// Storage: ArgoBridge OperatorAccount (r:1 w:0)
+	// Proof: ArgoBridge OperatorAccount (max_values: Some(1), max_size: Some(32), added: 527, mode: MaxEncodedLen)
+	// Storage: ArgoBridge OutboundTransfers (r:1 w:1)
+	// Proof: ArgoBridge OutboundTransfers (max_values: None, max_size: Some(137), added: 2612, mode: MaxEncodedLen)
+	fn complete_outbound_transfer() -> Weight {
+		Weight::from_parts(22_115_000, 0u64)
+			.saturating_add(Weight::from_parts(0, 5139))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	// PLACEHOLDER: hand-written estimate, not the benchmark output.
+	// Must be regenerated with the benchmark CLI before merging.
+	// Storage: ArgoBridge OperatorAccount (r:1 w:0)
+	// Proof: ArgoBridge OperatorAccount (max_values: Some(1), max_size: Some(32), added: 527, mode: MaxEncodedLen)
+	// Storage: ArgoBridge OutboundTransfers (r:1 w:1)
+	// Proof: ArgoBridge OutboundTransfers (max_values: None, max_size: Some(137), added: 2612, mode: MaxEncodedLen)
+	fn prune_outbound_transfer() -> Weight {
+		Weight::from_parts(21_390_000, 0u64)
+			.saturating_add(Weight::from_parts(0, 5139))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	// PLACEHOLDER: hand-written estimate, not the benchmark output.
+	// Must be regenerated with the benchmark CLI before merging.
+	// Storage: Membership MembershipById (r:1 w:0)
+	// Proof: Membership MembershipById (max_values: None, max_size: Some(125), added: 2600, mode: MaxEncodedLen)
+	// Storage: ArgoBridge PendingCreatorTokenRefunds (r:1 w:1)
+	// Proof: ArgoBridge PendingCreatorTokenRefunds (max_values: None, max_size: Some(64), added: 2539, mode: MaxEncodedLen)
+	// Storage: Token PalletFrozen (r:1 w:0)
+	// Proof: Token PalletFrozen (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	// Storage: Token TokenInfoById (r:1 w:1)
+	// Proof: Token TokenInfoById (max_values: None, max_size: Some(352), added: 2827, mode: MaxEncodedLen)
+	// Storage: Token AccountInfoByTokenAndMember (r:1 w:1)
+	// Proof: Token AccountInfoByTokenAndMember (max_values: None, max_size: Some(489), added: 2964, mode: MaxEncodedLen)
+	fn claim_creator_token_refund() -> Weight {
+		Weight::from_parts(42_000_000, 0u64)
+			.saturating_add(Weight::from_parts(0, 11426))
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
 	}
 }
 
@@ -189,7 +321,10 @@ impl WeightInfo for () {
 	fn revert_outbound_transfer() -> Weight {
 		Weight::from_parts(0, 0)
 	}
-	fn finalize_inbound_transfer() -> Weight {
+	fn finalize_inbound_transfer(a: u32, ) -> Weight {
+		Weight::from_parts(0, 0)
+	}
+	fn prune_inbound_transfer_attestations(a: u32, ) -> Weight {
 		Weight::from_parts(0, 0)
 	}
 	fn pause_bridge() -> Weight {
@@ -204,4 +339,16 @@ impl WeightInfo for () {
 	fn update_bridge_constrains() -> Weight {
 		Weight::from_parts(0, 0)
 	}
+	fn prune_processed_inbound_transfers() -> Weight {
+		Weight::from_parts(0, 0)
+	}
+	fn complete_outbound_transfer() -> Weight {
+		Weight::from_parts(0, 0)
+	}
+	fn prune_outbound_transfer() -> Weight {
+		Weight::from_parts(0, 0)
+	}
+	fn claim_creator_token_refund() -> Weight {
+		Weight::from_parts(0, 0)
+	}
 }
@@ -2,8 +2,8 @@
 
 use crate::{
     AssuranceContractType, BalanceOf, Bounties, BountyActor, BountyCreationParameters,
-    BountyMilestone, Call, Config, Entries, Event, FundingType, Module as Bounty,
-    OracleWorkEntryJudgment, Pallet,
+    BountyMilestone, Call, Config, DeliveryMilestone, Entries, Entry, EscrowedJudgments, Event,
+    FundingType, JudgmentDisputes, Module as Bounty, OraclePanel, OracleWorkEntryJudgment, Pallet,
 };
 use balances::Pallet as Balances;
 use common::council::CouncilBudgetManager;
@@ -92,7 +92,7 @@ fn handle_from_id<T: membership::Config>(id: u128) -> Vec<u8> {
 }
 
 //defines initial balance
-fn initial_balance<T: Config + membership::Config>() -> T::Balance {
+fn initial_balance<T: Config + membership::Config>() -> BalanceOf<T> {
     T::DefaultMembershipPrice::get()
         + T::CandidateStake::get()
         + T::FunderStateBloatBondAmount::get()
@@ -211,6 +211,140 @@ fn create_funded_bounty<T: Config>(params: BountyCreationParameters<T>) -> T::Bo
     bounty_id
 }
 
+// Max number of delivery milestones with equal funding shares (the last one gets the rest).
+fn max_delivery_milestones<T: Config>() -> Vec<DeliveryMilestone<T::BlockNumber>> {
+    let milestones_number = T::MaxDeliveryMilestones::get();
+    let funding_share_parts = Perbill::one().deconstruct() / milestones_number;
+
+    (1..=milestones_number)
+        .map(|index| {
+            let funding_share = if index == milestones_number {
+                Perbill::from_parts(
+                    Perbill::one().deconstruct() - funding_share_parts * (milestones_number - 1),
+                )
+            } else {
+                Perbill::from_parts(funding_share_parts)
+            };
+
+            DeliveryMilestone {
+                funding_share,
+                work_period: One::one(),
+                judging_period: One::one(),
+            }
+        })
+        .collect()
+}
+
+// Creates funded members for the oracle panel.
+fn oracle_panel_members<T>(start_id: u128, size: u32) -> Vec<(T::AccountId, T::MemberId)>
+where
+    T: Config + membership::Config,
+    T::AccountId: CreateAccountId,
+{
+    (start_id..start_id + u128::from(size))
+        .map(|id| member_funded_account::<T>(id))
+        .collect()
+}
+
+// Creates a bounty with an appeal period funded by the council and a contributor and submits
+// the council judgment for `entries` work entries (the first one wins, the rest are rejected).
+fn submit_escrowed_judgment<T>(
+    contributor_id: u128,
+    entries: u32,
+) -> (
+    T::BountyId,
+    (T::AccountId, T::MemberId),
+    Vec<(T::EntryId, Entry<T>)>,
+)
+where
+    T: Config + membership::Config,
+    T::AccountId: CreateAccountId,
+{
+    let cherry: BalanceOf<T> = 100u32.into();
+    let oracle_reward: BalanceOf<T> = 100u32.into();
+    let funding_amount: BalanceOf<T> = 100u32.into();
+    let contribution: BalanceOf<T> = 50u32.into();
+
+    T::CouncilBudgetManager::set_budget(
+        cherry
+            + oracle_reward
+            + funding_amount
+            + T::FunderStateBloatBondAmount::get()
+            + T::CreatorStateBloatBondAmount::get(),
+    );
+
+    let params = BountyCreationParameters::<T> {
+        creator: BountyActor::Council,
+        cherry,
+        oracle_reward,
+        entrant_stake: T::MinWorkEntrantStake::get(),
+        funding_type: FundingType::Perpetual {
+            target: funding_amount,
+        },
+        oracle: BountyActor::Council,
+        appeal_period: One::one(),
+        ..Default::default()
+    };
+
+    Bounty::<T>::create_bounty(RawOrigin::Root.into(), params, Vec::new()).unwrap();
+
+    let bounty_id: T::BountyId = Bounty::<T>::bounty_count().into();
+
+    let (account_id, member_id) = member_funded_account::<T>(contributor_id);
+
+    Bounty::<T>::fund_bounty(
+        RawOrigin::Signed(account_id.clone()).into(),
+        BountyActor::Member(member_id),
+        bounty_id,
+        contribution,
+    )
+    .unwrap();
+
+    Bounty::<T>::fund_bounty(
+        RawOrigin::Root.into(),
+        BountyActor::Council,
+        bounty_id,
+        funding_amount - contribution,
+    )
+    .unwrap();
+
+    let entries = (0..entries)
+        .map(|id| {
+            let entry_id = announce_entry_and_submit_work::<T>(&bounty_id, id.into());
+
+            let entry = <Entries<T>>::get(bounty_id, entry_id).unwrap();
+
+            (entry_id, entry)
+        })
+        .collect::<Vec<_>>();
+
+    let judgment = entries
+        .iter()
+        .enumerate()
+        .map(|(index, (entry_id, _))| {
+            let work_entry_judgment = if index == 0 {
+                OracleWorkEntryJudgment::Winner {
+                    reward: funding_amount,
+                }
+            } else {
+                OracleWorkEntryJudgment::Rejected {
+                    slashing_share: Perbill::from_percent(50),
+                    action_justification: Vec::new(),
+                }
+            };
+
+            (*entry_id, work_entry_judgment)
+        })
+        .collect::<BTreeMap<_, _>>();
+
+    Bounty::<T>::end_working_period(RawOrigin::Root.into(), bounty_id).unwrap();
+
+    Bounty::<T>::submit_oracle_judgment(RawOrigin::Root.into(), bounty_id, judgment, Vec::new())
+        .unwrap();
+
+    (bounty_id, (account_id, member_id), entries)
+}
+
 const MAX_KILOBYTES_METADATA: u32 = 100;
 const SEED: u32 = 0;
 const _MAX_MEMBERS: u32 = 150; //Same as mocks
@@ -256,6 +390,7 @@ benchmarks! {
             entrant_stake,
             funding_type: FundingType::Perpetual{ target: max_amount },
             contract_type: AssuranceContractType::Closed(members),
+            delivery_milestones: max_delivery_milestones::<T>(),
             ..Default::default()
         };
 
@@ -300,6 +435,7 @@ benchmarks! {
             creator: BountyActor::Member(member_id),
             funding_type: FundingType::Perpetual{ target: max_amount },
             contract_type: AssuranceContractType::Closed(members),
+            delivery_milestones: max_delivery_milestones::<T>(),
             ..Default::default()
         };
 
@@ -1436,6 +1572,95 @@ benchmarks! {
                 new_oracle).into());
     }
 
+    submit_oracle_panel_judgment {
+        let p in 1 .. T::MaxOraclePanelSize::get();
+
+        let oracle_reward: BalanceOf<T> = 100u32.into();
+        let funding_amount: BalanceOf<T> = 100u32.into();
+        let panel_members = oracle_panel_members::<T>(1, p);
+
+        let params = BountyCreationParameters::<T> {
+            oracle_reward,
+            funding_type: FundingType::Perpetual{ target: funding_amount },
+            entrant_stake: T::MinWorkEntrantStake::get(),
+            oracle_panel: Some(OraclePanel {
+                members: panel_members.iter().map(|(_, member_id)| *member_id).collect(),
+                council: false,
+                threshold: p,
+                judging_period: One::one(),
+            }),
+            ..Default::default()
+        };
+
+        let bounty_id = create_funded_bounty::<T>(params);
+        let entry_id = announce_entry_and_submit_work::<T>(&bounty_id, 0);
+
+        Bounty::<T>::end_working_period(RawOrigin::Root.into(), bounty_id).unwrap();
+
+        let mut judgment = BTreeMap::new();
+        judgment.insert(entry_id, OracleWorkEntryJudgment::Winner { reward: funding_amount });
+        let rationale = b"rationale".to_vec();
+
+        // All panel members except the last one have already voted for the same judgment.
+        let (account_id, member_id) = panel_members[(p - 1) as usize].clone();
+        for (panel_account_id, panel_member_id) in panel_members[..(p - 1) as usize].iter() {
+            Bounty::<T>::submit_oracle_panel_judgment(
+                RawOrigin::Signed(panel_account_id.clone()).into(),
+                *panel_member_id,
+                bounty_id,
+                judgment.clone(),
+                rationale.clone(),
+            )
+            .unwrap();
+        }
+
+    }: _(RawOrigin::Signed(account_id), member_id, bounty_id, judgment.clone(), rationale.clone())
+    verify {
+        assert_was_fired::<T>(
+            Event::<T>::OraclePanelJudgmentSubmitted(
+                bounty_id, member_id, judgment, rationale).into()
+        );
+
+        for (_, panel_member_id) in panel_members {
+            assert!(Bounty::<T>::oracle_panel_judgments(bounty_id, panel_member_id).is_none());
+        }
+
+        assert!(!Bounty::<T>::bounties(bounty_id).has_unpaid_oracle_reward);
+    }
+
+    switch_oracle_panel_member {
+        let panel_size = T::MaxOraclePanelSize::get();
+        let panel_members = oracle_panel_members::<T>(1, panel_size);
+        let (_, new_member_id) = member_funded_account::<T>(u128::from(panel_size) + 1);
+        let (_, current_member_id) = panel_members[0];
+
+        let params = BountyCreationParameters::<T> {
+            oracle_panel: Some(OraclePanel {
+                members: panel_members.iter().map(|(_, member_id)| *member_id).collect(),
+                council: false,
+                threshold: panel_size,
+                judging_period: One::one(),
+            }),
+            ..Default::default()
+        };
+
+        let bounty_id = create_funded_bounty::<T>(params);
+
+    }: _(RawOrigin::Root, bounty_id, current_member_id, new_member_id)
+    verify {
+        let oracle_panel = Bounty::<T>::bounties(bounty_id).creation_params.oracle_panel.unwrap();
+        assert!(oracle_panel.members.contains(&new_member_id));
+        assert!(!oracle_panel.members.contains(&current_member_id));
+
+        assert_last_event::<T>(
+            Event::<T>::OraclePanelMemberSwitched(
+                bounty_id,
+                BountyActor::Council,
+                current_member_id,
+                new_member_id).into()
+        );
+    }
+
     end_working_period{
         let cherry: BalanceOf<T> = 100u32.into();
         let oracle_reward: BalanceOf<T> = 100u32.into();
@@ -1777,6 +2002,72 @@ benchmarks! {
         assert_last_event::<T>(Event::<T>::BountyRemoved(bounty_id).into());
     }
 
+    withdraw_oracle_panel_reward {
+        let p in 1 .. T::MaxOraclePanelSize::get() - 1;
+
+        let oracle_reward: BalanceOf<T> = 100u32.into();
+        let funding_amount: BalanceOf<T> = 100u32.into();
+        let panel_size = T::MaxOraclePanelSize::get();
+        let panel_members = oracle_panel_members::<T>(1, panel_size);
+
+        // The threshold is never reached so that the judging period expires.
+        let params = BountyCreationParameters::<T> {
+            oracle_reward,
+            funding_type: FundingType::Perpetual{ target: funding_amount },
+            entrant_stake: T::MinWorkEntrantStake::get(),
+            oracle_panel: Some(OraclePanel {
+                members: panel_members.iter().map(|(_, member_id)| *member_id).collect(),
+                council: false,
+                threshold: panel_size,
+                judging_period: One::one(),
+            }),
+            ..Default::default()
+        };
+
+        let bounty_id = create_funded_bounty::<T>(params);
+        let entry_id = announce_entry_and_submit_work::<T>(&bounty_id, 0);
+
+        Bounty::<T>::end_working_period(RawOrigin::Root.into(), bounty_id).unwrap();
+
+        let mut judgment = BTreeMap::new();
+        judgment.insert(entry_id, OracleWorkEntryJudgment::Winner { reward: funding_amount });
+
+        let participants = panel_members[..p as usize].to_vec();
+        for (account_id, member_id) in participants.iter() {
+            Bounty::<T>::submit_oracle_panel_judgment(
+                RawOrigin::Signed(account_id.clone()).into(),
+                *member_id,
+                bounty_id,
+                judgment.clone(),
+                Vec::new(),
+            )
+            .unwrap();
+        }
+
+        run_to_block::<T>(System::<T>::block_number() + 2u32.into());
+
+        let (account_id, member_id) = participants[0].clone();
+
+    }: _(RawOrigin::Signed(account_id), member_id, bounty_id)
+    verify {
+        assert!(!Bounty::<T>::bounties(bounty_id).has_unpaid_oracle_reward);
+
+        let reward_share = oracle_reward / panel_size.into();
+        for (_, member_id) in participants.into_iter() {
+            assert_was_fired::<T>(
+                Event::<T>::BountyOracleRewardWithdrawal(
+                    bounty_id,
+                    BountyActor::Member(member_id),
+                    reward_share).into());
+        }
+
+        // The shares of the members that didn't submit their judgments are withheld.
+        assert_was_fired::<T>(
+            Event::<T>::OraclePanelRewardWithheld(
+                bounty_id,
+                oracle_reward - reward_share * p.into()).into());
+    }
+
     withdraw_oracle_reward_by_oracle_member{
         let cherry: BalanceOf<T> = 100u32.into();
         let oracle_reward: BalanceOf<T> = 100u32.into();
@@ -1824,6 +2115,96 @@ benchmarks! {
         assert_last_event::<T>(Event::<T>::BountyRemoved(bounty_id).into());
     }
 
+    open_judgment_dispute {
+        let i in 0 .. MAX_KILOBYTES_METADATA;
+
+        let rationale = vec![0u8].repeat((i * 1000) as usize);
+
+        let (bounty_id, _, entries) = submit_escrowed_judgment::<T>(
+            (MAX_WORK_ENTRIES_WINNERS + MAX_WORK_ENTRIES_REJECTED).into(),
+            2,
+        );
+
+        // The rejected entrant disputes the judgment.
+        let (entry_id, entry) = entries[1].clone();
+        let stake = T::JudgmentDisputeStake::get();
+        let _ = Balances::<T>::deposit_creating(&entry.staking_account_id, stake);
+
+    }: _(
+        RawOrigin::Signed(entry.staking_account_id.clone()),
+        entry.member_id,
+        bounty_id,
+        Some(entry_id),
+        rationale.clone()
+    )
+    verify {
+        assert!(<JudgmentDisputes<T>>::contains_key(bounty_id));
+        assert_last_event::<T>(
+            Event::<T>::JudgmentDisputeOpened(
+                bounty_id, entry.member_id, stake, rationale).into()
+        );
+    }
+
+    overturn_oracle_judgment {
+        let (bounty_id, (account_id, member_id), _) = submit_escrowed_judgment::<T>(
+            (MAX_WORK_ENTRIES_WINNERS + MAX_WORK_ENTRIES_REJECTED).into(),
+            2,
+        );
+
+        let _ = Balances::<T>::deposit_creating(&account_id, T::JudgmentDisputeStake::get());
+
+        Bounty::<T>::open_judgment_dispute(
+            RawOrigin::Signed(account_id).into(),
+            member_id,
+            bounty_id,
+            None,
+            Vec::new(),
+        ).unwrap();
+
+    }: _(RawOrigin::Root, bounty_id)
+    verify {
+        assert!(!<JudgmentDisputes<T>>::contains_key(bounty_id));
+        assert_last_event::<T>(Event::<T>::OracleJudgmentOverturned(bounty_id).into());
+    }
+
+    finalize_oracle_judgment {
+        let e in 1 .. MAX_WORK_ENTRIES_WINNERS + MAX_WORK_ENTRIES_REJECTED;
+
+        let (bounty_id, (account_id, member_id), _) = submit_escrowed_judgment::<T>(
+            (MAX_WORK_ENTRIES_WINNERS + MAX_WORK_ENTRIES_REJECTED).into(),
+            e,
+        );
+
+        // The dispute stake is slashed on finalization.
+        let stake = T::JudgmentDisputeStake::get();
+        let _ = Balances::<T>::deposit_creating(&account_id, stake);
+
+        Bounty::<T>::open_judgment_dispute(
+            RawOrigin::Signed(account_id.clone()).into(),
+            member_id,
+            bounty_id,
+            None,
+            Vec::new(),
+        ).unwrap();
+
+        let bounty = Bounty::<T>::bounties(bounty_id);
+        System::<T>::set_block_number(
+            System::<T>::block_number()
+                + bounty.creation_params.appeal_period
+                + T::JudgmentDisputePeriod::get()
+                + One::one()
+        );
+
+    }: _(RawOrigin::Signed(account_id), bounty_id, e)
+    verify {
+        assert!(!<JudgmentDisputes<T>>::contains_key(bounty_id));
+        assert!(<EscrowedJudgments<T>>::iter_prefix(bounty_id).next().is_none());
+        assert_was_fired::<T>(
+            Event::<T>::JudgmentDisputeStakeSlashed(bounty_id, member_id, stake).into()
+        );
+        assert_was_fired::<T>(Event::<T>::OracleJudgmentFinalized(bounty_id).into());
+    }
+
     entrant_remark {
         let i in 0 .. MAX_KILOBYTES_METADATA;
 
@@ -2169,6 +2550,48 @@ mod tests {
         });
     }
 
+    #[test]
+    fn submit_oracle_panel_judgment() {
+        build_test_externalities().execute_with(|| {
+            assert_ok!(Bounty::test_benchmark_submit_oracle_panel_judgment());
+        });
+    }
+
+    #[test]
+    fn switch_oracle_panel_member() {
+        build_test_externalities().execute_with(|| {
+            assert_ok!(Bounty::test_benchmark_switch_oracle_panel_member());
+        });
+    }
+
+    #[test]
+    fn withdraw_oracle_panel_reward() {
+        build_test_externalities().execute_with(|| {
+            assert_ok!(Bounty::test_benchmark_withdraw_oracle_panel_reward());
+        });
+    }
+
+    #[test]
+    fn open_judgment_dispute() {
+        build_test_externalities().execute_with(|| {
+            assert_ok!(Bounty::test_benchmark_open_judgment_dispute());
+        });
+    }
+
+    #[test]
+    fn overturn_oracle_judgment() {
+        build_test_externalities().execute_with(|| {
+            assert_ok!(Bounty::test_benchmark_overturn_oracle_judgment());
+        });
+    }
+
+    #[test]
+    fn finalize_oracle_judgment() {
+        build_test_externalities().execute_with(|| {
+            assert_ok!(Bounty::test_benchmark_finalize_oracle_judgment());
+        });
+    }
+
     #[test]
     fn withdraw_oracle_reward_by_oracle_council() {
         build_test_externalities().execute_with(|| {
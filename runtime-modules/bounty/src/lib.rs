@@ -14,6 +14,17 @@
 //!     along with a split cherry, none judged work entrants can unlock their stakes,
 //!     Oracle can withdraw his reward,
 //!
+//! A bounty can be denominated in a creator token: the funding is escrowed from the
+//! contributors' token accounts and the winners are rewarded in the token.
+//!
+//! A bounty can be judged by an oracle panel of members, or of the current council members,
+//! instead of a single oracle. The oracle reward is split among the panel members in equal
+//! shares and the shares of the members that didn't submit their judgments are returned to the
+//! bounty creator.
+//!
+//! Work entries can be announced by any member, by the members of a closed list, or only by the
+//! members with a valid membership attestation of the given kind.
+//!
 //! A detailed description could be found [here](https://github.com/Joystream/joystream/issues/1998).
 //!
 //! ### Supported extrinsics
@@ -82,6 +93,8 @@ pub(crate) mod tests;
 mod actors;
 mod stages;
 
+pub mod migrations;
+
 #[cfg(feature = "runtime-benchmarks")]
 mod benchmarking;
 
@@ -91,6 +104,8 @@ pub use weights::WeightInfo;
 
 type WeightInfoBounty<T> = <T as Config>::WeightInfo;
 
+const CURRENT_STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
 pub(crate) use actors::BountyActorManager;
 
 // use council::Balance;
@@ -100,23 +115,24 @@ use codec::{Decode, Encode, MaxEncodedLen};
 #[cfg(feature = "std")]
 use serde::{Deserialize, Serialize};
 
-use common::council::CouncilBudgetManager;
+use common::council::{CouncilBudgetManager, CouncilMembersProvider};
 use common::membership::{
-    MemberId, MemberOriginValidator, MembershipInfoProvider, MembershipTypes,
+    AttestationKind, MemberId, MemberOriginValidator, MembershipInfoProvider, MembershipTypes,
     StakingAccountValidator,
 };
 use common::to_kb;
 use frame_support::dispatch::{DispatchError, DispatchResult};
 use frame_support::storage::bounded_btree_set::BoundedBTreeSet;
-use frame_support::traits::{Currency, ExistenceRequirement, Get, LockIdentifier};
+use frame_support::storage::bounded_vec::BoundedVec;
+use frame_support::traits::{Currency, ExistenceRequirement, Get, LockIdentifier, StorageVersion};
 use frame_support::weights::Weight;
 use frame_support::{
     decl_error, decl_event, decl_module, decl_storage, ensure, PalletId, Parameter,
 };
-use frame_system::ensure_root;
+use frame_system::{ensure_root, ensure_signed};
 use scale_info::TypeInfo;
 use sp_arithmetic::traits::{One, Saturating, Zero};
-use sp_runtime::traits::AccountIdConversion;
+use sp_runtime::traits::{AccountIdConversion, CheckedDiv, Hash};
 use sp_runtime::{Perbill, SaturatedConversion};
 use sp_std::clone::Clone;
 use sp_std::collections::{btree_map::BTreeMap, btree_set::BTreeSet};
@@ -124,9 +140,18 @@ use sp_std::convert::{TryFrom, TryInto};
 use sp_std::{vec, vec::Vec};
 use staking_handler::StakingHandler;
 
+use project_token::traits::PalletToken;
+use project_token::types::{
+    AmmParamsOf, TokenBalanceOf, TokenIssuanceParametersOf, TokenSaleParamsOf,
+    TransferWithVestingOutputsOf, UploadContextOf,
+};
+
 /// Main pallet-bounty trait.
 pub trait Config:
-    frame_system::Config + balances::Config + common::membership::MembershipTypes
+    frame_system::Config
+    + balances::Config
+    + common::membership::MembershipTypes
+    + project_token::Config
 {
     /// Events
     type RuntimeEvent: From<Event<Self>> + Into<<Self as frame_system::Config>::RuntimeEvent>;
@@ -149,6 +174,9 @@ pub trait Config:
     /// Provides an access for the council budget.
     type CouncilBudgetManager: CouncilBudgetManager<Self::AccountId, BalanceOf<Self>>;
 
+    /// Provides the current council members acting as the council oracle panel.
+    type CouncilMembers: CouncilMembersProvider<MemberId<Self>>;
+
     /// Provides stake logic implementation.
     type StakingHandler: StakingHandler<
         Self::AccountId,
@@ -173,6 +201,33 @@ pub trait Config:
     /// Current state bloat bond a creator has to pay to create a bounty.
     /// The creator can withdraw the bond after he or someone else removes the bounty
     type CreatorStateBloatBondAmount: Get<BalanceOf<Self>>;
+
+    /// Defines max number of delivery milestones of a bounty.
+    type MaxDeliveryMilestones: Get<u32>;
+
+    /// Defines max number of members of a bounty oracle panel.
+    type MaxOraclePanelSize: Get<u32>;
+
+    /// Stake a member has to provide to dispute an oracle judgment.
+    type JudgmentDisputeStake: Get<BalanceOf<Self>>;
+
+    /// Number of blocks the council has to overturn a disputed oracle judgment.
+    type JudgmentDisputePeriod: Get<Self::BlockNumber>;
+
+    /// Interface for Creator Tokens functionality.
+    type ProjectToken: PalletToken<
+        Self::TokenId,
+        Self::MemberId,
+        Self::AccountId,
+        BalanceOf<Self>,
+        TokenIssuanceParametersOf<Self>,
+        Self::BlockNumber,
+        TokenSaleParamsOf<Self>,
+        UploadContextOf<Self>,
+        TransferWithVestingOutputsOf<Self>,
+        AmmParamsOf<Self>,
+        TokenBalanceOf<Self>,
+    >;
 }
 
 /// Alias type for the BountyParameters.
@@ -181,6 +236,9 @@ pub type BountyCreationParameters<T> = BountyParameters<
     <T as frame_system::Config>::BlockNumber,
     <T as MembershipTypes>::MemberId,
     BTreeSet<<T as MembershipTypes>::MemberId>,
+    Vec<DeliveryMilestone<<T as frame_system::Config>::BlockNumber>>,
+    BTreeSet<<T as MembershipTypes>::MemberId>,
+    <T as project_token::Config>::TokenId,
 >;
 
 /// Alias type for stored BountyParameters.
@@ -189,6 +247,9 @@ pub type BountyStoredCreationParameters<T> = BountyParameters<
     <T as frame_system::Config>::BlockNumber,
     <T as MembershipTypes>::MemberId,
     ClosedContractWhitelist<T>,
+    DeliveryMilestones<T>,
+    OraclePanelMembers<T>,
+    <T as project_token::Config>::TokenId,
 >;
 
 /// Defines who can submit the work.
@@ -200,6 +261,9 @@ pub enum AssuranceContractType<ClosedContractWhitelist> {
 
     /// Only specific members can submit the work.
     Closed(ClosedContractWhitelist),
+
+    /// Only members with a valid attestation of the given kind can submit the work.
+    Attested(AttestationKind),
 }
 
 impl<ClosedContractWhitelist> Default for AssuranceContractType<ClosedContractWhitelist> {
@@ -211,6 +275,51 @@ impl<ClosedContractWhitelist> Default for AssuranceContractType<ClosedContractWh
 pub type ClosedContractWhitelist<T> =
     BoundedBTreeSet<<T as MembershipTypes>::MemberId, <T as Config>::ClosedContractSizeLimit>;
 
+/// Staged delivery milestone of a bounty.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Default, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
+pub struct DeliveryMilestone<BlockNumber> {
+    /// Share of the total bounty funding rewarded to the milestone winners.
+    pub funding_share: Perbill,
+
+    /// Number of blocks the work for the milestone can be submitted.
+    pub work_period: BlockNumber,
+
+    /// Number of blocks the oracle has to judge the milestone after its work period.
+    pub judging_period: BlockNumber,
+}
+
+pub type DeliveryMilestones<T> = BoundedVec<
+    DeliveryMilestone<<T as frame_system::Config>::BlockNumber>,
+    <T as Config>::MaxDeliveryMilestones,
+>;
+
+/// Panel of members judging a bounty instead of a single oracle.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Default, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
+pub struct OraclePanel<BlockNumber, OraclePanelMembers> {
+    /// Members submitting their judgments.
+    pub members: OraclePanelMembers,
+
+    /// The current council members act as the panel instead of the listed members.
+    pub council: bool,
+
+    /// Number of matching judgments required to finalize the bounty judgment.
+    pub threshold: u32,
+
+    /// Number of blocks the panel has to reach the threshold after the work period.
+    /// The judging period of the delivery milestone applies for a bounty with delivery milestones.
+    pub judging_period: BlockNumber,
+}
+
+/// Alias type for the members of the OraclePanel.
+pub type OraclePanelMembers<T> =
+    BoundedBTreeSet<<T as MembershipTypes>::MemberId, <T as Config>::MaxOraclePanelSize>;
+
+/// Alias type for the stored OraclePanel.
+pub type OraclePanelOf<T> =
+    OraclePanel<<T as frame_system::Config>::BlockNumber, OraclePanelMembers<T>>;
+
 /// Defines funding conditions.
 #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 #[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
@@ -242,7 +351,15 @@ impl<BlockNumber, Balance: Default> Default for FundingType<BlockNumber, Balance
 /// Defines parameters for the bounty creation.
 #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 #[derive(Encode, Decode, Default, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
-pub struct BountyParameters<Balance, BlockNumber, MemberId: Ord, ClosedContractWhitelist> {
+pub struct BountyParameters<
+    Balance,
+    BlockNumber,
+    MemberId: Ord,
+    ClosedContractWhitelist,
+    DeliveryMilestones,
+    OraclePanelMembers,
+    TokenId,
+> {
     /// Origin that will select winner(s), is either a given member or a council.
     pub oracle: BountyActor<MemberId>,
 
@@ -269,23 +386,73 @@ pub struct BountyParameters<Balance, BlockNumber, MemberId: Ord, ClosedContractW
 
     /// Defines parameters for different funding types.
     pub funding_type: FundingType<BlockNumber, Balance>,
+
+    /// Ordered delivery milestones, each judged separately and rewarded with its share
+    /// of the total funding. A bounty without milestones is judged once.
+    pub delivery_milestones: DeliveryMilestones,
+
+    /// Optional panel judging the bounty by the threshold of matching judgments.
+    /// The oracle still manages the work periods of a bounty judged by the panel.
+    pub oracle_panel: Option<OraclePanel<BlockNumber, OraclePanelMembers>>,
+
+    /// Number of blocks after the oracle judgment during which it can be disputed.
+    /// Winner rewards and slashes are held in escrow until the judgment is finalized.
+    /// Zero value applies the judgment immediately.
+    pub appeal_period: BlockNumber,
+
+    /// Optional creator token the bounty is denominated in. Funding and winner rewards
+    /// are then paid in the token, while the cherry, the oracle reward, entrant stakes and
+    /// state bloat bonds remain in JOY.
+    pub token_id: Option<TokenId>,
 }
 
-impl<Balance: Clone, BlockNumber: Clone, MemberId: Ord + Clone, ClosedContractSizeLimit>
-    TryFrom<BountyParameters<Balance, BlockNumber, MemberId, BTreeSet<MemberId>>>
+impl<
+        Balance: Clone,
+        BlockNumber: Clone,
+        MemberId: Ord + Clone,
+        ClosedContractSizeLimit,
+        MaxDeliveryMilestones,
+        MaxOraclePanelSize,
+        TokenId,
+    >
+    TryFrom<
+        BountyParameters<
+            Balance,
+            BlockNumber,
+            MemberId,
+            BTreeSet<MemberId>,
+            Vec<DeliveryMilestone<BlockNumber>>,
+            BTreeSet<MemberId>,
+            TokenId,
+        >,
+    >
     for BountyParameters<
         Balance,
         BlockNumber,
         MemberId,
         BoundedBTreeSet<MemberId, ClosedContractSizeLimit>,
+        BoundedVec<DeliveryMilestone<BlockNumber>, MaxDeliveryMilestones>,
+        BoundedBTreeSet<MemberId, MaxOraclePanelSize>,
+        TokenId,
     >
 where
     BoundedBTreeSet<MemberId, ClosedContractSizeLimit>: TryFrom<BTreeSet<MemberId>>,
+    BoundedVec<DeliveryMilestone<BlockNumber>, MaxDeliveryMilestones>:
+        TryFrom<Vec<DeliveryMilestone<BlockNumber>>>,
+    BoundedBTreeSet<MemberId, MaxOraclePanelSize>: TryFrom<BTreeSet<MemberId>>,
 {
     type Error = ();
 
     fn try_from(
-        params: BountyParameters<Balance, BlockNumber, MemberId, BTreeSet<MemberId>>,
+        params: BountyParameters<
+            Balance,
+            BlockNumber,
+            MemberId,
+            BTreeSet<MemberId>,
+            Vec<DeliveryMilestone<BlockNumber>>,
+            BTreeSet<MemberId>,
+            TokenId,
+        >,
     ) -> Result<Self, Self::Error> {
         let contract_type = match params.contract_type.clone() {
             AssuranceContractType::Closed(whitelist) => {
@@ -293,6 +460,7 @@ where
                 AssuranceContractType::Closed(bounded_whitelist)
             }
             AssuranceContractType::Open => AssuranceContractType::Open,
+            AssuranceContractType::Attested(kind) => AssuranceContractType::Attested(kind),
         };
 
         Ok(Self {
@@ -303,6 +471,20 @@ where
             oracle_reward: params.oracle_reward,
             entrant_stake: params.entrant_stake,
             funding_type: params.funding_type,
+            delivery_milestones: params.delivery_milestones.try_into().map_err(|_| ())?,
+            oracle_panel: params
+                .oracle_panel
+                .map(|panel| {
+                    Ok::<_, ()>(OraclePanel {
+                        members: panel.members.try_into().map_err(|_| ())?,
+                        council: panel.council,
+                        threshold: panel.threshold,
+                        judging_period: panel.judging_period,
+                    })
+                })
+                .transpose()?,
+            appeal_period: params.appeal_period,
+            token_id: params.token_id,
         })
     }
 }
@@ -340,9 +522,14 @@ pub enum BountyStage {
     /// A bounty has gathered necessary funds and ready to accept work submissions.
     WorkSubmission,
 
-    /// Working periods ended and the oracle should provide their judgment.
+    /// Working periods ended and the oracle should provide their judgment
+    /// (of the current delivery milestone).
     Judgment,
 
+    /// The oracle judgment is held in escrow: it can be disputed during the appeal period
+    /// and overturned by the council until the dispute period is over.
+    JudgmentAppeal,
+
     /// Indicates a withdrawal on bounty success. Workers get rewards and their stake.
     SuccessfulBountyWithdrawal,
 
@@ -400,6 +587,24 @@ pub enum BountyMilestone<BlockNumber> {
         ///This flag indicates the judgment result (there is at least one work entrant winner),
         successful_bounty: bool,
     },
+
+    /// A delivery milestone (not the last one) was judged successful and the work
+    /// on the next delivery milestone started.
+    ///
+    /// This state will tranlate into:
+    /// - BountyStage::WorkSubmission within the work period of the next delivery milestone
+    /// - BountyStage::Judgment within its judging period if active_work_entry_count > 0
+    /// - BountyStage::FailedBountyWithdrawal after that
+    DeliveryMilestoneJudged,
+
+    /// A judgment was submitted for a bounty with an appeal period and is held in escrow.
+    ///
+    /// This state will tranlate into:
+    /// - BountyStage::JudgmentAppeal until the judgment is finalized or overturned
+    JudgmentEscrowed {
+        /// Judgment submission block.
+        judged_at: BlockNumber,
+    },
 }
 
 impl<BlockNumber: Default> Default for BountyMilestone<BlockNumber> {
@@ -417,14 +622,51 @@ pub type Bounty<T> = BountyRecord<
     <T as frame_system::Config>::BlockNumber,
     <T as common::membership::MembershipTypes>::MemberId,
     ClosedContractWhitelist<T>,
+    DeliveryMilestones<T>,
+    OraclePanelMembers<T>,
+    <T as project_token::Config>::TokenId,
 >;
 
+/// Progress of a bounty through its delivery milestones.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Default, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
+pub struct DeliveryProgress<BlockNumber, Balance> {
+    /// Index of the current delivery milestone.
+    pub current_milestone: u32,
+
+    /// Block the work on the current delivery milestone started at.
+    /// None if it started when the limited funding period expired.
+    pub work_started_at: Option<BlockNumber>,
+
+    /// Block the oracle ended the work period of the current delivery milestone at.
+    pub work_ended_at: Option<BlockNumber>,
+
+    /// Rewards paid to the winners of the judged delivery milestones.
+    pub paid_out: Balance,
+}
+
 /// Crowdfunded bounty record.
 #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 #[derive(Encode, Decode, Default, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
-pub struct BountyRecord<Balance, BlockNumber, MemberId: Ord, ClosedContractWhitelist> {
+pub struct BountyRecord<
+    Balance,
+    BlockNumber,
+    MemberId: Ord,
+    ClosedContractWhitelist,
+    DeliveryMilestones,
+    OraclePanelMembers,
+    TokenId,
+> {
     /// Bounty creation parameters.
-    pub creation_params: BountyParameters<Balance, BlockNumber, MemberId, ClosedContractWhitelist>,
+    pub creation_params: BountyParameters<
+        Balance,
+        BlockNumber,
+        MemberId,
+        ClosedContractWhitelist,
+        DeliveryMilestones,
+        OraclePanelMembers,
+        TokenId,
+    >,
 
     /// Total funding balance reached so far.
     /// Includes initial funding by a creator and other members funding.
@@ -439,10 +681,29 @@ pub struct BountyRecord<Balance, BlockNumber, MemberId: Ord, ClosedContractWhite
 
     ///This flag is set to false, if oracle called withdraw_oracle_reward.
     pub has_unpaid_oracle_reward: bool,
+
+    /// Progress through the delivery milestones (unused for a bounty without milestones).
+    pub delivery_progress: DeliveryProgress<BlockNumber, Balance>,
 }
 
-impl<Balance: PartialOrd + Clone, BlockNumber: Clone, MemberId: Ord, ClosedContractWhitelist>
-    BountyRecord<Balance, BlockNumber, MemberId, ClosedContractWhitelist>
+impl<
+        Balance: PartialOrd + Clone,
+        BlockNumber: Clone,
+        MemberId: Ord,
+        ClosedContractWhitelist,
+        DeliveryMilestones,
+        OraclePanelMembers,
+        TokenId,
+    >
+    BountyRecord<
+        Balance,
+        BlockNumber,
+        MemberId,
+        ClosedContractWhitelist,
+        DeliveryMilestones,
+        OraclePanelMembers,
+        TokenId,
+    >
 {
     // Increments bounty active work entry counter.
     fn increment_active_work_entry_counter(&mut self) {
@@ -574,6 +835,84 @@ pub type OracleJudgmentOf<T> = OracleJudgment<<T as Config>::EntryId, BalanceOf<
 /// The collection of the oracle judgments for the work entries.
 pub type OracleJudgment<EntryId, Balance> = BTreeMap<EntryId, OracleWorkEntryJudgment<Balance>>;
 
+/// Oracle judgment of a work entry held in escrow during the bounty appeal period.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
+pub enum EscrowedWorkEntryJudgment<Balance> {
+    /// The work entry won the reward.
+    Winner {
+        /// The reward to pay once the judgment is finalized.
+        reward: Balance,
+    },
+
+    /// The work entry was rejected.
+    Rejected {
+        /// The share of the entrant stake to slash once the judgment is finalized.
+        slashing_share: Perbill,
+    },
+}
+
+impl<Balance: Clone> From<&OracleWorkEntryJudgment<Balance>>
+    for EscrowedWorkEntryJudgment<Balance>
+{
+    fn from(work_entry_judgment: &OracleWorkEntryJudgment<Balance>) -> Self {
+        match work_entry_judgment {
+            OracleWorkEntryJudgment::Winner { reward } => EscrowedWorkEntryJudgment::Winner {
+                reward: reward.clone(),
+            },
+            OracleWorkEntryJudgment::Rejected { slashing_share, .. } => {
+                EscrowedWorkEntryJudgment::Rejected {
+                    slashing_share: *slashing_share,
+                }
+            }
+        }
+    }
+}
+
+impl<Balance> From<EscrowedWorkEntryJudgment<Balance>> for OracleWorkEntryJudgment<Balance> {
+    fn from(work_entry_judgment: EscrowedWorkEntryJudgment<Balance>) -> Self {
+        match work_entry_judgment {
+            EscrowedWorkEntryJudgment::Winner { reward } => {
+                OracleWorkEntryJudgment::Winner { reward }
+            }
+            EscrowedWorkEntryJudgment::Rejected { slashing_share } => {
+                OracleWorkEntryJudgment::Rejected {
+                    slashing_share,
+                    action_justification: Vec::new(),
+                }
+            }
+        }
+    }
+}
+
+/// Alias type for the EscrowedWorkEntryJudgment.
+pub type EscrowedWorkEntryJudgmentOf<T> = EscrowedWorkEntryJudgment<BalanceOf<T>>;
+
+/// Dispute of an escrowed oracle judgment.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
+pub struct JudgmentDispute<MemberId, AccountId, Balance, BlockNumber> {
+    /// Member that opened the dispute.
+    pub disputer: MemberId,
+
+    /// Account the dispute stake was provided from.
+    pub account_id: AccountId,
+
+    /// Dispute stake held in the bounty account.
+    pub stake: Balance,
+
+    /// Block the dispute was opened at.
+    pub opened_at: BlockNumber,
+}
+
+/// Alias type for the JudgmentDispute.
+pub type JudgmentDisputeOf<T> = JudgmentDispute<
+    MemberId<T>,
+    <T as frame_system::Config>::AccountId,
+    BalanceOf<T>,
+    <T as frame_system::Config>::BlockNumber,
+>;
+
 /// Represents a valid stage for doing withdrawals,
 /// is used as a safe internal representation of validation step in `withdraw_funding` extrinsic.
 enum ValidWithdrawalStage {
@@ -608,6 +947,25 @@ decl_storage! { generate_storage_info
 
         /// Count of all work entries that have been created.
         pub EntryCount get(fn entry_count): u32;
+
+        /// Hashes of the oracle panel member judgments submitted in the current judging round.
+        pub OraclePanelJudgments get(fn oracle_panel_judgments): double_map
+            hasher(blake2_128_concat) T::BountyId,
+            hasher(blake2_128_concat) MemberId<T> => Option<T::Hash>;
+
+        /// Oracle panel members that submitted at least one judgment for a bounty.
+        pub OraclePanelParticipants get(fn oracle_panel_participants): double_map
+            hasher(blake2_128_concat) T::BountyId,
+            hasher(blake2_128_concat) MemberId<T> => bool;
+
+        /// Oracle judgments of the work entries held in escrow during the appeal period.
+        pub EscrowedJudgments get(fn escrowed_judgments): double_map
+            hasher(blake2_128_concat) T::BountyId,
+            hasher(blake2_128_concat) T::EntryId => Option<EscrowedWorkEntryJudgmentOf<T>>;
+
+        /// Open disputes of the escrowed oracle judgments.
+        pub JudgmentDisputes get(fn judgment_disputes): map
+            hasher(blake2_128_concat) T::BountyId => Option<JudgmentDisputeOf<T>>;
     }
 }
 
@@ -748,6 +1106,60 @@ decl_event! {
         /// - message
         BountyCreatorRemarked(BountyActor<MemberId>, BountyId, Vec<u8>),
 
+        /// A delivery milestone was judged successful and its winners were rewarded.
+        /// Params:
+        /// - bounty ID
+        /// - delivery milestone index
+        DeliveryMilestoneCompleted(BountyId, u32),
+
+        /// Oracle panel member submitted a judgment.
+        /// Params:
+        /// - bounty ID
+        /// - oracle panel member ID
+        /// - judgment data
+        /// - rationale
+        OraclePanelJudgmentSubmitted(BountyId, MemberId, OracleJudgment, Vec<u8>),
+
+        /// Oracle panel member was switched by the member or the council.
+        /// Params:
+        /// - bounty ID
+        /// - switcher
+        /// - current oracle panel member ID
+        /// - new oracle panel member ID
+        OraclePanelMemberSwitched(BountyId, BountyActor<MemberId>, MemberId, MemberId),
+
+        /// Oracle reward shares of the oracle panel members that didn't submit their judgments
+        /// were returned to the bounty creator.
+        /// Params:
+        /// - bounty ID
+        /// - withheld reward
+        OraclePanelRewardWithheld(BountyId, Balance),
+
+        /// Escrowed oracle judgment was disputed.
+        /// Params:
+        /// - bounty ID
+        /// - disputer member ID
+        /// - dispute stake
+        /// - rationale
+        JudgmentDisputeOpened(BountyId, MemberId, Balance, Vec<u8>),
+
+        /// Disputed oracle judgment was overturned by the council.
+        /// Params:
+        /// - bounty ID
+        OracleJudgmentOverturned(BountyId),
+
+        /// Escrowed oracle judgment was finalized.
+        /// Params:
+        /// - bounty ID
+        OracleJudgmentFinalized(BountyId),
+
+        /// Stake of the dispute that did not overturn the oracle judgment was slashed.
+        /// Params:
+        /// - bounty ID
+        /// - disputer member ID
+        /// - slashed amount
+        JudgmentDisputeStakeSlashed(BountyId, MemberId, Balance),
+
         /// Work entry was slashed.
         /// Params:
         /// - bounty ID
@@ -828,6 +1240,9 @@ decl_error! {
         /// Unexpected bounty stage for an operation: Judgment.
         InvalidStageUnexpectedJudgment,
 
+        /// Unexpected bounty stage for an operation: JudgmentAppeal.
+        InvalidStageUnexpectedJudgmentAppeal,
+
         /// Unexpected bounty stage for an operation: SuccessfulBountyWithdrawal.
         InvalidStageUnexpectedSuccessfulBountyWithdrawal,
 
@@ -856,6 +1271,10 @@ decl_error! {
         /// assurance' bounty contract.
         CannotSubmitWorkToClosedContractBounty,
 
+        /// Incompatible assurance contract type for a member: cannot submit work to the 'attested
+        /// assurance' bounty contract without a valid attestation of the required kind.
+        CannotSubmitWorkWithoutAttestation,
+
         /// Cannot create a 'closed assurance contract' bounty with empty member list.
         ClosedContractMemberListIsEmpty,
 
@@ -906,7 +1325,88 @@ decl_error! {
         WorkEntryDoesntBelongToWorker,
 
         ///Oracle have already been withdrawn
-        OracleRewardAlreadyWithdrawn
+        OracleRewardAlreadyWithdrawn,
+
+        /// Cannot create a bounty with more delivery milestones than allowed.
+        TooManyDeliveryMilestones,
+
+        /// Delivery milestone funding shares should be non-zero and sum up to 100%.
+        InvalidDeliveryMilestonesFundingShares,
+
+        /// Cannot create a bounty with zero work or judging period of a delivery milestone.
+        DeliveryMilestonePeriodCannotBeZero,
+
+        /// The total reward for winners should be equal to the current delivery milestone funding.
+        TotalRewardShouldBeEqualToDeliveryMilestoneFunding,
+
+        /// Cannot create a bounty with an empty oracle panel.
+        OraclePanelIsEmpty,
+
+        /// Cannot create a bounty with an oracle panel larger than allowed.
+        OraclePanelIsTooLarge,
+
+        /// Oracle panel threshold should be non-zero and not greater than the panel size.
+        InvalidOraclePanelThreshold,
+
+        /// Cannot create a bounty with zero oracle panel judging period.
+        OraclePanelJudgingPeriodCannotBeZero,
+
+        /// Oracle panel member ID does not belong to an existing member.
+        InvalidOraclePanelMemberId,
+
+        /// The bounty is judged by its oracle panel.
+        BountyIsJudgedByOraclePanel,
+
+        /// The bounty has no oracle panel.
+        BountyHasNoOraclePanel,
+
+        /// Member is not in the bounty oracle panel.
+        NotAnOraclePanelMember,
+
+        /// Member is already in the bounty oracle panel.
+        OraclePanelMemberAlreadyExists,
+
+        /// Cannot create a bounty with the council oracle panel listing the members.
+        CouncilOraclePanelCannotHaveMembers,
+
+        /// Members of the council oracle panel cannot be switched.
+        CannotSwitchCouncilOraclePanelMember,
+
+        /// Appeal period cannot be set for a bounty with delivery milestones.
+        AppealPeriodNotSupportedWithDeliveryMilestones,
+
+        /// The appeal period of the oracle judgment is over.
+        JudgmentAppealPeriodExpired,
+
+        /// The appeal period of the oracle judgment is not over yet.
+        JudgmentAppealPeriodNotExpired,
+
+        /// The oracle judgment is already disputed.
+        JudgmentAlreadyDisputed,
+
+        /// The oracle judgment is not disputed.
+        JudgmentIsNotDisputed,
+
+        /// Only a contributor or an entrant slashed by the judgment can dispute it.
+        NotEligibleToDisputeJudgment,
+
+        /// Insufficient balance for the judgment dispute stake.
+        InsufficientBalanceForJudgmentDispute,
+
+        /// The dispute period of the oracle judgment is over.
+        JudgmentDisputePeriodExpired,
+
+        /// The dispute period of the oracle judgment is not over yet.
+        JudgmentDisputePeriodNotExpired,
+
+        /// The number of the judged work entries doesn't match the escrowed judgment.
+        InvalidJudgedEntriesWitness,
+
+        /// The creator token of the bounty doesn't exist.
+        CreatorTokenDoesNotExist,
+
+        /// Council cannot fund a bounty denominated in a creator token.
+        CouncilCannotFundCreatorTokenBounty
     }
 }
 
@@ -919,6 +1419,8 @@ decl_module! {
         /// Emits an event. Default substrate implementation.
         fn deposit_event() = default;
 
+        type StorageVersion = CURRENT_STORAGE_VERSION;
+
         /// Exports const - max work entry number for a closed assurance type contract bounty.
         const ClosedContractSizeLimit: u32 = T::ClosedContractSizeLimit::get();
 
@@ -931,6 +1433,18 @@ decl_module! {
         /// Exports const - creator state bloat bond amount for a bounty.
         const CreatorStateBloatBondAmount: BalanceOf<T> = T::CreatorStateBloatBondAmount::get();
 
+        /// Exports const - max number of delivery milestones of a bounty.
+        const MaxDeliveryMilestones: u32 = T::MaxDeliveryMilestones::get();
+
+        /// Exports const - max number of members of a bounty oracle panel.
+        const MaxOraclePanelSize: u32 = T::MaxOraclePanelSize::get();
+
+        /// Exports const - stake required to dispute an oracle judgment.
+        const JudgmentDisputeStake: BalanceOf<T> = T::JudgmentDisputeStake::get();
+
+        /// Exports const - number of blocks the council has to overturn a disputed judgment.
+        const JudgmentDisputePeriod: T::BlockNumber = T::JudgmentDisputePeriod::get();
+
         /// Creates a bounty. Metadata stored in the transaction log but discarded after that.
         /// <weight>
         ///
@@ -979,7 +1493,8 @@ decl_module! {
                 creation_params: stored_creation_params,
                 milestone: created_bounty_milestone,
                 active_work_entry_count: 0,
-                has_unpaid_oracle_reward: params.oracle_reward > Zero::zero()
+                has_unpaid_oracle_reward: params.oracle_reward > Zero::zero(),
+                delivery_progress: Default::default(),
             };
 
             <Bounties<T>>::insert(bounty_id, bounty);
@@ -1034,8 +1549,32 @@ decl_module! {
                     amount,
                     is_target_funding_reached);
 
+            // Creator token funding is escrowed from the funder token account,
+            // only the state bloat bond is transferred in JOY.
+            let joy_transfer_amount = match bounty.creation_params.token_id {
+                Some(_) => transfer_amount.saturating_sub(adjusted_amount),
+                None => transfer_amount,
+            };
+
             bounty_funder_manager.validate_balance_sufficiency(
-                transfer_amount)?;
+                joy_transfer_amount)?;
+
+            // Call to ProjectToken - should be the first call before MUTATION SAFE!
+            if let Some(token_id) = bounty.creation_params.token_id {
+                let member_id = match funder {
+                    BountyActor::Member(member_id) => member_id,
+                    BountyActor::Council => {
+                        return Err(Error::<T>::CouncilCannotFundCreatorTokenBounty.into())
+                    }
+                };
+
+                T::ProjectToken::escrow_member_tokens(
+                    token_id,
+                    member_id,
+                    Self::bounty_account_id(bounty_id),
+                    Self::to_token_balance(adjusted_amount)?,
+                )?;
+            }
 
             //
             // == MUTATION SAFE ==
@@ -1043,7 +1582,7 @@ decl_module! {
 
             bounty_funder_manager.transfer_funds_to_bounty_account(
                 bounty_id,
-                transfer_amount);
+                joy_transfer_amount);
 
             let new_milestone = Self::get_bounty_milestone_on_funding(
                 is_target_funding_reached,
@@ -1054,6 +1593,11 @@ decl_module! {
                 //Updates only the funds not the bloat bond.
                 bounty.total_funding = bounty.total_funding.saturating_add(adjusted_amount);
                 bounty.milestone = new_milestone;
+
+                // Work on the first delivery milestone starts once the target is reached.
+                if is_target_funding_reached && !bounty.creation_params.delivery_milestones.is_empty() {
+                    bounty.delivery_progress.work_started_at = Some(Self::current_block());
+                }
             });
 
             //Update member funding record
@@ -1197,6 +1741,70 @@ decl_module! {
                 new_oracle));
         }
 
+        /// Oracle panel member switches himself to a new one or the council replaces him.
+        /// # <weight>
+        ///
+        /// ## weight
+        /// `O (1)`
+        /// - db:
+        ///    - `O(1)` doesn't depend on the state or parameters
+        /// # </weight>
+        #[weight = WeightInfoBounty::<T>::switch_oracle_panel_member()]
+        pub fn switch_oracle_panel_member(
+            origin,
+            bounty_id: T::BountyId,
+            current_member_id: MemberId<T>,
+            new_member_id: MemberId<T>,
+        ) {
+            let bounty = Self::ensure_bounty_exists(&bounty_id)?;
+            let oracle_panel = Self::ensure_oracle_panel_member(&bounty, &current_member_id)?;
+
+            ensure!(!oracle_panel.council, Error::<T>::CannotSwitchCouncilOraclePanelMember);
+
+            ensure!(
+                !oracle_panel.members.contains(&new_member_id),
+                Error::<T>::OraclePanelMemberAlreadyExists
+            );
+
+            let switcher = Self::ensure_switch_oracle_actors(
+                origin,
+                BountyActor::Member(current_member_id),
+                BountyActor::Member(new_member_id),
+            )?;
+
+            let current_bounty_stage = Self::get_bounty_stage(&bounty);
+
+            ensure!(
+                matches!(current_bounty_stage,
+                    BountyStage::Funding{..} |
+                    BountyStage::WorkSubmission |
+                    BountyStage::Judgment
+                ),
+                Self::unexpected_bounty_stage_error(current_bounty_stage)
+            );
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            //Mutates the oracle panel replacing the current member
+            <Bounties<T>>::mutate(bounty_id, |bounty| {
+                if let Some(oracle_panel) = bounty.creation_params.oracle_panel.as_mut() {
+                    oracle_panel.members.remove(&current_member_id);
+                    let _ = oracle_panel.members.try_insert(new_member_id);
+                }
+            });
+
+            <OraclePanelJudgments<T>>::remove(bounty_id, current_member_id);
+            <OraclePanelParticipants<T>>::remove(bounty_id, current_member_id);
+
+            Self::deposit_event(RawEvent::OraclePanelMemberSwitched(
+                bounty_id,
+                switcher,
+                current_member_id,
+                new_member_id));
+        }
+
         /// Withdraw bounty funding by a member or a council.
         /// # <weight>
         ///
@@ -1229,6 +1837,16 @@ decl_module! {
                 bounty.creation_params.creator.clone(),
             )?;
 
+            // Call to ProjectToken - should be the first call before MUTATION SAFE!
+            if matches!(valid_withdrawal_stage, ValidWithdrawalStage::FailedBountyWithdrawal) {
+                Self::release_unspent_token_funding(
+                    bounty_id,
+                    &bounty,
+                    &funder,
+                    funding.amount,
+                )?;
+            }
+
             //
             // == MUTATION SAFE ==
             //
@@ -1273,7 +1891,7 @@ decl_module! {
         #[weight = WeightInfoBounty::<T>::announce_work_entry(
             to_kb(work_description.len().saturated_into()),
             T::ClosedContractSizeLimit::get().saturated_into(),
-        )]
+        ).saturating_add(T::DbWeight::get().reads(1))]
         pub fn announce_work_entry(
             origin,
             member_id: MemberId<T>,
@@ -1409,6 +2027,12 @@ decl_module! {
 
             <Bounties<T>>::mutate(bounty_id, |bounty| {
                 bounty.milestone = BountyMilestone::WorkSubmitted;
+
+                // Judging periods are counted from the end of the work period.
+                if !bounty.creation_params.delivery_milestones.is_empty() ||
+                    bounty.creation_params.oracle_panel.is_some() {
+                    bounty.delivery_progress.work_ended_at = Some(Self::current_block());
+                }
             });
             Self::deposit_event(RawEvent::WorkSubmissionPeriodEnded(bounty_id, current_oracle));
         }
@@ -1443,92 +2067,111 @@ decl_module! {
                 bounty.creation_params.oracle.clone(),
             )?;
 
+            ensure!(
+                bounty.creation_params.oracle_panel.is_none(),
+                Error::<T>::BountyIsJudgedByOraclePanel
+            );
+
             let bounty_creator_manager = Self::ensure_creator_actor_manager(&bounty)?;
 
             let current_bounty_stage = Self::get_bounty_stage(&bounty);
 
             Self::ensure_bounty_stage(current_bounty_stage, BountyStage::Judgment)?;
 
-            Self::validate_judgment(&bounty_id, &bounty, &judgment)?;
-
-            // Lookup for any winners in the judgment.
-            let successful_bounty = Self::judgment_has_winners(&judgment);
+            let reward_sum = Self::validate_judgment(&bounty_id, &bounty, &judgment)?;
 
             //
             // == MUTATION SAFE ==
             //
 
-            // Return a cherry to a creator.
-            if successful_bounty {
-                Self::return_bounty_cherry_to_creator(bounty_id, &bounty, &bounty_creator_manager);
-            }
-
-            // Update bounty record.
-            <Bounties<T>>::mutate(bounty_id, |bounty| {
-                bounty.milestone = BountyMilestone::JudgmentSubmitted{
-                    successful_bounty
-                };
-            });
-
-            // Judgments triage.
-            for (entry_id, work_entry_judgment) in judgment.iter() {
-
-                let entry = Self::ensure_work_entry_exists(&bounty_id, entry_id)?;
-
-                // Update work entries for winners.
-                match *work_entry_judgment{
-                    OracleWorkEntryJudgment::Winner{ reward } => {
-
-                        // Unstake the full work entry state.
-                        let worker_account_id = T::Membership::controller_account_id(entry.member_id)?;
-
-                        T::StakingHandler::unlock(&entry.staking_account_id);
-                        // Claim the winner reward.
-
-                        Self::transfer_funds_from_bounty_account(
-                            &worker_account_id,
-                            bounty_id,
-                            reward
-                        );
-                        // Delete the work entry record from the storage.
-                        Self::remove_work_entry(&bounty_id, entry_id);
-
-                        // Fire an event.
-                        Self::deposit_event(RawEvent::WorkEntrantFundsWithdrawn(bounty_id, *entry_id, entry.member_id));
-
-                    },
-                    OracleWorkEntryJudgment::Rejected{
-                        slashing_share,
-                        ..
-                    } => {
+            Self::apply_oracle_judgment(
+                bounty_id,
+                &bounty,
+                &bounty_creator_manager,
+                judgment,
+                reward_sum,
+                rationale,
+            );
+        }
 
-                        let slashing_amount = slashing_share * bounty.creation_params.entrant_stake;
+        /// Submits a judgment of an oracle panel member. The bounty judgment is applied
+        /// once the threshold of matching judgments of the panel members is reached.
+        /// # <weight>
+        ///
+        /// ## weight
+        /// `O (J + K + W + R + P)`
+        /// - `J` is rationale size in kilobytes,
+        /// - `K` is the sum of all action_justification sizes (in kilobytes) inside OracleJudgment,
+        /// - `W` is number of winner judgment entries,
+        /// - `R` is number of rejected judgment entries,
+        /// - `P` is max oracle panel size,
+        /// - db:
+        ///    - `O(W + R + P)`
+        /// # </weight>
+        #[weight = Module::<T>::submit_oracle_judgment_weight(
+            judgment,
+            to_kb(rationale.len().saturated_into())
+        ).saturating_add(
+            WeightInfoBounty::<T>::submit_oracle_panel_judgment(T::MaxOraclePanelSize::get())
+        )]
+        pub fn submit_oracle_panel_judgment(
+            origin,
+            member_id: MemberId<T>,
+            bounty_id: T::BountyId,
+            judgment: OracleJudgment<T::EntryId, BalanceOf<T>>,
+            rationale: Vec<u8>,
+        ) {
+            T::Membership::ensure_member_controller_account_origin(origin, member_id)?;
 
-                        if slashing_amount > Zero::zero() {
-                            T::StakingHandler::slash(&entry.staking_account_id, Some(slashing_amount));
-                        }
+            let bounty = Self::ensure_bounty_exists(&bounty_id)?;
+            let oracle_panel = Self::ensure_oracle_panel_member(&bounty, &member_id)?;
 
-                        T::StakingHandler::unlock(&entry.staking_account_id);
+            let bounty_creator_manager = Self::ensure_creator_actor_manager(&bounty)?;
 
-                        Self::remove_work_entry(&bounty_id, entry_id);
+            let current_bounty_stage = Self::get_bounty_stage(&bounty);
 
-                        // Fire a WorkEntrantStakeSlashed event.
-                        Self::deposit_event(RawEvent::WorkEntrantStakeSlashed(
-                            bounty_id,
-                            *entry_id,
-                            entry.staking_account_id,
-                            slashing_amount
-                        ));
-                    }
-                }
-            }
-            // Fire a judgment event.
-            Self::deposit_event(RawEvent::OracleJudgmentSubmitted(
+            Self::ensure_bounty_stage(current_bounty_stage, BountyStage::Judgment)?;
+
+            let reward_sum = Self::validate_judgment(&bounty_id, &bounty, &judgment)?;
+
+            let judgment_hash = T::Hashing::hash_of(&judgment);
+            let matching_judgments = Self::oracle_panel_members(oracle_panel)
+                .into_iter()
+                .filter(|panel_member_id| {
+                    *panel_member_id == member_id ||
+                        Self::oracle_panel_judgments(bounty_id, *panel_member_id) ==
+                            Some(judgment_hash)
+                })
+                .count();
+            let threshold_reached =
+                matching_judgments >= oracle_panel.threshold.saturated_into::<usize>();
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            <OraclePanelJudgments<T>>::insert(bounty_id, member_id, judgment_hash);
+            <OraclePanelParticipants<T>>::insert(bounty_id, member_id, true);
+
+            Self::deposit_event(RawEvent::OraclePanelJudgmentSubmitted(
                 bounty_id,
-                bounty.creation_params.oracle,
-                judgment,
-                rationale,
+                member_id,
+                judgment.clone(),
+                rationale.clone(),
             ));
+
+            if threshold_reached {
+                Self::clear_oracle_panel_judgments(&bounty_id);
+
+                Self::apply_oracle_judgment(
+                    bounty_id,
+                    &bounty,
+                    &bounty_creator_manager,
+                    judgment,
+                    reward_sum,
+                    rationale,
+                );
+            }
         }
 
         ///Unlocks the stake related to a work entry
@@ -1616,6 +2259,11 @@ decl_module! {
                 bounty.creation_params.oracle.clone(),
             )?;
 
+            ensure!(
+                bounty.creation_params.oracle_panel.is_none(),
+                Error::<T>::BountyIsJudgedByOraclePanel
+            );
+
             let oracle_reward = bounty.creation_params.oracle_reward;
             let current_bounty_stage = Self::get_bounty_stage(&bounty);
 
@@ -1656,6 +2304,287 @@ decl_module! {
             }
         }
 
+        /// Withdraws the oracle reward of a bounty judged by an oracle panel, if it wasn't paid
+        /// out with the judgment. Any oracle panel member (or a former council member that
+        /// participated in the council panel judgment) can withdraw the reward: it is split
+        /// among the participating panel members and the rest is returned to the creator.
+        /// # <weight>
+        ///
+        /// ## weight
+        /// `O (P)`
+        /// - `P` is max oracle panel size
+        /// - db:
+        ///    - `O(P)`
+        /// # </weight>
+        #[weight = WeightInfoBounty::<T>::withdraw_oracle_panel_reward(
+            T::MaxOraclePanelSize::get()
+        )]
+        pub fn withdraw_oracle_panel_reward(
+            origin,
+            member_id: MemberId<T>,
+            bounty_id: T::BountyId,
+        ) {
+            T::Membership::ensure_member_controller_account_origin(origin, member_id)?;
+
+            let bounty = Self::ensure_bounty_exists(&bounty_id)?;
+
+            let oracle_panel = bounty
+                .creation_params
+                .oracle_panel
+                .as_ref()
+                .ok_or(Error::<T>::BountyHasNoOraclePanel)?;
+
+            ensure!(
+                Self::oracle_panel_members(oracle_panel).contains(&member_id) ||
+                    <OraclePanelParticipants<T>>::contains_key(bounty_id, member_id),
+                Error::<T>::NotAnOraclePanelMember
+            );
+
+            let current_bounty_stage = Self::get_bounty_stage(&bounty);
+
+            ensure!(
+                matches!(current_bounty_stage,
+                    BountyStage::FailedBountyWithdrawal |
+                    BountyStage::SuccessfulBountyWithdrawal ),
+                Self::unexpected_bounty_stage_error(current_bounty_stage)
+            );
+            ensure!(bounty.has_unpaid_oracle_reward, Error::<T>::OracleRewardAlreadyWithdrawn);
+
+            let bounty_creator_manager = BountyActorManager::<T>::get_bounty_actor_manager(
+                bounty.creation_params.creator.clone(),
+            )?;
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            // The oracle reward is split among the oracle panel members by participation.
+            Self::pay_oracle_panel_reward(
+                &bounty_id,
+                &bounty,
+                &bounty_creator_manager,
+                oracle_panel
+            );
+
+            if Self::has_no_contributions_and_no_work_entries(&bounty_id) {
+                Self::remove_bounty(
+                    &bounty_id,
+                    &bounty,
+                    &bounty_creator_manager
+                );
+            }
+        }
+
+        /// Disputes the escrowed oracle judgment during the bounty appeal period by staking
+        /// the judgment dispute stake. The judgment can be disputed by a bounty contributor or
+        /// by an entrant slashed by the judgment (providing the slashed work entry).
+        /// # <weight>
+        ///
+        /// ## weight
+        /// `O (N)`
+        /// - `N` is rationale size in kilobytes
+        /// - db:
+        ///    - `O(1)` doesn't depend on the state or parameters
+        /// # </weight>
+        #[weight = WeightInfoBounty::<T>::open_judgment_dispute(
+            to_kb(rationale.len().saturated_into())
+        )]
+        pub fn open_judgment_dispute(
+            origin,
+            member_id: MemberId<T>,
+            bounty_id: T::BountyId,
+            entry_id: Option<T::EntryId>,
+            rationale: Vec<u8>,
+        ) {
+            let account_id =
+                T::Membership::ensure_member_controller_account_origin(origin, member_id)?;
+
+            let bounty = Self::ensure_bounty_exists(&bounty_id)?;
+
+            let current_bounty_stage = Self::get_bounty_stage(&bounty);
+
+            Self::ensure_bounty_stage(current_bounty_stage, BountyStage::JudgmentAppeal)?;
+
+            ensure!(
+                !Self::appeal_period_expired(&bounty),
+                Error::<T>::JudgmentAppealPeriodExpired
+            );
+
+            ensure!(
+                !<JudgmentDisputes<T>>::contains_key(bounty_id),
+                Error::<T>::JudgmentAlreadyDisputed
+            );
+
+            Self::ensure_judgment_disputer(&bounty_id, &bounty, &member_id, entry_id)?;
+
+            let stake = T::JudgmentDisputeStake::get();
+
+            ensure!(
+                Self::check_balance_for_account(stake, &account_id),
+                Error::<T>::InsufficientBalanceForJudgmentDispute
+            );
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            Self::transfer_funds_to_bounty_account(&account_id, bounty_id, stake);
+
+            <JudgmentDisputes<T>>::insert(bounty_id, JudgmentDispute {
+                disputer: member_id,
+                account_id,
+                stake,
+                opened_at: Self::current_block(),
+            });
+
+            Self::deposit_event(RawEvent::JudgmentDisputeOpened(
+                bounty_id,
+                member_id,
+                stake,
+                rationale,
+            ));
+        }
+
+        /// Overturns the disputed oracle judgment (the council via the proposal). The bounty
+        /// fails: entrants can unlock their stakes and contributors can withdraw their funding.
+        /// The dispute stake is returned to the disputer and the oracle reward to the creator.
+        /// # <weight>
+        ///
+        /// ## weight
+        /// `O (1)`
+        /// - db:
+        ///    - `O(1)` doesn't depend on the state or parameters
+        /// # </weight>
+        #[weight = WeightInfoBounty::<T>::overturn_oracle_judgment()]
+        pub fn overturn_oracle_judgment(origin, bounty_id: T::BountyId) {
+            ensure_root(origin)?;
+
+            let bounty = Self::ensure_bounty_exists(&bounty_id)?;
+
+            let current_bounty_stage = Self::get_bounty_stage(&bounty);
+
+            Self::ensure_bounty_stage(current_bounty_stage, BountyStage::JudgmentAppeal)?;
+
+            let dispute = Self::judgment_disputes(bounty_id)
+                .ok_or(Error::<T>::JudgmentIsNotDisputed)?;
+
+            ensure!(
+                !Self::judgment_dispute_period_expired(&dispute),
+                Error::<T>::JudgmentDisputePeriodExpired
+            );
+
+            let bounty_creator_manager = Self::ensure_creator_actor_manager(&bounty)?;
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            // Escrowed judgments are removed along with the work entries.
+            <JudgmentDisputes<T>>::remove(bounty_id);
+
+            Self::transfer_funds_from_bounty_account(&dispute.account_id, bounty_id, dispute.stake);
+
+            if bounty.has_unpaid_oracle_reward {
+                bounty_creator_manager.transfer_funds_from_bounty_account(
+                    bounty_id,
+                    bounty.creation_params.oracle_reward
+                );
+            }
+
+            <Bounties<T>>::mutate(bounty_id, |bounty| {
+                bounty.milestone = BountyMilestone::JudgmentSubmitted { successful_bounty: false };
+                bounty.has_unpaid_oracle_reward = false;
+            });
+
+            Self::deposit_event(RawEvent::OracleJudgmentOverturned(bounty_id));
+        }
+
+        /// Finalizes the escrowed oracle judgment after the appeal period (and the dispute period
+        /// of an open dispute): rewards the winners and slashes the rejected entries.
+        /// The stake of the dispute that didn't overturn the judgment is slashed.
+        /// # <weight>
+        ///
+        /// ## weight
+        /// `O (E)`
+        /// - `E` is number of the judged work entries
+        /// - db:
+        ///    - `O(E)`
+        /// # </weight>
+        #[weight = WeightInfoBounty::<T>::finalize_oracle_judgment(*judged_entries_witness)]
+        pub fn finalize_oracle_judgment(
+            origin,
+            bounty_id: T::BountyId,
+            judged_entries_witness: u32,
+        ) {
+            ensure_signed(origin)?;
+
+            let bounty = Self::ensure_bounty_exists(&bounty_id)?;
+
+            let current_bounty_stage = Self::get_bounty_stage(&bounty);
+
+            Self::ensure_bounty_stage(current_bounty_stage, BountyStage::JudgmentAppeal)?;
+
+            ensure!(
+                Self::appeal_period_expired(&bounty),
+                Error::<T>::JudgmentAppealPeriodNotExpired
+            );
+
+            let dispute = Self::judgment_disputes(bounty_id);
+
+            if let Some(ref dispute) = dispute {
+                ensure!(
+                    Self::judgment_dispute_period_expired(dispute),
+                    Error::<T>::JudgmentDisputePeriodNotExpired
+                );
+            }
+
+            let judgment: OracleJudgmentOf<T> = <EscrowedJudgments<T>>::iter_prefix(bounty_id)
+                .map(|(entry_id, work_entry_judgment)| (entry_id, work_entry_judgment.into()))
+                .collect();
+
+            ensure!(
+                judgment.len() == judged_entries_witness.saturated_into::<usize>(),
+                Error::<T>::InvalidJudgedEntriesWitness
+            );
+
+            let reward_sum = Self::judgment_reward_sum(&judgment);
+
+            Self::ensure_judgment_payouts(&bounty_id, &bounty, &judgment)?;
+
+            let bounty_creator_manager = Self::ensure_creator_actor_manager(&bounty)?;
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            if let Some(dispute) = dispute {
+                <JudgmentDisputes<T>>::remove(bounty_id);
+
+                let _ = <balances::Pallet<T> as Currency<T::AccountId>>::slash(
+                    &Self::bounty_account_id(bounty_id),
+                    dispute.stake,
+                );
+
+                Self::deposit_event(RawEvent::JudgmentDisputeStakeSlashed(
+                    bounty_id,
+                    dispute.disputer,
+                    dispute.stake,
+                ));
+            }
+
+            Self::execute_oracle_judgment(
+                bounty_id,
+                &bounty,
+                &bounty_creator_manager,
+                &judgment,
+                reward_sum,
+            );
+
+            Self::deposit_event(RawEvent::OracleJudgmentFinalized(bounty_id));
+
+            Self::complete_oracle_judgment(bounty_id, &bounty, &bounty_creator_manager, &judgment);
+        }
+
         /// Bounty Contributor made a remark
         ///
         /// # <weight>
@@ -1806,9 +2735,14 @@ impl<T: Config> Module<T> {
         funding: Contribution<T>,
     ) {
         let cherry_fraction = Self::get_cherry_fraction_for_member(bounty, funding.amount);
+        // Unspent creator token funding is released from the escrow on validation.
+        let unspent_funding = match bounty.creation_params.token_id {
+            Some(_) => Zero::zero(),
+            None => Self::get_unspent_funding_for_member(bounty, funding.amount),
+        };
 
-        let withdrawal_amount = funding
-            .total_bloat_bond_and_funding()
+        let withdrawal_amount = unspent_funding
+            .saturating_add(funding.funder_state_bloat_bond_amount)
             .saturating_add(cherry_fraction);
 
         bounty_funder_manager.transfer_funds_from_bounty_account(*bounty_id, withdrawal_amount);
@@ -1824,6 +2758,37 @@ impl<T: Config> Module<T> {
         Self::deposit_event(RawEvent::BountyFundingWithdrawal(*bounty_id, funder));
     }
 
+    // Releases the unspent creator token funding of a failed bounty to the funder.
+    fn release_unspent_token_funding(
+        bounty_id: T::BountyId,
+        bounty: &Bounty<T>,
+        funder: &BountyActor<MemberId<T>>,
+        funding_amount: BalanceOf<T>,
+    ) -> DispatchResult {
+        if let (Some(token_id), BountyActor::Member(member_id)) =
+            (bounty.creation_params.token_id, funder)
+        {
+            let unspent_funding = Self::get_unspent_funding_for_member(bounty, funding_amount);
+
+            if !unspent_funding.is_zero() {
+                T::ProjectToken::release_escrowed_tokens(
+                    token_id,
+                    Self::bounty_account_id(bounty_id),
+                    *member_id,
+                    Self::to_token_balance(unspent_funding)?,
+                )?;
+            }
+        }
+
+        Ok(())
+    }
+
+    // Converts the bounty balance to the creator token balance.
+    fn to_token_balance(amount: BalanceOf<T>) -> Result<TokenBalanceOf<T>, DispatchError> {
+        let amount: u128 = amount.try_into().map_err(|_| Error::<T>::ArithmeticError)?;
+        TokenBalanceOf::<T>::try_from(amount).map_err(|_| Error::<T>::ArithmeticError.into())
+    }
+
     fn withdraw_funding_state_bloat_bond_mutation(
         bounty_id: &T::BountyId,
         funder: BountyActor<MemberId<T>>,
@@ -1914,6 +2879,96 @@ impl<T: Config> Module<T> {
             );
         }
 
+        Self::ensure_delivery_milestones_valid(&params.delivery_milestones)?;
+
+        if let Some(token_id) = params.token_id {
+            ensure!(
+                T::ProjectToken::token_exists(token_id),
+                Error::<T>::CreatorTokenDoesNotExist
+            );
+        }
+
+        ensure!(
+            params.appeal_period.is_zero() || params.delivery_milestones.is_empty(),
+            Error::<T>::AppealPeriodNotSupportedWithDeliveryMilestones
+        );
+
+        if let Some(ref oracle_panel) = params.oracle_panel {
+            let panel_size = if oracle_panel.council {
+                ensure!(
+                    oracle_panel.members.is_empty(),
+                    Error::<T>::CouncilOraclePanelCannotHaveMembers
+                );
+
+                T::CouncilMembers::council_member_ids().len()
+            } else {
+                oracle_panel.members.len()
+            };
+
+            ensure!(panel_size > 0, Error::<T>::OraclePanelIsEmpty);
+
+            ensure!(
+                panel_size <= T::MaxOraclePanelSize::get().saturated_into(),
+                Error::<T>::OraclePanelIsTooLarge
+            );
+
+            ensure!(
+                oracle_panel.threshold > 0
+                    && oracle_panel.threshold.saturated_into::<usize>() <= panel_size,
+                Error::<T>::InvalidOraclePanelThreshold
+            );
+
+            ensure!(
+                !oracle_panel.judging_period.is_zero(),
+                Error::<T>::OraclePanelJudgingPeriodCannotBeZero
+            );
+
+            for member_id in oracle_panel.members.iter() {
+                ensure!(
+                    T::Membership::controller_account_id(*member_id).is_ok(),
+                    Error::<T>::InvalidOraclePanelMemberId
+                );
+            }
+        }
+
+        Ok(())
+    }
+
+    // Validates delivery milestones for a bounty creation. No milestones means a single delivery.
+    fn ensure_delivery_milestones_valid(
+        delivery_milestones: &[DeliveryMilestone<T::BlockNumber>],
+    ) -> DispatchResult {
+        if delivery_milestones.is_empty() {
+            return Ok(());
+        }
+
+        ensure!(
+            delivery_milestones.len() <= T::MaxDeliveryMilestones::get().saturated_into(),
+            Error::<T>::TooManyDeliveryMilestones
+        );
+
+        let mut total_funding_share: u64 = 0;
+        for delivery_milestone in delivery_milestones {
+            ensure!(
+                !delivery_milestone.funding_share.is_zero(),
+                Error::<T>::InvalidDeliveryMilestonesFundingShares
+            );
+
+            ensure!(
+                !delivery_milestone.work_period.is_zero()
+                    && !delivery_milestone.judging_period.is_zero(),
+                Error::<T>::DeliveryMilestonePeriodCannotBeZero
+            );
+
+            total_funding_share = total_funding_share
+                .saturating_add(delivery_milestone.funding_share.deconstruct().into());
+        }
+
+        ensure!(
+            total_funding_share == u64::from(Perbill::one().deconstruct()),
+            Error::<T>::InvalidDeliveryMilestonesFundingShares
+        );
+
         Ok(())
     }
 
@@ -2056,6 +3111,53 @@ impl<T: Config> Module<T> {
         funding_share * bounty.creation_params.cherry
     }
 
+    // Calculate the unspent funding fraction to refund to a member for an unsuccessful bounty.
+    // Unspent funding fraction = (total funding - paid out) * (member funding / total funding).
+    fn get_unspent_funding_for_member(
+        bounty: &Bounty<T>,
+        funding_amount: BalanceOf<T>,
+    ) -> BalanceOf<T> {
+        let paid_out = bounty.delivery_progress.paid_out;
+
+        // Nothing was paid out - the full funding is refunded.
+        if paid_out.is_zero() {
+            return funding_amount;
+        }
+
+        let funding_share = Perbill::from_rational(funding_amount, bounty.total_funding);
+
+        funding_share * bounty.total_funding.saturating_sub(paid_out)
+    }
+
+    // Calculates the funding rewarded to the winners of the current delivery milestone.
+    // Returns None for a bounty without delivery milestones.
+    pub(crate) fn current_delivery_milestone_funding(bounty: &Bounty<T>) -> Option<BalanceOf<T>> {
+        let index: usize = bounty.delivery_progress.current_milestone.saturated_into();
+        let delivery_milestone = bounty.creation_params.delivery_milestones.get(index)?;
+
+        // The last delivery milestone gets the rest to avoid rounding leftovers.
+        if Self::has_next_delivery_milestone(bounty) {
+            Some(delivery_milestone.funding_share * bounty.total_funding)
+        } else {
+            Some(
+                bounty
+                    .total_funding
+                    .saturating_sub(bounty.delivery_progress.paid_out),
+            )
+        }
+    }
+
+    // Checks whether the current delivery milestone of a bounty is followed by another one.
+    pub(crate) fn has_next_delivery_milestone(bounty: &Bounty<T>) -> bool {
+        let next_index: usize = bounty
+            .delivery_progress
+            .current_milestone
+            .saturating_add(1)
+            .saturated_into();
+
+        next_index < bounty.creation_params.delivery_milestones.len()
+    }
+
     /// Remove bounty and all related info from the storage.
     fn remove_bounty(
         bounty_id: &T::BountyId,
@@ -2071,11 +3173,168 @@ impl<T: Config> Module<T> {
             T::CreatorStateBloatBondAmount::get(),
         ));
 
+        if bounty.creation_params.oracle_panel.is_some() {
+            Self::clear_oracle_panel_judgments(bounty_id);
+
+            <OraclePanelParticipants<T>>::drain_prefix(bounty_id).for_each(drop);
+        }
+
         <Bounties<T>>::remove(bounty_id);
 
         Self::deposit_event(RawEvent::BountyRemoved(*bounty_id));
     }
 
+    // Verifies that the member is a bounty contributor or an entrant slashed by the escrowed
+    // oracle judgment.
+    fn ensure_judgment_disputer(
+        bounty_id: &T::BountyId,
+        bounty: &Bounty<T>,
+        member_id: &MemberId<T>,
+        entry_id: Option<T::EntryId>,
+    ) -> DispatchResult {
+        let eligible = match entry_id {
+            Some(entry_id) => {
+                let entry = Self::ensure_work_entry_exists(bounty_id, &entry_id)?;
+
+                Self::ensure_work_entry_ownership(&entry, member_id)?;
+
+                match Self::escrowed_judgments(bounty_id, entry_id) {
+                    Some(EscrowedWorkEntryJudgment::Rejected { slashing_share }) => {
+                        !(slashing_share * bounty.creation_params.entrant_stake).is_zero()
+                    }
+                    _ => false,
+                }
+            }
+            None => {
+                <BountyContributions<T>>::contains_key(bounty_id, BountyActor::Member(*member_id))
+            }
+        };
+
+        ensure!(eligible, Error::<T>::NotEligibleToDisputeJudgment);
+
+        Ok(())
+    }
+
+    // Checks whether the appeal period of the escrowed oracle judgment is over.
+    fn appeal_period_expired(bounty: &Bounty<T>) -> bool {
+        match bounty.milestone {
+            BountyMilestone::JudgmentEscrowed { judged_at } => {
+                judged_at.saturating_add(bounty.creation_params.appeal_period)
+                    < Self::current_block()
+            }
+            _ => false,
+        }
+    }
+
+    // Checks whether the council can no longer overturn the disputed oracle judgment.
+    fn judgment_dispute_period_expired(dispute: &JudgmentDisputeOf<T>) -> bool {
+        dispute
+            .opened_at
+            .saturating_add(T::JudgmentDisputePeriod::get())
+            < Self::current_block()
+    }
+
+    // Calculates the total reward of the judgment winners.
+    fn judgment_reward_sum(judgment: &OracleJudgmentOf<T>) -> BalanceOf<T> {
+        judgment
+            .values()
+            .fold(
+                Zero::zero(),
+                |reward_sum, work_entry_judgment| match work_entry_judgment {
+                    OracleWorkEntryJudgment::Winner { reward } => {
+                        reward_sum.saturating_add(*reward)
+                    }
+                    OracleWorkEntryJudgment::Rejected { .. } => reward_sum,
+                },
+            )
+    }
+
+    // Verifies that the member belongs to the bounty oracle panel. Returns the panel.
+    fn ensure_oracle_panel_member<'a>(
+        bounty: &'a Bounty<T>,
+        member_id: &MemberId<T>,
+    ) -> Result<&'a OraclePanelOf<T>, DispatchError> {
+        let oracle_panel = bounty
+            .creation_params
+            .oracle_panel
+            .as_ref()
+            .ok_or(Error::<T>::BountyHasNoOraclePanel)?;
+
+        ensure!(
+            Self::oracle_panel_members(oracle_panel).contains(member_id),
+            Error::<T>::NotAnOraclePanelMember
+        );
+
+        Ok(oracle_panel)
+    }
+
+    // Returns the members of the oracle panel: the current council members for the council panel.
+    fn oracle_panel_members(oracle_panel: &OraclePanelOf<T>) -> Vec<MemberId<T>> {
+        if oracle_panel.council {
+            T::CouncilMembers::council_member_ids()
+        } else {
+            oracle_panel.members.iter().copied().collect()
+        }
+    }
+
+    // Removes the oracle panel judgments of the finished judging round, including the judgments
+    // of the former council members for the council panel.
+    fn clear_oracle_panel_judgments(bounty_id: &T::BountyId) {
+        <OraclePanelJudgments<T>>::drain_prefix(bounty_id).for_each(drop);
+    }
+
+    // Splits the oracle reward among the oracle panel members in equal shares. Only the members
+    // that submitted their judgments are paid, the rest of the reward is withheld and returned to
+    // the bounty creator.
+    fn pay_oracle_panel_reward(
+        bounty_id: &T::BountyId,
+        bounty: &Bounty<T>,
+        bounty_creator_manager: &BountyActorManager<T>,
+        oracle_panel: &OraclePanelOf<T>,
+    ) {
+        let participants = <OraclePanelParticipants<T>>::iter_prefix(bounty_id)
+            .map(|(member_id, _)| member_id)
+            .collect::<Vec<_>>();
+
+        // Former council members could participate in the council panel judgment.
+        let panel_size: BalanceOf<T> = Self::oracle_panel_members(oracle_panel)
+            .len()
+            .max(participants.len())
+            .saturated_into();
+
+        let oracle_reward = bounty.creation_params.oracle_reward;
+        let reward_share = oracle_reward
+            .checked_div(&panel_size)
+            .unwrap_or_else(Zero::zero);
+        let mut withheld_reward = oracle_reward;
+
+        for member_id in participants {
+            if let Ok(account_id) = T::Membership::controller_account_id(member_id) {
+                Self::transfer_funds_from_bounty_account(&account_id, *bounty_id, reward_share);
+                withheld_reward = withheld_reward.saturating_sub(reward_share);
+
+                Self::deposit_event(RawEvent::BountyOracleRewardWithdrawal(
+                    *bounty_id,
+                    BountyActor::Member(member_id),
+                    reward_share,
+                ));
+            }
+        }
+
+        if !withheld_reward.is_zero() {
+            bounty_creator_manager.transfer_funds_from_bounty_account(*bounty_id, withheld_reward);
+
+            Self::deposit_event(RawEvent::OraclePanelRewardWithheld(
+                *bounty_id,
+                withheld_reward,
+            ));
+        }
+
+        <Bounties<T>>::mutate(bounty_id, |bounty| {
+            bounty.has_unpaid_oracle_reward = false;
+        });
+    }
+
     // Verifies that the bounty has no pending fund withdrawals left.
     fn has_no_contributions_and_no_work_entries(bounty_id: &T::BountyId) -> bool {
         let has_no_contributions = !Self::contributions_exist(bounty_id);
@@ -2102,7 +3361,7 @@ impl<T: Config> Module<T> {
 
     // The account ID of a bounty account. Tests require AccountID type to be at least u128.
     pub(crate) fn bounty_account_id(bounty_id: T::BountyId) -> T::AccountId {
-        T::ModuleId::get().into_sub_account_truncating(bounty_id)
+        <T as Config>::ModuleId::get().into_sub_account_truncating(bounty_id)
     }
 
     // Calculates bounty milestone on member funding.
@@ -2134,7 +3393,7 @@ impl<T: Config> Module<T> {
         bounty_id: &T::BountyId,
         bounty: &Bounty<T>,
         funder: &BountyActor<MemberId<T>>,
-        amount: T::Balance,
+        amount: BalanceOf<T>,
         is_target_funding_reached: bool,
     ) -> (Contribution<T>, BalanceOf<T>, BalanceOf<T>) {
         //The contribution should be saturated to the target funding,
@@ -2217,13 +3476,20 @@ impl<T: Config> Module<T> {
 
     // Validates the contract type for a bounty
     fn ensure_valid_contract_type(bounty: &Bounty<T>, member_id: &MemberId<T>) -> DispatchResult {
-        if let AssuranceContractType::Closed(ref valid_members) =
-            bounty.creation_params.contract_type
-        {
-            ensure!(
-                valid_members.contains(member_id),
-                Error::<T>::CannotSubmitWorkToClosedContractBounty
-            );
+        match bounty.creation_params.contract_type {
+            AssuranceContractType::Open => {}
+            AssuranceContractType::Closed(ref valid_members) => {
+                ensure!(
+                    valid_members.contains(member_id),
+                    Error::<T>::CannotSubmitWorkToClosedContractBounty
+                );
+            }
+            AssuranceContractType::Attested(kind) => {
+                ensure!(
+                    T::Membership::has_valid_attestation(*member_id, kind),
+                    Error::<T>::CannotSubmitWorkWithoutAttestation
+                );
+            }
         }
 
         Ok(())
@@ -2239,12 +3505,251 @@ impl<T: Config> Module<T> {
         sc.get_bounty_stage()
     }
 
-    // Validates oracle judgment.
-    fn validate_judgment(
+    // Applies the oracle (or oracle panel) judgment. The judgment of a bounty with an appeal
+    // period is held in escrow, otherwise it is executed immediately.
+    fn apply_oracle_judgment(
+        bounty_id: T::BountyId,
+        bounty: &Bounty<T>,
+        bounty_creator_manager: &BountyActorManager<T>,
+        judgment: OracleJudgmentOf<T>,
+        reward_sum: BalanceOf<T>,
+        rationale: Vec<u8>,
+    ) {
+        let escrowed = !bounty.creation_params.appeal_period.is_zero();
+
+        if escrowed {
+            Self::escrow_oracle_judgment(bounty_id, &judgment);
+        } else {
+            Self::execute_oracle_judgment(
+                bounty_id,
+                bounty,
+                bounty_creator_manager,
+                &judgment,
+                reward_sum,
+            );
+        }
+
+        // Fire a judgment event.
+        Self::deposit_event(RawEvent::OracleJudgmentSubmitted(
+            bounty_id,
+            bounty.creation_params.oracle.clone(),
+            judgment.clone(),
+            rationale,
+        ));
+
+        if !escrowed {
+            Self::complete_oracle_judgment(bounty_id, bounty, bounty_creator_manager, &judgment);
+        }
+    }
+
+    // Holds the oracle judgment in escrow until it is finalized or overturned.
+    fn escrow_oracle_judgment(bounty_id: T::BountyId, judgment: &OracleJudgmentOf<T>) {
+        for (entry_id, work_entry_judgment) in judgment.iter() {
+            <EscrowedJudgments<T>>::insert(
+                bounty_id,
+                entry_id,
+                EscrowedWorkEntryJudgment::from(work_entry_judgment),
+            );
+        }
+
+        <Bounties<T>>::mutate(bounty_id, |bounty| {
+            bounty.milestone = BountyMilestone::JudgmentEscrowed {
+                judged_at: Self::current_block(),
+            };
+        });
+    }
+
+    // Rewards the winners, slashes the rejected entries and either moves the bounty to the next
+    // delivery milestone or completes the judgment. The judged work entries and the winner
+    // payouts must be validated before the judgment execution.
+    fn execute_oracle_judgment(
+        bounty_id: T::BountyId,
+        bounty: &Bounty<T>,
+        bounty_creator_manager: &BountyActorManager<T>,
+        judgment: &OracleJudgmentOf<T>,
+        reward_sum: BalanceOf<T>,
+    ) {
+        // Lookup for any winners in the judgment.
+        let successful_bounty = Self::judgment_has_winners(judgment);
+
+        // Successful judgment of a delivery milestone followed by another one.
+        let next_delivery_milestone =
+            successful_bounty && Self::has_next_delivery_milestone(bounty);
+
+        // Return a cherry to a creator.
+        if successful_bounty && !next_delivery_milestone {
+            Self::return_bounty_cherry_to_creator(bounty_id, bounty, bounty_creator_manager);
+        }
+
+        // Update bounty record.
+        <Bounties<T>>::mutate(bounty_id, |bounty| {
+            let progress = &mut bounty.delivery_progress;
+            progress.paid_out = progress.paid_out.saturating_add(reward_sum);
+
+            if next_delivery_milestone {
+                bounty.milestone = BountyMilestone::DeliveryMilestoneJudged;
+                progress.current_milestone = progress.current_milestone.saturating_add(1);
+                progress.work_started_at = Some(Self::current_block());
+                progress.work_ended_at = None;
+            } else {
+                bounty.milestone = BountyMilestone::JudgmentSubmitted { successful_bounty };
+            }
+        });
+
+        // Judgments triage.
+        for (entry_id, work_entry_judgment) in judgment.iter() {
+            let entry = match Self::entries(bounty_id, entry_id) {
+                Some(entry) => entry,
+                None => continue,
+            };
+
+            // Update work entries for winners.
+            match *work_entry_judgment {
+                OracleWorkEntryJudgment::Winner { reward } => {
+                    // Claim the winner reward.
+                    Self::pay_winner_reward(bounty_id, bounty, entry.member_id, reward);
+
+                    // The winner keeps the work entry and the stake for the next delivery
+                    // milestone and has to submit the work again.
+                    if next_delivery_milestone {
+                        <Entries<T>>::mutate(bounty_id, entry_id, |entry| {
+                            if let Some(e) = entry.as_mut() {
+                                e.work_submitted = false;
+                            };
+                        });
+
+                        continue;
+                    }
+
+                    // Unstake the full work entry state.
+                    T::StakingHandler::unlock(&entry.staking_account_id);
+
+                    // Delete the work entry record from the storage.
+                    Self::remove_work_entry(&bounty_id, entry_id);
+
+                    // Fire an event.
+                    Self::deposit_event(RawEvent::WorkEntrantFundsWithdrawn(
+                        bounty_id,
+                        *entry_id,
+                        entry.member_id,
+                    ));
+                }
+                OracleWorkEntryJudgment::Rejected { slashing_share, .. } => {
+                    let slashing_amount = slashing_share * bounty.creation_params.entrant_stake;
+
+                    if slashing_amount > Zero::zero() {
+                        T::StakingHandler::slash(&entry.staking_account_id, Some(slashing_amount));
+                    }
+
+                    T::StakingHandler::unlock(&entry.staking_account_id);
+
+                    Self::remove_work_entry(&bounty_id, entry_id);
+
+                    // Fire a WorkEntrantStakeSlashed event.
+                    Self::deposit_event(RawEvent::WorkEntrantStakeSlashed(
+                        bounty_id,
+                        *entry_id,
+                        entry.staking_account_id,
+                        slashing_amount,
+                    ));
+                }
+            }
+        }
+    }
+
+    // Pays the winner reward in JOY or in the creator token of the bounty. The payout must be
+    // validated with `ensure_judgment_payouts` beforehand.
+    fn pay_winner_reward(
+        bounty_id: T::BountyId,
+        bounty: &Bounty<T>,
+        member_id: MemberId<T>,
+        reward: BalanceOf<T>,
+    ) {
+        match bounty.creation_params.token_id {
+            Some(token_id) => {
+                if let Ok(amount) = Self::to_token_balance(reward) {
+                    let _ = T::ProjectToken::release_escrowed_tokens(
+                        token_id,
+                        Self::bounty_account_id(bounty_id),
+                        member_id,
+                        amount,
+                    );
+                }
+            }
+            None => {
+                if let Ok(worker_account_id) = T::Membership::controller_account_id(member_id) {
+                    Self::transfer_funds_from_bounty_account(&worker_account_id, bounty_id, reward);
+                }
+            }
+        }
+    }
+
+    // Verifies that the judged work entries exist and the winner rewards can be paid out,
+    // so that the judgment execution cannot fail.
+    fn ensure_judgment_payouts(
         bounty_id: &T::BountyId,
         bounty: &Bounty<T>,
         judgment: &OracleJudgmentOf<T>,
     ) -> DispatchResult {
+        for (entry_id, work_entry_judgment) in judgment.iter() {
+            let entry = Self::ensure_work_entry_exists(bounty_id, entry_id)?;
+
+            if let OracleWorkEntryJudgment::Winner { reward } = work_entry_judgment {
+                match bounty.creation_params.token_id {
+                    Some(token_id) => T::ProjectToken::ensure_can_release_escrowed_tokens(
+                        token_id,
+                        Self::bounty_account_id(*bounty_id),
+                        entry.member_id,
+                        Self::to_token_balance(*reward)?,
+                    )?,
+                    None => {
+                        T::Membership::controller_account_id(entry.member_id)?;
+                    }
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    // Fires the delivery milestone event and rewards the oracle panel after the judgment
+    // execution.
+    fn complete_oracle_judgment(
+        bounty_id: T::BountyId,
+        bounty: &Bounty<T>,
+        bounty_creator_manager: &BountyActorManager<T>,
+        judgment: &OracleJudgmentOf<T>,
+    ) {
+        let successful_bounty = Self::judgment_has_winners(judgment);
+        let next_delivery_milestone =
+            successful_bounty && Self::has_next_delivery_milestone(bounty);
+
+        if successful_bounty && !bounty.creation_params.delivery_milestones.is_empty() {
+            Self::deposit_event(RawEvent::DeliveryMilestoneCompleted(
+                bounty_id,
+                bounty.delivery_progress.current_milestone,
+            ));
+        }
+
+        // Oracle panel members that participated are rewarded once the judgment is completed.
+        if let Some(ref oracle_panel) = bounty.creation_params.oracle_panel {
+            if !next_delivery_milestone && bounty.has_unpaid_oracle_reward {
+                Self::pay_oracle_panel_reward(
+                    &bounty_id,
+                    bounty,
+                    bounty_creator_manager,
+                    oracle_panel,
+                );
+            }
+        }
+    }
+
+    // Validates oracle judgment. Returns the total judgment reward.
+    fn validate_judgment(
+        bounty_id: &T::BountyId,
+        bounty: &Bounty<T>,
+        judgment: &OracleJudgmentOf<T>,
+    ) -> Result<BalanceOf<T>, DispatchError> {
         // Total judgment reward accumulator.
         let mut reward_sum_from_judgment: BalanceOf<T> = Zero::zero();
 
@@ -2265,20 +3770,30 @@ impl<T: Config> Module<T> {
             }
         }
 
+        // Check the winners can receive the rewards.
+        Self::ensure_judgment_payouts(bounty_id, bounty, judgment)?;
+
         // Check for invalid total sum for successful bounty.
         if reward_sum_from_judgment != Zero::zero() {
-            ensure!(
-                reward_sum_from_judgment == bounty.total_funding, // 100% bounty distribution
-                Error::<T>::TotalRewardShouldBeEqualToTotalFunding
-            );
+            match Self::current_delivery_milestone_funding(bounty) {
+                Some(delivery_milestone_funding) => ensure!(
+                    reward_sum_from_judgment == delivery_milestone_funding,
+                    Error::<T>::TotalRewardShouldBeEqualToDeliveryMilestoneFunding
+                ),
+                None => ensure!(
+                    reward_sum_from_judgment == bounty.total_funding, // 100% bounty distribution
+                    Error::<T>::TotalRewardShouldBeEqualToTotalFunding
+                ),
+            }
         }
 
-        Ok(())
+        Ok(reward_sum_from_judgment)
     }
 
     // Removes the work entry and decrements active entry count in a bounty.
     fn remove_work_entry(bounty_id: &T::BountyId, entry_id: &T::EntryId) {
         <Entries<T>>::remove(bounty_id, entry_id);
+        <EscrowedJudgments<T>>::remove(bounty_id, entry_id);
 
         // Decrement work entry counter and update bounty record.
         <Bounties<T>>::mutate(bounty_id, |bounty| {
@@ -2308,6 +3823,7 @@ impl<T: Config> Module<T> {
             }
             BountyStage::WorkSubmission => Error::<T>::InvalidStageUnexpectedWorkSubmission.into(),
             BountyStage::Judgment => Error::<T>::InvalidStageUnexpectedJudgment.into(),
+            BountyStage::JudgmentAppeal => Error::<T>::InvalidStageUnexpectedJudgmentAppeal.into(),
             BountyStage::SuccessfulBountyWithdrawal => {
                 Error::<T>::InvalidStageUnexpectedSuccessfulBountyWithdrawal.into()
             }
@@ -0,0 +1,89 @@
+// Migrations for Bounty Pallet
+
+use super::*;
+use frame_support::traits::{GetStorageVersion, OnRuntimeUpgrade};
+use frame_support::IterableStorageMap;
+use sp_std::marker::PhantomData;
+
+pub mod v1 {
+    use super::*;
+
+    /// Bounty creation parameters stored before the delivery milestones, the oracle panel,
+    /// the judgment appeals and the creator token bounties were introduced.
+    #[derive(Encode, Decode)]
+    pub struct OldBountyParameters<Balance, BlockNumber, MemberId: Ord, ClosedContractWhitelist> {
+        pub oracle: BountyActor<MemberId>,
+        pub contract_type: AssuranceContractType<ClosedContractWhitelist>,
+        pub creator: BountyActor<MemberId>,
+        pub cherry: Balance,
+        pub oracle_reward: Balance,
+        pub entrant_stake: Balance,
+        pub funding_type: FundingType<BlockNumber, Balance>,
+    }
+
+    /// Bounty stored before the delivery progress was tracked.
+    #[derive(Encode, Decode)]
+    pub struct OldBountyRecord<Balance, BlockNumber, MemberId: Ord, ClosedContractWhitelist> {
+        pub creation_params:
+            OldBountyParameters<Balance, BlockNumber, MemberId, ClosedContractWhitelist>,
+        pub total_funding: Balance,
+        pub milestone: BountyMilestone<BlockNumber>,
+        pub active_work_entry_count: u32,
+        pub has_unpaid_oracle_reward: bool,
+    }
+
+    pub type OldBounty<T> = OldBountyRecord<
+        BalanceOf<T>,
+        <T as frame_system::Config>::BlockNumber,
+        <T as MembershipTypes>::MemberId,
+        ClosedContractWhitelist<T>,
+    >;
+
+    /// Translates the existing bounties into bounties without delivery milestones, judged
+    /// by their oracle alone and without an appeal period, funded and paid out in JOY.
+    pub struct MigrateToV1<T>(PhantomData<T>);
+
+    impl<T: Config> OnRuntimeUpgrade for MigrateToV1<T> {
+        fn on_runtime_upgrade() -> Weight {
+            let onchain = Pallet::<T>::on_chain_storage_version();
+            let current = Pallet::<T>::current_storage_version();
+
+            if onchain > 0 {
+                return T::DbWeight::get().reads(1);
+            }
+
+            let mut translated = 0u64;
+
+            <Bounties<T>>::translate::<OldBounty<T>, _>(|_, old| {
+                translated = translated.saturating_add(1);
+
+                let params = old.creation_params;
+                Some(Bounty::<T> {
+                    creation_params: BountyParameters {
+                        oracle: params.oracle,
+                        contract_type: params.contract_type,
+                        creator: params.creator,
+                        cherry: params.cherry,
+                        oracle_reward: params.oracle_reward,
+                        entrant_stake: params.entrant_stake,
+                        funding_type: params.funding_type,
+                        delivery_milestones: Default::default(),
+                        oracle_panel: None,
+                        appeal_period: Zero::zero(),
+                        token_id: None,
+                    },
+                    total_funding: old.total_funding,
+                    milestone: old.milestone,
+                    active_work_entry_count: old.active_work_entry_count,
+                    has_unpaid_oracle_reward: old.has_unpaid_oracle_reward,
+                    delivery_progress: Default::default(),
+                })
+            });
+
+            current.put::<Pallet<T>>();
+
+            T::DbWeight::get()
+                .reads_writes(translated.saturating_add(1), translated.saturating_add(1))
+        }
+    }
+}
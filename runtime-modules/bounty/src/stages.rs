@@ -1,8 +1,9 @@
 //! This module contains the BountyStageCalculator - a bounty stage calculation helper.
 //! It allows to get a bounty stage based on the current bounty state and the current system block.
 
-use crate::{Bounty, BountyMilestone, BountyStage, Config, FundingType};
+use crate::{Bounty, BountyMilestone, BountyStage, Config, DeliveryMilestone, FundingType};
 use sp_arithmetic::traits::Saturating;
+use sp_runtime::SaturatedConversion;
 
 // Bounty stage helper.
 pub(crate) struct BountyStageCalculator<'a, T: Config> {
@@ -17,8 +18,10 @@ impl<'a, T: Config> BountyStageCalculator<'a, T> {
     pub(crate) fn get_bounty_stage(&self) -> BountyStage {
         self.is_funding_stage()
             .or_else(|| self.is_funding_expired_stage())
+            .or_else(|| self.is_delivery_milestone_stage())
             .or_else(|| self.is_work_submission_stage())
             .or_else(|| self.is_judgment_stage())
+            .or_else(|| self.is_judgment_appeal_stage())
             .or_else(|| self.is_successful_bounty_withdrawal_stage())
             .unwrap_or(BountyStage::FailedBountyWithdrawal)
     }
@@ -55,6 +58,52 @@ impl<'a, T: Config> BountyStageCalculator<'a, T> {
         }
     }
 
+    // Calculates the stage of the bounty within its current delivery milestone.
+    // Returns None for a bounty without delivery milestones or if conditions are not met.
+    fn is_delivery_milestone_stage(&self) -> Option<BountyStage> {
+        let delivery_milestone = self.current_delivery_milestone()?;
+        let progress = &self.bounty.delivery_progress;
+
+        let work_ended_at = match self.bounty.milestone.clone() {
+            // The oracle ended the work period.
+            BountyMilestone::WorkSubmitted => progress.work_ended_at?,
+            // Work on the current delivery milestone started.
+            BountyMilestone::Created { created_at, .. }
+            | BountyMilestone::BountyMaxFundingReached
+            | BountyMilestone::DeliveryMilestoneJudged => {
+                let work_started_at = match progress.work_started_at {
+                    Some(work_started_at) => work_started_at,
+                    // Limited funding period is over and the target funding was reached.
+                    None => match self.bounty.creation_params.funding_type {
+                        FundingType::Limited { funding_period, .. }
+                            if self.target_funding_reached()
+                                && self.funding_period_expired(created_at) =>
+                        {
+                            created_at.saturating_add(funding_period)
+                        }
+                        _ => return None,
+                    },
+                };
+
+                let work_ended_at = work_started_at.saturating_add(delivery_milestone.work_period);
+                if self.now <= work_ended_at {
+                    return Some(BountyStage::WorkSubmission);
+                }
+
+                work_ended_at
+            }
+            _ => return None,
+        };
+
+        // Can be judged only if there are work submissions and the judging period is not over.
+        let judging_ended_at = work_ended_at.saturating_add(delivery_milestone.judging_period);
+        if self.bounty.active_work_entry_count > 0 && self.now <= judging_ended_at {
+            return Some(BountyStage::Judgment);
+        }
+
+        Some(BountyStage::FailedBountyWithdrawal)
+    }
+
     // Calculates work submission stage of the bounty.
     // Returns None if conditions are not met.
     fn is_work_submission_stage(&self) -> Option<BountyStage> {
@@ -87,6 +136,7 @@ impl<'a, T: Config> BountyStageCalculator<'a, T> {
         // Can be judged only if there are work submissions.
         if self.bounty.active_work_entry_count > 0
             && BountyMilestone::WorkSubmitted == self.bounty.milestone
+            && !self.oracle_panel_judging_period_expired()
         {
             return Some(BountyStage::Judgment);
         }
@@ -94,6 +144,16 @@ impl<'a, T: Config> BountyStageCalculator<'a, T> {
         None
     }
 
+    // Calculates judgment appeal stage of the bounty.
+    // Returns None if conditions are not met.
+    fn is_judgment_appeal_stage(&self) -> Option<BountyStage> {
+        // The judgment is held in escrow until it is finalized or overturned.
+        match self.bounty.milestone {
+            BountyMilestone::JudgmentEscrowed { .. } => Some(BountyStage::JudgmentAppeal),
+            _ => None,
+        }
+    }
+
     // Calculates withdrawal stage for the bounty.
     // Returns None if conditions are not met.
     fn is_successful_bounty_withdrawal_stage(&self) -> Option<BountyStage> {
@@ -109,6 +169,31 @@ impl<'a, T: Config> BountyStageCalculator<'a, T> {
         }
     }
 
+    // Checks whether the oracle panel judging period expired by now.
+    // The judgment by a single oracle never expires.
+    fn oracle_panel_judging_period_expired(&self) -> bool {
+        match (
+            &self.bounty.creation_params.oracle_panel,
+            self.bounty.delivery_progress.work_ended_at,
+        ) {
+            (Some(oracle_panel), Some(work_ended_at)) => {
+                work_ended_at.saturating_add(oracle_panel.judging_period) < self.now
+            }
+            _ => false,
+        }
+    }
+
+    // Returns the current delivery milestone of the bounty.
+    fn current_delivery_milestone(&self) -> Option<&DeliveryMilestone<T::BlockNumber>> {
+        let index: usize = self
+            .bounty
+            .delivery_progress
+            .current_milestone
+            .saturated_into();
+
+        self.bounty.creation_params.delivery_milestones.get(index)
+    }
+
     // Checks whether the target funding reached for the bounty.
     fn target_funding_reached(&self) -> bool {
         match self.bounty.creation_params.funding_type {
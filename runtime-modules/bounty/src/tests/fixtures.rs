@@ -1,9 +1,11 @@
 use super::mocks::{Balances, Bounty, RuntimeEvent, System, Test};
 use crate::{
     AssuranceContractType, BountyActor, BountyCreationParameters, BountyMilestone, BountyRecord,
-    ClosedContractWhitelist, Config, Entry, FundingType, OracleJudgmentOf, RawEvent,
+    ClosedContractWhitelist, Config, DeliveryMilestone, DeliveryMilestones, Entry, FundingType,
+    OracleJudgmentOf, OraclePanel, OraclePanelMembers, RawEvent,
 };
 use common::council::CouncilBudgetManager;
+use common::membership::AttestationKind;
 use frame_support::dispatch::DispatchResult;
 use frame_support::storage::{StorageDoubleMap, StorageMap};
 use frame_support::traits::{Currency, OnFinalize, OnInitialize};
@@ -128,6 +130,10 @@ pub struct CreateBountyFixture {
     entrant_stake: u64,
     contract_type: AssuranceContractType<BTreeSet<u64>>,
     oracle: BountyActor<u64>,
+    delivery_milestones: Vec<DeliveryMilestone<u64>>,
+    oracle_panel: Option<OraclePanel<u64, BTreeSet<u64>>>,
+    appeal_period: u64,
+    token_id: Option<u64>,
 }
 
 impl CreateBountyFixture {
@@ -145,6 +151,10 @@ impl CreateBountyFixture {
             entrant_stake: DEFAULT_BOUNTY_ENTRANT_STAKE,
             contract_type: AssuranceContractType::Open,
             oracle: BountyActor::Council,
+            delivery_milestones: Vec::new(),
+            oracle_panel: None,
+            appeal_period: 0,
+            token_id: None,
         }
     }
 
@@ -234,6 +244,71 @@ impl CreateBountyFixture {
         }
     }
 
+    pub fn with_attested_contract(self, kind: AttestationKind) -> Self {
+        Self {
+            contract_type: AssuranceContractType::Attested(kind),
+            ..self
+        }
+    }
+
+    pub fn with_delivery_milestones(
+        self,
+        delivery_milestones: Vec<DeliveryMilestone<u64>>,
+    ) -> Self {
+        Self {
+            delivery_milestones,
+            ..self
+        }
+    }
+
+    pub fn with_oracle_panel(
+        self,
+        member_ids: Vec<u64>,
+        threshold: u32,
+        judging_period: u64,
+    ) -> Self {
+        Self {
+            oracle_panel: Some(OraclePanel {
+                members: BTreeSet::from_iter(member_ids.into_iter()),
+                council: false,
+                threshold,
+                judging_period,
+            }),
+            ..self
+        }
+    }
+
+    pub fn with_council_oracle_panel(
+        self,
+        member_ids: Vec<u64>,
+        threshold: u32,
+        judging_period: u64,
+    ) -> Self {
+        Self {
+            oracle_panel: Some(OraclePanel {
+                members: BTreeSet::from_iter(member_ids.into_iter()),
+                council: true,
+                threshold,
+                judging_period,
+            }),
+            ..self
+        }
+    }
+
+    pub fn with_appeal_period(self, appeal_period: u64) -> Self {
+        Self {
+            appeal_period,
+            ..self
+        }
+    }
+
+    pub fn with_token_id(self, token_id: u64) -> Self {
+        Self {
+            token_id: Some(token_id),
+            ..self
+        }
+    }
+
     pub fn get_bounty_creation_parameters(&self) -> BountyCreationParameters<Test> {
         BountyCreationParameters::<Test> {
             creator: self.creator.clone(),
@@ -243,7 +318,10 @@ impl CreateBountyFixture {
             entrant_stake: self.entrant_stake,
             contract_type: self.contract_type.clone(),
             oracle: self.oracle.clone(),
-            ..Default::default()
+            delivery_milestones: self.delivery_milestones.clone(),
+            oracle_panel: self.oracle_panel.clone(),
+            appeal_period: self.appeal_period,
+            token_id: self.token_id,
         }
     }
 
@@ -273,12 +351,20 @@ impl CreateBountyFixture {
                 },
             };
 
-            let expected_bounty = BountyRecord::<u64, u64, u64, ClosedContractWhitelist<Test>> {
+            let expected_bounty = BountyRecord::<
+                u64,
+                u64,
+                u64,
+                ClosedContractWhitelist<Test>,
+                DeliveryMilestones<Test>,
+                OraclePanelMembers<Test>,
+            > {
                 creation_params: params.clone().try_into().unwrap(),
                 total_funding: 0,
                 milestone: expected_milestone,
                 active_work_entry_count: 0,
                 has_unpaid_oracle_reward: params.oracle_reward > 0,
+                delivery_progress: Default::default(),
             };
 
             assert_eq!(expected_bounty, Bounty::bounties(bounty_id));
@@ -619,19 +705,274 @@ impl SubmitJudgmentFixture {
 
         let new_bounty = Bounty::bounties(self.bounty_id);
 
+        if actual_result.is_ok() {
+            let successful_bounty = Bounty::judgment_has_winners(&self.judgment);
+            let expected_milestone = if old_bounty.creation_params.appeal_period != 0 {
+                BountyMilestone::JudgmentEscrowed {
+                    judged_at: System::block_number(),
+                }
+            } else if successful_bounty && Bounty::has_next_delivery_milestone(&old_bounty) {
+                BountyMilestone::DeliveryMilestoneJudged
+            } else {
+                BountyMilestone::JudgmentSubmitted { successful_bounty }
+            };
+
+            assert_eq!(new_bounty.milestone, expected_milestone);
+        } else {
+            assert_eq!(new_bounty, old_bounty);
+        }
+    }
+}
+
+pub struct SubmitOraclePanelJudgmentFixture {
+    origin: RawOrigin<u128>,
+    member_id: u64,
+    bounty_id: u64,
+    judgment: OracleJudgmentOf<Test>,
+    rationale: Vec<u8>,
+}
+
+impl SubmitOraclePanelJudgmentFixture {
+    pub fn default() -> Self {
+        Self {
+            origin: RawOrigin::Signed(1),
+            member_id: 1,
+            bounty_id: 1,
+            judgment: Default::default(),
+            rationale: Default::default(),
+        }
+    }
+
+    pub fn with_origin(self, origin: RawOrigin<u128>) -> Self {
+        Self { origin, ..self }
+    }
+
+    pub fn with_member_id(self, member_id: u64) -> Self {
+        Self { member_id, ..self }
+    }
+
+    pub fn with_bounty_id(self, bounty_id: u64) -> Self {
+        Self { bounty_id, ..self }
+    }
+
+    pub fn with_judgment(self, judgment: OracleJudgmentOf<Test>) -> Self {
+        Self { judgment, ..self }
+    }
+
+    pub fn call_and_assert(&self, expected_result: DispatchResult) {
+        let old_bounty = Bounty::bounties(self.bounty_id);
+        let actual_result = Bounty::submit_oracle_panel_judgment(
+            self.origin.clone().into(),
+            self.member_id,
+            self.bounty_id,
+            self.judgment.clone(),
+            self.rationale.clone(),
+        );
+
+        assert_eq!(actual_result, expected_result);
+
+        if actual_result.is_err() {
+            assert_eq!(Bounty::bounties(self.bounty_id), old_bounty);
+        }
+    }
+}
+
+pub struct SwitchOraclePanelMemberFixture {
+    origin: RawOrigin<u128>,
+    bounty_id: u64,
+    current_member_id: u64,
+    new_member_id: u64,
+}
+
+impl SwitchOraclePanelMemberFixture {
+    pub fn default() -> Self {
+        Self {
+            origin: RawOrigin::Root,
+            bounty_id: 1,
+            current_member_id: 1,
+            new_member_id: 2,
+        }
+    }
+
+    pub fn with_origin(self, origin: RawOrigin<u128>) -> Self {
+        Self { origin, ..self }
+    }
+
+    pub fn with_bounty_id(self, bounty_id: u64) -> Self {
+        Self { bounty_id, ..self }
+    }
+
+    pub fn with_current_member_id(self, current_member_id: u64) -> Self {
+        Self {
+            current_member_id,
+            ..self
+        }
+    }
+
+    pub fn with_new_member_id(self, new_member_id: u64) -> Self {
+        Self {
+            new_member_id,
+            ..self
+        }
+    }
+
+    pub fn call_and_assert(&self, expected_result: DispatchResult) {
+        let actual_result = Bounty::switch_oracle_panel_member(
+            self.origin.clone().into(),
+            self.bounty_id,
+            self.current_member_id,
+            self.new_member_id,
+        );
+
+        assert_eq!(actual_result, expected_result);
+
+        if actual_result.is_ok() {
+            let oracle_panel = Bounty::bounties(self.bounty_id)
+                .creation_params
+                .oracle_panel
+                .unwrap();
+
+            assert!(!oracle_panel.members.contains(&self.current_member_id));
+            assert!(oracle_panel.members.contains(&self.new_member_id));
+        }
+    }
+}
+
+pub struct OpenJudgmentDisputeFixture {
+    origin: RawOrigin<u128>,
+    member_id: u64,
+    bounty_id: u64,
+    entry_id: Option<u64>,
+    rationale: Vec<u8>,
+}
+
+impl OpenJudgmentDisputeFixture {
+    pub fn default() -> Self {
+        Self {
+            origin: RawOrigin::Signed(1),
+            member_id: 1,
+            bounty_id: 1,
+            entry_id: None,
+            rationale: Vec::new(),
+        }
+    }
+
+    pub fn with_origin(self, origin: RawOrigin<u128>) -> Self {
+        Self { origin, ..self }
+    }
+
+    pub fn with_member_id(self, member_id: u64) -> Self {
+        Self { member_id, ..self }
+    }
+
+    pub fn with_entry_id(self, entry_id: u64) -> Self {
+        Self {
+            entry_id: Some(entry_id),
+            ..self
+        }
+    }
+
+    pub fn call_and_assert(&self, expected_result: DispatchResult) {
+        let actual_result = Bounty::open_judgment_dispute(
+            self.origin.clone().into(),
+            self.member_id,
+            self.bounty_id,
+            self.entry_id,
+            self.rationale.clone(),
+        );
+
+        assert_eq!(actual_result, expected_result);
+
+        let dispute = Bounty::judgment_disputes(self.bounty_id);
+        if actual_result.is_ok() {
+            assert_eq!(dispute.unwrap().disputer, self.member_id);
+        }
+    }
+}
+
+pub struct OverturnOracleJudgmentFixture {
+    origin: RawOrigin<u128>,
+    bounty_id: u64,
+}
+
+impl OverturnOracleJudgmentFixture {
+    pub fn default() -> Self {
+        Self {
+            origin: RawOrigin::Root,
+            bounty_id: 1,
+        }
+    }
+
+    pub fn with_origin(self, origin: RawOrigin<u128>) -> Self {
+        Self { origin, ..self }
+    }
+
+    pub fn call_and_assert(&self, expected_result: DispatchResult) {
+        let old_bounty = Bounty::bounties(self.bounty_id);
+        let actual_result =
+            Bounty::overturn_oracle_judgment(self.origin.clone().into(), self.bounty_id);
+
+        assert_eq!(actual_result, expected_result);
+
+        let new_bounty = Bounty::bounties(self.bounty_id);
         if actual_result.is_ok() {
             assert_eq!(
                 new_bounty.milestone,
                 BountyMilestone::JudgmentSubmitted {
-                    successful_bounty: Bounty::judgment_has_winners(&self.judgment)
+                    successful_bounty: false
                 }
             );
+            assert!(Bounty::judgment_disputes(self.bounty_id).is_none());
         } else {
             assert_eq!(new_bounty, old_bounty);
         }
     }
 }
 
+pub struct FinalizeOracleJudgmentFixture {
+    origin: RawOrigin<u128>,
+    bounty_id: u64,
+    judged_entries_witness: u32,
+}
+
+impl FinalizeOracleJudgmentFixture {
+    pub fn default() -> Self {
+        Self {
+            origin: RawOrigin::Signed(1),
+            bounty_id: 1,
+            judged_entries_witness: 1,
+        }
+    }
+
+    pub fn with_judged_entries_witness(self, judged_entries_witness: u32) -> Self {
+        Self {
+            judged_entries_witness,
+            ..self
+        }
+    }
+
+    pub fn call_and_assert(&self, expected_result: DispatchResult) {
+        let old_bounty = Bounty::bounties(self.bounty_id);
+        let actual_result = Bounty::finalize_oracle_judgment(
+            self.origin.clone().into(),
+            self.bounty_id,
+            self.judged_entries_witness,
+        );
+
+        assert_eq!(actual_result, expected_result);
+
+        if actual_result.is_ok() {
+            assert!(Bounty::judgment_disputes(self.bounty_id).is_none());
+            assert_eq!(
+                <crate::EscrowedJudgments<Test>>::iter_prefix(self.bounty_id).count(),
+                0
+            );
+        } else {
+            assert_eq!(Bounty::bounties(self.bounty_id), old_bounty);
+        }
+    }
+}
+
 pub struct SwitchOracleFixture {
     origin: RawOrigin<u128>,
     new_oracle: BountyActor<u64>,
@@ -811,3 +1152,41 @@ impl WithdrawOracleRewardFixture {
         assert_eq!(actual_result, expected_result);
     }
 }
+
+pub struct WithdrawOraclePanelRewardFixture {
+    origin: RawOrigin<u128>,
+    member_id: u64,
+    bounty_id: u64,
+}
+
+impl WithdrawOraclePanelRewardFixture {
+    pub fn default() -> Self {
+        Self {
+            origin: RawOrigin::Signed(1),
+            member_id: 1,
+            bounty_id: 1,
+        }
+    }
+
+    pub fn with_origin(self, origin: RawOrigin<u128>) -> Self {
+        Self { origin, ..self }
+    }
+
+    pub fn with_member_id(self, member_id: u64) -> Self {
+        Self { member_id, ..self }
+    }
+
+    pub fn call_and_assert(&self, expected_result: DispatchResult) {
+        let actual_result = Bounty::withdraw_oracle_panel_reward(
+            self.origin.clone().into(),
+            self.member_id,
+            self.bounty_id,
+        );
+
+        assert_eq!(actual_result, expected_result);
+
+        if actual_result.is_ok() {
+            assert!(!Bounty::bounties(self.bounty_id).has_unpaid_oracle_reward);
+        }
+    }
+}
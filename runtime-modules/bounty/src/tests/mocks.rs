@@ -12,9 +12,11 @@ use sp_runtime::{
 };
 
 use crate::Config;
+use common::membership::MembershipInfoProvider;
 use sp_std::convert::TryFrom;
 use sp_std::convert::TryInto;
 use staking_handler::{LockComparator, StakingManager};
+use std::cell::RefCell;
 type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Test>;
 type Block = frame_system::mocking::MockBlock<Test>;
 
@@ -27,6 +29,8 @@ frame_support::construct_runtime!(
         System: frame_system,
         Membership: membership::{Pallet, Call, Storage, Event<T>},
         Balances: balances,
+        Storage: storage::{Pallet, Call, Storage, Event<T>},
+        Token: project_token::{Pallet, Call, Storage, Event<T>},
         Bounty: crate::{Pallet, Call, Storage, Event<T>},
         Referendum: referendum::<Instance1>::{Pallet, Call, Storage, Event<T>},
         Council: council::{Pallet, Call, Storage, Event<T>},
@@ -45,6 +49,10 @@ parameter_types! {
     pub const MinWorkEntrantStake: u64 = 10;
     pub const CreatorStateBloatBondAmount: u64 = 10;
     pub const FunderStateBloatBondAmount: u64 = 10;
+    pub const MaxDeliveryMilestones: u32 = 3;
+    pub const MaxOraclePanelSize: u32 = 3;
+    pub const JudgmentDisputeStake: u64 = 20;
+    pub const JudgmentDisputePeriod: u64 = 10;
 }
 
 impl frame_system::Config for Test {
@@ -81,12 +89,84 @@ impl Config for Test {
     type Membership = ();
     type WeightInfo = ();
     type CouncilBudgetManager = CouncilBudgetManager;
+    type CouncilMembers = CouncilMembers;
     type StakingHandler = StakingManager<Test, BountyLockId>;
     type EntryId = u64;
     type ClosedContractSizeLimit = ClosedContractSizeLimit;
     type MinWorkEntrantStake = MinWorkEntrantStake;
     type CreatorStateBloatBondAmount = CreatorStateBloatBondAmount;
     type FunderStateBloatBondAmount = FunderStateBloatBondAmount;
+    type MaxDeliveryMilestones = MaxDeliveryMilestones;
+    type MaxOraclePanelSize = MaxOraclePanelSize;
+    type JudgmentDisputeStake = JudgmentDisputeStake;
+    type JudgmentDisputePeriod = JudgmentDisputePeriod;
+    type ProjectToken = project_token::Module<Test>;
+}
+
+parameter_types! {
+    // constants for storage::Config
+    pub const MaxDistributionBucketFamilyNumber: u64 = 4;
+    pub const StorageModuleId: PalletId = PalletId(*b"mstorage");
+    pub const BlacklistSizeLimit: u64 = 1;
+    pub const MaxNumberOfPendingInvitationsPerDistributionBucket: u32 = 1;
+    pub const DefaultMemberDynamicBagNumberOfStorageBuckets: u32 = 3;
+    pub const DefaultChannelDynamicBagNumberOfStorageBuckets: u32 = 4;
+    pub const MaxDataObjectSize: u64 = 1_000_000_000;
+    pub const MinStorageBucketsPerBag: u32 = 3;
+    pub const MaxStorageBucketsPerBag: u32 = 10;
+    pub const MinDistributionBucketsPerBag: u32 = 3;
+    pub const MaxDistributionBucketsPerBag: u32 = 10;
+    pub const MaxNumberOfOperatorsPerDistributionBucket: u32 = 5;
+    // constants for project_token::Config
+    pub const TokenModuleId: PalletId = PalletId(*b"m__Token");
+    pub const MaxVestingSchedulesPerAccountPerToken: u32 = 3;
+    pub const BlocksPerYear: u32 = 5259487;
+    pub const MaxOutputs: u32 = 256;
+}
+
+impl storage::Config for Test {
+    type RuntimeEvent = RuntimeEvent;
+    type DataObjectId = u64;
+    type StorageBucketId = u64;
+    type DistributionBucketIndex = u64;
+    type DistributionBucketFamilyId = u64;
+    type DistributionBucketOperatorId = u64;
+    type ChannelId = u64;
+    type BlacklistSizeLimit = BlacklistSizeLimit;
+    type ModuleId = StorageModuleId;
+    type MinStorageBucketsPerBag = MinStorageBucketsPerBag;
+    type MaxStorageBucketsPerBag = MaxStorageBucketsPerBag;
+    type MinDistributionBucketsPerBag = MinDistributionBucketsPerBag;
+    type MaxDistributionBucketsPerBag = MaxDistributionBucketsPerBag;
+    type DefaultMemberDynamicBagNumberOfStorageBuckets =
+        DefaultMemberDynamicBagNumberOfStorageBuckets;
+    type DefaultChannelDynamicBagNumberOfStorageBuckets =
+        DefaultChannelDynamicBagNumberOfStorageBuckets;
+    type MaxDistributionBucketFamilyNumber = MaxDistributionBucketFamilyNumber;
+    type MaxNumberOfPendingInvitationsPerDistributionBucket =
+        MaxNumberOfPendingInvitationsPerDistributionBucket;
+    type MaxNumberOfOperatorsPerDistributionBucket = MaxNumberOfOperatorsPerDistributionBucket;
+    type ContentId = u64;
+    type MaxDataObjectSize = MaxDataObjectSize;
+    type StorageWorkingGroup = Wg;
+    type DistributionWorkingGroup = Wg;
+    type ModuleAccountInitialBalance = ExistentialDeposit;
+    type WeightInfo = ();
+}
+
+impl project_token::Config for Test {
+    type RuntimeEvent = RuntimeEvent;
+    type Balance = u64;
+    type TokenId = u64;
+    type DataObjectStorage = storage::Module<Self>;
+    type ModuleId = TokenModuleId;
+    type JoyExistentialDeposit = ExistentialDeposit;
+    type MaxVestingSchedulesPerAccountPerToken = MaxVestingSchedulesPerAccountPerToken;
+    type BlocksPerYear = BlocksPerYear;
+    type WeightInfo = ();
+    type MemberOriginValidator = ();
+    type MembershipInfoProvider = ();
+    type MaxOutputs = MaxOutputs;
 }
 
 pub const STAKING_ACCOUNT_ID_NOT_BOUND_TO_MEMBER: u128 = 10000;
@@ -107,6 +187,10 @@ impl common::membership::MembershipInfoProvider<Test> for () {
 
         Err(membership::Error::<Test>::MemberProfileNotFound.into())
     }
+
+    fn has_valid_attestation(member_id: u64, kind: common::membership::AttestationKind) -> bool {
+        membership::Module::<Test>::has_valid_attestation(member_id, kind)
+    }
 }
 
 pub const COUNCIL_BUDGET_ACCOUNT_ID: u128 = 90000000;
@@ -140,6 +224,23 @@ impl common::council::CouncilBudgetManager<u128, u64> for CouncilBudgetManager {
     }
 }
 
+thread_local! {
+    pub static COUNCIL_MEMBER_IDS: RefCell<Vec<u64>> = RefCell::new(Vec::new());
+}
+
+pub struct CouncilMembers;
+impl common::council::CouncilMembersProvider<u64> for CouncilMembers {
+    fn council_member_ids() -> Vec<u64> {
+        COUNCIL_MEMBER_IDS.with(|val| val.borrow().clone())
+    }
+}
+
+pub fn set_council_member_ids(member_ids: Vec<u64>) {
+    COUNCIL_MEMBER_IDS.with(|val| {
+        *val.borrow_mut() = member_ids;
+    });
+}
+
 impl common::membership::MembershipTypes for Test {
     type MemberId = u64;
     type ActorId = u64;
@@ -173,6 +274,10 @@ parameter_types! {
     pub const StakingCandidateLockId: [u8; 8] = [3; 8];
     pub const DefaultInitialInvitationBalance: u64 = 100;
     pub const DefaultMemberInvitesCount: u32 = 2;
+    pub const HandleReclaimCooldown: u64 = 1000;
+    pub const MaxRecoveryGuardians: u32 = 5;
+    pub const RecoveryDelay: u64 = 100;
+    pub const RecoveryCooldown: u64 = 50;
     pub const CandidateStake: u64 = 130;
 }
 
@@ -190,6 +295,10 @@ impl membership::Config for Test {
     type WorkingGroup = Wg;
     type DefaultInitialInvitationBalance = DefaultInitialInvitationBalance;
     type DefaultMemberInvitesCount = DefaultMemberInvitesCount;
+    type HandleReclaimCooldown = HandleReclaimCooldown;
+    type MaxRecoveryGuardians = MaxRecoveryGuardians;
+    type RecoveryDelay = RecoveryDelay;
+    type RecoveryCooldown = RecoveryCooldown;
     type InvitedMemberStakingHandler = staking_handler::StakingManager<Self, InvitedMemberLockId>;
     type StakingCandidateStakingHandler =
         staking_handler::StakingManager<Self, StakingCandidateLockId>;
@@ -341,6 +450,11 @@ impl referendum::Config<ReferendumInstance> for Test {
     type MinimumStake = MinimumVotingStake;
     type WeightInfo = ();
     type MaxWinnerTargetCount = MaxWinnerTargetCount;
+    type DefaultVotePowerStrategy = ();
+    type MaxRankedBallotPreferences = ConstU32<5>;
+    type MaxRankedBallots = ConstU32<10>;
+    type MaxVotedOptions = ConstU32<100>;
+    type MaxDelegatorsPerDelegate = ConstU32<10>;
 
     fn calculate_vote_power(
         _: &<Self as frame_system::Config>::AccountId,
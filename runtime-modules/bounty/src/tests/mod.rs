@@ -3,34 +3,212 @@
 pub(crate) mod fixtures;
 pub(crate) mod mocks;
 
+use crate::migrations::v1::{MigrateToV1, OldBountyParameters, OldBountyRecord};
 use crate::{
     Bounties, BountyActor, BountyContributions, BountyMilestone, BountyRecord, BountyStage,
-    BountyStoredCreationParameters, Entries, Error, FundingType, OracleJudgment,
-    OracleWorkEntryJudgment, RawEvent,
+    BountyStoredCreationParameters, Config, DeliveryMilestone, DeliveryProgress, Entries, Error,
+    FundingType, OracleJudgment, OracleWorkEntryJudgment, RawEvent,
 };
+use common::membership::AttestationKind;
 use fixtures::{
     get_council_budget, get_creator_state_bloat_bond_amount, get_funder_state_bloat_bond_amount,
     increase_account_balance, increase_total_balance_issuance_using_account_id, run_to_block,
     set_council_budget, AnnounceWorkEntryFixture, CreateBountyFixture, EndWorkPeriodFixture,
-    EventFixture, FundBountyFixture, SubmitJudgmentFixture, SubmitWorkFixture, SwitchOracleFixture,
-    TerminateBountyFixture, WithdrawEntrantStakeFixture, WithdrawFundingFixture,
+    EventFixture, FinalizeOracleJudgmentFixture, FundBountyFixture, OpenJudgmentDisputeFixture,
+    OverturnOracleJudgmentFixture, SubmitJudgmentFixture, SubmitOraclePanelJudgmentFixture,
+    SubmitWorkFixture, SwitchOracleFixture, SwitchOraclePanelMemberFixture, TerminateBountyFixture,
+    WithdrawEntrantStakeFixture, WithdrawFundingFixture, WithdrawOraclePanelRewardFixture,
     WithdrawOracleRewardFixture, DEFAULT_BOUNTY_CHERRY, DEFAULT_BOUNTY_ORACLE_REWARD,
 };
-use frame_support::storage::{StorageDoubleMap, StorageMap};
+use frame_support::storage::{unhashed, StorageDoubleMap, StorageMap};
+use frame_support::traits::{Get, GetStorageVersion, OnRuntimeUpgrade, StorageVersion};
 use frame_support::{assert_err, assert_ok};
 use frame_system::RawOrigin;
 use mocks::{
-    build_test_externalities, Balances, Bounty, ClosedContractSizeLimit, System, Test,
-    COUNCIL_BUDGET_ACCOUNT_ID, INVALID_ACCOUNT_ID, INVALID_MEMBER_ID, MAX_MEMBERS,
-    STAKING_ACCOUNT_ID_NOT_BOUND_TO_MEMBER,
+    build_test_externalities, set_council_member_ids, Balances, Bounty, ClosedContractSizeLimit,
+    System, Test, Token, COUNCIL_BUDGET_ACCOUNT_ID, INVALID_ACCOUNT_ID, INVALID_MEMBER_ID,
+    MAX_MEMBERS, STAKING_ACCOUNT_ID_NOT_BOUND_TO_MEMBER,
 };
 use sp_runtime::DispatchError;
 use sp_runtime::DispatchError::Other;
 use sp_runtime::Perbill;
 use sp_std::collections::btree_map::BTreeMap;
+use sp_std::convert::TryInto;
+
+use project_token::traits::PalletToken;
+use project_token::types::{TokenAllocation, TokenIssuanceParametersOf, UploadContextOf};
 
 const DEFAULT_WINNER_REWARD: u64 = 10;
 
+const APPEAL_TARGET_FUNDING: u64 = 100;
+const APPEAL_ENTRANT_STAKE: u64 = 40;
+const APPEAL_ORACLE_REWARD: u64 = 10;
+const APPEAL_WINNER_ID: u64 = 1;
+const APPEAL_REJECTED_ID: u64 = 2;
+const APPEAL_CONTRIBUTOR_ID: u64 = 3;
+
+// Creates a bounty with an appeal period funded by the council and a member, announces two
+// work entries and submits the judgment with a winner and a rejected (half slashed) entry.
+fn submit_judgment_with_appeal_period(appeal_period: u64) {
+    set_council_budget(500);
+
+    CreateBountyFixture::default()
+        .with_limit_period_target_amount(APPEAL_TARGET_FUNDING)
+        .with_entrant_stake(APPEAL_ENTRANT_STAKE)
+        .with_oracle_reward(APPEAL_ORACLE_REWARD)
+        .with_appeal_period(appeal_period)
+        .call_and_assert(Ok(()));
+
+    let bounty_id = 1;
+
+    increase_account_balance(&APPEAL_CONTRIBUTOR_ID.into(), 500);
+    FundBountyFixture::default()
+        .with_bounty_id(bounty_id)
+        .with_origin(RawOrigin::Signed(APPEAL_CONTRIBUTOR_ID.into()))
+        .with_member_id(APPEAL_CONTRIBUTOR_ID)
+        .with_amount(APPEAL_TARGET_FUNDING / 2)
+        .call_and_assert(Ok(()));
+
+    FundBountyFixture::default()
+        .with_bounty_id(bounty_id)
+        .with_council()
+        .with_origin(RawOrigin::Root)
+        .with_amount(APPEAL_TARGET_FUNDING / 2)
+        .call_and_assert(Ok(()));
+
+    for member_id in [APPEAL_WINNER_ID, APPEAL_REJECTED_ID] {
+        let account_id = member_id.into();
+        increase_account_balance(&account_id, 500);
+
+        AnnounceWorkEntryFixture::default()
+            .with_origin(RawOrigin::Signed(account_id))
+            .with_member_id(member_id)
+            .with_staking_account_id(account_id)
+            .with_bounty_id(bounty_id)
+            .call_and_assert(Ok(()));
+
+        SubmitWorkFixture::default()
+            .with_origin(RawOrigin::Signed(account_id))
+            .with_member_id(member_id)
+            .with_entry_id(member_id)
+            .call_and_assert(Ok(()));
+    }
+
+    EndWorkPeriodFixture::default()
+        .with_bounty_id(bounty_id)
+        .with_origin(RawOrigin::Root)
+        .call_and_assert(Ok(()));
+
+    let mut judgment: OracleJudgment<u64, u64> = BTreeMap::new();
+    judgment.insert(
+        APPEAL_WINNER_ID,
+        OracleWorkEntryJudgment::Winner {
+            reward: APPEAL_TARGET_FUNDING,
+        },
+    );
+    judgment.insert(
+        APPEAL_REJECTED_ID,
+        OracleWorkEntryJudgment::Rejected {
+            slashing_share: Perbill::from_percent(50),
+            action_justification: Vec::new(),
+        },
+    );
+
+    SubmitJudgmentFixture::default()
+        .with_bounty_id(bounty_id)
+        .with_origin(RawOrigin::Root)
+        .with_judgment(judgment)
+        .call_and_assert(Ok(()));
+}
+
+const TOKEN_TARGET_FUNDING: u64 = 100;
+const TOKEN_INITIAL_ALLOCATION: u64 = 300;
+const TOKEN_WINNER_ID: u64 = 1;
+const TOKEN_CONTRIBUTOR_ID: u64 = 3;
+
+// Issues a creator token with the initial allocation for the given members.
+fn issue_creator_token(allocations: &[(u64, u64)]) -> u64 {
+    let initial_allocation = allocations
+        .iter()
+        .map(|(member_id, amount)| {
+            (
+                *member_id,
+                TokenAllocation {
+                    amount: *amount,
+                    vesting_schedule_params: None,
+                },
+            )
+        })
+        .collect();
+
+    Token::issue_token(
+        1,
+        TokenIssuanceParametersOf::<Test> {
+            initial_allocation,
+            ..Default::default()
+        },
+        UploadContextOf::<Test> {
+            uploader_account: 1,
+            bag_id: Default::default(),
+        },
+    )
+    .unwrap()
+}
+
+fn token_balance(token_id: u64, member_id: u64) -> u64 {
+    Token::account_info_by_token_and_member(token_id, member_id).amount
+}
+
+// Creates a bounty denominated in the creator token and funds it in full by a member.
+fn create_and_fund_token_bounty(token_id: u64) {
+    set_council_budget(500);
+
+    CreateBountyFixture::default()
+        .with_limit_period_target_amount(TOKEN_TARGET_FUNDING)
+        .with_token_id(token_id)
+        .call_and_assert(Ok(()));
+
+    increase_account_balance(&TOKEN_CONTRIBUTOR_ID.into(), 500);
+    FundBountyFixture::default()
+        .with_origin(RawOrigin::Signed(TOKEN_CONTRIBUTOR_ID.into()))
+        .with_member_id(TOKEN_CONTRIBUTOR_ID)
+        .with_amount(TOKEN_TARGET_FUNDING)
+        .call_and_assert(Ok(()));
+}
+
+// Announces and submits the work of the member for the token bounty.
+fn submit_token_bounty_work(member_id: u64) {
+    let account_id = member_id.into();
+    increase_account_balance(&account_id, 500);
+
+    AnnounceWorkEntryFixture::default()
+        .with_origin(RawOrigin::Signed(account_id))
+        .with_member_id(member_id)
+        .with_staking_account_id(account_id)
+        .call_and_assert(Ok(()));
+
+    SubmitWorkFixture::default()
+        .with_origin(RawOrigin::Signed(account_id))
+        .with_member_id(member_id)
+        .with_entry_id(1)
+        .call_and_assert(Ok(()));
+
+    EndWorkPeriodFixture::default()
+        .with_origin(RawOrigin::Root)
+        .call_and_assert(Ok(()));
+}
+
+fn delivery_milestones(funding_shares: &[u32]) -> Vec<DeliveryMilestone<u64>> {
+    funding_shares
+        .iter()
+        .map(|percent| DeliveryMilestone {
+            funding_share: Perbill::from_percent(*percent),
+            work_period: 5,
+            judging_period: 5,
+        })
+        .collect()
+}
+
 #[macro_export]
 macro_rules! to_origin {
     ($x: tt) => {
@@ -282,6 +460,105 @@ fn validate_failed_withdrawal_bounty_stage() {
     });
 }
 
+#[test]
+fn validate_delivery_milestone_bounty_stages() {
+    build_test_externalities().execute_with(|| {
+        let work_started_at = 10;
+        let params = BountyStoredCreationParameters::<Test> {
+            delivery_milestones: delivery_milestones(&[50, 50]).try_into().unwrap(),
+            ..Default::default()
+        };
+
+        // Work period of the first delivery milestone.
+        let bounty = BountyRecord {
+            creation_params: params.clone(),
+            milestone: BountyMilestone::BountyMaxFundingReached,
+            active_work_entry_count: 1,
+            delivery_progress: DeliveryProgress {
+                work_started_at: Some(work_started_at),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        System::set_block_number(work_started_at + 5);
+        assert_eq!(
+            Bounty::get_bounty_stage(&bounty),
+            BountyStage::WorkSubmission
+        );
+
+        // Judging period of the first delivery milestone.
+        System::set_block_number(work_started_at + 6);
+        assert_eq!(Bounty::get_bounty_stage(&bounty), BountyStage::Judgment);
+
+        // Judging period expired.
+        System::set_block_number(work_started_at + 11);
+        assert_eq!(
+            Bounty::get_bounty_stage(&bounty),
+            BountyStage::FailedBountyWithdrawal
+        );
+
+        // No work entries to judge.
+        let no_entries_bounty = BountyRecord {
+            active_work_entry_count: 0,
+            ..bounty
+        };
+
+        System::set_block_number(work_started_at + 6);
+        assert_eq!(
+            Bounty::get_bounty_stage(&no_entries_bounty),
+            BountyStage::FailedBountyWithdrawal
+        );
+
+        // Work period ended by the oracle.
+        let work_ended_at = 12;
+        let bounty = BountyRecord {
+            creation_params: params.clone(),
+            milestone: BountyMilestone::WorkSubmitted,
+            active_work_entry_count: 1,
+            delivery_progress: DeliveryProgress {
+                work_started_at: Some(work_started_at),
+                work_ended_at: Some(work_ended_at),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        System::set_block_number(work_ended_at + 5);
+        assert_eq!(Bounty::get_bounty_stage(&bounty), BountyStage::Judgment);
+
+        System::set_block_number(work_ended_at + 6);
+        assert_eq!(
+            Bounty::get_bounty_stage(&bounty),
+            BountyStage::FailedBountyWithdrawal
+        );
+
+        // Work period of the second delivery milestone.
+        let next_work_started_at = 20;
+        let bounty = BountyRecord {
+            creation_params: params,
+            milestone: BountyMilestone::DeliveryMilestoneJudged,
+            active_work_entry_count: 1,
+            delivery_progress: DeliveryProgress {
+                current_milestone: 1,
+                work_started_at: Some(next_work_started_at),
+                work_ended_at: None,
+                paid_out: 50,
+            },
+            ..Default::default()
+        };
+
+        System::set_block_number(next_work_started_at + 5);
+        assert_eq!(
+            Bounty::get_bounty_stage(&bounty),
+            BountyStage::WorkSubmission
+        );
+
+        System::set_block_number(next_work_started_at + 6);
+        assert_eq!(Bounty::get_bounty_stage(&bounty), BountyStage::Judgment);
+    });
+}
+
 #[test]
 fn create_bounty_succeeds() {
     build_test_externalities().execute_with(|| {
@@ -449,6 +726,96 @@ fn create_bounty_fails_with_invalid_funding_parameters() {
     });
 }
 
+#[test]
+fn create_bounty_fails_with_invalid_delivery_milestones() {
+    build_test_externalities().execute_with(|| {
+        set_council_budget(500);
+
+        CreateBountyFixture::default()
+            .with_delivery_milestones(delivery_milestones(&[25, 25, 25, 25]))
+            .call_and_assert(Err(Error::<Test>::TooManyDeliveryMilestones.into()));
+
+        CreateBountyFixture::default()
+            .with_delivery_milestones(delivery_milestones(&[50, 40]))
+            .call_and_assert(Err(
+                Error::<Test>::InvalidDeliveryMilestonesFundingShares.into()
+            ));
+
+        CreateBountyFixture::default()
+            .with_delivery_milestones(delivery_milestones(&[100, 0]))
+            .call_and_assert(Err(
+                Error::<Test>::InvalidDeliveryMilestonesFundingShares.into()
+            ));
+
+        let mut zero_period_milestones = delivery_milestones(&[50, 50]);
+        zero_period_milestones[1].judging_period = 0;
+        CreateBountyFixture::default()
+            .with_delivery_milestones(zero_period_milestones)
+            .call_and_assert(Err(
+                Error::<Test>::DeliveryMilestonePeriodCannotBeZero.into()
+            ));
+
+        CreateBountyFixture::default()
+            .with_delivery_milestones(delivery_milestones(&[30, 70]))
+            .call_and_assert(Ok(()));
+    });
+}
+
+#[test]
+fn create_bounty_fails_with_invalid_oracle_panel() {
+    build_test_externalities().execute_with(|| {
+        set_council_budget(500);
+
+        CreateBountyFixture::default()
+            .with_oracle_panel(Vec::new(), 1, 5)
+            .call_and_assert(Err(Error::<Test>::OraclePanelIsEmpty.into()));
+
+        CreateBountyFixture::default()
+            .with_oracle_panel(vec![1, 2, 3, 4], 2, 5)
+            .call_and_assert(Err(Error::<Test>::OraclePanelIsTooLarge.into()));
+
+        CreateBountyFixture::default()
+            .with_oracle_panel(vec![1, 2, 3], 0, 5)
+            .call_and_assert(Err(Error::<Test>::InvalidOraclePanelThreshold.into()));
+
+        CreateBountyFixture::default()
+            .with_oracle_panel(vec![1, 2, 3], 4, 5)
+            .call_and_assert(Err(Error::<Test>::InvalidOraclePanelThreshold.into()));
+
+        CreateBountyFixture::default()
+            .with_oracle_panel(vec![1, 2, 3], 2, 0)
+            .call_and_assert(Err(
+                Error::<Test>::OraclePanelJudgingPeriodCannotBeZero.into()
+            ));
+
+        CreateBountyFixture::default()
+            .with_oracle_panel(vec![1, 2, INVALID_MEMBER_ID], 2, 5)
+            .call_and_assert(Err(Error::<Test>::InvalidOraclePanelMemberId.into()));
+
+        CreateBountyFixture::default()
+            .with_oracle_panel(vec![1, 2, 3], 2, 5)
+            .call_and_assert(Ok(()));
+    });
+}
+
+#[test]
+fn create_bounty_fails_with_appeal_period_and_delivery_milestones() {
+    build_test_externalities().execute_with(|| {
+        set_council_budget(500);
+
+        CreateBountyFixture::default()
+            .with_appeal_period(5)
+            .with_delivery_milestones(delivery_milestones(&[30, 70]))
+            .call_and_assert(Err(
+                Error::<Test>::AppealPeriodNotSupportedWithDeliveryMilestones.into(),
+            ));
+
+        CreateBountyFixture::default()
+            .with_appeal_period(5)
+            .call_and_assert(Ok(()));
+    });
+}
+
 #[test]
 fn create_bounty_fails_with_invalid_entrant_stake() {
     build_test_externalities().execute_with(|| {
@@ -2495,56 +2862,156 @@ fn withdraw_funding_member_with_half_cherry_failed_bounty_no_removal() {
 }
 
 #[test]
-fn withdraw_funding_member_fails_with_invalid_bounty_id() {
+fn withdraw_funding_member_with_failed_delivery_milestone_refunds_unspent_funding() {
     build_test_externalities().execute_with(|| {
-        let invalid_bounty_id = 11u64;
-
-        WithdrawFundingFixture::default()
-            .with_bounty_id(invalid_bounty_id)
-            .call_and_assert(Err(Error::<Test>::BountyDoesntExist.into()));
-    });
-}
+        let starting_block = 1;
+        run_to_block(starting_block);
 
-#[test]
-fn withdraw_funding_member_fails_with_invalid_origin() {
-    build_test_externalities().execute_with(|| {
-        set_council_budget(500);
+        let initial_balance = 500;
+        let target_funding = 100;
+        let entrant_stake = 37;
+        let funding_member_id = 4;
+        let funding_account_id = 4;
+        let cherry = 200;
+        let oracle_reward = 100;
+        set_council_budget(initial_balance);
 
         CreateBountyFixture::default()
-            .with_origin(RawOrigin::Root)
+            .with_cherry(cherry)
+            .with_oracle_reward(oracle_reward)
+            .with_limit_period_target_amount(target_funding)
+            .with_entrant_stake(entrant_stake)
+            .with_delivery_milestones(delivery_milestones(&[30, 70]))
             .call_and_assert(Ok(()));
 
-        let bounty_id = 1u64;
+        let bounty_id = 1;
+        increase_account_balance(&funding_account_id, initial_balance);
 
-        WithdrawFundingFixture::default()
+        FundBountyFixture::default()
             .with_bounty_id(bounty_id)
-            .with_origin(RawOrigin::Signed(1))
-            .with_council()
-            .call_and_assert(Err(DispatchError::BadOrigin));
+            .with_amount(target_funding)
+            .with_origin(RawOrigin::Signed(funding_account_id))
+            .with_member_id(funding_member_id)
+            .call_and_assert(Ok(()));
 
-        WithdrawFundingFixture::default()
-            .with_bounty_id(bounty_id)
-            .with_origin(RawOrigin::None)
-            .with_council()
-            .call_and_assert(Err(DispatchError::BadOrigin));
+        let member_id = 1;
+        let account_id = 1;
+        increase_account_balance(&account_id, initial_balance);
 
-        WithdrawFundingFixture::default()
+        AnnounceWorkEntryFixture::default()
+            .with_origin(RawOrigin::Signed(account_id))
+            .with_member_id(member_id)
+            .with_staking_account_id(account_id)
             .with_bounty_id(bounty_id)
-            .with_origin(RawOrigin::Root)
-            .call_and_assert(Err(DispatchError::BadOrigin));
-    });
-}
-
-#[test]
-fn withdraw_funding_member_with_invalid_member_controller_account() {
-    build_test_externalities().execute_with(|| {
-        set_council_budget(500);
-
-        CreateBountyFixture::default()
-            .with_origin(RawOrigin::Root)
             .call_and_assert(Ok(()));
 
-        let bounty_id = 1u64;
+        let entry_id = 1;
+
+        SubmitWorkFixture::default()
+            .with_origin(RawOrigin::Signed(account_id))
+            .with_member_id(member_id)
+            .with_entry_id(entry_id)
+            .call_and_assert(Ok(()));
+
+        EndWorkPeriodFixture::default()
+            .with_bounty_id(bounty_id)
+            .with_origin(RawOrigin::Root)
+            .call_and_assert(Ok(()));
+
+        let first_milestone_reward = 30;
+        let mut judgment: OracleJudgment<u64, u64> = BTreeMap::new();
+        judgment.insert(
+            entry_id,
+            OracleWorkEntryJudgment::Winner {
+                reward: first_milestone_reward,
+            },
+        );
+
+        SubmitJudgmentFixture::default()
+            .with_bounty_id(bounty_id)
+            .with_origin(RawOrigin::Root)
+            .with_judgment(judgment)
+            .call_and_assert(Ok(()));
+
+        // Work and judging periods of the second delivery milestone expire.
+        run_to_block(starting_block + 11);
+
+        let bounty = Bounty::ensure_bounty_exists(&bounty_id).unwrap();
+        assert_eq!(
+            Bounty::get_bounty_stage(&bounty),
+            BountyStage::FailedBountyWithdrawal
+        );
+
+        WithdrawFundingFixture::default()
+            .with_bounty_id(bounty_id)
+            .with_member_id(funding_member_id)
+            .with_origin(RawOrigin::Signed(funding_account_id))
+            .call_and_assert(Ok(()));
+
+        // Only the unspent funding is refunded together with the cherry.
+        assert_eq!(
+            balances::Pallet::<Test>::usable_balance(&funding_account_id),
+            initial_balance - first_milestone_reward + cherry
+        );
+
+        assert_eq!(
+            balances::Pallet::<Test>::usable_balance(&Bounty::bounty_account_id(bounty_id)),
+            oracle_reward + get_creator_state_bloat_bond_amount()
+        );
+    });
+}
+
+#[test]
+fn withdraw_funding_member_fails_with_invalid_bounty_id() {
+    build_test_externalities().execute_with(|| {
+        let invalid_bounty_id = 11u64;
+
+        WithdrawFundingFixture::default()
+            .with_bounty_id(invalid_bounty_id)
+            .call_and_assert(Err(Error::<Test>::BountyDoesntExist.into()));
+    });
+}
+
+#[test]
+fn withdraw_funding_member_fails_with_invalid_origin() {
+    build_test_externalities().execute_with(|| {
+        set_council_budget(500);
+
+        CreateBountyFixture::default()
+            .with_origin(RawOrigin::Root)
+            .call_and_assert(Ok(()));
+
+        let bounty_id = 1u64;
+
+        WithdrawFundingFixture::default()
+            .with_bounty_id(bounty_id)
+            .with_origin(RawOrigin::Signed(1))
+            .with_council()
+            .call_and_assert(Err(DispatchError::BadOrigin));
+
+        WithdrawFundingFixture::default()
+            .with_bounty_id(bounty_id)
+            .with_origin(RawOrigin::None)
+            .with_council()
+            .call_and_assert(Err(DispatchError::BadOrigin));
+
+        WithdrawFundingFixture::default()
+            .with_bounty_id(bounty_id)
+            .with_origin(RawOrigin::Root)
+            .call_and_assert(Err(DispatchError::BadOrigin));
+    });
+}
+
+#[test]
+fn withdraw_funding_member_with_invalid_member_controller_account() {
+    build_test_externalities().execute_with(|| {
+        set_council_budget(500);
+
+        CreateBountyFixture::default()
+            .with_origin(RawOrigin::Root)
+            .call_and_assert(Ok(()));
+
+        let bounty_id = 1u64;
 
         WithdrawFundingFixture::default()
             .with_bounty_id(bounty_id)
@@ -3108,6 +3575,87 @@ fn announce_work_entry_failed_with_closed_contract() {
     });
 }
 
+#[test]
+fn announce_work_entry_with_attested_contract() {
+    build_test_externalities().execute_with(|| {
+        let initial_balance = 500;
+        let target_funding = 100;
+        let entrant_stake = 37;
+        let kind = AttestationKind::Identity;
+
+        set_council_budget(initial_balance);
+
+        CreateBountyFixture::default()
+            .with_limit_period_target_amount(target_funding)
+            .with_entrant_stake(entrant_stake)
+            .with_attested_contract(kind)
+            .call_and_assert(Ok(()));
+
+        let bounty_id = 1;
+
+        FundBountyFixture::default()
+            .with_bounty_id(bounty_id)
+            .with_amount(target_funding)
+            .with_council()
+            .with_origin(RawOrigin::Root)
+            .call_and_assert(Ok(()));
+
+        let attested_member_id = 1;
+        let expired_member_id = 2;
+        let other_kind_member_id = 3;
+
+        membership::MemberAttestations::<Test>::insert(
+            attested_member_id,
+            kind,
+            membership::Attestation {
+                issuer: 1,
+                issued_at: System::block_number(),
+                expires_at: None,
+            },
+        );
+        membership::MemberAttestations::<Test>::insert(
+            expired_member_id,
+            kind,
+            membership::Attestation {
+                issuer: 1,
+                issued_at: System::block_number(),
+                expires_at: Some(System::block_number()),
+            },
+        );
+
+        // only the attestation of the required kind is accepted
+        membership::MemberAttestations::<Test>::insert(
+            other_kind_member_id,
+            AttestationKind::Creator,
+            membership::Attestation {
+                issuer: 1,
+                issued_at: System::block_number(),
+                expires_at: None,
+            },
+        );
+
+        for member_id in [attested_member_id, expired_member_id, other_kind_member_id] {
+            increase_account_balance(&(member_id as u128), initial_balance);
+        }
+
+        for member_id in [expired_member_id, other_kind_member_id] {
+            AnnounceWorkEntryFixture::default()
+                .with_origin(RawOrigin::Signed(member_id as u128))
+                .with_member_id(member_id)
+                .with_staking_account_id(member_id as u128)
+                .with_bounty_id(bounty_id)
+                .call_and_assert(Err(Error::<Test>::CannotSubmitWorkWithoutAttestation.into()));
+        }
+
+        AnnounceWorkEntryFixture::default()
+            .with_origin(RawOrigin::Signed(attested_member_id as u128))
+            .with_member_id(attested_member_id)
+            .with_staking_account_id(attested_member_id as u128)
+            .with_bounty_id(bounty_id)
+            .call_and_assert(Ok(()));
+    });
+}
+
 #[test]
 fn announce_work_entry_fails_with_invalid_bounty_id() {
     build_test_externalities().execute_with(|| {
@@ -4776,89 +5324,1279 @@ fn submit_judgment_fails_with_invalid_judgment() {
 }
 
 #[test]
-fn switch_oracle_to_council_by_council_successful() {
+fn submit_judgment_with_delivery_milestones_succeeded() {
     build_test_externalities().execute_with(|| {
         let starting_block = 1;
         run_to_block(starting_block);
 
         let initial_balance = 500;
-        let actual_oracle_member_id = 5;
-
+        let target_funding = 100;
+        let entrant_stake = 37;
+        let cherry = 10;
+        let oracle_reward = 10;
         set_council_budget(initial_balance);
 
         CreateBountyFixture::default()
-            .with_oracle_member_id(actual_oracle_member_id)
+            .with_limit_period_target_amount(target_funding)
+            .with_entrant_stake(entrant_stake)
+            .with_cherry(cherry)
+            .with_oracle_reward(oracle_reward)
+            .with_delivery_milestones(delivery_milestones(&[30, 70]))
             .call_and_assert(Ok(()));
 
-        let bounty_id = 1u64;
+        let bounty_id = 1;
 
-        SwitchOracleFixture::default()
-            .with_new_oracle_member_id(BountyActor::Council)
+        FundBountyFixture::default()
+            .with_bounty_id(bounty_id)
+            .with_amount(target_funding)
+            .with_council()
+            .with_origin(RawOrigin::Root)
             .call_and_assert(Ok(()));
 
-        EventFixture::assert_last_crate_event(RawEvent::BountyOracleSwitched(
-            bounty_id,
-            BountyActor::Council,
-            BountyActor::Member(actual_oracle_member_id),
-            BountyActor::Council,
-        ));
-    });
-}
-
-#[test]
-fn switch_oracle_to_member_by_oracle_council_successful() {
-    build_test_externalities().execute_with(|| {
-        let starting_block = 1;
-        run_to_block(starting_block);
-
-        let initial_balance = 500;
-        let new_oracle_member_id = 5;
+        let member_id = 1;
+        let account_id = 1;
+        increase_account_balance(&account_id, initial_balance);
 
-        set_council_budget(initial_balance);
+        AnnounceWorkEntryFixture::default()
+            .with_origin(RawOrigin::Signed(account_id))
+            .with_member_id(member_id)
+            .with_staking_account_id(account_id)
+            .with_bounty_id(bounty_id)
+            .call_and_assert(Ok(()));
 
-        CreateBountyFixture::default().call_and_assert(Ok(()));
-        let bounty_id = 1u64;
+        let entry_id = 1;
 
-        SwitchOracleFixture::default()
-            .with_new_oracle_member_id(BountyActor::Member(new_oracle_member_id))
+        SubmitWorkFixture::default()
+            .with_origin(RawOrigin::Signed(account_id))
+            .with_member_id(member_id)
+            .with_entry_id(entry_id)
             .call_and_assert(Ok(()));
 
-        EventFixture::assert_last_crate_event(RawEvent::BountyOracleSwitched(
-            bounty_id,
-            BountyActor::Council,
-            BountyActor::Council,
-            BountyActor::Member(new_oracle_member_id),
-        ));
-    });
-}
+        EndWorkPeriodFixture::default()
+            .with_bounty_id(bounty_id)
+            .with_origin(RawOrigin::Root)
+            .call_and_assert(Ok(()));
 
-#[test]
-fn switch_oracle_to_member_by_council_successful() {
-    build_test_externalities().execute_with(|| {
-        let starting_block = 1;
-        run_to_block(starting_block);
+        // The first delivery milestone rewards only its share of the total funding.
+        let first_milestone_reward = 30;
+        let mut judgment: OracleJudgment<u64, u64> = BTreeMap::new();
+        judgment.insert(
+            entry_id,
+            OracleWorkEntryJudgment::Winner {
+                reward: target_funding,
+            },
+        );
 
-        let initial_balance = 500;
-        let current_oracle_member_id = 2;
-        let new_oracle_member_id = 5;
+        SubmitJudgmentFixture::default()
+            .with_bounty_id(bounty_id)
+            .with_origin(RawOrigin::Root)
+            .with_judgment(judgment)
+            .call_and_assert(Err(
+                Error::<Test>::TotalRewardShouldBeEqualToDeliveryMilestoneFunding.into(),
+            ));
 
-        set_council_budget(initial_balance);
+        let mut judgment: OracleJudgment<u64, u64> = BTreeMap::new();
+        judgment.insert(
+            entry_id,
+            OracleWorkEntryJudgment::Winner {
+                reward: first_milestone_reward,
+            },
+        );
 
-        let create_bounty_fixture =
-            CreateBountyFixture::default().with_oracle_member_id(current_oracle_member_id);
+        SubmitJudgmentFixture::default()
+            .with_bounty_id(bounty_id)
+            .with_origin(RawOrigin::Root)
+            .with_judgment(judgment)
+            .call_and_assert(Ok(()));
 
-        create_bounty_fixture.call_and_assert(Ok(()));
-        let bounty_id = 1u64;
+        EventFixture::contains_crate_event(RawEvent::DeliveryMilestoneCompleted(bounty_id, 0));
 
-        EventFixture::assert_last_crate_event(RawEvent::BountyCreated(
-            bounty_id,
-            create_bounty_fixture.get_bounty_creation_parameters(),
-            Vec::new(),
-        ));
+        // The winner is paid and keeps the entry and the stake for the next delivery milestone.
+        assert_eq!(
+            Balances::usable_balance(&account_id),
+            initial_balance - entrant_stake + first_milestone_reward
+        );
 
-        SwitchOracleFixture::default()
-            .with_new_oracle_member_id(BountyActor::Member(new_oracle_member_id))
-            .call_and_assert(Ok(()));
+        let entry = Bounty::entries(bounty_id, entry_id).unwrap();
+        assert!(!entry.work_submitted);
+
+        let bounty = Bounty::ensure_bounty_exists(&bounty_id).unwrap();
+        assert_eq!(bounty.delivery_progress.current_milestone, 1);
+        assert_eq!(bounty.delivery_progress.paid_out, first_milestone_reward);
+        assert_eq!(
+            Bounty::get_bounty_stage(&bounty),
+            BountyStage::WorkSubmission
+        );
+
+        // The cherry is kept until the last delivery milestone.
+        assert_eq!(
+            Balances::usable_balance(&COUNCIL_BUDGET_ACCOUNT_ID),
+            initial_balance
+                - oracle_reward
+                - cherry
+                - target_funding
+                - get_funder_state_bloat_bond_amount()
+                - get_creator_state_bloat_bond_amount()
+        );
+
+        SubmitWorkFixture::default()
+            .with_origin(RawOrigin::Signed(account_id))
+            .with_member_id(member_id)
+            .with_entry_id(entry_id)
+            .call_and_assert(Ok(()));
+
+        EndWorkPeriodFixture::default()
+            .with_bounty_id(bounty_id)
+            .with_origin(RawOrigin::Root)
+            .call_and_assert(Ok(()));
+
+        let last_milestone_reward = target_funding - first_milestone_reward;
+        let mut judgment: OracleJudgment<u64, u64> = BTreeMap::new();
+        judgment.insert(
+            entry_id,
+            OracleWorkEntryJudgment::Winner {
+                reward: last_milestone_reward,
+            },
+        );
+
+        SubmitJudgmentFixture::default()
+            .with_bounty_id(bounty_id)
+            .with_origin(RawOrigin::Root)
+            .with_judgment(judgment)
+            .call_and_assert(Ok(()));
+
+        EventFixture::contains_crate_event(RawEvent::DeliveryMilestoneCompleted(bounty_id, 1));
+
+        assert_eq!(
+            Balances::usable_balance(&account_id),
+            initial_balance + target_funding
+        );
+        assert!(!<Entries<Test>>::contains_key(bounty_id, entry_id));
+
+        EventFixture::contains_crate_event(RawEvent::BountyCreatorCherryWithdrawal(
+            bounty_id,
+            BountyActor::Council,
+        ));
+
+        let bounty = Bounty::ensure_bounty_exists(&bounty_id).unwrap();
+        assert_eq!(
+            Bounty::get_bounty_stage(&bounty),
+            BountyStage::SuccessfulBountyWithdrawal
+        );
+    });
+}
+
+#[test]
+fn submit_oracle_panel_judgment_succeeded() {
+    build_test_externalities().execute_with(|| {
+        let starting_block = 1;
+        run_to_block(starting_block);
+
+        let initial_balance = 500;
+        let target_funding = 100;
+        let entrant_stake = 37;
+        let oracle_reward = 30;
+        let panel_member_ids = vec![5, 6, 7];
+        set_council_budget(initial_balance);
+
+        CreateBountyFixture::default()
+            .with_limit_period_target_amount(target_funding)
+            .with_entrant_stake(entrant_stake)
+            .with_oracle_reward(oracle_reward)
+            .with_oracle_panel(panel_member_ids.clone(), 2, 5)
+            .call_and_assert(Ok(()));
+
+        let bounty_id = 1;
+
+        FundBountyFixture::default()
+            .with_bounty_id(bounty_id)
+            .with_amount(target_funding)
+            .with_council()
+            .with_origin(RawOrigin::Root)
+            .call_and_assert(Ok(()));
+
+        let member_id = 1;
+        let account_id = 1;
+        increase_account_balance(&account_id, initial_balance);
+
+        AnnounceWorkEntryFixture::default()
+            .with_origin(RawOrigin::Signed(account_id))
+            .with_member_id(member_id)
+            .with_staking_account_id(account_id)
+            .with_bounty_id(bounty_id)
+            .call_and_assert(Ok(()));
+
+        let entry_id = 1;
+
+        SubmitWorkFixture::default()
+            .with_origin(RawOrigin::Signed(account_id))
+            .with_member_id(member_id)
+            .with_entry_id(entry_id)
+            .call_and_assert(Ok(()));
+
+        EndWorkPeriodFixture::default()
+            .with_bounty_id(bounty_id)
+            .with_origin(RawOrigin::Root)
+            .call_and_assert(Ok(()));
+
+        let mut judgment: OracleJudgment<u64, u64> = BTreeMap::new();
+        judgment.insert(
+            entry_id,
+            OracleWorkEntryJudgment::Winner {
+                reward: target_funding,
+            },
+        );
+
+        // The single oracle cannot judge a bounty with an oracle panel.
+        SubmitJudgmentFixture::default()
+            .with_bounty_id(bounty_id)
+            .with_origin(RawOrigin::Root)
+            .with_judgment(judgment.clone())
+            .call_and_assert(Err(Error::<Test>::BountyIsJudgedByOraclePanel.into()));
+
+        let not_a_panel_member_id = 8;
+        SubmitOraclePanelJudgmentFixture::default()
+            .with_origin(RawOrigin::Signed(not_a_panel_member_id.into()))
+            .with_member_id(not_a_panel_member_id)
+            .with_judgment(judgment.clone())
+            .call_and_assert(Err(Error::<Test>::NotAnOraclePanelMember.into()));
+
+        SubmitOraclePanelJudgmentFixture::default()
+            .with_origin(RawOrigin::Signed(5))
+            .with_member_id(5)
+            .with_judgment(judgment.clone())
+            .call_and_assert(Ok(()));
+
+        EventFixture::contains_crate_event(RawEvent::OraclePanelJudgmentSubmitted(
+            bounty_id,
+            5,
+            judgment.clone(),
+            Vec::new(),
+        ));
+
+        // A diverging judgment doesn't count towards the threshold.
+        let mut rejection: OracleJudgment<u64, u64> = BTreeMap::new();
+        rejection.insert(
+            entry_id,
+            OracleWorkEntryJudgment::Rejected {
+                slashing_share: Perbill::from_percent(0),
+                action_justification: Vec::new(),
+            },
+        );
+
+        SubmitOraclePanelJudgmentFixture::default()
+            .with_origin(RawOrigin::Signed(6))
+            .with_member_id(6)
+            .with_judgment(rejection)
+            .call_and_assert(Ok(()));
+
+        let bounty = Bounty::ensure_bounty_exists(&bounty_id).unwrap();
+        assert_eq!(Bounty::get_bounty_stage(&bounty), BountyStage::Judgment);
+
+        SubmitOraclePanelJudgmentFixture::default()
+            .with_origin(RawOrigin::Signed(7))
+            .with_member_id(7)
+            .with_judgment(judgment.clone())
+            .call_and_assert(Ok(()));
+
+        EventFixture::contains_crate_event(RawEvent::OracleJudgmentSubmitted(
+            bounty_id,
+            BountyActor::Council,
+            judgment,
+            Vec::new(),
+        ));
+
+        let bounty = Bounty::ensure_bounty_exists(&bounty_id).unwrap();
+        assert_eq!(
+            Bounty::get_bounty_stage(&bounty),
+            BountyStage::SuccessfulBountyWithdrawal
+        );
+        assert!(!bounty.has_unpaid_oracle_reward);
+
+        // The oracle reward is split among the participating panel members.
+        let reward_share = oracle_reward / panel_member_ids.len() as u64;
+        for panel_member_id in panel_member_ids {
+            assert_eq!(
+                Balances::usable_balance(&panel_member_id.into()),
+                reward_share
+            );
+
+            EventFixture::contains_crate_event(RawEvent::BountyOracleRewardWithdrawal(
+                bounty_id,
+                BountyActor::Member(panel_member_id),
+                reward_share,
+            ));
+        }
+
+        WithdrawOraclePanelRewardFixture::default()
+            .with_origin(RawOrigin::Signed(5))
+            .with_member_id(5)
+            .call_and_assert(Err(Error::<Test>::OracleRewardAlreadyWithdrawn.into()));
+    });
+}
+
+#[test]
+fn oracle_panel_judging_period_expiration_fails_bounty() {
+    build_test_externalities().execute_with(|| {
+        let starting_block = 1;
+        run_to_block(starting_block);
+
+        let initial_balance = 500;
+        let target_funding = 100;
+        let entrant_stake = 37;
+        let oracle_reward = 30;
+        let judging_period = 5;
+        set_council_budget(initial_balance);
+
+        CreateBountyFixture::default()
+            .with_limit_period_target_amount(target_funding)
+            .with_entrant_stake(entrant_stake)
+            .with_oracle_reward(oracle_reward)
+            .with_oracle_panel(vec![5, 6], 2, judging_period)
+            .call_and_assert(Ok(()));
+
+        let bounty_id = 1;
+
+        FundBountyFixture::default()
+            .with_bounty_id(bounty_id)
+            .with_amount(target_funding)
+            .with_council()
+            .with_origin(RawOrigin::Root)
+            .call_and_assert(Ok(()));
+
+        let member_id = 1;
+        let account_id = 1;
+        increase_account_balance(&account_id, initial_balance);
+
+        AnnounceWorkEntryFixture::default()
+            .with_origin(RawOrigin::Signed(account_id))
+            .with_member_id(member_id)
+            .with_staking_account_id(account_id)
+            .with_bounty_id(bounty_id)
+            .call_and_assert(Ok(()));
+
+        let entry_id = 1;
+
+        SubmitWorkFixture::default()
+            .with_origin(RawOrigin::Signed(account_id))
+            .with_member_id(member_id)
+            .with_entry_id(entry_id)
+            .call_and_assert(Ok(()));
+
+        EndWorkPeriodFixture::default()
+            .with_bounty_id(bounty_id)
+            .with_origin(RawOrigin::Root)
+            .call_and_assert(Ok(()));
+
+        let mut judgment: OracleJudgment<u64, u64> = BTreeMap::new();
+        judgment.insert(
+            entry_id,
+            OracleWorkEntryJudgment::Winner {
+                reward: target_funding,
+            },
+        );
+
+        SubmitOraclePanelJudgmentFixture::default()
+            .with_origin(RawOrigin::Signed(5))
+            .with_member_id(5)
+            .with_judgment(judgment.clone())
+            .call_and_assert(Ok(()));
+
+        run_to_block(starting_block + judging_period + 1);
+
+        let bounty = Bounty::ensure_bounty_exists(&bounty_id).unwrap();
+        assert_eq!(
+            Bounty::get_bounty_stage(&bounty),
+            BountyStage::FailedBountyWithdrawal
+        );
+
+        SubmitOraclePanelJudgmentFixture::default()
+            .with_origin(RawOrigin::Signed(6))
+            .with_member_id(6)
+            .with_judgment(judgment)
+            .call_and_assert(Err(
+                Error::<Test>::InvalidStageUnexpectedFailedBountyWithdrawal.into(),
+            ));
+
+        // The single oracle cannot withdraw the oracle panel reward.
+        WithdrawOracleRewardFixture::default()
+            .call_and_assert(Err(Error::<Test>::BountyIsJudgedByOraclePanel.into()));
+
+        let not_a_panel_member_id = 8;
+        WithdrawOraclePanelRewardFixture::default()
+            .with_origin(RawOrigin::Signed(not_a_panel_member_id.into()))
+            .with_member_id(not_a_panel_member_id)
+            .call_and_assert(Err(Error::<Test>::NotAnOraclePanelMember.into()));
+
+        // The share of the panel member that didn't participate is returned to the creator.
+        let council_budget = get_council_budget();
+        let reward_share = oracle_reward / 2;
+
+        WithdrawOraclePanelRewardFixture::default()
+            .with_origin(RawOrigin::Signed(5))
+            .with_member_id(5)
+            .call_and_assert(Ok(()));
+
+        assert_eq!(Balances::usable_balance(&5), reward_share);
+        assert_eq!(Balances::usable_balance(&6), 0);
+        assert_eq!(get_council_budget(), council_budget + reward_share);
+
+        EventFixture::contains_crate_event(RawEvent::BountyOracleRewardWithdrawal(
+            bounty_id,
+            BountyActor::Member(5),
+            reward_share,
+        ));
+        EventFixture::assert_last_crate_event(RawEvent::OraclePanelRewardWithheld(
+            bounty_id,
+            reward_share,
+        ));
+    });
+}
+
+#[test]
+fn oracle_panel_reward_is_withheld_without_participation() {
+    build_test_externalities().execute_with(|| {
+        let starting_block = 1;
+        run_to_block(starting_block);
+
+        let initial_balance = 500;
+        let target_funding = 100;
+        let oracle_reward = 30;
+        let judging_period = 5;
+        set_council_budget(initial_balance);
+
+        CreateBountyFixture::default()
+            .with_limit_period_target_amount(target_funding)
+            .with_oracle_reward(oracle_reward)
+            .with_oracle_panel(vec![5, 6], 2, judging_period)
+            .call_and_assert(Ok(()));
+
+        let bounty_id = 1;
+
+        FundBountyFixture::default()
+            .with_bounty_id(bounty_id)
+            .with_amount(target_funding)
+            .with_council()
+            .with_origin(RawOrigin::Root)
+            .call_and_assert(Ok(()));
+
+        let member_id = 1;
+        let account_id = 1;
+        increase_account_balance(&account_id, initial_balance);
+
+        AnnounceWorkEntryFixture::default()
+            .with_origin(RawOrigin::Signed(account_id))
+            .with_member_id(member_id)
+            .with_staking_account_id(account_id)
+            .with_bounty_id(bounty_id)
+            .call_and_assert(Ok(()));
+
+        SubmitWorkFixture::default()
+            .with_origin(RawOrigin::Signed(account_id))
+            .with_member_id(member_id)
+            .call_and_assert(Ok(()));
+
+        EndWorkPeriodFixture::default()
+            .with_bounty_id(bounty_id)
+            .with_origin(RawOrigin::Root)
+            .call_and_assert(Ok(()));
+
+        run_to_block(starting_block + judging_period + 1);
+
+        let bounty = Bounty::ensure_bounty_exists(&bounty_id).unwrap();
+        assert_eq!(
+            Bounty::get_bounty_stage(&bounty),
+            BountyStage::FailedBountyWithdrawal
+        );
+
+        // No panel member gets the reward, the whole oracle reward is returned to the creator.
+        let council_budget = get_council_budget();
+
+        WithdrawOraclePanelRewardFixture::default()
+            .with_origin(RawOrigin::Signed(6))
+            .with_member_id(6)
+            .call_and_assert(Ok(()));
+
+        assert_eq!(Balances::usable_balance(&5), 0);
+        assert_eq!(Balances::usable_balance(&6), 0);
+        assert_eq!(get_council_budget(), council_budget + oracle_reward);
+
+        EventFixture::assert_last_crate_event(RawEvent::OraclePanelRewardWithheld(
+            bounty_id,
+            oracle_reward,
+        ));
+
+        WithdrawOraclePanelRewardFixture::default()
+            .with_origin(RawOrigin::Signed(5))
+            .with_member_id(5)
+            .call_and_assert(Err(Error::<Test>::OracleRewardAlreadyWithdrawn.into()));
+    });
+}
+
+#[test]
+fn submit_council_oracle_panel_judgment_succeeded() {
+    build_test_externalities().execute_with(|| {
+        let starting_block = 1;
+        run_to_block(starting_block);
+
+        let initial_balance = 500;
+        let target_funding = 100;
+        let oracle_reward = 30;
+        let council_member_ids = vec![5, 6, 7];
+        set_council_budget(initial_balance);
+        set_council_member_ids(council_member_ids);
+
+        CreateBountyFixture::default()
+            .with_limit_period_target_amount(target_funding)
+            .with_oracle_reward(oracle_reward)
+            .with_council_oracle_panel(Vec::new(), 2, 5)
+            .call_and_assert(Ok(()));
+
+        let bounty_id = 1;
+
+        FundBountyFixture::default()
+            .with_bounty_id(bounty_id)
+            .with_amount(target_funding)
+            .with_council()
+            .with_origin(RawOrigin::Root)
+            .call_and_assert(Ok(()));
+
+        let member_id = 1;
+        let account_id = 1;
+        increase_account_balance(&account_id, initial_balance);
+
+        AnnounceWorkEntryFixture::default()
+            .with_origin(RawOrigin::Signed(account_id))
+            .with_member_id(member_id)
+            .with_staking_account_id(account_id)
+            .with_bounty_id(bounty_id)
+            .call_and_assert(Ok(()));
+
+        let entry_id = 1;
+
+        SubmitWorkFixture::default()
+            .with_origin(RawOrigin::Signed(account_id))
+            .with_member_id(member_id)
+            .with_entry_id(entry_id)
+            .call_and_assert(Ok(()));
+
+        EndWorkPeriodFixture::default()
+            .with_bounty_id(bounty_id)
+            .with_origin(RawOrigin::Root)
+            .call_and_assert(Ok(()));
+
+        let mut judgment: OracleJudgment<u64, u64> = BTreeMap::new();
+        judgment.insert(
+            entry_id,
+            OracleWorkEntryJudgment::Winner {
+                reward: target_funding,
+            },
+        );
+
+        let not_a_council_member_id = 8;
+        SubmitOraclePanelJudgmentFixture::default()
+            .with_origin(RawOrigin::Signed(not_a_council_member_id.into()))
+            .with_member_id(not_a_council_member_id)
+            .with_judgment(judgment.clone())
+            .call_and_assert(Err(Error::<Test>::NotAnOraclePanelMember.into()));
+
+        SubmitOraclePanelJudgmentFixture::default()
+            .with_origin(RawOrigin::Signed(5))
+            .with_member_id(5)
+            .with_judgment(judgment.clone())
+            .call_and_assert(Ok(()));
+
+        SubmitOraclePanelJudgmentFixture::default()
+            .with_origin(RawOrigin::Signed(6))
+            .with_member_id(6)
+            .with_judgment(judgment.clone())
+            .call_and_assert(Ok(()));
+
+        let bounty = Bounty::ensure_bounty_exists(&bounty_id).unwrap();
+        assert_eq!(
+            Bounty::get_bounty_stage(&bounty),
+            BountyStage::SuccessfulBountyWithdrawal
+        );
+
+        // The share of the council member that didn't participate is returned to the creator.
+        let reward_share = oracle_reward / 3;
+        assert_eq!(Balances::usable_balance(&5), reward_share);
+        assert_eq!(Balances::usable_balance(&6), reward_share);
+        assert_eq!(Balances::usable_balance(&7), 0);
+
+        EventFixture::contains_crate_event(RawEvent::OraclePanelRewardWithheld(
+            bounty_id,
+            reward_share,
+        ));
+    });
+}
+
+#[test]
+fn create_bounty_fails_with_invalid_council_oracle_panel() {
+    build_test_externalities().execute_with(|| {
+        set_council_budget(500);
+
+        CreateBountyFixture::default()
+            .with_council_oracle_panel(Vec::new(), 1, 5)
+            .call_and_assert(Err(Error::<Test>::OraclePanelIsEmpty.into()));
+
+        set_council_member_ids(vec![5, 6, 7]);
+
+        CreateBountyFixture::default()
+            .with_council_oracle_panel(vec![5, 6], 1, 5)
+            .call_and_assert(Err(
+                Error::<Test>::CouncilOraclePanelCannotHaveMembers.into()
+            ));
+
+        CreateBountyFixture::default()
+            .with_council_oracle_panel(Vec::new(), 4, 5)
+            .call_and_assert(Err(Error::<Test>::InvalidOraclePanelThreshold.into()));
+
+        CreateBountyFixture::default()
+            .with_council_oracle_panel(Vec::new(), 1, 5)
+            .call_and_assert(Ok(()));
+
+        // Council oracle panel members are the current council members.
+        SwitchOraclePanelMemberFixture::default()
+            .with_current_member_id(5)
+            .with_new_member_id(8)
+            .call_and_assert(Err(
+                Error::<Test>::CannotSwitchCouncilOraclePanelMember.into()
+            ));
+    });
+}
+
+#[test]
+fn switch_oracle_panel_member_succeeded() {
+    build_test_externalities().execute_with(|| {
+        let starting_block = 1;
+        run_to_block(starting_block);
+
+        set_council_budget(500);
+
+        CreateBountyFixture::default()
+            .with_oracle_panel(vec![5, 6], 2, 5)
+            .call_and_assert(Ok(()));
+
+        let bounty_id = 1;
+
+        SwitchOraclePanelMemberFixture::default()
+            .with_current_member_id(5)
+            .with_new_member_id(7)
+            .call_and_assert(Ok(()));
+
+        EventFixture::assert_last_crate_event(RawEvent::OraclePanelMemberSwitched(
+            bounty_id,
+            BountyActor::Council,
+            5,
+            7,
+        ));
+
+        // A panel member can hand over its seat.
+        SwitchOraclePanelMemberFixture::default()
+            .with_origin(RawOrigin::Signed(6))
+            .with_current_member_id(6)
+            .with_new_member_id(8)
+            .call_and_assert(Ok(()));
+
+        EventFixture::assert_last_crate_event(RawEvent::OraclePanelMemberSwitched(
+            bounty_id,
+            BountyActor::Member(6),
+            6,
+            8,
+        ));
+    });
+}
+
+#[test]
+fn switch_oracle_panel_member_fails_with_invalid_parameters() {
+    build_test_externalities().execute_with(|| {
+        set_council_budget(500);
+
+        CreateBountyFixture::default().call_and_assert(Ok(()));
+
+        SwitchOraclePanelMemberFixture::default()
+            .with_current_member_id(5)
+            .with_new_member_id(7)
+            .call_and_assert(Err(Error::<Test>::BountyHasNoOraclePanel.into()));
+
+        CreateBountyFixture::default()
+            .with_oracle_panel(vec![5, 6], 2, 5)
+            .call_and_assert(Ok(()));
+
+        let bounty_id = 2;
+
+        SwitchOraclePanelMemberFixture::default()
+            .with_bounty_id(bounty_id)
+            .with_current_member_id(7)
+            .with_new_member_id(8)
+            .call_and_assert(Err(Error::<Test>::NotAnOraclePanelMember.into()));
+
+        SwitchOraclePanelMemberFixture::default()
+            .with_bounty_id(bounty_id)
+            .with_current_member_id(5)
+            .with_new_member_id(6)
+            .call_and_assert(Err(Error::<Test>::OraclePanelMemberAlreadyExists.into()));
+
+        SwitchOraclePanelMemberFixture::default()
+            .with_bounty_id(bounty_id)
+            .with_origin(RawOrigin::Signed(7))
+            .with_current_member_id(5)
+            .with_new_member_id(7)
+            .call_and_assert(Err(DispatchError::BadOrigin));
+    });
+}
+
+#[test]
+fn escrowed_oracle_judgment_is_finalized_after_appeal_period() {
+    build_test_externalities().execute_with(|| {
+        let starting_block = 1;
+        run_to_block(starting_block);
+
+        let appeal_period = 5;
+        submit_judgment_with_appeal_period(appeal_period);
+
+        let bounty_id = 1;
+        let bounty = Bounty::ensure_bounty_exists(&bounty_id).unwrap();
+        assert_eq!(
+            Bounty::get_bounty_stage(&bounty),
+            BountyStage::JudgmentAppeal
+        );
+
+        // Rewards and slashes are held in escrow.
+        let initial_balance = 500;
+        let slashed_amount = APPEAL_ENTRANT_STAKE / 2;
+        for member_id in [APPEAL_WINNER_ID, APPEAL_REJECTED_ID] {
+            assert_eq!(
+                Balances::usable_balance(&member_id.into()),
+                initial_balance - APPEAL_ENTRANT_STAKE
+            );
+            assert!(Bounty::entries(bounty_id, member_id).is_some());
+        }
+
+        FinalizeOracleJudgmentFixture::default()
+            .with_judged_entries_witness(2)
+            .call_and_assert(Err(Error::<Test>::JudgmentAppealPeriodNotExpired.into()));
+
+        run_to_block(starting_block + appeal_period + 1);
+
+        FinalizeOracleJudgmentFixture::default()
+            .with_judged_entries_witness(1)
+            .call_and_assert(Err(Error::<Test>::InvalidJudgedEntriesWitness.into()));
+
+        FinalizeOracleJudgmentFixture::default()
+            .with_judged_entries_witness(2)
+            .call_and_assert(Ok(()));
+
+        EventFixture::contains_crate_event(RawEvent::WorkEntrantFundsWithdrawn(
+            bounty_id,
+            APPEAL_WINNER_ID,
+            APPEAL_WINNER_ID,
+        ));
+        EventFixture::contains_crate_event(RawEvent::WorkEntrantStakeSlashed(
+            bounty_id,
+            APPEAL_REJECTED_ID,
+            APPEAL_REJECTED_ID.into(),
+            slashed_amount,
+        ));
+        EventFixture::contains_crate_event(RawEvent::OracleJudgmentFinalized(bounty_id));
+
+        assert_eq!(
+            Balances::usable_balance(&APPEAL_WINNER_ID.into()),
+            initial_balance + APPEAL_TARGET_FUNDING
+        );
+        assert_eq!(
+            Balances::usable_balance(&APPEAL_REJECTED_ID.into()),
+            initial_balance - slashed_amount
+        );
+
+        let bounty = Bounty::ensure_bounty_exists(&bounty_id).unwrap();
+        assert_eq!(
+            Bounty::get_bounty_stage(&bounty),
+            BountyStage::SuccessfulBountyWithdrawal
+        );
+        assert_eq!(bounty.active_work_entry_count, 0);
+    });
+}
+
+#[test]
+fn disputed_oracle_judgment_is_overturned_by_council() {
+    build_test_externalities().execute_with(|| {
+        let starting_block = 1;
+        run_to_block(starting_block);
+
+        submit_judgment_with_appeal_period(5);
+
+        let bounty_id = 1;
+        let initial_balance = 500;
+        let dispute_stake = <Test as Config>::JudgmentDisputeStake::get();
+
+        OverturnOracleJudgmentFixture::default()
+            .call_and_assert(Err(Error::<Test>::JudgmentIsNotDisputed.into()));
+
+        // The winner is not slashed by the judgment.
+        OpenJudgmentDisputeFixture::default()
+            .with_origin(RawOrigin::Signed(APPEAL_WINNER_ID.into()))
+            .with_member_id(APPEAL_WINNER_ID)
+            .with_entry_id(APPEAL_WINNER_ID)
+            .call_and_assert(Err(Error::<Test>::NotEligibleToDisputeJudgment.into()));
+
+        // Not a contributor.
+        OpenJudgmentDisputeFixture::default()
+            .with_origin(RawOrigin::Signed(APPEAL_REJECTED_ID.into()))
+            .with_member_id(APPEAL_REJECTED_ID)
+            .call_and_assert(Err(Error::<Test>::NotEligibleToDisputeJudgment.into()));
+
+        OpenJudgmentDisputeFixture::default()
+            .with_origin(RawOrigin::Signed(APPEAL_REJECTED_ID.into()))
+            .with_member_id(APPEAL_REJECTED_ID)
+            .with_entry_id(APPEAL_REJECTED_ID)
+            .call_and_assert(Ok(()));
+
+        EventFixture::assert_last_crate_event(RawEvent::JudgmentDisputeOpened(
+            bounty_id,
+            APPEAL_REJECTED_ID,
+            dispute_stake,
+            Vec::new(),
+        ));
+
+        OpenJudgmentDisputeFixture::default()
+            .with_origin(RawOrigin::Signed(APPEAL_CONTRIBUTOR_ID.into()))
+            .with_member_id(APPEAL_CONTRIBUTOR_ID)
+            .call_and_assert(Err(Error::<Test>::JudgmentAlreadyDisputed.into()));
+
+        OverturnOracleJudgmentFixture::default()
+            .with_origin(RawOrigin::Signed(APPEAL_CONTRIBUTOR_ID.into()))
+            .call_and_assert(Err(DispatchError::BadOrigin));
+
+        let council_budget = get_council_budget();
+
+        OverturnOracleJudgmentFixture::default().call_and_assert(Ok(()));
+
+        EventFixture::assert_last_crate_event(RawEvent::OracleJudgmentOverturned(bounty_id));
+
+        // The dispute stake is returned and the oracle reward goes back to the creator.
+        assert_eq!(
+            Balances::usable_balance(&APPEAL_REJECTED_ID.into()),
+            initial_balance - APPEAL_ENTRANT_STAKE
+        );
+        assert_eq!(get_council_budget(), council_budget + APPEAL_ORACLE_REWARD);
+
+        let bounty = Bounty::ensure_bounty_exists(&bounty_id).unwrap();
+        assert_eq!(
+            Bounty::get_bounty_stage(&bounty),
+            BountyStage::FailedBountyWithdrawal
+        );
+        assert!(!bounty.has_unpaid_oracle_reward);
+
+        // Entrants unlock their full stakes.
+        for member_id in [APPEAL_WINNER_ID, APPEAL_REJECTED_ID] {
+            WithdrawEntrantStakeFixture::default()
+                .with_origin(RawOrigin::Signed(member_id.into()))
+                .with_member_id(member_id)
+                .with_entry_id(member_id)
+                .call_and_assert(Ok(()));
+
+            assert_eq!(Balances::usable_balance(&member_id.into()), initial_balance);
+            assert!(Bounty::escrowed_judgments(bounty_id, member_id).is_none());
+        }
+    });
+}
+
+#[test]
+fn disputed_oracle_judgment_is_finalized_after_dispute_period() {
+    build_test_externalities().execute_with(|| {
+        let starting_block = 1;
+        run_to_block(starting_block);
+
+        let appeal_period = 5;
+        submit_judgment_with_appeal_period(appeal_period);
+
+        let bounty_id = 1;
+        let dispute_stake = <Test as Config>::JudgmentDisputeStake::get();
+        let dispute_period = <Test as Config>::JudgmentDisputePeriod::get();
+
+        let contributor_balance = Balances::usable_balance(&APPEAL_CONTRIBUTOR_ID.into());
+
+        OpenJudgmentDisputeFixture::default()
+            .with_origin(RawOrigin::Signed(APPEAL_CONTRIBUTOR_ID.into()))
+            .with_member_id(APPEAL_CONTRIBUTOR_ID)
+            .call_and_assert(Ok(()));
+
+        run_to_block(starting_block + appeal_period + 1);
+
+        FinalizeOracleJudgmentFixture::default()
+            .with_judged_entries_witness(2)
+            .call_and_assert(Err(Error::<Test>::JudgmentDisputePeriodNotExpired.into()));
+
+        run_to_block(starting_block + dispute_period + 1);
+
+        OverturnOracleJudgmentFixture::default()
+            .call_and_assert(Err(Error::<Test>::JudgmentDisputePeriodExpired.into()));
+
+        FinalizeOracleJudgmentFixture::default()
+            .with_judged_entries_witness(2)
+            .call_and_assert(Ok(()));
+
+        EventFixture::contains_crate_event(RawEvent::JudgmentDisputeStakeSlashed(
+            bounty_id,
+            APPEAL_CONTRIBUTOR_ID,
+            dispute_stake,
+        ));
+
+        assert_eq!(
+            Balances::usable_balance(&APPEAL_CONTRIBUTOR_ID.into()),
+            contributor_balance - dispute_stake
+        );
+
+        let bounty = Bounty::ensure_bounty_exists(&bounty_id).unwrap();
+        assert_eq!(
+            Bounty::get_bounty_stage(&bounty),
+            BountyStage::SuccessfulBountyWithdrawal
+        );
+    });
+}
+
+#[test]
+fn open_judgment_dispute_fails_after_appeal_period() {
+    build_test_externalities().execute_with(|| {
+        let starting_block = 1;
+        run_to_block(starting_block);
+
+        let appeal_period = 5;
+        submit_judgment_with_appeal_period(appeal_period);
+
+        run_to_block(starting_block + appeal_period + 1);
+
+        OpenJudgmentDisputeFixture::default()
+            .with_origin(RawOrigin::Signed(APPEAL_CONTRIBUTOR_ID.into()))
+            .with_member_id(APPEAL_CONTRIBUTOR_ID)
+            .call_and_assert(Err(Error::<Test>::JudgmentAppealPeriodExpired.into()));
+    });
+}
+
+#[test]
+fn create_bounty_fails_with_invalid_creator_token() {
+    build_test_externalities().execute_with(|| {
+        set_council_budget(500);
+
+        CreateBountyFixture::default()
+            .with_token_id(1)
+            .call_and_assert(Err(Error::<Test>::CreatorTokenDoesNotExist.into()));
+    });
+}
+
+#[test]
+fn fund_token_bounty_escrows_creator_tokens() {
+    build_test_externalities().execute_with(|| {
+        let token_id = issue_creator_token(&[(TOKEN_CONTRIBUTOR_ID, TOKEN_INITIAL_ALLOCATION)]);
+
+        create_and_fund_token_bounty(token_id);
+
+        // Only the state bloat bond is paid in JOY.
+        assert_eq!(
+            Balances::usable_balance(&TOKEN_CONTRIBUTOR_ID.into()),
+            500 - get_funder_state_bloat_bond_amount()
+        );
+        assert_eq!(
+            token_balance(token_id, TOKEN_CONTRIBUTOR_ID),
+            TOKEN_INITIAL_ALLOCATION - TOKEN_TARGET_FUNDING
+        );
+        assert_eq!(Bounty::bounties(1).total_funding, TOKEN_TARGET_FUNDING);
+        // The creator tokens are escrowed for the bounty account.
+        assert_eq!(
+            Token::escrowed_tokens(token_id, Bounty::bounty_account_id(1)),
+            TOKEN_TARGET_FUNDING
+        );
+        assert_eq!(
+            Token::escrowed_tokens(token_id, Bounty::bounty_account_id(2)),
+            0
+        );
+    });
+}
+
+#[test]
+fn fund_token_bounty_fails_with_insufficient_token_balance() {
+    build_test_externalities().execute_with(|| {
+        let token_id = issue_creator_token(&[(TOKEN_CONTRIBUTOR_ID, TOKEN_TARGET_FUNDING - 1)]);
+
+        set_council_budget(500);
+
+        CreateBountyFixture::default()
+            .with_limit_period_target_amount(TOKEN_TARGET_FUNDING)
+            .with_token_id(token_id)
+            .call_and_assert(Ok(()));
+
+        increase_account_balance(&TOKEN_CONTRIBUTOR_ID.into(), 500);
+        FundBountyFixture::default()
+            .with_origin(RawOrigin::Signed(TOKEN_CONTRIBUTOR_ID.into()))
+            .with_member_id(TOKEN_CONTRIBUTOR_ID)
+            .with_amount(TOKEN_TARGET_FUNDING)
+            .call_and_assert(Err(
+                project_token::Error::<Test>::InsufficientTransferrableBalance.into(),
+            ));
+    });
+}
+
+#[test]
+fn fund_token_bounty_by_council_fails() {
+    build_test_externalities().execute_with(|| {
+        let token_id = issue_creator_token(&[]);
+
+        set_council_budget(500);
+
+        CreateBountyFixture::default()
+            .with_token_id(token_id)
+            .call_and_assert(Ok(()));
+
+        FundBountyFixture::default()
+            .with_council()
+            .with_origin(RawOrigin::Root)
+            .call_and_assert(Err(
+                Error::<Test>::CouncilCannotFundCreatorTokenBounty.into()
+            ));
+    });
+}
+
+#[test]
+fn token_bounty_winner_is_rewarded_in_creator_tokens() {
+    build_test_externalities().execute_with(|| {
+        let token_id = issue_creator_token(&[
+            (TOKEN_CONTRIBUTOR_ID, TOKEN_INITIAL_ALLOCATION),
+            (TOKEN_WINNER_ID, 0),
+        ]);
+
+        create_and_fund_token_bounty(token_id);
+        submit_token_bounty_work(TOKEN_WINNER_ID);
+
+        let mut judgment: OracleJudgment<u64, u64> = BTreeMap::new();
+        judgment.insert(
+            1,
+            OracleWorkEntryJudgment::Winner {
+                reward: TOKEN_TARGET_FUNDING,
+            },
+        );
+
+        SubmitJudgmentFixture::default()
+            .with_judgment(judgment)
+            .call_and_assert(Ok(()));
+
+        assert_eq!(
+            token_balance(token_id, TOKEN_WINNER_ID),
+            TOKEN_TARGET_FUNDING
+        );
+        // The winner gets the JOY stake back, but no JOY reward.
+        assert_eq!(Balances::usable_balance(&TOKEN_WINNER_ID.into()), 500);
+    });
+}
+
+#[test]
+fn submit_judgment_fails_with_token_bounty_winner_without_token_account() {
+    build_test_externalities().execute_with(|| {
+        let token_id = issue_creator_token(&[(TOKEN_CONTRIBUTOR_ID, TOKEN_INITIAL_ALLOCATION)]);
+
+        create_and_fund_token_bounty(token_id);
+        submit_token_bounty_work(TOKEN_WINNER_ID);
+
+        let mut judgment: OracleJudgment<u64, u64> = BTreeMap::new();
+        judgment.insert(
+            1,
+            OracleWorkEntryJudgment::Winner {
+                reward: TOKEN_TARGET_FUNDING,
+            },
+        );
+
+        SubmitJudgmentFixture::default()
+            .with_judgment(judgment)
+            .call_and_assert(Err(
+                project_token::Error::<Test>::AccountInformationDoesNotExist.into(),
+            ));
+    });
+}
+
+#[test]
+fn failed_token_bounty_funding_is_refunded_in_creator_tokens() {
+    build_test_externalities().execute_with(|| {
+        let token_id = issue_creator_token(&[(TOKEN_CONTRIBUTOR_ID, TOKEN_INITIAL_ALLOCATION)]);
+
+        create_and_fund_token_bounty(token_id);
+
+        TerminateBountyFixture::default().call_and_assert(Ok(()));
+
+        WithdrawFundingFixture::default()
+            .with_origin(RawOrigin::Signed(TOKEN_CONTRIBUTOR_ID.into()))
+            .with_member_id(TOKEN_CONTRIBUTOR_ID)
+            .call_and_assert(Ok(()));
+
+        assert_eq!(
+            token_balance(token_id, TOKEN_CONTRIBUTOR_ID),
+            TOKEN_INITIAL_ALLOCATION
+        );
+        // The state bloat bond and the whole cherry are returned in JOY.
+        assert_eq!(
+            Balances::usable_balance(&TOKEN_CONTRIBUTOR_ID.into()),
+            500 + DEFAULT_BOUNTY_CHERRY
+        );
+    });
+}
+
+#[test]
+fn finalize_oracle_judgment_fails_without_mutation_when_winner_cannot_be_paid() {
+    build_test_externalities().execute_with(|| {
+        let starting_block = 1;
+        run_to_block(starting_block);
+
+        let token_id = issue_creator_token(&[
+            (TOKEN_CONTRIBUTOR_ID, TOKEN_INITIAL_ALLOCATION),
+            (TOKEN_WINNER_ID, 0),
+        ]);
+
+        let appeal_period = 5;
+        set_council_budget(500);
+
+        CreateBountyFixture::default()
+            .with_limit_period_target_amount(TOKEN_TARGET_FUNDING)
+            .with_token_id(token_id)
+            .with_appeal_period(appeal_period)
+            .call_and_assert(Ok(()));
+
+        increase_account_balance(&TOKEN_CONTRIBUTOR_ID.into(), 500);
+        FundBountyFixture::default()
+            .with_origin(RawOrigin::Signed(TOKEN_CONTRIBUTOR_ID.into()))
+            .with_member_id(TOKEN_CONTRIBUTOR_ID)
+            .with_amount(TOKEN_TARGET_FUNDING)
+            .call_and_assert(Ok(()));
+
+        submit_token_bounty_work(TOKEN_WINNER_ID);
+
+        let mut judgment: OracleJudgment<u64, u64> = BTreeMap::new();
+        judgment.insert(
+            1,
+            OracleWorkEntryJudgment::Winner {
+                reward: TOKEN_TARGET_FUNDING,
+            },
+        );
+
+        SubmitJudgmentFixture::default()
+            .with_judgment(judgment)
+            .call_and_assert(Ok(()));
+
+        let bounty_id = 1;
+        let dispute_period = <Test as Config>::JudgmentDisputePeriod::get();
+
+        OpenJudgmentDisputeFixture::default()
+            .with_origin(RawOrigin::Signed(TOKEN_CONTRIBUTOR_ID.into()))
+            .with_member_id(TOKEN_CONTRIBUTOR_ID)
+            .call_and_assert(Ok(()));
+
+        run_to_block(starting_block + dispute_period + 1);
+
+        // The escrowed creator tokens cannot be released while the token pallet is frozen.
+        assert_ok!(Token::set_frozen_status(RawOrigin::Root.into(), true));
+
+        FinalizeOracleJudgmentFixture::default()
+            .call_and_assert(Err(project_token::Error::<Test>::PalletFrozen.into()));
+
+        // The dispute stake is not slashed and the judgment is still escrowed.
+        assert!(Bounty::judgment_disputes(bounty_id).is_some());
+        assert!(Bounty::escrowed_judgments(bounty_id, 1).is_some());
+        assert_eq!(token_balance(token_id, TOKEN_WINNER_ID), 0);
+
+        assert_ok!(Token::set_frozen_status(RawOrigin::Root.into(), false));
+
+        FinalizeOracleJudgmentFixture::default().call_and_assert(Ok(()));
+
+        assert_eq!(
+            token_balance(token_id, TOKEN_WINNER_ID),
+            TOKEN_TARGET_FUNDING
+        );
+    });
+}
+
+#[test]
+fn switch_oracle_to_council_by_council_successful() {
+    build_test_externalities().execute_with(|| {
+        let starting_block = 1;
+        run_to_block(starting_block);
+
+        let initial_balance = 500;
+        let actual_oracle_member_id = 5;
+
+        set_council_budget(initial_balance);
+
+        CreateBountyFixture::default()
+            .with_oracle_member_id(actual_oracle_member_id)
+            .call_and_assert(Ok(()));
+
+        let bounty_id = 1u64;
+
+        SwitchOracleFixture::default()
+            .with_new_oracle_member_id(BountyActor::Council)
+            .call_and_assert(Ok(()));
+
+        EventFixture::assert_last_crate_event(RawEvent::BountyOracleSwitched(
+            bounty_id,
+            BountyActor::Council,
+            BountyActor::Member(actual_oracle_member_id),
+            BountyActor::Council,
+        ));
+    });
+}
+
+#[test]
+fn switch_oracle_to_member_by_oracle_council_successful() {
+    build_test_externalities().execute_with(|| {
+        let starting_block = 1;
+        run_to_block(starting_block);
+
+        let initial_balance = 500;
+        let new_oracle_member_id = 5;
+
+        set_council_budget(initial_balance);
+
+        CreateBountyFixture::default().call_and_assert(Ok(()));
+        let bounty_id = 1u64;
+
+        SwitchOracleFixture::default()
+            .with_new_oracle_member_id(BountyActor::Member(new_oracle_member_id))
+            .call_and_assert(Ok(()));
+
+        EventFixture::assert_last_crate_event(RawEvent::BountyOracleSwitched(
+            bounty_id,
+            BountyActor::Council,
+            BountyActor::Council,
+            BountyActor::Member(new_oracle_member_id),
+        ));
+    });
+}
+
+#[test]
+fn switch_oracle_to_member_by_council_successful() {
+    build_test_externalities().execute_with(|| {
+        let starting_block = 1;
+        run_to_block(starting_block);
+
+        let initial_balance = 500;
+        let current_oracle_member_id = 2;
+        let new_oracle_member_id = 5;
+
+        set_council_budget(initial_balance);
+
+        let create_bounty_fixture =
+            CreateBountyFixture::default().with_oracle_member_id(current_oracle_member_id);
+
+        create_bounty_fixture.call_and_assert(Ok(()));
+        let bounty_id = 1u64;
+
+        EventFixture::assert_last_crate_event(RawEvent::BountyCreated(
+            bounty_id,
+            create_bounty_fixture.get_bounty_creation_parameters(),
+            Vec::new(),
+        ));
+
+        SwitchOracleFixture::default()
+            .with_new_oracle_member_id(BountyActor::Member(new_oracle_member_id))
+            .call_and_assert(Ok(()));
 
         EventFixture::assert_last_crate_event(RawEvent::BountyOracleSwitched(
             bounty_id,
@@ -6848,3 +8586,43 @@ fn entrant_remark_fails_invalid_member_controller_account() {
         );
     });
 }
+
+#[test]
+fn migrate_to_v1_translates_bounties() {
+    build_test_externalities().execute_with(|| {
+        let starting_block = 1;
+        run_to_block(starting_block);
+
+        set_council_budget(500);
+
+        CreateBountyFixture::default().call_and_assert(Ok(()));
+
+        let bounty_id = 1u64;
+        let bounty = Bounty::bounties(bounty_id);
+
+        // Simulate the bounty created before the storage upgrade.
+        let params = bounty.creation_params.clone();
+        let old_bounty = OldBountyRecord {
+            creation_params: OldBountyParameters {
+                oracle: params.oracle,
+                contract_type: params.contract_type,
+                creator: params.creator,
+                cherry: params.cherry,
+                oracle_reward: params.oracle_reward,
+                entrant_stake: params.entrant_stake,
+                funding_type: params.funding_type,
+            },
+            total_funding: bounty.total_funding,
+            milestone: bounty.milestone.clone(),
+            active_work_entry_count: bounty.active_work_entry_count,
+            has_unpaid_oracle_reward: bounty.has_unpaid_oracle_reward,
+        };
+        unhashed::put(&Bounties::<Test>::hashed_key_for(bounty_id), &old_bounty);
+        StorageVersion::new(0).put::<Bounty>();
+
+        MigrateToV1::<Test>::on_runtime_upgrade();
+
+        assert_eq!(Bounty::bounties(bounty_id), bounty);
+        assert_eq!(Bounty::on_chain_storage_version(), StorageVersion::new(1));
+    });
+}
@@ -20,6 +20,9 @@
 //! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 4.0.0-dev
 //! DATE: 2023-11-07, STEPS: `50`, REPEAT: 20, LOW RANGE: `[]`, HIGH RANGE: `[]`
 //! EXECUTION: Some(Wasm), WASM-EXECUTION: Compiled, CHAIN: Some("prod-test"), DB CACHE: 1024
+//!
+//! NOTE: the weight functions marked as PLACEHOLDER are hand-written estimates, not the
+//! benchmark output. They must be regenerated with the benchmark CLI before merging.
 
 // Executed Command:
 // ./scripts/../target/release/joystream-node
@@ -76,6 +79,12 @@ pub trait WeightInfo {
 	fn contributor_remark(_i: u32, ) -> Weight;
 	fn oracle_remark(_i: u32, ) -> Weight;
 	fn creator_remark(_i: u32, ) -> Weight;
+	fn submit_oracle_panel_judgment(_p: u32, ) -> Weight;
+	fn switch_oracle_panel_member() -> Weight;
+	fn withdraw_oracle_panel_reward(_p: u32, ) -> Weight;
+	fn open_judgment_dispute(_i: u32, ) -> Weight;
+	fn overturn_oracle_judgment() -> Weight;
+	fn finalize_oracle_judgment(_e: u32, ) -> Weight;
 }
 
 /// Weights for bounty using the Substrate node and recommended hardware.
@@ -90,7 +99,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	// Storage: System Account (r:1 w:1)
 	// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
 	// Storage: Bounty Bounties (r:0 w:1)
-	// Proof: Bounty Bounties (max_values: None, max_size: Some(540), added: 3015, mode: MaxEncodedLen)
+	// Proof: Bounty Bounties (max_values: None, max_size: Some(793), added: 3268, mode: MaxEncodedLen)
 	/// The range of component `i` is `[1, 100]`.
 	/// The range of component `j` is `[1, 50]`.
 	fn create_bounty_by_council(i: u32, j: u32, ) -> Weight {
@@ -116,7 +125,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	// Storage: Bounty BountyCount (r:1 w:1)
 	// Proof: Bounty BountyCount (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
 	// Storage: Bounty Bounties (r:0 w:1)
-	// Proof: Bounty Bounties (max_values: None, max_size: Some(540), added: 3015, mode: MaxEncodedLen)
+	// Proof: Bounty Bounties (max_values: None, max_size: Some(793), added: 3268, mode: MaxEncodedLen)
 	/// The range of component `i` is `[1, 100]`.
 	/// The range of component `j` is `[1, 50]`.
 	fn create_bounty_by_member(i: u32, j: u32, ) -> Weight {
@@ -136,7 +145,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(Weight::from_parts(0, 2600).saturating_mul(j.into()))
 	}
 	// Storage: Bounty Bounties (r:1 w:1)
-	// Proof: Bounty Bounties (max_values: None, max_size: Some(540), added: 3015, mode: MaxEncodedLen)
+	// Proof: Bounty Bounties (max_values: None, max_size: Some(793), added: 3268, mode: MaxEncodedLen)
 	// Storage: Bounty BountyContributions (r:1 w:0)
 	// Proof: Bounty BountyContributions (max_values: None, max_size: Some(81), added: 2556, mode: MaxEncodedLen)
 	// Storage: System Account (r:1 w:1)
@@ -154,7 +163,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().writes(3_u64))
 	}
 	// Storage: Bounty Bounties (r:1 w:1)
-	// Proof: Bounty Bounties (max_values: None, max_size: Some(540), added: 3015, mode: MaxEncodedLen)
+	// Proof: Bounty Bounties (max_values: None, max_size: Some(793), added: 3268, mode: MaxEncodedLen)
 	// Storage: Bounty BountyContributions (r:1 w:0)
 	// Proof: Bounty BountyContributions (max_values: None, max_size: Some(81), added: 2556, mode: MaxEncodedLen)
 	// Storage: System Account (r:1 w:1)
@@ -172,7 +181,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().writes(3_u64))
 	}
 	// Storage: Bounty Bounties (r:1 w:1)
-	// Proof: Bounty Bounties (max_values: None, max_size: Some(540), added: 3015, mode: MaxEncodedLen)
+	// Proof: Bounty Bounties (max_values: None, max_size: Some(793), added: 3268, mode: MaxEncodedLen)
 	// Storage: Bounty BountyContributions (r:1 w:0)
 	// Proof: Bounty BountyContributions (max_values: None, max_size: Some(81), added: 2556, mode: MaxEncodedLen)
 	// Storage: System Account (r:1 w:1)
@@ -190,7 +199,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().writes(3_u64))
 	}
 	// Storage: Bounty Bounties (r:1 w:1)
-	// Proof: Bounty Bounties (max_values: None, max_size: Some(540), added: 3015, mode: MaxEncodedLen)
+	// Proof: Bounty Bounties (max_values: None, max_size: Some(793), added: 3268, mode: MaxEncodedLen)
 	// Storage: Bounty BountyContributions (r:1 w:0)
 	// Proof: Bounty BountyContributions (max_values: None, max_size: Some(81), added: 2556, mode: MaxEncodedLen)
 	// Storage: System Account (r:1 w:1)
@@ -208,7 +217,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().writes(3_u64))
 	}
 	// Storage: Bounty Bounties (r:1 w:1)
-	// Proof: Bounty Bounties (max_values: None, max_size: Some(540), added: 3015, mode: MaxEncodedLen)
+	// Proof: Bounty Bounties (max_values: None, max_size: Some(793), added: 3268, mode: MaxEncodedLen)
 	// Storage: Bounty BountyContributions (r:2 w:0)
 	// Proof: Bounty BountyContributions (max_values: None, max_size: Some(81), added: 2556, mode: MaxEncodedLen)
 	fn terminate_bounty_w_oracle_reward_w_funds_funding() -> Weight {
@@ -222,7 +231,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
 	// Storage: Bounty Bounties (r:1 w:1)
-	// Proof: Bounty Bounties (max_values: None, max_size: Some(540), added: 3015, mode: MaxEncodedLen)
+	// Proof: Bounty Bounties (max_values: None, max_size: Some(793), added: 3268, mode: MaxEncodedLen)
 	// Storage: Bounty BountyContributions (r:2 w:0)
 	// Proof: Bounty BountyContributions (max_values: None, max_size: Some(81), added: 2556, mode: MaxEncodedLen)
 	fn terminate_bounty_wo_oracle_reward_w_funds_funding() -> Weight {
@@ -236,7 +245,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
 	// Storage: Bounty Bounties (r:1 w:1)
-	// Proof: Bounty Bounties (max_values: None, max_size: Some(540), added: 3015, mode: MaxEncodedLen)
+	// Proof: Bounty Bounties (max_values: None, max_size: Some(793), added: 3268, mode: MaxEncodedLen)
 	// Storage: Bounty BountyContributions (r:2 w:0)
 	// Proof: Bounty BountyContributions (max_values: None, max_size: Some(81), added: 2556, mode: MaxEncodedLen)
 	fn terminate_bounty_work_or_judging_period() -> Weight {
@@ -252,7 +261,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	// Storage: Membership MembershipById (r:1 w:0)
 	// Proof: Membership MembershipById (max_values: None, max_size: Some(125), added: 2600, mode: MaxEncodedLen)
 	// Storage: Bounty Bounties (r:1 w:1)
-	// Proof: Bounty Bounties (max_values: None, max_size: Some(540), added: 3015, mode: MaxEncodedLen)
+	// Proof: Bounty Bounties (max_values: None, max_size: Some(793), added: 3268, mode: MaxEncodedLen)
 	// Storage: Bounty BountyContributions (r:1 w:1)
 	// Proof: Bounty BountyContributions (max_values: None, max_size: Some(81), added: 2556, mode: MaxEncodedLen)
 	// Storage: System Account (r:2 w:2)
@@ -268,7 +277,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().writes(4_u64))
 	}
 	// Storage: Bounty Bounties (r:1 w:1)
-	// Proof: Bounty Bounties (max_values: None, max_size: Some(540), added: 3015, mode: MaxEncodedLen)
+	// Proof: Bounty Bounties (max_values: None, max_size: Some(793), added: 3268, mode: MaxEncodedLen)
 	// Storage: Bounty BountyContributions (r:1 w:1)
 	// Proof: Bounty BountyContributions (max_values: None, max_size: Some(81), added: 2556, mode: MaxEncodedLen)
 	// Storage: Council Budget (r:1 w:1)
@@ -288,7 +297,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	// Storage: Membership MembershipById (r:1 w:0)
 	// Proof: Membership MembershipById (max_values: None, max_size: Some(125), added: 2600, mode: MaxEncodedLen)
 	// Storage: Bounty Bounties (r:1 w:0)
-	// Proof: Bounty Bounties (max_values: None, max_size: Some(540), added: 3015, mode: MaxEncodedLen)
+	// Proof: Bounty Bounties (max_values: None, max_size: Some(793), added: 3268, mode: MaxEncodedLen)
 	// Storage: Bounty BountyContributions (r:1 w:1)
 	// Proof: Bounty BountyContributions (max_values: None, max_size: Some(81), added: 2556, mode: MaxEncodedLen)
 	// Storage: System Account (r:2 w:2)
@@ -304,7 +313,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().writes(3_u64))
 	}
 	// Storage: Bounty Bounties (r:1 w:0)
-	// Proof: Bounty Bounties (max_values: None, max_size: Some(540), added: 3015, mode: MaxEncodedLen)
+	// Proof: Bounty Bounties (max_values: None, max_size: Some(793), added: 3268, mode: MaxEncodedLen)
 	// Storage: Bounty BountyContributions (r:1 w:1)
 	// Proof: Bounty BountyContributions (max_values: None, max_size: Some(81), added: 2556, mode: MaxEncodedLen)
 	// Storage: System Account (r:1 w:1)
@@ -324,7 +333,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	// Storage: Membership MembershipById (r:1 w:0)
 	// Proof: Membership MembershipById (max_values: None, max_size: Some(125), added: 2600, mode: MaxEncodedLen)
 	// Storage: Bounty Bounties (r:1 w:1)
-	// Proof: Bounty Bounties (max_values: None, max_size: Some(540), added: 3015, mode: MaxEncodedLen)
+	// Proof: Bounty Bounties (max_values: None, max_size: Some(793), added: 3268, mode: MaxEncodedLen)
 	// Storage: Membership StakingAccountIdMemberStatus (r:1 w:0)
 	// Proof: Membership StakingAccountIdMemberStatus (max_values: None, max_size: Some(57), added: 2532, mode: MaxEncodedLen)
 	// Storage: Balances Locks (r:1 w:1)
@@ -354,7 +363,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	// Storage: Membership MembershipById (r:1 w:0)
 	// Proof: Membership MembershipById (max_values: None, max_size: Some(125), added: 2600, mode: MaxEncodedLen)
 	// Storage: Bounty Bounties (r:1 w:0)
-	// Proof: Bounty Bounties (max_values: None, max_size: Some(540), added: 3015, mode: MaxEncodedLen)
+	// Proof: Bounty Bounties (max_values: None, max_size: Some(793), added: 3268, mode: MaxEncodedLen)
 	// Storage: Bounty Entries (r:1 w:1)
 	// Proof: Bounty Entries (max_values: None, max_size: Some(93), added: 2568, mode: MaxEncodedLen)
 	/// The range of component `i` is `[0, 100]`.
@@ -371,7 +380,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
 	// Storage: Bounty Bounties (r:1 w:1)
-	// Proof: Bounty Bounties (max_values: None, max_size: Some(540), added: 3015, mode: MaxEncodedLen)
+	// Proof: Bounty Bounties (max_values: None, max_size: Some(793), added: 3268, mode: MaxEncodedLen)
 	// Storage: Bounty Entries (r:40 w:40)
 	// Proof: Bounty Entries (max_values: None, max_size: Some(93), added: 2568, mode: MaxEncodedLen)
 	// Storage: Membership MembershipById (r:40 w:0)
@@ -407,7 +416,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(Weight::from_parts(0, 11545).saturating_mul(r.into()))
 	}
 	// Storage: Bounty Bounties (r:1 w:1)
-	// Proof: Bounty Bounties (max_values: None, max_size: Some(540), added: 3015, mode: MaxEncodedLen)
+	// Proof: Bounty Bounties (max_values: None, max_size: Some(793), added: 3268, mode: MaxEncodedLen)
 	// Storage: Membership MembershipById (r:40 w:0)
 	// Proof: Membership MembershipById (max_values: None, max_size: Some(125), added: 2600, mode: MaxEncodedLen)
 	// Storage: Bounty Entries (r:39 w:39)
@@ -443,7 +452,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(Weight::from_parts(0, 11545).saturating_mul(r.into()))
 	}
 	// Storage: Bounty Bounties (r:1 w:1)
-	// Proof: Bounty Bounties (max_values: None, max_size: Some(540), added: 3015, mode: MaxEncodedLen)
+	// Proof: Bounty Bounties (max_values: None, max_size: Some(793), added: 3268, mode: MaxEncodedLen)
 	fn switch_oracle_to_council_by_council_successful() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `184`
@@ -455,7 +464,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
 	// Storage: Bounty Bounties (r:1 w:1)
-	// Proof: Bounty Bounties (max_values: None, max_size: Some(540), added: 3015, mode: MaxEncodedLen)
+	// Proof: Bounty Bounties (max_values: None, max_size: Some(793), added: 3268, mode: MaxEncodedLen)
 	// Storage: Membership MembershipById (r:1 w:0)
 	// Proof: Membership MembershipById (max_values: None, max_size: Some(125), added: 2600, mode: MaxEncodedLen)
 	fn switch_oracle_to_member_by_oracle_council() -> Weight {
@@ -469,7 +478,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
 	// Storage: Bounty Bounties (r:1 w:1)
-	// Proof: Bounty Bounties (max_values: None, max_size: Some(540), added: 3015, mode: MaxEncodedLen)
+	// Proof: Bounty Bounties (max_values: None, max_size: Some(793), added: 3268, mode: MaxEncodedLen)
 	// Storage: Membership MembershipById (r:1 w:0)
 	// Proof: Membership MembershipById (max_values: None, max_size: Some(125), added: 2600, mode: MaxEncodedLen)
 	fn switch_oracle_to_member_by_council() -> Weight {
@@ -483,7 +492,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
 	// Storage: Bounty Bounties (r:1 w:1)
-	// Proof: Bounty Bounties (max_values: None, max_size: Some(540), added: 3015, mode: MaxEncodedLen)
+	// Proof: Bounty Bounties (max_values: None, max_size: Some(793), added: 3268, mode: MaxEncodedLen)
 	// Storage: Membership MembershipById (r:2 w:0)
 	// Proof: Membership MembershipById (max_values: None, max_size: Some(125), added: 2600, mode: MaxEncodedLen)
 	fn switch_oracle_to_member_by_oracle_member() -> Weight {
@@ -497,7 +506,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
 	// Storage: Bounty Bounties (r:1 w:1)
-	// Proof: Bounty Bounties (max_values: None, max_size: Some(540), added: 3015, mode: MaxEncodedLen)
+	// Proof: Bounty Bounties (max_values: None, max_size: Some(793), added: 3268, mode: MaxEncodedLen)
 	// Storage: Membership MembershipById (r:1 w:0)
 	// Proof: Membership MembershipById (max_values: None, max_size: Some(125), added: 2600, mode: MaxEncodedLen)
 	fn switch_oracle_to_council_by_oracle_member() -> Weight {
@@ -511,7 +520,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
 	// Storage: Bounty Bounties (r:1 w:1)
-	// Proof: Bounty Bounties (max_values: None, max_size: Some(540), added: 3015, mode: MaxEncodedLen)
+	// Proof: Bounty Bounties (max_values: None, max_size: Some(793), added: 3268, mode: MaxEncodedLen)
 	// Storage: Membership MembershipById (r:1 w:0)
 	// Proof: Membership MembershipById (max_values: None, max_size: Some(125), added: 2600, mode: MaxEncodedLen)
 	fn end_working_period() -> Weight {
@@ -527,7 +536,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	// Storage: Membership MembershipById (r:1 w:0)
 	// Proof: Membership MembershipById (max_values: None, max_size: Some(125), added: 2600, mode: MaxEncodedLen)
 	// Storage: Bounty Bounties (r:1 w:1)
-	// Proof: Bounty Bounties (max_values: None, max_size: Some(540), added: 3015, mode: MaxEncodedLen)
+	// Proof: Bounty Bounties (max_values: None, max_size: Some(793), added: 3268, mode: MaxEncodedLen)
 	// Storage: Bounty Entries (r:1 w:1)
 	// Proof: Bounty Entries (max_values: None, max_size: Some(93), added: 2568, mode: MaxEncodedLen)
 	// Storage: Balances Locks (r:1 w:1)
@@ -545,7 +554,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().writes(4_u64))
 	}
 	// Storage: Bounty Bounties (r:1 w:0)
-	// Proof: Bounty Bounties (max_values: None, max_size: Some(540), added: 3015, mode: MaxEncodedLen)
+	// Proof: Bounty Bounties (max_values: None, max_size: Some(793), added: 3268, mode: MaxEncodedLen)
 	// Storage: Bounty BountyContributions (r:1 w:1)
 	// Proof: Bounty BountyContributions (max_values: None, max_size: Some(81), added: 2556, mode: MaxEncodedLen)
 	// Storage: System Account (r:1 w:1)
@@ -565,7 +574,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	// Storage: Membership MembershipById (r:1 w:0)
 	// Proof: Membership MembershipById (max_values: None, max_size: Some(125), added: 2600, mode: MaxEncodedLen)
 	// Storage: Bounty Bounties (r:1 w:0)
-	// Proof: Bounty Bounties (max_values: None, max_size: Some(540), added: 3015, mode: MaxEncodedLen)
+	// Proof: Bounty Bounties (max_values: None, max_size: Some(793), added: 3268, mode: MaxEncodedLen)
 	// Storage: Bounty BountyContributions (r:1 w:1)
 	// Proof: Bounty BountyContributions (max_values: None, max_size: Some(81), added: 2556, mode: MaxEncodedLen)
 	// Storage: System Account (r:2 w:2)
@@ -581,7 +590,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().writes(3_u64))
 	}
 	// Storage: Bounty Bounties (r:1 w:1)
-	// Proof: Bounty Bounties (max_values: None, max_size: Some(540), added: 3015, mode: MaxEncodedLen)
+	// Proof: Bounty Bounties (max_values: None, max_size: Some(793), added: 3268, mode: MaxEncodedLen)
 	// Storage: System Account (r:1 w:1)
 	// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
 	// Storage: Council Budget (r:1 w:1)
@@ -599,7 +608,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().writes(3_u64))
 	}
 	// Storage: Bounty Bounties (r:1 w:1)
-	// Proof: Bounty Bounties (max_values: None, max_size: Some(540), added: 3015, mode: MaxEncodedLen)
+	// Proof: Bounty Bounties (max_values: None, max_size: Some(793), added: 3268, mode: MaxEncodedLen)
 	// Storage: Membership MembershipById (r:1 w:0)
 	// Proof: Membership MembershipById (max_values: None, max_size: Some(125), added: 2600, mode: MaxEncodedLen)
 	// Storage: System Account (r:2 w:2)
@@ -651,7 +660,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(2_u64))
 	}
 	// Storage: Bounty Bounties (r:1 w:0)
-	// Proof: Bounty Bounties (max_values: None, max_size: Some(540), added: 3015, mode: MaxEncodedLen)
+	// Proof: Bounty Bounties (max_values: None, max_size: Some(793), added: 3268, mode: MaxEncodedLen)
 	/// The range of component `i` is `[0, 100]`.
 	fn oracle_remark(i: u32, ) -> Weight {
 		// Proof Size summary in bytes:
@@ -665,7 +674,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(1_u64))
 	}
 	// Storage: Bounty Bounties (r:1 w:0)
-	// Proof: Bounty Bounties (max_values: None, max_size: Some(540), added: 3015, mode: MaxEncodedLen)
+	// Proof: Bounty Bounties (max_values: None, max_size: Some(793), added: 3268, mode: MaxEncodedLen)
 	/// The range of component `i` is `[0, 100]`.
 	fn creator_remark(i: u32, ) -> Weight {
 		// Proof Size summary in bytes:
@@ -678,6 +687,138 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(Weight::from_parts(763_705, 0u64).saturating_mul(i.into()))
 			.saturating_add(T::DbWeight::get().reads(1_u64))
 	}
+	// PLACEHOLDER: hand-written estimate, not the benchmark output.
+	// Must be regenerated with the benchmark CLI before merging.
+	// Storage: Membership MembershipById (r:2 w:0)
+	// Proof: Membership MembershipById (max_values: None, max_size: Some(125), added: 2600, mode: MaxEncodedLen)
+	// Storage: Bounty Bounties (r:1 w:0)
+	// Proof: Bounty Bounties (max_values: None, max_size: Some(793), added: 3268, mode: MaxEncodedLen)
+	// Storage: Bounty Entries (r:1 w:0)
+	// Proof: Bounty Entries (max_values: None, max_size: Some(93), added: 2568, mode: MaxEncodedLen)
+	// Storage: Bounty OraclePanelJudgments (r:10 w:1)
+	// Proof: Bounty OraclePanelJudgments (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	// Storage: Bounty OraclePanelParticipants (r:0 w:1)
+	// Proof: Bounty OraclePanelParticipants (max_values: None, max_size: Some(49), added: 2524, mode: MaxEncodedLen)
+	// Storage: Council CouncilMembers (r:1 w:0)
+	// Proof: Council CouncilMembers (max_values: Some(1), max_size: Some(1121), added: 1616, mode: MaxEncodedLen)
+	/// The range of component `p` is `[1, 10]`.
+	fn submit_oracle_panel_judgment(p: u32, ) -> Weight {
+		Weight::from_parts(62_480_642, 0u64)
+			.saturating_add(Weight::from_parts(0, 15248))
+			.saturating_add(Weight::from_parts(3_418_205, 0u64).saturating_mul(p.into()))
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(p.into())))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+			.saturating_add(Weight::from_parts(0, 2555).saturating_mul(p.into()))
+	}
+	// PLACEHOLDER: hand-written estimate, not the benchmark output.
+	// Must be regenerated with the benchmark CLI before merging.
+	// Storage: Bounty Bounties (r:1 w:1)
+	// Proof: Bounty Bounties (max_values: None, max_size: Some(793), added: 3268, mode: MaxEncodedLen)
+	// Storage: Membership MembershipById (r:2 w:0)
+	// Proof: Membership MembershipById (max_values: None, max_size: Some(125), added: 2600, mode: MaxEncodedLen)
+	// Storage: Bounty OraclePanelJudgments (r:0 w:1)
+	// Proof: Bounty OraclePanelJudgments (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	// Storage: Bounty OraclePanelParticipants (r:0 w:1)
+	// Proof: Bounty OraclePanelParticipants (max_values: None, max_size: Some(49), added: 2524, mode: MaxEncodedLen)
+	fn switch_oracle_panel_member() -> Weight {
+		Weight::from_parts(39_702_000, 0u64)
+			.saturating_add(Weight::from_parts(0, 8464))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	// PLACEHOLDER: hand-written estimate, not the benchmark output.
+	// Must be regenerated with the benchmark CLI before merging.
+	// Storage: Bounty Bounties (r:1 w:1)
+	// Proof: Bounty Bounties (max_values: None, max_size: Some(793), added: 3268, mode: MaxEncodedLen)
+	// Storage: Membership MembershipById (r:12 w:0)
+	// Proof: Membership MembershipById (max_values: None, max_size: Some(125), added: 2600, mode: MaxEncodedLen)
+	// Storage: Bounty OraclePanelParticipants (r:10 w:0)
+	// Proof: Bounty OraclePanelParticipants (max_values: None, max_size: Some(49), added: 2524, mode: MaxEncodedLen)
+	// Storage: System Account (r:12 w:12)
+	// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	// Storage: Bounty BountyContributions (r:1 w:0)
+	// Proof: Bounty BountyContributions (max_values: None, max_size: Some(81), added: 2556, mode: MaxEncodedLen)
+	// Storage: Council CouncilMembers (r:1 w:0)
+	// Proof: Council CouncilMembers (max_values: Some(1), max_size: Some(1121), added: 1616, mode: MaxEncodedLen)
+	/// The range of component `p` is `[1, 10]`.
+	fn withdraw_oracle_panel_reward(p: u32, ) -> Weight {
+		Weight::from_parts(91_608_390, 0u64)
+			.saturating_add(Weight::from_parts(0, 20849))
+			.saturating_add(Weight::from_parts(27_391_118, 0u64).saturating_mul(p.into()))
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().reads((3_u64).saturating_mul(p.into())))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(p.into())))
+			.saturating_add(Weight::from_parts(0, 7727).saturating_mul(p.into()))
+	}
+	// PLACEHOLDER: hand-written estimate, not the benchmark output.
+	// Must be regenerated with the benchmark CLI before merging.
+	// Storage: Membership MembershipById (r:2 w:0)
+	// Proof: Membership MembershipById (max_values: None, max_size: Some(125), added: 2600, mode: MaxEncodedLen)
+	// Storage: Bounty Bounties (r:1 w:0)
+	// Proof: Bounty Bounties (max_values: None, max_size: Some(793), added: 3268, mode: MaxEncodedLen)
+	// Storage: Bounty JudgmentDisputes (r:1 w:1)
+	// Proof: Bounty JudgmentDisputes (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	// Storage: Bounty Entries (r:1 w:0)
+	// Proof: Bounty Entries (max_values: None, max_size: Some(93), added: 2568, mode: MaxEncodedLen)
+	// Storage: Bounty EscrowedJudgments (r:1 w:0)
+	// Proof: Bounty EscrowedJudgments (max_values: None, max_size: Some(65), added: 2540, mode: MaxEncodedLen)
+	// Storage: System Account (r:2 w:2)
+	// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// The range of component `i` is `[0, 100]`.
+	fn open_judgment_dispute(i: u32, ) -> Weight {
+		Weight::from_parts(63_904_117, 0u64)
+			.saturating_add(Weight::from_parts(0, 19651))
+			.saturating_add(Weight::from_parts(771_480, 0u64).saturating_mul(i.into()))
+			.saturating_add(T::DbWeight::get().reads(8_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	// PLACEHOLDER: hand-written estimate, not the benchmark output.
+	// Must be regenerated with the benchmark CLI before merging.
+	// Storage: Bounty Bounties (r:1 w:1)
+	// Proof: Bounty Bounties (max_values: None, max_size: Some(793), added: 3268, mode: MaxEncodedLen)
+	// Storage: Bounty JudgmentDisputes (r:1 w:1)
+	// Proof: Bounty JudgmentDisputes (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	// Storage: System Account (r:3 w:3)
+	// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	// Storage: Council Budget (r:1 w:1)
+	// Proof: Council Budget (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	fn overturn_oracle_judgment() -> Weight {
+		Weight::from_parts(56_118_000, 0u64)
+			.saturating_add(Weight::from_parts(0, 14147))
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
+	}
+	// PLACEHOLDER: hand-written estimate, not the benchmark output.
+	// Must be regenerated with the benchmark CLI before merging.
+	// Storage: Bounty Bounties (r:1 w:1)
+	// Proof: Bounty Bounties (max_values: None, max_size: Some(793), added: 3268, mode: MaxEncodedLen)
+	// Storage: Bounty JudgmentDisputes (r:1 w:1)
+	// Proof: Bounty JudgmentDisputes (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	// Storage: Bounty EscrowedJudgments (r:21 w:20)
+	// Proof: Bounty EscrowedJudgments (max_values: None, max_size: Some(65), added: 2540, mode: MaxEncodedLen)
+	// Storage: Bounty Entries (r:20 w:20)
+	// Proof: Bounty Entries (max_values: None, max_size: Some(93), added: 2568, mode: MaxEncodedLen)
+	// Storage: Membership MembershipById (r:20 w:0)
+	// Proof: Membership MembershipById (max_values: None, max_size: Some(125), added: 2600, mode: MaxEncodedLen)
+	// Storage: System Account (r:22 w:22)
+	// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	// Storage: Balances Locks (r:20 w:20)
+	// Proof: Balances Locks (max_values: None, max_size: Some(1299), added: 3774, mode: MaxEncodedLen)
+	// Storage: Council Budget (r:1 w:1)
+	// Proof: Council Budget (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	/// The range of component `e` is `[1, 20]`.
+	fn finalize_oracle_judgment(e: u32, ) -> Weight {
+		Weight::from_parts(70_381_604, 0u64)
+			.saturating_add(Weight::from_parts(0, 17931))
+			.saturating_add(Weight::from_parts(59_714_312, 0u64).saturating_mul(e.into()))
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().reads((6_u64).saturating_mul(e.into())))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+			.saturating_add(T::DbWeight::get().writes((5_u64).saturating_mul(e.into())))
+			.saturating_add(Weight::from_parts(0, 16685).saturating_mul(e.into()))
+	}
 }
 
 // Default implementation for tests
@@ -778,4 +919,22 @@ impl WeightInfo for () {
 	fn creator_remark(i: u32, ) -> Weight {
 		Weight::from_parts(0, 0)
 	}
+	fn submit_oracle_panel_judgment(p: u32, ) -> Weight {
+		Weight::from_parts(0, 0)
+	}
+	fn switch_oracle_panel_member() -> Weight {
+		Weight::from_parts(0, 0)
+	}
+	fn withdraw_oracle_panel_reward(p: u32, ) -> Weight {
+		Weight::from_parts(0, 0)
+	}
+	fn open_judgment_dispute(i: u32, ) -> Weight {
+		Weight::from_parts(0, 0)
+	}
+	fn overturn_oracle_judgment() -> Weight {
+		Weight::from_parts(0, 0)
+	}
+	fn finalize_oracle_judgment(e: u32, ) -> Weight {
+		Weight::from_parts(0, 0)
+	}
 }
@@ -1,4 +1,5 @@
 use frame_support::dispatch::DispatchResult;
+use sp_std::vec::Vec;
 
 /// Provides an interface for the council budget.
 pub use crate::BudgetManager as CouncilBudgetManager;
@@ -8,3 +9,43 @@ pub trait CouncilOriginValidator<Origin, MemberId, AccountId> {
     /// Check for valid combination of origin and member_id for a councilor.
     fn ensure_member_consulate(origin: Origin, member_id: MemberId) -> DispatchResult;
 }
+
+/// Provides the members of the current council.
+pub trait CouncilMembersProvider<MemberId> {
+    /// Returns the member IDs of the current councilors.
+    fn council_member_ids() -> Vec<MemberId>;
+}
+
+/// Observes the council participation in the proposal voting.
+pub trait CouncilParticipationObserver<MemberId, BlockNumber> {
+    /// Registers the proposal voting concluded by the council decision or expiration along with
+    /// the members that voted on the proposal, the block the voting started at and whether the
+    /// voting period expired.
+    fn proposal_voting_concluded(
+        voters: &[MemberId],
+        voting_started_at: BlockNumber,
+        voting_period_expired: bool,
+    );
+}
+
+impl<MemberId, BlockNumber> CouncilParticipationObserver<MemberId, BlockNumber> for () {
+    fn proposal_voting_concluded(_: &[MemberId], _: BlockNumber, _: bool) {}
+}
+
+/// Identifier of the earmarked council budget allocation.
+pub type BudgetAllocationId = u64;
+
+/// Provides an interface for the earmarked council budget allocations the council spendings can
+/// draw against.
+pub trait CouncilBudgetAllocations<Balance> {
+    /// Ensures the amount can be drawn from the existing, not expired budget allocation without
+    /// exceeding its cap.
+    fn ensure_can_draw_from_allocation(
+        allocation_id: BudgetAllocationId,
+        amount: Balance,
+    ) -> DispatchResult;
+
+    /// Registers the amount drawn from the budget allocation. The amount itself must be withdrawn
+    /// from the council budget by the caller.
+    fn draw_from_allocation(allocation_id: BudgetAllocationId, amount: Balance);
+}
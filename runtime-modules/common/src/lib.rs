@@ -20,6 +20,7 @@ pub mod membership;
 pub mod merkle_tree;
 pub mod no_panic;
 pub mod numerical;
+pub mod referendum;
 pub mod storage;
 pub mod working_group;
 
@@ -1,6 +1,9 @@
-use codec::{Codec, MaxEncodedLen};
+use codec::{Codec, Decode, Encode, MaxEncodedLen};
 use frame_support::dispatch::DispatchError;
 use frame_support::Parameter;
+use scale_info::TypeInfo;
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
 use sp_arithmetic::traits::BaseArithmetic;
 use sp_runtime::traits::{MaybeSerialize, MaybeSerializeDeserialize, Member};
 use sp_std::convert::Into;
@@ -41,6 +44,20 @@ pub trait MembershipTypes: frame_system::Config {
         + Into<u128>;
 }
 
+/// Kinds of the member attestations issued by the membership working group workers.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
+pub enum AttestationKind {
+    /// Member identity is verified.
+    Identity,
+
+    /// Member is a verified content creator.
+    Creator,
+
+    /// Member represents a verified organisation.
+    Organisation,
+}
+
 /// Validates staking account ownership for a member.
 pub trait StakingAccountValidator<T: MembershipTypes> {
     /// Verifies that staking account bound to the member.
@@ -63,4 +80,7 @@ pub trait MemberOriginValidator<Origin, MemberId, AccountId> {
 pub trait MembershipInfoProvider<T: MembershipTypes> {
     /// Returns current controller account for a member.
     fn controller_account_id(member_id: MemberId<T>) -> Result<T::AccountId, DispatchError>;
+
+    /// Verifies that the member has a not expired attestation of the given kind.
+    fn has_valid_attestation(member_id: MemberId<T>, kind: AttestationKind) -> bool;
 }
@@ -0,0 +1,45 @@
+use codec::{Decode, Encode, MaxEncodedLen};
+use scale_info::TypeInfo;
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
+use sp_runtime::traits::Zero;
+use sp_runtime::Percent;
+
+/// Strategy used by the referendum to derive the vote power from the vote stake.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
+pub enum VotePowerStrategy<BlockNumber> {
+    /// Vote power is proportional to the stake.
+    Stake,
+
+    /// Vote power is proportional to the integer square root of the stake.
+    SquareRoot,
+
+    /// Vote power is proportional to the stake increased by a bonus that grows linearly with
+    /// the number of blocks the stake has been locked for, reaching `max_bonus` of the stake
+    /// once the stake has been locked for `maturity_period` blocks.
+    TimeWeighted {
+        /// Number of blocks the stake has to be locked for to get the full bonus.
+        maturity_period: BlockNumber,
+        /// Bonus granted to the fully matured stake.
+        max_bonus: Percent,
+    },
+}
+
+impl<BlockNumber> Default for VotePowerStrategy<BlockNumber> {
+    fn default() -> Self {
+        Self::Stake
+    }
+}
+
+impl<BlockNumber: Zero> VotePowerStrategy<BlockNumber> {
+    /// Checks that the strategy parameters are well formed.
+    pub fn is_valid(&self) -> bool {
+        match self {
+            Self::Stake | Self::SquareRoot => true,
+            Self::TimeWeighted {
+                maturity_period, ..
+            } => !maturity_period.is_zero(),
+        }
+    }
+}
@@ -1,4 +1,5 @@
-use sp_runtime::DispatchResult;
+use frame_support::traits::LockIdentifier;
+use sp_runtime::{DispatchError, DispatchResult};
 
 // Proc macro (EnumIter) clippy::integer_arithmetic disable hack
 #[allow(clippy::integer_arithmetic)]
@@ -75,3 +76,59 @@ pub trait WorkingGroupAuthenticator<T: crate::MembershipTypes> {
 
 /// Provides an interface for the working group budget.
 pub use crate::BudgetManager as WorkingGroupBudgetHandler;
+
+/// Worker moved between the working groups by the worker transfer.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct TransferredWorker<AccountId, MemberId, BlockNumber, Balance> {
+    /// Member id related to the worker.
+    pub member_id: MemberId,
+
+    /// Account used to authenticate in the worker role.
+    pub role_account_id: AccountId,
+
+    /// Account used to stake in the worker role.
+    pub staking_account_id: AccountId,
+
+    /// Reward account id.
+    pub reward_account_id: AccountId,
+
+    /// Unstaking period when the worker chooses to leave the role.
+    pub job_unstaking_period: BlockNumber,
+
+    /// Optional reward setting for the worker.
+    pub reward_per_block: Option<Balance>,
+
+    /// Specifies the block when the worker was hired: the tenure is kept by the transfer.
+    pub created_at: BlockNumber,
+
+    /// Stake lock identifier of the working group the worker is transferred from.
+    pub lock_id: LockIdentifier,
+}
+
+/// Working group interface to transfer the workers between the groups without unstaking.
+pub trait WorkingGroupWorkerTransferHandler<AccountId, ActorId, MemberId, BlockNumber, Balance> {
+    /// Verifies that the leader approved the worker transfer to the destination working group.
+    fn is_worker_transfer_approved(worker_id: &ActorId, destination: WorkingGroup) -> bool;
+
+    /// Verifies that the worker can be transferred from the working group.
+    /// Returns the worker to transfer.
+    fn ensure_worker_can_be_transferred(
+        worker_id: &ActorId,
+    ) -> Result<TransferredWorker<AccountId, MemberId, BlockNumber, Balance>, DispatchError>;
+
+    /// Verifies that the working group can accept the transferred worker.
+    fn ensure_worker_can_be_accepted(
+        worker: &TransferredWorker<AccountId, MemberId, BlockNumber, Balance>,
+    ) -> DispatchResult;
+
+    /// Removes the worker transferred to the destination working group. The stake stays locked.
+    /// The reward accrued since the last reward payout is paid before the removal.
+    fn transfer_worker_out(worker_id: &ActorId, destination: WorkingGroup);
+
+    /// Adds the worker transferred from the source working group. The stake lock of the source
+    /// working group is replaced with the lock of this working group. Returns the new worker id.
+    fn transfer_worker_in(
+        worker: TransferredWorker<AccountId, MemberId, BlockNumber, Balance>,
+        source: WorkingGroup,
+    ) -> ActorId;
+}
@@ -1485,6 +1485,37 @@ benchmarks! {
             );
         }
 
+    update_creator_token_bridging_status {
+        let (channel_id, group_id, lead_acc_id, curator_id, curator_acc_id) =
+            setup_worst_case_scenario_curator_channel_all_max::<T>(false)?;
+        let curator_member_id = curator_member_id::<T>(curator_id);
+        let origin = RawOrigin::Signed(curator_acc_id.clone());
+        let actor = ContentActor::Curator(group_id, curator_id);
+        let token_id =
+            issue_creator_token_with_worst_case_scenario_owner::<T>(
+                curator_acc_id,
+                actor,
+                channel_id,
+                curator_member_id
+            )?;
+        // No pausable feature prevents this
+        set_all_channel_paused_features::<T>(channel_id);
+    }: _ (
+        origin, actor, channel_id, true
+    )
+        verify {
+            assert!(project_token::Pallet::<T>::bridging_enabled(token_id));
+            // Check event emitted
+            assert_last_event::<T>(
+                <T as project_token::Config>::RuntimeEvent::from(
+                    project_token::Event::<T>::BridgingStatusUpdated(
+                        token_id,
+                        true
+                    )
+                ).into()
+            );
+        }
+
     deissue_creator_token {
         let (channel_id, group_id, lead_acc_id, curator_id, curator_acc_id) =
             setup_worst_case_scenario_curator_channel_all_max::<T>(false)?;
@@ -3771,6 +3802,13 @@ pub mod tests {
         });
     }
 
+    #[test]
+    fn update_creator_token_bridging_status() {
+        with_default_mock_builder(|| {
+            assert_ok!(Content::test_benchmark_update_creator_token_bridging_status());
+        });
+    }
+
     #[test]
     fn deissue_creator_token() {
         with_default_mock_builder(|| {
@@ -86,7 +86,7 @@ const DEFAULT_CRT_SALE_UPPER_BOUND: u32 = DEFAULT_CRT_OWNER_ISSUANCE;
 const DEFAULT_CRT_REVENUE_SPLIT_RATE: Permill = Permill::from_percent(50);
 const DEFAULT_CRT_PATRONAGE_RATE: YearlyRate = YearlyRate(Permill::from_percent(10));
 
-const CHANNEL_AGENT_PERMISSIONS: [ChannelActionPermission; 22] = [
+const CHANNEL_AGENT_PERMISSIONS: [ChannelActionPermission; 23] = [
     ChannelActionPermission::UpdateChannelMetadata,
     ChannelActionPermission::ManageNonVideoChannelAssets,
     ChannelActionPermission::ManageChannelCollaborators,
@@ -109,6 +109,7 @@ const CHANNEL_AGENT_PERMISSIONS: [ChannelActionPermission; 22] = [
     ChannelActionPermission::ManageRevenueSplits,
     ChannelActionPermission::DeissueCreatorToken,
     ChannelActionPermission::AmmControl,
+    ChannelActionPermission::UpdateCreatorTokenBridgingStatus,
 ];
 
 const CONTENT_MODERATION_ACTIONS: [ContentModerationAction; 13] = [
@@ -36,7 +36,7 @@ pub mod weights;
 use core::marker::PhantomData;
 use project_token::traits::PalletToken;
 use project_token::types::{
-    AmmParamsOf, JoyBalanceOf, TokenIssuanceParametersOf, TokenSaleParamsOf,
+    AmmParamsOf, JoyBalanceOf, TokenBalanceOf, TokenIssuanceParametersOf, TokenSaleParamsOf,
     TransferWithVestingOutputsOf, UploadContextOf, YearlyRate,
 };
 use sp_std::vec;
@@ -195,6 +195,7 @@ pub trait Config:
         UploadContextOf<Self>,
         TransferWithVestingOutputsOf<Self>,
         AmmParamsOf<Self>,
+        TokenBalanceOf<Self>,
     >;
 
     /// Minimum cashout allowed limit
@@ -3366,6 +3367,40 @@ decl_module! {
             T::ProjectToken::change_to_permissionless(token_id)?;
         }
 
+        /// Allow or forbid bridging channel's creator token to remote chains
+        ///
+        /// <weight>
+        ///
+        /// ## Weight
+        /// `O (1)`
+        /// - DB:
+        ///    - O(1) doesn't depend on the state or parameters
+        /// # </weight>
+        #[weight = WeightInfoContent::<T>::update_creator_token_bridging_status()]
+        pub fn update_creator_token_bridging_status(
+            origin,
+            actor: ContentActor<T::CuratorGroupId, T::CuratorId, T::MemberId>,
+            channel_id: T::ChannelId,
+            enabled: bool,
+        ) {
+            let channel = Self::ensure_channel_exists(&channel_id)?;
+
+            channel.ensure_has_no_active_transfer::<T>()?;
+
+            // Permissions check
+            ensure_actor_authorized_to_update_creator_token_bridging_status::<T>(
+                origin,
+                &actor,
+                &channel
+            )?;
+
+            // Ensure token was issued
+            let token_id = channel.ensure_creator_token_issued::<T>()?;
+
+            // Call to ProjectToken - should be the first call before MUTATION SAFE!
+            T::ProjectToken::set_bridging_status(token_id, enabled)?;
+        }
+
         /// Reduce channel's creator token patronage rate to given value
         ///
         /// <weight>
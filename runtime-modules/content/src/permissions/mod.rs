@@ -681,6 +681,17 @@ pub fn ensure_actor_authorized_to_make_creator_token_permissionless<T: Config>(
     Ok(sender)
 }
 
+pub fn ensure_actor_authorized_to_update_creator_token_bridging_status<T: Config>(
+    origin: T::RuntimeOrigin,
+    actor: &ContentActor<T::CuratorGroupId, T::CuratorId, T::MemberId>,
+    channel: &Channel<T>,
+) -> Result<T::AccountId, DispatchError> {
+    let sender = ensure_signed(origin)?;
+    let required_permissions = vec![ChannelActionPermission::UpdateCreatorTokenBridgingStatus];
+    ensure_actor_has_channel_permissions::<T>(&sender, actor, channel, &required_permissions)?;
+    Ok(sender)
+}
+
 pub fn ensure_actor_authorized_to_reduce_creator_token_patronage_rate<T: Config>(
     origin: T::RuntimeOrigin,
     actor: &ContentActor<T::CuratorGroupId, T::CuratorId, T::MemberId>,
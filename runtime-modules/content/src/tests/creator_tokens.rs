@@ -10,4 +10,5 @@ mod issue_revenue_split;
 mod issuer_transfer;
 mod make_permissionless;
 mod reduce_patronage_rate;
+mod update_bridging_status;
 mod update_upcoming_sale;
@@ -0,0 +1,127 @@
+#![cfg(test)]
+use crate::tests::fixtures::*;
+use crate::tests::mock::*;
+use crate::*;
+use frame_support::assert_noop;
+use frame_support::assert_ok;
+use frame_system::RawOrigin;
+
+#[test]
+fn unsuccessful_update_creator_token_bridging_status_non_existing_channel() {
+    with_default_mock_builder(|| {
+        run_to_block(1);
+
+        UpdateCreatorTokenBridgingStatusFixture::default()
+            .call_and_assert(Err(Error::<Test>::ChannelDoesNotExist.into()));
+    })
+}
+
+#[test]
+fn unsuccessful_update_creator_token_bridging_status_token_not_issued() {
+    with_default_mock_builder(|| {
+        ContentTest::with_member_channel().setup();
+        UpdateCreatorTokenBridgingStatusFixture::default()
+            .call_and_assert(Err(Error::<Test>::CreatorTokenNotIssued.into()));
+    })
+}
+
+// Member channel
+
+#[test]
+fn unsuccessful_update_member_channel_creator_token_bridging_status_by_collaborator_without_permissions(
+) {
+    with_default_mock_builder(|| {
+        ContentTest::with_member_channel()
+            .with_all_agent_permissions_except(&[
+                ChannelActionPermission::UpdateCreatorTokenBridgingStatus,
+            ])
+            .setup();
+        IssueCreatorTokenFixture::default().call_and_assert(Ok(()));
+        UpdateCreatorTokenBridgingStatusFixture::default()
+            .with_sender(COLLABORATOR_MEMBER_ACCOUNT_ID)
+            .with_actor(ContentActor::Member(COLLABORATOR_MEMBER_ID))
+            .call_and_assert(Err(
+                Error::<Test>::ChannelAgentInsufficientPermissions.into()
+            ));
+    })
+}
+
+#[test]
+fn successful_update_member_channel_creator_token_bridging_status_by_collaborator() {
+    with_default_mock_builder(|| {
+        ContentTest::with_member_channel()
+            .with_agent_permissions(&[ChannelActionPermission::UpdateCreatorTokenBridgingStatus])
+            .setup();
+        IssueCreatorTokenFixture::default().call_and_assert(Ok(()));
+        UpdateCreatorTokenBridgingStatusFixture::default()
+            .with_sender(COLLABORATOR_MEMBER_ACCOUNT_ID)
+            .with_actor(ContentActor::Member(COLLABORATOR_MEMBER_ID))
+            .call_and_assert(Ok(()));
+    })
+}
+
+#[test]
+fn successful_update_member_channel_creator_token_bridging_status_by_owner() {
+    with_default_mock_builder(|| {
+        ContentTest::with_member_channel().setup();
+        IssueCreatorTokenFixture::default().call_and_assert(Ok(()));
+        UpdateCreatorTokenBridgingStatusFixture::default().call_and_assert(Ok(()));
+        UpdateCreatorTokenBridgingStatusFixture::default()
+            .with_enabled(false)
+            .call_and_assert(Ok(()));
+    })
+}
+
+// Curator channel
+
+#[test]
+fn successful_update_curator_channel_creator_token_bridging_status_by_lead() {
+    with_default_mock_builder(|| {
+        ContentTest::with_curator_channel().setup();
+        IssueCreatorTokenFixture::default()
+            .with_sender(LEAD_ACCOUNT_ID)
+            .with_actor(ContentActor::Lead)
+            .with_initial_allocation_to(LEAD_MEMBER_ID)
+            .call_and_assert(Ok(()));
+        UpdateCreatorTokenBridgingStatusFixture::default()
+            .with_sender(LEAD_ACCOUNT_ID)
+            .with_actor(ContentActor::Lead)
+            .call_and_assert(Ok(()));
+    })
+}
+
+#[test]
+fn update_creator_token_bridging_status_fails_during_transfer() {
+    with_default_mock_builder(|| {
+        ContentTest::with_member_channel().setup();
+        IssueCreatorTokenFixture::default().call_and_assert(Ok(()));
+        InitializeChannelTransferFixture::default()
+            .with_new_member_channel_owner(THIRD_MEMBER_ID)
+            .call_and_assert(Ok(()));
+
+        assert_noop!(
+            Content::update_creator_token_bridging_status(
+                RuntimeOrigin::signed(DEFAULT_MEMBER_ACCOUNT_ID),
+                ContentActor::Member(DEFAULT_MEMBER_ID),
+                1u64,
+                true,
+            ),
+            Error::<Test>::InvalidChannelTransferStatus,
+        );
+    })
+}
+
+#[test]
+fn update_member_channel_creator_token_bridging_status_by_owner_fails_on_frozen_pallet() {
+    with_default_mock_builder(|| {
+        ContentTest::with_member_channel().setup();
+        IssueCreatorTokenFixture::default().call_and_assert(Ok(()));
+
+        assert_ok!(Token::set_frozen_status(RawOrigin::Root.into(), true));
+        UpdateCreatorTokenBridgingStatusFixture::default()
+            .call_and_assert(Err(project_token::Error::<Test>::PalletFrozen.into()));
+
+        assert_ok!(Token::set_frozen_status(RawOrigin::Root.into(), false));
+        UpdateCreatorTokenBridgingStatusFixture::default().call_and_assert(Ok(()));
+    })
+}
@@ -2556,6 +2556,57 @@ impl MakeCreatorTokenPermissionlessFixture {
     }
 }
 
+pub struct UpdateCreatorTokenBridgingStatusFixture {
+    sender: AccountId,
+    actor: ContentActor<CuratorGroupId, CuratorId, MemberId>,
+    channel_id: ChannelId,
+    enabled: bool,
+}
+
+impl UpdateCreatorTokenBridgingStatusFixture {
+    pub fn default() -> Self {
+        Self {
+            sender: DEFAULT_MEMBER_ACCOUNT_ID,
+            actor: ContentActor::Member(DEFAULT_MEMBER_ID),
+            channel_id: ChannelId::one(),
+            enabled: true,
+        }
+    }
+
+    pub fn with_sender(self, sender: AccountId) -> Self {
+        Self { sender, ..self }
+    }
+
+    pub fn with_actor(self, actor: ContentActor<CuratorGroupId, CuratorId, MemberId>) -> Self {
+        Self { actor, ..self }
+    }
+
+    pub fn with_enabled(self, enabled: bool) -> Self {
+        Self { enabled, ..self }
+    }
+
+    pub fn call_and_assert(&self, expected_result: DispatchResult) {
+        let origin = RuntimeOrigin::signed(self.sender);
+
+        let actual_result = Content::update_creator_token_bridging_status(
+            origin,
+            self.actor,
+            self.channel_id,
+            self.enabled,
+        );
+
+        if expected_result.is_ok() {
+            assert_ok!(actual_result);
+            let token_id = Content::channel_by_id(self.channel_id)
+                .creator_token_id
+                .unwrap();
+            assert_eq!(Token::bridging_enabled(token_id), self.enabled);
+        } else {
+            assert_noop!(actual_result, expected_result.err().unwrap());
+        }
+    }
+}
+
 pub struct IssueRevenueSplitFixture {
     sender: AccountId,
     actor: ContentActor<CuratorGroupId, CuratorId, MemberId>,
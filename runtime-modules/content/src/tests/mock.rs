@@ -496,8 +496,10 @@ impl working_group::Config<StorageWorkingGroupInstance> for Test {
     type MemberOriginValidator = ();
     type MinUnstakingPeriodLimit = ();
     type RewardPeriod = ();
+    type MaxRewardPayoutsPerBlock = ();
     type WeightInfo = ();
     type MinimumApplicationStake = MinimumApplicationStake;
+    type MaxApplicationEndorsements = ConstU32<10>;
     type LeaderOpeningStake = LeaderOpeningStake;
     type VestingBalanceToBalance = BalanceConverter;
 }
@@ -512,8 +514,10 @@ impl working_group::Config<DistributionWorkingGroupInstance> for Test {
     type MemberOriginValidator = ();
     type MinUnstakingPeriodLimit = ();
     type RewardPeriod = ();
+    type MaxRewardPayoutsPerBlock = ();
     type WeightInfo = ();
     type MinimumApplicationStake = MinimumApplicationStake;
+    type MaxApplicationEndorsements = ConstU32<10>;
     type LeaderOpeningStake = LeaderOpeningStake;
     type VestingBalanceToBalance = BalanceConverter;
 }
@@ -553,8 +557,10 @@ impl working_group::Config<ContentWorkingGroupInstance> for Test {
     type MemberOriginValidator = ();
     type MinUnstakingPeriodLimit = ();
     type RewardPeriod = ();
+    type MaxRewardPayoutsPerBlock = ();
     type WeightInfo = ();
     type MinimumApplicationStake = MinimumApplicationStake;
+    type MaxApplicationEndorsements = ConstU32<10>;
     type LeaderOpeningStake = LeaderOpeningStake;
     type VestingBalanceToBalance = BalanceConverter;
 }
@@ -782,6 +788,10 @@ parameter_types! {
     pub const LockId3: LockIdentifier = [11; 8];
     pub const DefaultInitialInvitationBalance: u64 = 100;
     pub const DefaultMemberInvitesCount: u32 = 2;
+    pub const HandleReclaimCooldown: u64 = 1000;
+    pub const MaxRecoveryGuardians: u32 = 5;
+    pub const RecoveryDelay: u64 = 100;
+    pub const RecoveryCooldown: u64 = 50;
     pub const ReferralCutMaximumPercent: u8 = 50;
     pub const MinimumStakeForOpening: u32 = 50;
     pub const MinimumApplicationStake: u32 = 50;
@@ -800,6 +810,10 @@ impl membership::Config for Test {
     type CandidateStake = CandidateStake;
     type WeightInfo = ();
     type DefaultMemberInvitesCount = DefaultMemberInvitesCount;
+    type HandleReclaimCooldown = HandleReclaimCooldown;
+    type MaxRecoveryGuardians = MaxRecoveryGuardians;
+    type RecoveryDelay = RecoveryDelay;
+    type RecoveryCooldown = RecoveryCooldown;
 }
 
 pub const WORKING_GROUP_BUDGET: u64 = 100;
@@ -912,6 +926,13 @@ impl MembershipInfoProvider<Test> for TestMemberships {
             }
         })
     }
+
+    fn has_valid_attestation(
+        member_id: common::MemberId<Test>,
+        kind: common::membership::AttestationKind,
+    ) -> bool {
+        Membership::has_valid_attestation(member_id, kind)
+    }
 }
 
 // Mock MemberOriginValidator impl.
@@ -242,6 +242,9 @@ pub mod iterable_enums {
         /// - activate_amm
         /// - deactivate_amm
         AmmControl,
+        /// Allows allowing/forbidding bridging of the creator token through:
+        /// - `update_creator_token_bridging_status`
+        UpdateCreatorTokenBridgingStatus,
     }
 }
 
@@ -20,6 +20,9 @@
 //! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 4.0.0-dev
 //! DATE: 2023-11-07, STEPS: `50`, REPEAT: 20, LOW RANGE: `[]`, HIGH RANGE: `[]`
 //! EXECUTION: Some(Wasm), WASM-EXECUTION: Compiled, CHAIN: Some("prod-test"), DB CACHE: 1024
+//!
+//! NOTE: the weight functions marked as PLACEHOLDER are hand-written estimates, not the
+//! benchmark output. They must be regenerated with the benchmark CLI before merging.
 
 // Executed Command:
 // ./scripts/../target/release/joystream-node
@@ -75,6 +78,7 @@ pub trait WeightInfo {
 	fn issue_creator_token(_a: u32, ) -> Weight;
 	fn creator_token_issuer_transfer(_a: u32, _b: u32, ) -> Weight;
 	fn make_creator_token_permissionless() -> Weight;
+	fn update_creator_token_bridging_status() -> Weight;
 	fn deissue_creator_token() -> Weight;
 	fn init_creator_token_sale(_a: u32, ) -> Weight;
 	fn update_upcoming_creator_token_sale() -> Weight;
@@ -1209,6 +1213,26 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(5_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
+	// PLACEHOLDER: hand-written estimate, not the benchmark output.
+	// Must be regenerated with the benchmark CLI before merging.
+	// Storage: Content ChannelById (r:1 w:0)
+	// Proof: Content ChannelById (max_values: None, max_size: Some(909), added: 3384, mode: MaxEncodedLen)
+	// Storage: Instance3WorkingGroup WorkerById (r:1 w:0)
+	// Proof: Instance3WorkingGroup WorkerById (max_values: None, max_size: Some(175), added: 2650, mode: MaxEncodedLen)
+	// Storage: Content CuratorGroupById (r:1 w:0)
+	// Proof: Content CuratorGroupById (max_values: None, max_size: Some(1037), added: 3512, mode: MaxEncodedLen)
+	// Storage: Token PalletFrozen (r:1 w:0)
+	// Proof: Token PalletFrozen (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	// Storage: Token TokenInfoById (r:1 w:0)
+	// Proof: Token TokenInfoById (max_values: None, max_size: Some(352), added: 2827, mode: MaxEncodedLen)
+	// Storage: Token BridgingEnabled (r:0 w:1)
+	// Proof: Token BridgingEnabled (max_values: None, max_size: Some(25), added: 2500, mode: MaxEncodedLen)
+	fn update_creator_token_bridging_status() -> Weight {
+		Weight::from_parts(153_480_000, 0u64)
+			.saturating_add(Weight::from_parts(0, 17819))
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
 	// Storage: Content ChannelById (r:1 w:1)
 	// Proof: Content ChannelById (max_values: None, max_size: Some(909), added: 3384, mode: MaxEncodedLen)
 	// Storage: Instance3WorkingGroup WorkerById (r:1 w:0)
@@ -2250,6 +2274,9 @@ impl WeightInfo for () {
 	fn make_creator_token_permissionless() -> Weight {
 		Weight::from_parts(0, 0)
 	}
+	fn update_creator_token_bridging_status() -> Weight {
+		Weight::from_parts(0, 0)
+	}
 	fn deissue_creator_token() -> Weight {
 		Weight::from_parts(0, 0)
 	}
@@ -339,11 +339,27 @@ benchmarks! {
 
         assert_eq!(funding_requests.len() as u32, i);
 
-    }: _(RawOrigin::Root, funding_requests.clone())
+        // Worst case the funding is drawn against the budget allocation
+        let funding_total = Balance::<T>::from(i) * amount;
+        Council::<T>::create_budget_allocation(
+            RawOrigin::Root.into(),
+            Vec::new(),
+            funding_total,
+            System::<T>::block_number() + One::one(),
+        ).unwrap();
+        let allocation_id = Council::<T>::next_budget_allocation_id() - 1;
+
+    }: _(RawOrigin::Root, funding_requests.clone(), Some(allocation_id))
     verify {
         assert_eq!(
             Council::<T>::budget(),
-            Balance::<T>::max_value() - Balance::<T>::from(i) * amount
+            Balance::<T>::max_value() - funding_total
+        );
+
+        assert_eq!(
+            Council::<T>::budget_allocations(allocation_id).map(|allocation| allocation.spent),
+            Some(funding_total),
+            "Allocation not drawn"
         );
 
         for fund_request in funding_requests {
@@ -778,6 +794,97 @@ benchmarks! {
     verify {
         assert_last_event::<T>(RawEvent::CouncilorRemarked(candidates_id[0], msg).into());
     }
+
+    recall_councilor {
+        // periods easier to calculate
+        let current_block_number = Zero::zero();
+        System::<T>::set_block_number(current_block_number);
+        assert_eq!(System::<T>::block_number(), current_block_number, "Block number not updated");
+
+        // Worst case we have a council elected and the special election is started
+        let (accounts_id, candidates_id) = start_period_announce_multiple_candidates::<T>(
+            T::CouncilSize::get()
+        );
+
+        let winners = candidates_id.iter().map(|candidate_id| {
+            let option_id: T::MemberId = *candidate_id;
+            OptionResult {
+                option_id,
+                vote_power: Zero::zero(),
+            }
+        }).collect::<Vec<_>>();
+
+        Council::<T>::end_announcement_period(T::CouncilSize::get());
+
+        Council::<T>::end_election_period(&winners[..]);
+
+        assert_eq!(
+            Council::<T>::council_members().len(),
+            T::CouncilSize::get() as usize,
+            "Council not updated"
+        );
+    }: _(RawOrigin::Root, candidates_id[0], Percent::from_percent(50))
+    verify {
+        assert!(
+            !Council::<T>::council_members()
+                .iter()
+                .any(|council_member| council_member.membership_id == candidates_id[0]),
+            "Councilor not recalled"
+        );
+
+        assert_eq!(
+            Council::<T>::ongoing_special_election().map(|special_election| special_election.seats),
+            Some(1),
+            "Special election not started"
+        );
+
+        assert_last_event::<T>(
+            RawEvent::SpecialElectionStarted(
+                1,
+                current_block_number + T::AnnouncingPeriodDuration::get()
+            ).into()
+        );
+    }
+
+    create_budget_allocation {
+        let i in 0 .. MAX_KILOBYTES_METADATA;
+
+        let name = vec![0u8; (i * 1000).try_into().unwrap()];
+        let cap = T::MinCandidateStake::get();
+        let expires_at = System::<T>::block_number() + T::BudgetRefillPeriod::get();
+
+        Council::<T>::set_budget(RawOrigin::Root.into(), Balance::<T>::max_value()).unwrap();
+
+        // Worst case: the max number of other allocations expire at the same block
+        let allocation_id = MAX_BUDGET_ALLOCATIONS_EXPIRING_PER_BLOCK as BudgetAllocationId - 1;
+        for _ in 0..allocation_id {
+            Council::<T>::create_budget_allocation(
+                RawOrigin::Root.into(),
+                Vec::new(),
+                cap,
+                expires_at,
+            ).unwrap();
+        }
+
+    }: _(RawOrigin::Root, name.clone(), cap, expires_at)
+    verify {
+        assert_eq!(
+            Council::<T>::budget_allocations(allocation_id),
+            Some(BudgetAllocation {
+                name_hash: T::Hashing::hash(&name),
+                cap,
+                spent: Zero::zero(),
+                created_at: System::<T>::block_number(),
+                expires_at,
+                spendings_count: 0,
+            }),
+            "Allocation not created"
+        );
+
+        assert_last_event::<T>(
+            RawEvent::BudgetAllocationCreated(allocation_id, name, cap, expires_at).into()
+        );
+    }
 }
 
 #[cfg(test)]
@@ -918,4 +1025,20 @@ mod tests {
             assert_ok!(Council::<Runtime>::test_benchmark_set_era_payout_damping_factor());
         })
     }
+
+    #[test]
+    fn test_recall_councilor() {
+        let config = default_genesis_config();
+        build_test_externalities(config).execute_with(|| {
+            assert_ok!(Council::<Runtime>::test_benchmark_recall_councilor());
+        })
+    }
+
+    #[test]
+    fn test_create_budget_allocation() {
+        let config = default_genesis_config();
+        build_test_externalities(config).execute_with(|| {
+            assert_ok!(Council::<Runtime>::test_benchmark_create_budget_allocation());
+        })
+    }
 }
@@ -16,6 +16,27 @@
 //!
 //! The module supports requiring staking currency for the both candidacy and voting.
 //!
+//! Councilor rewards scale with the councilor participation in the proposal voting: the reward
+//! earned since the last payment is reduced by the share of the concluded proposal votings the
+//! councilor didn't vote in. A voting is missed only when its voting period expired without the
+//! councilor vote and the councilor was seated before the voting started. The withheld part of
+//! the reward stays in the council budget.
+//!
+//! Part of the council budget can be earmarked by the named allocations with a cap and an expiry
+//! block. Funding requests and working group budget updates can optionally draw against such an
+//! allocation: the drawn amount is still withdrawn from the council budget, but it can't exceed
+//! the remaining allocation cap and can't be drawn after the allocation expired. Every spending
+//! drawn from the allocation is recorded, so the allocations can be used to plan the spending
+//! across the council terms. The remaining cap of the unexpired allocations is earmarked: the
+//! spendings not drawn against an allocation can only use the rest of the council budget.
+//!
+//! A councilor can be recalled by the root origin (e.g. the council proposal) before the term ends,
+//! slashing part of their councilor stake. When the council is idle, the vacated seat is filled by
+//! a special election: a shortened election cycle run by the Referendum module for the vacant
+//! seats only, after which the council returns to the interrupted idle stage. Seats vacated while
+//! the new council is being elected or while the special election voting is running stay vacant
+//! until the next election.
+//!
 //! ## Implementation
 //! When implementing runtime for this module, don't forget to call all ReferendumConnection trait
 //! functions at proper places. See the trait details for more information.
@@ -30,6 +51,8 @@
 //! - [set_councilor_reward](./struct.Module.html#method.set_councilor_reward)
 //! - [funding_request](./struct.Module.html#method.funding_request)
 //! - [fund_council_budget](./struct.Module.html#method.fund_council_budget)
+//! - [create_budget_allocation](./struct.Module.html#method.create_budget_allocation)
+//! - [recall_councilor](./struct.Module.html#method.recall_councilor)
 //!
 //! ## Important functions
 //! These functions have to be called by the runtime for the council to work properly.
@@ -63,15 +86,19 @@ extern crate common;
 // used dependencies
 use codec::{Decode, Encode, MaxEncodedLen};
 use common::costs::burn_from_usable;
-use common::council::CouncilOriginValidator;
+use common::council::{
+    BudgetAllocationId, CouncilBudgetAllocations, CouncilMembersProvider, CouncilOriginValidator,
+    CouncilParticipationObserver,
+};
 use common::membership::{MemberId, MemberOriginValidator};
 use common::to_kb;
 use common::{FundingRequestParameters, StakingAccountValidator};
 use core::marker::PhantomData;
 use frame_support::dispatch::DispatchResult;
 use frame_support::storage::weak_bounded_vec::WeakBoundedVec;
-use frame_support::traits::{Currency, Get, LockIdentifier};
+use frame_support::traits::{Currency, Get, LockIdentifier, StorageVersion};
 use frame_support::weights::Weight;
+use frame_support::IterableStorageDoubleMap;
 use frame_support::{decl_error, decl_event, decl_module, decl_storage, ensure, error::BadOrigin};
 use frame_system::ensure_root;
 use referendum::{CastVote, OptionResult, ReferendumManager};
@@ -79,13 +106,14 @@ use scale_info::TypeInfo;
 #[cfg(feature = "std")]
 use serde::{Deserialize, Serialize};
 use sp_runtime::traits::{Hash, One, SaturatedConversion, Saturating, Zero};
-use sp_runtime::Percent;
+use sp_runtime::{Perbill, Percent};
 use sp_std::convert::TryInto;
 use sp_std::{vec, vec::Vec};
 use staking_handler::StakingHandler;
 
 // declared modules
 mod benchmarking;
+pub mod migrations;
 mod mock;
 mod tests;
 pub mod weights;
@@ -171,6 +199,83 @@ pub struct CouncilMember<AccountId, MemberId, Balance, BlockNumber> {
     unpaid_reward: Balance,
 }
 
+/// Special election filling the council seats vacated by the recalled councilors.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, PartialEq, Eq, Debug, Default, Clone, TypeInfo, MaxEncodedLen)]
+pub struct SpecialElection<BlockNumber> {
+    // number of vacant council seats to be filled
+    pub seats: u32,
+    // end of the idle stage interrupted by the special election
+    pub idle_ends_at: BlockNumber,
+}
+
+/// Councilor participation in the proposal voting.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, PartialEq, Eq, Debug, Default, Clone, TypeInfo, MaxEncodedLen)]
+pub struct ParticipationStats {
+    // proposal votings concluded since the last reward payment
+    pub votings_count: u32,
+    // votes cast in the proposal votings concluded since the last reward payment
+    pub votes_count: u32,
+    // proposal votings concluded during the council term
+    pub total_votings_count: u32,
+    // votes cast in the proposal votings concluded during the council term
+    pub total_votes_count: u32,
+}
+
+impl ParticipationStats {
+    /// Share of the proposal votings concluded since the last reward payment the councilor voted
+    /// in. Full participation is assumed when no proposal voting was concluded.
+    pub fn participation_rate(&self) -> Perbill {
+        if self.votings_count == 0 {
+            return Perbill::one();
+        }
+
+        Perbill::from_rational(self.votes_count, self.votings_count)
+    }
+}
+
+/// Earmarked council budget allocation: the capped part of the council budget the council
+/// spendings can draw against until the allocation expires.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, PartialEq, Eq, Debug, Default, Clone, TypeInfo, MaxEncodedLen)]
+pub struct BudgetAllocation<Balance, BlockNumber, Hash> {
+    // hash of the allocation name
+    pub name_hash: Hash,
+    // max amount that can be drawn from the allocation
+    pub cap: Balance,
+    // amount drawn from the allocation so far
+    pub spent: Balance,
+    // block the allocation was created at
+    pub created_at: BlockNumber,
+    // block the allocation can no longer be drawn from
+    pub expires_at: BlockNumber,
+    // number of spendings drawn from the allocation
+    pub spendings_count: u32,
+}
+
+impl<Balance: Saturating + Copy, BlockNumber: PartialOrd, Hash>
+    BudgetAllocation<Balance, BlockNumber, Hash>
+{
+    /// Amount that can still be drawn from the allocation.
+    pub fn remaining(&self) -> Balance {
+        self.cap.saturating_sub(self.spent)
+    }
+
+    /// Whether the allocation can no longer be drawn from.
+    pub fn is_expired(&self, now: BlockNumber) -> bool {
+        now >= self.expires_at
+    }
+}
+
+/// Spending drawn from the earmarked council budget allocation.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, PartialEq, Eq, Debug, Default, Clone, TypeInfo, MaxEncodedLen)]
+pub struct BudgetAllocationSpending<Balance, BlockNumber> {
+    pub amount: Balance,
+    pub spent_at: BlockNumber,
+}
+
 impl<AccountId, MemberId, Balance, BlockNumber>
     CouncilMember<AccountId, MemberId, Balance, BlockNumber>
 {
@@ -234,9 +339,21 @@ pub type CandidateOf<T> = Candidate<
     <T as frame_system::Config>::Hash,
     VotePowerOf<T>,
 >;
+pub type BudgetAllocationOf<T> = BudgetAllocation<
+    Balance<T>,
+    <T as frame_system::Config>::BlockNumber,
+    <T as frame_system::Config>::Hash,
+>;
+pub type BudgetAllocationSpendingOf<T> =
+    BudgetAllocationSpending<Balance<T>, <T as frame_system::Config>::BlockNumber>;
 pub type CouncilStageUpdateOf<T> = CouncilStageUpdate<<T as frame_system::Config>::BlockNumber>;
 pub(crate) type Balances<T> = balances::Pallet<T>;
 
+const CURRENT_STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
+/// Max number of the earmarked budget allocations expiring at the same block.
+pub const MAX_BUDGET_ALLOCATIONS_EXPIRING_PER_BLOCK: u32 = 10;
+
 /////////////////// Traits, Storage, Errors, and Events /////////////////////////
 
 type CouncilWeightInfo<T> = <T as Config>::WeightInfo;
@@ -346,6 +463,22 @@ decl_storage! { generate_storage_info
         /// Current council members
         pub CouncilMembers get(fn council_members): WeakBoundedVec<CouncilMemberOf<T>, T::CouncilSize>;
 
+        /// Index of the candidacy period the current council was elected in.
+        pub CouncilElectionCycle get(fn council_election_cycle): u64;
+
+        /// Special election filling the vacated council seats that is in progress.
+        pub OngoingSpecialElection get(fn ongoing_special_election):
+            Option<SpecialElection<T::BlockNumber>>;
+
+        /// Participation of the current council members in the proposal voting.
+        pub CouncilorParticipation get(fn councilor_participation): map hasher(blake2_128_concat)
+            T::MemberId => ParticipationStats;
+
+        /// Block the current council members were seated at. Zero for the council members seated
+        /// before the seating blocks were tracked.
+        pub CouncilorSeatedAt get(fn councilor_seated_at): map hasher(blake2_128_concat)
+            T::MemberId => T::BlockNumber;
+
         /// Map of all candidates that ever candidated and haven't unstake yet.
         pub Candidates get(fn candidates): map hasher(blake2_128_concat)
             T::MemberId => Option<Candidate<T::AccountId, Balance<T>, T::Hash, VotePowerOf::<T>>>;
@@ -372,6 +505,28 @@ decl_storage! { generate_storage_info
         /// Era payou damping factor: a parameter in [0,1] that can be used to reduce the era
         /// payout without changing the reward curve directly
         pub EraPayoutDampingFactor get(fn era_payout_damping_factor) config(): Percent = Percent::from_percent(100);
+
+        /// Identifier of the next earmarked budget allocation.
+        pub NextBudgetAllocationId get(fn next_budget_allocation_id): BudgetAllocationId;
+
+        /// Earmarked council budget allocations.
+        pub BudgetAllocations get(fn budget_allocations): map hasher(blake2_128_concat)
+            BudgetAllocationId => Option<BudgetAllocationOf<T>>;
+
+        /// History of the spendings drawn from the earmarked budget allocations by the allocation
+        /// and the spending index.
+        pub BudgetAllocationSpendings get(fn budget_allocation_spendings): double_map
+            hasher(blake2_128_concat) BudgetAllocationId,
+            hasher(blake2_128_concat) u32 => Option<BudgetAllocationSpendingOf<T>>;
+
+        /// Remaining cap of the unexpired budget allocations: the part of the council budget
+        /// that can't be spent without drawing against an allocation.
+        pub EarmarkedBudget get(fn earmarked_budget): Balance<T>;
+
+        /// Budget allocations by the block they expire at.
+        pub BudgetAllocationExpirations get(fn budget_allocation_expirations): double_map
+            hasher(blake2_128_concat) T::BlockNumber,
+            hasher(blake2_128_concat) BudgetAllocationId => ();
     }
 }
 
@@ -451,6 +606,55 @@ decl_event! {
 
         /// Era payou damping factor set
         EraPayoutDampingFactorSet(Percent),
+
+        /// Councilor was recalled
+        /// Params:
+        /// - Member ID of the recalled councilor
+        /// - Slashed councilor stake
+        CouncilorRecalled(MemberId, Balance),
+
+        /// Special election filling the vacated council seats started
+        /// Params:
+        /// - Number of vacant seats
+        /// - End of the candidacy announcing period
+        SpecialElectionStarted(u32, BlockNumber),
+
+        /// Vacant council seats were filled by the special election
+        /// Params:
+        /// - Elected members
+        /// - End of the idle period
+        CouncilSeatsFilled(Vec<MemberId>, BlockNumber),
+
+        /// Vacant council seats were not filled by the special election
+        /// Params:
+        /// - End of the idle period
+        CouncilSeatsNotFilled(BlockNumber),
+
+        /// Part of the councilor reward was withheld for the missed proposal votings
+        /// Params:
+        /// - Member ID of the councilor
+        /// - Withheld reward left in the council budget
+        CouncilorRewardWithheld(MemberId, Balance),
+
+        /// Earmarked budget allocation was created
+        /// Params:
+        /// - Allocation ID
+        /// - Allocation name
+        /// - Allocation cap
+        /// - Block the allocation expires at
+        BudgetAllocationCreated(BudgetAllocationId, Vec<u8>, Balance, BlockNumber),
+
+        /// Amount was drawn from the earmarked budget allocation
+        /// Params:
+        /// - Allocation ID
+        /// - Drawn amount
+        BudgetAllocationDrawn(BudgetAllocationId, Balance),
+
+        /// Earmarked budget allocation expired
+        /// Params:
+        /// - Allocation ID
+        /// - Remaining allocation cap released to the council budget
+        BudgetAllocationExpired(BudgetAllocationId, Balance),
     }
 }
 
@@ -533,7 +737,31 @@ decl_error! {
         InsufficientBalanceForTransfer,
 
         /// Cannot reduce the budget by the given amount.
-        ReductionAmountTooLarge
+        ReductionAmountTooLarge,
+
+        /// Councilor can't candidate in the special election for the vacated seats.
+        CouncilorCantCandidateNow,
+
+        /// Budget allocation cap can't be zero.
+        ZeroBudgetAllocationCap,
+
+        /// Budget allocation must expire in the future.
+        BudgetAllocationExpiryInPast,
+
+        /// Budget allocation doesn't exist.
+        BudgetAllocationDoesNotExist,
+
+        /// Budget allocation has expired.
+        BudgetAllocationExpired,
+
+        /// Amount exceeds the remaining budget allocation cap.
+        BudgetAllocationCapExceeded,
+
+        /// Budget allocation cap exceeds the council budget that is not earmarked yet.
+        InsufficientBudgetForBudgetAllocation,
+
+        /// Too many budget allocations expire at the provided block.
+        TooManyBudgetAllocationsExpiring
     }
 }
 
@@ -600,6 +828,9 @@ decl_module! {
             // Budget reward payment + budget refill
             Self::try_process_budget(now);
 
+            // Release the earmarked budget of the expired allocations
+            Self::release_expired_budget_allocations(now);
+
             // Calculates the weight using the candidate count
             Self::calculate_on_initialize_weight(mb_candidate_count)
         }
@@ -859,7 +1090,8 @@ decl_module! {
             Ok(())
         }
 
-        /// Transfers funds from council budget to account
+        /// Transfers funds from council budget to account, optionally drawing the total amount
+        /// against the earmarked budget allocation.
         ///
         /// # <weight>
         ///
@@ -874,7 +1106,8 @@ decl_module! {
         )]
         pub fn funding_request(
             origin,
-            funding_requests: Vec<FundingRequestParameters<Balance<T>, T::AccountId>>
+            funding_requests: Vec<FundingRequestParameters<Balance<T>, T::AccountId>>,
+            allocation_id: Option<BudgetAllocationId>
         ) {
             // Checks
             ensure_root(origin)?;
@@ -885,10 +1118,15 @@ decl_module! {
                     |accumulated, funding_request| accumulated.saturating_add(funding_request.amount),
                 );
 
-            let current_budget = Self::budget();
+            // Only the funding drawn against the allocation can spend the earmarked budget.
+            let spendable_budget = if allocation_id.is_some() {
+                Self::budget()
+            } else {
+                Self::unearmarked_budget()
+            };
 
             ensure!(
-                funding_total <= current_budget,
+                funding_total <= spendable_budget,
                 Error::<T>::InsufficientFundsForFundingRequest
             );
 
@@ -910,12 +1148,20 @@ decl_module! {
                 recieving_accounts.push(&funding_request.account);
             }
 
+            if let Some(allocation_id) = allocation_id {
+                EnsureChecks::<T>::can_draw_from_budget_allocation(allocation_id, funding_total)?;
+            }
+
             //
             // == MUTATION SAFE ==
             //
 
             Mutations::<T>::decrease_budget(funding_total);
 
+            if let Some(allocation_id) = allocation_id {
+                Self::draw_from_allocation(allocation_id, funding_total);
+            }
+
             for funding_request in funding_requests {
                 let amount = funding_request.amount;
                 let account = funding_request.account;
@@ -1077,6 +1323,88 @@ decl_module! {
 
             Ok(())
         }
+
+        /// Recall the councilor before the end of the term, slashing the given part of their
+        /// councilor stake. The councilor's unpaid reward is forfeited. When the council is idle,
+        /// the special election filling the vacated seat is started.
+        ///
+        /// # <weight>
+        ///
+        /// ## weight
+        /// `O (1)`
+        /// - db:
+        ///    - `O(1)` doesn't depend on the state or parameters
+        /// # </weight>
+        #[weight = CouncilWeightInfo::<T>::recall_councilor()]
+        pub fn recall_councilor(
+            origin,
+            membership_id: T::MemberId,
+            slashing_percentage: Percent
+        ) -> Result<(), Error<T>> {
+            // ensure action can be started
+            let council_member = EnsureChecks::<T>::can_recall_councilor(origin, &membership_id)?;
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            // update state
+            let slashed_stake = Mutations::<T>::recall_councilor(
+                &membership_id,
+                &council_member,
+                slashing_percentage
+            );
+
+            // emit event
+            Self::deposit_event(RawEvent::CouncilorRecalled(membership_id, slashed_stake));
+
+            // fill the vacated seat
+            Self::try_start_special_election();
+
+            Ok(())
+        }
+
+        /// Earmark the capped part of the council budget by the named allocation that the council
+        /// spendings can draw against until it expires.
+        ///
+        /// # <weight>
+        ///
+        /// ## weight
+        /// `O (N)` where:
+        /// `N` is the length of the allocation name in kilobytes
+        /// - db:
+        ///    - `O(1)` doesn't depend on the state or parameters
+        /// # </weight>
+        #[weight = CouncilWeightInfo::<T>::create_budget_allocation(to_kb(name.len().saturated_into()))]
+        pub fn create_budget_allocation(
+            origin,
+            name: Vec<u8>,
+            cap: Balance<T>,
+            expires_at: T::BlockNumber
+        ) -> Result<(), Error<T>> {
+            // ensure action can be started
+            EnsureChecks::<T>::can_create_budget_allocation(origin, cap, expires_at)?;
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            // update state
+            let name_hash = T::Hashing::hash(name.as_slice());
+            let allocation_id = Mutations::<T>::create_budget_allocation(name_hash, cap, expires_at);
+
+            // emit event
+            Self::deposit_event(RawEvent::BudgetAllocationCreated(
+                allocation_id,
+                name,
+                cap,
+                expires_at
+            ));
+
+            Ok(())
+        }
+
+        type StorageVersion = CURRENT_STORAGE_VERSION;
     }
 }
 
@@ -1109,6 +1437,24 @@ impl<T: Config> Module<T> {
         }
     }
 
+    /// Council budget that is not earmarked by the unexpired budget allocations.
+    pub fn unearmarked_budget() -> Balance<T> {
+        Self::budget().saturating_sub(Self::earmarked_budget())
+    }
+
+    // Release the remaining cap of the allocations expiring at the current block.
+    fn release_expired_budget_allocations(now: T::BlockNumber) {
+        for (allocation_id, _) in BudgetAllocationExpirations::<T>::drain_prefix(now) {
+            if let Some(allocation) = BudgetAllocations::<T>::get(allocation_id) {
+                let released = allocation.remaining();
+
+                Mutations::<T>::release_earmarked_budget(released);
+
+                Self::deposit_event(RawEvent::BudgetAllocationExpired(allocation_id, released));
+            }
+        }
+    }
+
     // Checkout elected council members reward payments.
     fn try_process_budget(now: T::BlockNumber) {
         // budget autorefill
@@ -1124,8 +1470,20 @@ impl<T: Config> Module<T> {
 
     // Finish voting and start ravealing.
     fn end_announcement_period(candidates_count: u32) {
-        let min_candidate_count =
-            T::CouncilSize::get().saturating_add(T::MinNumberOfExtraCandidates::get());
+        let seats_count = Self::seats_to_elect();
+        let min_candidate_count = seats_count.saturating_add(T::MinNumberOfExtraCandidates::get());
+
+        // leave the seats vacant when not enough candidates registered for the special election
+        if candidates_count < min_candidate_count {
+            if let Some(special_election) = OngoingSpecialElection::<T>::get() {
+                let idle_ends_at = Mutations::<T>::end_special_election(&special_election, &[]);
+
+                // emit event
+                Self::deposit_event(RawEvent::CouncilSeatsNotFilled(idle_ends_at));
+
+                return;
+            }
+        }
 
         // reset announcing period when not enough candidates registered
         if candidates_count < min_candidate_count {
@@ -1140,7 +1498,7 @@ impl<T: Config> Module<T> {
         }
 
         // update state
-        Mutations::<T>::finalize_announcing_period(candidates_count);
+        Mutations::<T>::finalize_announcing_period(candidates_count, seats_count);
 
         // emit event
         Self::deposit_event(RawEvent::VotingPeriodStarted(candidates_count));
@@ -1153,6 +1511,12 @@ impl<T: Config> Module<T> {
             VotePowerOf<T>,
         >],
     ) {
+        if let Some(special_election) = OngoingSpecialElection::<T>::get() {
+            Self::end_special_election(winners, &special_election);
+
+            return;
+        }
+
         let council_size = T::CouncilSize::get();
         if winners.len() as u32 != council_size {
             // reset candidacy announcement period
@@ -1169,17 +1533,8 @@ impl<T: Config> Module<T> {
         let now: T::BlockNumber = <frame_system::Pallet<T>>::block_number();
 
         // prepare candidates that got elected
-        let elected_members: Vec<CouncilMemberOf<T>> = winners
-            .iter()
-            .filter_map(|item| {
-                let membership_id = item.option_id;
-                Candidates::<T>::get(membership_id).map(|candidate| {
-                    // clear candidate record and unlock their candidacy stake
-                    Mutations::<T>::clear_candidate(&membership_id, &candidate);
-                    (candidate, membership_id, now, Zero::zero()).into()
-                })
-            })
-            .collect();
+        let elected_members = Self::prepare_elected_members(winners, now);
+
         // prepare council users for event
         let elected_council_users = elected_members
             .iter()
@@ -1199,6 +1554,67 @@ impl<T: Config> Module<T> {
         T::new_council_elected(elected_members.as_slice());
     }
 
+    // Conclude special election and fill the vacant seats if possible.
+    fn end_special_election(
+        winners: &[OptionResult<
+            <T as common::membership::MembershipTypes>::MemberId,
+            VotePowerOf<T>,
+        >],
+        special_election: &SpecialElection<T::BlockNumber>,
+    ) {
+        // leave the seats vacant when not enough candidates got votes
+        if winners.len() as u32 != special_election.seats {
+            let idle_ends_at = Mutations::<T>::end_special_election(special_election, &[]);
+
+            // emit event
+            Self::deposit_event(RawEvent::CouncilSeatsNotFilled(idle_ends_at));
+
+            return;
+        }
+
+        let now: T::BlockNumber = <frame_system::Pallet<T>>::block_number();
+
+        // prepare candidates that got elected
+        let elected_members = Self::prepare_elected_members(winners, now);
+
+        // update state
+        let idle_ends_at =
+            Mutations::<T>::end_special_election(special_election, elected_members.as_slice());
+
+        // emit event
+        Self::deposit_event(RawEvent::CouncilSeatsFilled(
+            elected_members
+                .iter()
+                .map(|item| item.membership_id)
+                .collect(),
+            idle_ends_at,
+        ));
+    }
+
+    // Start special election for the vacated seat when the council is idle or add the seat to the
+    // special election that is still announcing candidacies.
+    fn try_start_special_election() {
+        match (Stage::<T>::get().stage, OngoingSpecialElection::<T>::get()) {
+            (CouncilStage::Idle(stage_data), None) => {
+                // update state
+                let announcing_period_end_block =
+                    Mutations::<T>::start_special_election(stage_data.ends_at);
+
+                // emit event
+                Self::deposit_event(RawEvent::SpecialElectionStarted(
+                    1,
+                    announcing_period_end_block,
+                ));
+            }
+            (CouncilStage::Announcing(_), Some(special_election)) => {
+                // update state
+                Mutations::<T>::add_special_election_seat(&special_election);
+            }
+            // the seat stays vacant until the next election
+            _ => (),
+        }
+    }
+
     // Finish idle period and start new council election cycle (announcing period).
     fn end_idle_period() {
         // update state
@@ -1241,12 +1657,22 @@ impl<T: Config> Module<T> {
         let new_balance = CouncilMembers::<T>::get().iter().enumerate().fold(
             starting_balance,
             |balance, (member_index, council_member)| {
+                let participation_rate =
+                    CouncilorParticipation::<T>::get(council_member.membership_id)
+                        .participation_rate();
+
                 // calculate unpaid reward
-                let unpaid_reward =
-                    Calculations::<T>::get_current_reward(council_member, reward_per_block, now);
+                let (unpaid_reward, withheld_reward) = Calculations::<T>::get_current_reward(
+                    council_member,
+                    reward_per_block,
+                    now,
+                    participation_rate,
+                );
 
-                // depleted budget or no accumulated reward to be paid?
-                if balance == Zero::zero() || unpaid_reward == Zero::zero() {
+                // depleted budget or no accumulated reward to be paid or withheld?
+                if balance == Zero::zero()
+                    || (unpaid_reward == Zero::zero() && withheld_reward == Zero::zero())
+                {
                     // no need to update council member record here; their unpaid reward will be
                     // recalculated next time rewards are paid
 
@@ -1272,8 +1698,9 @@ impl<T: Config> Module<T> {
                     &missing_balance,
                     &now,
                 );
+                Mutations::<T>::reset_participation_period(&council_member.membership_id);
 
-                // emit event
+                // emit events
                 Self::deposit_event(RawEvent::RewardPayment(
                     council_member.membership_id,
                     council_member.reward_account_id.clone(),
@@ -1281,6 +1708,13 @@ impl<T: Config> Module<T> {
                     missing_balance,
                 ));
 
+                if !withheld_reward.is_zero() {
+                    Self::deposit_event(RawEvent::CouncilorRewardWithheld(
+                        council_member.membership_id,
+                        withheld_reward,
+                    ));
+                }
+
                 // return new balance
                 balance.saturating_sub(available_balance)
             },
@@ -1292,6 +1726,34 @@ impl<T: Config> Module<T> {
 
     /////////////////// Utils //////////////////////////////////////////////////
 
+    // Number of council seats to be filled by the current election.
+    fn seats_to_elect() -> u32 {
+        OngoingSpecialElection::<T>::get().map_or_else(T::CouncilSize::get, |special_election| {
+            special_election.seats
+        })
+    }
+
+    // Prepare council members from the candidates that got elected.
+    fn prepare_elected_members(
+        winners: &[OptionResult<
+            <T as common::membership::MembershipTypes>::MemberId,
+            VotePowerOf<T>,
+        >],
+        now: T::BlockNumber,
+    ) -> Vec<CouncilMemberOf<T>> {
+        winners
+            .iter()
+            .filter_map(|item| {
+                let membership_id = item.option_id;
+                Candidates::<T>::get(membership_id).map(|candidate| {
+                    // clear candidate record and unlock their candidacy stake
+                    Mutations::<T>::clear_candidate(&membership_id, &candidate);
+                    (candidate, membership_id, now, Zero::zero()).into()
+                })
+            })
+            .collect()
+    }
+
     // Construct a new candidate for council election.
     fn prepare_new_candidate(
         staking_account_id: T::AccountId,
@@ -1327,12 +1789,20 @@ impl<T: Config> Module<T> {
             weight
         };
 
+        // Worst case of the expired budget allocations release
+        let max_expired_allocations: u64 = MAX_BUDGET_ALLOCATIONS_EXPIRING_PER_BLOCK.into();
+        let release_allocations_weight = T::DbWeight::get().reads_writes(
+            max_expired_allocations.saturating_mul(2).saturating_add(2),
+            max_expired_allocations.saturating_add(1),
+        );
+
         // Total weight = try progress weight + refill budget weight
-        //      + pay council member rewards weight
+        //      + pay council member rewards weight + release expired allocations weight
         CouncilWeightInfo::<T>::try_process_budget_refill_budget_only()
             .saturating_add(
                 CouncilWeightInfo::<T>::try_process_budget_payout_council_members_only(),
             )
+            .saturating_add(release_allocations_weight)
             .saturating_add(weight)
     }
 }
@@ -1356,32 +1826,36 @@ impl<T: Config> ReferendumConnection<T> for Module<T> {
     // Check that it is a proper time to release stake.
     fn can_unlock_vote_stake(vote: &CastVoteOf<T>) -> Result<(), Error<T>> {
         let current_voting_cycle_id = AnnouncementPeriodNr::get();
+        let is_idle = matches!(Stage::<T>::get().stage, CouncilStage::Idle(_));
 
-        // If the vote was cast before the latest Announcing stage...
-        if vote.cycle_id != current_voting_cycle_id {
+        // If the vote is for the ongoing election...
+        if vote.cycle_id == current_voting_cycle_id && !is_idle {
+            // ..it is not recoverable.
+            return Err(Error::CantReleaseStakeNow);
+        }
+
+        // The council serves until the Idle stage ends, possibly interrupted by special elections.
+        let is_council_term = is_idle || OngoingSpecialElection::<T>::exists();
+
+        // If the vote was cast before the current council was elected or the term is over...
+        if vote.cycle_id < CouncilElectionCycle::get() || !is_council_term {
             // ..it is always recoverable.
             return Ok(());
         }
 
-        // The vote is for the current election cycle.
+        // The vote is for the current council election or its special elections.
 
-        if let CouncilStage::Idle(_) = Stage::<T>::get().stage {
-            // The election is concluded..
-            let voted_for_winner = CouncilMembers::<T>::get()
-                .iter()
-                .map(|council_member| council_member.membership_id)
-                .any(|membership_id| vote.vote_for == Some(membership_id));
+        let voted_for_councilor = CouncilMembers::<T>::get()
+            .iter()
+            .map(|council_member| council_member.membership_id)
+            .any(|membership_id| vote.vote_for == Some(membership_id));
 
-            if voted_for_winner {
-                // ..and vote is for a winning candidate, so it is not recoverable.
-                Err(Error::CantReleaseStakeNow)
-            } else {
-                // ..and vote is for a losing candidate, so it is recoverable.
-                Ok(())
-            }
-        } else {
-            // The election is ongoing, so it is not recoverable.
+        if voted_for_councilor {
+            // ..and vote is for a sitting councilor, so it is not recoverable.
             Err(Error::CantReleaseStakeNow)
+        } else {
+            // ..and vote is for a losing candidate, so it is recoverable.
+            Ok(())
         }
     }
 
@@ -1418,21 +1892,34 @@ struct Calculations<T: Config> {
 }
 
 impl<T: Config> Calculations<T> {
-    // Calculate current reward for the recipient.
+    // Calculate current reward for the recipient and the reward withheld for the missed proposal
+    // votings.
     fn get_current_reward(
         council_member: &CouncilMemberOf<T>,
         reward_per_block: Balance<T>,
         now: T::BlockNumber,
-    ) -> Balance<T> {
-        // calculate currently unpaid reward for elected council member
-        // previously_unpaid_reward +
+        participation_rate: Perbill,
+    ) -> (Balance<T>, Balance<T>) {
+        // calculate reward earned since the last payment
         // (current_block_number - last_payment_block_number) *
         // reward_per_block
-        council_member.unpaid_reward.saturating_add(
-            now.saturating_sub(council_member.last_payment_block)
-                .saturated_into::<u64>()
-                .saturating_mul(reward_per_block.saturated_into())
-                .saturated_into(),
+        let earned_reward: Balance<T> = now
+            .saturating_sub(council_member.last_payment_block)
+            .saturated_into::<u64>()
+            .saturating_mul(reward_per_block.saturated_into())
+            .saturated_into();
+
+        // scale earned reward by the participation in the proposal voting
+        let participation_reward = participation_rate * earned_reward;
+        let withheld_reward = earned_reward.saturating_sub(participation_reward);
+
+        // calculate currently unpaid reward for elected council member
+        // previously_unpaid_reward + participation_reward
+        (
+            council_member
+                .unpaid_reward
+                .saturating_add(participation_reward),
+            withheld_reward,
         )
     }
 
@@ -1485,8 +1972,8 @@ impl<T: Config> Mutations<T> {
     }
 
     // Change the council stage from the announcing to the election stage.
-    fn finalize_announcing_period(candidates_count: u32) {
-        let extra_winning_target_count = T::CouncilSize::get().saturating_sub(1);
+    fn finalize_announcing_period(candidates_count: u32, seats_count: u32) {
+        let extra_winning_target_count = seats_count.saturating_sub(1);
 
         // start referendum
         T::Referendum::force_start(extra_winning_target_count, AnnouncementPeriodNr::get());
@@ -1515,9 +2002,11 @@ impl<T: Config> Mutations<T> {
         // try to pay any unpaid rewards (any unpaid rewards after this will be discarded call)
         Module::<T>::pay_elected_member_rewards(now);
 
-        // release stakes for previous council members
+        // release stakes and clear participation for previous council members
         for council_member in CouncilMembers::<T>::get() {
             T::CouncilorLock::unlock(&council_member.staking_account_id);
+            CouncilorParticipation::<T>::remove(council_member.membership_id);
+            CouncilorSeatedAt::<T>::remove(council_member.membership_id);
         }
 
         // set new council
@@ -1525,12 +2014,98 @@ impl<T: Config> Mutations<T> {
             elected_members.to_vec(),
             Some("CouncilMembers"),
         ));
+        CouncilElectionCycle::put(AnnouncementPeriodNr::get());
 
         // setup elected member lock for new council's members
         for council_member in CouncilMembers::<T>::get() {
             // lock council member stake
             T::CouncilorLock::lock(&council_member.staking_account_id, council_member.stake);
+            CouncilorSeatedAt::<T>::insert(council_member.membership_id, now);
+        }
+    }
+
+    // Start special election interrupting the idle stage.
+    fn start_special_election(idle_ends_at: T::BlockNumber) -> T::BlockNumber {
+        OngoingSpecialElection::<T>::put(SpecialElection {
+            seats: 1,
+            idle_ends_at,
+        });
+
+        Self::start_announcing_period()
+    }
+
+    // Add vacated seat to the special election.
+    fn add_special_election_seat(special_election: &SpecialElection<T::BlockNumber>) {
+        OngoingSpecialElection::<T>::put(SpecialElection {
+            seats: special_election.seats.saturating_add(1),
+            ..special_election.clone()
+        });
+    }
+
+    // Fill the vacant seats with the elected members and return to the interrupted idle stage.
+    fn end_special_election(
+        special_election: &SpecialElection<T::BlockNumber>,
+        elected_members: &[CouncilMemberOf<T>],
+    ) -> T::BlockNumber {
+        let now = <frame_system::Pallet<T>>::block_number();
+        let ends_at = special_election
+            .idle_ends_at
+            .max(now.saturating_add(One::one()));
+
+        OngoingSpecialElection::<T>::kill();
+
+        // change council state
+        Stage::<T>::put(CouncilStageUpdate {
+            stage: CouncilStage::Idle(CouncilStageIdle { ends_at }),
+            changed_at: now,
+        });
+
+        // add elected members to the council
+        let council_members = CouncilMembers::<T>::get()
+            .into_iter()
+            .chain(elected_members.iter().cloned())
+            .collect::<Vec<_>>();
+        CouncilMembers::<T>::put(WeakBoundedVec::<_, _>::force_from(
+            council_members,
+            Some("CouncilMembers"),
+        ));
+
+        // setup elected member lock for the new members
+        for council_member in elected_members {
+            T::CouncilorLock::lock(&council_member.staking_account_id, council_member.stake);
+            CouncilorSeatedAt::<T>::insert(council_member.membership_id, now);
         }
+
+        ends_at
+    }
+
+    // Remove councilor from the council, slash the given part of their stake and release the rest.
+    fn recall_councilor(
+        membership_id: &T::MemberId,
+        council_member: &CouncilMemberOf<T>,
+        slashing_percentage: Percent,
+    ) -> Balance<T> {
+        // remove councilor from the council
+        let council_members = CouncilMembers::<T>::get()
+            .into_iter()
+            .filter(|council_member| council_member.membership_id != *membership_id)
+            .collect::<Vec<_>>();
+        CouncilMembers::<T>::put(WeakBoundedVec::<_, _>::force_from(
+            council_members,
+            Some("CouncilMembers"),
+        ));
+
+        CouncilorParticipation::<T>::remove(membership_id);
+        CouncilorSeatedAt::<T>::remove(membership_id);
+
+        // slash and release councilor stake
+        let slashed_stake = T::CouncilorLock::slash(
+            &council_member.staking_account_id,
+            Some(slashing_percentage * council_member.stake),
+        );
+        T::CouncilorLock::unlock(&council_member.staking_account_id);
+
+        slashed_stake
     }
 
     // Announce user's candidacy.
@@ -1641,6 +2216,57 @@ impl<T: Config> Mutations<T> {
         Budget::<T>::mutate(|balance| *balance = balance.saturating_sub(amount));
     }
 
+    // Create new earmarked budget allocation.
+    fn create_budget_allocation(
+        name_hash: T::Hash,
+        cap: Balance<T>,
+        expires_at: T::BlockNumber,
+    ) -> BudgetAllocationId {
+        let allocation_id = NextBudgetAllocationId::get();
+
+        BudgetAllocations::<T>::insert(
+            allocation_id,
+            BudgetAllocation {
+                name_hash,
+                cap,
+                spent: Zero::zero(),
+                created_at: frame_system::Pallet::<T>::block_number(),
+                expires_at,
+                spendings_count: 0,
+            },
+        );
+        NextBudgetAllocationId::put(allocation_id.saturating_add(1));
+        BudgetAllocationExpirations::<T>::insert(expires_at, allocation_id, ());
+        EarmarkedBudget::<T>::mutate(|earmarked| *earmarked = earmarked.saturating_add(cap));
+
+        allocation_id
+    }
+
+    // Release the earmarked budget.
+    fn release_earmarked_budget(amount: Balance<T>) {
+        EarmarkedBudget::<T>::mutate(|earmarked| *earmarked = earmarked.saturating_sub(amount));
+    }
+
+    // Record the spending drawn from the earmarked budget allocation.
+    fn draw_from_budget_allocation(allocation_id: BudgetAllocationId, amount: Balance<T>) {
+        let spent_at = frame_system::Pallet::<T>::block_number();
+
+        BudgetAllocations::<T>::mutate(allocation_id, |maybe_allocation| {
+            if let Some(allocation) = maybe_allocation {
+                BudgetAllocationSpendings::<T>::insert(
+                    allocation_id,
+                    allocation.spendings_count,
+                    BudgetAllocationSpending { amount, spent_at },
+                );
+
+                allocation.spent = allocation.spent.saturating_add(amount);
+                allocation.spendings_count = allocation.spendings_count.saturating_add(1);
+            }
+        });
+
+        Self::release_earmarked_budget(amount);
+    }
+
     // Plan next budget refill.
     fn plan_budget_refill(refill_at: &T::BlockNumber) {
         NextBudgetRefill::<T>::put(refill_at);
@@ -1679,6 +2305,45 @@ impl<T: Config> Mutations<T> {
         });
     }
 
+    // Start a new participation period for the councilor after the reward payment.
+    fn reset_participation_period(membership_id: &T::MemberId) {
+        CouncilorParticipation::<T>::mutate(membership_id, |participation| {
+            participation.votings_count = 0;
+            participation.votes_count = 0;
+        });
+    }
+
+    // Register the concluded proposal voting in the participation of the council members. The
+    // voting is missed only by the council members seated before the voting started, when its
+    // voting period expired without their vote.
+    fn register_proposal_voting(
+        voters: &[T::MemberId],
+        voting_started_at: T::BlockNumber,
+        voting_period_expired: bool,
+    ) {
+        for council_member in CouncilMembers::<T>::get() {
+            let voted = voters.contains(&council_member.membership_id);
+            let missed = voting_period_expired
+                && CouncilorSeatedAt::<T>::get(council_member.membership_id) <= voting_started_at;
+
+            if !voted && !missed {
+                continue;
+            }
+
+            CouncilorParticipation::<T>::mutate(council_member.membership_id, |participation| {
+                participation.votings_count = participation.votings_count.saturating_add(1);
+                participation.total_votings_count =
+                    participation.total_votings_count.saturating_add(1);
+
+                if voted {
+                    participation.votes_count = participation.votes_count.saturating_add(1);
+                    participation.total_votes_count =
+                        participation.total_votes_count.saturating_add(1);
+                }
+            });
+        }
+    }
+
     // Save reward-payments-related changes and plan the next reward payout.
     fn finish_reward_payments(new_balance: Balance<T>, now: T::BlockNumber) {
         // update budget's balance
@@ -1744,6 +2409,15 @@ impl<T: Config> EnsureChecks<T> {
             _ => return Err(Error::CantCandidateNow),
         };
 
+        // prevent councilor from taking another seat in the special election
+        if OngoingSpecialElection::<T>::exists()
+            && CouncilMembers::<T>::get()
+                .iter()
+                .any(|council_member| council_member.member_id() == membership_id)
+        {
+            return Err(Error::CouncilorCantCandidateNow);
+        }
+
         // when previous candidacy record is present, ensure user is not candidating twice &
         // prepare old stake for unlocking
         let mut existing_staking_account_id = None;
@@ -1880,6 +2554,69 @@ impl<T: Config> EnsureChecks<T> {
 
         Ok(())
     }
+
+    // Ensures there is no problem in recalling the councilor.
+    fn can_recall_councilor(
+        origin: T::RuntimeOrigin,
+        membership_id: &T::MemberId,
+    ) -> Result<CouncilMemberOf<T>, Error<T>> {
+        ensure_root(origin)?;
+
+        CouncilMembers::<T>::get()
+            .into_iter()
+            .find(|council_member| council_member.member_id() == membership_id)
+            .ok_or(Error::NotCouncilor)
+    }
+
+    // Ensures there is no problem in creating the budget allocation.
+    fn can_create_budget_allocation(
+        origin: T::RuntimeOrigin,
+        cap: Balance<T>,
+        expires_at: T::BlockNumber,
+    ) -> Result<(), Error<T>> {
+        ensure_root(origin)?;
+
+        ensure!(!cap.is_zero(), Error::ZeroBudgetAllocationCap);
+
+        ensure!(
+            expires_at > frame_system::Pallet::<T>::block_number(),
+            Error::BudgetAllocationExpiryInPast
+        );
+
+        ensure!(
+            cap <= Module::<T>::unearmarked_budget(),
+            Error::InsufficientBudgetForBudgetAllocation
+        );
+
+        let expiring_count = BudgetAllocationExpirations::<T>::iter_prefix(expires_at).count();
+        ensure!(
+            expiring_count < MAX_BUDGET_ALLOCATIONS_EXPIRING_PER_BLOCK as usize,
+            Error::TooManyBudgetAllocationsExpiring
+        );
+
+        Ok(())
+    }
+
+    // Ensures the amount can be drawn from the budget allocation.
+    fn can_draw_from_budget_allocation(
+        allocation_id: BudgetAllocationId,
+        amount: Balance<T>,
+    ) -> Result<BudgetAllocationOf<T>, Error<T>> {
+        let allocation = BudgetAllocations::<T>::get(allocation_id)
+            .ok_or(Error::BudgetAllocationDoesNotExist)?;
+
+        ensure!(
+            !allocation.is_expired(frame_system::Pallet::<T>::block_number()),
+            Error::BudgetAllocationExpired
+        );
+
+        ensure!(
+            amount <= allocation.remaining(),
+            Error::BudgetAllocationCapExceeded
+        );
+
+        Ok(allocation)
+    }
 }
 
 impl<T: Config + common::membership::MembershipTypes>
@@ -1898,6 +2635,25 @@ impl<T: Config + common::membership::MembershipTypes>
     }
 }
 
+impl<T: Config> CouncilMembersProvider<T::MemberId> for Module<T> {
+    fn council_member_ids() -> Vec<T::MemberId> {
+        Self::council_members()
+            .iter()
+            .map(|council_member| *council_member.member_id())
+            .collect()
+    }
+}
+
+impl<T: Config> CouncilParticipationObserver<T::MemberId, T::BlockNumber> for Module<T> {
+    fn proposal_voting_concluded(
+        voters: &[T::MemberId],
+        voting_started_at: T::BlockNumber,
+        voting_period_expired: bool,
+    ) {
+        Mutations::<T>::register_proposal_voting(voters, voting_started_at, voting_period_expired);
+    }
+}
+
 impl<T: Config + balances::Config> common::council::CouncilBudgetManager<T::AccountId, Balance<T>>
     for Module<T>
 {
@@ -1923,6 +2679,23 @@ impl<T: Config + balances::Config> common::council::CouncilBudgetManager<T::Acco
     }
 }
 
+impl<T: Config> CouncilBudgetAllocations<Balance<T>> for Module<T> {
+    fn ensure_can_draw_from_allocation(
+        allocation_id: BudgetAllocationId,
+        amount: Balance<T>,
+    ) -> DispatchResult {
+        EnsureChecks::<T>::can_draw_from_budget_allocation(allocation_id, amount)?;
+
+        Ok(())
+    }
+
+    fn draw_from_allocation(allocation_id: BudgetAllocationId, amount: Balance<T>) {
+        Mutations::<T>::draw_from_budget_allocation(allocation_id, amount);
+
+        Self::deposit_event(RawEvent::BudgetAllocationDrawn(allocation_id, amount));
+    }
+}
+
 impl<T: Config> frame_support::traits::Hooks<T::BlockNumber> for Pallet<T> {
     #[cfg(feature = "try-runtime")]
     fn try_state(_: T::BlockNumber) -> Result<(), &'static str> {
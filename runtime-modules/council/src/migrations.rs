@@ -0,0 +1,36 @@
+// Migrations for Council Pallet
+
+use super::*;
+use frame_support::traits::{GetStorageVersion, OnRuntimeUpgrade};
+
+pub mod v1 {
+    use super::*;
+
+    /// Seeds `CouncilElectionCycle` with the candidacy period the current council was elected in,
+    /// so the stakes of the votes cast in the earlier elections stay recoverable.
+    pub struct MigrateToV1<T>(PhantomData<T>);
+
+    impl<T: Config> OnRuntimeUpgrade for MigrateToV1<T> {
+        fn on_runtime_upgrade() -> Weight {
+            let onchain = Pallet::<T>::on_chain_storage_version();
+            let current = Pallet::<T>::current_storage_version();
+
+            if onchain > 0 {
+                return T::DbWeight::get().reads(1);
+            }
+
+            // The candidacy period counter is already increased during the ongoing election.
+            let announcement_period_nr = AnnouncementPeriodNr::get();
+            let council_election_cycle = match Stage::<T>::get().stage {
+                CouncilStage::Idle(_) => announcement_period_nr,
+                _ => announcement_period_nr.saturating_sub(1),
+            };
+
+            CouncilElectionCycle::put(council_election_cycle);
+
+            current.put::<Pallet<T>>();
+
+            T::DbWeight::get().reads_writes(3, 2)
+        }
+    }
+}
@@ -3,12 +3,15 @@
 /////////////////// Configuration //////////////////////////////////////////////
 use crate as council;
 use crate::{
-    AnnouncementPeriodNr, Balance, Budget, BudgetIncrement, CandidateOf, Candidates, Config,
-    CouncilMemberOf, CouncilMembers, CouncilStage, CouncilStageAnnouncing, CouncilStageElection,
-    CouncilStageIdle, CouncilStageUpdate, CouncilorReward, Error, Module, NextBudgetRefill,
-    RawEvent, ReferendumConnection, Stage,
+    AnnouncementPeriodNr, Balance, Budget, BudgetAllocation, BudgetAllocationSpending,
+    BudgetIncrement, CandidateOf, Candidates, Config, CouncilMemberOf, CouncilMembers,
+    CouncilStage, CouncilStageAnnouncing, CouncilStageElection, CouncilStageIdle,
+    CouncilStageUpdate, CouncilorParticipation, CouncilorReward, CouncilorSeatedAt, Error, Module,
+    NextBudgetRefill, ParticipationStats, RawEvent, ReferendumConnection, Stage,
 };
 
+use common::council::{BudgetAllocationId, CouncilParticipationObserver};
+
 use frame_support::dispatch::{DispatchError, DispatchResult};
 use frame_support::traits::{
     ConstU16, ConstU32, ConstU64, Currency, EitherOfDiverse, Get, LockIdentifier, OnFinalize,
@@ -201,6 +204,10 @@ parameter_types! {
     pub const DefaultMembershipPrice: u64 = 100;
     pub const DefaultInitialInvitationBalance: u64 = 100;
     pub const DefaultMemberInvitesCount: u32 = 2;
+    pub const HandleReclaimCooldown: u64 = 1000;
+    pub const MaxRecoveryGuardians: u32 = 5;
+    pub const RecoveryDelay: u64 = 100;
+    pub const RecoveryCooldown: u64 = 50;
     pub const MinimumPeriod: u64 = 5;
     pub const InvitedMemberLockId: [u8; 8] = [2; 8];
     pub const StakingCandidateLockId: [u8; 8] = [3; 8];
@@ -228,6 +235,12 @@ impl referendum::Config<ReferendumInstance> for Runtime {
 
     type MaxWinnerTargetCount = MaxWinnerTargetCount;
 
+    type DefaultVotePowerStrategy = ();
+    type MaxRankedBallotPreferences = ConstU32<5>;
+    type MaxRankedBallots = ConstU32<10>;
+    type MaxVotedOptions = ConstU32<100>;
+    type MaxDelegatorsPerDelegate = ConstU32<10>;
+
     fn calculate_vote_power(
         account_id: &<Self as frame_system::Config>::AccountId,
         stake: &Balance<Self>,
@@ -305,6 +318,10 @@ impl membership::Config for Runtime {
         staking_handler::StakingManager<Self, StakingCandidateLockId>;
     type CandidateStake = CandidateStake;
     type DefaultMemberInvitesCount = DefaultMemberInvitesCount;
+    type HandleReclaimCooldown = HandleReclaimCooldown;
+    type MaxRecoveryGuardians = MaxRecoveryGuardians;
+    type RecoveryDelay = RecoveryDelay;
+    type RecoveryCooldown = RecoveryCooldown;
 }
 
 pub struct Wg;
@@ -1019,6 +1036,87 @@ where
         );
     }
 
+    pub fn recall_councilor(
+        origin: OriginType<T::AccountId>,
+        member_id: T::MemberId,
+        slashing_percentage: Percent,
+        expected_result: Result<(), Error<T>>,
+    ) {
+        let council_member = CouncilMembers::<T>::get()
+            .into_iter()
+            .find(|council_member| council_member.membership_id == member_id);
+
+        // check method returns expected result
+        assert_eq!(
+            Module::<T>::recall_councilor(
+                InstanceMockUtils::<T>::mock_origin(origin),
+                member_id,
+                slashing_percentage,
+            ),
+            expected_result,
+        );
+
+        if expected_result.is_err() {
+            return;
+        }
+
+        let council_member = council_member.unwrap();
+        let slashed_stake = slashing_percentage * council_member.stake;
+
+        assert!(!CouncilMembers::<T>::get()
+            .iter()
+            .any(|council_member| council_member.membership_id == member_id));
+
+        assert_eq!(
+            T::CouncilorLock::current_stake(&council_member.staking_account_id),
+            0.into(),
+        );
+
+        assert!(frame_system::Pallet::<Runtime>::events()
+            .iter()
+            .any(|ev| ev.event
+                == RuntimeEvent::Council(RawEvent::CouncilorRecalled(
+                    member_id.into(),
+                    slashed_stake.into(),
+                ))));
+    }
+
+    pub fn conclude_proposal_voting(
+        voters: &[T::MemberId],
+        voting_started_at: T::BlockNumber,
+        voting_period_expired: bool,
+    ) {
+        let council_members = CouncilMembers::<T>::get();
+        let participation_before: Vec<ParticipationStats> = council_members
+            .iter()
+            .map(|council_member| CouncilorParticipation::<T>::get(council_member.membership_id))
+            .collect();
+
+        <Module<T> as CouncilParticipationObserver<T::MemberId, T::BlockNumber>>::proposal_voting_concluded(
+            voters,
+            voting_started_at,
+            voting_period_expired,
+        );
+
+        for (council_member, before) in council_members.iter().zip(participation_before) {
+            let voted = voters.contains(&council_member.membership_id);
+            let missed = voting_period_expired
+                && CouncilorSeatedAt::<T>::get(council_member.membership_id) <= voting_started_at;
+            let counted = (voted || missed) as u32;
+            let voted = voted as u32;
+
+            assert_eq!(
+                CouncilorParticipation::<T>::get(council_member.membership_id),
+                ParticipationStats {
+                    votings_count: before.votings_count + counted,
+                    votes_count: before.votes_count + voted,
+                    total_votings_count: before.total_votings_count + counted,
+                    total_votes_count: before.total_votes_count + voted,
+                }
+            );
+        }
+    }
+
     pub fn set_budget(
         origin: OriginType<T::AccountId>,
         amount: Balance<T>,
@@ -1048,14 +1146,18 @@ where
     pub fn funding_request(
         origin: OriginType<T::AccountId>,
         funding_requests: Vec<common::FundingRequestParameters<Balance<T>, T::AccountId>>,
+        allocation_id: Option<BudgetAllocationId>,
         expected_result: Result<(), Error<T>>,
     ) {
         let initial_budget = Module::<T>::budget();
+        let initial_allocation =
+            allocation_id.and_then(|allocation_id| Module::<T>::budget_allocations(allocation_id));
         // check method returns expected result
         assert_eq!(
             Module::<T>::funding_request(
                 InstanceMockUtils::<T>::mock_origin(origin),
                 funding_requests.clone(),
+                allocation_id,
             )
             .is_ok(),
             expected_result.is_ok(),
@@ -1087,6 +1189,84 @@ where
             });
 
         assert_eq!(Module::<T>::budget(), initial_budget - spent_amount);
+
+        if let (Some(allocation_id), Some(initial_allocation)) = (allocation_id, initial_allocation)
+        {
+            let allocation = Module::<T>::budget_allocations(allocation_id).unwrap();
+
+            assert_eq!(allocation.spent, initial_allocation.spent + spent_amount);
+            assert_eq!(
+                Module::<T>::budget_allocation_spendings(
+                    allocation_id,
+                    initial_allocation.spendings_count
+                ),
+                Some(BudgetAllocationSpending {
+                    amount: spent_amount,
+                    spent_at: frame_system::Pallet::<T>::block_number(),
+                })
+            );
+            assert_eq!(
+                frame_system::Pallet::<Runtime>::events()
+                    .last()
+                    .unwrap()
+                    .event,
+                RuntimeEvent::Council(RawEvent::BudgetAllocationDrawn(
+                    allocation_id,
+                    spent_amount.into(),
+                ))
+            );
+        }
+    }
+
+    pub fn create_budget_allocation(
+        origin: OriginType<T::AccountId>,
+        name: Vec<u8>,
+        cap: Balance<T>,
+        expires_at: T::BlockNumber,
+        expected_result: Result<(), Error<T>>,
+    ) {
+        let allocation_id = Module::<T>::next_budget_allocation_id();
+
+        // check method returns expected result
+        assert_eq!(
+            Module::<T>::create_budget_allocation(
+                InstanceMockUtils::<T>::mock_origin(origin),
+                name.clone(),
+                cap,
+                expires_at,
+            ),
+            expected_result,
+        );
+
+        if expected_result.is_err() {
+            assert_eq!(Module::<T>::next_budget_allocation_id(), allocation_id);
+            return;
+        }
+
+        assert_eq!(Module::<T>::next_budget_allocation_id(), allocation_id + 1);
+        assert_eq!(
+            Module::<T>::budget_allocations(allocation_id),
+            Some(BudgetAllocation {
+                name_hash: T::Hashing::hash(name.as_slice()),
+                cap,
+                spent: Balance::<T>::zero(),
+                created_at: frame_system::Pallet::<T>::block_number(),
+                expires_at,
+                spendings_count: 0,
+            })
+        );
+        assert_eq!(
+            frame_system::Pallet::<Runtime>::events()
+                .last()
+                .unwrap()
+                .event,
+            RuntimeEvent::Council(RawEvent::BudgetAllocationCreated(
+                allocation_id,
+                name,
+                cap.into(),
+                expires_at.into(),
+            ))
+        );
     }
 
     pub fn plan_budget_refill(
@@ -2,16 +2,18 @@
 
 use super::{
     AnnouncementPeriodNr, Budget, BudgetIncrement, Config, CouncilMemberOf, CouncilMembers,
-    CouncilStageAnnouncing, Error, Module,
+    CouncilStage, CouncilStageAnnouncing, CouncilStageIdle, CouncilorParticipation, Error, Module,
 };
+use crate::migrations::v1::MigrateToV1;
 use crate::mock::*;
 use common::council::CouncilBudgetManager;
 use common::council::CouncilOriginValidator;
 use frame_support::dispatch::DispatchError;
-use frame_support::traits::Currency;
+use frame_support::traits::{Currency, GetStorageVersion, OnRuntimeUpgrade, StorageVersion};
 use frame_support::WeakBoundedVec;
-use frame_support::{assert_err, assert_noop, assert_ok, StorageValue};
+use frame_support::{assert_err, assert_noop, assert_ok, StorageDoubleMap, StorageValue};
 use frame_system::RawOrigin;
+use sp_runtime::Percent;
 use staking_handler::StakingHandler;
 
 use crate::Balances;
@@ -1582,6 +1584,7 @@ fn test_funding_request_fails_insufficient_fundings() {
                 account: 0,
                 amount: 100,
             }],
+            None,
             Err(Error::InsufficientFundsForFundingRequest),
         );
     });
@@ -1597,6 +1600,7 @@ fn test_funding_request_fails_no_accounts() {
         Mocks::funding_request(
             origin,
             Vec::<common::FundingRequestParameters<u64, u64>>::new(),
+            None,
             Err(Error::EmptyFundingRequests),
         );
     });
@@ -1618,6 +1622,7 @@ fn test_funding_request_fails_repeated_account() {
                 };
                 2
             ],
+            None,
             Err(Error::RepeatedFundRequestAccount),
         );
     });
@@ -1636,6 +1641,7 @@ fn test_funding_request_fails_zero_balance() {
                 account: 0,
                 amount: 0,
             }],
+            None,
             Err(Error::ZeroBalanceFundRequest),
         );
     });
@@ -1660,6 +1666,7 @@ fn test_funding_request_fails_insufficient_fundings_in_multiple_accounts() {
                     amount: 51,
                 },
             ],
+            None,
             Err(Error::InsufficientFundsForFundingRequest),
         );
     });
@@ -1705,6 +1712,7 @@ fn test_funding_request_fails_permission() {
                 amount: 100,
                 account: 0,
             }],
+            None,
             Err(Error::BadOrigin),
         );
     });
@@ -1812,6 +1820,7 @@ fn test_funding_request_succeeds() {
                     account: 1,
                 },
             ],
+            None,
             Ok(()),
         );
     });
@@ -2177,3 +2186,873 @@ fn decrease_council_budget_ok_with_event_deposited() {
         ));
     });
 }
+
+#[test]
+fn recall_councilor_succeeded() {
+    let config = default_genesis_config();
+
+    build_test_externalities(config).execute_with(|| {
+        let params = Mocks::run_council_cycle_with_interrupt(
+            1,
+            &[],
+            0,
+            Some(CouncilCycleInterrupt::AfterElectionComplete),
+        );
+        let council_member = params.expected_final_council_members[0].clone();
+        let initial_balance = Balances::<Runtime>::free_balance(&council_member.staking_account_id);
+
+        Mocks::recall_councilor(
+            OriginType::Root,
+            council_member.membership_id,
+            Percent::from_percent(50),
+            Ok(()),
+        );
+
+        assert_eq!(
+            Balances::<Runtime>::free_balance(&council_member.staking_account_id),
+            initial_balance - council_member.stake / 2
+        );
+        assert_eq!(
+            Council::council_members().len() as u32,
+            <Runtime as Config>::CouncilSize::get() - 1
+        );
+
+        // special election filling the vacated seat is started
+        let now = frame_system::Pallet::<Runtime>::block_number();
+        let announcing_period_end_block =
+            now + <Runtime as Config>::AnnouncingPeriodDuration::get();
+        Mocks::check_announcing_period(
+            now,
+            CouncilStageAnnouncing {
+                candidates_count: 0,
+                ends_at: announcing_period_end_block,
+            },
+        );
+        assert_eq!(
+            Council::ongoing_special_election().map(|special_election| special_election.seats),
+            Some(1)
+        );
+        EventFixture::assert_last_crate_event(crate::RawEvent::SpecialElectionStarted(
+            1,
+            announcing_period_end_block,
+        ));
+    });
+}
+
+#[test]
+fn recall_councilor_fails_with_invalid_origin() {
+    let config = default_genesis_config();
+
+    build_test_externalities(config).execute_with(|| {
+        let params = Mocks::run_council_cycle_with_interrupt(
+            1,
+            &[],
+            0,
+            Some(CouncilCycleInterrupt::AfterElectionComplete),
+        );
+        let council_member = params.expected_final_council_members[0].clone();
+
+        Mocks::recall_councilor(
+            OriginType::Signed(council_member.membership_id),
+            council_member.membership_id,
+            Percent::from_percent(50),
+            Err(Error::<Runtime>::BadOrigin),
+        );
+    });
+}
+
+#[test]
+fn recall_councilor_fails_with_invalid_councilor() {
+    let config = default_genesis_config();
+
+    build_test_externalities(config).execute_with(|| {
+        let params = Mocks::run_council_cycle_with_interrupt(
+            1,
+            &[],
+            0,
+            Some(CouncilCycleInterrupt::AfterElectionComplete),
+        );
+        let not_elected_candidate = params.candidates_announcing[2].clone();
+
+        Mocks::recall_councilor(
+            OriginType::Root,
+            not_elected_candidate.membership_id,
+            Percent::from_percent(50),
+            Err(Error::<Runtime>::NotCouncilor),
+        );
+    });
+}
+
+#[test]
+fn recall_councilor_adds_seat_to_announcing_special_election() {
+    let config = default_genesis_config();
+
+    build_test_externalities(config).execute_with(|| {
+        let params = Mocks::run_council_cycle_with_interrupt(
+            1,
+            &[],
+            0,
+            Some(CouncilCycleInterrupt::AfterElectionComplete),
+        );
+
+        for council_member in params.expected_final_council_members.iter().take(2) {
+            Mocks::recall_councilor(
+                OriginType::Root,
+                council_member.membership_id,
+                Percent::from_percent(0),
+                Ok(()),
+            );
+        }
+
+        assert_eq!(Council::council_members().len(), 1);
+        assert_eq!(
+            Council::ongoing_special_election().map(|special_election| special_election.seats),
+            Some(2)
+        );
+    });
+}
+
+#[test]
+fn recall_councilor_leaves_seat_vacant_during_regular_election() {
+    let config = default_genesis_config();
+
+    build_test_externalities(config).execute_with(|| {
+        let council_settings = CouncilSettings::<Runtime>::extract_settings();
+        let params = Mocks::run_full_council_cycle(1, &[], 0);
+        let council_member = params.expected_final_council_members[0].clone();
+
+        // start next election round
+        Mocks::run_council_cycle_with_interrupt(
+            params.cycle_start_block_number + council_settings.cycle_duration,
+            &params.expected_final_council_members,
+            100,
+            Some(CouncilCycleInterrupt::BeforeCandidatesAnnounce),
+        );
+
+        Mocks::recall_councilor(
+            OriginType::Root,
+            council_member.membership_id,
+            Percent::from_percent(50),
+            Ok(()),
+        );
+
+        assert_eq!(Council::ongoing_special_election(), None);
+        EventFixture::assert_last_crate_event(crate::RawEvent::CouncilorRecalled(
+            council_member.membership_id,
+            council_member.stake / 2,
+        ));
+    });
+}
+
+#[test]
+fn special_election_fills_vacated_seat() {
+    let config = default_genesis_config();
+
+    build_test_externalities(config).execute_with(|| {
+        let council_settings = CouncilSettings::<Runtime>::extract_settings();
+        let params = Mocks::run_council_cycle_with_interrupt(
+            1,
+            &[],
+            0,
+            Some(CouncilCycleInterrupt::AfterElectionComplete),
+        );
+        let council_member = params.expected_final_council_members[0].clone();
+        let idle_ends_at = match Council::stage().stage {
+            CouncilStage::Idle(stage_data) => stage_data.ends_at,
+            _ => panic!("Council is not idle"),
+        };
+
+        Mocks::recall_councilor(
+            OriginType::Root,
+            council_member.membership_id,
+            Percent::from_percent(50),
+            Ok(()),
+        );
+
+        // announce candidacies for the vacated seat
+        let candidates: Vec<CandidateInfo<Runtime>> = (10..12)
+            .map(|i| MockUtils::generate_candidate(i, council_settings.min_candidate_stake))
+            .collect();
+        candidates.iter().for_each(|candidate| {
+            Mocks::announce_candidacy(
+                candidate.origin.clone(),
+                candidate.account_id,
+                council_settings.min_candidate_stake,
+                Ok(()),
+            );
+        });
+        MockUtils::increase_block_number(council_settings.announcing_stage_duration);
+
+        // vote for the first candidate
+        let voter = MockUtils::generate_voter(
+            20,
+            <Runtime as referendum::Config<ReferendumInstance>>::MinimumStake::get(),
+            candidates[0].membership_id,
+            AnnouncementPeriodNr::get(),
+        );
+        Mocks::vote_for_candidate(voter.origin.clone(), voter.commitment, voter.stake, Ok(()));
+        MockUtils::increase_block_number(council_settings.voting_stage_duration);
+        Mocks::reveal_vote(voter.origin.clone(), voter.salt, voter.vote_for, Ok(()));
+        MockUtils::increase_block_number(council_settings.reveal_stage_duration);
+
+        // the council returns to the interrupted idle period with the seat filled
+        let now = frame_system::Pallet::<Runtime>::block_number();
+        let expected_idle_ends_at = idle_ends_at.max(now + 1);
+        assert_eq!(
+            Council::stage().stage,
+            CouncilStage::Idle(CouncilStageIdle {
+                ends_at: expected_idle_ends_at
+            })
+        );
+        assert_eq!(Council::ongoing_special_election(), None);
+        assert_eq!(
+            Council::council_members()
+                .iter()
+                .map(|council_member| council_member.membership_id)
+                .collect::<Vec<_>>(),
+            vec![
+                params.expected_final_council_members[1].membership_id,
+                params.expected_final_council_members[2].membership_id,
+                candidates[0].membership_id,
+            ]
+        );
+        assert_eq!(
+            CouncilorLock::current_stake(&candidates[0].candidate.staking_account_id),
+            council_settings.min_candidate_stake
+        );
+        EventFixture::assert_last_crate_event(crate::RawEvent::CouncilSeatsFilled(
+            vec![candidates[0].membership_id],
+            expected_idle_ends_at,
+        ));
+    });
+}
+
+#[test]
+fn special_election_leaves_seat_vacant_without_enough_candidates() {
+    let config = default_genesis_config();
+
+    build_test_externalities(config).execute_with(|| {
+        let council_settings = CouncilSettings::<Runtime>::extract_settings();
+        let params = Mocks::run_council_cycle_with_interrupt(
+            1,
+            &[],
+            0,
+            Some(CouncilCycleInterrupt::AfterElectionComplete),
+        );
+        let council_member = params.expected_final_council_members[0].clone();
+
+        Mocks::recall_councilor(
+            OriginType::Root,
+            council_member.membership_id,
+            Percent::from_percent(50),
+            Ok(()),
+        );
+
+        MockUtils::increase_block_number(council_settings.announcing_stage_duration);
+
+        let idle_ends_at = match Council::stage().stage {
+            CouncilStage::Idle(stage_data) => stage_data.ends_at,
+            _ => panic!("Council is not idle"),
+        };
+        assert_eq!(Council::ongoing_special_election(), None);
+        assert_eq!(
+            Council::council_members().len() as u32,
+            council_settings.council_size - 1
+        );
+        EventFixture::assert_last_crate_event(crate::RawEvent::CouncilSeatsNotFilled(idle_ends_at));
+    });
+}
+
+#[test]
+fn councilor_cant_candidate_in_special_election() {
+    let config = default_genesis_config();
+
+    build_test_externalities(config).execute_with(|| {
+        let council_settings = CouncilSettings::<Runtime>::extract_settings();
+        let params = Mocks::run_council_cycle_with_interrupt(
+            1,
+            &[],
+            0,
+            Some(CouncilCycleInterrupt::AfterElectionComplete),
+        );
+        let recalled_member = params.expected_final_council_members[0].clone();
+        let sitting_member = params.expected_final_council_members[1].clone();
+
+        Mocks::recall_councilor(
+            OriginType::Root,
+            recalled_member.membership_id,
+            Percent::from_percent(50),
+            Ok(()),
+        );
+
+        Mocks::announce_candidacy(
+            OriginType::Signed(sitting_member.membership_id),
+            sitting_member.membership_id,
+            council_settings.min_candidate_stake,
+            Err(Error::<Runtime>::CouncilorCantCandidateNow.into()),
+        );
+    });
+}
+
+#[test]
+fn vote_stake_released_after_councilor_recalled() {
+    let config = default_genesis_config();
+
+    build_test_externalities(config).execute_with(|| {
+        let params = Mocks::run_council_cycle_with_interrupt(
+            1,
+            &[],
+            0,
+            Some(CouncilCycleInterrupt::AfterElectionComplete),
+        );
+
+        // voters 0..4 voted for the first councilor, voters 4..7 for the second one
+        let voter_for_recalled = params.voters[0].clone();
+        let voter_for_sitting = params.voters[4].clone();
+
+        Mocks::release_vote_stake(voter_for_recalled.origin.clone(), Err(()));
+
+        Mocks::recall_councilor(
+            OriginType::Root,
+            params.expected_final_council_members[0].membership_id,
+            Percent::from_percent(50),
+            Ok(()),
+        );
+
+        // vote stake for the sitting councilor stays locked during the special election
+        Mocks::release_vote_stake(voter_for_recalled.origin, Ok(()));
+        Mocks::release_vote_stake(voter_for_sitting.origin, Err(()));
+    });
+}
+
+// Test that councilor reward is scaled by the participation in the concluded proposal votings.
+#[test]
+fn councilor_reward_scaled_by_proposal_voting_participation() {
+    let config = default_genesis_config();
+
+    build_test_externalities(config).execute_with(|| {
+        let origin = OriginType::Root;
+        let initial_budget = 10000000;
+        let councilor_reward = 10;
+
+        Mocks::set_budget(origin.clone(), initial_budget, Ok(()));
+        Mocks::set_councilor_reward(origin, councilor_reward, Ok(()));
+
+        Mocks::run_council_cycle_with_interrupt(
+            1,
+            &[],
+            0,
+            Some(CouncilCycleInterrupt::AfterElectionComplete),
+        );
+        let council_members = Council::council_members();
+        let full_voter = council_members[0].membership_id;
+        let partial_voter = council_members[1].membership_id;
+        let voting_started_at = frame_system::Pallet::<Runtime>::block_number();
+
+        Mocks::conclude_proposal_voting(&[full_voter, partial_voter], voting_started_at, true);
+        Mocks::conclude_proposal_voting(&[full_voter], voting_started_at, true);
+
+        let balances_before: Vec<u64> = council_members
+            .iter()
+            .map(|council_member| {
+                balances::Pallet::<Runtime>::free_balance(council_member.reward_account_id)
+            })
+            .collect();
+        let budget_before = Budget::<Runtime>::get();
+
+        // forward to block after the reward payment
+        MockUtils::increase_block_number(<Runtime as Config>::ElectedMemberRewardPeriod::get());
+
+        let paid_blocks = Council::council_members()[0].last_payment_block
+            - council_members[0].last_payment_block;
+        let full_reward = paid_blocks * councilor_reward;
+        let expected_rewards = [full_reward, full_reward / 2, 0];
+
+        for ((council_member, balance_before), expected_reward) in council_members
+            .iter()
+            .zip(balances_before)
+            .zip(expected_rewards)
+        {
+            assert_eq!(
+                balances::Pallet::<Runtime>::free_balance(council_member.reward_account_id),
+                balance_before + expected_reward
+            );
+        }
+
+        // withheld rewards stay in the budget
+        assert_eq!(
+            Budget::<Runtime>::get(),
+            budget_before - full_reward * 3 / 2
+        );
+        assert!(frame_system::Pallet::<Runtime>::events()
+            .iter()
+            .any(|ev| ev.event
+                == RuntimeEvent::Council(crate::RawEvent::CouncilorRewardWithheld(
+                    council_members[2].membership_id,
+                    full_reward,
+                ))));
+    });
+}
+
+// Test that councilor reward isn't reduced when no proposal voting was concluded.
+#[test]
+fn councilor_reward_not_scaled_without_proposal_votings() {
+    let config = default_genesis_config();
+
+    build_test_externalities(config).execute_with(|| {
+        let origin = OriginType::Root;
+        let councilor_reward = 10;
+
+        Mocks::set_budget(origin.clone(), 10000000, Ok(()));
+        Mocks::set_councilor_reward(origin, councilor_reward, Ok(()));
+
+        Mocks::run_council_cycle_with_interrupt(
+            1,
+            &[],
+            0,
+            Some(CouncilCycleInterrupt::AfterElectionComplete),
+        );
+        let council_members = Council::council_members();
+        let budget_before = Budget::<Runtime>::get();
+
+        // forward to block after the reward payment
+        MockUtils::increase_block_number(<Runtime as Config>::ElectedMemberRewardPeriod::get());
+
+        let paid_blocks = Council::council_members()[0].last_payment_block
+            - council_members[0].last_payment_block;
+
+        assert_eq!(
+            Budget::<Runtime>::get(),
+            budget_before - paid_blocks * councilor_reward * council_members.len() as u64
+        );
+    });
+}
+
+// Test that participation period is reset after the reward payment and participation is cleared
+// for the recalled councilor.
+#[test]
+fn councilor_participation_reset_after_reward_payment_and_cleared_on_recall() {
+    let config = default_genesis_config();
+
+    build_test_externalities(config).execute_with(|| {
+        let origin = OriginType::Root;
+
+        Mocks::set_budget(origin.clone(), 10000000, Ok(()));
+        Mocks::set_councilor_reward(origin, 1, Ok(()));
+
+        Mocks::run_council_cycle_with_interrupt(
+            1,
+            &[],
+            0,
+            Some(CouncilCycleInterrupt::AfterElectionComplete),
+        );
+        let council_member = Council::council_members()[0].membership_id;
+        let voting_started_at = frame_system::Pallet::<Runtime>::block_number();
+
+        Mocks::conclude_proposal_voting(&[council_member], voting_started_at, true);
+
+        // forward to block after the reward payment
+        MockUtils::increase_block_number(<Runtime as Config>::ElectedMemberRewardPeriod::get());
+
+        let participation = Council::councilor_participation(council_member);
+        assert_eq!(participation.votings_count, 0);
+        assert_eq!(participation.votes_count, 0);
+        assert_eq!(participation.total_votings_count, 1);
+        assert_eq!(participation.total_votes_count, 1);
+
+        Mocks::recall_councilor(
+            OriginType::Root,
+            council_member,
+            Percent::from_percent(0),
+            Ok(()),
+        );
+
+        assert!(!CouncilorParticipation::<Runtime>::contains_key(
+            council_member
+        ));
+    });
+}
+
+// Test that a proposal voting is missed only when its voting period expired and the councilor was
+// seated before the voting started.
+#[test]
+fn councilor_misses_only_expired_votings_started_after_seating() {
+    let config = default_genesis_config();
+
+    build_test_externalities(config).execute_with(|| {
+        Mocks::run_council_cycle_with_interrupt(
+            1,
+            &[],
+            0,
+            Some(CouncilCycleInterrupt::AfterElectionComplete),
+        );
+        let council_members = Council::council_members();
+        let voter = council_members[0].membership_id;
+        let non_voter = council_members[1].membership_id;
+        let seated_at = Council::councilor_seated_at(non_voter);
+        assert_eq!(seated_at, frame_system::Pallet::<Runtime>::block_number());
+
+        // voting decided before the voting period expired
+        Mocks::conclude_proposal_voting(&[voter], seated_at, false);
+        assert_eq!(Council::councilor_participation(non_voter).votings_count, 0);
+
+        // voting started before the councilor was seated
+        Mocks::conclude_proposal_voting(&[voter], seated_at - 1, true);
+        assert_eq!(Council::councilor_participation(non_voter).votings_count, 0);
+
+        // voting expired without the councilor vote
+        Mocks::conclude_proposal_voting(&[voter], seated_at, true);
+        assert_eq!(Council::councilor_participation(non_voter).votings_count, 1);
+        assert_eq!(Council::councilor_participation(non_voter).votes_count, 0);
+
+        // votes are counted in every concluded voting
+        assert_eq!(Council::councilor_participation(voter).votings_count, 3);
+        assert_eq!(Council::councilor_participation(voter).votes_count, 3);
+    });
+}
+
+#[test]
+fn create_budget_allocation_succeeds() {
+    let config = default_genesis_config();
+
+    build_test_externalities(config).execute_with(|| {
+        let expires_at = frame_system::Pallet::<Runtime>::block_number() + 100;
+
+        Mocks::set_budget(OriginType::Root, 1500, Ok(()));
+
+        Mocks::create_budget_allocation(
+            OriginType::Root,
+            b"storage infra Q3".to_vec(),
+            1000,
+            expires_at,
+            Ok(()),
+        );
+        Mocks::create_budget_allocation(
+            OriginType::Root,
+            b"bounties".to_vec(),
+            500,
+            expires_at,
+            Ok(()),
+        );
+    });
+}
+
+#[test]
+fn create_budget_allocation_fails_with_invalid_origin() {
+    let config = default_genesis_config();
+
+    build_test_externalities(config).execute_with(|| {
+        let expires_at = frame_system::Pallet::<Runtime>::block_number() + 100;
+
+        Mocks::create_budget_allocation(
+            OriginType::Signed(0),
+            Vec::new(),
+            1000,
+            expires_at,
+            Err(Error::BadOrigin),
+        );
+    });
+}
+
+#[test]
+fn create_budget_allocation_fails_with_zero_cap() {
+    let config = default_genesis_config();
+
+    build_test_externalities(config).execute_with(|| {
+        let expires_at = frame_system::Pallet::<Runtime>::block_number() + 100;
+
+        Mocks::create_budget_allocation(
+            OriginType::Root,
+            Vec::new(),
+            0,
+            expires_at,
+            Err(Error::ZeroBudgetAllocationCap),
+        );
+    });
+}
+
+#[test]
+fn create_budget_allocation_fails_with_expiry_in_past() {
+    let config = default_genesis_config();
+
+    build_test_externalities(config).execute_with(|| {
+        let now = frame_system::Pallet::<Runtime>::block_number();
+
+        Mocks::create_budget_allocation(
+            OriginType::Root,
+            Vec::new(),
+            1000,
+            now,
+            Err(Error::BudgetAllocationExpiryInPast),
+        );
+    });
+}
+
+#[test]
+fn funding_request_draws_from_budget_allocation() {
+    let config = default_genesis_config();
+
+    build_test_externalities(config).execute_with(|| {
+        let origin = OriginType::Root;
+        let expires_at = frame_system::Pallet::<Runtime>::block_number() + 100;
+
+        Mocks::set_budget(origin.clone(), 1000, Ok(()));
+        Mocks::create_budget_allocation(origin.clone(), Vec::new(), 100, expires_at, Ok(()));
+
+        Mocks::funding_request(
+            origin.clone(),
+            vec![common::FundingRequestParameters {
+                account: 0,
+                amount: 40,
+            }],
+            Some(0),
+            Ok(()),
+        );
+
+        MockUtils::increase_block_number(1);
+
+        Mocks::funding_request(
+            origin,
+            vec![common::FundingRequestParameters {
+                account: 1,
+                amount: 60,
+            }],
+            Some(0),
+            Ok(()),
+        );
+
+        let allocation = Council::budget_allocations(0).unwrap();
+        assert_eq!(allocation.spendings_count, 2);
+        assert_eq!(allocation.remaining(), 0);
+    });
+}
+
+#[test]
+fn funding_request_fails_with_exceeded_budget_allocation_cap() {
+    let config = default_genesis_config();
+
+    build_test_externalities(config).execute_with(|| {
+        let origin = OriginType::Root;
+        let expires_at = frame_system::Pallet::<Runtime>::block_number() + 100;
+
+        Mocks::set_budget(origin.clone(), 1000, Ok(()));
+        Mocks::create_budget_allocation(origin, Vec::new(), 100, expires_at, Ok(()));
+
+        assert_noop!(
+            Council::funding_request(
+                RawOrigin::Root.into(),
+                vec![common::FundingRequestParameters {
+                    account: 0,
+                    amount: 101,
+                }],
+                Some(0),
+            ),
+            Error::<Runtime>::BudgetAllocationCapExceeded
+        );
+    });
+}
+
+#[test]
+fn funding_request_fails_with_expired_budget_allocation() {
+    let config = default_genesis_config();
+
+    build_test_externalities(config).execute_with(|| {
+        let origin = OriginType::Root;
+        let expires_at = frame_system::Pallet::<Runtime>::block_number() + 10;
+
+        Mocks::set_budget(origin.clone(), 1000, Ok(()));
+        Mocks::create_budget_allocation(origin, Vec::new(), 100, expires_at, Ok(()));
+
+        MockUtils::increase_block_number(10);
+
+        assert_noop!(
+            Council::funding_request(
+                RawOrigin::Root.into(),
+                vec![common::FundingRequestParameters {
+                    account: 0,
+                    amount: 10,
+                }],
+                Some(0),
+            ),
+            Error::<Runtime>::BudgetAllocationExpired
+        );
+    });
+}
+
+#[test]
+fn funding_request_fails_with_non_existing_budget_allocation() {
+    let config = default_genesis_config();
+
+    build_test_externalities(config).execute_with(|| {
+        Mocks::set_budget(OriginType::Root, 1000, Ok(()));
+
+        assert_noop!(
+            Council::funding_request(
+                RawOrigin::Root.into(),
+                vec![common::FundingRequestParameters {
+                    account: 0,
+                    amount: 10,
+                }],
+                Some(0),
+            ),
+            Error::<Runtime>::BudgetAllocationDoesNotExist
+        );
+    });
+}
+
+#[test]
+fn create_budget_allocation_fails_with_insufficient_unearmarked_budget() {
+    let config = default_genesis_config();
+
+    build_test_externalities(config).execute_with(|| {
+        let origin = OriginType::Root;
+        let expires_at = frame_system::Pallet::<Runtime>::block_number() + 100;
+
+        Mocks::set_budget(origin.clone(), 1000, Ok(()));
+        Mocks::create_budget_allocation(origin.clone(), Vec::new(), 600, expires_at, Ok(()));
+
+        Mocks::create_budget_allocation(
+            origin,
+            Vec::new(),
+            401,
+            expires_at,
+            Err(Error::InsufficientBudgetForBudgetAllocation),
+        );
+    });
+}
+
+#[test]
+fn create_budget_allocation_fails_with_too_many_allocations_expiring() {
+    let config = default_genesis_config();
+
+    build_test_externalities(config).execute_with(|| {
+        let origin = OriginType::Root;
+        let expires_at = frame_system::Pallet::<Runtime>::block_number() + 100;
+
+        Mocks::set_budget(origin.clone(), 1000, Ok(()));
+
+        for _ in 0..crate::MAX_BUDGET_ALLOCATIONS_EXPIRING_PER_BLOCK {
+            Mocks::create_budget_allocation(origin.clone(), Vec::new(), 1, expires_at, Ok(()));
+        }
+
+        Mocks::create_budget_allocation(
+            origin,
+            Vec::new(),
+            1,
+            expires_at,
+            Err(Error::TooManyBudgetAllocationsExpiring),
+        );
+    });
+}
+
+#[test]
+fn funding_request_without_allocation_cant_spend_earmarked_budget() {
+    let config = default_genesis_config();
+
+    build_test_externalities(config).execute_with(|| {
+        let origin = OriginType::Root;
+        let expires_at = frame_system::Pallet::<Runtime>::block_number() + 100;
+
+        Mocks::set_budget(origin.clone(), 1000, Ok(()));
+        Mocks::create_budget_allocation(origin.clone(), Vec::new(), 600, expires_at, Ok(()));
+
+        assert_eq!(Council::earmarked_budget(), 600);
+        assert_eq!(Council::unearmarked_budget(), 400);
+
+        Mocks::funding_request(
+            origin.clone(),
+            vec![common::FundingRequestParameters {
+                account: 0,
+                amount: 401,
+            }],
+            None,
+            Err(Error::InsufficientFundsForFundingRequest),
+        );
+
+        Mocks::funding_request(
+            origin.clone(),
+            vec![common::FundingRequestParameters {
+                account: 0,
+                amount: 400,
+            }],
+            None,
+            Ok(()),
+        );
+
+        // The allocation still can be drawn from.
+        Mocks::funding_request(
+            origin,
+            vec![common::FundingRequestParameters {
+                account: 1,
+                amount: 250,
+            }],
+            Some(0),
+            Ok(()),
+        );
+
+        assert_eq!(Council::earmarked_budget(), 350);
+        assert_eq!(Council::unearmarked_budget(), 0);
+    });
+}
+
+#[test]
+fn expired_budget_allocation_releases_earmarked_budget() {
+    let config = default_genesis_config();
+
+    build_test_externalities(config).execute_with(|| {
+        let origin = OriginType::Root;
+        let expires_at = frame_system::Pallet::<Runtime>::block_number() + 10;
+
+        Mocks::set_budget(origin.clone(), 1000, Ok(()));
+        Mocks::create_budget_allocation(origin.clone(), Vec::new(), 600, expires_at, Ok(()));
+
+        Mocks::funding_request(
+            origin,
+            vec![common::FundingRequestParameters {
+                account: 0,
+                amount: 100,
+            }],
+            Some(0),
+            Ok(()),
+        );
+
+        assert_eq!(Council::earmarked_budget(), 500);
+
+        MockUtils::increase_block_number(10);
+
+        assert_eq!(Council::earmarked_budget(), 0);
+        assert_eq!(Council::unearmarked_budget(), Council::budget());
+        assert!(!crate::BudgetAllocationExpirations::<Runtime>::contains_key(expires_at, 0));
+        assert!(frame_system::Pallet::<Runtime>::events()
+            .iter()
+            .any(|record| {
+                record.event
+                    == RuntimeEvent::Council(crate::RawEvent::BudgetAllocationExpired(0, 500))
+            }));
+    });
+}
+
+#[test]
+fn council_election_cycle_migration_seeds_current_council_cycle() {
+    let config = default_genesis_config();
+
+    build_test_externalities(config).execute_with(|| {
+        Mocks::run_council_cycle_with_interrupt(
+            1,
+            &[],
+            0,
+            Some(CouncilCycleInterrupt::AfterElectionComplete),
+        );
+        let council_election_cycle = Council::council_election_cycle();
+        assert!(council_election_cycle > 0);
+
+        // Simulate the council elected before the upgrade.
+        crate::CouncilElectionCycle::kill();
+        StorageVersion::new(0).put::<Council>();
+
+        MigrateToV1::<Runtime>::on_runtime_upgrade();
+
+        assert_eq!(Council::council_election_cycle(), council_election_cycle);
+        assert_eq!(Council::on_chain_storage_version(), StorageVersion::new(1));
+    });
+}
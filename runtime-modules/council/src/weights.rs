@@ -20,6 +20,9 @@
 //! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 4.0.0-dev
 //! DATE: 2024-04-08, STEPS: `50`, REPEAT: 20, LOW RANGE: `[]`, HIGH RANGE: `[]`
 //! EXECUTION: Some(Wasm), WASM-EXECUTION: Compiled, CHAIN: Some("prod-test"), DB CACHE: 1024
+//!
+//! NOTE: the weight functions marked as PLACEHOLDER are hand-written estimates, not the
+//! benchmark output. They must be regenerated with the benchmark CLI before merging.
 
 // Executed Command:
 // ./scripts/../target/release/joystream-node
@@ -63,6 +66,8 @@ pub trait WeightInfo {
 	fn set_era_payout_damping_factor() -> Weight;
 	fn candidate_remark() -> Weight;
 	fn councilor_remark() -> Weight;
+	fn recall_councilor() -> Weight;
+	fn create_budget_allocation(_i: u32, ) -> Weight;
 }
 
 /// Weights for council using the Substrate node and recommended hardware.
@@ -90,23 +95,26 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(Weight::from_parts(0, 0))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
+	// PLACEHOLDER: hand-written estimate, not the benchmark output.
+	// Must be regenerated with the benchmark CLI before merging.
 	// Storage: Council Budget (r:1 w:1)
 	// Proof: Council Budget (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	// Storage: Council BudgetAllocations (r:1 w:1)
+	// Proof: Council BudgetAllocations (max_values: None, max_size: Some(100), added: 2575, mode: MaxEncodedLen)
 	// Storage: System Account (r:100 w:100)
 	// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	// Storage: Council EarmarkedBudget (r:1 w:1)
+	// Proof: Council EarmarkedBudget (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	// Storage: Council BudgetAllocationSpendings (r:0 w:1)
+	// Proof: Council BudgetAllocationSpendings (max_values: None, max_size: Some(64), added: 2539, mode: MaxEncodedLen)
 	/// The range of component `i` is `[1, 100]`.
 	fn funding_request(i: u32, ) -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `734 + i * (2 ±0)`
-		//  Estimated: `2491 + i * (2603 ±0)`
-		// Minimum execution time: 44_015 nanoseconds.
-		Weight::from_parts(12_317_434, 0u64)
-			.saturating_add(Weight::from_parts(0, 2491))
-			// Standard Error: 25_581
+		Weight::from_parts(19_862_407, 0u64)
+			.saturating_add(Weight::from_parts(0, 6072))
 			.saturating_add(Weight::from_parts(26_730_094, 0u64).saturating_mul(i.into()))
-			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
 			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(i.into())))
-			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
 			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(i.into())))
 			.saturating_add(Weight::from_parts(0, 2603).saturating_mul(i.into()))
 	}
@@ -126,25 +134,25 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(2_u64))
 			.saturating_add(T::DbWeight::get().writes(2_u64))
 	}
+	// PLACEHOLDER: hand-written estimate, not the benchmark output.
+	// Must be regenerated with the benchmark CLI before merging.
 	// Storage: Council CouncilorReward (r:1 w:0)
 	// Proof: Council CouncilorReward (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
 	// Storage: Council Budget (r:1 w:1)
 	// Proof: Council Budget (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
 	// Storage: Council CouncilMembers (r:1 w:1)
 	// Proof: Council CouncilMembers (max_values: Some(1), max_size: Some(325), added: 820, mode: MaxEncodedLen)
+	// Storage: Council CouncilorParticipation (r:3 w:3)
+	// Proof: Council CouncilorParticipation (max_values: None, max_size: Some(40), added: 2515, mode: MaxEncodedLen)
 	// Storage: System Account (r:3 w:3)
 	// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
 	// Storage: Council NextRewardPayments (r:0 w:1)
 	// Proof: Council NextRewardPayments (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
 	fn try_process_budget_payout_council_members_only() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `1061`
-		//  Estimated: `13611`
-		// Minimum execution time: 63_996 nanoseconds.
 		Weight::from_parts(65_986_000, 0u64)
-			.saturating_add(Weight::from_parts(0, 13611))
-			.saturating_add(T::DbWeight::get().reads(6_u64))
-			.saturating_add(T::DbWeight::get().writes(6_u64))
+			.saturating_add(Weight::from_parts(0, 21156))
+			.saturating_add(T::DbWeight::get().reads(9_u64))
+			.saturating_add(T::DbWeight::get().writes(9_u64))
 	}
 	// Storage: Council Stage (r:1 w:1)
 	// Proof: Council Stage (max_values: Some(1), max_size: Some(13), added: 508, mode: MaxEncodedLen)
@@ -369,6 +377,50 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(Weight::from_parts(0, 5400))
 			.saturating_add(T::DbWeight::get().reads(2_u64))
 	}
+	// PLACEHOLDER: hand-written estimate, not the benchmark output.
+	// Must be regenerated with the benchmark CLI before merging.
+	// Storage: Council CouncilMembers (r:1 w:1)
+	// Proof: Council CouncilMembers (max_values: Some(1), max_size: Some(325), added: 820, mode: MaxEncodedLen)
+	// Storage: Balances Locks (r:1 w:1)
+	// Proof: Balances Locks (max_values: None, max_size: Some(1299), added: 3774, mode: MaxEncodedLen)
+	// Storage: System Account (r:1 w:1)
+	// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	// Storage: Council Stage (r:1 w:1)
+	// Proof: Council Stage (max_values: Some(1), max_size: Some(13), added: 508, mode: MaxEncodedLen)
+	// Storage: Council OngoingSpecialElection (r:1 w:1)
+	// Proof: Council OngoingSpecialElection (max_values: Some(1), max_size: Some(9), added: 504, mode: MaxEncodedLen)
+	// Storage: Council AnnouncementPeriodNr (r:1 w:1)
+	// Proof: Council AnnouncementPeriodNr (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	// Storage: Council CouncilorParticipation (r:0 w:1)
+	// Proof: Council CouncilorParticipation (max_values: None, max_size: Some(40), added: 2515, mode: MaxEncodedLen)
+	// Storage: Council CouncilorSeatedAt (r:0 w:1)
+	// Proof: Council CouncilorSeatedAt (max_values: None, max_size: Some(28), added: 2503, mode: MaxEncodedLen)
+	fn recall_councilor() -> Weight {
+		Weight::from_parts(60_114_000, 0u64)
+			.saturating_add(Weight::from_parts(0, 8712))
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(8_u64))
+	}
+	// PLACEHOLDER: hand-written estimate, not the benchmark output.
+	// Must be regenerated with the benchmark CLI before merging.
+	// Storage: Council Budget (r:1 w:0)
+	// Proof: Council Budget (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	// Storage: Council EarmarkedBudget (r:1 w:1)
+	// Proof: Council EarmarkedBudget (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	// Storage: Council BudgetAllocationExpirations (r:10 w:1)
+	// Proof: Council BudgetAllocationExpirations (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
+	// Storage: Council NextBudgetAllocationId (r:1 w:1)
+	// Proof: Council NextBudgetAllocationId (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	// Storage: Council BudgetAllocations (r:0 w:1)
+	// Proof: Council BudgetAllocations (max_values: None, max_size: Some(100), added: 2575, mode: MaxEncodedLen)
+	/// The range of component `i` is `[0, 100]`.
+	fn create_budget_allocation(i: u32, ) -> Weight {
+		Weight::from_parts(42_118_530, 0u64)
+			.saturating_add(Weight::from_parts(0, 28724))
+			.saturating_add(Weight::from_parts(1_986_430, 0u64).saturating_mul(i.into()))
+			.saturating_add(T::DbWeight::get().reads(13_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
 }
 
 // Default implementation for tests
@@ -430,4 +482,10 @@ impl WeightInfo for () {
 	fn councilor_remark() -> Weight {
 		Weight::from_parts(0, 0)
 	}
+	fn recall_councilor() -> Weight {
+		Weight::from_parts(0, 0)
+	}
+	fn create_budget_allocation(i: u32, ) -> Weight {
+		Weight::from_parts(0, 0)
+	}
 }
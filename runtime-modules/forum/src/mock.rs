@@ -30,6 +30,10 @@ parameter_types! {
     pub const DefaultMembershipPrice: u64 = 100;
     pub const DefaultInitialInvitationBalance: u64 = 100;
     pub const DefaultMemberInvitesCount: u32 = 2;
+    pub const HandleReclaimCooldown: u64 = 1000;
+    pub const MaxRecoveryGuardians: u32 = 5;
+    pub const RecoveryDelay: u64 = 100;
+    pub const RecoveryCooldown: u64 = 50;
 }
 
 type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Runtime>;
@@ -151,8 +155,10 @@ impl working_group::Config<ForumWorkingGroupInstance> for Runtime {
     type MemberOriginValidator = ();
     type MinUnstakingPeriodLimit = ();
     type RewardPeriod = ();
+    type MaxRewardPayoutsPerBlock = ();
     type WeightInfo = ();
     type MinimumApplicationStake = MinimumApplicationStake;
+    type MaxApplicationEndorsements = ConstU32<10>;
     type LeaderOpeningStake = LeaderOpeningStake;
     type VestingBalanceToBalance = VestingBalanceToBalance;
 }
@@ -201,6 +207,10 @@ impl membership::Config for Runtime {
         staking_handler::StakingManager<Self, BoundStakingAccountLockId>;
     type CandidateStake = CandidateStake;
     type DefaultMemberInvitesCount = DefaultMemberInvitesCount;
+    type HandleReclaimCooldown = HandleReclaimCooldown;
+    type MaxRecoveryGuardians = MaxRecoveryGuardians;
+    type RecoveryDelay = RecoveryDelay;
+    type RecoveryCooldown = RecoveryCooldown;
 }
 
 parameter_types! {
@@ -1,19 +1,23 @@
 #![cfg(feature = "runtime-benchmarks")]
 use super::*;
 use crate::{
-    BuyMembershipParameters, Config, InviteMembershipParameters, MemberIdByHandleHash, Membership,
-    MembershipById, MembershipObject, StakingAccountIdMemberStatus, StakingAccountMemberBinding,
+    Attestation, BuyMembershipParameters, Config, HandleOffer, InviteMembershipParameters,
+    MemberAttestations, MemberIdByHandleHash, Membership, MembershipById, MembershipObject,
+    ProtectedHandleHashes, RecoveryCancelledAt, RecoveryConfig, StakingAccountIdMemberStatus,
+    StakingAccountMemberBinding,
 };
 use balances::Pallet as Balances;
+use common::membership::AttestationKind;
 use core::convert::TryInto;
 use frame_benchmarking::v1::{account, benchmarks};
-use frame_support::storage::StorageMap;
+use frame_support::storage::{StorageDoubleMap, StorageMap};
 use frame_support::traits::Currency;
 use frame_system::Pallet as System;
 use frame_system::{EventRecord, RawOrigin};
 use sp_arithmetic::traits::One;
 use sp_arithmetic::Perbill;
 use sp_runtime::traits::{Bounded, SaturatedConversion, Saturating};
+use sp_std::collections::btree_set::BTreeSet;
 use sp_std::prelude::*;
 use sp_std::vec;
 
@@ -67,6 +71,46 @@ fn member_funded_account<T: Config + balances::Config>(
     (account_id, member_id)
 }
 
+// Set the recovery guardians of the member with the threshold equal to the number of guardians.
+fn set_recovery_guardians<T: Config + balances::Config>(
+    account_id: &T::AccountId,
+    member_id: T::MemberId,
+    guardians_count: u32,
+) -> Vec<(T::AccountId, T::MemberId)> {
+    let guardians = (1..=guardians_count)
+        .map(|id| member_funded_account::<T>("guardian", id))
+        .collect::<Vec<_>>();
+
+    Module::<T>::set_recovery_config(
+        RawOrigin::Signed(account_id.clone()).into(),
+        member_id,
+        guardians
+            .iter()
+            .map(|(_, guardian_id)| *guardian_id)
+            .collect(),
+        guardians_count,
+    )
+    .unwrap();
+
+    guardians
+}
+
+fn start_recovery<T: Config + balances::Config>(
+    account_id: &T::AccountId,
+    member_id: T::MemberId,
+) {
+    let guardians = set_recovery_guardians::<T>(account_id, member_id, 1);
+    let (guardian_account_id, guardian_id) = guardians[0].clone();
+
+    Module::<T>::initiate_recovery(
+        RawOrigin::Signed(guardian_account_id).into(),
+        guardian_id,
+        member_id,
+        account::<T::AccountId>("new_root", 0, SEED),
+    )
+    .unwrap();
+}
+
 // Method to generate a distintic valid handle
 // for a membership. For each index.
 fn handle_from_id<T: Config>(id: u32) -> Vec<u8> {
@@ -291,6 +335,9 @@ benchmarks! {
 
         let (account_id, member_id) = member_funded_account::<T>("member", member_id);
 
+        // Worst case: the new root account cancels the recovery in progress.
+        start_recovery::<T>(&account_id, member_id);
+
     }: update_accounts(RawOrigin::Signed(account_id.clone()), member_id, Some(new_root_account_id.clone()), None)
 
     verify {
@@ -365,6 +412,9 @@ benchmarks! {
 
         let (account_id, member_id) = member_funded_account::<T>("member", member_id);
 
+        // Worst case: the new root account cancels the recovery in progress.
+        start_recovery::<T>(&account_id, member_id);
+
     }: update_accounts(RawOrigin::Signed(account_id.clone()), member_id, Some(new_root_account_id.clone()), Some(new_controller_account_id.clone()))
 
     verify {
@@ -642,6 +692,223 @@ benchmarks! {
         );
     }
 
+    attest_member {
+        let (account_id, member_id) = member_funded_account::<T>("member", 0);
+
+        // Set leader member id
+        let leader_id = T::insert_a_lead(0, &account_id, member_id);
+
+        let kind = AttestationKind::Identity;
+        let expires_at = Some(System::<T>::block_number().saturating_add(One::one()));
+
+        // Worst case: the lead replaces a valid attestation issued by another worker.
+        MemberAttestations::<T>::insert(member_id, kind, Attestation {
+            issuer: leader_id.saturating_add(One::one()),
+            issued_at: System::<T>::block_number(),
+            expires_at: None,
+        });
+
+    }: _(RawOrigin::Signed(account_id.clone()), leader_id, member_id, kind, expires_at)
+    verify {
+        assert_eq!(
+            Module::<T>::member_attestation(member_id, kind),
+            Some(Attestation {
+                issuer: leader_id,
+                issued_at: System::<T>::block_number(),
+                expires_at,
+            })
+        );
+
+        assert_last_event::<T>(
+            RawEvent::MemberAttested(member_id, kind, leader_id, expires_at).into()
+        );
+    }
+
+    revoke_member_attestation {
+        let (account_id, member_id) = member_funded_account::<T>("member", 0);
+
+        // Set leader member id
+        let leader_id = T::insert_a_lead(0, &account_id, member_id);
+
+        let kind = AttestationKind::Identity;
+
+        // Worst case: the lead revokes the attestation issued by another worker.
+        MemberAttestations::<T>::insert(member_id, kind, Attestation {
+            issuer: leader_id.saturating_add(One::one()),
+            issued_at: System::<T>::block_number(),
+            expires_at: None,
+        });
+
+    }: _(RawOrigin::Signed(account_id.clone()), leader_id, member_id, kind)
+    verify {
+        assert!(Module::<T>::member_attestation(member_id, kind).is_none());
+
+        assert_last_event::<T>(
+            RawEvent::MemberAttestationRevoked(member_id, kind, leader_id).into()
+        );
+    }
+
+    remove_expired_member_attestation {
+        let (account_id, member_id) = member_funded_account::<T>("member", 0);
+
+        let kind = AttestationKind::Identity;
+
+        MemberAttestations::<T>::insert(member_id, kind, Attestation {
+            issuer: Default::default(),
+            issued_at: System::<T>::block_number(),
+            expires_at: Some(System::<T>::block_number()),
+        });
+
+    }: _(RawOrigin::Signed(account_id.clone()), member_id, kind)
+    verify {
+        assert!(Module::<T>::member_attestation(member_id, kind).is_none());
+
+        assert_last_event::<T>(RawEvent::MemberAttestationRemoved(member_id, kind).into());
+    }
+
+    set_recovery_config {
+        let i in 1 .. T::MaxRecoveryGuardians::get();
+
+        let (account_id, member_id) = member_funded_account::<T>("member", 0);
+
+        let guardians = (1..=i)
+            .map(|id| member_funded_account::<T>("guardian", id).1)
+            .collect::<BTreeSet<_>>();
+
+        let threshold = i;
+
+    }: _(RawOrigin::Signed(account_id.clone()), member_id, guardians.clone(), threshold)
+    verify {
+        assert_eq!(
+            Module::<T>::recovery_config(member_id),
+            Some(RecoveryConfig {
+                guardians: guardians.clone().try_into().unwrap(),
+                threshold,
+            })
+        );
+
+        assert_last_event::<T>(RawEvent::RecoveryConfigSet(member_id, guardians, threshold).into());
+    }
+
+    remove_recovery_config {
+        let (account_id, member_id) = member_funded_account::<T>("member", 0);
+
+        set_recovery_guardians::<T>(&account_id, member_id, 1);
+
+    }: _(RawOrigin::Signed(account_id.clone()), member_id)
+    verify {
+        assert!(Module::<T>::recovery_config(member_id).is_none());
+
+        assert_last_event::<T>(RawEvent::RecoveryConfigRemoved(member_id).into());
+    }
+
+    initiate_recovery {
+        let (account_id, member_id) = member_funded_account::<T>("member", 0);
+
+        let guardians = set_recovery_guardians::<T>(&account_id, member_id, 1);
+        let (guardian_account_id, guardian_id) = guardians[0].clone();
+
+        let new_root_account = account::<T::AccountId>("new_root", 0, SEED);
+
+        // Worst case: the previous recovery was cancelled and the cooldown has passed.
+        RecoveryCancelledAt::<T>::insert(member_id, System::<T>::block_number());
+
+        System::<T>::set_block_number(
+            System::<T>::block_number().saturating_add(T::RecoveryCooldown::get())
+        );
+
+    }: _(RawOrigin::Signed(guardian_account_id), guardian_id, member_id, new_root_account)
+    verify {
+        let executable_at = System::<T>::block_number().saturating_add(T::RecoveryDelay::get());
+
+        assert_eq!(
+            Module::<T>::active_recovery(member_id).unwrap().executable_at,
+            Some(executable_at)
+        );
+
+        assert_last_event::<T>(RawEvent::RecoveryThresholdReached(member_id, executable_at).into());
+    }
+
+    approve_recovery {
+        let (account_id, member_id) = member_funded_account::<T>("member", 0);
+
+        let guardians = set_recovery_guardians::<T>(&account_id, member_id, 2);
+        let (initiator_account_id, initiator_id) = guardians[0].clone();
+        let (guardian_account_id, guardian_id) = guardians[1].clone();
+
+        Module::<T>::initiate_recovery(
+            RawOrigin::Signed(initiator_account_id).into(),
+            initiator_id,
+            member_id,
+            account::<T::AccountId>("new_root", 0, SEED),
+        )
+        .unwrap();
+
+    }: _(RawOrigin::Signed(guardian_account_id), guardian_id, member_id)
+    verify {
+        let executable_at = System::<T>::block_number().saturating_add(T::RecoveryDelay::get());
+
+        assert!(Module::<T>::active_recovery(member_id).unwrap().approvals.contains(&guardian_id));
+
+        assert_last_event::<T>(RawEvent::RecoveryThresholdReached(member_id, executable_at).into());
+    }
+
+    cancel_recovery {
+        let (account_id, member_id) = member_funded_account::<T>("member", 0);
+
+        let guardians = set_recovery_guardians::<T>(&account_id, member_id, 1);
+        let (guardian_account_id, guardian_id) = guardians[0].clone();
+
+        Module::<T>::initiate_recovery(
+            RawOrigin::Signed(guardian_account_id).into(),
+            guardian_id,
+            member_id,
+            account::<T::AccountId>("new_root", 0, SEED),
+        )
+        .unwrap();
+
+    }: _(RawOrigin::Signed(account_id.clone()), member_id)
+    verify {
+        assert!(Module::<T>::active_recovery(member_id).is_none());
+
+        assert_eq!(
+            Module::<T>::recovery_cancelled_at(member_id),
+            Some(System::<T>::block_number())
+        );
+
+        assert_last_event::<T>(RawEvent::RecoveryCancelled(member_id).into());
+    }
+
+    finalize_recovery {
+        let (account_id, member_id) = member_funded_account::<T>("member", 0);
+
+        let guardians = set_recovery_guardians::<T>(&account_id, member_id, 1);
+        let (guardian_account_id, guardian_id) = guardians[0].clone();
+
+        let new_root_account = account::<T::AccountId>("new_root", 0, SEED);
+
+        Module::<T>::initiate_recovery(
+            RawOrigin::Signed(guardian_account_id.clone()).into(),
+            guardian_id,
+            member_id,
+            new_root_account.clone(),
+        )
+        .unwrap();
+
+        System::<T>::set_block_number(
+            System::<T>::block_number().saturating_add(T::RecoveryDelay::get())
+        );
+
+    }: _(RawOrigin::Signed(guardian_account_id), member_id)
+    verify {
+        assert_eq!(
+            MembershipById::<T>::get(member_id).unwrap().root_account,
+            new_root_account.clone()
+        );
+
+        assert_last_event::<T>(RawEvent::RecoveryFinalized(member_id, new_root_account).into());
+    }
+
     set_leader_invitation_quota {
         // Set leader member id
 
@@ -838,6 +1105,147 @@ benchmarks! {
         );
     }
 
+    protect_handle {
+        let i in 1 .. MAX_KILOBYTES_METADATA;
+
+        let member_id = 0;
+
+        let (account_id, member_id) = member_funded_account::<T>("member", member_id);
+
+        // Set leader member id
+        T::insert_a_lead(0, &account_id, member_id);
+
+        let handle = vec![1u8].repeat((i * 1000) as usize);
+
+    }: _(RawOrigin::Signed(account_id.clone()), handle.clone())
+    verify {
+        assert!(ProtectedHandleHashes::<T>::contains_key(T::Hashing::hash(&handle)));
+
+        assert_last_event::<T>(RawEvent::HandleProtected(handle).into());
+    }
+
+    unprotect_handle {
+        let i in 1 .. MAX_KILOBYTES_METADATA;
+
+        let member_id = 0;
+
+        let (account_id, member_id) = member_funded_account::<T>("member", member_id);
+
+        // Set leader member id
+        T::insert_a_lead(0, &account_id, member_id);
+
+        let handle = vec![1u8].repeat((i * 1000) as usize);
+
+        Module::<T>::protect_handle(
+            RawOrigin::Signed(account_id.clone()).into(),
+            handle.clone(),
+        )
+        .unwrap();
+
+    }: _(RawOrigin::Signed(account_id.clone()), handle.clone())
+    verify {
+        assert!(!ProtectedHandleHashes::<T>::contains_key(T::Hashing::hash(&handle)));
+
+        assert_last_event::<T>(RawEvent::HandleUnprotected(handle).into());
+    }
+
+    offer_handle {
+        let (account_id, member_id) = member_funded_account::<T>("member", 0);
+        let (_, target_member_id) = member_funded_account::<T>("target", 1);
+
+        let price = Some(BalanceOf::<T>::from(100u32));
+
+    }: _(RawOrigin::Signed(account_id.clone()), member_id, target_member_id, price)
+    verify {
+        assert_eq!(
+            Module::<T>::handle_offer(member_id),
+            Some(HandleOffer {
+                handle_hash: T::Hashing::hash(&handle_from_id::<T>(0)),
+                target_member_id,
+                price,
+            })
+        );
+
+        assert_last_event::<T>(RawEvent::HandleOffered(member_id, target_member_id, price).into());
+    }
+
+    cancel_handle_offer {
+        let (account_id, member_id) = member_funded_account::<T>("member", 0);
+        let (_, target_member_id) = member_funded_account::<T>("target", 1);
+
+        Module::<T>::offer_handle(
+            RawOrigin::Signed(account_id.clone()).into(),
+            member_id,
+            target_member_id,
+            None,
+        )
+        .unwrap();
+
+    }: _(RawOrigin::Signed(account_id.clone()), member_id)
+    verify {
+        assert!(Module::<T>::handle_offer(member_id).is_none());
+
+        assert_last_event::<T>(RawEvent::HandleOfferCancelled(member_id).into());
+    }
+
+    accept_handle_offer {
+        let (offering_account_id, offering_member_id) = member_funded_account::<T>("member", 0);
+        let (account_id, member_id) = member_funded_account::<T>("target", 1);
+
+        let price = Some(BalanceOf::<T>::from(100u32));
+
+        Module::<T>::offer_handle(
+            RawOrigin::Signed(offering_account_id.clone()).into(),
+            offering_member_id,
+            member_id,
+            price,
+        )
+        .unwrap();
+
+        let handle_hash = T::Hashing::hash(&handle_from_id::<T>(1));
+        let offering_handle_hash = T::Hashing::hash(&handle_from_id::<T>(0));
+
+    }: _(RawOrigin::Signed(account_id.clone()), member_id, offering_member_id)
+    verify {
+        assert_eq!(MemberIdByHandleHash::<T>::get(offering_handle_hash), member_id);
+        assert_eq!(MemberIdByHandleHash::<T>::get(handle_hash), offering_member_id);
+        assert!(Module::<T>::handle_offer(offering_member_id).is_none());
+
+        assert_last_event::<T>(
+            RawEvent::HandlesSwapped(offering_member_id, member_id, price).into()
+        );
+    }
+
+    release_inactive_member_handle {
+        let (account_id, member_id) = member_funded_account::<T>("member", 0);
+        let (_, target_member_id) = member_funded_account::<T>("target", 1);
+
+        Module::<T>::offer_handle(
+            RawOrigin::Signed(account_id.clone()).into(),
+            member_id,
+            target_member_id,
+            None,
+        )
+        .unwrap();
+
+        // member registered before the activity tracking
+        MemberLastActivity::<T>::remove(member_id);
+
+        System::<T>::set_block_number(
+            System::<T>::block_number().saturating_add(T::HandleReclaimCooldown::get())
+        );
+
+        let handle_hash = T::Hashing::hash(&handle_from_id::<T>(0));
+
+    }: _(RawOrigin::Signed(account_id.clone()), member_id)
+    verify {
+        assert!(!MemberIdByHandleHash::<T>::contains_key(handle_hash));
+        assert_eq!(Module::<T>::membership(member_id).unwrap().handle_hash, T::Hash::default());
+        assert!(Module::<T>::handle_offer(member_id).is_none());
+
+        assert_last_event::<T>(RawEvent::InactiveMemberHandleReleased(member_id).into());
+    }
+
     // impl_benchmark_test_suite!(Module, tests::mock::build_test_externalities(), tests::mock::Test)
     impl_benchmark_test_suite!(
         Module,
@@ -34,10 +34,65 @@
 //! - [update_accounts](./struct.Module.html#method.update_accounts) - updates member accounts.
 //! - [update_profile_verification](./struct.Module.html#method.update_profile_verification) -
 //! updates member profile verification status.
+//! - [attest_member](./struct.Module.html#method.attest_member) - issues the member attestation
+//! of the given kind.
+//! - [revoke_member_attestation](./struct.Module.html#method.revoke_member_attestation) - revokes
+//! the member attestation of the given kind.
+//! - [remove_expired_member_attestation](./struct.Module.html#method.remove_expired_member_attestation)
+//! - removes the expired member attestation.
+//! - [set_recovery_config](./struct.Module.html#method.set_recovery_config) - sets the recovery
+//! guardians and the threshold of the member.
+//! - [remove_recovery_config](./struct.Module.html#method.remove_recovery_config) - removes the
+//! recovery guardians of the member.
+//! - [initiate_recovery](./struct.Module.html#method.initiate_recovery) - starts the member root
+//! account recovery.
+//! - [approve_recovery](./struct.Module.html#method.approve_recovery) - approves the member root
+//! account recovery.
+//! - [cancel_recovery](./struct.Module.html#method.cancel_recovery) - cancels the member root
+//! account recovery.
+//! - [finalize_recovery](./struct.Module.html#method.finalize_recovery) - rotates the member root
+//! account after the recovery delay.
 //! - [set_referral_cut](./struct.Module.html#method.set_referral_cut) -
 //! updates the referral cut percent value.
 //! - [transfer_invites](./struct.Module.html#method.transfer_invites) - transfers the invites
 //! from one member to another.
+//! - [protect_handle](./struct.Module.html#method.protect_handle) - adds the handle to the
+//! protected handle list.
+//! - [unprotect_handle](./struct.Module.html#method.unprotect_handle) - removes the handle from
+//! the protected handle list.
+//! - [offer_handle](./struct.Module.html#method.offer_handle) - offers the member handle to
+//! another member.
+//! - [cancel_handle_offer](./struct.Module.html#method.cancel_handle_offer) - cancels the handle
+//! offer.
+//! - [accept_handle_offer](./struct.Module.html#method.accept_handle_offer) - swaps the handles
+//! of the offering and the accepting members.
+//! - [release_inactive_member_handle](./struct.Module.html#method.release_inactive_member_handle)
+//! - releases the handle of the inactive member.
+//!
+//! #### Attestations
+//! Membership working group workers can attest members with the structured attestations of
+//! different kinds (identity, creator, organisation). Each attestation records the issuing worker
+//! and an optional expiry block. A valid attestation can be replaced or revoked only by its
+//! issuer or the membership working group lead, while an expired attestation can be removed by
+//! anyone. Other pallets query valid attestations using the `MembershipInfoProvider` trait.
+//!
+//! #### Social recovery
+//! A member can nominate other members as the recovery guardians and set the threshold of the
+//! guardian approvals. If the root account is lost, a guardian initiates the recovery with the new
+//! root account and other guardians approve it. Once the threshold is reached the root account can
+//! be rotated after the recovery delay. The current root account can cancel the recovery at any
+//! time before the rotation, either explicitly or by setting a new root account. A new recovery
+//! can't be initiated until the recovery cooldown passes after the cancellation.
+//!
+//! #### Handles
+//! Each handle is unique and belongs to a single member. The membership working group lead
+//! maintains the list of protected handles (e.g. for brands or system accounts) that can only be
+//! registered by the root. A member can offer their handle to another member, optionally for a
+//! price. Accepting the offer atomically swaps the handles of both members. The handle of the
+//! member that wasn't active (created the membership, updated the profile, made a remark or traded
+//! the handle) for the handle reclaim cooldown can be released by anyone, so it can be registered
+//! by another member. The member with the released handle can set a new handle with the profile
+//! update.
 //!
 //! [Joystream handbook description](https://joystream.gitbook.io/joystream-handbook/subsystems/membership)
 
@@ -62,6 +117,7 @@
 extern crate common;
 
 pub mod benchmarking;
+pub mod migrations;
 #[cfg(test)]
 mod tests;
 pub mod weights;
@@ -69,7 +125,10 @@ pub use weights::WeightInfo;
 
 use codec::{Decode, Encode, MaxEncodedLen};
 use frame_support::dispatch::DispatchError;
-use frame_support::traits::{Currency, ExistenceRequirement, Get, LockIdentifier, WithdrawReasons};
+use frame_support::storage::bounded_btree_set::BoundedBTreeSet;
+use frame_support::traits::{
+    Currency, ExistenceRequirement, Get, LockIdentifier, StorageVersion, WithdrawReasons,
+};
 pub use frame_support::weights::Weight;
 use frame_support::{decl_error, decl_event, decl_module, decl_storage, ensure};
 use frame_system::{ensure_root, ensure_signed};
@@ -82,11 +141,12 @@ use sp_runtime::{
     traits::{Hash, Saturating},
     SaturatedConversion,
 };
+use sp_std::collections::btree_set::BTreeSet;
 use sp_std::convert::TryInto;
 use sp_std::{vec, vec::Vec};
 
 use common::costs::{burn_from_usable, has_sufficient_balance_for_payment};
-use common::membership::{MemberOriginValidator, MembershipInfoProvider};
+use common::membership::{AttestationKind, MemberOriginValidator, MembershipInfoProvider};
 use common::to_kb;
 use common::working_group::{WorkingGroupAuthenticator, WorkingGroupBudgetHandler};
 use staking_handler::StakingHandler;
@@ -99,6 +159,8 @@ type BalanceOf<T> = <T as balances::Config>::Balance;
 
 type WeightInfoMembership<T> = <T as Config>::WeightInfo;
 
+const CURRENT_STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
 pub trait Config:
     frame_system::Config
     + balances::Config
@@ -145,6 +207,18 @@ pub trait Config:
 
     /// Default number of invites a paid membership recieves.
     type DefaultMemberInvitesCount: Get<u32>;
+
+    /// Period of the member inactivity after which the member handle can be released.
+    type HandleReclaimCooldown: Get<Self::BlockNumber>;
+
+    /// Maximum number of the member recovery guardians.
+    type MaxRecoveryGuardians: Get<u32>;
+
+    /// Delay between reaching the recovery threshold and the root account rotation.
+    type RecoveryDelay: Get<Self::BlockNumber>;
+
+    /// Period after the recovery cancellation during which a new recovery can't be initiated.
+    type RecoveryCooldown: Get<Self::BlockNumber>;
 }
 
 /// Public membership profile alias.
@@ -176,6 +250,94 @@ pub struct MembershipObject<AccountId: Ord, Hash> {
     pub invites: u32,
 }
 
+/// Member attestation issued by the membership working group worker.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
+pub struct Attestation<ActorId, BlockNumber> {
+    /// Worker that issued the attestation.
+    pub issuer: ActorId,
+
+    /// Block the attestation was issued at.
+    pub issued_at: BlockNumber,
+
+    /// Block the attestation expires at. The attestation doesn't expire if not set.
+    pub expires_at: Option<BlockNumber>,
+}
+
+impl<ActorId, BlockNumber: PartialOrd> Attestation<ActorId, BlockNumber> {
+    /// Verifies that the attestation is not expired at the given block.
+    pub fn is_valid(&self, now: &BlockNumber) -> bool {
+        self.expires_at
+            .as_ref()
+            .map_or(true, |expires_at| now < expires_at)
+    }
+}
+
+/// Attestation alias.
+pub type AttestationOf<T> = Attestation<
+    <T as common::membership::MembershipTypes>::ActorId,
+    <T as frame_system::Config>::BlockNumber,
+>;
+
+/// Recovery guardians of the member and the number of the guardian approvals required to recover
+/// the member root account.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
+pub struct RecoveryConfig<Guardians> {
+    /// Members approving the root account recovery.
+    pub guardians: Guardians,
+
+    /// Number of the guardian approvals required to recover the root account.
+    pub threshold: u32,
+}
+
+/// Alias type for the member recovery guardians.
+pub type RecoveryGuardians<T> = BoundedBTreeSet<
+    <T as common::membership::MembershipTypes>::MemberId,
+    <T as Config>::MaxRecoveryGuardians,
+>;
+
+/// Alias type for the stored RecoveryConfig.
+pub type RecoveryConfigOf<T> = RecoveryConfig<RecoveryGuardians<T>>;
+
+/// Root account recovery in progress.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
+pub struct ActiveRecovery<AccountId, BlockNumber, Guardians> {
+    /// Root account to set for the member.
+    pub new_root_account: AccountId,
+
+    /// Guardians approved the recovery.
+    pub approvals: Guardians,
+
+    /// Block the root account can be rotated at. Set when the threshold is reached.
+    pub executable_at: Option<BlockNumber>,
+}
+
+/// Alias type for the stored ActiveRecovery.
+pub type ActiveRecoveryOf<T> = ActiveRecovery<
+    <T as frame_system::Config>::AccountId,
+    <T as frame_system::Config>::BlockNumber,
+    RecoveryGuardians<T>,
+>;
+
+/// Offer of the member handle to another member.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
+pub struct HandleOffer<MemberId, Balance, Hash> {
+    /// Hash of the offered handle.
+    pub handle_hash: Hash,
+
+    /// Member the handle is offered to.
+    pub target_member_id: MemberId,
+
+    /// Price the target member pays for the handle.
+    pub price: Option<Balance>,
+}
+
+/// Handle offer alias.
+pub type HandleOfferOf<T> = HandleOffer<
+    <T as common::membership::MembershipTypes>::MemberId,
+    BalanceOf<T>,
+    <T as frame_system::Config>::Hash,
+>;
+
 // Contain staking account to member binding and its confirmation.
 #[derive(Encode, Decode, Default, Debug, PartialEq, TypeInfo, MaxEncodedLen)]
 pub struct StakingAccountMemberBinding<MemberId> {
@@ -341,6 +503,81 @@ decl_error! {
 
         /// Insufficient balance to cover payment.
         InsufficientBalanceToCoverPayment,
+
+        /// Handle is protected and can only be registered by the root.
+        HandleIsProtected,
+
+        /// Handle is already protected.
+        HandleAlreadyProtected,
+
+        /// Handle is not protected.
+        HandleIsNotProtected,
+
+        /// Member doesn't own a handle.
+        MemberHasNoHandle,
+
+        /// Member can't offer the handle to themselves.
+        CannotOfferHandleToSelf,
+
+        /// Handle offer doesn't exist.
+        HandleOfferNotFound,
+
+        /// Handle offer was made to another member.
+        HandleOfferedToAnotherMember,
+
+        /// Offered handle is no longer owned by the offering member.
+        HandleOfferOutdated,
+
+        /// Insufficient balance to pay the handle price.
+        InsufficientBalanceToBuyHandle,
+
+        /// Member was active during the handle reclaim cooldown.
+        HandleReclaimCooldownNotPassed,
+
+        /// Attestation expiry block should be in the future.
+        AttestationExpiryInPast,
+
+        /// Member doesn't have the attestation of the given kind.
+        AttestationNotFound,
+
+        /// Only the attestation issuer or the working group lead can manage the attestation.
+        NotAttestationIssuerOrLeader,
+
+        /// Attestation is not expired yet.
+        AttestationNotExpired,
+
+        /// Recovery guardians should be provided.
+        NoRecoveryGuardians,
+
+        /// Number of the recovery guardians exceeds the limit.
+        TooManyRecoveryGuardians,
+
+        /// Recovery threshold should be positive and not greater than the number of guardians.
+        InvalidRecoveryThreshold,
+
+        /// Member can't be their own recovery guardian.
+        CannotGuardOwnRecovery,
+
+        /// Member has no recovery guardians.
+        RecoveryConfigNotFound,
+
+        /// Member is not a recovery guardian of the member.
+        NotRecoveryGuardian,
+
+        /// Root account recovery is already in progress.
+        RecoveryInProgress,
+
+        /// Root account recovery is not in progress.
+        RecoveryNotFound,
+
+        /// Guardian already approved the recovery.
+        RecoveryAlreadyApproved,
+
+        /// Recovery threshold is not reached or the recovery delay has not passed.
+        RecoveryNotExecutable,
+
+        /// Recovery was cancelled during the recovery cooldown.
+        RecoveryCooldownNotPassed,
     }
 }
 
@@ -375,6 +612,39 @@ decl_storage! { generate_storage_info
         /// Double of a staking account id and member id to the confirmation status.
         pub(crate) StakingAccountIdMemberStatus get(fn staking_account_id_member_status):
             map hasher(blake2_128_concat) T::AccountId => StakingAccountMemberBinding<T::MemberId>;
+
+        /// Hashes of the protected handles that can only be registered by the root.
+        pub ProtectedHandleHashes get(fn protected_handle_hashes): map hasher(blake2_128_concat)
+            T::Hash => ();
+
+        /// Handle offers by the offering member.
+        pub HandleOfferByMemberId get(fn handle_offer): map hasher(blake2_128_concat)
+            T::MemberId => Option<HandleOfferOf<T>>;
+
+        /// Member attestations by the member and the attestation kind.
+        pub MemberAttestations get(fn member_attestation): double_map
+            hasher(blake2_128_concat) T::MemberId,
+            hasher(blake2_128_concat) AttestationKind => Option<AttestationOf<T>>;
+
+        /// Recovery guardians by the member.
+        pub RecoveryConfigByMemberId get(fn recovery_config): map hasher(blake2_128_concat)
+            T::MemberId => Option<RecoveryConfigOf<T>>;
+
+        /// Root account recoveries in progress by the member.
+        pub ActiveRecoveryByMemberId get(fn active_recovery): map hasher(blake2_128_concat)
+            T::MemberId => Option<ActiveRecoveryOf<T>>;
+
+        /// Block of the last root account recovery cancellation by the member.
+        pub RecoveryCancelledAt get(fn recovery_cancelled_at): map hasher(blake2_128_concat)
+            T::MemberId => Option<T::BlockNumber>;
+
+        /// Block of the last member activity.
+        pub MemberLastActivity get(fn member_last_activity): map hasher(blake2_128_concat)
+            T::MemberId => Option<T::BlockNumber>;
+
+        /// Block since which the member activity is tracked. Members without the recorded
+        /// activity are considered last active at this block.
+        pub MemberActivityTrackedSince get(fn member_activity_tracked_since): T::BlockNumber;
     }
     add_extra_genesis {
         build(|_| {
@@ -404,6 +674,7 @@ decl_event! {
         <T as frame_system::Config>::AccountId,
         BalanceOf<T>,
       >,
+        <T as frame_system::Config>::BlockNumber,
     {
         MemberInvited(MemberId, InviteMembershipParameters, Balance),
         MembershipGifted(MemberId, GiftMembershipParameters),
@@ -426,6 +697,22 @@ decl_event! {
         StakingAccountConfirmed(AccountId, MemberId),
         MemberRemarked(MemberId, Vec<u8>, Option<(AccountId, Balance)>),
         MemberCreated(MemberId, CreateMemberParameters, u32),
+        HandleProtected(Vec<u8>),
+        HandleUnprotected(Vec<u8>),
+        HandleOffered(MemberId, MemberId, Option<Balance>),
+        HandleOfferCancelled(MemberId),
+        HandlesSwapped(MemberId, MemberId, Option<Balance>),
+        InactiveMemberHandleReleased(MemberId),
+        MemberAttested(MemberId, AttestationKind, ActorId, Option<BlockNumber>),
+        MemberAttestationRevoked(MemberId, AttestationKind, ActorId),
+        MemberAttestationRemoved(MemberId, AttestationKind),
+        RecoveryConfigSet(MemberId, BTreeSet<MemberId>, u32),
+        RecoveryConfigRemoved(MemberId),
+        RecoveryInitiated(MemberId, MemberId, AccountId),
+        RecoveryApproved(MemberId, MemberId),
+        RecoveryThresholdReached(MemberId, BlockNumber),
+        RecoveryCancelled(MemberId),
+        RecoveryFinalized(MemberId, AccountId),
     }
 }
 
@@ -455,6 +742,21 @@ decl_module! {
         /// Exports const - staking candidate lock id.
         const StakingCandidateLockId: LockIdentifier = T::StakingCandidateStakingHandler::lock_id();
 
+        /// Exports const - period of the member inactivity after which the member handle can be
+        /// released.
+        const HandleReclaimCooldown: T::BlockNumber = T::HandleReclaimCooldown::get();
+
+        /// Exports const - maximum number of the member recovery guardians.
+        const MaxRecoveryGuardians: u32 = T::MaxRecoveryGuardians::get();
+
+        /// Exports const - delay between reaching the recovery threshold and the root account
+        /// rotation.
+        const RecoveryDelay: T::BlockNumber = T::RecoveryDelay::get();
+
+        /// Exports const - period after the recovery cancellation during which a new recovery
+        /// can't be initiated.
+        const RecoveryCooldown: T::BlockNumber = T::RecoveryCooldown::get();
+
         /// Non-members can buy membership.
         ///
         /// <weight>
@@ -567,20 +869,28 @@ decl_module! {
 
             if let Some(new_handle_hash) = new_handle_hash {
                 // remove old handle hash
-                <MemberIdByHandleHash<T>>::remove(membership.handle_hash);
+                if Self::owns_handle(&member_id, &membership) {
+                    <MemberIdByHandleHash<T>>::remove(membership.handle_hash);
+                }
 
                 <MemberIdByHandleHash<T>>::insert(new_handle_hash, member_id);
 
-                <MembershipById<T>>::insert(member_id, Membership::<T> {
+                <MembershipById<T>>::insert(member_id, MembershipObject {
                     handle_hash: new_handle_hash,
                     ..membership
                 });
+
+                // offered handle is no longer owned
+                <HandleOfferByMemberId<T>>::remove(member_id);
             }
 
+            Self::record_member_activity(&member_id);
+
             Self::deposit_event(RawEvent::MemberProfileUpdated(member_id, handle, metadata));
         }
 
         /// Updates member root or controller accounts. No effect if both new accounts are empty.
+        /// Setting a new root account cancels the root account recovery in progress.
         ///
         /// <weight>
         ///
@@ -612,20 +922,389 @@ decl_module! {
             // == MUTATION SAFE ==
             //
 
-            <MembershipById<T>>::insert(member_id, Membership::<T> {
+            <MembershipById<T>>::insert(member_id, MembershipObject {
                 root_account: new_root_account.clone().unwrap_or(membership.root_account),
                 controller_account: new_controller_account.clone().unwrap_or(membership.controller_account),
                 ..membership
             });
 
-            Self::deposit_event(RawEvent::MemberAccountsUpdated(
-                member_id,
-                new_root_account,
-                new_controller_account
-            ));
+            if new_root_account.is_some() && <ActiveRecoveryByMemberId<T>>::contains_key(member_id) {
+                Self::cancel_active_recovery(member_id);
+            }
+
+            Self::deposit_event(RawEvent::MemberAccountsUpdated(
+                member_id,
+                new_root_account,
+                new_controller_account
+            ));
+        }
+
+        /// Updates member profile verification status. Requires working group member origin.
+        ///
+        /// <weight>
+        ///
+        /// ## Weight
+        /// `O (1)`
+        /// - DB:
+        ///    - O(1) doesn't depend on the state or parameters
+        /// # </weight>
+        #[weight = WeightInfoMembership::<T>::update_profile_verification()]
+        pub fn update_profile_verification(
+            origin,
+            worker_id: T::ActorId,
+            target_member_id: T::MemberId,
+            is_verified: bool
+        ) {
+            T::WorkingGroup::ensure_worker_origin(origin, &worker_id)?;
+
+            let membership = Self::ensure_membership(target_member_id)?;
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            <MembershipById<T>>::insert(target_member_id, Membership::<T> {
+                verified: is_verified,
+                ..membership
+            });
+
+            Self::deposit_event(
+                RawEvent::MemberVerificationStatusUpdated(target_member_id, is_verified, worker_id)
+            );
+        }
+
+        /// Issues the member attestation of the given kind. Replaces the existing attestation of
+        /// the same kind. Requires working group member origin. A valid existing attestation can
+        /// only be replaced by its issuer or the working group lead.
+        ///
+        /// <weight>
+        ///
+        /// ## Weight
+        /// `O (1)`
+        /// - DB:
+        ///    - O(1) doesn't depend on the state or parameters
+        /// # </weight>
+        #[weight = WeightInfoMembership::<T>::attest_member()]
+        pub fn attest_member(
+            origin,
+            worker_id: T::ActorId,
+            target_member_id: T::MemberId,
+            kind: AttestationKind,
+            expires_at: Option<T::BlockNumber>,
+        ) {
+            T::WorkingGroup::ensure_worker_origin(origin.clone(), &worker_id)?;
+
+            Self::ensure_membership(target_member_id)?;
+
+            let now = frame_system::Pallet::<T>::block_number();
+
+            if let Some(expires_at) = expires_at {
+                ensure!(expires_at > now, Error::<T>::AttestationExpiryInPast);
+            }
+
+            if let Some(attestation) = Self::member_attestation(target_member_id, kind) {
+                if attestation.is_valid(&now) {
+                    Self::ensure_attestation_issuer_or_leader(origin, &worker_id, &attestation)?;
+                }
+            }
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            <MemberAttestations<T>>::insert(target_member_id, kind, Attestation {
+                issuer: worker_id,
+                issued_at: now,
+                expires_at,
+            });
+
+            Self::deposit_event(
+                RawEvent::MemberAttested(target_member_id, kind, worker_id, expires_at)
+            );
+        }
+
+        /// Revokes the member attestation of the given kind. Requires the origin of the worker
+        /// that issued the attestation or the working group lead origin.
+        ///
+        /// <weight>
+        ///
+        /// ## Weight
+        /// `O (1)`
+        /// - DB:
+        ///    - O(1) doesn't depend on the state or parameters
+        /// # </weight>
+        #[weight = WeightInfoMembership::<T>::revoke_member_attestation()]
+        pub fn revoke_member_attestation(
+            origin,
+            worker_id: T::ActorId,
+            target_member_id: T::MemberId,
+            kind: AttestationKind,
+        ) {
+            T::WorkingGroup::ensure_worker_origin(origin.clone(), &worker_id)?;
+
+            let attestation = Self::member_attestation(target_member_id, kind)
+                .ok_or(Error::<T>::AttestationNotFound)?;
+
+            Self::ensure_attestation_issuer_or_leader(origin, &worker_id, &attestation)?;
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            <MemberAttestations<T>>::remove(target_member_id, kind);
+
+            Self::deposit_event(
+                RawEvent::MemberAttestationRevoked(target_member_id, kind, worker_id)
+            );
+        }
+
+        /// Removes the expired member attestation of the given kind. Requires signed origin.
+        ///
+        /// <weight>
+        ///
+        /// ## Weight
+        /// `O (1)`
+        /// - DB:
+        ///    - O(1) doesn't depend on the state or parameters
+        /// # </weight>
+        #[weight = WeightInfoMembership::<T>::remove_expired_member_attestation()]
+        pub fn remove_expired_member_attestation(
+            origin,
+            target_member_id: T::MemberId,
+            kind: AttestationKind,
+        ) {
+            ensure_signed(origin)?;
+
+            let attestation = Self::member_attestation(target_member_id, kind)
+                .ok_or(Error::<T>::AttestationNotFound)?;
+
+            ensure!(
+                !attestation.is_valid(&frame_system::Pallet::<T>::block_number()),
+                Error::<T>::AttestationNotExpired
+            );
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            <MemberAttestations<T>>::remove(target_member_id, kind);
+
+            Self::deposit_event(RawEvent::MemberAttestationRemoved(target_member_id, kind));
+        }
+
+        /// Sets the recovery guardians and the threshold of the member. Requires the member root
+        /// account origin.
+        ///
+        /// <weight>
+        ///
+        /// ## Weight
+        /// `O (W)` where:
+        /// - `W` is the number of the guardians
+        /// - DB:
+        ///    - O(W)
+        /// # </weight>
+        #[weight = WeightInfoMembership::<T>::set_recovery_config(guardians.len().saturated_into())]
+        pub fn set_recovery_config(
+            origin,
+            member_id: T::MemberId,
+            guardians: BTreeSet<T::MemberId>,
+            threshold: u32,
+        ) {
+            Self::ensure_member_root_account_origin_signed(origin, &member_id)?;
+
+            ensure!(!guardians.is_empty(), Error::<T>::NoRecoveryGuardians);
+
+            ensure!(
+                threshold > 0 && threshold as usize <= guardians.len(),
+                Error::<T>::InvalidRecoveryThreshold
+            );
+
+            ensure!(!guardians.contains(&member_id), Error::<T>::CannotGuardOwnRecovery);
+
+            let bounded_guardians: RecoveryGuardians<T> = guardians
+                .clone()
+                .try_into()
+                .map_err(|_| Error::<T>::TooManyRecoveryGuardians)?;
+
+            for guardian_id in guardians.iter() {
+                Self::ensure_membership(*guardian_id)?;
+            }
+
+            ensure!(
+                !<ActiveRecoveryByMemberId<T>>::contains_key(member_id),
+                Error::<T>::RecoveryInProgress
+            );
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            <RecoveryConfigByMemberId<T>>::insert(member_id, RecoveryConfig {
+                guardians: bounded_guardians,
+                threshold,
+            });
+
+            Self::deposit_event(RawEvent::RecoveryConfigSet(member_id, guardians, threshold));
+        }
+
+        /// Removes the recovery guardians of the member and cancels the recovery in progress.
+        /// Requires the member root account origin.
+        ///
+        /// <weight>
+        ///
+        /// ## Weight
+        /// `O (1)`
+        /// - DB:
+        ///    - O(1) doesn't depend on the state or parameters
+        /// # </weight>
+        #[weight = WeightInfoMembership::<T>::remove_recovery_config()]
+        pub fn remove_recovery_config(origin, member_id: T::MemberId) {
+            Self::ensure_member_root_account_origin_signed(origin, &member_id)?;
+
+            ensure!(
+                <RecoveryConfigByMemberId<T>>::contains_key(member_id),
+                Error::<T>::RecoveryConfigNotFound
+            );
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            <RecoveryConfigByMemberId<T>>::remove(member_id);
+            <ActiveRecoveryByMemberId<T>>::remove(member_id);
+
+            Self::deposit_event(RawEvent::RecoveryConfigRemoved(member_id));
+        }
+
+        /// Starts the recovery of the member root account. Requires the guardian controller
+        /// account origin. The initiating guardian approves the recovery. Fails during the
+        /// recovery cooldown after the last cancellation.
+        ///
+        /// <weight>
+        ///
+        /// ## Weight
+        /// `O (1)`
+        /// - DB:
+        ///    - O(1) doesn't depend on the state or parameters
+        /// # </weight>
+        #[weight = WeightInfoMembership::<T>::initiate_recovery()]
+        pub fn initiate_recovery(
+            origin,
+            guardian_id: T::MemberId,
+            member_id: T::MemberId,
+            new_root_account: T::AccountId,
+        ) {
+            let recovery_config = Self::ensure_recovery_guardian_origin(
+                origin,
+                &guardian_id,
+                &member_id
+            )?;
+
+            ensure!(
+                !<ActiveRecoveryByMemberId<T>>::contains_key(member_id),
+                Error::<T>::RecoveryInProgress
+            );
+
+            if let Some(cancelled_at) = Self::recovery_cancelled_at(member_id) {
+                ensure!(
+                    frame_system::Pallet::<T>::block_number()
+                        >= cancelled_at.saturating_add(T::RecoveryCooldown::get()),
+                    Error::<T>::RecoveryCooldownNotPassed
+                );
+            }
+
+            let mut approvals = RecoveryGuardians::<T>::default();
+            approvals
+                .try_insert(guardian_id)
+                .map_err(|_| Error::<T>::TooManyRecoveryGuardians)?;
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            <RecoveryCancelledAt<T>>::remove(member_id);
+
+            let recovery = ActiveRecovery {
+                new_root_account: new_root_account.clone(),
+                approvals,
+                executable_at: None,
+            };
+
+            Self::deposit_event(
+                RawEvent::RecoveryInitiated(member_id, guardian_id, new_root_account)
+            );
+
+            Self::save_recovery_approval(member_id, &recovery_config, recovery);
+        }
+
+        /// Approves the recovery of the member root account. Requires the guardian controller
+        /// account origin.
+        ///
+        /// <weight>
+        ///
+        /// ## Weight
+        /// `O (1)`
+        /// - DB:
+        ///    - O(1) doesn't depend on the state or parameters
+        /// # </weight>
+        #[weight = WeightInfoMembership::<T>::approve_recovery()]
+        pub fn approve_recovery(origin, guardian_id: T::MemberId, member_id: T::MemberId) {
+            let recovery_config = Self::ensure_recovery_guardian_origin(
+                origin,
+                &guardian_id,
+                &member_id
+            )?;
+
+            let mut recovery = Self::active_recovery(member_id)
+                .ok_or(Error::<T>::RecoveryNotFound)?;
+
+            ensure!(
+                !recovery.approvals.contains(&guardian_id),
+                Error::<T>::RecoveryAlreadyApproved
+            );
+
+            recovery
+                .approvals
+                .try_insert(guardian_id)
+                .map_err(|_| Error::<T>::TooManyRecoveryGuardians)?;
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            Self::deposit_event(RawEvent::RecoveryApproved(member_id, guardian_id));
+
+            Self::save_recovery_approval(member_id, &recovery_config, recovery);
+        }
+
+        /// Cancels the recovery of the member root account and starts the recovery cooldown.
+        /// Requires the member root account origin.
+        ///
+        /// <weight>
+        ///
+        /// ## Weight
+        /// `O (1)`
+        /// - DB:
+        ///    - O(1) doesn't depend on the state or parameters
+        /// # </weight>
+        #[weight = WeightInfoMembership::<T>::cancel_recovery()]
+        pub fn cancel_recovery(origin, member_id: T::MemberId) {
+            Self::ensure_member_root_account_origin_signed(origin, &member_id)?;
+
+            ensure!(
+                <ActiveRecoveryByMemberId<T>>::contains_key(member_id),
+                Error::<T>::RecoveryNotFound
+            );
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            Self::cancel_active_recovery(member_id);
         }
 
-        /// Updates member profile verification status. Requires working group member origin.
+        /// Rotates the member root account once the recovery threshold is reached and the
+        /// recovery delay has passed. Can be called by any signed origin.
         ///
         /// <weight>
         ///
@@ -634,29 +1313,33 @@ decl_module! {
         /// - DB:
         ///    - O(1) doesn't depend on the state or parameters
         /// # </weight>
-        #[weight = WeightInfoMembership::<T>::update_profile_verification()]
-        pub fn update_profile_verification(
-            origin,
-            worker_id: T::ActorId,
-            target_member_id: T::MemberId,
-            is_verified: bool
-        ) {
-            T::WorkingGroup::ensure_worker_origin(origin, &worker_id)?;
+        #[weight = WeightInfoMembership::<T>::finalize_recovery()]
+        pub fn finalize_recovery(origin, member_id: T::MemberId) {
+            ensure_signed(origin)?;
 
-            let membership = Self::ensure_membership(target_member_id)?;
+            let membership = Self::ensure_membership(member_id)?;
+
+            let recovery = Self::active_recovery(member_id)
+                .ok_or(Error::<T>::RecoveryNotFound)?;
+
+            let now = frame_system::Pallet::<T>::block_number();
+            ensure!(
+                recovery.executable_at.map_or(false, |executable_at| now >= executable_at),
+                Error::<T>::RecoveryNotExecutable
+            );
 
             //
             // == MUTATION SAFE ==
             //
 
-            <MembershipById<T>>::insert(target_member_id, Membership::<T> {
-                verified: is_verified,
+            <MembershipById<T>>::insert(member_id, MembershipObject {
+                root_account: recovery.new_root_account.clone(),
                 ..membership
             });
 
-            Self::deposit_event(
-                RawEvent::MemberVerificationStatusUpdated(target_member_id, is_verified, worker_id)
-            );
+            <ActiveRecoveryByMemberId<T>>::remove(member_id);
+
+            Self::deposit_event(RawEvent::RecoveryFinalized(member_id, recovery.new_root_account));
         }
 
         /// Updates membership referral cut percent value. Requires root origin.
@@ -999,7 +1682,7 @@ decl_module! {
             // == MUTATION SAFE ==
             //
 
-            <MembershipById<T>>::insert(member_id, Membership::<T> {
+            <MembershipById<T>>::insert(member_id, MembershipObject {
                 invites: invitation_quota,
                 ..membership
             });
@@ -1215,6 +1898,8 @@ decl_module! {
                 )?;
             }
 
+            Self::record_member_activity(&member_id);
+
             Self::deposit_event(RawEvent::MemberRemarked(member_id, msg, payment));
         }
 
@@ -1239,7 +1924,8 @@ decl_module! {
         ) {
             ensure_root(origin)?;
 
-            let handle_hash = Self::get_handle_hash(&Some(params.handle.clone()))?;
+            // root can register the protected handles
+            let handle_hash = Self::get_unprotected_handle_hash(&Some(params.handle.clone()))?;
 
             //
             // == MUTATION SAFE ==
@@ -1258,6 +1944,277 @@ decl_module! {
                 RawEvent::MemberCreated(member_id, params, initial_invitation_count)
             );
         }
+
+        /// Adds the handle to the protected handle list. Protected handles can only be registered
+        /// by the root. Requires the membership working group leader origin.
+        ///
+        /// <weight>
+        ///
+        /// ## Weight
+        /// `O (W)` where:
+        /// - `W` is the handle size in kilobytes
+        /// - DB:
+        ///    - O(1) doesn't depend on the state or parameters
+        /// # </weight>
+        #[weight = WeightInfoMembership::<T>::protect_handle(to_kb(handle.len().saturated_into()))]
+        pub fn protect_handle(origin, handle: Vec<u8>) {
+            T::WorkingGroup::ensure_leader_origin(origin)?;
+
+            ensure!(!handle.is_empty(), Error::<T>::HandleMustBeProvidedDuringRegistration);
+
+            let handle_hash = T::Hashing::hash(&handle);
+
+            ensure!(
+                !<ProtectedHandleHashes<T>>::contains_key(handle_hash),
+                Error::<T>::HandleAlreadyProtected
+            );
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            <ProtectedHandleHashes<T>>::insert(handle_hash, ());
+
+            Self::deposit_event(RawEvent::HandleProtected(handle));
+        }
+
+        /// Removes the handle from the protected handle list. Requires the membership working
+        /// group leader origin.
+        ///
+        /// <weight>
+        ///
+        /// ## Weight
+        /// `O (W)` where:
+        /// - `W` is the handle size in kilobytes
+        /// - DB:
+        ///    - O(1) doesn't depend on the state or parameters
+        /// # </weight>
+        #[weight = WeightInfoMembership::<T>::unprotect_handle(to_kb(handle.len().saturated_into()))]
+        pub fn unprotect_handle(origin, handle: Vec<u8>) {
+            T::WorkingGroup::ensure_leader_origin(origin)?;
+
+            let handle_hash = T::Hashing::hash(&handle);
+
+            ensure!(
+                <ProtectedHandleHashes<T>>::contains_key(handle_hash),
+                Error::<T>::HandleIsNotProtected
+            );
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            <ProtectedHandleHashes<T>>::remove(handle_hash);
+
+            Self::deposit_event(RawEvent::HandleUnprotected(handle));
+        }
+
+        /// Offers the member handle to another member, optionally for a price. Replaces the
+        /// existing offer of the member.
+        ///
+        /// <weight>
+        ///
+        /// ## Weight
+        /// `O (1)`
+        /// - DB:
+        ///    - O(1) doesn't depend on the state or parameters
+        /// # </weight>
+        #[weight = WeightInfoMembership::<T>::offer_handle()]
+        pub fn offer_handle(
+            origin,
+            member_id: T::MemberId,
+            target_member_id: T::MemberId,
+            price: Option<BalanceOf<T>>,
+        ) {
+            let membership = Self::ensure_member_controller_account_origin_signed(
+                origin,
+                &member_id
+            )?;
+
+            ensure!(member_id != target_member_id, Error::<T>::CannotOfferHandleToSelf);
+
+            Self::ensure_membership(target_member_id)?;
+
+            Self::ensure_tradable_handle(&member_id, &membership)?;
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            <HandleOfferByMemberId<T>>::insert(member_id, HandleOffer {
+                handle_hash: membership.handle_hash,
+                target_member_id,
+                price,
+            });
+
+            Self::record_member_activity(&member_id);
+
+            Self::deposit_event(RawEvent::HandleOffered(member_id, target_member_id, price));
+        }
+
+        /// Cancels the handle offer of the member.
+        ///
+        /// <weight>
+        ///
+        /// ## Weight
+        /// `O (1)`
+        /// - DB:
+        ///    - O(1) doesn't depend on the state or parameters
+        /// # </weight>
+        #[weight = WeightInfoMembership::<T>::cancel_handle_offer()]
+        pub fn cancel_handle_offer(origin, member_id: T::MemberId) {
+            Self::ensure_member_controller_account_origin_signed(origin, &member_id)?;
+
+            ensure!(
+                <HandleOfferByMemberId<T>>::contains_key(member_id),
+                Error::<T>::HandleOfferNotFound
+            );
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            <HandleOfferByMemberId<T>>::remove(member_id);
+
+            Self::record_member_activity(&member_id);
+
+            Self::deposit_event(RawEvent::HandleOfferCancelled(member_id));
+        }
+
+        /// Accepts the handle offer made to the member: atomically swaps the handles of the
+        /// offering and the accepting members and pays the handle price from the accepting member
+        /// controller account to the offering member controller account.
+        ///
+        /// <weight>
+        ///
+        /// ## Weight
+        /// `O (1)`
+        /// - DB:
+        ///    - O(1) doesn't depend on the state or parameters
+        /// # </weight>
+        #[weight = WeightInfoMembership::<T>::accept_handle_offer()]
+        pub fn accept_handle_offer(
+            origin,
+            member_id: T::MemberId,
+            offering_member_id: T::MemberId,
+        ) {
+            let membership = Self::ensure_member_controller_account_origin_signed(
+                origin,
+                &member_id
+            )?;
+
+            let offer = Self::handle_offer(offering_member_id)
+                .ok_or(Error::<T>::HandleOfferNotFound)?;
+
+            ensure!(
+                offer.target_member_id == member_id,
+                Error::<T>::HandleOfferedToAnotherMember
+            );
+
+            let offering_membership = Self::ensure_membership(offering_member_id)?;
+
+            ensure!(
+                offering_membership.handle_hash == offer.handle_hash
+                    && Self::owns_handle(&offering_member_id, &offering_membership),
+                Error::<T>::HandleOfferOutdated
+            );
+
+            // offered handle could have been protected after the offer was made
+            Self::ensure_unprotected_handle_hash(&offer.handle_hash)?;
+
+            let has_handle = Self::owns_handle(&member_id, &membership);
+            if has_handle {
+                Self::ensure_unprotected_handle_hash(&membership.handle_hash)?;
+            }
+
+            if let Some(price) = offer.price {
+                ensure!(
+                    has_sufficient_balance_for_payment::<T>(&membership.controller_account, price),
+                    Error::<T>::InsufficientBalanceToBuyHandle
+                );
+            }
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            if let Some(price) = offer.price {
+                <balances::Pallet::<T> as Currency<T::AccountId>>::transfer(
+                    &membership.controller_account,
+                    &offering_membership.controller_account,
+                    price,
+                    ExistenceRequirement::KeepAlive
+                )?;
+            }
+
+            // swap the handles
+            <MemberIdByHandleHash<T>>::insert(offering_membership.handle_hash, member_id);
+            if has_handle {
+                <MemberIdByHandleHash<T>>::insert(membership.handle_hash, offering_member_id);
+            }
+
+            <MembershipById<T>>::insert(offering_member_id, Membership::<T> {
+                handle_hash: membership.handle_hash,
+                ..offering_membership
+            });
+            <MembershipById<T>>::insert(member_id, MembershipObject {
+                handle_hash: offering_membership.handle_hash,
+                ..membership
+            });
+
+            // swapped handles are no longer owned by the offering members
+            <HandleOfferByMemberId<T>>::remove(offering_member_id);
+            <HandleOfferByMemberId<T>>::remove(member_id);
+
+            Self::record_member_activity(&member_id);
+
+            Self::deposit_event(RawEvent::HandlesSwapped(offering_member_id, member_id, offer.price));
+        }
+
+        /// Releases the handle of the member that wasn't active for the handle reclaim cooldown,
+        /// so it can be registered by another member. Protected handles can't be released.
+        /// The member activity is recorded on each action authorized by the member controller
+        /// account.
+        ///
+        /// <weight>
+        ///
+        /// ## Weight
+        /// `O (1)`
+        /// - DB:
+        ///    - O(1) doesn't depend on the state or parameters
+        /// # </weight>
+        #[weight = WeightInfoMembership::<T>::release_inactive_member_handle()]
+        pub fn release_inactive_member_handle(origin, member_id: T::MemberId) {
+            ensure_signed(origin)?;
+
+            let membership = Self::ensure_membership(member_id)?;
+
+            Self::ensure_tradable_handle(&member_id, &membership)?;
+
+            let last_activity = Self::member_last_activity(member_id)
+                .unwrap_or_else(Self::member_activity_tracked_since);
+
+            ensure!(
+                frame_system::Pallet::<T>::block_number()
+                    >= last_activity.saturating_add(T::HandleReclaimCooldown::get()),
+                Error::<T>::HandleReclaimCooldownNotPassed
+            );
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            <MemberIdByHandleHash<T>>::remove(membership.handle_hash);
+            <HandleOfferByMemberId<T>>::remove(member_id);
+            <MembershipById<T>>::insert(member_id, MembershipObject {
+                handle_hash: T::Hash::default(),
+                ..membership
+            });
+
+            Self::deposit_event(RawEvent::InactiveMemberHandleReleased(member_id));
+        }
+
+        type StorageVersion = CURRENT_STORAGE_VERSION;
     }
 }
 
@@ -1269,8 +2226,8 @@ impl<T: Config> Module<T> {
     ) -> Weight {
         match (new_root_account.is_some(), new_controller_account.is_some()) {
             (true, true) => WeightInfoMembership::<T>::update_accounts_both(),
-            (false, true) => WeightInfoMembership::<T>::update_accounts_root(),
-            (true, false) => WeightInfoMembership::<T>::update_accounts_controller(),
+            (true, false) => WeightInfoMembership::<T>::update_accounts_root(),
+            (false, true) => WeightInfoMembership::<T>::update_accounts_controller(),
             _ => WeightInfoMembership::<T>::update_accounts_none(),
         }
     }
@@ -1320,6 +2277,29 @@ impl<T: Config> Module<T> {
         Self::membership(id).ok_or(error)
     }
 
+    // Cancels the root account recovery in progress and starts the recovery cooldown.
+    fn cancel_active_recovery(member_id: T::MemberId) {
+        <ActiveRecoveryByMemberId<T>>::remove(member_id);
+        <RecoveryCancelledAt<T>>::insert(member_id, frame_system::Pallet::<T>::block_number());
+
+        Self::deposit_event(RawEvent::RecoveryCancelled(member_id));
+    }
+
+    // Ensures that the worker issued the attestation or that the origin is the lead origin.
+    fn ensure_attestation_issuer_or_leader(
+        origin: T::RuntimeOrigin,
+        worker_id: &T::ActorId,
+        attestation: &AttestationOf<T>,
+    ) -> Result<(), Error<T>> {
+        ensure!(
+            attestation.issuer == *worker_id
+                || T::WorkingGroup::ensure_leader_origin(origin).is_ok(),
+            Error::<T>::NotAttestationIssuerOrLeader
+        );
+
+        Ok(())
+    }
+
     // Ensure possible member handle hash is unique.
     fn ensure_unique_handle_hash(handle_hash: &T::Hash) -> Result<(), Error<T>> {
         ensure!(
@@ -1329,8 +2309,50 @@ impl<T: Config> Module<T> {
         Ok(())
     }
 
-    // Validate handle and return its hash.
+    // Validate not protected handle and return its hash.
     fn get_handle_hash(handle: &Option<Vec<u8>>) -> Result<T::Hash, Error<T>> {
+        let hash = Self::get_unprotected_handle_hash(handle)?;
+
+        Self::ensure_unprotected_handle_hash(&hash)?;
+
+        Ok(hash)
+    }
+
+    // Ensure the handle is not protected.
+    fn ensure_unprotected_handle_hash(handle_hash: &T::Hash) -> Result<(), Error<T>> {
+        ensure!(
+            !<ProtectedHandleHashes<T>>::contains_key(handle_hash),
+            Error::<T>::HandleIsProtected
+        );
+        Ok(())
+    }
+
+    // Ensure the member owns the handle that can be traded or released.
+    fn ensure_tradable_handle(
+        member_id: &T::MemberId,
+        membership: &Membership<T>,
+    ) -> Result<(), Error<T>> {
+        ensure!(
+            Self::owns_handle(member_id, membership),
+            Error::<T>::MemberHasNoHandle
+        );
+
+        Self::ensure_unprotected_handle_hash(&membership.handle_hash)
+    }
+
+    // Verifies that the member still owns their handle (it wasn't released).
+    fn owns_handle(member_id: &T::MemberId, membership: &Membership<T>) -> bool {
+        <MemberIdByHandleHash<T>>::contains_key(membership.handle_hash)
+            && Self::handles(membership.handle_hash) == *member_id
+    }
+
+    // Record the member activity at the current block.
+    fn record_member_activity(member_id: &T::MemberId) {
+        <MemberLastActivity<T>>::insert(member_id, frame_system::Pallet::<T>::block_number());
+    }
+
+    // Validate handle, ignoring its protection, and return its hash.
+    fn get_unprotected_handle_hash(handle: &Option<Vec<u8>>) -> Result<T::Hash, Error<T>> {
         // Handle is required during registration
         let handle = handle
             .as_ref()
@@ -1367,6 +2389,7 @@ impl<T: Config> Module<T> {
 
         <MembershipById<T>>::insert(new_member_id, membership);
         <MemberIdByHandleHash<T>>::insert(handle_hash, new_member_id);
+        Self::record_member_activity(&new_member_id);
 
         <NextMemberId<T>>::put(new_member_id + One::one());
 
@@ -1384,6 +2407,64 @@ impl<T: Config> Module<T> {
         Self::ensure_is_controller_account_for_member(member_id, &signer_account_id)
     }
 
+    // Ensure origin corresponds to the root account of the member.
+    fn ensure_member_root_account_origin_signed(
+        origin: T::RuntimeOrigin,
+        member_id: &T::MemberId,
+    ) -> Result<Membership<T>, DispatchError> {
+        let sender = ensure_signed(origin)?;
+        let membership = Self::ensure_membership(*member_id)?;
+
+        ensure!(
+            membership.root_account == sender,
+            Error::<T>::RootAccountRequired
+        );
+
+        Ok(membership)
+    }
+
+    // Ensure origin corresponds to the controller account of the member recovery guardian.
+    fn ensure_recovery_guardian_origin(
+        origin: T::RuntimeOrigin,
+        guardian_id: &T::MemberId,
+        member_id: &T::MemberId,
+    ) -> Result<RecoveryConfigOf<T>, DispatchError> {
+        Self::ensure_member_controller_account_origin_signed(origin, guardian_id)?;
+
+        Self::ensure_membership(*member_id)?;
+
+        let recovery_config =
+            Self::recovery_config(member_id).ok_or(Error::<T>::RecoveryConfigNotFound)?;
+
+        ensure!(
+            recovery_config.guardians.contains(guardian_id),
+            Error::<T>::NotRecoveryGuardian
+        );
+
+        Ok(recovery_config)
+    }
+
+    // Save the approved recovery, scheduling the root account rotation once the threshold is
+    // reached.
+    fn save_recovery_approval(
+        member_id: T::MemberId,
+        recovery_config: &RecoveryConfigOf<T>,
+        mut recovery: ActiveRecoveryOf<T>,
+    ) {
+        let threshold_reached = recovery.approvals.len() >= recovery_config.threshold as usize;
+
+        if threshold_reached && recovery.executable_at.is_none() {
+            let executable_at =
+                frame_system::Pallet::<T>::block_number().saturating_add(T::RecoveryDelay::get());
+
+            recovery.executable_at = Some(executable_at);
+
+            Self::deposit_event(RawEvent::RecoveryThresholdReached(member_id, executable_at));
+        }
+
+        <ActiveRecoveryByMemberId<T>>::insert(member_id, recovery);
+    }
+
     // Ensure that given member has given account as the controller account
     fn ensure_is_controller_account_for_member(
         member_id: &T::MemberId,
@@ -1462,6 +2543,8 @@ impl<T: Config> MemberOriginValidator<T::RuntimeOrigin, T::MemberId, T::AccountI
 
         Self::ensure_is_controller_account_for_member(&actor_id, &signer_account_id)?;
 
+        Self::record_member_activity(&actor_id);
+
         Ok(signer_account_id)
     }
 
@@ -1478,6 +2561,12 @@ impl<T: Config> MembershipInfoProvider<T> for Module<T> {
 
         Ok(membership.controller_account)
     }
+
+    fn has_valid_attestation(member_id: common::MemberId<T>, kind: AttestationKind) -> bool {
+        Self::member_attestation(member_id, kind).map_or(false, |attestation| {
+            attestation.is_valid(&frame_system::Pallet::<T>::block_number())
+        })
+    }
 }
 
 impl<T: Config> frame_support::traits::Hooks<T::BlockNumber> for Pallet<T> {
@@ -0,0 +1,30 @@
+// Migrations for Membership Pallet
+
+use super::*;
+use frame_support::traits::{GetStorageVersion, OnRuntimeUpgrade};
+use sp_std::marker::PhantomData;
+
+pub mod v1 {
+    use super::*;
+
+    /// Starts tracking the member activity at the upgrade block, so the members without
+    /// the recorded activity are considered last active at the upgrade.
+    pub struct MigrateToV1<T>(PhantomData<T>);
+
+    impl<T: Config> OnRuntimeUpgrade for MigrateToV1<T> {
+        fn on_runtime_upgrade() -> Weight {
+            let onchain = Pallet::<T>::on_chain_storage_version();
+            let current = Pallet::<T>::current_storage_version();
+
+            if onchain > 0 {
+                return T::DbWeight::get().reads(1);
+            }
+
+            <MemberActivityTrackedSince<T>>::put(frame_system::Pallet::<T>::block_number());
+
+            current.put::<Pallet<T>>();
+
+            T::DbWeight::get().reads_writes(2, 2)
+        }
+    }
+}
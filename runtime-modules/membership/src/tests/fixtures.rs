@@ -1,14 +1,16 @@
 use super::mock::*;
 use crate::Event as MembershipEvent;
 use crate::{
-    BalanceOf, BuyMembershipParameters, CreateMemberParameters, GiftMembershipParameters,
-    InviteMembershipParameters, MembershipObject,
+    Attestation, BalanceOf, BuyMembershipParameters, CreateMemberParameters,
+    GiftMembershipParameters, InviteMembershipParameters, MembershipObject,
 };
+use common::membership::AttestationKind;
 use frame_support::dispatch::DispatchResult;
 use frame_support::traits::{OnFinalize, OnInitialize, WithdrawReasons};
 use frame_support::{assert_noop, assert_ok, StorageMap};
 use frame_system::{EventRecord, Phase, RawOrigin};
 use sp_runtime::traits::Hash;
+use sp_std::collections::btree_set::BTreeSet;
 use staking_handler::StakingHandler;
 
 // Recommendation from Parity on testing on_finalize
@@ -119,6 +121,9 @@ pub const BOB_ROOT_ACCOUNT_ID: u64 = 3;
 pub const BOB_CONTROLLER_ACCOUNT_ID: u64 = BOB_ACCOUNT_ID;
 pub const ALICE_MEMBER_ID: u64 = 0;
 pub const BOB_MEMBER_ID: u64 = 1;
+pub const CHARLIE_ACCOUNT_ID: u64 = 4;
+pub const CHARLIE_MEMBER_ID: u64 = 2;
+pub const NEW_ROOT_ACCOUNT_ID: u64 = 10;
 
 pub fn set_alice_as_initial_member() {
     let initial_balance = ed() + DefaultMembershipPrice::get();
@@ -264,6 +269,122 @@ impl UpdateMembershipVerificationFixture {
     }
 }
 
+pub struct AttestMemberFixture {
+    pub origin: RawOrigin<u64>,
+    pub worker_id: u64,
+    pub member_id: u64,
+    pub kind: AttestationKind,
+    pub expires_at: Option<u64>,
+}
+
+impl Default for AttestMemberFixture {
+    fn default() -> Self {
+        Self {
+            origin: RawOrigin::Signed(ALICE_ACCOUNT_ID),
+            worker_id: 1,
+            member_id: ALICE_MEMBER_ID,
+            kind: AttestationKind::Identity,
+            expires_at: None,
+        }
+    }
+}
+
+impl AttestMemberFixture {
+    pub fn call_and_assert(&self, expected_result: DispatchResult) {
+        let actual_result = Membership::attest_member(
+            self.origin.clone().into(),
+            self.worker_id,
+            self.member_id,
+            self.kind,
+            self.expires_at,
+        );
+
+        assert_eq!(expected_result, actual_result);
+
+        if actual_result.is_ok() {
+            assert_eq!(
+                Membership::member_attestation(self.member_id, self.kind),
+                Some(Attestation {
+                    issuer: self.worker_id,
+                    issued_at: System::block_number(),
+                    expires_at: self.expires_at,
+                })
+            );
+
+            EventFixture::assert_last_crate_event(MembershipEvent::<Test>::MemberAttested(
+                self.member_id,
+                self.kind,
+                self.worker_id,
+                self.expires_at,
+            ));
+        }
+    }
+
+    pub fn call_revoke_and_assert(&self, expected_result: DispatchResult) {
+        let actual_result = Membership::revoke_member_attestation(
+            self.origin.clone().into(),
+            self.worker_id,
+            self.member_id,
+            self.kind,
+        );
+
+        assert_eq!(expected_result, actual_result);
+
+        if actual_result.is_ok() {
+            assert!(Membership::member_attestation(self.member_id, self.kind).is_none());
+
+            EventFixture::assert_last_crate_event(
+                MembershipEvent::<Test>::MemberAttestationRevoked(
+                    self.member_id,
+                    self.kind,
+                    self.worker_id,
+                ),
+            );
+        }
+    }
+
+    pub fn call_remove_expired_and_assert(&self, expected_result: DispatchResult) {
+        let actual_result = Membership::remove_expired_member_attestation(
+            self.origin.clone().into(),
+            self.member_id,
+            self.kind,
+        );
+
+        assert_eq!(expected_result, actual_result);
+
+        if actual_result.is_ok() {
+            assert!(Membership::member_attestation(self.member_id, self.kind).is_none());
+
+            EventFixture::assert_last_crate_event(
+                MembershipEvent::<Test>::MemberAttestationRemoved(self.member_id, self.kind),
+            );
+        }
+    }
+
+    pub fn with_origin(self, origin: RawOrigin<u64>) -> Self {
+        Self { origin, ..self }
+    }
+
+    pub fn with_member_id(self, member_id: u64) -> Self {
+        Self { member_id, ..self }
+    }
+
+    pub fn with_worker_id(self, worker_id: u64) -> Self {
+        Self { worker_id, ..self }
+    }
+
+    pub fn with_kind(self, kind: AttestationKind) -> Self {
+        Self { kind, ..self }
+    }
+
+    pub fn with_expiry(self, expires_at: u64) -> Self {
+        Self {
+            expires_at: Some(expires_at),
+            ..self
+        }
+    }
+}
+
 pub struct BuyMembershipFixture {
     pub origin: RawOrigin<u64>,
     pub root_account: u64,
@@ -868,3 +989,342 @@ impl CreateMemberFixture {
         }
     }
 }
+
+pub fn create_bob_member() {
+    let bob = get_bob_info();
+    assert_ok!(Membership::create_member(
+        RawOrigin::Root.into(),
+        CreateMemberParameters {
+            root_account: BOB_ROOT_ACCOUNT_ID,
+            controller_account: BOB_CONTROLLER_ACCOUNT_ID,
+            handle: bob.handle.unwrap(),
+            metadata: bob.metadata,
+            is_founding_member: false,
+        }
+    ));
+}
+
+pub struct ProtectHandleFixture {
+    pub origin: RawOrigin<u64>,
+    pub handle: Vec<u8>,
+}
+
+impl Default for ProtectHandleFixture {
+    fn default() -> Self {
+        Self {
+            origin: RawOrigin::Signed(ALICE_ACCOUNT_ID),
+            handle: get_bob_info().handle.unwrap(),
+        }
+    }
+}
+
+impl ProtectHandleFixture {
+    pub fn call_and_assert(&self, expected_result: DispatchResult) {
+        let actual_result =
+            Membership::protect_handle(self.origin.clone().into(), self.handle.clone());
+
+        assert_eq!(expected_result, actual_result);
+
+        if actual_result.is_ok() {
+            let handle_hash = <Test as frame_system::Config>::Hashing::hash(&self.handle);
+            assert!(<crate::ProtectedHandleHashes<Test>>::contains_key(
+                handle_hash
+            ));
+
+            EventFixture::assert_last_crate_event(MembershipEvent::<Test>::HandleProtected(
+                self.handle.clone(),
+            ));
+        }
+    }
+
+    pub fn call_unprotect_and_assert(&self, expected_result: DispatchResult) {
+        let actual_result =
+            Membership::unprotect_handle(self.origin.clone().into(), self.handle.clone());
+
+        assert_eq!(expected_result, actual_result);
+
+        if actual_result.is_ok() {
+            let handle_hash = <Test as frame_system::Config>::Hashing::hash(&self.handle);
+            assert!(!<crate::ProtectedHandleHashes<Test>>::contains_key(
+                handle_hash
+            ));
+
+            EventFixture::assert_last_crate_event(MembershipEvent::<Test>::HandleUnprotected(
+                self.handle.clone(),
+            ));
+        }
+    }
+
+    pub fn with_origin(self, origin: RawOrigin<u64>) -> Self {
+        Self { origin, ..self }
+    }
+
+    pub fn with_handle(self, handle: Vec<u8>) -> Self {
+        Self { handle, ..self }
+    }
+}
+
+pub struct OfferHandleFixture {
+    pub origin: RawOrigin<u64>,
+    pub member_id: u64,
+    pub target_member_id: u64,
+    pub price: Option<u64>,
+}
+
+impl Default for OfferHandleFixture {
+    fn default() -> Self {
+        Self {
+            origin: RawOrigin::Signed(ALICE_ACCOUNT_ID),
+            member_id: ALICE_MEMBER_ID,
+            target_member_id: BOB_MEMBER_ID,
+            price: None,
+        }
+    }
+}
+
+impl OfferHandleFixture {
+    pub fn call_and_assert(&self, expected_result: DispatchResult) {
+        let actual_result = Membership::offer_handle(
+            self.origin.clone().into(),
+            self.member_id,
+            self.target_member_id,
+            self.price,
+        );
+
+        assert_eq!(expected_result, actual_result);
+
+        if actual_result.is_ok() {
+            assert_eq!(
+                Membership::handle_offer(self.member_id),
+                Some(crate::HandleOffer {
+                    handle_hash: get_membership_by_id(self.member_id).handle_hash,
+                    target_member_id: self.target_member_id,
+                    price: self.price,
+                })
+            );
+
+            EventFixture::assert_last_crate_event(MembershipEvent::<Test>::HandleOffered(
+                self.member_id,
+                self.target_member_id,
+                self.price,
+            ));
+        }
+    }
+
+    pub fn with_origin(self, origin: RawOrigin<u64>) -> Self {
+        Self { origin, ..self }
+    }
+
+    pub fn with_member_id(self, member_id: u64) -> Self {
+        Self { member_id, ..self }
+    }
+
+    pub fn with_target_member_id(self, target_member_id: u64) -> Self {
+        Self {
+            target_member_id,
+            ..self
+        }
+    }
+
+    pub fn with_price(self, price: u64) -> Self {
+        Self {
+            price: Some(price),
+            ..self
+        }
+    }
+}
+
+pub struct AcceptHandleOfferFixture {
+    pub origin: RawOrigin<u64>,
+    pub member_id: u64,
+    pub offering_member_id: u64,
+}
+
+impl Default for AcceptHandleOfferFixture {
+    fn default() -> Self {
+        Self {
+            origin: RawOrigin::Signed(BOB_CONTROLLER_ACCOUNT_ID),
+            member_id: BOB_MEMBER_ID,
+            offering_member_id: ALICE_MEMBER_ID,
+        }
+    }
+}
+
+impl AcceptHandleOfferFixture {
+    pub fn call_and_assert(&self, expected_result: DispatchResult) {
+        let offer = Membership::handle_offer(self.offering_member_id);
+        let handle_hash = get_membership_by_id(self.member_id).handle_hash;
+        let offering_handle_hash = get_membership_by_id(self.offering_member_id).handle_hash;
+
+        let actual_result = Membership::accept_handle_offer(
+            self.origin.clone().into(),
+            self.member_id,
+            self.offering_member_id,
+        );
+
+        assert_eq!(expected_result, actual_result);
+
+        if actual_result.is_ok() {
+            assert_eq!(
+                get_membership_by_id(self.member_id).handle_hash,
+                offering_handle_hash
+            );
+            assert_eq!(
+                get_membership_by_id(self.offering_member_id).handle_hash,
+                handle_hash
+            );
+            assert_eq!(Membership::handles(offering_handle_hash), self.member_id);
+            assert_eq!(Membership::handles(handle_hash), self.offering_member_id);
+            assert!(Membership::handle_offer(self.offering_member_id).is_none());
+
+            EventFixture::assert_last_crate_event(MembershipEvent::<Test>::HandlesSwapped(
+                self.offering_member_id,
+                self.member_id,
+                offer.unwrap().price,
+            ));
+        }
+    }
+
+    pub fn with_origin(self, origin: RawOrigin<u64>) -> Self {
+        Self { origin, ..self }
+    }
+
+    pub fn with_member_id(self, member_id: u64) -> Self {
+        Self { member_id, ..self }
+    }
+}
+
+pub struct ReleaseInactiveMemberHandleFixture {
+    pub origin: RawOrigin<u64>,
+    pub member_id: u64,
+}
+
+impl Default for ReleaseInactiveMemberHandleFixture {
+    fn default() -> Self {
+        Self {
+            origin: RawOrigin::Signed(BOB_ACCOUNT_ID),
+            member_id: ALICE_MEMBER_ID,
+        }
+    }
+}
+
+impl ReleaseInactiveMemberHandleFixture {
+    pub fn call_and_assert(&self, expected_result: DispatchResult) {
+        let handle_hash = get_membership_by_id(self.member_id).handle_hash;
+
+        let actual_result =
+            Membership::release_inactive_member_handle(self.origin.clone().into(), self.member_id);
+
+        assert_eq!(expected_result, actual_result);
+
+        if actual_result.is_ok() {
+            assert!(!<crate::MemberIdByHandleHash<Test>>::contains_key(
+                handle_hash
+            ));
+            assert_eq!(
+                get_membership_by_id(self.member_id).handle_hash,
+                Default::default()
+            );
+            assert!(Membership::handle_offer(self.member_id).is_none());
+
+            EventFixture::assert_last_crate_event(
+                MembershipEvent::<Test>::InactiveMemberHandleReleased(self.member_id),
+            );
+        }
+    }
+}
+
+pub fn create_charlie_member() {
+    assert_ok!(Membership::create_member(
+        RawOrigin::Root.into(),
+        CreateMemberParameters {
+            root_account: CHARLIE_ACCOUNT_ID,
+            controller_account: CHARLIE_ACCOUNT_ID,
+            handle: b"charlie".to_vec(),
+            metadata: Vec::new(),
+            is_founding_member: false,
+        }
+    ));
+}
+
+pub struct SetRecoveryConfigFixture {
+    pub origin: RawOrigin<u64>,
+    pub member_id: u64,
+    pub guardians: BTreeSet<u64>,
+    pub threshold: u32,
+}
+
+impl Default for SetRecoveryConfigFixture {
+    fn default() -> Self {
+        Self {
+            origin: RawOrigin::Signed(ALICE_ACCOUNT_ID),
+            member_id: ALICE_MEMBER_ID,
+            guardians: vec![BOB_MEMBER_ID, CHARLIE_MEMBER_ID].into_iter().collect(),
+            threshold: 2,
+        }
+    }
+}
+
+impl SetRecoveryConfigFixture {
+    pub fn call_and_assert(&self, expected_result: DispatchResult) {
+        let actual_result = Membership::set_recovery_config(
+            self.origin.clone().into(),
+            self.member_id,
+            self.guardians.clone(),
+            self.threshold,
+        );
+
+        assert_eq!(expected_result, actual_result);
+
+        if actual_result.is_ok() {
+            let recovery_config = Membership::recovery_config(self.member_id).unwrap();
+            assert_eq!(recovery_config.guardians.into_inner(), self.guardians);
+            assert_eq!(recovery_config.threshold, self.threshold);
+
+            EventFixture::assert_last_crate_event(MembershipEvent::<Test>::RecoveryConfigSet(
+                self.member_id,
+                self.guardians.clone(),
+                self.threshold,
+            ));
+        }
+    }
+
+    pub fn with_origin(self, origin: RawOrigin<u64>) -> Self {
+        Self { origin, ..self }
+    }
+
+    pub fn with_guardians(self, guardians: Vec<u64>) -> Self {
+        Self {
+            guardians: guardians.into_iter().collect(),
+            ..self
+        }
+    }
+
+    pub fn with_threshold(self, threshold: u32) -> Self {
+        Self { threshold, ..self }
+    }
+}
+
+pub fn initiate_recovery_as_bob() -> DispatchResult {
+    Membership::initiate_recovery(
+        RawOrigin::Signed(BOB_CONTROLLER_ACCOUNT_ID).into(),
+        BOB_MEMBER_ID,
+        ALICE_MEMBER_ID,
+        NEW_ROOT_ACCOUNT_ID,
+    )
+}
+
+pub fn approve_recovery_as_charlie() -> DispatchResult {
+    Membership::approve_recovery(
+        RawOrigin::Signed(CHARLIE_ACCOUNT_ID).into(),
+        CHARLIE_MEMBER_ID,
+        ALICE_MEMBER_ID,
+    )
+}
+
+pub fn finalize_alice_recovery() -> DispatchResult {
+    Membership::finalize_recovery(
+        RawOrigin::Signed(CHARLIE_ACCOUNT_ID).into(),
+        ALICE_MEMBER_ID,
+    )
+}
@@ -111,6 +111,10 @@ parameter_types! {
     pub const MinimumApplicationStake: u32 = 50;
     pub const LeaderOpeningStake: u32 = 20;
     pub const DefaultMemberInvitesCount: u32 = 2;
+    pub const HandleReclaimCooldown: u64 = 1000;
+    pub const MaxRecoveryGuardians: u32 = 5;
+    pub const RecoveryDelay: u64 = 100;
+    pub const RecoveryCooldown: u64 = 50;
 }
 
 impl LockComparator<u64> for Test {
@@ -150,6 +154,10 @@ impl Config for Test {
     type CandidateStake = CandidateStake;
     type WeightInfo = ();
     type DefaultMemberInvitesCount = DefaultMemberInvitesCount;
+    type HandleReclaimCooldown = HandleReclaimCooldown;
+    type MaxRecoveryGuardians = MaxRecoveryGuardians;
+    type RecoveryDelay = RecoveryDelay;
+    type RecoveryCooldown = RecoveryCooldown;
 }
 
 pub const WORKING_GROUP_BUDGET: u64 = 100;
@@ -205,9 +213,22 @@ impl common::working_group::WorkingGroupAuthenticator<Test> for Wg {
     }
 
     fn ensure_leader_origin(
-        _origin: <Test as frame_system::Config>::RuntimeOrigin,
+        origin: <Test as frame_system::Config>::RuntimeOrigin,
     ) -> DispatchResult {
-        unimplemented!()
+        let raw_origin: Result<RawOrigin<u64>, <Test as frame_system::Config>::RuntimeOrigin> =
+            origin.into();
+
+        if let RawOrigin::Signed(_) = raw_origin.unwrap() {
+            let lead_set = LEAD_SET.with(|lead_set| *lead_set.borrow());
+
+            if lead_set {
+                Ok(())
+            } else {
+                Err(DispatchError::Other("Not a lead"))
+            }
+        } else {
+            Err(DispatchError::BadOrigin)
+        }
     }
 
     fn get_leader_member_id() -> Option<<Test as common::membership::MembershipTypes>::MemberId> {
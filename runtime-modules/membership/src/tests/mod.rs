@@ -3,13 +3,15 @@
 pub(crate) mod fixtures;
 pub(crate) mod mock;
 
+use crate::migrations::v1::MigrateToV1;
 use crate::{BalanceOf, Error, Event};
 pub use fixtures::*;
 pub use mock::*;
 
-use common::membership::{MemberOriginValidator, MembershipInfoProvider};
+use common::membership::{AttestationKind, MemberOriginValidator, MembershipInfoProvider};
 use common::working_group::WorkingGroupBudgetHandler;
 use common::StakingAccountValidator;
+use frame_support::traits::{OnRuntimeUpgrade, StorageVersion};
 use frame_support::{assert_err, assert_ok, StorageMap, StorageValue};
 use frame_system::RawOrigin;
 use sp_arithmetic::Perbill;
@@ -462,6 +464,217 @@ fn update_verification_status_fails_with_invalid_worker_id() {
     });
 }
 
+#[test]
+fn attest_member_succeeds() {
+    build_test_externalities().execute_with(|| {
+        run_to_block(1);
+        set_alice_as_initial_member();
+
+        AttestMemberFixture::default()
+            .with_expiry(10)
+            .call_and_assert(Ok(()));
+
+        AttestMemberFixture::default()
+            .with_kind(AttestationKind::Creator)
+            .call_and_assert(Ok(()));
+
+        assert!(Membership::has_valid_attestation(
+            ALICE_MEMBER_ID,
+            AttestationKind::Identity
+        ));
+        assert!(Membership::has_valid_attestation(
+            ALICE_MEMBER_ID,
+            AttestationKind::Creator
+        ));
+        assert!(!Membership::has_valid_attestation(
+            ALICE_MEMBER_ID,
+            AttestationKind::Organisation
+        ));
+    });
+}
+
+#[test]
+fn attestation_expires() {
+    build_test_externalities().execute_with(|| {
+        run_to_block(1);
+        set_alice_as_initial_member();
+
+        AttestMemberFixture::default()
+            .with_expiry(10)
+            .call_and_assert(Ok(()));
+
+        run_to_block(10);
+
+        assert!(!Membership::has_valid_attestation(
+            ALICE_MEMBER_ID,
+            AttestationKind::Identity
+        ));
+
+        // renewal replaces the expired attestation
+        AttestMemberFixture::default()
+            .with_expiry(20)
+            .call_and_assert(Ok(()));
+
+        assert!(Membership::has_valid_attestation(
+            ALICE_MEMBER_ID,
+            AttestationKind::Identity
+        ));
+    });
+}
+
+#[test]
+fn attest_member_fails_with_invalid_parameters() {
+    build_test_externalities().execute_with(|| {
+        run_to_block(5);
+        set_alice_as_initial_member();
+
+        AttestMemberFixture::default()
+            .with_origin(RawOrigin::None)
+            .call_and_assert(Err(DispatchError::BadOrigin));
+
+        AttestMemberFixture::default()
+            .with_worker_id(44)
+            .call_and_assert(Err(DispatchError::Other("worker does not exist")));
+
+        AttestMemberFixture::default()
+            .with_member_id(44)
+            .call_and_assert(Err(Error::<Test>::MemberProfileNotFound.into()));
+
+        AttestMemberFixture::default()
+            .with_expiry(5)
+            .call_and_assert(Err(Error::<Test>::AttestationExpiryInPast.into()));
+    });
+}
+
+#[test]
+fn revoke_member_attestation_succeeds() {
+    build_test_externalities().execute_with(|| {
+        run_to_block(1);
+        set_alice_as_initial_member();
+
+        AttestMemberFixture::default().call_and_assert(Ok(()));
+
+        AttestMemberFixture::default().call_revoke_and_assert(Ok(()));
+
+        assert!(!Membership::has_valid_attestation(
+            ALICE_MEMBER_ID,
+            AttestationKind::Identity
+        ));
+    });
+}
+
+#[test]
+fn lead_can_revoke_and_replace_attestation_of_another_worker() {
+    build_test_externalities_with_lead_set().execute_with(|| {
+        run_to_block(1);
+        set_alice_as_initial_member();
+
+        AttestMemberFixture::default().call_and_assert(Ok(()));
+
+        AttestMemberFixture::default()
+            .with_worker_id(0)
+            .with_expiry(10)
+            .call_and_assert(Ok(()));
+
+        AttestMemberFixture::default()
+            .with_worker_id(0)
+            .call_revoke_and_assert(Ok(()));
+    });
+}
+
+#[test]
+fn non_issuer_worker_cannot_revoke_or_replace_valid_attestation() {
+    build_test_externalities().execute_with(|| {
+        run_to_block(1);
+        set_alice_as_initial_member();
+
+        AttestMemberFixture::default()
+            .with_expiry(10)
+            .call_and_assert(Ok(()));
+
+        AttestMemberFixture::default()
+            .with_worker_id(0)
+            .call_revoke_and_assert(Err(Error::<Test>::NotAttestationIssuerOrLeader.into()));
+
+        AttestMemberFixture::default()
+            .with_worker_id(0)
+            .call_and_assert(Err(Error::<Test>::NotAttestationIssuerOrLeader.into()));
+
+        // expired attestation can be replaced by any worker
+        run_to_block(10);
+
+        AttestMemberFixture::default()
+            .with_worker_id(0)
+            .call_and_assert(Ok(()));
+    });
+}
+
+#[test]
+fn remove_expired_member_attestation_succeeds() {
+    build_test_externalities().execute_with(|| {
+        run_to_block(1);
+        set_alice_as_initial_member();
+
+        AttestMemberFixture::default()
+            .with_expiry(10)
+            .call_and_assert(Ok(()));
+
+        run_to_block(10);
+
+        // anyone can remove the expired attestation
+        AttestMemberFixture::default()
+            .with_origin(RawOrigin::Signed(BOB_ACCOUNT_ID))
+            .call_remove_expired_and_assert(Ok(()));
+    });
+}
+
+#[test]
+fn remove_expired_member_attestation_fails_with_invalid_parameters() {
+    build_test_externalities().execute_with(|| {
+        run_to_block(1);
+        set_alice_as_initial_member();
+
+        AttestMemberFixture::default()
+            .with_origin(RawOrigin::None)
+            .call_remove_expired_and_assert(Err(DispatchError::BadOrigin));
+
+        AttestMemberFixture::default()
+            .call_remove_expired_and_assert(Err(Error::<Test>::AttestationNotFound.into()));
+
+        AttestMemberFixture::default()
+            .with_expiry(10)
+            .call_and_assert(Ok(()));
+
+        AttestMemberFixture::default()
+            .call_remove_expired_and_assert(Err(Error::<Test>::AttestationNotExpired.into()));
+
+        AttestMemberFixture::default().call_and_assert(Ok(()));
+
+        run_to_block(20);
+
+        // attestation without expiry never expires
+        AttestMemberFixture::default()
+            .call_remove_expired_and_assert(Err(Error::<Test>::AttestationNotExpired.into()));
+    });
+}
+
+#[test]
+fn revoke_member_attestation_fails_with_invalid_parameters() {
+    build_test_externalities().execute_with(|| {
+        run_to_block(1);
+        set_alice_as_initial_member();
+
+        AttestMemberFixture::default()
+            .call_revoke_and_assert(Err(Error::<Test>::AttestationNotFound.into()));
+
+        AttestMemberFixture::default().call_and_assert(Ok(()));
+
+        AttestMemberFixture::default()
+            .with_worker_id(44)
+            .call_revoke_and_assert(Err(DispatchError::Other("worker does not exist")));
+    });
+}
+
 #[test]
 fn buy_membership_fails_with_non_member_referrer_id() {
     build_test_externalities().execute_with(|| {
@@ -1509,3 +1722,643 @@ fn create_member_fails_with_non_unique_handle() {
             .call_and_assert(Err(Error::<Test>::HandleAlreadyRegistered.into()));
     });
 }
+
+#[test]
+fn protect_handle_succeeds() {
+    build_test_externalities_with_lead_set().execute_with(|| {
+        run_to_block(1);
+
+        ProtectHandleFixture::default().call_and_assert(Ok(()));
+
+        // only root can register the protected handle
+        let params = get_bob_gift_membership_parameters();
+        let _ = Balances::deposit_creating(&ALICE_ACCOUNT_ID, ed() + 10_000);
+        set_alice_as_initial_member();
+        assert_err!(
+            gift_bob_membership_as_alice(params),
+            Error::<Test>::HandleIsProtected
+        );
+
+        CreateMemberFixture::default()
+            .with_handle(get_bob_info().handle.unwrap())
+            .call_and_assert(Ok(()));
+    });
+}
+
+#[test]
+fn protect_handle_fails_with_invalid_origin() {
+    build_test_externalities().execute_with(|| {
+        ProtectHandleFixture::default().call_and_assert(Err(DispatchError::Other("Not a lead")));
+    });
+}
+
+#[test]
+fn protect_handle_fails_with_already_protected_handle() {
+    build_test_externalities_with_lead_set().execute_with(|| {
+        ProtectHandleFixture::default().call_and_assert(Ok(()));
+        ProtectHandleFixture::default()
+            .call_and_assert(Err(Error::<Test>::HandleAlreadyProtected.into()));
+    });
+}
+
+#[test]
+fn unprotect_handle_succeeds() {
+    build_test_externalities_with_lead_set().execute_with(|| {
+        run_to_block(1);
+
+        ProtectHandleFixture::default().call_and_assert(Ok(()));
+        ProtectHandleFixture::default().call_unprotect_and_assert(Ok(()));
+
+        set_alice_as_initial_member();
+        let _ = Balances::deposit_creating(&ALICE_ACCOUNT_ID, ed() + 10_000);
+        assert_ok!(gift_bob_membership_as_alice(
+            get_bob_gift_membership_parameters()
+        ));
+    });
+}
+
+#[test]
+fn unprotect_handle_fails_with_not_protected_handle() {
+    build_test_externalities_with_lead_set().execute_with(|| {
+        ProtectHandleFixture::default()
+            .call_unprotect_and_assert(Err(Error::<Test>::HandleIsNotProtected.into()));
+    });
+}
+
+#[test]
+fn offer_handle_succeeds() {
+    build_test_externalities().execute_with(|| {
+        run_to_block(1);
+        set_alice_as_initial_member();
+        create_bob_member();
+
+        OfferHandleFixture::default()
+            .with_price(100)
+            .call_and_assert(Ok(()));
+    });
+}
+
+#[test]
+fn offer_handle_fails_with_invalid_parameters() {
+    build_test_externalities_with_lead_set().execute_with(|| {
+        run_to_block(1);
+        set_alice_as_initial_member();
+        create_bob_member();
+
+        OfferHandleFixture::default()
+            .with_origin(RawOrigin::Signed(BOB_ACCOUNT_ID))
+            .call_and_assert(Err(Error::<Test>::ControllerAccountRequired.into()));
+
+        OfferHandleFixture::default()
+            .with_target_member_id(ALICE_MEMBER_ID)
+            .call_and_assert(Err(Error::<Test>::CannotOfferHandleToSelf.into()));
+
+        OfferHandleFixture::default()
+            .with_target_member_id(111)
+            .call_and_assert(Err(Error::<Test>::MemberProfileNotFound.into()));
+
+        ProtectHandleFixture::default()
+            .with_handle(get_alice_info().handle.unwrap())
+            .call_and_assert(Ok(()));
+
+        OfferHandleFixture::default().call_and_assert(Err(Error::<Test>::HandleIsProtected.into()));
+    });
+}
+
+#[test]
+fn cancel_handle_offer_succeeds() {
+    build_test_externalities().execute_with(|| {
+        run_to_block(1);
+        set_alice_as_initial_member();
+        create_bob_member();
+
+        assert_err!(
+            Membership::cancel_handle_offer(
+                RawOrigin::Signed(ALICE_ACCOUNT_ID).into(),
+                ALICE_MEMBER_ID
+            ),
+            Error::<Test>::HandleOfferNotFound
+        );
+
+        OfferHandleFixture::default().call_and_assert(Ok(()));
+
+        assert_ok!(Membership::cancel_handle_offer(
+            RawOrigin::Signed(ALICE_ACCOUNT_ID).into(),
+            ALICE_MEMBER_ID
+        ));
+
+        assert!(Membership::handle_offer(ALICE_MEMBER_ID).is_none());
+        EventFixture::assert_last_crate_event(Event::<Test>::HandleOfferCancelled(ALICE_MEMBER_ID));
+    });
+}
+
+#[test]
+fn accept_handle_offer_succeeds() {
+    build_test_externalities().execute_with(|| {
+        run_to_block(1);
+        set_alice_as_initial_member();
+        create_bob_member();
+
+        let price = 100;
+        let _ = Balances::deposit_creating(&BOB_CONTROLLER_ACCOUNT_ID, ed() + price);
+        let alice_balance = Balances::usable_balance(&ALICE_ACCOUNT_ID);
+
+        OfferHandleFixture::default()
+            .with_price(price)
+            .call_and_assert(Ok(()));
+        AcceptHandleOfferFixture::default().call_and_assert(Ok(()));
+
+        assert_eq!(
+            Balances::usable_balance(&ALICE_ACCOUNT_ID),
+            alice_balance + price
+        );
+        assert_eq!(Balances::usable_balance(&BOB_CONTROLLER_ACCOUNT_ID), ed());
+    });
+}
+
+#[test]
+fn accept_handle_offer_fails_with_invalid_parameters() {
+    build_test_externalities().execute_with(|| {
+        run_to_block(1);
+        set_alice_as_initial_member();
+        create_bob_member();
+
+        AcceptHandleOfferFixture::default()
+            .call_and_assert(Err(Error::<Test>::HandleOfferNotFound.into()));
+
+        OfferHandleFixture::default()
+            .with_price(100)
+            .call_and_assert(Ok(()));
+
+        AcceptHandleOfferFixture::default()
+            .with_origin(RawOrigin::Signed(ALICE_ACCOUNT_ID))
+            .call_and_assert(Err(Error::<Test>::ControllerAccountRequired.into()));
+
+        AcceptHandleOfferFixture::default()
+            .with_origin(RawOrigin::Signed(ALICE_ACCOUNT_ID))
+            .with_member_id(ALICE_MEMBER_ID)
+            .call_and_assert(Err(Error::<Test>::HandleOfferedToAnotherMember.into()));
+
+        AcceptHandleOfferFixture::default()
+            .call_and_assert(Err(Error::<Test>::InsufficientBalanceToBuyHandle.into()));
+    });
+}
+
+#[test]
+fn accept_handle_offer_fails_with_outdated_offer() {
+    build_test_externalities().execute_with(|| {
+        run_to_block(1);
+        set_alice_as_initial_member();
+        create_bob_member();
+
+        OfferHandleFixture::default().call_and_assert(Ok(()));
+
+        assert_ok!(Membership::update_profile(
+            RawOrigin::Signed(ALICE_ACCOUNT_ID).into(),
+            ALICE_MEMBER_ID,
+            Some(b"alice2".to_vec()),
+            None,
+        ));
+
+        // profile update cancels the offer
+        AcceptHandleOfferFixture::default()
+            .call_and_assert(Err(Error::<Test>::HandleOfferNotFound.into()));
+    });
+}
+
+#[test]
+fn release_inactive_member_handle_succeeds() {
+    build_test_externalities().execute_with(|| {
+        run_to_block(1);
+        set_alice_as_initial_member();
+        create_bob_member();
+
+        ReleaseInactiveMemberHandleFixture::default()
+            .call_and_assert(Err(Error::<Test>::HandleReclaimCooldownNotPassed.into()));
+
+        run_to_block(1 + HandleReclaimCooldown::get());
+
+        ReleaseInactiveMemberHandleFixture::default().call_and_assert(Ok(()));
+
+        // released handle can be registered by another member
+        let alice_handle = get_alice_info().handle;
+        assert_ok!(Membership::update_profile(
+            RawOrigin::Signed(BOB_ACCOUNT_ID).into(),
+            BOB_MEMBER_ID,
+            alice_handle.clone(),
+            None,
+        ));
+        assert_eq!(
+            Membership::handles(get_alice_info().handle_hash.unwrap()),
+            BOB_MEMBER_ID
+        );
+
+        // member with the released handle can set a new one
+        assert_ok!(Membership::update_profile(
+            RawOrigin::Signed(ALICE_ACCOUNT_ID).into(),
+            ALICE_MEMBER_ID,
+            Some(b"alice2".to_vec()),
+            None,
+        ));
+        assert_eq!(
+            Membership::handles(get_alice_info().handle_hash.unwrap()),
+            BOB_MEMBER_ID
+        );
+    });
+}
+
+#[test]
+fn release_inactive_member_handle_fails_with_recent_activity() {
+    build_test_externalities().execute_with(|| {
+        run_to_block(1);
+        set_alice_as_initial_member();
+        create_bob_member();
+
+        run_to_block(HandleReclaimCooldown::get());
+
+        assert_ok!(Membership::member_remark(
+            RawOrigin::Signed(ALICE_ACCOUNT_ID).into(),
+            ALICE_MEMBER_ID,
+            b"test".to_vec(),
+            None,
+        ));
+
+        run_to_block(1 + HandleReclaimCooldown::get());
+
+        ReleaseInactiveMemberHandleFixture::default()
+            .call_and_assert(Err(Error::<Test>::HandleReclaimCooldownNotPassed.into()));
+    });
+}
+
+#[test]
+fn release_inactive_member_handle_fails_with_protected_handle() {
+    build_test_externalities_with_lead_set().execute_with(|| {
+        run_to_block(1);
+        set_alice_as_initial_member();
+        create_bob_member();
+
+        ProtectHandleFixture::default()
+            .with_handle(get_alice_info().handle.unwrap())
+            .call_and_assert(Ok(()));
+
+        run_to_block(1 + HandleReclaimCooldown::get());
+
+        ReleaseInactiveMemberHandleFixture::default()
+            .call_and_assert(Err(Error::<Test>::HandleIsProtected.into()));
+    });
+}
+
+#[test]
+fn release_inactive_member_handle_counts_activity_since_tracking_start() {
+    build_test_externalities().execute_with(|| {
+        run_to_block(1);
+        set_alice_as_initial_member();
+        create_bob_member();
+
+        // Simulate the member registered before the activity tracking.
+        <crate::MemberLastActivity<Test>>::remove(ALICE_MEMBER_ID);
+        StorageVersion::new(0).put::<Membership>();
+
+        run_to_block(HandleReclaimCooldown::get());
+        MigrateToV1::<Test>::on_runtime_upgrade();
+
+        assert_eq!(
+            Membership::member_activity_tracked_since(),
+            HandleReclaimCooldown::get()
+        );
+
+        run_to_block(2 * HandleReclaimCooldown::get() - 1);
+
+        ReleaseInactiveMemberHandleFixture::default()
+            .call_and_assert(Err(Error::<Test>::HandleReclaimCooldownNotPassed.into()));
+
+        run_to_block(2 * HandleReclaimCooldown::get());
+
+        ReleaseInactiveMemberHandleFixture::default().call_and_assert(Ok(()));
+    });
+}
+
+#[test]
+fn release_inactive_member_handle_fails_with_activity_in_other_pallets() {
+    build_test_externalities().execute_with(|| {
+        run_to_block(1);
+        set_alice_as_initial_member();
+        create_bob_member();
+
+        run_to_block(HandleReclaimCooldown::get());
+
+        // Actions authorized by the controller account in other pallets count as activity.
+        assert_ok!(Membership::ensure_member_controller_account_origin(
+            RawOrigin::Signed(ALICE_ACCOUNT_ID).into(),
+            ALICE_MEMBER_ID,
+        ));
+        assert_eq!(
+            Membership::member_last_activity(ALICE_MEMBER_ID),
+            Some(HandleReclaimCooldown::get())
+        );
+
+        run_to_block(1 + HandleReclaimCooldown::get());
+
+        ReleaseInactiveMemberHandleFixture::default()
+            .call_and_assert(Err(Error::<Test>::HandleReclaimCooldownNotPassed.into()));
+    });
+}
+
+fn set_up_recovery_guardians() {
+    run_to_block(1);
+    set_alice_as_initial_member();
+    create_bob_member();
+    create_charlie_member();
+}
+
+#[test]
+fn set_recovery_config_succeeds() {
+    build_test_externalities().execute_with(|| {
+        set_up_recovery_guardians();
+
+        SetRecoveryConfigFixture::default().call_and_assert(Ok(()));
+    });
+}
+
+#[test]
+fn set_recovery_config_fails_with_invalid_parameters() {
+    build_test_externalities().execute_with(|| {
+        set_up_recovery_guardians();
+
+        SetRecoveryConfigFixture::default()
+            .with_origin(RawOrigin::Signed(BOB_ACCOUNT_ID))
+            .call_and_assert(Err(Error::<Test>::RootAccountRequired.into()));
+
+        SetRecoveryConfigFixture::default()
+            .with_guardians(Vec::new())
+            .call_and_assert(Err(Error::<Test>::NoRecoveryGuardians.into()));
+
+        SetRecoveryConfigFixture::default()
+            .with_threshold(0)
+            .call_and_assert(Err(Error::<Test>::InvalidRecoveryThreshold.into()));
+
+        SetRecoveryConfigFixture::default()
+            .with_threshold(3)
+            .call_and_assert(Err(Error::<Test>::InvalidRecoveryThreshold.into()));
+
+        SetRecoveryConfigFixture::default()
+            .with_guardians(vec![ALICE_MEMBER_ID, BOB_MEMBER_ID])
+            .call_and_assert(Err(Error::<Test>::CannotGuardOwnRecovery.into()));
+
+        SetRecoveryConfigFixture::default()
+            .with_guardians((10..=(10 + MaxRecoveryGuardians::get() as u64)).collect())
+            .call_and_assert(Err(Error::<Test>::TooManyRecoveryGuardians.into()));
+
+        SetRecoveryConfigFixture::default()
+            .with_guardians(vec![BOB_MEMBER_ID, 44])
+            .call_and_assert(Err(Error::<Test>::MemberProfileNotFound.into()));
+    });
+}
+
+#[test]
+fn recovery_succeeds() {
+    build_test_externalities().execute_with(|| {
+        set_up_recovery_guardians();
+        SetRecoveryConfigFixture::default().call_and_assert(Ok(()));
+
+        assert_ok!(initiate_recovery_as_bob());
+        EventFixture::assert_last_crate_event(Event::<Test>::RecoveryInitiated(
+            ALICE_MEMBER_ID,
+            BOB_MEMBER_ID,
+            NEW_ROOT_ACCOUNT_ID,
+        ));
+
+        assert_err!(
+            finalize_alice_recovery(),
+            Error::<Test>::RecoveryNotExecutable
+        );
+
+        assert_ok!(approve_recovery_as_charlie());
+        let executable_at = 1 + RecoveryDelay::get();
+        EventFixture::assert_last_crate_event(Event::<Test>::RecoveryThresholdReached(
+            ALICE_MEMBER_ID,
+            executable_at,
+        ));
+
+        run_to_block(executable_at - 1);
+        assert_err!(
+            finalize_alice_recovery(),
+            Error::<Test>::RecoveryNotExecutable
+        );
+
+        run_to_block(executable_at);
+        assert_ok!(finalize_alice_recovery());
+
+        assert_eq!(
+            get_membership_by_id(ALICE_MEMBER_ID).root_account,
+            NEW_ROOT_ACCOUNT_ID
+        );
+        assert!(Membership::active_recovery(ALICE_MEMBER_ID).is_none());
+        EventFixture::assert_last_crate_event(Event::<Test>::RecoveryFinalized(
+            ALICE_MEMBER_ID,
+            NEW_ROOT_ACCOUNT_ID,
+        ));
+    });
+}
+
+#[test]
+fn initiate_recovery_fails_with_invalid_parameters() {
+    build_test_externalities().execute_with(|| {
+        set_up_recovery_guardians();
+
+        assert_err!(
+            initiate_recovery_as_bob(),
+            Error::<Test>::RecoveryConfigNotFound
+        );
+
+        SetRecoveryConfigFixture::default()
+            .with_guardians(vec![CHARLIE_MEMBER_ID])
+            .with_threshold(1)
+            .call_and_assert(Ok(()));
+
+        assert_err!(
+            initiate_recovery_as_bob(),
+            Error::<Test>::NotRecoveryGuardian
+        );
+
+        assert_err!(
+            Membership::initiate_recovery(
+                RawOrigin::Signed(BOB_ACCOUNT_ID).into(),
+                CHARLIE_MEMBER_ID,
+                ALICE_MEMBER_ID,
+                NEW_ROOT_ACCOUNT_ID,
+            ),
+            Error::<Test>::ControllerAccountRequired
+        );
+
+        assert_ok!(Membership::initiate_recovery(
+            RawOrigin::Signed(CHARLIE_ACCOUNT_ID).into(),
+            CHARLIE_MEMBER_ID,
+            ALICE_MEMBER_ID,
+            NEW_ROOT_ACCOUNT_ID,
+        ));
+
+        assert_err!(
+            Membership::initiate_recovery(
+                RawOrigin::Signed(CHARLIE_ACCOUNT_ID).into(),
+                CHARLIE_MEMBER_ID,
+                ALICE_MEMBER_ID,
+                NEW_ROOT_ACCOUNT_ID,
+            ),
+            Error::<Test>::RecoveryInProgress
+        );
+
+        // recovery in progress can't be bypassed with new guardians
+        SetRecoveryConfigFixture::default()
+            .call_and_assert(Err(Error::<Test>::RecoveryInProgress.into()));
+    });
+}
+
+#[test]
+fn approve_recovery_fails_with_invalid_parameters() {
+    build_test_externalities().execute_with(|| {
+        set_up_recovery_guardians();
+        SetRecoveryConfigFixture::default().call_and_assert(Ok(()));
+
+        assert_err!(
+            approve_recovery_as_charlie(),
+            Error::<Test>::RecoveryNotFound
+        );
+
+        assert_ok!(initiate_recovery_as_bob());
+
+        assert_err!(
+            Membership::approve_recovery(
+                RawOrigin::Signed(BOB_CONTROLLER_ACCOUNT_ID).into(),
+                BOB_MEMBER_ID,
+                ALICE_MEMBER_ID,
+            ),
+            Error::<Test>::RecoveryAlreadyApproved
+        );
+    });
+}
+
+#[test]
+fn cancel_recovery_succeeds() {
+    build_test_externalities().execute_with(|| {
+        set_up_recovery_guardians();
+        SetRecoveryConfigFixture::default().call_and_assert(Ok(()));
+
+        assert_ok!(initiate_recovery_as_bob());
+        assert_ok!(approve_recovery_as_charlie());
+
+        assert_err!(
+            Membership::cancel_recovery(
+                RawOrigin::Signed(BOB_CONTROLLER_ACCOUNT_ID).into(),
+                ALICE_MEMBER_ID
+            ),
+            Error::<Test>::RootAccountRequired
+        );
+
+        assert_ok!(Membership::cancel_recovery(
+            RawOrigin::Signed(ALICE_ACCOUNT_ID).into(),
+            ALICE_MEMBER_ID
+        ));
+        EventFixture::assert_last_crate_event(Event::<Test>::RecoveryCancelled(ALICE_MEMBER_ID));
+
+        run_to_block(1 + RecoveryDelay::get());
+
+        assert_err!(finalize_alice_recovery(), Error::<Test>::RecoveryNotFound);
+        assert_eq!(
+            get_membership_by_id(ALICE_MEMBER_ID).root_account,
+            ALICE_ACCOUNT_ID
+        );
+    });
+}
+
+#[test]
+fn initiate_recovery_fails_during_cooldown_after_cancellation() {
+    build_test_externalities().execute_with(|| {
+        set_up_recovery_guardians();
+        SetRecoveryConfigFixture::default().call_and_assert(Ok(()));
+
+        assert_ok!(initiate_recovery_as_bob());
+        assert_ok!(Membership::cancel_recovery(
+            RawOrigin::Signed(ALICE_ACCOUNT_ID).into(),
+            ALICE_MEMBER_ID
+        ));
+
+        assert_eq!(Membership::recovery_cancelled_at(ALICE_MEMBER_ID), Some(1));
+
+        run_to_block(RecoveryCooldown::get());
+        assert_err!(
+            initiate_recovery_as_bob(),
+            Error::<Test>::RecoveryCooldownNotPassed
+        );
+
+        run_to_block(1 + RecoveryCooldown::get());
+        assert_ok!(initiate_recovery_as_bob());
+
+        assert!(Membership::recovery_cancelled_at(ALICE_MEMBER_ID).is_none());
+    });
+}
+
+#[test]
+fn update_accounts_with_new_root_account_cancels_recovery() {
+    build_test_externalities().execute_with(|| {
+        set_up_recovery_guardians();
+        SetRecoveryConfigFixture::default().call_and_assert(Ok(()));
+
+        assert_ok!(initiate_recovery_as_bob());
+
+        const ALICE_NEW_ACCOUNT_ID: u64 = 11;
+
+        // new controller account doesn't affect the recovery
+        assert_ok!(Membership::update_accounts(
+            RawOrigin::Signed(ALICE_ACCOUNT_ID).into(),
+            ALICE_MEMBER_ID,
+            None,
+            Some(ALICE_NEW_ACCOUNT_ID),
+        ));
+        assert!(Membership::active_recovery(ALICE_MEMBER_ID).is_some());
+
+        assert_ok!(Membership::update_accounts(
+            RawOrigin::Signed(ALICE_ACCOUNT_ID).into(),
+            ALICE_MEMBER_ID,
+            Some(ALICE_NEW_ACCOUNT_ID),
+            None,
+        ));
+
+        assert!(Membership::active_recovery(ALICE_MEMBER_ID).is_none());
+        assert_eq!(Membership::recovery_cancelled_at(ALICE_MEMBER_ID), Some(1));
+
+        assert_err!(
+            approve_recovery_as_charlie(),
+            Error::<Test>::RecoveryNotFound
+        );
+    });
+}
+
+#[test]
+fn remove_recovery_config_succeeds() {
+    build_test_externalities().execute_with(|| {
+        set_up_recovery_guardians();
+        SetRecoveryConfigFixture::default().call_and_assert(Ok(()));
+
+        assert_ok!(initiate_recovery_as_bob());
+
+        assert_ok!(Membership::remove_recovery_config(
+            RawOrigin::Signed(ALICE_ACCOUNT_ID).into(),
+            ALICE_MEMBER_ID
+        ));
+
+        assert!(Membership::recovery_config(ALICE_MEMBER_ID).is_none());
+        assert!(Membership::active_recovery(ALICE_MEMBER_ID).is_none());
+        EventFixture::assert_last_crate_event(Event::<Test>::RecoveryConfigRemoved(
+            ALICE_MEMBER_ID,
+        ));
+
+        assert_err!(
+            Membership::remove_recovery_config(
+                RawOrigin::Signed(ALICE_ACCOUNT_ID).into(),
+                ALICE_MEMBER_ID
+            ),
+            Error::<Test>::RecoveryConfigNotFound
+        );
+    });
+}
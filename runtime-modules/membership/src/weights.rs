@@ -20,6 +20,9 @@
 //! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 4.0.0-dev
 //! DATE: 2023-11-07, STEPS: `50`, REPEAT: 20, LOW RANGE: `[]`, HIGH RANGE: `[]`
 //! EXECUTION: Some(Wasm), WASM-EXECUTION: Compiled, CHAIN: Some("prod-test"), DB CACHE: 1024
+//!
+//! NOTE: the weight functions marked as PLACEHOLDER are hand-written estimates, not the
+//! benchmark output. They must be regenerated with the benchmark CLI before merging.
 
 // Executed Command:
 // ./scripts/../target/release/joystream-node
@@ -66,11 +69,28 @@ pub trait WeightInfo {
 	fn member_remark_without_payment() -> Weight;
 	fn member_remark_with_payment() -> Weight;
 	fn create_member(_i: u32, _j: u32, ) -> Weight;
+	fn protect_handle(_i: u32, ) -> Weight;
+	fn unprotect_handle(_i: u32, ) -> Weight;
+	fn offer_handle() -> Weight;
+	fn cancel_handle_offer() -> Weight;
+	fn accept_handle_offer() -> Weight;
+	fn release_inactive_member_handle() -> Weight;
+	fn attest_member() -> Weight;
+	fn revoke_member_attestation() -> Weight;
+	fn remove_expired_member_attestation() -> Weight;
+	fn set_recovery_config(_i: u32, ) -> Weight;
+	fn remove_recovery_config() -> Weight;
+	fn initiate_recovery() -> Weight;
+	fn approve_recovery() -> Weight;
+	fn cancel_recovery() -> Weight;
+	fn finalize_recovery() -> Weight;
 }
 
 /// Weights for membership using the Substrate node and recommended hardware.
 pub struct SubstrateWeight<T>(PhantomData<T>);
 impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	// PLACEHOLDER: hand-written estimate, not the benchmark output.
+	// Must be regenerated with the benchmark CLI before merging.
 	// Storage: Membership MembershipPrice (r:1 w:0)
 	// Proof: Membership MembershipPrice (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
 	// Storage: System Account (r:1 w:1)
@@ -83,22 +103,22 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	// Proof: Membership NextMemberId (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
 	// Storage: Membership MembershipById (r:0 w:1)
 	// Proof: Membership MembershipById (max_values: None, max_size: Some(125), added: 2600, mode: MaxEncodedLen)
+	// Storage: Membership ProtectedHandleHashes (r:1 w:0)
+	// Proof: Membership ProtectedHandleHashes (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	// Storage: Membership MemberLastActivity (r:0 w:1)
+	// Proof: Membership MemberLastActivity (max_values: None, max_size: Some(28), added: 2503, mode: MaxEncodedLen)
 	/// The range of component `i` is `[0, 100]`.
 	/// The range of component `j` is `[0, 100]`.
 	fn buy_membership_without_referrer(i: u32, j: u32, ) -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `180`
-		//  Estimated: `11597`
-		// Minimum execution time: 124_066 nanoseconds.
 		Weight::from_parts(49_859_298, 0u64)
 			.saturating_add(Weight::from_parts(0, 11597))
-			// Standard Error: 9_133
 			.saturating_add(Weight::from_parts(1_621_936, 0u64).saturating_mul(i.into()))
-			// Standard Error: 9_133
 			.saturating_add(Weight::from_parts(820_862, 0u64).saturating_mul(j.into()))
-			.saturating_add(T::DbWeight::get().reads(5_u64))
-			.saturating_add(T::DbWeight::get().writes(4_u64))
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
 	}
+	// PLACEHOLDER: hand-written estimate, not the benchmark output.
+	// Must be regenerated with the benchmark CLI before merging.
 	// Storage: Membership MembershipPrice (r:1 w:0)
 	// Proof: Membership MembershipPrice (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
 	// Storage: System Account (r:1 w:1)
@@ -113,41 +133,41 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	// Proof: Membership NextMemberId (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
 	// Storage: Membership ReferralCut (r:1 w:0)
 	// Proof: Membership ReferralCut (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	// Storage: Membership ProtectedHandleHashes (r:1 w:0)
+	// Proof: Membership ProtectedHandleHashes (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	// Storage: Membership MemberLastActivity (r:0 w:1)
+	// Proof: Membership MemberLastActivity (max_values: None, max_size: Some(28), added: 2503, mode: MaxEncodedLen)
 	/// The range of component `i` is `[0, 100]`.
 	/// The range of component `j` is `[0, 100]`.
 	fn buy_membership_with_referrer(i: u32, j: u32, ) -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `459`
-		//  Estimated: `16673`
-		// Minimum execution time: 157_415 nanoseconds.
 		Weight::from_parts(67_131_450, 0u64)
 			.saturating_add(Weight::from_parts(0, 16673))
-			// Standard Error: 7_744
 			.saturating_add(Weight::from_parts(1_630_128, 0u64).saturating_mul(i.into()))
-			// Standard Error: 7_744
 			.saturating_add(Weight::from_parts(1_085_601, 0u64).saturating_mul(j.into()))
-			.saturating_add(T::DbWeight::get().reads(7_u64))
-			.saturating_add(T::DbWeight::get().writes(4_u64))
+			.saturating_add(T::DbWeight::get().reads(8_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
 	}
+	// PLACEHOLDER: hand-written estimate, not the benchmark output.
+	// Must be regenerated with the benchmark CLI before merging.
 	// Storage: Membership MembershipById (r:1 w:1)
 	// Proof: Membership MembershipById (max_values: None, max_size: Some(125), added: 2600, mode: MaxEncodedLen)
 	// Storage: Membership MemberIdByHandleHash (r:1 w:2)
 	// Proof: Membership MemberIdByHandleHash (max_values: None, max_size: Some(56), added: 2531, mode: MaxEncodedLen)
+	// Storage: Membership ProtectedHandleHashes (r:1 w:0)
+	// Proof: Membership ProtectedHandleHashes (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	// Storage: Membership MemberLastActivity (r:0 w:1)
+	// Proof: Membership MemberLastActivity (max_values: None, max_size: Some(28), added: 2503, mode: MaxEncodedLen)
+	// Storage: Membership HandleOfferByMemberId (r:0 w:1)
+	// Proof: Membership HandleOfferByMemberId (max_values: None, max_size: Some(81), added: 2556, mode: MaxEncodedLen)
 	/// The range of component `i` is `[0, 100]`.
 	/// The range of component `j` is `[0, 100]`.
 	fn update_profile(i: u32, j: u32, ) -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `265`
-		//  Estimated: `7111`
-		// Minimum execution time: 96_191 nanoseconds.
 		Weight::from_parts(30_922_914, 0u64)
 			.saturating_add(Weight::from_parts(0, 7111))
-			// Standard Error: 7_258
 			.saturating_add(Weight::from_parts(1_737_636, 0u64).saturating_mul(i.into()))
-			// Standard Error: 7_258
 			.saturating_add(Weight::from_parts(835_360, 0u64).saturating_mul(j.into()))
-			.saturating_add(T::DbWeight::get().reads(2_u64))
-			.saturating_add(T::DbWeight::get().writes(3_u64))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
 	}
 	fn update_accounts_none() -> Weight {
 		// Proof Size summary in bytes:
@@ -157,17 +177,19 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		Weight::from_parts(4_270_000, 0u64)
 			.saturating_add(Weight::from_parts(0, 0))
 	}
+	// PLACEHOLDER: hand-written estimate, not the benchmark output.
+	// Must be regenerated with the benchmark CLI before merging.
 	// Storage: Membership MembershipById (r:1 w:1)
 	// Proof: Membership MembershipById (max_values: None, max_size: Some(125), added: 2600, mode: MaxEncodedLen)
+	// Storage: Membership ActiveRecoveryByMemberId (r:1 w:1)
+	// Proof: Membership ActiveRecoveryByMemberId (max_values: None, max_size: Some(146), added: 2621, mode: MaxEncodedLen)
+	// Storage: Membership RecoveryCancelledAt (r:0 w:1)
+	// Proof: Membership RecoveryCancelledAt (max_values: None, max_size: Some(28), added: 2503, mode: MaxEncodedLen)
 	fn update_accounts_root() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `220`
-		//  Estimated: `3590`
-		// Minimum execution time: 18_599 nanoseconds.
-		Weight::from_parts(19_508_000, 0u64)
-			.saturating_add(Weight::from_parts(0, 3590))
-			.saturating_add(T::DbWeight::get().reads(1_u64))
-			.saturating_add(T::DbWeight::get().writes(1_u64))
+		Weight::from_parts(24_870_000, 0u64)
+			.saturating_add(Weight::from_parts(0, 7211))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
 	}
 	// Storage: Membership MembershipById (r:1 w:1)
 	// Proof: Membership MembershipById (max_values: None, max_size: Some(125), added: 2600, mode: MaxEncodedLen)
@@ -181,17 +203,19 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(1_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
+	// PLACEHOLDER: hand-written estimate, not the benchmark output.
+	// Must be regenerated with the benchmark CLI before merging.
 	// Storage: Membership MembershipById (r:1 w:1)
 	// Proof: Membership MembershipById (max_values: None, max_size: Some(125), added: 2600, mode: MaxEncodedLen)
+	// Storage: Membership ActiveRecoveryByMemberId (r:1 w:1)
+	// Proof: Membership ActiveRecoveryByMemberId (max_values: None, max_size: Some(146), added: 2621, mode: MaxEncodedLen)
+	// Storage: Membership RecoveryCancelledAt (r:0 w:1)
+	// Proof: Membership RecoveryCancelledAt (max_values: None, max_size: Some(28), added: 2503, mode: MaxEncodedLen)
 	fn update_accounts_both() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `220`
-		//  Estimated: `3590`
-		// Minimum execution time: 18_329 nanoseconds.
-		Weight::from_parts(19_186_000, 0u64)
-			.saturating_add(Weight::from_parts(0, 3590))
-			.saturating_add(T::DbWeight::get().reads(1_u64))
-			.saturating_add(T::DbWeight::get().writes(1_u64))
+		Weight::from_parts(24_540_000, 0u64)
+			.saturating_add(Weight::from_parts(0, 7211))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
 	}
 	// Storage: Membership ReferralCut (r:0 w:1)
 	// Proof: Membership ReferralCut (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
@@ -216,6 +240,8 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(2_u64))
 			.saturating_add(T::DbWeight::get().writes(2_u64))
 	}
+	// PLACEHOLDER: hand-written estimate, not the benchmark output.
+	// Must be regenerated with the benchmark CLI before merging.
 	// Storage: Membership MembershipById (r:1 w:2)
 	// Proof: Membership MembershipById (max_values: None, max_size: Some(125), added: 2600, mode: MaxEncodedLen)
 	// Storage: Membership MemberIdByHandleHash (r:1 w:1)
@@ -230,22 +256,22 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	// Proof: Membership NextMemberId (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
 	// Storage: System Account (r:1 w:1)
 	// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	// Storage: Membership ProtectedHandleHashes (r:1 w:0)
+	// Proof: Membership ProtectedHandleHashes (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	// Storage: Membership MemberLastActivity (r:0 w:1)
+	// Proof: Membership MemberLastActivity (max_values: None, max_size: Some(28), added: 2503, mode: MaxEncodedLen)
 	/// The range of component `i` is `[1, 100]`.
 	/// The range of component `j` is `[0, 100]`.
 	fn invite_member(i: u32, j: u32, ) -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `518`
-		//  Estimated: `19963`
-		// Minimum execution time: 143_149 nanoseconds.
 		Weight::from_parts(71_819_302, 0u64)
 			.saturating_add(Weight::from_parts(0, 19963))
-			// Standard Error: 7_766
 			.saturating_add(Weight::from_parts(1_606_300, 0u64).saturating_mul(i.into()))
-			// Standard Error: 7_674
 			.saturating_add(Weight::from_parts(798_967, 0u64).saturating_mul(j.into()))
-			.saturating_add(T::DbWeight::get().reads(7_u64))
-			.saturating_add(T::DbWeight::get().writes(7_u64))
+			.saturating_add(T::DbWeight::get().reads(8_u64))
+			.saturating_add(T::DbWeight::get().writes(8_u64))
 	}
+	// PLACEHOLDER: hand-written estimate, not the benchmark output.
+	// Must be regenerated with the benchmark CLI before merging.
 	// Storage: Membership MemberIdByHandleHash (r:1 w:1)
 	// Proof: Membership MemberIdByHandleHash (max_values: None, max_size: Some(56), added: 2531, mode: MaxEncodedLen)
 	// Storage: Membership MembershipPrice (r:1 w:0)
@@ -258,21 +284,19 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	// Proof: Membership NextMemberId (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
 	// Storage: Membership MembershipById (r:0 w:1)
 	// Proof: Membership MembershipById (max_values: None, max_size: Some(125), added: 2600, mode: MaxEncodedLen)
+	// Storage: Membership ProtectedHandleHashes (r:1 w:0)
+	// Proof: Membership ProtectedHandleHashes (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	// Storage: Membership MemberLastActivity (r:0 w:1)
+	// Proof: Membership MemberLastActivity (max_values: None, max_size: Some(28), added: 2503, mode: MaxEncodedLen)
 	/// The range of component `i` is `[1, 100]`.
 	/// The range of component `j` is `[0, 100]`.
 	fn gift_membership(i: u32, j: u32, ) -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `260`
-		//  Estimated: `23852`
-		// Minimum execution time: 203_829 nanoseconds.
 		Weight::from_parts(130_217_562, 0u64)
 			.saturating_add(Weight::from_parts(0, 23852))
-			// Standard Error: 9_437
 			.saturating_add(Weight::from_parts(1_629_554, 0u64).saturating_mul(i.into()))
-			// Standard Error: 9_325
 			.saturating_add(Weight::from_parts(833_591, 0u64).saturating_mul(j.into()))
-			.saturating_add(T::DbWeight::get().reads(8_u64))
-			.saturating_add(T::DbWeight::get().writes(8_u64))
+			.saturating_add(T::DbWeight::get().reads(9_u64))
+			.saturating_add(T::DbWeight::get().writes(9_u64))
 	}
 	// Storage: Membership MembershipPrice (r:0 w:1)
 	// Proof: Membership MembershipPrice (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
@@ -387,31 +411,34 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(4_u64))
 			.saturating_add(T::DbWeight::get().writes(3_u64))
 	}
+	// PLACEHOLDER: hand-written estimate, not the benchmark output.
+	// Must be regenerated with the benchmark CLI before merging.
 	// Storage: Membership MembershipById (r:1 w:0)
 	// Proof: Membership MembershipById (max_values: None, max_size: Some(125), added: 2600, mode: MaxEncodedLen)
+	// Storage: Membership MemberLastActivity (r:0 w:1)
+	// Proof: Membership MemberLastActivity (max_values: None, max_size: Some(28), added: 2503, mode: MaxEncodedLen)
 	fn member_remark_without_payment() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `220`
-		//  Estimated: `3590`
-		// Minimum execution time: 16_199 nanoseconds.
 		Weight::from_parts(16_750_000, 0u64)
 			.saturating_add(Weight::from_parts(0, 3590))
 			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
+	// PLACEHOLDER: hand-written estimate, not the benchmark output.
+	// Must be regenerated with the benchmark CLI before merging.
 	// Storage: Membership MembershipById (r:1 w:0)
 	// Proof: Membership MembershipById (max_values: None, max_size: Some(125), added: 2600, mode: MaxEncodedLen)
 	// Storage: System Account (r:2 w:2)
 	// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	// Storage: Membership MemberLastActivity (r:0 w:1)
+	// Proof: Membership MemberLastActivity (max_values: None, max_size: Some(28), added: 2503, mode: MaxEncodedLen)
 	fn member_remark_with_payment() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `446`
-		//  Estimated: `9786`
-		// Minimum execution time: 49_317 nanoseconds.
 		Weight::from_parts(50_654_000, 0u64)
 			.saturating_add(Weight::from_parts(0, 9786))
 			.saturating_add(T::DbWeight::get().reads(3_u64))
-			.saturating_add(T::DbWeight::get().writes(2_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
 	}
+	// PLACEHOLDER: hand-written estimate, not the benchmark output.
+	// Must be regenerated with the benchmark CLI before merging.
 	// Storage: Membership MemberIdByHandleHash (r:1 w:1)
 	// Proof: Membership MemberIdByHandleHash (max_values: None, max_size: Some(56), added: 2531, mode: MaxEncodedLen)
 	// Storage: Membership InitialInvitationCount (r:1 w:0)
@@ -420,22 +447,250 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	// Proof: Membership NextMemberId (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
 	// Storage: Membership MembershipById (r:0 w:1)
 	// Proof: Membership MembershipById (max_values: None, max_size: Some(125), added: 2600, mode: MaxEncodedLen)
+	// Storage: Membership MemberLastActivity (r:0 w:1)
+	// Proof: Membership MemberLastActivity (max_values: None, max_size: Some(28), added: 2503, mode: MaxEncodedLen)
 	/// The range of component `i` is `[1, 100]`.
 	/// The range of component `j` is `[0, 100]`.
 	fn create_member(i: u32, j: u32, ) -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `6`
-		//  Estimated: `6503`
-		// Minimum execution time: 125_594 nanoseconds.
 		Weight::from_parts(24_314_062, 0u64)
 			.saturating_add(Weight::from_parts(0, 6503))
-			// Standard Error: 7_340
 			.saturating_add(Weight::from_parts(1_988_519, 0u64).saturating_mul(i.into()))
-			// Standard Error: 7_253
 			.saturating_add(Weight::from_parts(1_087_322, 0u64).saturating_mul(j.into()))
 			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	// PLACEHOLDER: hand-written estimate, not the benchmark output.
+	// Must be regenerated with the benchmark CLI before merging.
+	// Storage: Instance6WorkingGroup CurrentLead (r:1 w:0)
+	// Proof: Instance6WorkingGroup CurrentLead (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	// Storage: Instance6WorkingGroup WorkerById (r:1 w:0)
+	// Proof: Instance6WorkingGroup WorkerById (max_values: None, max_size: Some(175), added: 2650, mode: MaxEncodedLen)
+	// Storage: Membership ProtectedHandleHashes (r:1 w:1)
+	// Proof: Membership ProtectedHandleHashes (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// The range of component `i` is `[1, 100]`.
+	fn protect_handle(i: u32, ) -> Weight {
+		Weight::from_parts(22_416_553, 0u64)
+			.saturating_add(Weight::from_parts(0, 8686))
+			.saturating_add(Weight::from_parts(1_412_306, 0u64).saturating_mul(i.into()))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	// PLACEHOLDER: hand-written estimate, not the benchmark output.
+	// Must be regenerated with the benchmark CLI before merging.
+	// Storage: Instance6WorkingGroup CurrentLead (r:1 w:0)
+	// Proof: Instance6WorkingGroup CurrentLead (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	// Storage: Instance6WorkingGroup WorkerById (r:1 w:0)
+	// Proof: Instance6WorkingGroup WorkerById (max_values: None, max_size: Some(175), added: 2650, mode: MaxEncodedLen)
+	// Storage: Membership ProtectedHandleHashes (r:1 w:1)
+	// Proof: Membership ProtectedHandleHashes (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// The range of component `i` is `[1, 100]`.
+	fn unprotect_handle(i: u32, ) -> Weight {
+		Weight::from_parts(23_107_912, 0u64)
+			.saturating_add(Weight::from_parts(0, 8686))
+			.saturating_add(Weight::from_parts(1_398_741, 0u64).saturating_mul(i.into()))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	// PLACEHOLDER: hand-written estimate, not the benchmark output.
+	// Must be regenerated with the benchmark CLI before merging.
+	// Storage: Membership MembershipById (r:2 w:0)
+	// Proof: Membership MembershipById (max_values: None, max_size: Some(125), added: 2600, mode: MaxEncodedLen)
+	// Storage: Membership MemberIdByHandleHash (r:1 w:0)
+	// Proof: Membership MemberIdByHandleHash (max_values: None, max_size: Some(56), added: 2531, mode: MaxEncodedLen)
+	// Storage: Membership ProtectedHandleHashes (r:1 w:0)
+	// Proof: Membership ProtectedHandleHashes (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	// Storage: Membership HandleOfferByMemberId (r:0 w:1)
+	// Proof: Membership HandleOfferByMemberId (max_values: None, max_size: Some(81), added: 2556, mode: MaxEncodedLen)
+	// Storage: Membership MemberLastActivity (r:0 w:1)
+	// Proof: Membership MemberLastActivity (max_values: None, max_size: Some(28), added: 2503, mode: MaxEncodedLen)
+	fn offer_handle() -> Weight {
+		Weight::from_parts(28_815_000, 0u64)
+			.saturating_add(Weight::from_parts(0, 12696))
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	// PLACEHOLDER: hand-written estimate, not the benchmark output.
+	// Must be regenerated with the benchmark CLI before merging.
+	// Storage: Membership MembershipById (r:1 w:0)
+	// Proof: Membership MembershipById (max_values: None, max_size: Some(125), added: 2600, mode: MaxEncodedLen)
+	// Storage: Membership HandleOfferByMemberId (r:1 w:1)
+	// Proof: Membership HandleOfferByMemberId (max_values: None, max_size: Some(81), added: 2556, mode: MaxEncodedLen)
+	// Storage: Membership MemberLastActivity (r:0 w:1)
+	// Proof: Membership MemberLastActivity (max_values: None, max_size: Some(28), added: 2503, mode: MaxEncodedLen)
+	fn cancel_handle_offer() -> Weight {
+		Weight::from_parts(20_102_000, 0u64)
+			.saturating_add(Weight::from_parts(0, 7136))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	// PLACEHOLDER: hand-written estimate, not the benchmark output.
+	// Must be regenerated with the benchmark CLI before merging.
+	// Storage: Membership HandleOfferByMemberId (r:1 w:2)
+	// Proof: Membership HandleOfferByMemberId (max_values: None, max_size: Some(81), added: 2556, mode: MaxEncodedLen)
+	// Storage: Membership MembershipById (r:2 w:2)
+	// Proof: Membership MembershipById (max_values: None, max_size: Some(125), added: 2600, mode: MaxEncodedLen)
+	// Storage: Membership MemberIdByHandleHash (r:2 w:2)
+	// Proof: Membership MemberIdByHandleHash (max_values: None, max_size: Some(56), added: 2531, mode: MaxEncodedLen)
+	// Storage: Membership ProtectedHandleHashes (r:2 w:0)
+	// Proof: Membership ProtectedHandleHashes (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	// Storage: System Account (r:2 w:2)
+	// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	// Storage: Membership MemberLastActivity (r:0 w:1)
+	// Proof: Membership MemberLastActivity (max_values: None, max_size: Some(28), added: 2503, mode: MaxEncodedLen)
+	fn accept_handle_offer() -> Weight {
+		Weight::from_parts(70_345_000, 0u64)
+			.saturating_add(Weight::from_parts(0, 25514))
+			.saturating_add(T::DbWeight::get().reads(9_u64))
+			.saturating_add(T::DbWeight::get().writes(9_u64))
+	}
+	// PLACEHOLDER: hand-written estimate, not the benchmark output.
+	// Must be regenerated with the benchmark CLI before merging.
+	// Storage: Membership MembershipById (r:1 w:1)
+	// Proof: Membership MembershipById (max_values: None, max_size: Some(125), added: 2600, mode: MaxEncodedLen)
+	// Storage: Membership MemberIdByHandleHash (r:1 w:1)
+	// Proof: Membership MemberIdByHandleHash (max_values: None, max_size: Some(56), added: 2531, mode: MaxEncodedLen)
+	// Storage: Membership ProtectedHandleHashes (r:1 w:0)
+	// Proof: Membership ProtectedHandleHashes (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	// Storage: Membership MemberLastActivity (r:1 w:0)
+	// Proof: Membership MemberLastActivity (max_values: None, max_size: Some(28), added: 2503, mode: MaxEncodedLen)
+	// Storage: Membership MemberActivityTrackedSince (r:1 w:0)
+	// Proof: Membership MemberActivityTrackedSince (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	// Storage: Membership HandleOfferByMemberId (r:0 w:1)
+	// Proof: Membership HandleOfferByMemberId (max_values: None, max_size: Some(81), added: 2556, mode: MaxEncodedLen)
+	fn release_inactive_member_handle() -> Weight {
+		Weight::from_parts(30_087_000, 0u64)
+			.saturating_add(Weight::from_parts(0, 14663))
+			.saturating_add(T::DbWeight::get().reads(5_u64))
 			.saturating_add(T::DbWeight::get().writes(3_u64))
 	}
+	// PLACEHOLDER: hand-written estimate, not the benchmark output.
+	// Must be regenerated with the benchmark CLI before merging.
+	// Storage: Instance6WorkingGroup WorkerById (r:2 w:0)
+	// Proof: Instance6WorkingGroup WorkerById (max_values: None, max_size: Some(175), added: 2650, mode: MaxEncodedLen)
+	// Storage: Membership MembershipById (r:1 w:0)
+	// Proof: Membership MembershipById (max_values: None, max_size: Some(125), added: 2600, mode: MaxEncodedLen)
+	// Storage: Membership MemberAttestations (r:1 w:1)
+	// Proof: Membership MemberAttestations (max_values: None, max_size: Some(58), added: 2533, mode: MaxEncodedLen)
+	// Storage: Instance6WorkingGroup CurrentLead (r:1 w:0)
+	// Proof: Instance6WorkingGroup CurrentLead (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	fn attest_member() -> Weight {
+		Weight::from_parts(30_112_000, 0u64)
+			.saturating_add(Weight::from_parts(0, 10266))
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	// PLACEHOLDER: hand-written estimate, not the benchmark output.
+	// Must be regenerated with the benchmark CLI before merging.
+	// Storage: Instance6WorkingGroup WorkerById (r:2 w:0)
+	// Proof: Instance6WorkingGroup WorkerById (max_values: None, max_size: Some(175), added: 2650, mode: MaxEncodedLen)
+	// Storage: Membership MemberAttestations (r:1 w:1)
+	// Proof: Membership MemberAttestations (max_values: None, max_size: Some(58), added: 2533, mode: MaxEncodedLen)
+	// Storage: Instance6WorkingGroup CurrentLead (r:1 w:0)
+	// Proof: Instance6WorkingGroup CurrentLead (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	fn revoke_member_attestation() -> Weight {
+		Weight::from_parts(26_270_000, 0u64)
+			.saturating_add(Weight::from_parts(0, 7666))
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	// PLACEHOLDER: hand-written estimate, not the benchmark output.
+	// Must be regenerated with the benchmark CLI before merging.
+	// Storage: Membership MemberAttestations (r:1 w:1)
+	// Proof: Membership MemberAttestations (max_values: None, max_size: Some(58), added: 2533, mode: MaxEncodedLen)
+	fn remove_expired_member_attestation() -> Weight {
+		Weight::from_parts(16_540_000, 0u64)
+			.saturating_add(Weight::from_parts(0, 3523))
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	// PLACEHOLDER: hand-written estimate, not the benchmark output.
+	// Must be regenerated with the benchmark CLI before merging.
+	// Storage: Membership MembershipById (r:6 w:0)
+	// Proof: Membership MembershipById (max_values: None, max_size: Some(125), added: 2600, mode: MaxEncodedLen)
+	// Storage: Membership ActiveRecoveryByMemberId (r:1 w:0)
+	// Proof: Membership ActiveRecoveryByMemberId (max_values: None, max_size: Some(146), added: 2621, mode: MaxEncodedLen)
+	// Storage: Membership RecoveryConfigByMemberId (r:0 w:1)
+	// Proof: Membership RecoveryConfigByMemberId (max_values: None, max_size: Some(109), added: 2584, mode: MaxEncodedLen)
+	/// The range of component `i` is `[1, 10]`.
+	fn set_recovery_config(i: u32, ) -> Weight {
+		Weight::from_parts(21_318_477, 0u64)
+			.saturating_add(Weight::from_parts(0, 9221))
+			.saturating_add(Weight::from_parts(4_126_930, 0u64).saturating_mul(i.into()))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(i.into())))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(Weight::from_parts(0, 2600).saturating_mul(i.into()))
+	}
+	// PLACEHOLDER: hand-written estimate, not the benchmark output.
+	// Must be regenerated with the benchmark CLI before merging.
+	// Storage: Membership MembershipById (r:1 w:0)
+	// Proof: Membership MembershipById (max_values: None, max_size: Some(125), added: 2600, mode: MaxEncodedLen)
+	// Storage: Membership RecoveryConfigByMemberId (r:1 w:1)
+	// Proof: Membership RecoveryConfigByMemberId (max_values: None, max_size: Some(109), added: 2584, mode: MaxEncodedLen)
+	// Storage: Membership ActiveRecoveryByMemberId (r:0 w:1)
+	// Proof: Membership ActiveRecoveryByMemberId (max_values: None, max_size: Some(146), added: 2621, mode: MaxEncodedLen)
+	fn remove_recovery_config() -> Weight {
+		Weight::from_parts(22_518_000, 0u64)
+			.saturating_add(Weight::from_parts(0, 9164))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	// PLACEHOLDER: hand-written estimate, not the benchmark output.
+	// Must be regenerated with the benchmark CLI before merging.
+	// Storage: Membership MembershipById (r:2 w:0)
+	// Proof: Membership MembershipById (max_values: None, max_size: Some(125), added: 2600, mode: MaxEncodedLen)
+	// Storage: Membership RecoveryConfigByMemberId (r:1 w:0)
+	// Proof: Membership RecoveryConfigByMemberId (max_values: None, max_size: Some(109), added: 2584, mode: MaxEncodedLen)
+	// Storage: Membership ActiveRecoveryByMemberId (r:1 w:1)
+	// Proof: Membership ActiveRecoveryByMemberId (max_values: None, max_size: Some(146), added: 2621, mode: MaxEncodedLen)
+	// Storage: Membership RecoveryCancelledAt (r:1 w:1)
+	// Proof: Membership RecoveryCancelledAt (max_values: None, max_size: Some(28), added: 2503, mode: MaxEncodedLen)
+	fn initiate_recovery() -> Weight {
+		Weight::from_parts(35_010_000, 0u64)
+			.saturating_add(Weight::from_parts(0, 17888))
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	// PLACEHOLDER: hand-written estimate, not the benchmark output.
+	// Must be regenerated with the benchmark CLI before merging.
+	// Storage: Membership MembershipById (r:2 w:0)
+	// Proof: Membership MembershipById (max_values: None, max_size: Some(125), added: 2600, mode: MaxEncodedLen)
+	// Storage: Membership RecoveryConfigByMemberId (r:1 w:0)
+	// Proof: Membership RecoveryConfigByMemberId (max_values: None, max_size: Some(109), added: 2584, mode: MaxEncodedLen)
+	// Storage: Membership ActiveRecoveryByMemberId (r:1 w:1)
+	// Proof: Membership ActiveRecoveryByMemberId (max_values: None, max_size: Some(146), added: 2621, mode: MaxEncodedLen)
+	fn approve_recovery() -> Weight {
+		Weight::from_parts(33_264_000, 0u64)
+			.saturating_add(Weight::from_parts(0, 14395))
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	// PLACEHOLDER: hand-written estimate, not the benchmark output.
+	// Must be regenerated with the benchmark CLI before merging.
+	// Storage: Membership MembershipById (r:1 w:0)
+	// Proof: Membership MembershipById (max_values: None, max_size: Some(125), added: 2600, mode: MaxEncodedLen)
+	// Storage: Membership ActiveRecoveryByMemberId (r:1 w:1)
+	// Proof: Membership ActiveRecoveryByMemberId (max_values: None, max_size: Some(146), added: 2621, mode: MaxEncodedLen)
+	// Storage: Membership RecoveryCancelledAt (r:0 w:1)
+	// Proof: Membership RecoveryCancelledAt (max_values: None, max_size: Some(28), added: 2503, mode: MaxEncodedLen)
+	fn cancel_recovery() -> Weight {
+		Weight::from_parts(22_410_000, 0u64)
+			.saturating_add(Weight::from_parts(0, 6581))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	// PLACEHOLDER: hand-written estimate, not the benchmark output.
+	// Must be regenerated with the benchmark CLI before merging.
+	// Storage: Membership MembershipById (r:1 w:1)
+	// Proof: Membership MembershipById (max_values: None, max_size: Some(125), added: 2600, mode: MaxEncodedLen)
+	// Storage: Membership ActiveRecoveryByMemberId (r:1 w:1)
+	// Proof: Membership ActiveRecoveryByMemberId (max_values: None, max_size: Some(146), added: 2621, mode: MaxEncodedLen)
+	fn finalize_recovery() -> Weight {
+		Weight::from_parts(23_190_000, 0u64)
+			.saturating_add(Weight::from_parts(0, 6581))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
 }
 
 // Default implementation for tests
@@ -506,4 +761,49 @@ impl WeightInfo for () {
 	fn create_member(i: u32, j: u32, ) -> Weight {
 		Weight::from_parts(0, 0)
 	}
+	fn protect_handle(i: u32, ) -> Weight {
+		Weight::from_parts(0, 0)
+	}
+	fn unprotect_handle(i: u32, ) -> Weight {
+		Weight::from_parts(0, 0)
+	}
+	fn offer_handle() -> Weight {
+		Weight::from_parts(0, 0)
+	}
+	fn cancel_handle_offer() -> Weight {
+		Weight::from_parts(0, 0)
+	}
+	fn accept_handle_offer() -> Weight {
+		Weight::from_parts(0, 0)
+	}
+	fn release_inactive_member_handle() -> Weight {
+		Weight::from_parts(0, 0)
+	}
+	fn attest_member() -> Weight {
+		Weight::from_parts(0, 0)
+	}
+	fn revoke_member_attestation() -> Weight {
+		Weight::from_parts(0, 0)
+	}
+	fn remove_expired_member_attestation() -> Weight {
+		Weight::from_parts(0, 0)
+	}
+	fn set_recovery_config(i: u32, ) -> Weight {
+		Weight::from_parts(0, 0)
+	}
+	fn remove_recovery_config() -> Weight {
+		Weight::from_parts(0, 0)
+	}
+	fn initiate_recovery() -> Weight {
+		Weight::from_parts(0, 0)
+	}
+	fn approve_recovery() -> Weight {
+		Weight::from_parts(0, 0)
+	}
+	fn cancel_recovery() -> Weight {
+		Weight::from_parts(0, 0)
+	}
+	fn finalize_recovery() -> Weight {
+		Weight::from_parts(0, 0)
+	}
 }
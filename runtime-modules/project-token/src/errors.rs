@@ -163,6 +163,19 @@ decl_error! {
         /// Amount of tokens to burn exceeds total amount of tokens owned by the account
         BurnAmountGreaterThanAccountTokensAmount,
 
+        // ------ Bridging -----------------------------------------------------
+
+        /// Token issuer did not opt in for bridging the token
+        BridgingNotEnabledForToken,
+
+        /// Tokens with a permissioned transfer policy cannot be bridged out
+        CannotBridgePermissionedToken,
+
+        // ------ Escrow -------------------------------------------------------
+
+        /// Attempt to release more tokens than escrowed
+        InsufficientEscrowedTokens,
+
         /// ------ AMM ---------------------------------------------------------
 
         /// not in AMM state
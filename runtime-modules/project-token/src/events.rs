@@ -187,6 +187,42 @@ decl_event! {
         /// - amm treasury amount burned upon deactivation
         AmmDeactivated(TokenId, MemberId, JoyBalance),
 
+        /// Token bridging status updated by the issuer
+        /// Params:
+        /// - token identifier
+        /// - whether bridging is enabled
+        BridgingStatusUpdated(TokenId, bool),
+
+        /// Tokens burned when bridged out to a remote chain
+        /// Params:
+        /// - token identifier
+        /// - id of the member burning the tokens
+        /// - number of tokens burned
+        BridgedTokensBurned(TokenId, MemberId, Balance),
+
+        /// Tokens minted when bridged in from a remote chain
+        /// Params:
+        /// - token identifier
+        /// - id of the member receiving the tokens
+        /// - number of tokens minted
+        BridgedTokensMinted(TokenId, MemberId, Balance),
+
+        /// Member tokens moved into an escrow held by another pallet
+        /// Params:
+        /// - token identifier
+        /// - id of the member whose tokens are escrowed
+        /// - escrow account
+        /// - number of tokens escrowed
+        TokensEscrowed(TokenId, MemberId, AccountId, Balance),
+
+        /// Escrowed tokens released into the member account
+        /// Params:
+        /// - token identifier
+        /// - escrow account
+        /// - id of the member receiving the tokens
+        /// - number of tokens released
+        EscrowedTokensReleased(TokenId, AccountId, MemberId, Balance),
+
         /// Pallet Frozen status toggled
         /// Params:
         /// - new frozen status (true | false)
@@ -169,6 +169,18 @@ decl_storage! { generate_storage_info
         /// Minimum slope parameters allowed for AMM curve
         pub MinAmmSlopeParameter get(fn min_amm_slope_parameter) config(): TokenBalanceOf<T> = TokenBalanceOf::<T>::from(1_000_000_u32);
 
+        /// Tokens whose issuer opted in for bridging them through the Argo bridge
+        pub BridgingEnabled get(fn bridging_enabled):
+        map
+            hasher(blake2_128_concat) T::TokenId => bool;
+
+        /// Tokens held in escrow by other pallets (ie. bounty funding), per token and
+        /// escrow account (ie. bounty account)
+        pub EscrowedTokens get(fn escrowed_tokens):
+        double_map
+            hasher(blake2_128_concat) T::TokenId,
+            hasher(blake2_128_concat) T::AccountId => TokenBalanceOf<T>;
+
         /// Current frozen state.
         pub PalletFrozen get(fn pallet_frozen) : bool;
     }
@@ -1082,6 +1094,7 @@ impl<T: Config>
         UploadContextOf<T>,
         TransferWithVestingOutputsOf<T>,
         AmmParamsOf<T>,
+        TokenBalanceOf<T>,
     > for Module<T>
 {
     /// Establish whether there's an unfinalized revenue split
@@ -1726,6 +1739,246 @@ impl<T: Config>
 
         Ok(())
     }
+
+    /// Allow or forbid bridging the token to remote chains
+    /// Preconditions
+    /// - token by `token_id` must exist
+    ///
+    /// Postconditions
+    /// - bridging status of `token_id` set to `enabled`
+    /// - event deposited
+    fn set_bridging_status(token_id: T::TokenId, enabled: bool) -> DispatchResult {
+        Self::ensure_unfrozen_state()?;
+
+        Self::ensure_token_exists(token_id).map(|_| ())?;
+
+        // == MUTATION SAFE ==
+
+        if enabled {
+            BridgingEnabled::<T>::insert(token_id, true);
+        } else {
+            BridgingEnabled::<T>::remove(token_id);
+        }
+
+        Self::deposit_event(RawEvent::BridgingStatusUpdated(token_id, enabled));
+
+        Ok(())
+    }
+
+    /// Establish whether the token issuer opted in for bridging
+    /// Postconditions: true if token @ token_id can be bridged out, false otherwise
+    fn is_bridging_enabled(token_id: T::TokenId) -> bool {
+        Self::bridging_enabled(token_id)
+    }
+
+    /// Burn member tokens being bridged out to a remote chain
+    /// Preconditions
+    /// - token by `token_id` must exist and have bridging enabled
+    /// - token transfer policy must be permissionless
+    /// - token supply can be modified
+    /// - account for `token_id` x `member_id` must exist
+    /// - `amount` must not exceed the account transferrable balance
+    ///
+    /// Postconditions
+    /// - account amount decreased by `amount`
+    /// - token supply decreased by `amount`
+    /// - event deposited
+    fn burn_bridged_tokens(
+        token_id: T::TokenId,
+        member_id: T::MemberId,
+        amount: TokenBalanceOf<T>,
+    ) -> DispatchResult {
+        Self::ensure_unfrozen_state()?;
+
+        let token_info = Self::ensure_token_exists(token_id)?;
+
+        ensure!(
+            Self::bridging_enabled(token_id),
+            Error::<T>::BridgingNotEnabledForToken
+        );
+
+        ensure!(
+            token_info.transfer_policy == TransferPolicyOf::<T>::Permissionless,
+            Error::<T>::CannotBridgePermissionedToken
+        );
+
+        token_info.ensure_can_modify_supply::<T>()?;
+
+        let account_info = Self::ensure_account_data_exists(token_id, &member_id)?;
+
+        account_info.ensure_can_transfer::<T>(Self::current_block(), amount)?;
+
+        // == MUTATION SAFE ==
+
+        AccountInfoByTokenAndMember::<T>::mutate(token_id, member_id, |account| {
+            account.decrease_amount_by(amount);
+        });
+
+        TokenInfoById::<T>::mutate(token_id, |token| {
+            token.decrease_supply_by(amount);
+        });
+
+        Self::deposit_event(RawEvent::BridgedTokensBurned(token_id, member_id, amount));
+
+        Ok(())
+    }
+
+    /// Mint tokens bridged in from a remote chain into the member account
+    /// Preconditions
+    /// - token by `token_id` must exist
+    /// - token supply can be modified
+    /// - account for `token_id` x `member_id` must exist, so that for a permissioned
+    ///   token only whitelisted members can receive the tokens
+    ///
+    /// Postconditions
+    /// - account amount increased by `amount`
+    /// - token total supply increased by `amount`
+    /// - event deposited
+    fn mint_bridged_tokens(
+        token_id: T::TokenId,
+        member_id: T::MemberId,
+        amount: TokenBalanceOf<T>,
+    ) -> DispatchResult {
+        Self::ensure_unfrozen_state()?;
+
+        let token_info = Self::ensure_token_exists(token_id)?;
+
+        token_info.ensure_can_modify_supply::<T>()?;
+
+        Self::ensure_account_data_exists(token_id, &member_id).map(|_| ())?;
+
+        // == MUTATION SAFE ==
+
+        AccountInfoByTokenAndMember::<T>::mutate(token_id, member_id, |account| {
+            account.increase_amount_by(amount);
+        });
+
+        // bridged tokens were already issued, only the supply is restored
+        TokenInfoById::<T>::mutate(token_id, |token| {
+            token.restore_supply_by(amount);
+        });
+
+        Self::deposit_event(RawEvent::BridgedTokensMinted(token_id, member_id, amount));
+
+        Ok(())
+    }
+
+    /// Establish whether the token exists
+    /// Postconditions: true if token @ token_id exists, false otherwise
+    fn token_exists(token_id: T::TokenId) -> bool {
+        TokenInfoById::<T>::contains_key(token_id)
+    }
+
+    /// Move member tokens into the escrow account held by another pallet (ie. bounty funding)
+    /// Preconditions
+    /// - token by `token_id` must exist
+    /// - account for `token_id` x `member_id` must exist
+    /// - `amount` must not exceed the account transferrable balance
+    ///
+    /// Postconditions
+    /// - account amount decreased by `amount`, token supply is unchanged
+    /// - escrowed tokens of `token_id` x `escrow_account` increased by `amount`
+    /// - event deposited
+    fn escrow_member_tokens(
+        token_id: T::TokenId,
+        member_id: T::MemberId,
+        escrow_account: T::AccountId,
+        amount: TokenBalanceOf<T>,
+    ) -> DispatchResult {
+        Self::ensure_unfrozen_state()?;
+
+        Self::ensure_token_exists(token_id).map(|_| ())?;
+
+        let account_info = Self::ensure_account_data_exists(token_id, &member_id)?;
+
+        account_info.ensure_can_transfer::<T>(Self::current_block(), amount)?;
+
+        // == MUTATION SAFE ==
+
+        AccountInfoByTokenAndMember::<T>::mutate(token_id, member_id, |account| {
+            account.decrease_amount_by(amount);
+        });
+
+        EscrowedTokens::<T>::mutate(token_id, &escrow_account, |escrowed| {
+            *escrowed = escrowed.saturating_add(amount);
+        });
+
+        Self::deposit_event(RawEvent::TokensEscrowed(
+            token_id,
+            member_id,
+            escrow_account,
+            amount,
+        ));
+
+        Ok(())
+    }
+
+    /// Ensure the tokens of the escrow account can be released into the member account
+    /// Preconditions
+    /// - token by `token_id` must exist
+    /// - account for `token_id` x `member_id` must exist, so that for a permissioned
+    ///   token only whitelisted members can receive the tokens
+    /// - `amount` must not exceed the escrowed tokens of `token_id` x `escrow_account`
+    fn ensure_can_release_escrowed_tokens(
+        token_id: T::TokenId,
+        escrow_account: T::AccountId,
+        member_id: T::MemberId,
+        amount: TokenBalanceOf<T>,
+    ) -> DispatchResult {
+        Self::ensure_unfrozen_state()?;
+
+        Self::ensure_token_exists(token_id).map(|_| ())?;
+
+        Self::ensure_account_data_exists(token_id, &member_id)?;
+
+        ensure!(
+            amount <= Self::escrowed_tokens(token_id, &escrow_account),
+            Error::<T>::InsufficientEscrowedTokens,
+        );
+
+        Ok(())
+    }
+
+    /// Release the tokens of the escrow account into the member account
+    /// Preconditions
+    /// - escrowed tokens can be released into the member account
+    ///
+    /// Postconditions
+    /// - account amount increased by `amount`, token supply is unchanged
+    /// - escrowed tokens of `token_id` x `escrow_account` decreased by `amount`
+    /// - event deposited
+    fn release_escrowed_tokens(
+        token_id: T::TokenId,
+        escrow_account: T::AccountId,
+        member_id: T::MemberId,
+        amount: TokenBalanceOf<T>,
+    ) -> DispatchResult {
+        Self::ensure_can_release_escrowed_tokens(
+            token_id,
+            escrow_account.clone(),
+            member_id,
+            amount,
+        )?;
+
+        // == MUTATION SAFE ==
+
+        AccountInfoByTokenAndMember::<T>::mutate(token_id, member_id, |account| {
+            account.increase_amount_by(amount);
+        });
+
+        EscrowedTokens::<T>::mutate(token_id, &escrow_account, |escrowed| {
+            *escrowed = escrowed.saturating_sub(amount);
+        });
+
+        Self::deposit_event(RawEvent::EscrowedTokensReleased(
+            token_id,
+            escrow_account,
+            member_id,
+            amount,
+        ));
+
+        Ok(())
+    }
 }
 
 /// Module implementation
@@ -1754,6 +2007,7 @@ impl<T: Config> Module<T> {
     /// Perform token de-issuing: unfallible
     pub(crate) fn do_deissue_token(token_id: T::TokenId) {
         TokenInfoById::<T>::remove(token_id);
+        BridgingEnabled::<T>::remove(token_id);
         // TODO: add extra state removal as implementation progresses
     }
 
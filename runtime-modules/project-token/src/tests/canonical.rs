@@ -1904,3 +1904,320 @@ fn join_whitelist_fails_on_frozen_pallet() {
         ));
     })
 }
+
+#[test]
+fn set_bridging_status_fails_with_invalid_token_id() {
+    let token_id = token!(1);
+    let token_data = TokenDataBuilder::new_empty().build();
+
+    let config = GenesisConfigBuilder::new_empty()
+        .with_token(token_id, token_data)
+        .build();
+
+    build_test_externalities(config).execute_with(|| {
+        let result = Token::set_bridging_status(token_id + 1, true);
+
+        assert_noop!(result, Error::<Test>::TokenDoesNotExist);
+    })
+}
+
+#[test]
+fn set_bridging_status_ok() {
+    let token_id = token!(1);
+    let token_data = TokenDataBuilder::new_empty().build();
+
+    let config = GenesisConfigBuilder::new_empty()
+        .with_token(token_id, token_data)
+        .build();
+
+    build_test_externalities(config).execute_with(|| {
+        let result = Token::set_bridging_status(token_id, true);
+
+        assert_ok!(result);
+        assert!(Token::is_bridging_enabled(token_id));
+        last_event_eq!(RawEvent::BridgingStatusUpdated(token_id, true));
+    })
+}
+
+#[test]
+fn burn_bridged_tokens_fails_with_bridging_not_enabled() {
+    let (token_id, amount, member_id) = (token!(1), balance!(100), member!(1).0);
+    let token_data = TokenDataBuilder::new_empty().build();
+
+    let config = GenesisConfigBuilder::new_empty()
+        .with_token(token_id, token_data)
+        .with_account(member_id, ConfigAccountData::new_with_amount(amount))
+        .build();
+
+    build_test_externalities(config).execute_with(|| {
+        let result = Token::burn_bridged_tokens(token_id, member_id, amount);
+
+        assert_noop!(result, Error::<Test>::BridgingNotEnabledForToken);
+    })
+}
+
+#[test]
+fn burn_bridged_tokens_fails_with_permissioned_token() {
+    let (token_id, amount, member_id) = (token!(1), balance!(100), member!(1).0);
+    let commit = merkle_root![member_id];
+    let token_data = TokenDataBuilder::new_empty()
+        .with_transfer_policy(Policy::Permissioned(commit))
+        .build();
+
+    let config = GenesisConfigBuilder::new_empty()
+        .with_token(token_id, token_data)
+        .with_account(member_id, ConfigAccountData::new_with_amount(amount))
+        .build();
+
+    build_test_externalities(config).execute_with(|| {
+        assert_ok!(Token::set_bridging_status(token_id, true));
+
+        let result = Token::burn_bridged_tokens(token_id, member_id, amount);
+
+        assert_noop!(result, Error::<Test>::CannotBridgePermissionedToken);
+    })
+}
+
+#[test]
+fn burn_bridged_tokens_fails_with_insufficient_transferrable_balance() {
+    let (token_id, amount, member_id) = (token!(1), balance!(100), member!(1).0);
+    let token_data = TokenDataBuilder::new_empty().build();
+
+    let config = GenesisConfigBuilder::new_empty()
+        .with_token(token_id, token_data)
+        .with_account(
+            member_id,
+            ConfigAccountData::new_with_amount(amount).with_staked(amount),
+        )
+        .build();
+
+    build_test_externalities(config).execute_with(|| {
+        assert_ok!(Token::set_bridging_status(token_id, true));
+
+        let result = Token::burn_bridged_tokens(token_id, member_id, amount);
+
+        assert_noop!(result, Error::<Test>::InsufficientTransferrableBalance);
+    })
+}
+
+#[test]
+fn burn_bridged_tokens_ok() {
+    let (token_id, amount, member_id) = (token!(1), balance!(100), member!(1).0);
+    let token_data = TokenDataBuilder::new_empty().build();
+
+    let config = GenesisConfigBuilder::new_empty()
+        .with_token(token_id, token_data)
+        .with_account(member_id, ConfigAccountData::new_with_amount(amount))
+        .build();
+
+    build_test_externalities(config).execute_with(|| {
+        assert_ok!(Token::set_bridging_status(token_id, true));
+
+        let result = Token::burn_bridged_tokens(token_id, member_id, amount);
+
+        assert_ok!(result);
+        let acc_data = Token::ensure_account_data_exists(token_id, &member_id).unwrap();
+        assert_eq!(acc_data.amount, 0);
+        assert_eq!(Token::token_info_by_id(token_id).total_supply, 0);
+        last_event_eq!(RawEvent::BridgedTokensBurned(token_id, member_id, amount));
+    })
+}
+
+#[test]
+fn mint_bridged_tokens_fails_with_non_existing_account() {
+    let (token_id, amount, member_id) = (token!(1), balance!(100), member!(1).0);
+    let token_data = TokenDataBuilder::new_empty().build();
+
+    let config = GenesisConfigBuilder::new_empty()
+        .with_token(token_id, token_data)
+        .build();
+
+    build_test_externalities(config).execute_with(|| {
+        let result = Token::mint_bridged_tokens(token_id, member_id, amount);
+
+        assert_noop!(result, Error::<Test>::AccountInformationDoesNotExist);
+    })
+}
+
+#[test]
+fn mint_bridged_tokens_ok() {
+    let (token_id, amount, member_id) = (token!(1), balance!(100), member!(1).0);
+    let token_data = TokenDataBuilder::new_empty().build();
+
+    let config = GenesisConfigBuilder::new_empty()
+        .with_token(token_id, token_data)
+        .with_account(member_id, ConfigAccountData::new_with_amount(amount))
+        .build();
+
+    build_test_externalities(config).execute_with(|| {
+        assert_ok!(Token::set_bridging_status(token_id, true));
+        assert_ok!(Token::burn_bridged_tokens(token_id, member_id, amount));
+
+        let result = Token::mint_bridged_tokens(token_id, member_id, amount);
+
+        assert_ok!(result);
+        let acc_data = Token::ensure_account_data_exists(token_id, &member_id).unwrap();
+        assert_eq!(acc_data.amount, amount);
+        let token_data = Token::token_info_by_id(token_id);
+        assert_eq!(token_data.total_supply, amount);
+        assert_eq!(token_data.tokens_issued, amount);
+        last_event_eq!(RawEvent::BridgedTokensMinted(token_id, member_id, amount));
+    })
+}
+
+#[test]
+fn escrow_member_tokens_fails_with_insufficient_transferrable_balance() {
+    let (token_id, amount, member_id) = (token!(1), balance!(100), member!(1).0);
+    let escrow_account = account!(1);
+    let token_data = TokenDataBuilder::new_empty().build();
+
+    let config = GenesisConfigBuilder::new_empty()
+        .with_token(token_id, token_data)
+        .with_account(
+            member_id,
+            ConfigAccountData::new_with_amount(amount).with_staked(amount),
+        )
+        .build();
+
+    build_test_externalities(config).execute_with(|| {
+        let result = Token::escrow_member_tokens(token_id, member_id, escrow_account, amount);
+
+        assert_noop!(result, Error::<Test>::InsufficientTransferrableBalance);
+    })
+}
+
+#[test]
+fn escrow_member_tokens_ok() {
+    let (token_id, amount, member_id) = (token!(1), balance!(100), member!(1).0);
+    let escrow_account = account!(1);
+    let token_data = TokenDataBuilder::new_empty().build();
+
+    let config = GenesisConfigBuilder::new_empty()
+        .with_token(token_id, token_data)
+        .with_account(member_id, ConfigAccountData::new_with_amount(amount))
+        .build();
+
+    build_test_externalities(config).execute_with(|| {
+        let result = Token::escrow_member_tokens(token_id, member_id, escrow_account, amount);
+
+        assert_ok!(result);
+        let acc_data = Token::ensure_account_data_exists(token_id, &member_id).unwrap();
+        assert_eq!(acc_data.amount, 0);
+        assert_eq!(Token::token_info_by_id(token_id).total_supply, amount);
+        assert_eq!(Token::escrowed_tokens(token_id, escrow_account), amount);
+        last_event_eq!(RawEvent::TokensEscrowed(
+            token_id,
+            member_id,
+            escrow_account,
+            amount
+        ));
+    })
+}
+
+#[test]
+fn release_escrowed_tokens_fails_with_non_whitelisted_member() {
+    let (token_id, amount, member_id) = (token!(1), balance!(100), member!(1).0);
+    let escrow_account = account!(1);
+    let commit = merkle_root![member_id];
+    let token_data = TokenDataBuilder::new_empty()
+        .with_transfer_policy(Policy::Permissioned(commit))
+        .build();
+
+    let config = GenesisConfigBuilder::new_empty()
+        .with_token(token_id, token_data)
+        .build();
+
+    build_test_externalities(config).execute_with(|| {
+        let result = Token::release_escrowed_tokens(token_id, escrow_account, member_id, amount);
+
+        assert_noop!(result, Error::<Test>::AccountInformationDoesNotExist);
+    })
+}
+
+#[test]
+fn release_escrowed_tokens_ok() {
+    let (token_id, amount, member_id) = (token!(1), balance!(100), member!(1).0);
+    let escrow_account = account!(1);
+    let token_data = TokenDataBuilder::new_empty().build();
+
+    let config = GenesisConfigBuilder::new_empty()
+        .with_token(token_id, token_data)
+        .with_account(member_id, ConfigAccountData::new_with_amount(amount))
+        .build();
+
+    build_test_externalities(config).execute_with(|| {
+        assert_ok!(Token::escrow_member_tokens(
+            token_id,
+            member_id,
+            escrow_account,
+            amount
+        ));
+
+        let result = Token::release_escrowed_tokens(token_id, escrow_account, member_id, amount);
+
+        assert_ok!(result);
+        let acc_data = Token::ensure_account_data_exists(token_id, &member_id).unwrap();
+        assert_eq!(acc_data.amount, amount);
+        assert_eq!(Token::token_info_by_id(token_id).total_supply, amount);
+        assert_eq!(Token::escrowed_tokens(token_id, escrow_account), 0);
+        last_event_eq!(RawEvent::EscrowedTokensReleased(
+            token_id,
+            escrow_account,
+            member_id,
+            amount
+        ));
+    })
+}
+
+#[test]
+fn release_escrowed_tokens_fails_with_insufficient_escrowed_tokens() {
+    let (token_id, amount, member_id) = (token!(1), balance!(100), member!(1).0);
+    let escrow_account = account!(1);
+    let token_data = TokenDataBuilder::new_empty().build();
+
+    let config = GenesisConfigBuilder::new_empty()
+        .with_token(token_id, token_data)
+        .with_account(member_id, ConfigAccountData::new_with_amount(amount))
+        .build();
+
+    build_test_externalities(config).execute_with(|| {
+        assert_ok!(Token::escrow_member_tokens(
+            token_id,
+            member_id,
+            escrow_account,
+            amount
+        ));
+
+        let result =
+            Token::release_escrowed_tokens(token_id, escrow_account, member_id, amount + 1);
+
+        assert_noop!(result, Error::<Test>::InsufficientEscrowedTokens);
+    })
+}
+
+#[test]
+fn release_escrowed_tokens_fails_with_tokens_escrowed_by_other_account() {
+    let (token_id, amount, member_id) = (token!(1), balance!(100), member!(1).0);
+    let (escrow_account, other_escrow_account) = (account!(1), account!(2));
+    let token_data = TokenDataBuilder::new_empty().build();
+
+    let config = GenesisConfigBuilder::new_empty()
+        .with_token(token_id, token_data)
+        .with_account(member_id, ConfigAccountData::new_with_amount(amount))
+        .build();
+
+    build_test_externalities(config).execute_with(|| {
+        assert_ok!(Token::escrow_member_tokens(
+            token_id,
+            member_id,
+            escrow_account,
+            amount
+        ));
+
+        let result =
+            Token::release_escrowed_tokens(token_id, other_escrow_account, member_id, amount);
+
+        assert_noop!(result, Error::<Test>::InsufficientEscrowedTokens);
+        assert_eq!(Token::escrowed_tokens(token_id, escrow_account), amount);
+    })
+}
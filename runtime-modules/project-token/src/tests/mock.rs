@@ -110,6 +110,10 @@ parameter_types! {
     pub const CandidateStake: u64 = 100;
     pub const DefaultInitialInvitationBalance: u64 = 100;
     pub const DefaultMemberInvitesCount: u32 = 2;
+    pub const HandleReclaimCooldown: u64 = 1000;
+    pub const MaxRecoveryGuardians: u32 = 5;
+    pub const RecoveryDelay: u64 = 100;
+    pub const RecoveryCooldown: u64 = 50;
     pub const ReferralCutMaximumPercent: u8 = 50;
 }
 
@@ -394,6 +398,10 @@ impl membership::Config for Test {
     type CandidateStake = CandidateStake;
     type WeightInfo = ();
     type DefaultMemberInvitesCount = DefaultMemberInvitesCount;
+    type HandleReclaimCooldown = HandleReclaimCooldown;
+    type MaxRecoveryGuardians = MaxRecoveryGuardians;
+    type RecoveryDelay = RecoveryDelay;
+    type RecoveryCooldown = RecoveryCooldown;
 }
 
 impl LockComparator<u128> for Test {
@@ -482,6 +490,13 @@ impl MembershipInfoProvider<Test> for TestMemberships {
             Err(DispatchError::Other("no account found"))
         })
     }
+
+    fn has_valid_attestation(
+        member_id: common::MemberId<Test>,
+        kind: common::membership::AttestationKind,
+    ) -> bool {
+        membership::Module::<Test>::has_valid_attestation(member_id, kind)
+    }
 }
 
 // Mock MemberOriginValidator impl
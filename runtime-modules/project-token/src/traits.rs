@@ -15,6 +15,7 @@ pub trait PalletToken<
     UploadContext,
     TransfersWithVesting,
     AmmParams,
+    TokenBalance,
 >
 {
     /// Issue token with specified characteristics
@@ -91,4 +92,51 @@ pub trait PalletToken<
 
     /// Deactivate Amm functionality for the token
     fn deactivate_amm(token_id: TokenId, member_id: MemberId) -> DispatchResult;
+
+    /// Allow or forbid bridging the token to remote chains
+    fn set_bridging_status(token_id: TokenId, enabled: bool) -> DispatchResult;
+
+    /// Establish whether the token issuer opted in for bridging
+    fn is_bridging_enabled(token_id: TokenId) -> bool;
+
+    /// Burn member tokens being bridged out to a remote chain
+    fn burn_bridged_tokens(
+        token_id: TokenId,
+        member_id: MemberId,
+        amount: TokenBalance,
+    ) -> DispatchResult;
+
+    /// Mint tokens bridged in from a remote chain into the member account
+    fn mint_bridged_tokens(
+        token_id: TokenId,
+        member_id: MemberId,
+        amount: TokenBalance,
+    ) -> DispatchResult;
+
+    /// Establish whether the token exists
+    fn token_exists(token_id: TokenId) -> bool;
+
+    /// Move member tokens into the escrow account held by another pallet
+    fn escrow_member_tokens(
+        token_id: TokenId,
+        member_id: MemberId,
+        escrow_account: AccountId,
+        amount: TokenBalance,
+    ) -> DispatchResult;
+
+    /// Ensure the tokens of the escrow account can be released into the member account
+    fn ensure_can_release_escrowed_tokens(
+        token_id: TokenId,
+        escrow_account: AccountId,
+        member_id: MemberId,
+        amount: TokenBalance,
+    ) -> DispatchResult;
+
+    /// Release the tokens of the escrow account into the member account
+    fn release_escrowed_tokens(
+        token_id: TokenId,
+        escrow_account: AccountId,
+        member_id: MemberId,
+        amount: TokenBalance,
+    ) -> DispatchResult;
 }
@@ -1348,6 +1348,11 @@ where
         self.total_supply = self.total_supply.saturating_sub(amount);
     }
 
+    // increase total supply without issuing new tokens
+    pub(crate) fn restore_supply_by(&mut self, amount: Balance) {
+        self.total_supply = self.total_supply.saturating_add(amount);
+    }
+
     // ensure token supply can be modified
     pub(crate) fn ensure_can_modify_supply<T: Config>(&self) -> DispatchResult {
         ensure!(
@@ -6,6 +6,7 @@ use super::*;
 use crate::Module as Codex;
 use balances::Pallet as Balances;
 
+use common::referendum::VotePowerStrategy;
 use common::to_kb;
 use common::working_group::WorkingGroup;
 use common::BalanceKind;
@@ -968,14 +969,58 @@ benchmarks! {
         let pauser_accounts: Vec<T::AccountId> = (0..T::MaxPauserAccounts::get())
         .map(|i| account::<T::AccountId>("pauser", 0, SEED))
         .collect();
+        let attester_accounts: Vec<T::AccountId> = (0..T::MaxAttesterAccounts::get())
+        .map(|i| account::<T::AccountId>("attester", i, SEED))
+        .collect();
         let chains: Vec<argo_bridge::types::ChainId> = (0u32..argo_bridge::types::MAX_REMOTE_CHAINS).collect();
+        let rate_limit = argo_bridge::types::RateLimit {
+            max_amount: 1_000_000u32.into(),
+            window: 100u32.into(),
+        };
+        let rate_limits: Vec<_> = (0u32..argo_bridge::types::MAX_RATE_LIMITS)
+            .map(|i| {
+                let direction = if i % 2 == 0 {
+                    argo_bridge::types::TransferDirection::Inbound
+                } else {
+                    argo_bridge::types::TransferDirection::Outbound
+                };
+                let chain_id = (i / 2).checked_sub(1);
+                (
+                    argo_bridge::types::RateLimitKey {
+                        direction,
+                        chain_id,
+                        token_id: None,
+                    },
+                    rate_limit,
+                )
+            })
+            .collect();
+        let fee_schedule = argo_bridge::types::FeeSchedule {
+            flat_fee: 100u32.into(),
+            percentage_fee: Permill::from_percent(1),
+        };
+        let remote_chain_fees: Vec<_> = chains
+            .iter()
+            .map(|chain_id| (*chain_id, Some(fee_schedule)))
+            .collect();
         let proposal_details = ProposalDetails::UpdateArgoBridgeConstraints(
             argo_bridge::types::BridgeConstraints {
                 operator_account: Some(account::<T::AccountId>("operator", 0, SEED)),
                 pauser_accounts: Some(pauser_accounts),
                 bridging_fee: Some(100u32.into()),
                 thawn_duration: Some(1u32.into()),
-                remote_chains: Some(chains.try_into().unwrap())
+                remote_chains: Some(chains.try_into().unwrap()),
+                attester_accounts: Some(attester_accounts),
+                attestation_threshold: Some(T::MaxAttesterAccounts::get()),
+                attestation_ttl: Some(100u32.into()),
+                rate_limits: Some(rate_limits.try_into().unwrap()),
+                remote_chain_fees: Some(remote_chain_fees.try_into().unwrap()),
+                fee_distribution: Some(argo_bridge::types::FeeDistribution {
+                    council_budget_share: Permill::from_percent(30),
+                    operator_reward_share: Permill::from_percent(30),
+                }),
+                operator_reward_account: Some(account::<T::AccountId>("operator", 1, SEED)),
+                creator_token_mint_allowances: None
             }
         );
     }: create_proposal(
@@ -1035,6 +1080,243 @@ benchmarks! {
             proposal_details
         );
     }
+
+    create_proposal_overturn_bounty_judgment {
+        let t in 1 .. to_kb(T::TitleMaxLength::get());
+        let d in 1 .. to_kb(T::DescriptionMaxLength::get());
+
+        let (account_id, member_id, general_proposal_paramters) =
+            create_proposal_parameters::<T>(t, d);
+
+        let proposal_details = ProposalDetails::OverturnBountyJudgment(1u32.into());
+    }: create_proposal(
+        RawOrigin::Signed(account_id.clone()),
+        general_proposal_paramters.clone(),
+        proposal_details.clone()
+    )
+    verify {
+        create_proposal_verify::<T>(
+            account_id,
+            member_id,
+            general_proposal_paramters,
+            proposal_details
+        );
+    }
+
+    create_proposal_revoke_working_group_deputy {
+        let t in 1 .. to_kb(T::TitleMaxLength::get());
+        let d in 1 .. to_kb(T::DescriptionMaxLength::get());
+
+        let (account_id, member_id, general_proposal_paramters) =
+            create_proposal_parameters::<T>(t, d);
+
+        let proposal_details = ProposalDetails::RevokeWorkingGroupDeputy(
+            working_group::WorkerId::<T>::one(),
+            WorkingGroup::Forum,
+        );
+    }: create_proposal(
+        RawOrigin::Signed(account_id.clone()),
+        general_proposal_paramters.clone(),
+        proposal_details.clone()
+    )
+    verify {
+        create_proposal_verify::<T>(
+            account_id,
+            member_id,
+            general_proposal_paramters,
+            proposal_details
+        );
+    }
+
+    create_proposal_transfer_working_group_worker {
+        let t in 1 .. to_kb(T::TitleMaxLength::get());
+        let d in 1 .. to_kb(T::DescriptionMaxLength::get());
+
+        let (account_id, member_id, general_proposal_paramters) =
+            create_proposal_parameters::<T>(t, d);
+
+        let proposal_details = ProposalDetails::TransferWorkingGroupWorker(
+            WorkingGroup::Storage,
+            working_group::WorkerId::<T>::one(),
+            WorkingGroup::Distribution,
+        );
+    }: create_proposal(
+        RawOrigin::Signed(account_id.clone()),
+        general_proposal_paramters.clone(),
+        proposal_details.clone()
+    )
+    verify {
+        create_proposal_verify::<T>(
+            account_id,
+            member_id,
+            general_proposal_paramters,
+            proposal_details
+        );
+    }
+
+    create_proposal_set_referendum_vote_power_strategy {
+        let t in 1 .. to_kb(T::TitleMaxLength::get());
+        let d in 1 .. to_kb(T::DescriptionMaxLength::get());
+
+        let (account_id, member_id, general_proposal_paramters) =
+            create_proposal_parameters::<T>(t, d);
+
+        let proposal_details = ProposalDetails::SetReferendumVotePowerStrategy(
+            VotePowerStrategy::TimeWeighted {
+                maturity_period: One::one(),
+                max_bonus: Percent::from_percent(100),
+            },
+        );
+    }: create_proposal(
+        RawOrigin::Signed(account_id.clone()),
+        general_proposal_paramters.clone(),
+        proposal_details.clone()
+    )
+    verify {
+        create_proposal_verify::<T>(
+            account_id,
+            member_id,
+            general_proposal_paramters,
+            proposal_details
+        );
+    }
+
+    create_proposal_recall_councilor {
+        let t in 1 .. to_kb(T::TitleMaxLength::get());
+        let d in 1 .. to_kb(T::DescriptionMaxLength::get());
+
+        let (account_id, member_id, general_proposal_paramters) =
+            create_proposal_parameters::<T>(t, d);
+
+        let proposal_details =
+            ProposalDetails::RecallCouncilor(member_id, Percent::from_percent(50));
+    }: create_proposal(
+        RawOrigin::Signed(account_id.clone()),
+        general_proposal_paramters.clone(),
+        proposal_details.clone()
+    )
+    verify {
+        create_proposal_verify::<T>(
+            account_id,
+            member_id,
+            general_proposal_paramters,
+            proposal_details
+        );
+    }
+
+    create_proposal_resolve_worker_review_contest {
+        let t in 1 .. to_kb(T::TitleMaxLength::get());
+        let d in 1 .. to_kb(T::DescriptionMaxLength::get());
+
+        let (account_id, member_id, general_proposal_paramters) =
+            create_proposal_parameters::<T>(t, d);
+
+        let proposal_details = ProposalDetails::ResolveWorkerReviewContest(
+            working_group::WorkerId::<T>::one(),
+            0,
+            true,
+            WorkingGroup::Forum,
+        );
+    }: create_proposal(
+        RawOrigin::Signed(account_id.clone()),
+        general_proposal_paramters.clone(),
+        proposal_details.clone()
+    )
+    verify {
+        create_proposal_verify::<T>(
+            account_id,
+            member_id,
+            general_proposal_paramters,
+            proposal_details
+        );
+    }
+
+    create_proposal_create_budget_allocation {
+        let i in 1 .. MAX_KILOBYTES_METADATA;
+        let t in 1 .. to_kb(T::TitleMaxLength::get());
+        let d in 1 .. to_kb(T::DescriptionMaxLength::get());
+
+        let (account_id, member_id, general_proposal_paramters) =
+            create_proposal_parameters::<T>(t, d);
+
+        let proposal_details = ProposalDetails::CreateBudgetAllocation(
+            vec![0u8; (i * 1000).try_into().unwrap()],
+            One::one(),
+            System::<T>::block_number() + One::one(),
+        );
+    }: create_proposal(
+        RawOrigin::Signed(account_id.clone()),
+        general_proposal_paramters.clone(),
+        proposal_details.clone()
+    )
+    verify {
+        create_proposal_verify::<T>(
+            account_id,
+            member_id,
+            general_proposal_paramters,
+            proposal_details
+        );
+    }
+
+    create_proposal_funding_request_from_allocation {
+        let i in 1 .. T::FundingRequestProposalMaxAccounts::get();
+        let t in 1 .. to_kb(T::TitleMaxLength::get());
+        let d in 1 .. to_kb(T::DescriptionMaxLength::get());
+
+        let (account_id, member_id, general_proposal_paramters) =
+            create_proposal_parameters::<T>(t, d);
+
+        let mut funding_requests =
+            Vec::<common::FundingRequestParameters<council::Balance::<T>, T::AccountId>>::new();
+
+        for id in 0 .. i {
+            funding_requests.push(common::FundingRequestParameters {
+                account: account::<T::AccountId>("reciever", id, SEED),
+                amount: One::one(),
+            });
+        }
+
+        let proposal_details =
+            ProposalDetails::FundingRequestFromAllocation(funding_requests, Zero::zero());
+    }: create_proposal(
+        RawOrigin::Signed(account_id.clone()),
+        general_proposal_paramters.clone(),
+        proposal_details.clone()
+    )
+    verify {
+        create_proposal_verify::<T>(
+            account_id,
+            member_id,
+            general_proposal_paramters,
+            proposal_details
+        );
+    }
+
+    create_proposal_update_working_group_budget_from_allocation {
+        let t in 1 .. to_kb(T::TitleMaxLength::get());
+        let d in 1 .. to_kb(T::DescriptionMaxLength::get());
+
+        let (account_id, member_id, general_proposal_paramters) =
+            create_proposal_parameters::<T>(t, d);
+
+        let proposal_details = ProposalDetails::UpdateWorkingGroupBudgetFromAllocation(
+            One::one(),
+            WorkingGroup::Forum,
+            Zero::zero(),
+        );
+    }: create_proposal(
+        RawOrigin::Signed(account_id.clone()),
+        general_proposal_paramters.clone(),
+        proposal_details.clone()
+    )
+    verify {
+        create_proposal_verify::<T>(
+            account_id,
+            member_id,
+            general_proposal_paramters,
+            proposal_details
+        );
+    }
 }
 
 #[cfg(test)]
@@ -1240,4 +1522,79 @@ mod tests {
             assert_ok!(ProposalsCodex::test_benchmark_create_proposal_decrease_council_budget());
         });
     }
+
+    #[test]
+    fn test_create_proposal_overturn_bounty_judgment() {
+        initial_test_ext().execute_with(|| {
+            assert_ok!(ProposalsCodex::test_benchmark_create_proposal_overturn_bounty_judgment());
+        });
+    }
+
+    #[test]
+    fn test_create_proposal_revoke_working_group_deputy() {
+        initial_test_ext().execute_with(|| {
+            assert_ok!(
+                ProposalsCodex::test_benchmark_create_proposal_revoke_working_group_deputy()
+            );
+        });
+    }
+
+    #[test]
+    fn test_create_proposal_transfer_working_group_worker() {
+        initial_test_ext().execute_with(|| {
+            assert_ok!(
+                ProposalsCodex::test_benchmark_create_proposal_transfer_working_group_worker()
+            );
+        });
+    }
+
+    #[test]
+    fn test_create_proposal_set_referendum_vote_power_strategy() {
+        initial_test_ext().execute_with(|| {
+            assert_ok!(
+                ProposalsCodex::test_benchmark_create_proposal_set_referendum_vote_power_strategy()
+            );
+        });
+    }
+
+    #[test]
+    fn test_create_proposal_recall_councilor() {
+        initial_test_ext().execute_with(|| {
+            assert_ok!(ProposalsCodex::test_benchmark_create_proposal_recall_councilor());
+        });
+    }
+
+    #[test]
+    fn test_create_proposal_resolve_worker_review_contest() {
+        initial_test_ext().execute_with(|| {
+            assert_ok!(
+                ProposalsCodex::test_benchmark_create_proposal_resolve_worker_review_contest()
+            );
+        });
+    }
+
+    #[test]
+    fn test_create_proposal_create_budget_allocation() {
+        initial_test_ext().execute_with(|| {
+            assert_ok!(ProposalsCodex::test_benchmark_create_proposal_create_budget_allocation());
+        });
+    }
+
+    #[test]
+    fn test_create_proposal_funding_request_from_allocation() {
+        initial_test_ext().execute_with(|| {
+            assert_ok!(
+                ProposalsCodex::test_benchmark_create_proposal_funding_request_from_allocation()
+            );
+        });
+    }
+
+    #[test]
+    fn test_create_proposal_update_working_group_budget_from_allocation() {
+        initial_test_ext().execute_with(|| {
+            assert_ok!(
+                ProposalsCodex::test_benchmark_create_proposal_update_working_group_budget_from_allocation()
+            );
+        });
+    }
 }
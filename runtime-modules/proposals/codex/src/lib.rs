@@ -140,6 +140,7 @@ pub trait Config:
     + working_group::Config<OperationsWorkingGroupInstanceGamma>
     + working_group::Config<DistributionWorkingGroupInstance>
     + council::Config
+    + bounty::Config
 {
     /// Proposal Codex module event type.
     type RuntimeEvent: From<Event<Self>> + Into<<Self as frame_system::Config>::RuntimeEvent>;
@@ -299,6 +300,51 @@ pub trait Config:
     type DecreaseCouncilBudgetProposalParameters: Get<
         ProposalParameters<Self::BlockNumber, BalanceOf<Self>>,
     >;
+
+    /// `Overturn Bounty Judgment` proposal parameters
+    type OverturnBountyJudgmentProposalParameters: Get<
+        ProposalParameters<Self::BlockNumber, BalanceOf<Self>>,
+    >;
+
+    /// `Revoke Working Group Deputy` proposal parameters
+    type RevokeWorkingGroupDeputyProposalParameters: Get<
+        ProposalParameters<Self::BlockNumber, BalanceOf<Self>>,
+    >;
+
+    /// `Transfer Working Group Worker` proposal parameters
+    type TransferWorkingGroupWorkerProposalParameters: Get<
+        ProposalParameters<Self::BlockNumber, BalanceOf<Self>>,
+    >;
+
+    /// `Set Referendum Vote Power Strategy` proposal parameters
+    type SetReferendumVotePowerStrategyProposalParameters: Get<
+        ProposalParameters<Self::BlockNumber, BalanceOf<Self>>,
+    >;
+
+    /// `Recall Councilor` proposal parameters
+    type RecallCouncilorProposalParameters: Get<
+        ProposalParameters<Self::BlockNumber, BalanceOf<Self>>,
+    >;
+
+    /// `Resolve Worker Review Contest` proposal parameters
+    type ResolveWorkerReviewContestProposalParameters: Get<
+        ProposalParameters<Self::BlockNumber, BalanceOf<Self>>,
+    >;
+
+    /// `Create Budget Allocation` proposal parameters
+    type CreateBudgetAllocationProposalParameters: Get<
+        ProposalParameters<Self::BlockNumber, BalanceOf<Self>>,
+    >;
+
+    /// `Funding Request From Allocation` proposal parameters
+    type FundingRequestFromAllocationProposalParameters: Get<
+        ProposalParameters<Self::BlockNumber, BalanceOf<Self>>,
+    >;
+
+    /// `Update Working Group Budget From Allocation` proposal parameters
+    type UpdateWorkingGroupBudgetFromAllocationProposalParameters: Get<
+        ProposalParameters<Self::BlockNumber, BalanceOf<Self>>,
+    >;
 }
 
 /// Specialized alias of GeneralProposalParams
@@ -408,6 +454,21 @@ decl_error! {
 
         /// Reduction Amount Zero
         ReductionAmountZero,
+
+        /// The specified attestation threshold is zero or greater than the number of specified attesters in `Update Argo Bridge Constraints` proposal.
+        InvalidArgoBridgeConstraintsAttestationThreshold,
+
+        /// The specified council budget and operator reward fee shares exceed 100% in `Update Argo Bridge Constraints` proposal.
+        InvalidArgoBridgeConstraintsFeeDistribution,
+
+        /// Worker cannot be transferred within the same working group
+        InvalidWorkerTransferDestination,
+
+        /// The specified vote power strategy parameters are invalid
+        InvalidVotePowerStrategy,
+
+        /// The budget allocation cap is zero or greater than the max funding request amount
+        InvalidBudgetAllocationCap,
     }
 }
 
@@ -530,6 +591,42 @@ decl_module! {
         const DecreaseCouncilBudgetProposalParameters:
             ProposalParameters<T::BlockNumber, BalanceOf<T>> = T::DecreaseCouncilBudgetProposalParameters::get();
 
+        /// Overturn bounty judgment parameters
+        const OverturnBountyJudgmentProposalParameters:
+            ProposalParameters<T::BlockNumber, BalanceOf<T>> = T::OverturnBountyJudgmentProposalParameters::get();
+
+        /// Revoke working group deputy parameters
+        const RevokeWorkingGroupDeputyProposalParameters:
+            ProposalParameters<T::BlockNumber, BalanceOf<T>> = T::RevokeWorkingGroupDeputyProposalParameters::get();
+
+        /// Transfer working group worker parameters
+        const TransferWorkingGroupWorkerProposalParameters:
+            ProposalParameters<T::BlockNumber, BalanceOf<T>> = T::TransferWorkingGroupWorkerProposalParameters::get();
+
+        /// Set referendum vote power strategy parameters
+        const SetReferendumVotePowerStrategyProposalParameters:
+            ProposalParameters<T::BlockNumber, BalanceOf<T>> = T::SetReferendumVotePowerStrategyProposalParameters::get();
+
+        /// Recall councilor proposal parameters
+        const RecallCouncilorProposalParameters:
+            ProposalParameters<T::BlockNumber, BalanceOf<T>> = T::RecallCouncilorProposalParameters::get();
+
+        /// Resolve worker review contest proposal parameters
+        const ResolveWorkerReviewContestProposalParameters:
+            ProposalParameters<T::BlockNumber, BalanceOf<T>> = T::ResolveWorkerReviewContestProposalParameters::get();
+
+        /// Create budget allocation proposal parameters
+        const CreateBudgetAllocationProposalParameters:
+            ProposalParameters<T::BlockNumber, BalanceOf<T>> = T::CreateBudgetAllocationProposalParameters::get();
+
+        /// Funding request from allocation proposal parameters
+        const FundingRequestFromAllocationProposalParameters:
+            ProposalParameters<T::BlockNumber, BalanceOf<T>> = T::FundingRequestFromAllocationProposalParameters::get();
+
+        /// Update working group budget from allocation proposal parameters
+        const UpdateWorkingGroupBudgetFromAllocationProposalParameters:
+            ProposalParameters<T::BlockNumber, BalanceOf<T>> = T::UpdateWorkingGroupBudgetFromAllocationProposalParameters::get();
+
         /// Set Pallet Frozen status
         const SetPalletFozenStatusProposalParameters:
             ProposalParameters<T::BlockNumber, BalanceOf<T>> = T::SetPalletFozenStatusProposalParameters::get();
@@ -782,7 +879,8 @@ impl<T: Config> Module<T> {
             ProposalDetails::RuntimeUpgrade(ref blob) => {
                 ensure!(!blob.is_empty(), Error::<T>::RuntimeProposalIsEmpty);
             }
-            ProposalDetails::FundingRequest(ref funding_requests) => {
+            ProposalDetails::FundingRequest(ref funding_requests)
+            | ProposalDetails::FundingRequestFromAllocation(ref funding_requests, _) => {
                 ensure!(
                     !funding_requests.is_empty(),
                     Error::<T>::InvalidFundingRequestProposalNumberOfAccount
@@ -852,7 +950,8 @@ impl<T: Config> Module<T> {
                     &params.application_id,
                 )?;
             }
-            ProposalDetails::UpdateWorkingGroupBudget(..) => {
+            ProposalDetails::UpdateWorkingGroupBudget(..)
+            | ProposalDetails::UpdateWorkingGroupBudgetFromAllocation(..) => {
                 // Note: No checks for this proposal for now
             }
             ProposalDetails::DecreaseWorkingGroupLeadStake(worker_id, ref stake_amount, wg) => {
@@ -926,12 +1025,57 @@ impl<T: Config> Module<T> {
             ProposalDetails::UpdateTokenPalletTokenConstraints(..) => {
                 // Note: No checks for this proposal for now
             }
-            ProposalDetails::UpdateArgoBridgeConstraints(..) => {
-                // Note: No checks for this proposal for now
+            ProposalDetails::UpdateArgoBridgeConstraints(params) => {
+                if let (Some(attester_accounts), Some(attestation_threshold)) =
+                    (&params.attester_accounts, params.attestation_threshold)
+                {
+                    ensure!(
+                        attestation_threshold > 0
+                            && attestation_threshold
+                                <= attester_accounts.len().saturated_into::<u32>(),
+                        Error::<T>::InvalidArgoBridgeConstraintsAttestationThreshold
+                    );
+                }
+                if let Some(fee_distribution) = &params.fee_distribution {
+                    ensure!(
+                        fee_distribution.is_valid(),
+                        Error::<T>::InvalidArgoBridgeConstraintsFeeDistribution
+                    );
+                }
             }
             ProposalDetails::SetEraPayoutDampingFactor(..) => {
                 // Note: No checks for this proposal for now
             }
+            ProposalDetails::OverturnBountyJudgment(..) => {
+                // Note: The judgment dispute is checked on the proposal execution
+            }
+            ProposalDetails::RevokeWorkingGroupDeputy(..) => {
+                // Note: The deputy is checked on the proposal execution
+            }
+            ProposalDetails::TransferWorkingGroupWorker(source, _, destination) => {
+                // Note: The worker is checked on the proposal execution
+                ensure!(
+                    source != destination,
+                    Error::<T>::InvalidWorkerTransferDestination
+                );
+            }
+            ProposalDetails::SetReferendumVotePowerStrategy(strategy) => {
+                ensure!(strategy.is_valid(), Error::<T>::InvalidVotePowerStrategy);
+            }
+            ProposalDetails::RecallCouncilor(..) => {
+                // Note: The councilor is checked on the proposal execution
+            }
+            ProposalDetails::ResolveWorkerReviewContest(..) => {
+                // Note: The contested review is checked on the proposal execution
+            }
+            ProposalDetails::CreateBudgetAllocation(_, cap, _) => {
+                // Note: The expiry and the available council budget are checked on the proposal
+                // execution
+                ensure!(
+                    !cap.is_zero() && *cap <= T::FundingRequestProposalMaxTotalAmount::get(),
+                    Error::<T>::InvalidBudgetAllocationCap
+                );
+            }
         }
 
         Ok(())
@@ -1014,6 +1158,31 @@ impl<T: Config> Module<T> {
             ProposalDetails::SetEraPayoutDampingFactor(..) => {
                 T::SetEraPayoutDampingFactorProposalParameters::get()
             }
+            ProposalDetails::OverturnBountyJudgment(..) => {
+                T::OverturnBountyJudgmentProposalParameters::get()
+            }
+            ProposalDetails::RevokeWorkingGroupDeputy(..) => {
+                T::RevokeWorkingGroupDeputyProposalParameters::get()
+            }
+            ProposalDetails::TransferWorkingGroupWorker(..) => {
+                T::TransferWorkingGroupWorkerProposalParameters::get()
+            }
+            ProposalDetails::SetReferendumVotePowerStrategy(..) => {
+                T::SetReferendumVotePowerStrategyProposalParameters::get()
+            }
+            ProposalDetails::RecallCouncilor(..) => T::RecallCouncilorProposalParameters::get(),
+            ProposalDetails::ResolveWorkerReviewContest(..) => {
+                T::ResolveWorkerReviewContestProposalParameters::get()
+            }
+            ProposalDetails::CreateBudgetAllocation(..) => {
+                T::CreateBudgetAllocationProposalParameters::get()
+            }
+            ProposalDetails::FundingRequestFromAllocation(..) => {
+                T::FundingRequestFromAllocationProposalParameters::get()
+            }
+            ProposalDetails::UpdateWorkingGroupBudgetFromAllocation(..) => {
+                T::UpdateWorkingGroupBudgetFromAllocationProposalParameters::get()
+            }
         }
     }
 
@@ -1205,6 +1374,62 @@ impl<T: Config> Module<T> {
                     to_kb(description_length.saturated_into()),
                 )
             }
+            ProposalDetails::OverturnBountyJudgment(..) => {
+                WeightInfoCodex::<T>::create_proposal_overturn_bounty_judgment(
+                    to_kb(title_length.saturated_into()),
+                    to_kb(description_length.saturated_into()),
+                )
+            }
+            ProposalDetails::RevokeWorkingGroupDeputy(..) => {
+                WeightInfoCodex::<T>::create_proposal_revoke_working_group_deputy(
+                    to_kb(title_length.saturated_into()),
+                    to_kb(description_length.saturated_into()),
+                )
+            }
+            ProposalDetails::TransferWorkingGroupWorker(..) => {
+                WeightInfoCodex::<T>::create_proposal_transfer_working_group_worker(
+                    to_kb(title_length.saturated_into()),
+                    to_kb(description_length.saturated_into()),
+                )
+            }
+            ProposalDetails::SetReferendumVotePowerStrategy(..) => {
+                WeightInfoCodex::<T>::create_proposal_set_referendum_vote_power_strategy(
+                    to_kb(title_length.saturated_into()),
+                    to_kb(description_length.saturated_into()),
+                )
+            }
+            ProposalDetails::RecallCouncilor(..) => {
+                WeightInfoCodex::<T>::create_proposal_recall_councilor(
+                    to_kb(title_length.saturated_into()),
+                    to_kb(description_length.saturated_into()),
+                )
+            }
+            ProposalDetails::ResolveWorkerReviewContest(..) => {
+                WeightInfoCodex::<T>::create_proposal_resolve_worker_review_contest(
+                    to_kb(title_length.saturated_into()),
+                    to_kb(description_length.saturated_into()),
+                )
+            }
+            ProposalDetails::CreateBudgetAllocation(name, ..) => {
+                WeightInfoCodex::<T>::create_proposal_create_budget_allocation(
+                    to_kb(name.len().saturated_into()),
+                    to_kb(title_length.saturated_into()),
+                    to_kb(description_length.saturated_into()),
+                )
+            }
+            ProposalDetails::FundingRequestFromAllocation(params, _) => {
+                WeightInfoCodex::<T>::create_proposal_funding_request_from_allocation(
+                    params.len().saturated_into(),
+                    to_kb(title_length.saturated_into()),
+                    to_kb(description_length.saturated_into()),
+                )
+            }
+            ProposalDetails::UpdateWorkingGroupBudgetFromAllocation(..) => {
+                WeightInfoCodex::<T>::create_proposal_update_working_group_budget_from_allocation(
+                    to_kb(title_length.saturated_into()),
+                    to_kb(description_length.saturated_into()),
+                )
+            }
         }
     }
 }
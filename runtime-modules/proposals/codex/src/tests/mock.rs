@@ -111,7 +111,8 @@ frame_support::construct_runtime!(
         Council: council::{Pallet, Call, Storage, Event<T>},
         Storage: storage::{Pallet, Call, Storage, Event<T>},
         Token: token::{Pallet, Call, Storage, Event<T>},
-        ArgoBridge: argo_bridge::{Pallet, Call, Storage, Event<T>}
+        ArgoBridge: argo_bridge::{Pallet, Call, Storage, Event<T>},
+        Bounty: bounty::{Pallet, Call, Storage, Event<T>}
     }
 );
 
@@ -305,6 +306,10 @@ parameter_types! {
     pub const StakingCandidateLockId: [u8; 8] = [3; 8];
     pub const CandidateStake: u64 = 100;
     pub const DefaultMemberInvitesCount: u32 = 2;
+    pub const HandleReclaimCooldown: u64 = 1000;
+    pub const MaxRecoveryGuardians: u32 = 5;
+    pub const RecoveryDelay: u64 = 100;
+    pub const RecoveryCooldown: u64 = 50;
 }
 
 impl common::membership::MembershipTypes for Test {
@@ -324,6 +329,10 @@ impl membership::Config for Test {
         staking_handler::StakingManager<Self, StakingCandidateLockId>;
     type CandidateStake = CandidateStake;
     type DefaultMemberInvitesCount = DefaultMemberInvitesCount;
+    type HandleReclaimCooldown = HandleReclaimCooldown;
+    type MaxRecoveryGuardians = MaxRecoveryGuardians;
+    type RecoveryDelay = RecoveryDelay;
+    type RecoveryCooldown = RecoveryCooldown;
 }
 
 parameter_types! {
@@ -351,7 +360,19 @@ parameter_types! {
     pub const MaxNumberOfOperatorsPerDistributionBucket: u32 = 5;
     /// constants for argo_bridge::config
     pub const MaxPauserAccounts: u32 = 10;
+    pub const MaxAttesterAccounts: u32 = 10;
     pub const DefaultBridgingFee: Balance = 1;
+    /// constants for bounty::config
+    pub const BountyModuleId: PalletId = PalletId(*b"m:bounty");
+    pub const BountyLockId: LockIdentifier = [12; 8];
+    pub const ClosedContractSizeLimit: u32 = 3;
+    pub const MinWorkEntrantStake: u64 = 10;
+    pub const FunderStateBloatBondAmount: u64 = 10;
+    pub const CreatorStateBloatBondAmount: u64 = 10;
+    pub const MaxDeliveryMilestones: u32 = 5;
+    pub const MaxOraclePanelSize: u32 = 5;
+    pub const JudgmentDisputeStake: u64 = 20;
+    pub const JudgmentDisputePeriod: u64 = 10;
 }
 
 impl storage::Config for Test {
@@ -402,8 +423,32 @@ impl token::Config for Test {
 impl argo_bridge::Config for Test {
     type RuntimeEvent = RuntimeEvent;
     type MaxPauserAccounts = MaxPauserAccounts;
+    type MaxAttesterAccounts = MaxAttesterAccounts;
     type WeightInfo = argo_bridge::weights::SubstrateWeight<Test>;
     type DefaultBridgingFee = DefaultBridgingFee;
+    type ProjectToken = Token;
+    type CouncilBudgetManager = Council;
+}
+
+impl bounty::Config for Test {
+    type RuntimeEvent = RuntimeEvent;
+    type ModuleId = BountyModuleId;
+    type BountyId = u64;
+    type Membership = membership::Module<Self>;
+    type WeightInfo = ();
+    type CouncilBudgetManager = Council;
+    type CouncilMembers = Council;
+    type StakingHandler = StakingManager<Self, BountyLockId>;
+    type EntryId = u64;
+    type ClosedContractSizeLimit = ClosedContractSizeLimit;
+    type MinWorkEntrantStake = MinWorkEntrantStake;
+    type FunderStateBloatBondAmount = FunderStateBloatBondAmount;
+    type CreatorStateBloatBondAmount = CreatorStateBloatBondAmount;
+    type MaxDeliveryMilestones = MaxDeliveryMilestones;
+    type MaxOraclePanelSize = MaxOraclePanelSize;
+    type JudgmentDisputeStake = JudgmentDisputeStake;
+    type JudgmentDisputePeriod = JudgmentDisputePeriod;
+    type ProjectToken = Token;
 }
 
 pub struct Wg;
@@ -502,6 +547,7 @@ impl proposals_engine::Config for Test {
     type WeightInfo = ();
     type StakingAccountValidator = ();
     type DispatchableCallCodeMaxLen = DispatchableCallCodeMaxLen;
+    type CouncilParticipationObserver = ();
 }
 
 pub const STAKING_ACCOUNT_ID_NOT_BOUND_TO_MEMBER: u64 = 222;
@@ -537,6 +583,10 @@ impl common::membership::MembershipInfoProvider<Test> for () {
     fn controller_account_id(member_id: u64) -> Result<u64, DispatchError> {
         Ok(member_id)
     }
+
+    fn has_valid_attestation(_member_id: u64, _kind: common::membership::AttestationKind) -> bool {
+        false
+    }
 }
 
 impl common::council::CouncilOriginValidator<RuntimeOrigin, u64, u64> for () {
@@ -629,8 +679,10 @@ impl working_group::Config<ForumWorkingGroupInstance> for Test {
     type MemberOriginValidator = ();
     type MinUnstakingPeriodLimit = ();
     type RewardPeriod = ();
+    type MaxRewardPayoutsPerBlock = ();
     type WeightInfo = ();
     type MinimumApplicationStake = MinimumApplicationStake;
+    type MaxApplicationEndorsements = ConstU32<10>;
     type LeaderOpeningStake = LeaderOpeningStake;
     type VestingBalanceToBalance = VestingBalanceToBalance;
 }
@@ -643,8 +695,10 @@ impl working_group::Config<StorageWorkingGroupInstance> for Test {
     type MemberOriginValidator = ();
     type MinUnstakingPeriodLimit = ();
     type RewardPeriod = ();
+    type MaxRewardPayoutsPerBlock = ();
     type WeightInfo = ();
     type MinimumApplicationStake = MinimumApplicationStake;
+    type MaxApplicationEndorsements = ConstU32<10>;
     type LeaderOpeningStake = LeaderOpeningStake;
     type VestingBalanceToBalance = VestingBalanceToBalance;
 }
@@ -657,8 +711,10 @@ impl working_group::Config<ContentWorkingGroupInstance> for Test {
     type MemberOriginValidator = ();
     type MinUnstakingPeriodLimit = ();
     type RewardPeriod = ();
+    type MaxRewardPayoutsPerBlock = ();
     type WeightInfo = ();
     type MinimumApplicationStake = MinimumApplicationStake;
+    type MaxApplicationEndorsements = ConstU32<10>;
     type LeaderOpeningStake = LeaderOpeningStake;
     type VestingBalanceToBalance = VestingBalanceToBalance;
 }
@@ -671,8 +727,10 @@ impl working_group::Config<OperationsWorkingGroupInstanceAlpha> for Test {
     type MemberOriginValidator = ();
     type MinUnstakingPeriodLimit = ();
     type RewardPeriod = ();
+    type MaxRewardPayoutsPerBlock = ();
     type WeightInfo = ();
     type MinimumApplicationStake = MinimumApplicationStake;
+    type MaxApplicationEndorsements = ConstU32<10>;
     type LeaderOpeningStake = LeaderOpeningStake;
     type VestingBalanceToBalance = VestingBalanceToBalance;
 }
@@ -685,8 +743,10 @@ impl working_group::Config<AppWorkingGroupInstance> for Test {
     type MemberOriginValidator = ();
     type MinUnstakingPeriodLimit = ();
     type RewardPeriod = ();
+    type MaxRewardPayoutsPerBlock = ();
     type WeightInfo = ();
     type MinimumApplicationStake = MinimumApplicationStake;
+    type MaxApplicationEndorsements = ConstU32<10>;
     type LeaderOpeningStake = LeaderOpeningStake;
     type VestingBalanceToBalance = VestingBalanceToBalance;
 }
@@ -699,8 +759,10 @@ impl working_group::Config<MembershipWorkingGroupInstance> for Test {
     type MemberOriginValidator = ();
     type MinUnstakingPeriodLimit = ();
     type RewardPeriod = ();
+    type MaxRewardPayoutsPerBlock = ();
     type WeightInfo = ();
     type MinimumApplicationStake = MinimumApplicationStake;
+    type MaxApplicationEndorsements = ConstU32<10>;
     type LeaderOpeningStake = LeaderOpeningStake;
     type VestingBalanceToBalance = VestingBalanceToBalance;
 }
@@ -713,8 +775,10 @@ impl working_group::Config<OperationsWorkingGroupInstanceBeta> for Test {
     type MemberOriginValidator = ();
     type MinUnstakingPeriodLimit = ();
     type RewardPeriod = ();
+    type MaxRewardPayoutsPerBlock = ();
     type WeightInfo = ();
     type MinimumApplicationStake = MinimumApplicationStake;
+    type MaxApplicationEndorsements = ConstU32<10>;
     type LeaderOpeningStake = LeaderOpeningStake;
     type VestingBalanceToBalance = VestingBalanceToBalance;
 }
@@ -727,8 +791,10 @@ impl working_group::Config<OperationsWorkingGroupInstanceGamma> for Test {
     type MemberOriginValidator = ();
     type MinUnstakingPeriodLimit = ();
     type RewardPeriod = ();
+    type MaxRewardPayoutsPerBlock = ();
     type WeightInfo = ();
     type MinimumApplicationStake = MinimumApplicationStake;
+    type MaxApplicationEndorsements = ConstU32<10>;
     type LeaderOpeningStake = LeaderOpeningStake;
     type VestingBalanceToBalance = VestingBalanceToBalance;
 }
@@ -741,8 +807,10 @@ impl working_group::Config<DistributionWorkingGroupInstance> for Test {
     type MemberOriginValidator = ();
     type MinUnstakingPeriodLimit = ();
     type RewardPeriod = ();
+    type MaxRewardPayoutsPerBlock = ();
     type WeightInfo = ();
     type MinimumApplicationStake = MinimumApplicationStake;
+    type MaxApplicationEndorsements = ConstU32<10>;
     type LeaderOpeningStake = LeaderOpeningStake;
     type VestingBalanceToBalance = VestingBalanceToBalance;
 }
@@ -800,6 +868,15 @@ impl crate::Config for Test {
     type UpdateArgoBridgeConstraints = DefaultProposalParameters;
     type SetEraPayoutDampingFactorProposalParameters = DefaultProposalParameters;
     type DecreaseCouncilBudgetProposalParameters = DefaultProposalParameters;
+    type OverturnBountyJudgmentProposalParameters = DefaultProposalParameters;
+    type RevokeWorkingGroupDeputyProposalParameters = DefaultProposalParameters;
+    type TransferWorkingGroupWorkerProposalParameters = DefaultProposalParameters;
+    type SetReferendumVotePowerStrategyProposalParameters = DefaultProposalParameters;
+    type RecallCouncilorProposalParameters = DefaultProposalParameters;
+    type ResolveWorkerReviewContestProposalParameters = DefaultProposalParameters;
+    type CreateBudgetAllocationProposalParameters = DefaultProposalParameters;
+    type FundingRequestFromAllocationProposalParameters = DefaultProposalParameters;
+    type UpdateWorkingGroupBudgetFromAllocationProposalParameters = DefaultProposalParameters;
 }
 
 parameter_types! {
@@ -876,6 +953,12 @@ impl referendum::Config<ReferendumInstance> for Test {
 
     type MaxWinnerTargetCount = MaxWinnerTargetCount;
 
+    type DefaultVotePowerStrategy = ();
+    type MaxRankedBallotPreferences = ConstU32<5>;
+    type MaxRankedBallots = ConstU32<10>;
+    type MaxVotedOptions = ConstU32<100>;
+    type MaxDelegatorsPerDelegate = ConstU32<10>;
+
     fn calculate_vote_power(
         _: &<Self as frame_system::Config>::AccountId,
         _: &Self::Balance,
@@ -7,13 +7,15 @@ use scale_info::TypeInfo;
 use serde::{Deserialize, Serialize};
 use sp_std::vec::Vec;
 
+use common::council::BudgetAllocationId;
+use common::referendum::VotePowerStrategy;
 use common::working_group::WorkingGroup;
 use common::BalanceKind;
 use common::FundingRequestParameters;
 use sp_runtime::Percent;
 
 use content::NftLimitPeriod;
-use working_group::StakePolicy;
+use working_group::{StakePolicy, WorkerReviewId};
 
 /// Encodes proposal using its details information.
 pub trait ProposalEncoder<T: crate::Config> {
@@ -35,6 +37,8 @@ pub type ProposalDetailsOf<T> = ProposalDetails<
     content::UpdateChannelPayoutsParameters<T>,
     token::TokenConstraintsOf<T>,
     argo_bridge::types::BridgeConstraintsOf<T>,
+    <T as bounty::Config>::BountyId,
+    <T as common::membership::MembershipTypes>::MemberId,
 >;
 
 /// Proposal details provide voters the information required for the perceived voting.
@@ -50,6 +54,8 @@ pub enum ProposalDetails<
     UpdateChannelPayoutsParameters,
     TokenConstraints,
     ArgoBridgeConstraints,
+    BountyId,
+    MemberId,
 > {
     /// The signal of the `Signal` proposal
     Signal(Vec<u8>),
@@ -139,6 +145,50 @@ pub enum ProposalDetails<
 
     /// `DecreaseCouncilBudget` proposal
     DecreaseCouncilBudget(Balance),
+
+    /// `Overturn Bounty Judgment` proposal:
+    /// Overturns the disputed oracle judgment of the bounty during its appeal period.
+    OverturnBountyJudgment(BountyId),
+
+    /// `Revoke Working Group Deputy` proposal:
+    /// Revokes the deputy appointed by the working group leader.
+    RevokeWorkingGroupDeputy(WorkerId, WorkingGroup),
+
+    /// `Transfer Working Group Worker` proposal:
+    /// Transfers the worker between the working groups keeping the stake and the tenure.
+    /// Params: source working group, worker id, destination working group.
+    TransferWorkingGroupWorker(WorkingGroup, WorkerId, WorkingGroup),
+
+    /// `Set Referendum Vote Power Strategy` proposal:
+    /// Sets the vote power strategy used by the council elections from the next referendum.
+    SetReferendumVotePowerStrategy(VotePowerStrategy<BlockNumber>),
+
+    /// `Recall Councilor` proposal:
+    /// Removes the councilor from the council slashing the given part of their stake.
+    /// The vacated seat is filled by the special election.
+    RecallCouncilor(MemberId, Percent),
+
+    /// `Resolve Worker Review Contest` proposal:
+    /// Upholds or overturns the performance review contested by the worker.
+    /// Params: worker id, review id, whether the review is upheld, working group.
+    ResolveWorkerReviewContest(WorkerId, WorkerReviewId, bool, WorkingGroup),
+
+    /// `Create Budget Allocation` proposal:
+    /// Earmarks the capped part of the council budget by the named allocation.
+    /// Params: allocation name, allocation cap, block the allocation expires at.
+    CreateBudgetAllocation(Vec<u8>, Balance, BlockNumber),
+
+    /// `Funding Request From Allocation` proposal:
+    /// Vector of balance and destination accounts funded from the earmarked council budget
+    /// allocation.
+    FundingRequestFromAllocation(
+        Vec<FundingRequestParameters<Balance, AccountId>>,
+        BudgetAllocationId,
+    ),
+
+    /// `Update Working Group Budget From Allocation` proposal:
+    /// Moves funds from the earmarked council budget allocation to the working group budget.
+    UpdateWorkingGroupBudgetFromAllocation(Balance, WorkingGroup, BudgetAllocationId),
 }
 
 impl<
@@ -151,6 +201,8 @@ impl<
         UpdateChannelPayoutsParameters,
         TokenConstraints,
         ArgoBridgeConstraints,
+        BountyId,
+        MemberId,
     > Default
     for ProposalDetails<
         Balance,
@@ -162,6 +214,8 @@ impl<
         UpdateChannelPayoutsParameters,
         TokenConstraints,
         ArgoBridgeConstraints,
+        BountyId,
+        MemberId,
     >
 {
     fn default() -> Self {
@@ -20,6 +20,9 @@
 //! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 4.0.0-dev
 //! DATE: 2024-04-08, STEPS: `50`, REPEAT: 20, LOW RANGE: `[]`, HIGH RANGE: `[]`
 //! EXECUTION: Some(Wasm), WASM-EXECUTION: Compiled, CHAIN: Some("prod-test"), DB CACHE: 1024
+//!
+//! NOTE: the weight functions marked as PLACEHOLDER are hand-written estimates, not the
+//! benchmark output. They must be regenerated with the benchmark CLI before merging.
 
 // Executed Command:
 // ./scripts/../target/release/joystream-node
@@ -72,6 +75,15 @@ pub trait WeightInfo {
 	fn create_proposal_update_argo_bridge_constraints(_t: u32, _d: u32, ) -> Weight;
 	fn create_proposal_set_era_payout_damping_factor(_t: u32, _d: u32, ) -> Weight;
 	fn create_proposal_decrease_council_budget(_t: u32, _d: u32, ) -> Weight;
+	fn create_proposal_overturn_bounty_judgment(_t: u32, _d: u32, ) -> Weight;
+	fn create_proposal_revoke_working_group_deputy(_t: u32, _d: u32, ) -> Weight;
+	fn create_proposal_transfer_working_group_worker(_t: u32, _d: u32, ) -> Weight;
+	fn create_proposal_set_referendum_vote_power_strategy(_t: u32, _d: u32, ) -> Weight;
+	fn create_proposal_recall_councilor(_t: u32, _d: u32, ) -> Weight;
+	fn create_proposal_resolve_worker_review_contest(_t: u32, _d: u32, ) -> Weight;
+	fn create_proposal_create_budget_allocation(_i: u32, _t: u32, _d: u32, ) -> Weight;
+	fn create_proposal_funding_request_from_allocation(_i: u32, _t: u32, _d: u32, ) -> Weight;
+	fn create_proposal_update_working_group_budget_from_allocation(_t: u32, _d: u32, ) -> Weight;
 }
 
 /// Weights for proposals_codex using the Substrate node and recommended hardware.
@@ -1153,6 +1165,316 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(7_u64))
 			.saturating_add(T::DbWeight::get().writes(9_u64))
 	}
+	// PLACEHOLDER: hand-written estimate, not the benchmark output.
+	// Must be regenerated with the benchmark CLI before merging.
+	// Storage: Membership MembershipById (r:1 w:0)
+	// Proof: Membership MembershipById (max_values: None, max_size: Some(125), added: 2600, mode: MaxEncodedLen)
+	// Storage: ProposalEngine ActiveProposalCount (r:1 w:1)
+	// Proof: ProposalEngine ActiveProposalCount (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	// Storage: Membership StakingAccountIdMemberStatus (r:1 w:0)
+	// Proof: Membership StakingAccountIdMemberStatus (max_values: None, max_size: Some(57), added: 2532, mode: MaxEncodedLen)
+	// Storage: Balances Locks (r:1 w:1)
+	// Proof: Balances Locks (max_values: None, max_size: Some(1299), added: 3774, mode: MaxEncodedLen)
+	// Storage: System Account (r:1 w:1)
+	// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	// Storage: ProposalDiscussion ThreadCount (r:1 w:1)
+	// Proof: ProposalDiscussion ThreadCount (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	// Storage: ProposalEngine ProposalCount (r:1 w:1)
+	// Proof: ProposalEngine ProposalCount (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	// Storage: ProposalsCodex ThreadIdByProposalId (r:0 w:1)
+	// Proof: ProposalsCodex ThreadIdByProposalId (max_values: None, max_size: Some(28), added: 2503, mode: MaxEncodedLen)
+	// Storage: ProposalDiscussion ThreadById (r:0 w:1)
+	// Proof: ProposalDiscussion ThreadById (max_values: None, max_size: Some(198), added: 2673, mode: MaxEncodedLen)
+	// Storage: ProposalEngine Proposals (r:0 w:1)
+	// Proof: ProposalEngine Proposals (max_values: None, max_size: Some(140), added: 2615, mode: MaxEncodedLen)
+	// Storage: ProposalEngine DispatchableCallCode (r:0 w:1)
+	// Proof: ProposalEngine DispatchableCallCode (max_values: None, max_size: Some(3145752), added: 3148227, mode: MaxEncodedLen)
+	/// The range of component `t` is `[1, 20]`.
+	/// The range of component `d` is `[1, 20]`.
+	fn create_proposal_overturn_bounty_judgment(t: u32, d: u32, ) -> Weight {
+		Weight::from_parts(80_716_422, 0u64)
+			.saturating_add(Weight::from_parts(0, 19940))
+			.saturating_add(Weight::from_parts(1_102_337, 0u64).saturating_mul(t.into()))
+			.saturating_add(Weight::from_parts(1_296_815, 0u64).saturating_mul(d.into()))
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(9_u64))
+	}
+	// PLACEHOLDER: hand-written estimate, not the benchmark output.
+	// Must be regenerated with the benchmark CLI before merging.
+	// Storage: Membership MembershipById (r:1 w:0)
+	// Proof: Membership MembershipById (max_values: None, max_size: Some(125), added: 2600, mode: MaxEncodedLen)
+	// Storage: ProposalEngine ActiveProposalCount (r:1 w:1)
+	// Proof: ProposalEngine ActiveProposalCount (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	// Storage: Membership StakingAccountIdMemberStatus (r:1 w:0)
+	// Proof: Membership StakingAccountIdMemberStatus (max_values: None, max_size: Some(57), added: 2532, mode: MaxEncodedLen)
+	// Storage: Balances Locks (r:1 w:1)
+	// Proof: Balances Locks (max_values: None, max_size: Some(1299), added: 3774, mode: MaxEncodedLen)
+	// Storage: System Account (r:1 w:1)
+	// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	// Storage: ProposalDiscussion ThreadCount (r:1 w:1)
+	// Proof: ProposalDiscussion ThreadCount (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	// Storage: ProposalEngine ProposalCount (r:1 w:1)
+	// Proof: ProposalEngine ProposalCount (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	// Storage: ProposalsCodex ThreadIdByProposalId (r:0 w:1)
+	// Proof: ProposalsCodex ThreadIdByProposalId (max_values: None, max_size: Some(28), added: 2503, mode: MaxEncodedLen)
+	// Storage: ProposalDiscussion ThreadById (r:0 w:1)
+	// Proof: ProposalDiscussion ThreadById (max_values: None, max_size: Some(198), added: 2673, mode: MaxEncodedLen)
+	// Storage: ProposalEngine Proposals (r:0 w:1)
+	// Proof: ProposalEngine Proposals (max_values: None, max_size: Some(140), added: 2615, mode: MaxEncodedLen)
+	// Storage: ProposalEngine DispatchableCallCode (r:0 w:1)
+	// Proof: ProposalEngine DispatchableCallCode (max_values: None, max_size: Some(3145752), added: 3148227, mode: MaxEncodedLen)
+	/// The range of component `t` is `[1, 20]`.
+	/// The range of component `d` is `[1, 20]`.
+	fn create_proposal_revoke_working_group_deputy(t: u32, d: u32, ) -> Weight {
+		Weight::from_parts(79_860_215, 0u64)
+			.saturating_add(Weight::from_parts(0, 19940))
+			.saturating_add(Weight::from_parts(1_087_904, 0u64).saturating_mul(t.into()))
+			.saturating_add(Weight::from_parts(1_311_268, 0u64).saturating_mul(d.into()))
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(9_u64))
+	}
+	// PLACEHOLDER: hand-written estimate, not the benchmark output.
+	// Must be regenerated with the benchmark CLI before merging.
+	// Storage: Membership MembershipById (r:1 w:0)
+	// Proof: Membership MembershipById (max_values: None, max_size: Some(125), added: 2600, mode: MaxEncodedLen)
+	// Storage: ProposalEngine ActiveProposalCount (r:1 w:1)
+	// Proof: ProposalEngine ActiveProposalCount (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	// Storage: Membership StakingAccountIdMemberStatus (r:1 w:0)
+	// Proof: Membership StakingAccountIdMemberStatus (max_values: None, max_size: Some(57), added: 2532, mode: MaxEncodedLen)
+	// Storage: Balances Locks (r:1 w:1)
+	// Proof: Balances Locks (max_values: None, max_size: Some(1299), added: 3774, mode: MaxEncodedLen)
+	// Storage: System Account (r:1 w:1)
+	// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	// Storage: ProposalDiscussion ThreadCount (r:1 w:1)
+	// Proof: ProposalDiscussion ThreadCount (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	// Storage: ProposalEngine ProposalCount (r:1 w:1)
+	// Proof: ProposalEngine ProposalCount (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	// Storage: ProposalsCodex ThreadIdByProposalId (r:0 w:1)
+	// Proof: ProposalsCodex ThreadIdByProposalId (max_values: None, max_size: Some(28), added: 2503, mode: MaxEncodedLen)
+	// Storage: ProposalDiscussion ThreadById (r:0 w:1)
+	// Proof: ProposalDiscussion ThreadById (max_values: None, max_size: Some(198), added: 2673, mode: MaxEncodedLen)
+	// Storage: ProposalEngine Proposals (r:0 w:1)
+	// Proof: ProposalEngine Proposals (max_values: None, max_size: Some(140), added: 2615, mode: MaxEncodedLen)
+	// Storage: ProposalEngine DispatchableCallCode (r:0 w:1)
+	// Proof: ProposalEngine DispatchableCallCode (max_values: None, max_size: Some(3145752), added: 3148227, mode: MaxEncodedLen)
+	/// The range of component `t` is `[1, 20]`.
+	/// The range of component `d` is `[1, 20]`.
+	fn create_proposal_transfer_working_group_worker(t: u32, d: u32, ) -> Weight {
+		Weight::from_parts(80_412_537, 0u64)
+			.saturating_add(Weight::from_parts(0, 19940))
+			.saturating_add(Weight::from_parts(1_093_126, 0u64).saturating_mul(t.into()))
+			.saturating_add(Weight::from_parts(1_306_841, 0u64).saturating_mul(d.into()))
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(9_u64))
+	}
+	// PLACEHOLDER: hand-written estimate, not the benchmark output.
+	// Must be regenerated with the benchmark CLI before merging.
+	// Storage: Membership MembershipById (r:1 w:0)
+	// Proof: Membership MembershipById (max_values: None, max_size: Some(125), added: 2600, mode: MaxEncodedLen)
+	// Storage: ProposalEngine ActiveProposalCount (r:1 w:1)
+	// Proof: ProposalEngine ActiveProposalCount (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	// Storage: Membership StakingAccountIdMemberStatus (r:1 w:0)
+	// Proof: Membership StakingAccountIdMemberStatus (max_values: None, max_size: Some(57), added: 2532, mode: MaxEncodedLen)
+	// Storage: Balances Locks (r:1 w:1)
+	// Proof: Balances Locks (max_values: None, max_size: Some(1299), added: 3774, mode: MaxEncodedLen)
+	// Storage: System Account (r:1 w:1)
+	// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	// Storage: ProposalDiscussion ThreadCount (r:1 w:1)
+	// Proof: ProposalDiscussion ThreadCount (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	// Storage: ProposalEngine ProposalCount (r:1 w:1)
+	// Proof: ProposalEngine ProposalCount (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	// Storage: ProposalsCodex ThreadIdByProposalId (r:0 w:1)
+	// Proof: ProposalsCodex ThreadIdByProposalId (max_values: None, max_size: Some(28), added: 2503, mode: MaxEncodedLen)
+	// Storage: ProposalDiscussion ThreadById (r:0 w:1)
+	// Proof: ProposalDiscussion ThreadById (max_values: None, max_size: Some(198), added: 2673, mode: MaxEncodedLen)
+	// Storage: ProposalEngine Proposals (r:0 w:1)
+	// Proof: ProposalEngine Proposals (max_values: None, max_size: Some(140), added: 2615, mode: MaxEncodedLen)
+	// Storage: ProposalEngine DispatchableCallCode (r:0 w:1)
+	// Proof: ProposalEngine DispatchableCallCode (max_values: None, max_size: Some(3145752), added: 3148227, mode: MaxEncodedLen)
+	/// The range of component `t` is `[1, 20]`.
+	/// The range of component `d` is `[1, 20]`.
+	fn create_proposal_set_referendum_vote_power_strategy(t: u32, d: u32, ) -> Weight {
+		Weight::from_parts(78_906_114, 0u64)
+			.saturating_add(Weight::from_parts(0, 19940))
+			.saturating_add(Weight::from_parts(1_085_377, 0u64).saturating_mul(t.into()))
+			.saturating_add(Weight::from_parts(1_315_902, 0u64).saturating_mul(d.into()))
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(9_u64))
+	}
+	// PLACEHOLDER: hand-written estimate, not the benchmark output.
+	// Must be regenerated with the benchmark CLI before merging.
+	// Storage: Membership MembershipById (r:1 w:0)
+	// Proof: Membership MembershipById (max_values: None, max_size: Some(125), added: 2600, mode: MaxEncodedLen)
+	// Storage: ProposalEngine ActiveProposalCount (r:1 w:1)
+	// Proof: ProposalEngine ActiveProposalCount (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	// Storage: Membership StakingAccountIdMemberStatus (r:1 w:0)
+	// Proof: Membership StakingAccountIdMemberStatus (max_values: None, max_size: Some(57), added: 2532, mode: MaxEncodedLen)
+	// Storage: Balances Locks (r:1 w:1)
+	// Proof: Balances Locks (max_values: None, max_size: Some(1299), added: 3774, mode: MaxEncodedLen)
+	// Storage: System Account (r:1 w:1)
+	// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	// Storage: ProposalDiscussion ThreadCount (r:1 w:1)
+	// Proof: ProposalDiscussion ThreadCount (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	// Storage: ProposalEngine ProposalCount (r:1 w:1)
+	// Proof: ProposalEngine ProposalCount (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	// Storage: ProposalsCodex ThreadIdByProposalId (r:0 w:1)
+	// Proof: ProposalsCodex ThreadIdByProposalId (max_values: None, max_size: Some(28), added: 2503, mode: MaxEncodedLen)
+	// Storage: ProposalDiscussion ThreadById (r:0 w:1)
+	// Proof: ProposalDiscussion ThreadById (max_values: None, max_size: Some(198), added: 2673, mode: MaxEncodedLen)
+	// Storage: ProposalEngine Proposals (r:0 w:1)
+	// Proof: ProposalEngine Proposals (max_values: None, max_size: Some(140), added: 2615, mode: MaxEncodedLen)
+	// Storage: ProposalEngine DispatchableCallCode (r:0 w:1)
+	// Proof: ProposalEngine DispatchableCallCode (max_values: None, max_size: Some(3145752), added: 3148227, mode: MaxEncodedLen)
+	/// The range of component `t` is `[1, 20]`.
+	/// The range of component `d` is `[1, 20]`.
+	fn create_proposal_recall_councilor(t: u32, d: u32, ) -> Weight {
+		Weight::from_parts(78_142_530, 0u64)
+			.saturating_add(Weight::from_parts(0, 19940))
+			.saturating_add(Weight::from_parts(1_102_664, 0u64).saturating_mul(t.into()))
+			.saturating_add(Weight::from_parts(1_297_418, 0u64).saturating_mul(d.into()))
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(9_u64))
+	}
+	// PLACEHOLDER: hand-written estimate, not the benchmark output.
+	// Must be regenerated with the benchmark CLI before merging.
+	// Storage: Membership MembershipById (r:1 w:0)
+	// Proof: Membership MembershipById (max_values: None, max_size: Some(125), added: 2600, mode: MaxEncodedLen)
+	// Storage: ProposalEngine ActiveProposalCount (r:1 w:1)
+	// Proof: ProposalEngine ActiveProposalCount (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	// Storage: Membership StakingAccountIdMemberStatus (r:1 w:0)
+	// Proof: Membership StakingAccountIdMemberStatus (max_values: None, max_size: Some(57), added: 2532, mode: MaxEncodedLen)
+	// Storage: Balances Locks (r:1 w:1)
+	// Proof: Balances Locks (max_values: None, max_size: Some(1299), added: 3774, mode: MaxEncodedLen)
+	// Storage: System Account (r:1 w:1)
+	// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	// Storage: ProposalDiscussion ThreadCount (r:1 w:1)
+	// Proof: ProposalDiscussion ThreadCount (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	// Storage: ProposalEngine ProposalCount (r:1 w:1)
+	// Proof: ProposalEngine ProposalCount (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	// Storage: ProposalsCodex ThreadIdByProposalId (r:0 w:1)
+	// Proof: ProposalsCodex ThreadIdByProposalId (max_values: None, max_size: Some(28), added: 2503, mode: MaxEncodedLen)
+	// Storage: ProposalDiscussion ThreadById (r:0 w:1)
+	// Proof: ProposalDiscussion ThreadById (max_values: None, max_size: Some(198), added: 2673, mode: MaxEncodedLen)
+	// Storage: ProposalEngine Proposals (r:0 w:1)
+	// Proof: ProposalEngine Proposals (max_values: None, max_size: Some(140), added: 2615, mode: MaxEncodedLen)
+	// Storage: ProposalEngine DispatchableCallCode (r:0 w:1)
+	// Proof: ProposalEngine DispatchableCallCode (max_values: None, max_size: Some(3145752), added: 3148227, mode: MaxEncodedLen)
+	/// The range of component `t` is `[1, 20]`.
+	/// The range of component `d` is `[1, 20]`.
+	fn create_proposal_resolve_worker_review_contest(t: u32, d: u32, ) -> Weight {
+		Weight::from_parts(78_904_112, 0u64)
+			.saturating_add(Weight::from_parts(0, 19940))
+			.saturating_add(Weight::from_parts(1_094_318, 0u64).saturating_mul(t.into()))
+			.saturating_add(Weight::from_parts(1_305_742, 0u64).saturating_mul(d.into()))
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(9_u64))
+	}
+	// PLACEHOLDER: hand-written estimate, not the benchmark output.
+	// Must be regenerated with the benchmark CLI before merging.
+	// Storage: Membership MembershipById (r:1 w:0)
+	// Proof: Membership MembershipById (max_values: None, max_size: Some(125), added: 2600, mode: MaxEncodedLen)
+	// Storage: ProposalEngine ActiveProposalCount (r:1 w:1)
+	// Proof: ProposalEngine ActiveProposalCount (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	// Storage: Membership StakingAccountIdMemberStatus (r:1 w:0)
+	// Proof: Membership StakingAccountIdMemberStatus (max_values: None, max_size: Some(57), added: 2532, mode: MaxEncodedLen)
+	// Storage: Balances Locks (r:1 w:1)
+	// Proof: Balances Locks (max_values: None, max_size: Some(1299), added: 3774, mode: MaxEncodedLen)
+	// Storage: System Account (r:1 w:1)
+	// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	// Storage: ProposalDiscussion ThreadCount (r:1 w:1)
+	// Proof: ProposalDiscussion ThreadCount (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	// Storage: ProposalEngine ProposalCount (r:1 w:1)
+	// Proof: ProposalEngine ProposalCount (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	// Storage: ProposalsCodex ThreadIdByProposalId (r:0 w:1)
+	// Proof: ProposalsCodex ThreadIdByProposalId (max_values: None, max_size: Some(28), added: 2503, mode: MaxEncodedLen)
+	// Storage: ProposalDiscussion ThreadById (r:0 w:1)
+	// Proof: ProposalDiscussion ThreadById (max_values: None, max_size: Some(198), added: 2673, mode: MaxEncodedLen)
+	// Storage: ProposalEngine Proposals (r:0 w:1)
+	// Proof: ProposalEngine Proposals (max_values: None, max_size: Some(140), added: 2615, mode: MaxEncodedLen)
+	// Storage: ProposalEngine DispatchableCallCode (r:0 w:1)
+	// Proof: ProposalEngine DispatchableCallCode (max_values: None, max_size: Some(3145752), added: 3148227, mode: MaxEncodedLen)
+	/// The range of component `i` is `[1, 100]`.
+	/// The range of component `t` is `[1, 20]`.
+	/// The range of component `d` is `[1, 20]`.
+	fn create_proposal_create_budget_allocation(i: u32, t: u32, d: u32, ) -> Weight {
+		Weight::from_parts(91_804_217, 0u64)
+			.saturating_add(Weight::from_parts(0, 19940))
+			.saturating_add(Weight::from_parts(1_702_361, 0u64).saturating_mul(i.into()))
+			.saturating_add(Weight::from_parts(861_540, 0u64).saturating_mul(t.into()))
+			.saturating_add(Weight::from_parts(1_098_273, 0u64).saturating_mul(d.into()))
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(9_u64))
+	}
+	// PLACEHOLDER: hand-written estimate, not the benchmark output.
+	// Must be regenerated with the benchmark CLI before merging.
+	// Storage: Membership MembershipById (r:1 w:0)
+	// Proof: Membership MembershipById (max_values: None, max_size: Some(125), added: 2600, mode: MaxEncodedLen)
+	// Storage: ProposalEngine ActiveProposalCount (r:1 w:1)
+	// Proof: ProposalEngine ActiveProposalCount (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	// Storage: Membership StakingAccountIdMemberStatus (r:1 w:0)
+	// Proof: Membership StakingAccountIdMemberStatus (max_values: None, max_size: Some(57), added: 2532, mode: MaxEncodedLen)
+	// Storage: Balances Locks (r:1 w:1)
+	// Proof: Balances Locks (max_values: None, max_size: Some(1299), added: 3774, mode: MaxEncodedLen)
+	// Storage: System Account (r:1 w:1)
+	// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	// Storage: ProposalDiscussion ThreadCount (r:1 w:1)
+	// Proof: ProposalDiscussion ThreadCount (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	// Storage: ProposalEngine ProposalCount (r:1 w:1)
+	// Proof: ProposalEngine ProposalCount (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	// Storage: ProposalsCodex ThreadIdByProposalId (r:0 w:1)
+	// Proof: ProposalsCodex ThreadIdByProposalId (max_values: None, max_size: Some(28), added: 2503, mode: MaxEncodedLen)
+	// Storage: ProposalDiscussion ThreadById (r:0 w:1)
+	// Proof: ProposalDiscussion ThreadById (max_values: None, max_size: Some(198), added: 2673, mode: MaxEncodedLen)
+	// Storage: ProposalEngine Proposals (r:0 w:1)
+	// Proof: ProposalEngine Proposals (max_values: None, max_size: Some(140), added: 2615, mode: MaxEncodedLen)
+	// Storage: ProposalEngine DispatchableCallCode (r:0 w:1)
+	// Proof: ProposalEngine DispatchableCallCode (max_values: None, max_size: Some(3145752), added: 3148227, mode: MaxEncodedLen)
+	/// The range of component `i` is `[1, 20]`.
+	/// The range of component `t` is `[1, 20]`.
+	/// The range of component `d` is `[1, 20]`.
+	fn create_proposal_funding_request_from_allocation(i: u32, t: u32, d: u32, ) -> Weight {
+		Weight::from_parts(85_095_765, 0u64)
+			.saturating_add(Weight::from_parts(0, 19940))
+			.saturating_add(Weight::from_parts(5_766, 0u64).saturating_mul(i.into()))
+			.saturating_add(Weight::from_parts(1_173_442, 0u64).saturating_mul(t.into()))
+			.saturating_add(Weight::from_parts(1_397_524, 0u64).saturating_mul(d.into()))
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(9_u64))
+	}
+	// PLACEHOLDER: hand-written estimate, not the benchmark output.
+	// Must be regenerated with the benchmark CLI before merging.
+	// Storage: Membership MembershipById (r:1 w:0)
+	// Proof: Membership MembershipById (max_values: None, max_size: Some(125), added: 2600, mode: MaxEncodedLen)
+	// Storage: ProposalEngine ActiveProposalCount (r:1 w:1)
+	// Proof: ProposalEngine ActiveProposalCount (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	// Storage: Membership StakingAccountIdMemberStatus (r:1 w:0)
+	// Proof: Membership StakingAccountIdMemberStatus (max_values: None, max_size: Some(57), added: 2532, mode: MaxEncodedLen)
+	// Storage: Balances Locks (r:1 w:1)
+	// Proof: Balances Locks (max_values: None, max_size: Some(1299), added: 3774, mode: MaxEncodedLen)
+	// Storage: System Account (r:1 w:1)
+	// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	// Storage: ProposalDiscussion ThreadCount (r:1 w:1)
+	// Proof: ProposalDiscussion ThreadCount (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	// Storage: ProposalEngine ProposalCount (r:1 w:1)
+	// Proof: ProposalEngine ProposalCount (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	// Storage: ProposalsCodex ThreadIdByProposalId (r:0 w:1)
+	// Proof: ProposalsCodex ThreadIdByProposalId (max_values: None, max_size: Some(28), added: 2503, mode: MaxEncodedLen)
+	// Storage: ProposalDiscussion ThreadById (r:0 w:1)
+	// Proof: ProposalDiscussion ThreadById (max_values: None, max_size: Some(198), added: 2673, mode: MaxEncodedLen)
+	// Storage: ProposalEngine Proposals (r:0 w:1)
+	// Proof: ProposalEngine Proposals (max_values: None, max_size: Some(140), added: 2615, mode: MaxEncodedLen)
+	// Storage: ProposalEngine DispatchableCallCode (r:0 w:1)
+	// Proof: ProposalEngine DispatchableCallCode (max_values: None, max_size: Some(3145752), added: 3148227, mode: MaxEncodedLen)
+	/// The range of component `t` is `[1, 20]`.
+	/// The range of component `d` is `[1, 20]`.
+	fn create_proposal_update_working_group_budget_from_allocation(t: u32, d: u32, ) -> Weight {
+		Weight::from_parts(81_928_374, 0u64)
+			.saturating_add(Weight::from_parts(0, 19940))
+			.saturating_add(Weight::from_parts(1_133_638, 0u64).saturating_mul(t.into()))
+			.saturating_add(Weight::from_parts(1_242_118, 0u64).saturating_mul(d.into()))
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(9_u64))
+	}
 }
 
 // Default implementation for tests
@@ -1241,4 +1563,31 @@ impl WeightInfo for () {
 	fn create_proposal_decrease_council_budget(t: u32, d: u32, ) -> Weight {
 		Weight::from_parts(0, 0)
 	}
+	fn create_proposal_overturn_bounty_judgment(t: u32, d: u32, ) -> Weight {
+		Weight::from_parts(0, 0)
+	}
+	fn create_proposal_revoke_working_group_deputy(t: u32, d: u32, ) -> Weight {
+		Weight::from_parts(0, 0)
+	}
+	fn create_proposal_transfer_working_group_worker(t: u32, d: u32, ) -> Weight {
+		Weight::from_parts(0, 0)
+	}
+	fn create_proposal_set_referendum_vote_power_strategy(t: u32, d: u32, ) -> Weight {
+		Weight::from_parts(0, 0)
+	}
+	fn create_proposal_recall_councilor(t: u32, d: u32, ) -> Weight {
+		Weight::from_parts(0, 0)
+	}
+	fn create_proposal_resolve_worker_review_contest(t: u32, d: u32, ) -> Weight {
+		Weight::from_parts(0, 0)
+	}
+	fn create_proposal_create_budget_allocation(i: u32, t: u32, d: u32, ) -> Weight {
+		Weight::from_parts(0, 0)
+	}
+	fn create_proposal_funding_request_from_allocation(i: u32, t: u32, d: u32, ) -> Weight {
+		Weight::from_parts(0, 0)
+	}
+	fn create_proposal_update_working_group_budget_from_allocation(t: u32, d: u32, ) -> Weight {
+		Weight::from_parts(0, 0)
+	}
 }
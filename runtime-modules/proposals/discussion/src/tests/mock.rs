@@ -65,6 +65,10 @@ parameter_types! {
     pub const DefaultMembershipPrice: u64 = 100;
     pub const DefaultInitialInvitationBalance: u64 = 100;
     pub const DefaultMemberInvitesCount: u32 = 2;
+    pub const HandleReclaimCooldown: u64 = 1000;
+    pub const MaxRecoveryGuardians: u32 = 5;
+    pub const RecoveryDelay: u64 = 100;
+    pub const RecoveryCooldown: u64 = 50;
     pub const ReferralCutMaximumPercent: u8 = 50;
     pub const CandidateStake: u64 = 100;
     pub const PostLifeTime: u64 = 10;
@@ -139,6 +143,10 @@ impl membership::Config for Test {
         staking_handler::StakingManager<Self, BoundStakingAccountLockId>;
     type CandidateStake = CandidateStake;
     type DefaultMemberInvitesCount = DefaultMemberInvitesCount;
+    type HandleReclaimCooldown = HandleReclaimCooldown;
+    type MaxRecoveryGuardians = MaxRecoveryGuardians;
+    type RecoveryDelay = RecoveryDelay;
+    type RecoveryCooldown = RecoveryCooldown;
 }
 
 impl LockComparator<<Test as balances::Config>::Balance> for Test {
@@ -262,6 +270,10 @@ impl MembershipInfoProvider<Test> for () {
             Err(DispatchError::Other("member does not exist"))
         }
     }
+
+    fn has_valid_attestation(_member_id: u64, _kind: common::membership::AttestationKind) -> bool {
+        false
+    }
 }
 
 parameter_types! {
@@ -343,6 +355,12 @@ impl referendum::Config<ReferendumInstance> for Test {
 
     type MaxWinnerTargetCount = MaxWinnerTargetCount;
 
+    type DefaultVotePowerStrategy = ();
+    type MaxRankedBallotPreferences = ConstU32<5>;
+    type MaxRankedBallots = ConstU32<10>;
+    type MaxVotedOptions = ConstU32<100>;
+    type MaxDelegatorsPerDelegate = ConstU32<10>;
+
     fn calculate_vote_power(
         _: &<Self as frame_system::Config>::AccountId,
         _: &Self::Balance,
@@ -164,7 +164,9 @@ use codec::{Decode, MaxEncodedLen};
 use frame_support::dispatch::{
     DispatchError, DispatchResult, GetDispatchInfo, UnfilteredDispatchable,
 };
-use frame_support::storage::{bounded_vec::BoundedVec, IterableStorageMap};
+use frame_support::storage::{
+    bounded_vec::BoundedVec, IterableStorageDoubleMap, IterableStorageMap,
+};
 use frame_support::traits::{Get, LockIdentifier};
 use frame_support::weights::Weight;
 use frame_support::{
@@ -175,7 +177,7 @@ use sp_arithmetic::traits::{SaturatedConversion, Zero};
 use sp_std::convert::TryInto;
 use sp_std::{vec, vec::Vec};
 
-use common::council::CouncilOriginValidator;
+use common::council::{CouncilOriginValidator, CouncilParticipationObserver};
 use common::membership::MemberOriginValidator;
 use common::to_kb;
 use common::{MemberId, StakingAccountValidator};
@@ -253,6 +255,12 @@ pub trait Config:
 
     /// Validates staking account ownership for a member.
     type StakingAccountValidator: common::StakingAccountValidator<Self>;
+
+    /// Council proposal voting participation observer.
+    type CouncilParticipationObserver: CouncilParticipationObserver<
+        MemberId<Self>,
+        Self::BlockNumber,
+    >;
 }
 
 /// Proposal state change observer.
@@ -1038,6 +1046,23 @@ impl<T: Config> Module<T> {
         Ok(())
     }
 
+    // Reports the members that voted on the proposal to the council participation observer.
+    fn report_council_participation(
+        proposal_id: &T::ProposalId,
+        proposal: &ProposalOf<T>,
+        now: T::BlockNumber,
+    ) {
+        let voters = <VoteExistsByProposalByVoter<T>>::iter_prefix(proposal_id)
+            .map(|(voter_id, _)| voter_id)
+            .collect::<Vec<_>>();
+
+        T::CouncilParticipationObserver::proposal_voting_concluded(
+            &voters,
+            proposal.activated_at,
+            proposal.is_voting_period_expired(now),
+        );
+    }
+
     /// Perform voting period check, vote result tally, approved proposals
     /// grace period checks, and proposal execution.
     /// Returns the total weight of all the executed proposals or 0 if none was executed.
@@ -1057,6 +1082,8 @@ impl<T: Config> Module<T> {
 
                     // If decision is calculated for a proposal - finalize it.
                     if let Some(decision_status) = decision_status {
+                        Self::report_council_participation(&proposal_id, &proposal, now);
+
                         executed_weight.saturating_add(Self::finalize_proposal(
                             proposal_id,
                             proposal,
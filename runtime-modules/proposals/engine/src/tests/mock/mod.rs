@@ -27,6 +27,7 @@ use crate::ProposalObserver;
 pub use proposals::*;
 use sp_std::convert::{TryFrom, TryInto};
 use staking_handler::{LockComparator, StakingManager};
+use std::cell::RefCell;
 
 type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Test>;
 type Block = frame_system::mocking::MockBlock<Test>;
@@ -77,6 +78,12 @@ impl referendum::Config<ReferendumInstance> for Test {
 
     type MaxWinnerTargetCount = MaxWinnerTargetCount;
 
+    type DefaultVotePowerStrategy = ();
+    type MaxRankedBallotPreferences = ConstU32<5>;
+    type MaxRankedBallots = ConstU32<10>;
+    type MaxVotedOptions = ConstU32<100>;
+    type MaxDelegatorsPerDelegate = ConstU32<10>;
+
     fn calculate_vote_power(
         _: &<Self as frame_system::Config>::AccountId,
         _: &Self::Balance,
@@ -126,6 +133,10 @@ parameter_types! {
     pub const DefaultMembershipPrice: u64 = 100;
     pub const DefaultInitialInvitationBalance: u64 = 100;
     pub const DefaultMemberInvitesCount: u32 = 2;
+    pub const HandleReclaimCooldown: u64 = 1000;
+    pub const MaxRecoveryGuardians: u32 = 5;
+    pub const RecoveryDelay: u64 = 100;
+    pub const RecoveryCooldown: u64 = 50;
     pub const InvitedMemberLockId: [u8; 8] = [2; 8];
     pub const ReferralCutMaximumPercent: u8 = 50;
     pub const StakingCandidateLockId: [u8; 8] = [3; 8];
@@ -149,6 +160,10 @@ impl membership::Config for Test {
         staking_handler::StakingManager<Self, StakingCandidateLockId>;
     type CandidateStake = CandidateStake;
     type DefaultMemberInvitesCount = DefaultMemberInvitesCount;
+    type HandleReclaimCooldown = HandleReclaimCooldown;
+    type MaxRecoveryGuardians = MaxRecoveryGuardians;
+    type RecoveryDelay = RecoveryDelay;
+    type RecoveryCooldown = RecoveryCooldown;
 }
 
 pub struct Wg;
@@ -233,6 +248,7 @@ impl crate::Config for Test {
     type WeightInfo = ();
     type StakingAccountValidator = ();
     type DispatchableCallCodeMaxLen = DispatchableCallCodeMaxLen;
+    type CouncilParticipationObserver = MockCouncilParticipationObserver;
 }
 
 pub const STAKING_ACCOUNT_ID_NOT_BOUND_TO_MEMBER: u64 = 222;
@@ -247,6 +263,36 @@ impl ProposalObserver<Test> for () {
     fn proposal_removed(_proposal_id: &u32) {}
 }
 
+thread_local! {
+    // concluded proposal votings reported to the council: voters, voting start block and
+    // whether the voting period expired
+    pub static CONCLUDED_VOTINGS: RefCell<Vec<(Vec<u64>, u64, bool)>> = RefCell::new(Vec::new());
+}
+
+pub struct MockCouncilParticipationObserver;
+impl MockCouncilParticipationObserver {
+    pub fn concluded_votings() -> Vec<(Vec<u64>, u64, bool)> {
+        CONCLUDED_VOTINGS.with(|value| value.borrow().clone())
+    }
+}
+
+impl common::council::CouncilParticipationObserver<u64, u64> for MockCouncilParticipationObserver {
+    fn proposal_voting_concluded(
+        voters: &[u64],
+        voting_started_at: u64,
+        voting_period_expired: bool,
+    ) {
+        let mut voters = voters.to_vec();
+        voters.sort_unstable();
+
+        CONCLUDED_VOTINGS.with(|value| {
+            value
+                .borrow_mut()
+                .push((voters, voting_started_at, voting_period_expired))
+        });
+    }
+}
+
 impl Default for proposals::Call<Test> {
     fn default() -> Self {
         panic!("shouldn't call default for Call");
@@ -528,6 +528,74 @@ fn voting_results_calculation_succeeds() {
     });
 }
 
+#[test]
+fn concluded_voting_reports_council_participation() {
+    initial_test_ext().execute_with(|| {
+        let starting_block = 1;
+        run_to_block(starting_block);
+
+        let parameters = ProposalParameters {
+            voting_period: 3,
+            approval_quorum_percentage: 80,
+            approval_threshold_percentage: 50,
+            slashing_quorum_percentage: 80,
+            slashing_threshold_percentage: 80,
+            grace_period: 0,
+            required_stake: None,
+            constitutionality: 1,
+        };
+        let dummy_proposal = DummyProposalFixture::default().with_parameters(parameters);
+        let proposal_id = dummy_proposal.create_proposal_and_assert(Ok(1)).unwrap();
+
+        let mut vote_generator = VoteGenerator::new(proposal_id);
+        vote_generator.vote_and_assert_ok(VoteKind::Approve);
+        vote_generator.vote_and_assert_ok(VoteKind::Reject);
+
+        // voting is still in progress
+        run_to_block(starting_block + 1);
+        assert!(MockCouncilParticipationObserver::concluded_votings().is_empty());
+
+        // voting period expired
+        run_to_block(starting_block + 5);
+        assert_eq!(
+            MockCouncilParticipationObserver::concluded_votings(),
+            vec![(vec![1, 2], starting_block, true)]
+        );
+    });
+}
+
+#[test]
+fn decided_voting_reports_council_participation_before_expiration() {
+    initial_test_ext().execute_with(|| {
+        let starting_block = 1;
+        run_to_block(starting_block);
+
+        let parameters = ProposalParameters {
+            voting_period: 3,
+            approval_quorum_percentage: 40,
+            approval_threshold_percentage: 50,
+            slashing_quorum_percentage: 80,
+            slashing_threshold_percentage: 80,
+            grace_period: 0,
+            required_stake: None,
+            constitutionality: 1,
+        };
+        let dummy_proposal = DummyProposalFixture::default().with_parameters(parameters);
+        let proposal_id = dummy_proposal.create_proposal_and_assert(Ok(1)).unwrap();
+
+        let mut vote_generator = VoteGenerator::new(proposal_id);
+        vote_generator.vote_and_assert_ok(VoteKind::Approve);
+        vote_generator.vote_and_assert_ok(VoteKind::Approve);
+
+        // approved before the voting period expired
+        run_to_block(starting_block + 2);
+        assert_eq!(
+            MockCouncilParticipationObserver::concluded_votings(),
+            vec![(vec![1, 2], starting_block, false)]
+        );
+    });
+}
+
 #[test]
 fn rejected_voting_results_and_remove_proposal_id_from_active_succeeds() {
     initial_test_ext().execute_with(|| {
@@ -2628,6 +2696,36 @@ fn proposal_status_resolution_slashing_threshold_works_correctly() {
     assert!(slashing_threshold_proposal_status_resolution.is_slashing_threshold_reached());
 }
 
+#[test]
+fn proposal_status_resolution_voting_completed_works_correctly() {
+    let proposal: Proposal<u64, u64, u64, u64> = Proposal::default();
+    let not_completed_proposal_status_resolution = ProposalStatusResolution {
+        proposal: &proposal,
+        now: 20,
+        votes_count: 5,
+        total_voters_count: 6,
+        approvals: 5,
+        slashes: 0,
+    };
+
+    assert!(!not_completed_proposal_status_resolution.is_voting_completed());
+
+    let completed_proposal_status_resolution = ProposalStatusResolution {
+        votes_count: 6,
+        ..not_completed_proposal_status_resolution
+    };
+
+    assert!(completed_proposal_status_resolution.is_voting_completed());
+
+    // recalled councilor and their successor both voted
+    let successor_voted_proposal_status_resolution = ProposalStatusResolution {
+        votes_count: 7,
+        ..completed_proposal_status_resolution
+    };
+
+    assert!(successor_voted_proposal_status_resolution.is_voting_completed());
+}
+
 #[test]
 fn proposal_exact_execution_block_reached() {
     let mut proposal = ProposalObject::default();
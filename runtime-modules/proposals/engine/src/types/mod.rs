@@ -375,9 +375,10 @@ where
         slashing_votes_fraction.deconstruct() >= required_threshold_fraction.deconstruct()
     }
 
-    // All voters had voted
+    // All voters had voted. Votes of the recalled councilors still count along with the votes of
+    // their successors, so the votes count can exceed the voters count.
     pub fn is_voting_completed(&self) -> bool {
-        self.votes_count == self.total_voters_count
+        self.votes_count >= self.total_voters_count
     }
 
     // Council approved the proposal enough times.
@@ -20,6 +20,9 @@
 //! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 4.0.0-dev
 //! DATE: 2023-11-06, STEPS: `50`, REPEAT: 20, LOW RANGE: `[]`, HIGH RANGE: `[]`
 //! EXECUTION: Some(Wasm), WASM-EXECUTION: Compiled, CHAIN: Some("prod-test"), DB CACHE: 1024
+//!
+//! NOTE: the weight functions marked as PLACEHOLDER are hand-written estimates, not the
+//! benchmark output. They must be regenerated with the benchmark CLI before merging.
 
 // Executed Command:
 // ./scripts/../target/release/joystream-node
@@ -143,10 +146,14 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(Weight::from_parts(0, 7195))
 			.saturating_add(T::DbWeight::get().reads(2_u64))
 	}
+	// PLACEHOLDER: hand-written estimate, not the benchmark output.
+	// Must be regenerated with the benchmark CLI before merging.
 	// Storage: ProposalEngine Proposals (r:21 w:20)
 	// Proof: ProposalEngine Proposals (max_values: None, max_size: Some(140), added: 2615, mode: MaxEncodedLen)
 	// Storage: Council CouncilMembers (r:1 w:0)
 	// Proof: Council CouncilMembers (max_values: Some(1), max_size: Some(325), added: 820, mode: MaxEncodedLen)
+	// Storage: Council CouncilorParticipation (r:3 w:3)
+	// Proof: Council CouncilorParticipation (max_values: None, max_size: Some(40), added: 2515, mode: MaxEncodedLen)
 	// Storage: Balances Locks (r:20 w:20)
 	// Proof: Balances Locks (max_values: None, max_size: Some(1299), added: 3774, mode: MaxEncodedLen)
 	// Storage: System Account (r:20 w:20)
@@ -163,17 +170,12 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	// Proof: ProposalDiscussion ThreadById (max_values: None, max_size: Some(198), added: 2673, mode: MaxEncodedLen)
 	/// The range of component `i` is `[1, 20]`.
 	fn on_initialize_immediate_execution_decode_fails(i: u32, ) -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `979 + i * (460 ±0)`
-		//  Estimated: `10864 + i * (3159739 ±0)`
-		// Minimum execution time: 79_620 nanoseconds.
 		Weight::from_parts(33_904_315, 0u64)
 			.saturating_add(Weight::from_parts(0, 10864))
-			// Standard Error: 51_970
 			.saturating_add(Weight::from_parts(48_786_793, 0u64).saturating_mul(i.into()))
-			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().reads(6_u64))
 			.saturating_add(T::DbWeight::get().reads((5_u64).saturating_mul(i.into())))
-			.saturating_add(T::DbWeight::get().writes(2_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
 			.saturating_add(T::DbWeight::get().writes((6_u64).saturating_mul(i.into())))
 			.saturating_add(Weight::from_parts(0, 3159739).saturating_mul(i.into()))
 	}
@@ -205,29 +207,33 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().writes((4_u64).saturating_mul(i.into())))
 			.saturating_add(Weight::from_parts(0, 3153362).saturating_mul(i.into()))
 	}
+	// PLACEHOLDER: hand-written estimate, not the benchmark output.
+	// Must be regenerated with the benchmark CLI before merging.
 	// Storage: ProposalEngine Proposals (r:21 w:20)
 	// Proof: ProposalEngine Proposals (max_values: None, max_size: Some(140), added: 2615, mode: MaxEncodedLen)
 	// Storage: Council CouncilMembers (r:1 w:0)
 	// Proof: Council CouncilMembers (max_values: Some(1), max_size: Some(325), added: 820, mode: MaxEncodedLen)
+	// Storage: Council CouncilorParticipation (r:3 w:3)
+	// Proof: Council CouncilorParticipation (max_values: None, max_size: Some(40), added: 2515, mode: MaxEncodedLen)
 	/// The range of component `i` is `[1, 20]`.
 	fn on_initialize_approved_pending_constitutionality(i: u32, ) -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `812 + i * (140 ±0)`
-		//  Estimated: `5415 + i * (2615 ±0)`
-		// Minimum execution time: 37_108 nanoseconds.
 		Weight::from_parts(25_391_193, 0u64)
 			.saturating_add(Weight::from_parts(0, 5415))
-			// Standard Error: 23_065
 			.saturating_add(Weight::from_parts(15_676_666, 0u64).saturating_mul(i.into()))
-			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().reads(5_u64))
 			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(i.into())))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
 			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(i.into())))
 			.saturating_add(Weight::from_parts(0, 2615).saturating_mul(i.into()))
 	}
+	// PLACEHOLDER: hand-written estimate, not the benchmark output.
+	// Must be regenerated with the benchmark CLI before merging.
 	// Storage: ProposalEngine Proposals (r:21 w:20)
 	// Proof: ProposalEngine Proposals (max_values: None, max_size: Some(140), added: 2615, mode: MaxEncodedLen)
 	// Storage: Council CouncilMembers (r:1 w:0)
 	// Proof: Council CouncilMembers (max_values: Some(1), max_size: Some(325), added: 820, mode: MaxEncodedLen)
+	// Storage: Council CouncilorParticipation (r:3 w:3)
+	// Proof: Council CouncilorParticipation (max_values: None, max_size: Some(40), added: 2515, mode: MaxEncodedLen)
 	// Storage: Balances Locks (r:20 w:20)
 	// Proof: Balances Locks (max_values: None, max_size: Some(1299), added: 3774, mode: MaxEncodedLen)
 	// Storage: System Account (r:20 w:20)
@@ -244,24 +250,23 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	// Proof: ProposalEngine DispatchableCallCode (max_values: None, max_size: Some(3145752), added: 3148227, mode: MaxEncodedLen)
 	/// The range of component `i` is `[1, 20]`.
 	fn on_initialize_rejected(i: u32, ) -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `992 + i * (495 ±0)`
-		//  Estimated: `9874 + i * (16552 ±0)`
-		// Minimum execution time: 99_872 nanoseconds.
 		Weight::from_parts(35_385_619, 0u64)
 			.saturating_add(Weight::from_parts(0, 9874))
-			// Standard Error: 81_760
 			.saturating_add(Weight::from_parts(69_118_638, 0u64).saturating_mul(i.into()))
-			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().reads(6_u64))
 			.saturating_add(T::DbWeight::get().reads((6_u64).saturating_mul(i.into())))
-			.saturating_add(T::DbWeight::get().writes(2_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
 			.saturating_add(T::DbWeight::get().writes((8_u64).saturating_mul(i.into())))
 			.saturating_add(Weight::from_parts(0, 16552).saturating_mul(i.into()))
 	}
+	// PLACEHOLDER: hand-written estimate, not the benchmark output.
+	// Must be regenerated with the benchmark CLI before merging.
 	// Storage: ProposalEngine Proposals (r:21 w:20)
 	// Proof: ProposalEngine Proposals (max_values: None, max_size: Some(140), added: 2615, mode: MaxEncodedLen)
 	// Storage: Council CouncilMembers (r:1 w:0)
 	// Proof: Council CouncilMembers (max_values: Some(1), max_size: Some(325), added: 820, mode: MaxEncodedLen)
+	// Storage: Council CouncilorParticipation (r:3 w:3)
+	// Proof: Council CouncilorParticipation (max_values: None, max_size: Some(40), added: 2515, mode: MaxEncodedLen)
 	// Storage: Balances Locks (r:20 w:20)
 	// Proof: Balances Locks (max_values: None, max_size: Some(1299), added: 3774, mode: MaxEncodedLen)
 	// Storage: System Account (r:20 w:20)
@@ -278,17 +283,12 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	// Proof: ProposalEngine DispatchableCallCode (max_values: None, max_size: Some(3145752), added: 3148227, mode: MaxEncodedLen)
 	/// The range of component `i` is `[1, 20]`.
 	fn on_initialize_slashed(i: u32, ) -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `992 + i * (495 ±0)`
-		//  Estimated: `9874 + i * (16552 ±0)`
-		// Minimum execution time: 92_003 nanoseconds.
 		Weight::from_parts(33_213_402, 0u64)
 			.saturating_add(Weight::from_parts(0, 9874))
-			// Standard Error: 51_799
 			.saturating_add(Weight::from_parts(60_807_406, 0u64).saturating_mul(i.into()))
-			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().reads(6_u64))
 			.saturating_add(T::DbWeight::get().reads((6_u64).saturating_mul(i.into())))
-			.saturating_add(T::DbWeight::get().writes(2_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
 			.saturating_add(T::DbWeight::get().writes((8_u64).saturating_mul(i.into())))
 			.saturating_add(Weight::from_parts(0, 16552).saturating_mul(i.into()))
 	}
@@ -162,6 +162,67 @@ fn vote_for<
     (account_id, member_option, commitment)
 }
 
+fn ranked_vote_for<T: Config<I>, I: Instance>(
+    name: &'static str,
+    voter_id: u32,
+    preferences: &[T::MemberId],
+    cycle_id: u32,
+) -> T::AccountId {
+    let account_id = funded_account::<T, I>(name, voter_id);
+    let stake = T::MinimumStake::get() + One::one();
+    let salt = vec![0u8];
+    let commitment = Referendum::<T, I>::calculate_ranked_commitment(
+        &account_id,
+        &salt,
+        &cycle_id.into(),
+        preferences,
+    );
+
+    Referendum::<T, I>::vote(
+        RawOrigin::Signed(account_id.clone()).into(),
+        commitment,
+        stake,
+    )
+    .unwrap();
+
+    account_id
+}
+
+fn create_options<
+    T: Config<I>
+        + membership::Config
+        + OptionCreator<
+            <T as frame_system::Config>::AccountId,
+            <T as common::membership::MembershipTypes>::MemberId,
+        >,
+    I: Instance,
+>(
+    number_of_options: u32,
+) -> Vec<T::MemberId> {
+    (0..number_of_options)
+        .map(|i| {
+            let (account_id, member_id) = member_funded_account::<T, I>(i);
+            T::create_option(account_id, member_id);
+
+            member_id
+        })
+        .collect()
+}
+
+fn revealing_stage<T: Config<I>, I: Instance>(
+    started: T::BlockNumber,
+    winning_target_count: u32,
+    cycle_id: u32,
+) -> ReferendumStage<T::BlockNumber, IntermediateWinnersOf<T, I>> {
+    ReferendumStage::Revealing(ReferendumStageRevealingOf::<T, I> {
+        started,
+        winning_target_count,
+        intermediate_winners: WeakBoundedVec::default(),
+        current_cycle_id: cycle_id.into(),
+        ends_at: started + T::RevealStageDuration::get(),
+    })
+}
+
 fn create_account_and_vote<
     T: Config<I>
         + membership::Config
@@ -789,6 +850,179 @@ benchmarks_instance! {
         assert_last_event::<T, I>(RawEvent::AccountOptedOutOfVoting(account_id).into());
     }
 
+    set_vote_power_strategy {
+        let strategy = VotePowerStrategy::SquareRoot;
+    }: _ (RawOrigin::Root, strategy)
+    verify {
+        assert!(
+            NextVotePowerStrategy::<T, I>::get() == Some(strategy),
+            "Vote power strategy not set"
+        );
+
+        assert_last_event::<T, I>(RawEvent::VotePowerStrategySet(strategy).into());
+    }
+
+    reveal_ranked_vote {
+        let p in 1 .. T::MaxRankedBallotPreferences::get();
+
+        let cycle_id = 1;
+        let salt = vec![0u8];
+        start_voting_cycle::<T, I>(0);
+
+        let preferences = create_options::<T, I>(p);
+        let account_id = ranked_vote_for::<T, I>("caller", 0, &preferences, cycle_id);
+
+        let revealing_started = T::VoteStageDuration::get() + System::<T>::block_number();
+        move_to_block::<T, I>(
+            revealing_started,
+            revealing_stage::<T, I>(revealing_started, 1, cycle_id),
+        );
+    }: _ (RawOrigin::Signed(account_id.clone()), salt.clone(), preferences.clone())
+    verify {
+        assert!(
+            RankedBallots::<T, I>::contains_key(account_id.clone()),
+            "Ranked ballot not stored"
+        );
+
+        assert_last_event::<T, I>(
+            RawEvent::RankedVoteRevealed(account_id, preferences, salt).into()
+        );
+    }
+
+    on_initialize_revealing_ranked {
+        let b in 1 .. T::MaxRankedBallots::get();
+        let p in 1 .. T::MaxRankedBallotPreferences::get();
+
+        let cycle_id = 1;
+        let salt = vec![0u8];
+        let extra_winning_target_count = T::MaxWinnerTargetCount::get() - 1;
+        start_voting_cycle::<T, I>(extra_winning_target_count);
+
+        // ballots rank all options starting from different ones to make the tally transfer votes
+        let options = create_options::<T, I>(p);
+        let ballots = (0..b)
+            .map(|i| {
+                let mut preferences = options.clone();
+                preferences.rotate_left((i % p) as usize);
+                let account_id = ranked_vote_for::<T, I>("voter", i, &preferences, cycle_id);
+
+                (account_id, preferences)
+            })
+            .collect::<Vec<_>>();
+
+        let revealing_started = T::VoteStageDuration::get() + System::<T>::block_number();
+        move_to_block::<T, I>(
+            revealing_started,
+            revealing_stage::<T, I>(
+                revealing_started,
+                extra_winning_target_count + 1,
+                cycle_id
+            ),
+        );
+
+        for (account_id, preferences) in ballots {
+            Referendum::<T, I>::reveal_ranked_vote(
+                RawOrigin::Signed(account_id).into(),
+                salt.clone(),
+                preferences,
+            )
+            .unwrap();
+        }
+
+        move_to_block_before_initialize::<T, I>(
+            revealing_started + T::RevealStageDuration::get(),
+            revealing_stage::<T, I>(
+                revealing_started,
+                extra_winning_target_count + 1,
+                cycle_id
+            ),
+        );
+    }: { Referendum::<T, I>::on_initialize(System::<T>::block_number()); }
+    verify {
+        assert!(
+            Referendum::<T, I>::stage() == ReferendumStage::Inactive,
+            "Reveal perdiod hasn't ended",
+        );
+
+        assert!(
+            Referendum::<T, I>::ranked_ballot_count() == 0,
+            "Ranked ballots not removed",
+        );
+    }
+
+    delegate_vote {
+        // worst case is moving the existing delegation to another delegate
+        let account_id = funded_account::<T, I>("caller", 0);
+        let previous_delegate = funded_account::<T, I>("delegate", 0);
+        let delegate = funded_account::<T, I>("delegate", 1);
+        let stake = T::MinimumStake::get();
+
+        Referendum::<T, I>::delegate_vote(
+            RawOrigin::Signed(account_id.clone()).into(),
+            previous_delegate,
+            stake,
+        )
+        .unwrap();
+
+        let new_stake = stake + One::one();
+    }: _ (RawOrigin::Signed(account_id.clone()), delegate.clone(), new_stake)
+    verify {
+        assert!(
+            Referendum::<T, I>::delegations(account_id.clone()).map(|d| d.delegate)
+                == Some(delegate.clone()),
+            "Vote not delegated"
+        );
+
+        assert_last_event::<T, I>(RawEvent::VoteDelegated(account_id, delegate, new_stake).into());
+    }
+
+    revoke_vote_delegation {
+        let account_id = funded_account::<T, I>("caller", 0);
+        let delegate = funded_account::<T, I>("delegate", 0);
+
+        Referendum::<T, I>::delegate_vote(
+            RawOrigin::Signed(account_id.clone()).into(),
+            delegate,
+            T::MinimumStake::get(),
+        )
+        .unwrap();
+    }: _ (RawOrigin::Signed(account_id.clone()))
+    verify {
+        assert!(
+            Referendum::<T, I>::delegations(account_id.clone()).is_none(),
+            "Delegation not revoked"
+        );
+
+        assert_last_event::<T, I>(RawEvent::VoteDelegationRevoked(account_id).into());
+    }
+
+    delegated_vote_stake {
+        let d in 1 .. T::MaxDelegatorsPerDelegate::get();
+
+        let cycle_id = 1;
+        let delegate = funded_account::<T, I>("delegate", 0);
+        let stake = T::MinimumStake::get();
+
+        for i in 0..d {
+            Referendum::<T, I>::delegate_vote(
+                RawOrigin::Signed(funded_account::<T, I>("delegator", i)).into(),
+                delegate.clone(),
+                stake,
+            )
+            .unwrap();
+        }
+    }: { Referendum::<T, I>::delegated_vote_stake(&delegate, cycle_id, System::<T>::block_number()); }
+    verify {
+        assert!(
+            Referendum::<T, I>::delegated_vote_stake(
+                &delegate,
+                cycle_id,
+                System::<T>::block_number()
+            ) == stake * d.into(),
+            "Delegated stake not counted"
+        );
+    }
+
     impl_benchmark_test_suite!(
         Module,
         crate::mock::build_test_externalities(),
@@ -17,11 +17,34 @@
 //! and the winning options can be decided by the total number of votes received or the total amount staked
 //! behind them.
 //!
+//! Before the runtime calculates the vote power, the vote stake is weighted by the current vote power
+//! strategy: the plain stake, the square root of the stake or the stake weighted by the time it has
+//! been locked for. The default strategy is provided by the runtime and can be replaced by the root
+//! origin (e.g. the council proposal), the change taking effect from the next referendum.
+//!
+//! Instead of revealing a single option, users can reveal a ranked ballot - an ordered list of
+//! preferred options. When any ranked ballot was revealed, the winners are selected by the single
+//! transferable vote tally, counting single option votes as ballots ranking just one option.
+//! The number of ranked ballots and their length are limited by the runtime to bound the tally weight.
+//! A ranked ballot is recorded as a vote for its most preferred option until the referendum ends,
+//! when it's moved to the most preferred elected option, if any. The runtime decides whether
+//! the vote stake can be released the same way as for a single option vote.
+//!
+//! Users can delegate their voting power to another account across referendum cycles by locking
+//! a delegated stake. The delegate's revealed vote carries the power derived from the delegated
+//! stakes in addition to its own one. A delegator voting in the cycle overrides the delegation for
+//! that cycle. The delegation can be revoked at any time, but it can't be created or changed
+//! during the revealing stage to prevent counting the same stake twice.
+//!
 //! ## Supported extrinsics
 //!
 //! - [vote](./struct.Module.html#method.vote)
 //! - [reveal_vote](./struct.Module.html#method.reveal_vote)
+//! - [reveal_ranked_vote](./struct.Module.html#method.reveal_ranked_vote)
 //! - [release_vote_stake](./struct.Module.html#method.release_vote_stake)
+//! - [delegate_vote](./struct.Module.html#method.delegate_vote)
+//! - [revoke_vote_delegation](./struct.Module.html#method.revoke_vote_delegation)
+//! - [set_vote_power_strategy](./struct.Module.html#method.set_vote_power_strategy)
 //!
 //! ## Notes
 //! This module is instantiable pallet as described here https://substrate.dev/recipes/3-entrees/instantiable.html
@@ -50,18 +73,22 @@ extern crate common;
 
 // used dependencies
 use codec::{Codec, Decode, Encode, MaxEncodedLen};
+use common::referendum::VotePowerStrategy;
 use core::marker::PhantomData;
 use frame_support::traits::{EnsureOrigin, Get, LockIdentifier};
 use frame_support::weights::Weight;
 use frame_support::{
     decl_error, decl_event, decl_module, decl_storage, ensure, error::BadOrigin,
-    storage::weak_bounded_vec::WeakBoundedVec, storage::StorageMap, Parameter, StorageValue,
+    storage::bounded_vec::BoundedVec, storage::weak_bounded_vec::WeakBoundedVec,
+    storage::IterableStorageDoubleMap, storage::IterableStorageMap, storage::StorageDoubleMap,
+    storage::StorageMap, Parameter, StorageValue,
 };
-use frame_system::ensure_signed;
+use frame_system::{ensure_root, ensure_signed};
 use scale_info::TypeInfo;
 use sp_arithmetic::traits::BaseArithmetic;
-use sp_runtime::traits::{MaybeSerialize, Member, Saturating, Zero};
-use sp_runtime::SaturatedConversion;
+use sp_runtime::traits::{IntegerSquareRoot, MaybeSerialize, Member, Saturating, Zero};
+use sp_runtime::{Perbill, Perquintill, SaturatedConversion};
+use sp_std::collections::{btree_map::BTreeMap, btree_set::BTreeSet};
 use sp_std::convert::TryInto;
 use sp_std::vec;
 use sp_std::vec::Vec;
@@ -158,6 +185,30 @@ pub struct CastVote<Hash, Currency, MemberId> {
     pub vote_for: Option<MemberId>,
 }
 
+/// Ranked ballot revealed in referendum.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, PartialEq, Eq, Debug, Default, Clone, TypeInfo, MaxEncodedLen)]
+pub struct RankedBallot<Preferences, VotePower> {
+    // index of the referendum cycle the ballot was revealed in
+    pub cycle_id: u64,
+    // options ordered from the most preferred one
+    pub preferences: Preferences,
+    // power of the vote backing the ballot
+    pub vote_power: VotePower,
+}
+
+/// Delegation of the voting power to another account.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, PartialEq, Eq, Debug, Default, Clone, TypeInfo, MaxEncodedLen)]
+pub struct Delegation<AccountId, Currency, BlockNumber> {
+    // account voting on behalf of the delegator
+    pub delegate: AccountId,
+    // stake locked for the delegation
+    pub stake: Currency,
+    // block since which the stake has been locked for the delegation
+    pub since: BlockNumber,
+}
+
 /////////////////// Type aliases ///////////////////////////////////////////////
 
 // `Ez` prefix in some of the following type aliases means *easy* and is meant to create unique
@@ -170,6 +221,11 @@ pub type CastVoteOf<T> = CastVote<
     BalanceOf<T>,
     <T as common::membership::MembershipTypes>::MemberId,
 >;
+pub type DelegationOf<T> = Delegation<
+    <T as frame_system::Config>::AccountId,
+    BalanceOf<T>,
+    <T as frame_system::Config>::BlockNumber,
+>;
 pub type IntermediateWinnersOf<T, I> =
     WeakBoundedVec<OptionResultOf<T, I>, <T as Config<I>>::MaxWinnerTargetCount>;
 pub type ReferendumStageVotingOf<T> =
@@ -185,6 +241,20 @@ pub type CanRevealResult<T, I> = (
     <T as frame_system::Config>::AccountId,
     CastVoteOf<T>,
 );
+pub type CanRevealRankedResult<T, I> = (
+    ReferendumStageRevealingOf<T, I>,
+    <T as frame_system::Config>::AccountId,
+    CastVoteOf<T>,
+    RankedBallotPreferencesOf<T, I>,
+);
+
+pub type VotePowerStrategyOf<T> = VotePowerStrategy<<T as frame_system::Config>::BlockNumber>;
+pub type RankedBallotPreferencesOf<T, I> = BoundedVec<
+    <T as common::membership::MembershipTypes>::MemberId,
+    <T as Config<I>>::MaxRankedBallotPreferences,
+>;
+pub type RankedBallotOf<T, I> =
+    RankedBallot<RankedBallotPreferencesOf<T, I>, <T as Config<I>>::VotePower>;
 
 /////////////////// Configs, Storage, Errors, and Events /////////////////////////
 
@@ -226,6 +296,14 @@ pub trait ReferendumManager<Origin, AccountId, MemberId, Hash> {
         cycle_id: &u64,
         vote_option_id: &MemberId,
     ) -> Hash;
+
+    /// Calculate commitment for a ranked vote.
+    fn calculate_ranked_commitment(
+        account_id: &AccountId,
+        salt: &[u8],
+        cycle_id: &u64,
+        preferences: &[MemberId],
+    ) -> Hash;
 }
 
 /// The main Referendum module's trait.
@@ -275,7 +353,23 @@ pub trait Config<I: Instance = DefaultInstance>:
     /// Maximum number of winning target count
     type MaxWinnerTargetCount: Get<u32>;
 
-    /// Calculate the vote's power for user and his stake.
+    /// Vote power strategy used until it is replaced by the root origin.
+    type DefaultVotePowerStrategy: Get<VotePowerStrategyOf<Self>>;
+
+    /// Maximum number of options in a ranked ballot.
+    type MaxRankedBallotPreferences: Get<u32>;
+
+    /// Maximum number of ranked ballots revealed in a referendum.
+    type MaxRankedBallots: Get<u32>;
+
+    /// Maximum number of distinct options receiving a single option vote in a referendum.
+    type MaxVotedOptions: Get<u32>;
+
+    /// Maximum number of accounts delegating their voting power to a single account.
+    type MaxDelegatorsPerDelegate: Get<u32>;
+
+    /// Calculate the vote's power for user and his stake weighted by the current vote power
+    /// strategy.
     fn calculate_vote_power(
         account_id: &<Self as frame_system::Config>::AccountId,
         stake: &BalanceOf<Self>,
@@ -317,6 +411,47 @@ decl_storage! { generate_storage_info
         /// Accounts that permanently opted out of voting in referendum.
         pub AccountsOptedOut get(fn accounts_opted_out): map hasher(blake2_128_concat)
             T::AccountId => ();
+
+        /// Vote power strategy used in the current referendum. The runtime default strategy is
+        /// used when not set.
+        pub VotePowerStrategyInUse get(fn vote_power_strategy_in_use):
+            Option<VotePowerStrategyOf<T>>;
+
+        /// Vote power strategy that replaces the current one when the next referendum starts.
+        pub NextVotePowerStrategy get(fn next_vote_power_strategy): Option<VotePowerStrategyOf<T>>;
+
+        /// Block since which the vote stake of the account has been locked. It is reset when
+        /// the stake is increased and removed when the stake is released.
+        pub VoteStakeLockedSince get(fn vote_stake_locked_since): map hasher(blake2_128_concat)
+            T::AccountId => Option<T::BlockNumber>;
+
+        /// Ranked ballots revealed in the current referendum. They are removed when the
+        /// referendum ends.
+        pub RankedBallots get(fn ranked_ballots): map hasher(blake2_128_concat)
+            T::AccountId => Option<RankedBallotOf<T, I>>;
+
+        /// Number of ranked ballots revealed in the current referendum.
+        pub RankedBallotCount get(fn ranked_ballot_count): u32;
+
+        /// Options that received a single option vote in the current referendum. They are
+        /// removed when the referendum ends.
+        pub VotedOptions get(fn voted_options): map hasher(blake2_128_concat)
+            T::MemberId => ();
+
+        /// Number of options that received a single option vote in the current referendum.
+        pub VotedOptionCount get(fn voted_option_count): u32;
+
+        /// Voting power delegations by the delegator account.
+        pub Delegations get(fn delegations): map hasher(blake2_128_concat)
+            T::AccountId => Option<DelegationOf<T>>;
+
+        /// Accounts delegating their voting power by the delegate account.
+        pub Delegators get(fn delegators): double_map hasher(blake2_128_concat)
+            T::AccountId, hasher(blake2_128_concat) T::AccountId => ();
+
+        /// Number of accounts delegating their voting power by the delegate account.
+        pub DelegatorCount get(fn delegator_count): map hasher(blake2_128_concat)
+            T::AccountId => u32;
     }
 }
 
@@ -329,6 +464,7 @@ decl_event! {
         <T as frame_system::Config>::AccountId,
         <T as Config<I>>::VotePower,
         <T as common::membership::MembershipTypes>::MemberId,
+        VotePowerStrategy = VotePowerStrategyOf<T>,
     {
         /// Referendum started
         ReferendumStarted(u32, BlockNumber),
@@ -348,11 +484,27 @@ decl_event! {
         /// User revealed his vote
         VoteRevealed(AccountId, MemberId, Vec<u8>),
 
+        /// User revealed his ranked vote
+        RankedVoteRevealed(AccountId, Vec<MemberId>, Vec<u8>),
+
         /// User released his stake
         StakeReleased(AccountId),
 
+        /// User delegated his voting power to another account
+        /// Params:
+        /// - delegator account
+        /// - delegate account
+        /// - delegated stake
+        VoteDelegated(AccountId, AccountId, Balance),
+
+        /// User revoked his voting power delegation
+        VoteDelegationRevoked(AccountId),
+
         /// Account permanently opted out of voting in referendum.
         AccountOptedOutOfVoting(AccountId),
+
+        /// Vote power strategy was set to be used from the next referendum.
+        VotePowerStrategySet(VotePowerStrategy),
     }
 }
 
@@ -401,6 +553,30 @@ decl_error! {
 
         /// A vote cannot be cast from an account that already opted out of voting.
         AccountAlreadyOptedOutOfVoting,
+
+        /// Vote power strategy parameters are invalid.
+        InvalidVotePowerStrategy,
+
+        /// Ranked ballot is empty, too long or ranks some option multiple times
+        InvalidRankedBallot,
+
+        /// Maximum number of ranked ballots in the referendum was reached
+        MaxRankedBallotsReached,
+
+        /// Maximum number of options voted for in the referendum was reached
+        MaxVotedOptionsReached,
+
+        /// Account can't delegate the voting power to itself
+        InvalidDelegate,
+
+        /// Maximum number of accounts delegating to the delegate was reached
+        MaxDelegatorsReached,
+
+        /// Voting power delegation doesn't exist
+        DelegationNotExisting,
+
+        /// Voting power delegation can't be changed during the revealing stage
+        DelegationLockedDuringRevealing,
     }
 }
 
@@ -443,6 +619,18 @@ decl_module! {
         /// Exports const - staking handler lock id.
         const StakingHandlerLockId: LockIdentifier = T::StakingHandler::lock_id();
 
+        /// Maximum number of options in a ranked ballot.
+        const MaxRankedBallotPreferences: u32 = T::MaxRankedBallotPreferences::get();
+
+        /// Maximum number of ranked ballots revealed in a referendum.
+        const MaxRankedBallots: u32 = T::MaxRankedBallots::get();
+
+        /// Maximum number of distinct options receiving a single option vote in a referendum.
+        const MaxVotedOptions: u32 = T::MaxVotedOptions::get();
+
+        /// Maximum number of accounts delegating their voting power to a single account.
+        const MaxDelegatorsPerDelegate: u32 = T::MaxDelegatorsPerDelegate::get();
+
         /////////////////// Lifetime ///////////////////////////////////////////
 
         // No origin so this is a priviledged call
@@ -453,6 +641,11 @@ decl_module! {
                 .max(ReferendumWeightInfo::<T, I>::on_initialize_revealing(
                         T::MaxWinnerTargetCount::get().saturated_into()
                 ))
+                .max(ReferendumWeightInfo::<T, I>::on_initialize_revealing_ranked(
+                        T::MaxRankedBallots::get(),
+                        T::MaxRankedBallotPreferences::get(),
+                ))
+                .saturating_add(Self::calculate_conclusion_extra_weight())
         }
 
         /////////////////// User actions ///////////////////////////////////////
@@ -489,11 +682,12 @@ decl_module! {
         /// # <weight>
         ///
         /// ## Weight
-        /// `O (W)` where:
+        /// `O (W + D)` where:
         /// - `W` is the number of `intermediate_winners` stored in the current
         ///     `Stage::<T, I>::get()`
+        /// - `D` is the number of accounts delegating to the caller
         /// - DB:
-        ///    - `O(1)` doesn't depend on the state or parameters
+        ///    - `O(D)`
         /// # </weight>
         #[weight = Module::<T, I>::calculate_reveal_vote_weight(
             T::MaxWinnerTargetCount::get().saturated_into()
@@ -519,6 +713,52 @@ decl_module! {
             Ok(())
         }
 
+        /// Reveal a sealed vote in the referendum as a ranked ballot.
+        ///
+        /// # <weight>
+        ///
+        /// ## Weight
+        /// `O (P + D)` where:
+        /// - `P` is the number of `preferences`
+        /// - `D` is the number of accounts delegating to the caller
+        /// - DB:
+        ///    - `O(P + D)`
+        /// # </weight>
+        #[weight = ReferendumWeightInfo::<T, I>::reveal_ranked_vote(
+            preferences.len().saturated_into()
+        ).saturating_add(ReferendumWeightInfo::<T, I>::delegated_vote_stake(
+            T::MaxDelegatorsPerDelegate::get()
+        ))]
+        pub fn reveal_ranked_vote(
+            origin,
+            salt: Vec<u8>,
+            preferences: Vec<<T as common::membership::MembershipTypes>::MemberId>
+        ) -> Result<(), Error<T, I>> {
+            let (stage_data, account_id, cast_vote, bounded_preferences) =
+                EnsureChecks::<T, I>::can_reveal_ranked_vote::<Self>(
+                    origin,
+                    &salt,
+                    &preferences,
+                )?;
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            // store the ranked ballot
+            Mutations::<T, I>::reveal_ranked_vote(
+                stage_data,
+                &account_id,
+                bounded_preferences,
+                cast_vote,
+            );
+
+            // emit event
+            Self::deposit_event(RawEvent::RankedVoteRevealed(account_id, preferences, salt));
+
+            Ok(())
+        }
+
         /// Release a locked stake.
         /// # <weight>
         ///
@@ -544,6 +784,62 @@ decl_module! {
             Ok(())
         }
 
+        /// Delegate the voting power to another account, locking the delegated stake. Replaces
+        /// the existing delegation.
+        ///
+        /// # <weight>
+        ///
+        /// ## weight
+        /// `O (1)`
+        /// - db:
+        ///    - `O(1)` doesn't depend on the state or parameters
+        /// # </weight>
+        #[weight = ReferendumWeightInfo::<T, I>::delegate_vote()]
+        pub fn delegate_vote(
+            origin,
+            delegate: T::AccountId,
+            stake: BalanceOf<T>
+        ) -> Result<(), Error<T, I>> {
+            let account_id = EnsureChecks::<T, I>::can_delegate_vote(origin, &delegate, &stake)?;
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            Mutations::<T, I>::delegate_vote(&account_id, &delegate, &stake);
+
+            // emit event
+            Self::deposit_event(RawEvent::VoteDelegated(account_id, delegate, stake));
+
+            Ok(())
+        }
+
+        /// Revoke the voting power delegation, releasing the delegated stake.
+        ///
+        /// # <weight>
+        ///
+        /// ## weight
+        /// `O (1)`
+        /// - db:
+        ///    - `O(1)` doesn't depend on the state or parameters
+        /// # </weight>
+        #[weight = ReferendumWeightInfo::<T, I>::revoke_vote_delegation()]
+        pub fn revoke_vote_delegation(origin) -> Result<(), Error<T, I>> {
+            let (account_id, delegation) =
+                EnsureChecks::<T, I>::can_revoke_vote_delegation(origin)?;
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            Mutations::<T, I>::revoke_vote_delegation(&account_id, &delegation);
+
+            // emit event
+            Self::deposit_event(RawEvent::VoteDelegationRevoked(account_id));
+
+            Ok(())
+        }
+
         /// Permanently opt out of voting from a given account.
         ///
         /// # <weight>
@@ -572,6 +868,34 @@ decl_module! {
 
             Ok(())
         }
+
+        /// Set the vote power strategy to be used from the next referendum.
+        ///
+        /// # <weight>
+        ///
+        /// ## weight
+        /// `O (1)`
+        /// - db:
+        ///    - `O(1)` doesn't depend on the state or parameters
+        /// # </weight>
+        #[weight = ReferendumWeightInfo::<T, I>::set_vote_power_strategy()]
+        pub fn set_vote_power_strategy(
+            origin,
+            strategy: VotePowerStrategyOf<T>
+        ) -> Result<(), Error<T, I>> {
+            EnsureChecks::<T, I>::can_set_vote_power_strategy(origin, &strategy)?;
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            NextVotePowerStrategy::<T, I>::put(strategy);
+
+            // emit event
+            Self::deposit_event(RawEvent::VotePowerStrategySet(strategy));
+
+            Ok(())
+        }
     }
 }
 
@@ -595,6 +919,92 @@ impl<T: Config<I>, I: Instance> Module<T, I> {
             .max(ReferendumWeightInfo::<T, I>::reveal_vote_already_existing(
                 number_of_winners,
             ))
+            .saturating_add(ReferendumWeightInfo::<T, I>::delegated_vote_stake(
+                T::MaxDelegatorsPerDelegate::get(),
+            ))
+            // voted options record
+            .saturating_add(T::DbWeight::get().reads_writes(2, 2))
+    }
+
+    // Weight of the referendum conclusion not covered by the benchmarks: removing the voted
+    // options, reading their power for the ranked ballots tally and updating the votes of
+    // the ranked ballots.
+    fn calculate_conclusion_extra_weight() -> Weight {
+        let max_voted_options: u64 = T::MaxVotedOptions::get().into();
+        let max_ranked_ballots: u64 = T::MaxRankedBallots::get().into();
+
+        T::DbWeight::get().reads_writes(
+            max_voted_options
+                .saturating_mul(2)
+                .saturating_add(1)
+                .saturating_add(max_ranked_ballots),
+            max_voted_options
+                .saturating_add(1)
+                .saturating_add(max_ranked_ballots),
+        )
+    }
+
+    /// Vote power strategy used in the current referendum.
+    pub fn current_vote_power_strategy() -> VotePowerStrategyOf<T> {
+        VotePowerStrategyInUse::<T, I>::get().unwrap_or_else(T::DefaultVotePowerStrategy::get)
+    }
+
+    /// Weight the vote stake of the account by the current vote power strategy. The lock age
+    /// used by the time weighted strategy is measured at the `at` block.
+    pub fn weighted_vote_stake(
+        account_id: &T::AccountId,
+        stake: &BalanceOf<T>,
+        at: T::BlockNumber,
+    ) -> BalanceOf<T> {
+        Self::weighted_stake(stake, VoteStakeLockedSince::<T, I>::get(account_id), at)
+    }
+
+    /// Sum of the stakes delegated to the account weighted by the current vote power strategy,
+    /// skipping the delegators who voted themselves in the given cycle. The lock age used by the
+    /// time weighted strategy is measured at the `at` block.
+    pub fn delegated_vote_stake(
+        delegate: &T::AccountId,
+        cycle_id: u64,
+        at: T::BlockNumber,
+    ) -> BalanceOf<T> {
+        Delegators::<T, I>::iter_prefix(delegate)
+            .filter(|(delegator, _)| {
+                !Votes::<T, I>::contains_key(delegator)
+                    || Votes::<T, I>::get(delegator).cycle_id != cycle_id
+            })
+            .filter_map(|(delegator, _)| Delegations::<T, I>::get(delegator))
+            .fold(Zero::zero(), |total: BalanceOf<T>, delegation| {
+                total.saturating_add(Self::weighted_stake(
+                    &delegation.stake,
+                    Some(delegation.since),
+                    at,
+                ))
+            })
+    }
+
+    // Weight the stake locked since the given block by the current vote power strategy.
+    fn weighted_stake(
+        stake: &BalanceOf<T>,
+        locked_since: Option<T::BlockNumber>,
+        at: T::BlockNumber,
+    ) -> BalanceOf<T> {
+        match Self::current_vote_power_strategy() {
+            VotePowerStrategy::Stake => *stake,
+            VotePowerStrategy::SquareRoot => stake.integer_sqrt(),
+            VotePowerStrategy::TimeWeighted {
+                maturity_period,
+                max_bonus,
+            } => {
+                let locked_for = locked_since
+                    .map_or_else(Zero::zero, |locked_since| at.saturating_sub(locked_since));
+                let maturity = Perbill::from_rational(
+                    locked_for.min(maturity_period).saturated_into::<u64>(),
+                    maturity_period.saturated_into::<u64>(),
+                );
+
+                stake.saturating_add(maturity * (max_bonus * *stake))
+            }
+        }
     }
 
     // Checkout expire of referendum stage.
@@ -722,6 +1132,25 @@ impl<T: Config<I>, I: Instance>
 
         <T::Hashing as sp_runtime::traits::Hash>::hash(&payload)
     }
+
+    // Calculate commitment for a ranked vote.
+    fn calculate_ranked_commitment(
+        account_id: &<T as frame_system::Config>::AccountId,
+        salt: &[u8],
+        cycle_id: &u64,
+        preferences: &[<T as common::membership::MembershipTypes>::MemberId],
+    ) -> T::Hash {
+        let mut payload = account_id.encode();
+        let mut mut_preferences = preferences.encode();
+        let mut mut_salt = salt.encode();
+        let mut mut_cycle_id = cycle_id.encode();
+
+        payload.append(&mut mut_preferences);
+        payload.append(&mut mut_salt);
+        payload.append(&mut mut_cycle_id);
+
+        <T::Hashing as sp_runtime::traits::Hash>::hash(&payload)
+    }
 }
 
 /////////////////// Mutations //////////////////////////////////////////////////
@@ -735,6 +1164,16 @@ impl<T: Config<I>, I: Instance> Mutations<T, I> {
     fn start_voting_period(winning_target_count: &u32, cycle_id: &u64) -> T::BlockNumber {
         let now = <frame_system::Pallet<T>>::block_number();
         let ends_at = now.saturating_add(T::VoteStageDuration::get());
+
+        // apply the vote power strategy scheduled for the next referendum
+        if let Some(strategy) = NextVotePowerStrategy::<T, I>::take() {
+            VotePowerStrategyInUse::<T, I>::put(strategy);
+        }
+
+        // remove ranked ballots and voted options left by a discontinued referendum
+        Self::take_ranked_ballots();
+        Self::take_voted_options();
+
         // change referendum state
         Stage::<T, I>::put(ReferendumStage::Voting(ReferendumStageVoting::<
             T::BlockNumber,
@@ -779,8 +1218,181 @@ impl<T: Config<I>, I: Instance> Mutations<T, I> {
         // reset referendum state
         Stage::<T, I>::put(ReferendumStage::Inactive);
 
+        let voted_options = Self::take_voted_options();
+        let ranked_ballots: Vec<(T::AccountId, RankedBallotOf<T, I>)> = Self::take_ranked_ballots()
+            .into_iter()
+            .filter(|(_, ballot)| ballot.cycle_id == revealing_stage.current_cycle_id)
+            .collect();
+
         // return winning option
-        revealing_stage.intermediate_winners.to_vec()
+        if ranked_ballots.is_empty() {
+            return revealing_stage.intermediate_winners.to_vec();
+        }
+
+        let winners = Self::tally_ranked_ballots(
+            ranked_ballots
+                .iter()
+                .map(|(_, ballot)| ballot.clone())
+                .collect(),
+            voted_options,
+            revealing_stage.winning_target_count,
+        );
+
+        // A ranked ballot backs its most preferred winner, so its stake is locked the same way
+        // as the stake of a single option vote for that winner. Ballots without any winner keep
+        // backing their most preferred option.
+        let winner_ids: BTreeSet<T::MemberId> =
+            winners.iter().map(|winner| winner.option_id).collect();
+        for (account_id, ballot) in ranked_ballots {
+            if let Some(backed_winner) = ballot
+                .preferences
+                .iter()
+                .find(|option_id| winner_ids.contains(option_id))
+            {
+                Votes::<T, I>::mutate(account_id, |vote| vote.vote_for = Some(*backed_winner));
+            }
+        }
+
+        winners
+    }
+
+    // Remove all ranked ballots and return them along with their voters.
+    fn take_ranked_ballots() -> Vec<(T::AccountId, RankedBallotOf<T, I>)> {
+        if RankedBallotCount::<T, I>::take() == 0 {
+            return Vec::new();
+        }
+
+        RankedBallots::<T, I>::drain().collect()
+    }
+
+    // Remove all options voted for by the single option votes and return them.
+    fn take_voted_options() -> Vec<T::MemberId> {
+        if VotedOptionCount::<T, I>::take() == 0 {
+            return Vec::new();
+        }
+
+        VotedOptions::<T, I>::drain()
+            .map(|(option_id, _)| option_id)
+            .collect()
+    }
+
+    // Select the winners using the single transferable vote with the Droop quota. The surplus of
+    // an elected option is transferred to the next preferences of all its ballots by reducing
+    // their weight (weighted inclusive Gregory method). Single option votes take part as ballots
+    // ranking just the voted option.
+    fn tally_ranked_ballots(
+        ranked_ballots: Vec<RankedBallotOf<T, I>>,
+        voted_options: Vec<T::MemberId>,
+        winning_target_count: u32,
+    ) -> Vec<OptionResultOf<T, I>> {
+        let mut ballots: Vec<(Vec<T::MemberId>, u128)> = ranked_ballots
+            .into_iter()
+            .map(|ballot| {
+                (
+                    ballot.preferences.into_inner(),
+                    ballot.vote_power.saturated_into(),
+                )
+            })
+            .collect();
+
+        // every option ranked by a ballot or voted for by a single option vote takes part
+        let mut continuing = BTreeSet::<T::MemberId>::new();
+        for (preferences, _) in ballots.iter() {
+            continuing.extend(preferences.iter().copied());
+        }
+        continuing.extend(voted_options);
+
+        // options power contains only the single option votes, since the ranked ballots don't
+        // increase it
+        for option_id in continuing.iter() {
+            let power = T::get_option_power(option_id);
+            if !power.is_zero() {
+                ballots.push((vec![*option_id], power.saturated_into()));
+            }
+        }
+
+        let seats = winning_target_count.saturated_into::<usize>();
+        let total_weight = ballots
+            .iter()
+            .fold(0u128, |total, (_, weight)| total.saturating_add(*weight));
+        let quota = total_weight
+            .checked_div(seats.saturating_add(1).saturated_into())
+            .unwrap_or_default()
+            .saturating_add(1);
+
+        let mut elected = Vec::<(T::MemberId, u128)>::new();
+        while elected.len() < seats && !continuing.is_empty() {
+            // count each ballot for its most preferred continuing option
+            let current_choice = |preferences: &Vec<T::MemberId>| {
+                preferences
+                    .iter()
+                    .find(|option_id| continuing.contains(option_id))
+                    .copied()
+            };
+            let mut tallies: BTreeMap<T::MemberId, u128> =
+                continuing.iter().map(|option_id| (*option_id, 0)).collect();
+            for (preferences, weight) in ballots.iter() {
+                if let Some(tally) =
+                    current_choice(preferences).and_then(|option_id| tallies.get_mut(&option_id))
+                {
+                    *tally = tally.saturating_add(*weight);
+                }
+            }
+
+            // all supported continuing options fill the remaining seats
+            if continuing.len() <= seats.saturating_sub(elected.len()) {
+                let mut remaining: Vec<(T::MemberId, u128)> = tallies
+                    .into_iter()
+                    .filter(|(_, tally)| !tally.is_zero())
+                    .collect();
+                remaining.sort_by(|a, b| b.1.cmp(&a.1));
+                elected.append(&mut remaining);
+                break;
+            }
+
+            // the strongest option (the lowest id on ties) is elected when it reaches the quota
+            let strongest =
+                tallies
+                    .iter()
+                    .fold(None, |strongest, (option_id, tally)| match strongest {
+                        Some((_, strongest_tally)) if strongest_tally >= *tally => strongest,
+                        _ => Some((*option_id, *tally)),
+                    });
+            if let Some((option_id, tally)) = strongest.filter(|(_, tally)| *tally >= quota) {
+                let surplus = Perquintill::from_rational(tally.saturating_sub(quota), tally);
+                for (preferences, weight) in ballots.iter_mut() {
+                    if current_choice(preferences) == Some(option_id) {
+                        *weight = surplus * *weight;
+                    }
+                }
+
+                continuing.remove(&option_id);
+                elected.push((option_id, tally));
+                continue;
+            }
+
+            // otherwise the weakest option (the highest id on ties) is eliminated
+            let weakest = tallies
+                .iter()
+                .fold(None, |weakest, (option_id, tally)| match weakest {
+                    Some((_, weakest_tally)) if weakest_tally < *tally => weakest,
+                    _ => Some((*option_id, *tally)),
+                });
+            if let Some((option_id, _)) = weakest {
+                continuing.remove(&option_id);
+            }
+        }
+
+        // keep the winners ordered by the vote power
+        elected.sort_by(|a, b| b.1.cmp(&a.1));
+
+        elected
+            .into_iter()
+            .map(|(option_id, tally)| OptionResult {
+                option_id,
+                vote_power: tally.saturated_into(),
+            })
+            .collect()
     }
 
     // Cast a user's sealed vote for the current referendum cycle.
@@ -790,8 +1402,21 @@ impl<T: Config<I>, I: Instance> Mutations<T, I> {
         stake: &BalanceOf<T>,
         current_cycle_id: &u64,
     ) {
-        // Should call after `can_vote`
-        T::StakingHandler::lock(account_id, *stake);
+        // restart the lock age when the stake is locked for the first time or increased
+        let is_stake_increased = !Votes::<T, I>::contains_key(account_id)
+            || Votes::<T, I>::get(account_id).stake < *stake;
+        if is_stake_increased || !VoteStakeLockedSince::<T, I>::contains_key(account_id) {
+            VoteStakeLockedSince::<T, I>::insert(
+                account_id,
+                <frame_system::Pallet<T>>::block_number(),
+            );
+        }
+
+        // Should call after `can_vote`; the lock covers the delegated stake too
+        T::StakingHandler::lock(
+            account_id,
+            Self::total_locked_stake(account_id, Some(*stake)),
+        );
 
         // store vote
         Votes::<T, I>::insert(
@@ -813,7 +1438,7 @@ impl<T: Config<I>, I: Instance> Mutations<T, I> {
         cast_vote: CastVoteOf<T>,
     ) {
         // prepare new values
-        let vote_power = T::calculate_vote_power(account_id, &cast_vote.stake);
+        let vote_power = Self::vote_power(&stage_data, account_id, &cast_vote);
         let total_vote_power = T::get_option_power(option_id) + vote_power;
         let option_result = OptionResult {
             option_id: *option_id,
@@ -833,6 +1458,12 @@ impl<T: Config<I>, I: Instance> Mutations<T, I> {
         // let runtime update option's vote power
         T::increase_option_power(option_id, &vote_power);
 
+        // record the option for the ranked ballots tally
+        if !VotedOptions::<T, I>::contains_key(option_id) {
+            VotedOptions::<T, I>::insert(option_id, ());
+            VotedOptionCount::<T, I>::mutate(|count| *count = count.saturating_add(1));
+        }
+
         // update stage data, store new calculated winners
         Stage::<T, I>::mutate(|stage| *stage = ReferendumStage::Revealing(new_stage_data));
 
@@ -840,13 +1471,148 @@ impl<T: Config<I>, I: Instance> Mutations<T, I> {
         Votes::<T, I>::mutate(account_id, |vote| vote.vote_for = Some(*option_id));
     }
 
+    // Store user's ranked ballot after checking the commitment proof.
+    fn reveal_ranked_vote(
+        stage_data: ReferendumStageRevealingOf<T, I>,
+        account_id: &<T as frame_system::Config>::AccountId,
+        preferences: RankedBallotPreferencesOf<T, I>,
+        cast_vote: CastVoteOf<T>,
+    ) {
+        let vote_power = Self::vote_power(&stage_data, account_id, &cast_vote);
+        let most_preferred = preferences.first().copied();
+
+        RankedBallots::<T, I>::insert(
+            account_id,
+            RankedBallot {
+                cycle_id: stage_data.current_cycle_id,
+                preferences,
+                vote_power,
+            },
+        );
+        RankedBallotCount::<T, I>::mutate(|count| *count = count.saturating_add(1));
+
+        // store revealed vote, the elected option backed by the ballot replaces it when the
+        // referendum ends
+        Votes::<T, I>::mutate(account_id, |vote| vote.vote_for = most_preferred);
+    }
+
+    // Calculate the power of the revealed vote including the stakes delegated to the voter.
+    fn vote_power(
+        stage_data: &ReferendumStageRevealingOf<T, I>,
+        account_id: &<T as frame_system::Config>::AccountId,
+        cast_vote: &CastVoteOf<T>,
+    ) -> T::VotePower {
+        let weighted_stake =
+            Module::<T, I>::weighted_vote_stake(account_id, &cast_vote.stake, stage_data.started)
+                .saturating_add(Module::<T, I>::delegated_vote_stake(
+                    account_id,
+                    stage_data.current_cycle_id,
+                    stage_data.started,
+                ));
+
+        T::calculate_vote_power(account_id, &weighted_stake)
+    }
+
     // Release stake associated to the user's last vote.
     fn release_vote_stake(account_id: &<T as frame_system::Config>::AccountId) {
-        // unlock stake amount
-        T::StakingHandler::unlock(account_id);
+        // unlock stake amount except the delegated stake
+        Self::update_stake_lock(account_id, None);
 
         // remove vote record
         Votes::<T, I>::remove(account_id);
+        VoteStakeLockedSince::<T, I>::remove(account_id);
+    }
+
+    // Delegate user's voting power to the delegate replacing the existing delegation.
+    fn delegate_vote(
+        account_id: &<T as frame_system::Config>::AccountId,
+        delegate: &<T as frame_system::Config>::AccountId,
+        stake: &BalanceOf<T>,
+    ) {
+        let now = <frame_system::Pallet<T>>::block_number();
+        let since = match Delegations::<T, I>::get(account_id) {
+            Some(delegation) => {
+                Self::remove_delegator(&delegation.delegate, account_id);
+
+                // restart the lock age only when the stake is increased
+                if delegation.stake < *stake {
+                    now
+                } else {
+                    delegation.since
+                }
+            }
+            None => now,
+        };
+
+        Delegators::<T, I>::insert(delegate, account_id, ());
+        DelegatorCount::<T, I>::mutate(delegate, |count| *count = count.saturating_add(1));
+        Delegations::<T, I>::insert(
+            account_id,
+            Delegation {
+                delegate: delegate.clone(),
+                stake: *stake,
+                since,
+            },
+        );
+
+        let vote_stake = Self::vote_stake(account_id);
+        Self::update_stake_lock(account_id, vote_stake);
+    }
+
+    // Revoke user's voting power delegation and release the delegated stake.
+    fn revoke_vote_delegation(
+        account_id: &<T as frame_system::Config>::AccountId,
+        delegation: &DelegationOf<T>,
+    ) {
+        Self::remove_delegator(&delegation.delegate, account_id);
+        Delegations::<T, I>::remove(account_id);
+
+        let vote_stake = Self::vote_stake(account_id);
+        Self::update_stake_lock(account_id, vote_stake);
+    }
+
+    // Remove the delegator from the delegate's delegators.
+    fn remove_delegator(
+        delegate: &<T as frame_system::Config>::AccountId,
+        delegator: &<T as frame_system::Config>::AccountId,
+    ) {
+        Delegators::<T, I>::remove(delegate, delegator);
+        DelegatorCount::<T, I>::mutate_exists(delegate, |count| {
+            *count = count
+                .map(|count| count.saturating_sub(1))
+                .filter(|count| *count > 0)
+        });
+    }
+
+    // Stake locked for user's last vote.
+    fn vote_stake(account_id: &<T as frame_system::Config>::AccountId) -> Option<BalanceOf<T>> {
+        Votes::<T, I>::contains_key(account_id).then(|| Votes::<T, I>::get(account_id).stake)
+    }
+
+    // Stake covering both the given vote stake and the delegated stake.
+    fn total_locked_stake(
+        account_id: &<T as frame_system::Config>::AccountId,
+        vote_stake: Option<BalanceOf<T>>,
+    ) -> BalanceOf<T> {
+        let delegated_stake = Delegations::<T, I>::get(account_id)
+            .map_or_else(Zero::zero, |delegation| delegation.stake);
+
+        vote_stake.unwrap_or_else(Zero::zero).max(delegated_stake)
+    }
+
+    // Lock the stake covering both the given vote stake and the delegated stake or unlock it
+    // when there is none.
+    fn update_stake_lock(
+        account_id: &<T as frame_system::Config>::AccountId,
+        vote_stake: Option<BalanceOf<T>>,
+    ) {
+        let stake = Self::total_locked_stake(account_id, vote_stake);
+
+        if stake.is_zero() {
+            T::StakingHandler::unlock(account_id);
+        } else {
+            T::StakingHandler::lock(account_id, stake);
+        }
     }
 
     // Tries to insert option to the proper place in the winners list. Utility for reaveal_vote()
@@ -1080,6 +1846,13 @@ impl<T: Config<I>, I: Instance> EnsureChecks<T, I> {
             return Err(Error::SaltTooLong);
         }
 
+        // ensure the voted options can be recorded for the ranked ballots tally
+        if !VotedOptions::<T, I>::contains_key(vote_option_id)
+            && VotedOptionCount::<T, I>::get() >= T::MaxVotedOptions::get()
+        {
+            return Err(Error::MaxVotedOptionsReached);
+        }
+
         // ensure commitment corresponds to salt and vote option
         let commitment = R::calculate_commitment(
             &account_id,
@@ -1094,6 +1867,75 @@ impl<T: Config<I>, I: Instance> EnsureChecks<T, I> {
         Ok((stage_data, account_id, cast_vote))
     }
 
+    fn can_reveal_ranked_vote<
+        R: ReferendumManager<T::RuntimeOrigin, T::AccountId, T::MemberId, T::Hash>,
+    >(
+        origin: T::RuntimeOrigin,
+        salt: &[u8],
+        preferences: &[<T as common::membership::MembershipTypes>::MemberId],
+    ) -> Result<CanRevealRankedResult<T, I>, Error<T, I>> {
+        // ensure superuser requested action
+        let account_id = Self::ensure_regular_user(origin)?;
+
+        // ensure referendum is running
+        let stage_data = match Stage::<T, I>::get() {
+            ReferendumStage::Revealing(tmp_stage_data) => tmp_stage_data,
+            _ => return Err(Error::RevealingNotInProgress),
+        };
+
+        let cast_vote = Self::ensure_vote_exists(&account_id)?;
+
+        // ensure the ballot is not empty and doesn't rank any option twice
+        let bounded_preferences: RankedBallotPreferencesOf<T, I> = preferences
+            .to_vec()
+            .try_into()
+            .map_err(|_| Error::InvalidRankedBallot)?;
+        let unique_options: BTreeSet<_> = preferences.iter().collect();
+        ensure!(
+            !preferences.is_empty() && unique_options.len() == preferences.len(),
+            Error::InvalidRankedBallot
+        );
+
+        // ask runtime if options are valid
+        if !preferences.iter().all(T::is_valid_option_id) {
+            return Err(Error::InvalidVote);
+        }
+
+        // ensure vote was cast for the running referendum
+        if stage_data.current_cycle_id != cast_vote.cycle_id {
+            return Err(Error::InvalidVote);
+        }
+
+        // ensure vote was not already revealed
+        if cast_vote.vote_for.is_some() {
+            return Err(Error::InvalidReveal);
+        }
+
+        // ensure salt is not too long
+        if salt.len() as u64 > T::MaxSaltLength::get() {
+            return Err(Error::SaltTooLong);
+        }
+
+        // ensure commitment corresponds to salt and ranked options
+        let commitment = R::calculate_ranked_commitment(
+            &account_id,
+            salt,
+            &stage_data.current_cycle_id,
+            preferences,
+        );
+        if commitment != cast_vote.commitment {
+            return Err(Error::InvalidReveal);
+        }
+
+        // ensure the tally weight stays bounded
+        ensure!(
+            RankedBallotCount::<T, I>::get() < T::MaxRankedBallots::get(),
+            Error::MaxRankedBallotsReached
+        );
+
+        Ok((stage_data, account_id, cast_vote, bounded_preferences))
+    }
+
     fn can_release_vote_stake(origin: T::RuntimeOrigin) -> Result<T::AccountId, Error<T, I>> {
         // ensure superuser requested action
         let account_id = Self::ensure_regular_user(origin)?;
@@ -1108,6 +1950,72 @@ impl<T: Config<I>, I: Instance> EnsureChecks<T, I> {
         Ok(account_id)
     }
 
+    fn can_delegate_vote(
+        origin: T::RuntimeOrigin,
+        delegate: &T::AccountId,
+        stake: &BalanceOf<T>,
+    ) -> Result<T::AccountId, Error<T, I>> {
+        let account_id = Self::ensure_regular_user(origin)?;
+
+        // ensure account did not opt out of voting
+        if AccountsOptedOut::<T, I>::contains_key(&account_id) {
+            return Err(Error::<T, I>::AccountAlreadyOptedOutOfVoting);
+        }
+
+        ensure!(account_id != *delegate, Error::InvalidDelegate);
+
+        // prevent the delegated stake from being counted by multiple delegates
+        ensure!(
+            !matches!(Stage::<T, I>::get(), ReferendumStage::Revealing(_)),
+            Error::DelegationLockedDuringRevealing
+        );
+
+        ensure!(stake >= &T::MinimumStake::get(), Error::InsufficientStake);
+
+        // Ensure account doesn't have conflicting stakes
+        ensure!(
+            T::StakingHandler::is_account_free_of_conflicting_stakes(&account_id),
+            Error::ConflictStakesOnAccount
+        );
+
+        ensure!(
+            T::StakingHandler::is_enough_balance_for_stake(&account_id, *stake),
+            Error::InsufficientStake
+        );
+
+        // the delegator is already counted when only the stake changes
+        if !Delegators::<T, I>::contains_key(delegate, &account_id) {
+            ensure!(
+                DelegatorCount::<T, I>::get(delegate) < T::MaxDelegatorsPerDelegate::get(),
+                Error::MaxDelegatorsReached
+            );
+        }
+
+        Ok(account_id)
+    }
+
+    fn can_revoke_vote_delegation(
+        origin: T::RuntimeOrigin,
+    ) -> Result<(T::AccountId, DelegationOf<T>), Error<T, I>> {
+        let account_id = Self::ensure_regular_user(origin)?;
+
+        let delegation =
+            Delegations::<T, I>::get(&account_id).ok_or(Error::DelegationNotExisting)?;
+
+        Ok((account_id, delegation))
+    }
+
+    fn can_set_vote_power_strategy(
+        origin: T::RuntimeOrigin,
+        strategy: &VotePowerStrategyOf<T>,
+    ) -> Result<(), Error<T, I>> {
+        ensure_root(origin)?;
+
+        ensure!(strategy.is_valid(), Error::InvalidVotePowerStrategy);
+
+        Ok(())
+    }
+
     fn ensure_vote_exists(account_id: &T::AccountId) -> Result<CastVoteOf<T>, Error<T, I>> {
         // ensure there is some vote with locked stake
         if !Votes::<T, I>::contains_key(account_id) {
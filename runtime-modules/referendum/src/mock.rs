@@ -2,9 +2,10 @@
 
 /////////////////// Configuration //////////////////////////////////////////////
 use crate::{
-    AccountsOptedOut, BalanceOf, CastVote, Config, Error, Instance, Module, OptionResult, RawEvent,
+    AccountsOptedOut, BalanceOf, CastVote, Config, Delegation, Delegations, Delegators, Error,
+    Instance, Module, NextVotePowerStrategy, OptionResult, RankedBallots, RawEvent,
     ReferendumManager, ReferendumStage, ReferendumStageRevealing, ReferendumStageVoting, Stage,
-    Votes,
+    VotePowerStrategyOf, Votes,
 };
 
 pub use crate::DefaultInstance;
@@ -52,6 +53,10 @@ parameter_types! {
     pub const MinimumStake: u64 = 10000;
     pub const LockId: LockIdentifier = *b"referend";
     pub const MaxWinnerTargetCount: u32 = 10;
+    pub const MaxRankedBallotPreferences: u32 = 5;
+    pub const MaxRankedBallots: u32 = 10;
+    pub const MaxVotedOptions: u32 = 10;
+    pub const MaxDelegatorsPerDelegate: u32 = 3;
 }
 
 thread_local! {
@@ -90,6 +95,13 @@ impl Config for Runtime {
 
     type MaxWinnerTargetCount = MaxWinnerTargetCount;
 
+    type DefaultVotePowerStrategy = ();
+
+    type MaxRankedBallotPreferences = MaxRankedBallotPreferences;
+    type MaxRankedBallots = MaxRankedBallots;
+    type MaxVotedOptions = MaxVotedOptions;
+    type MaxDelegatorsPerDelegate = MaxDelegatorsPerDelegate;
+
     fn calculate_vote_power(
         account_id: &<Self as frame_system::Config>::AccountId,
         stake: &BalanceOf<Self>,
@@ -145,6 +157,10 @@ parameter_types! {
     pub const DefaultMembershipPrice: u64 = 100;
     pub const DefaultInitialInvitationBalance: u64 = 100;
     pub const DefaultMemberInvitesCount: u32 = 2;
+    pub const HandleReclaimCooldown: u64 = 1000;
+    pub const MaxRecoveryGuardians: u32 = 5;
+    pub const RecoveryDelay: u64 = 100;
+    pub const RecoveryCooldown: u64 = 50;
     pub const InvitedMemberLockId: [u8; 8] = [2; 8];
     pub const ReferralCutMaximumPercent: u8 = 50;
     pub const StakingCandidateLockId: [u8; 8] = [3; 8];
@@ -163,6 +179,10 @@ impl membership::Config for Runtime {
         staking_handler::StakingManager<Self, StakingCandidateLockId>;
     type CandidateStake = CandidateStake;
     type DefaultMemberInvitesCount = DefaultMemberInvitesCount;
+    type HandleReclaimCooldown = HandleReclaimCooldown;
+    type MaxRecoveryGuardians = MaxRecoveryGuardians;
+    type RecoveryDelay = RecoveryDelay;
+    type RecoveryCooldown = RecoveryCooldown;
 }
 
 impl pallet_timestamp::Config for Runtime {
@@ -362,7 +382,7 @@ pub fn build_test_externalities() -> sp_io::TestExternalities {
 }
 
 // topup currency to the account
-fn topup_account(account_id: u64, amount: u64) {
+pub fn topup_account(account_id: u64, amount: u64) {
     let account_id = account_id;
     let _ = balances::Pallet::<Runtime>::deposit_creating(&account_id, amount);
 }
@@ -466,6 +486,24 @@ where
         )
     }
 
+    pub fn calculate_ranked_commitment(
+        account_id: &<T as frame_system::Config>::AccountId,
+        preferences: &[<T as common::membership::MembershipTypes>::MemberId],
+        cycle_id: &u64,
+    ) -> (T::Hash, Vec<u8>) {
+        let salt = Self::generate_salt();
+
+        (
+            <Module<T, I> as ReferendumManager<
+                <T as frame_system::Config>::RuntimeOrigin,
+                <T as frame_system::Config>::AccountId,
+                <T as common::membership::MembershipTypes>::MemberId,
+                <T as frame_system::Config>::Hash,
+            >>::calculate_ranked_commitment(account_id, &salt, cycle_id, preferences),
+            salt,
+        )
+    }
+
     pub fn transform_results(input: Vec<T::VotePower>) -> BTreeMap<u64, T::VotePower> {
         BTreeMap::from_iter(
             input
@@ -735,6 +773,124 @@ impl InstanceMocks<Runtime, DefaultInstance> {
         );
     }
 
+    pub fn reveal_ranked_vote(
+        origin: OriginType<<Runtime as frame_system::Config>::AccountId>,
+        account_id: <Runtime as frame_system::Config>::AccountId,
+        salt: Vec<u8>,
+        preferences: Vec<u64>,
+        expected_result: Result<(), Error<Runtime, DefaultInstance>>,
+    ) -> () {
+        // check method returns expected result
+        assert_eq!(
+            Module::<Runtime>::reveal_ranked_vote(
+                InstanceMockUtils::<Runtime, DefaultInstance>::mock_origin(origin),
+                salt.clone(),
+                preferences.clone(),
+            ),
+            expected_result,
+        );
+
+        if expected_result.is_err() {
+            return;
+        }
+
+        assert_eq!(
+            RankedBallots::<Runtime, DefaultInstance>::get(account_id)
+                .map(|ballot| ballot.preferences.into_inner()),
+            Some(preferences.clone()),
+        );
+
+        // check event was emitted
+        assert_eq!(
+            frame_system::Pallet::<Runtime>::events()
+                .last()
+                .unwrap()
+                .event,
+            RuntimeEvent::Referendum(RawEvent::RankedVoteRevealed(account_id, preferences, salt))
+        );
+    }
+
+    pub fn delegate_vote(
+        origin: OriginType<<Runtime as frame_system::Config>::AccountId>,
+        account_id: <Runtime as frame_system::Config>::AccountId,
+        delegate: <Runtime as frame_system::Config>::AccountId,
+        stake: BalanceOf<Runtime>,
+        expected_result: Result<(), Error<Runtime, DefaultInstance>>,
+    ) -> () {
+        // check method returns expected result
+        assert_eq!(
+            Module::<Runtime>::delegate_vote(
+                InstanceMockUtils::<Runtime, DefaultInstance>::mock_origin(origin),
+                delegate,
+                stake,
+            ),
+            expected_result,
+        );
+
+        if expected_result.is_err() {
+            return;
+        }
+
+        assert_eq!(
+            Delegations::<Runtime, DefaultInstance>::get(account_id).map(
+                |Delegation {
+                     delegate, stake, ..
+                 }| (delegate, stake)
+            ),
+            Some((delegate, stake)),
+        );
+        assert!(Delegators::<Runtime, DefaultInstance>::contains_key(
+            delegate, account_id
+        ));
+
+        // check event was emitted
+        assert_eq!(
+            frame_system::Pallet::<Runtime>::events()
+                .last()
+                .unwrap()
+                .event,
+            RuntimeEvent::Referendum(RawEvent::VoteDelegated(account_id, delegate, stake))
+        );
+    }
+
+    pub fn revoke_vote_delegation(
+        origin: OriginType<<Runtime as frame_system::Config>::AccountId>,
+        account_id: <Runtime as frame_system::Config>::AccountId,
+        expected_result: Result<(), Error<Runtime, DefaultInstance>>,
+    ) -> () {
+        let delegation = Delegations::<Runtime, DefaultInstance>::get(account_id);
+
+        // check method returns expected result
+        assert_eq!(
+            Module::<Runtime>::revoke_vote_delegation(
+                InstanceMockUtils::<Runtime, DefaultInstance>::mock_origin(origin),
+            ),
+            expected_result,
+        );
+
+        if expected_result.is_err() {
+            return;
+        }
+
+        assert_eq!(
+            Delegations::<Runtime, DefaultInstance>::get(account_id),
+            None
+        );
+        assert!(!Delegators::<Runtime, DefaultInstance>::contains_key(
+            delegation.unwrap().delegate,
+            account_id
+        ));
+
+        // check event was emitted
+        assert_eq!(
+            frame_system::Pallet::<Runtime>::events()
+                .last()
+                .unwrap()
+                .event,
+            RuntimeEvent::Referendum(RawEvent::VoteDelegationRevoked(account_id))
+        );
+    }
+
     pub fn release_stake(
         origin: OriginType<<Runtime as frame_system::Config>::AccountId>,
         account_id: <Runtime as frame_system::Config>::AccountId,
@@ -791,6 +947,39 @@ impl InstanceMocks<Runtime, DefaultInstance> {
             RuntimeEvent::Referendum(RawEvent::AccountOptedOutOfVoting(account_id))
         );
     }
+
+    pub fn set_vote_power_strategy(
+        origin: OriginType<<Runtime as frame_system::Config>::AccountId>,
+        strategy: VotePowerStrategyOf<Runtime>,
+        expected_result: Result<(), Error<Runtime, DefaultInstance>>,
+    ) -> () {
+        // check method returns expected result
+        assert_eq!(
+            Module::<Runtime>::set_vote_power_strategy(
+                InstanceMockUtils::<Runtime, DefaultInstance>::mock_origin(origin),
+                strategy,
+            ),
+            expected_result,
+        );
+
+        if expected_result.is_err() {
+            return;
+        }
+
+        assert_eq!(
+            NextVotePowerStrategy::<Runtime, DefaultInstance>::get(),
+            Some(strategy),
+        );
+
+        // check event was emitted
+        assert_eq!(
+            frame_system::Pallet::<Runtime>::events()
+                .last()
+                .unwrap()
+                .event,
+            RuntimeEvent::Referendum(RawEvent::VotePowerStrategySet(strategy))
+        );
+    }
 }
 
 #[cfg(feature = "runtime-benchmarks")]
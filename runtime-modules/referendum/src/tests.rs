@@ -1,8 +1,12 @@
 #![cfg(test)]
 
-use super::{Config, Error, OptionResult};
+use super::{Config, Error, Module, OptionResult, RawEvent, ReferendumStage, Stage};
 use crate::mock::*;
+use common::referendum::VotePowerStrategy;
 use frame_support::error::BadOrigin;
+use frame_support::StorageValue;
+use sp_runtime::Percent;
+use staking_handler::StakingHandler;
 
 type Mocks = InstanceMocks<Runtime, DefaultInstance>;
 type MockUtils = InstanceMockUtils<Runtime, DefaultInstance>;
@@ -1224,3 +1228,955 @@ fn opt_out_of_voting_invalid_origin() {
         Mocks::opt_out_of_voting(none_origin, Err(BadOrigin.into()));
     });
 }
+
+/////////////////// Vote power strategy ////////////////////////////////////////
+
+// Runs a single winner referendum using the given vote power strategy with votes given as
+// (account id, stake, option id, block of the vote) and returns the referendum winners.
+fn run_referendum_with_vote_power_strategy(
+    strategy: VotePowerStrategy<u64>,
+    votes: Vec<(u64, u64, u64, u64)>,
+) -> Vec<OptionResult<u64, u64>> {
+    let voting_stage_duration = <Runtime as Config>::VoteStageDuration::get();
+    let reveal_stage_duration = <Runtime as Config>::RevealStageDuration::get();
+    let origin = OriginType::Signed(USER_ADMIN);
+    let cycle_id = 1;
+    let winning_target_count = 1;
+
+    Mocks::set_vote_power_strategy(OriginType::Root, strategy, Ok(()));
+    Mocks::start_referendum_extrinsic(origin, winning_target_count, cycle_id, Ok(()));
+    assert_eq!(Module::<Runtime>::current_vote_power_strategy(), strategy);
+
+    let mut reveals = Vec::new();
+    for (account_id, stake, option_id, block) in votes {
+        MockUtils::move_to_block(block);
+
+        let (commitment, salt) =
+            MockUtils::calculate_commitment(&account_id, &option_id, &cycle_id);
+        Mocks::vote(
+            OriginType::Signed(account_id),
+            account_id,
+            commitment,
+            stake,
+            cycle_id,
+            Ok(()),
+        );
+
+        reveals.push((account_id, salt, option_id));
+    }
+
+    // voting period starts at block 1
+    MockUtils::move_to_block(voting_stage_duration + 1);
+    Mocks::check_voting_finished(winning_target_count, cycle_id);
+
+    for (account_id, salt, option_id) in reveals {
+        Mocks::reveal_vote(
+            OriginType::Signed(account_id),
+            account_id,
+            salt,
+            option_id,
+            Ok(()),
+        );
+    }
+
+    MockUtils::increase_block_number(reveal_stage_duration);
+    assert_eq!(
+        Stage::<Runtime, DefaultInstance>::get(),
+        ReferendumStage::Inactive,
+    );
+
+    match frame_system::Pallet::<Runtime>::events()
+        .last()
+        .unwrap()
+        .event
+        .clone()
+    {
+        RuntimeEvent::Referendum(RawEvent::ReferendumFinished(winners)) => winners,
+        _ => panic!("Referendum did not finish"),
+    }
+}
+
+// One large stake backing the first option against three minimal stakes backing the second one.
+fn large_stake_against_broad_support_votes() -> Vec<(u64, u64, u64, u64)> {
+    let stake = <Runtime as Config>::MinimumStake::get();
+
+    vec![
+        (USER_REGULAR, 4 * stake, 0, 2),
+        (USER_REGULAR_2, stake, 1, 2),
+        (USER_REGULAR_3, stake, 1, 2),
+        (USER_REGULAR_4, stake, 1, 2),
+    ]
+}
+
+// A minimal stake locked at the beginning of the voting stage backing the first option against
+// a bigger stake locked just before the voting stage ends backing the second one.
+fn old_stake_against_fresh_stake_votes() -> Vec<(u64, u64, u64, u64)> {
+    let stake = <Runtime as Config>::MinimumStake::get();
+
+    vec![
+        (USER_REGULAR, stake, 0, 2),
+        (USER_REGULAR_2, stake * 3 / 2, 1, 5),
+    ]
+}
+
+/// Test that the vote power strategy can be set and it is applied when the next referendum starts.
+#[test]
+fn set_vote_power_strategy() {
+    build_test_externalities().execute_with(|| {
+        let strategy = VotePowerStrategy::SquareRoot;
+
+        assert_eq!(
+            Module::<Runtime>::current_vote_power_strategy(),
+            VotePowerStrategy::Stake
+        );
+
+        Mocks::set_vote_power_strategy(OriginType::Root, strategy, Ok(()));
+        assert_eq!(
+            Module::<Runtime>::current_vote_power_strategy(),
+            VotePowerStrategy::Stake
+        );
+
+        Mocks::start_referendum_extrinsic(OriginType::Signed(USER_ADMIN), 1, 1, Ok(()));
+        assert_eq!(Module::<Runtime>::current_vote_power_strategy(), strategy);
+        assert_eq!(Module::<Runtime>::next_vote_power_strategy(), None);
+    });
+}
+
+/// Test that the vote power strategy can be set only by the root origin.
+#[test]
+fn set_vote_power_strategy_invalid_origin() {
+    build_test_externalities().execute_with(|| {
+        Mocks::set_vote_power_strategy(
+            OriginType::Signed(USER_ADMIN),
+            VotePowerStrategy::SquareRoot,
+            Err(Error::BadOrigin),
+        );
+    });
+}
+
+/// Test that the time weighted vote power strategy requires non-zero maturity period.
+#[test]
+fn set_vote_power_strategy_invalid_strategy() {
+    build_test_externalities().execute_with(|| {
+        Mocks::set_vote_power_strategy(
+            OriginType::Root,
+            VotePowerStrategy::TimeWeighted {
+                maturity_period: 0,
+                max_bonus: Percent::from_percent(100),
+            },
+            Err(Error::InvalidVotePowerStrategy),
+        );
+    });
+}
+
+/// Test that the large stake wins against the broad support when the vote power equals the stake.
+#[test]
+fn stake_vote_power_strategy_favours_large_stake() {
+    build_test_externalities().execute_with(|| {
+        let stake = <Runtime as Config>::MinimumStake::get();
+
+        let winners = run_referendum_with_vote_power_strategy(
+            VotePowerStrategy::Stake,
+            large_stake_against_broad_support_votes(),
+        );
+
+        assert_eq!(
+            winners,
+            vec![OptionResult {
+                option_id: 0,
+                vote_power: 4 * stake,
+            }]
+        );
+    });
+}
+
+/// Test that the broad support wins against the large stake when the vote power equals the square
+/// root of the stake.
+#[test]
+fn square_root_vote_power_strategy_favours_broad_support() {
+    build_test_externalities().execute_with(|| {
+        // square root of the minimal stake
+        let stake_root = 100;
+
+        let winners = run_referendum_with_vote_power_strategy(
+            VotePowerStrategy::SquareRoot,
+            large_stake_against_broad_support_votes(),
+        );
+
+        assert_eq!(
+            winners,
+            vec![OptionResult {
+                option_id: 1,
+                vote_power: 3 * stake_root,
+            }]
+        );
+        assert_eq!(<Runtime as Config>::get_option_power(&0), 2 * stake_root,);
+    });
+}
+
+/// Test that the bigger stake wins regardless of the lock age when the vote power equals the stake.
+#[test]
+fn stake_vote_power_strategy_ignores_lock_age() {
+    build_test_externalities().execute_with(|| {
+        let stake = <Runtime as Config>::MinimumStake::get();
+
+        let winners = run_referendum_with_vote_power_strategy(
+            VotePowerStrategy::Stake,
+            old_stake_against_fresh_stake_votes(),
+        );
+
+        assert_eq!(
+            winners,
+            vec![OptionResult {
+                option_id: 1,
+                vote_power: stake * 3 / 2,
+            }]
+        );
+    });
+}
+
+/// Test that the older stake wins against the bigger fresh stake when the vote power is weighted
+/// by the lock age.
+#[test]
+fn time_weighted_vote_power_strategy_favours_old_stake() {
+    build_test_externalities().execute_with(|| {
+        let stake = <Runtime as Config>::MinimumStake::get();
+
+        // revealing stage starts at block 6, so the first stake is locked for 4 blocks and
+        // gets the full bonus while the second one is locked for 1 block only
+        let winners = run_referendum_with_vote_power_strategy(
+            VotePowerStrategy::TimeWeighted {
+                maturity_period: 4,
+                max_bonus: Percent::from_percent(100),
+            },
+            old_stake_against_fresh_stake_votes(),
+        );
+
+        assert_eq!(
+            winners,
+            vec![OptionResult {
+                option_id: 0,
+                vote_power: 2 * stake,
+            }]
+        );
+        assert_eq!(
+            <Runtime as Config>::get_option_power(&1),
+            stake * 3 / 2 + stake * 3 / 8,
+        );
+    });
+}
+
+/// Test that the lock age is kept when the stake is reused and restarted when the stake is
+/// increased or released.
+#[test]
+fn vote_stake_lock_age_restarts_on_stake_increase() {
+    build_test_externalities().execute_with(|| {
+        let voting_stage_duration = <Runtime as Config>::VoteStageDuration::get();
+        let reveal_stage_duration = <Runtime as Config>::RevealStageDuration::get();
+        let cycle_duration = voting_stage_duration + reveal_stage_duration;
+        let account_id = USER_ADMIN;
+        let origin = OriginType::Signed(account_id);
+        let option_to_vote_for = 0;
+        let stake = <Runtime as Config>::MinimumStake::get();
+
+        for (cycle_id, cycle_stake, locked_since) in
+            vec![(1, stake, 2), (2, stake, 2), (3, stake * 2, 26)]
+        {
+            Mocks::start_referendum_extrinsic(origin.clone(), 1, cycle_id, Ok(()));
+
+            let (commitment, _) =
+                MockUtils::calculate_commitment(&account_id, &option_to_vote_for, &cycle_id);
+            Mocks::vote(
+                origin.clone(),
+                account_id,
+                commitment,
+                cycle_stake,
+                cycle_id,
+                Ok(()),
+            );
+            assert_eq!(
+                Module::<Runtime>::vote_stake_locked_since(account_id),
+                Some(locked_since)
+            );
+
+            // voting period starts at block 1
+            MockUtils::move_to_block(cycle_duration * cycle_id + 1);
+        }
+
+        Mocks::release_stake(origin, account_id, Ok(()));
+        assert_eq!(Module::<Runtime>::vote_stake_locked_since(account_id), None);
+    });
+}
+
+/////////////////// Ranked ballots /////////////////////////////////////////////
+
+// Casts a vote committing to the given ranked preferences and returns the salt used.
+fn vote_ranked(account_id: u64, stake: u64, preferences: &[u64], cycle_id: u64) -> Vec<u8> {
+    let (commitment, salt) =
+        MockUtils::calculate_ranked_commitment(&account_id, preferences, &cycle_id);
+    Mocks::vote(
+        OriginType::Signed(account_id),
+        account_id,
+        commitment,
+        stake,
+        cycle_id,
+        Ok(()),
+    );
+
+    salt
+}
+
+// Runs a referendum with the given ranked ballots given as (account id, stake, preferences) and
+// single option votes given as (account id, stake, option id) and returns the referendum winners.
+fn run_referendum_with_ranked_ballots(
+    winning_target_count: u32,
+    ranked_votes: Vec<(u64, u64, Vec<u64>)>,
+    single_votes: Vec<(u64, u64, u64)>,
+) -> Vec<OptionResult<u64, u64>> {
+    let voting_stage_duration = <Runtime as Config>::VoteStageDuration::get();
+    let reveal_stage_duration = <Runtime as Config>::RevealStageDuration::get();
+    let cycle_id = 1;
+
+    Mocks::start_referendum_extrinsic(
+        OriginType::Signed(USER_ADMIN),
+        winning_target_count,
+        cycle_id,
+        Ok(()),
+    );
+
+    let ranked_reveals: Vec<_> = ranked_votes
+        .into_iter()
+        .map(|(account_id, stake, preferences)| {
+            let salt = vote_ranked(account_id, stake, &preferences, cycle_id);
+            (account_id, salt, preferences)
+        })
+        .collect();
+
+    let mut single_reveals = Vec::new();
+    for (account_id, stake, option_id) in single_votes {
+        let (commitment, salt) =
+            MockUtils::calculate_commitment(&account_id, &option_id, &cycle_id);
+        Mocks::vote(
+            OriginType::Signed(account_id),
+            account_id,
+            commitment,
+            stake,
+            cycle_id,
+            Ok(()),
+        );
+
+        single_reveals.push((account_id, salt, option_id));
+    }
+
+    // voting period starts at block 1
+    MockUtils::move_to_block(voting_stage_duration + 1);
+    Mocks::check_voting_finished(winning_target_count, cycle_id);
+
+    for (account_id, salt, preferences) in ranked_reveals {
+        Mocks::reveal_ranked_vote(
+            OriginType::Signed(account_id),
+            account_id,
+            salt,
+            preferences,
+            Ok(()),
+        );
+    }
+
+    for (account_id, salt, option_id) in single_reveals {
+        Mocks::reveal_vote(
+            OriginType::Signed(account_id),
+            account_id,
+            salt,
+            option_id,
+            Ok(()),
+        );
+    }
+
+    MockUtils::increase_block_number(reveal_stage_duration);
+    assert_eq!(
+        Stage::<Runtime, DefaultInstance>::get(),
+        ReferendumStage::Inactive,
+    );
+    assert_eq!(Module::<Runtime>::ranked_ballot_count(), 0);
+    assert_eq!(Module::<Runtime>::voted_option_count(), 0);
+
+    match frame_system::Pallet::<Runtime>::events()
+        .last()
+        .unwrap()
+        .event
+        .clone()
+    {
+        RuntimeEvent::Referendum(RawEvent::ReferendumFinished(winners)) => winners,
+        _ => panic!("Referendum did not finish"),
+    }
+}
+
+/// Test that a ranked ballot can be revealed.
+#[test]
+fn reveal_ranked_vote() {
+    build_test_externalities().execute_with(|| {
+        let voting_stage_duration = <Runtime as Config>::VoteStageDuration::get();
+        let account_id = USER_ADMIN;
+        let origin = OriginType::Signed(account_id);
+        let cycle_id = 1;
+        let stake = <Runtime as Config>::MinimumStake::get();
+        let preferences = vec![0, 1];
+
+        Mocks::start_referendum_extrinsic(origin.clone(), 1, cycle_id, Ok(()));
+        let salt = vote_ranked(account_id, stake, &preferences, cycle_id);
+
+        MockUtils::move_to_block(voting_stage_duration + 1);
+        Mocks::check_voting_finished(1, cycle_id);
+
+        Mocks::reveal_ranked_vote(origin, account_id, salt, preferences, Ok(()));
+        assert_eq!(Module::<Runtime>::ranked_ballot_count(), 1);
+        assert_eq!(Module::<Runtime>::votes(account_id).vote_for, Some(0));
+    });
+}
+
+/// Test that empty, duplicated or too long preferences are rejected.
+#[test]
+fn reveal_ranked_vote_invalid_ballot() {
+    build_test_externalities().execute_with(|| {
+        let voting_stage_duration = <Runtime as Config>::VoteStageDuration::get();
+        let max_preferences = <Runtime as Config>::MaxRankedBallotPreferences::get() as u64;
+        let account_id = USER_ADMIN;
+        let origin = OriginType::Signed(account_id);
+        let cycle_id = 1;
+        let stake = <Runtime as Config>::MinimumStake::get();
+
+        Mocks::start_referendum_extrinsic(origin.clone(), 1, cycle_id, Ok(()));
+        let salt = vote_ranked(account_id, stake, &[0, 1], cycle_id);
+
+        MockUtils::move_to_block(voting_stage_duration + 1);
+        Mocks::check_voting_finished(1, cycle_id);
+
+        for preferences in vec![vec![], vec![0, 0], (0..=max_preferences).collect()] {
+            Mocks::reveal_ranked_vote(
+                origin.clone(),
+                account_id,
+                salt.clone(),
+                preferences,
+                Err(Error::InvalidRankedBallot),
+            );
+        }
+    });
+}
+
+/// Test that ranked preferences have to match the commitment.
+#[test]
+fn reveal_ranked_vote_invalid_commitment() {
+    build_test_externalities().execute_with(|| {
+        let voting_stage_duration = <Runtime as Config>::VoteStageDuration::get();
+        let account_id = USER_ADMIN;
+        let origin = OriginType::Signed(account_id);
+        let cycle_id = 1;
+        let stake = <Runtime as Config>::MinimumStake::get();
+
+        Mocks::start_referendum_extrinsic(origin.clone(), 1, cycle_id, Ok(()));
+        let salt = vote_ranked(account_id, stake, &[0, 1], cycle_id);
+
+        MockUtils::move_to_block(voting_stage_duration + 1);
+        Mocks::check_voting_finished(1, cycle_id);
+
+        Mocks::reveal_ranked_vote(
+            origin.clone(),
+            account_id,
+            salt.clone(),
+            vec![1, 0],
+            Err(Error::InvalidReveal),
+        );
+        Mocks::reveal_vote(origin, account_id, salt, 0, Err(Error::InvalidReveal));
+    });
+}
+
+/// Test that no more than the maximum number of ranked ballots can be revealed.
+#[test]
+fn reveal_ranked_vote_max_ballots_reached() {
+    build_test_externalities().execute_with(|| {
+        let voting_stage_duration = <Runtime as Config>::VoteStageDuration::get();
+        let max_ballots = <Runtime as Config>::MaxRankedBallots::get() as u64;
+        let cycle_id = 1;
+        let stake = <Runtime as Config>::MinimumStake::get();
+        let preferences = vec![0, 1];
+        let first_account_id = 100;
+
+        Mocks::start_referendum_extrinsic(OriginType::Signed(USER_ADMIN), 1, cycle_id, Ok(()));
+
+        let reveals: Vec<_> = (first_account_id..=first_account_id + max_ballots)
+            .map(|account_id| {
+                topup_account(account_id, 2 * stake);
+                (
+                    account_id,
+                    vote_ranked(account_id, stake, &preferences, cycle_id),
+                )
+            })
+            .collect();
+
+        MockUtils::move_to_block(voting_stage_duration + 1);
+        Mocks::check_voting_finished(1, cycle_id);
+
+        for (account_id, salt) in reveals {
+            let expected_result = if account_id < first_account_id + max_ballots {
+                Ok(())
+            } else {
+                Err(Error::MaxRankedBallotsReached)
+            };
+
+            Mocks::reveal_ranked_vote(
+                OriginType::Signed(account_id),
+                account_id,
+                salt,
+                preferences.clone(),
+                expected_result,
+            );
+        }
+    });
+}
+
+/// Test that no more than the maximum number of options can receive a single option vote.
+#[test]
+fn reveal_vote_max_voted_options_reached() {
+    build_test_externalities().execute_with(|| {
+        let voting_stage_duration = <Runtime as Config>::VoteStageDuration::get();
+        let max_voted_options = <Runtime as Config>::MaxVotedOptions::get() as u64;
+        let cycle_id = 1;
+        let stake = <Runtime as Config>::MinimumStake::get();
+        let first_account_id = 100;
+
+        Mocks::start_referendum_extrinsic(OriginType::Signed(USER_ADMIN), 1, cycle_id, Ok(()));
+
+        // each account votes for a different option except the last one voting for the first
+        // option again
+        let reveals: Vec<_> = (0..=max_voted_options + 1)
+            .map(|index| {
+                let account_id = first_account_id + index;
+                let option_id = if index <= max_voted_options { index } else { 0 };
+                topup_account(account_id, 2 * stake);
+
+                let (commitment, salt) =
+                    MockUtils::calculate_commitment(&account_id, &option_id, &cycle_id);
+                Mocks::vote(
+                    OriginType::Signed(account_id),
+                    account_id,
+                    commitment,
+                    stake,
+                    cycle_id,
+                    Ok(()),
+                );
+
+                (account_id, salt, option_id)
+            })
+            .collect();
+
+        MockUtils::move_to_block(voting_stage_duration + 1);
+        Mocks::check_voting_finished(1, cycle_id);
+
+        for (account_id, salt, option_id) in reveals {
+            let expected_result = if option_id == max_voted_options {
+                Err(Error::MaxVotedOptionsReached)
+            } else {
+                Ok(())
+            };
+
+            Mocks::reveal_vote(
+                OriginType::Signed(account_id),
+                account_id,
+                salt,
+                option_id,
+                expected_result,
+            );
+        }
+
+        assert_eq!(
+            Module::<Runtime>::voted_option_count(),
+            max_voted_options as u32
+        );
+    });
+}
+
+/// Test that votes of an eliminated option are transferred to the next preference, electing the
+/// option that would lose the single option vote.
+#[test]
+fn ranked_ballots_transfer_votes_of_eliminated_options() {
+    build_test_externalities().execute_with(|| {
+        let winners = run_referendum_with_ranked_ballots(
+            1,
+            vec![
+                (USER_REGULAR, 15000, vec![0]),
+                (USER_REGULAR_2, 12000, vec![2, 1]),
+                (USER_REGULAR_3, 10000, vec![1, 2]),
+            ],
+            vec![],
+        );
+
+        assert_eq!(
+            winners,
+            vec![OptionResult {
+                option_id: 2,
+                vote_power: 22000,
+            }]
+        );
+        assert_eq!(Module::<Runtime>::ranked_ballots(USER_REGULAR_2), None);
+    });
+}
+
+/// Test that the surplus of an elected option is transferred to the next preference.
+#[test]
+fn ranked_ballots_transfer_surplus_of_elected_options() {
+    build_test_externalities().execute_with(|| {
+        let winners = run_referendum_with_ranked_ballots(
+            2,
+            vec![
+                (USER_REGULAR, 30000, vec![0, 1]),
+                (USER_REGULAR_2, 10000, vec![2]),
+                (USER_REGULAR_3, 12000, vec![3]),
+            ],
+            vec![],
+        );
+
+        assert_eq!(
+            winners,
+            vec![
+                OptionResult {
+                    option_id: 0,
+                    vote_power: 30000,
+                },
+                OptionResult {
+                    option_id: 1,
+                    vote_power: 12666,
+                },
+            ]
+        );
+    });
+}
+
+/// Test that single option votes are counted along with the ranked ballots but never transferred.
+#[test]
+fn ranked_ballots_counted_with_single_option_votes() {
+    build_test_externalities().execute_with(|| {
+        let winners = run_referendum_with_ranked_ballots(
+            1,
+            vec![
+                (USER_REGULAR, 15000, vec![0]),
+                (USER_REGULAR_2, 12000, vec![2, 1]),
+            ],
+            vec![(USER_REGULAR_3, 10000, 1)],
+        );
+
+        assert_eq!(
+            winners,
+            vec![OptionResult {
+                option_id: 0,
+                vote_power: 15000,
+            }]
+        );
+    });
+}
+
+/// Test that options receiving only single option votes are counted in the tally even when they
+/// aren't among the single option winners.
+#[test]
+fn ranked_ballots_counted_with_all_voted_options() {
+    build_test_externalities().execute_with(|| {
+        let winners = run_referendum_with_ranked_ballots(
+            2,
+            vec![(USER_REGULAR, 30000, vec![0, 1])],
+            vec![
+                (USER_REGULAR_3, 10000, 3),
+                (USER_REGULAR_4, 9000, 4),
+                (USER_REGULAR_5, 8003, 5),
+            ],
+        );
+
+        // option 5 counts in the quota (57003 / 3 + 1 = 19002) and so reduces the surplus
+        // transferred to option 1
+        assert_eq!(
+            winners,
+            vec![
+                OptionResult {
+                    option_id: 0,
+                    vote_power: 30000,
+                },
+                OptionResult {
+                    option_id: 1,
+                    vote_power: 10998,
+                },
+            ]
+        );
+    });
+}
+
+/// Test that a ranked ballot ends up backing its most preferred elected option.
+#[test]
+fn ranked_ballots_back_most_preferred_elected_option() {
+    build_test_externalities().execute_with(|| {
+        run_referendum_with_ranked_ballots(
+            1,
+            vec![
+                (USER_REGULAR, 15000, vec![0]),
+                (USER_REGULAR_2, 12000, vec![2, 1]),
+                (USER_REGULAR_3, 10000, vec![1, 2]),
+            ],
+            vec![],
+        );
+
+        // option 2 was elected
+        assert_eq!(Module::<Runtime>::votes(USER_REGULAR_2).vote_for, Some(2));
+        assert_eq!(Module::<Runtime>::votes(USER_REGULAR_3).vote_for, Some(2));
+        // ballot without an elected option keeps its most preferred option
+        assert_eq!(Module::<Runtime>::votes(USER_REGULAR).vote_for, Some(0));
+    });
+}
+
+/////////////////// Vote delegation ////////////////////////////////////////////
+
+fn locked_stake(account_id: u64) -> u64 {
+    <Runtime as Config>::StakingHandler::current_stake(&account_id)
+}
+
+/// Test that the voting power can be delegated and the delegation can be moved.
+#[test]
+fn delegate_vote() {
+    build_test_externalities().execute_with(|| {
+        let stake = <Runtime as Config>::MinimumStake::get();
+
+        Mocks::delegate_vote(
+            OriginType::Signed(USER_REGULAR_2),
+            USER_REGULAR_2,
+            USER_REGULAR,
+            stake,
+            Ok(()),
+        );
+        assert_eq!(Module::<Runtime>::delegator_count(USER_REGULAR), 1);
+        assert_eq!(locked_stake(USER_REGULAR_2), stake);
+
+        Mocks::delegate_vote(
+            OriginType::Signed(USER_REGULAR_2),
+            USER_REGULAR_2,
+            USER_REGULAR_3,
+            2 * stake,
+            Ok(()),
+        );
+        assert_eq!(Module::<Runtime>::delegator_count(USER_REGULAR), 0);
+        assert_eq!(Module::<Runtime>::delegator_count(USER_REGULAR_3), 1);
+        assert_eq!(locked_stake(USER_REGULAR_2), 2 * stake);
+    });
+}
+
+/// Test that the voting power can't be delegated to the delegator itself.
+#[test]
+fn delegate_vote_invalid_delegate() {
+    build_test_externalities().execute_with(|| {
+        Mocks::delegate_vote(
+            OriginType::Signed(USER_REGULAR),
+            USER_REGULAR,
+            USER_REGULAR,
+            <Runtime as Config>::MinimumStake::get(),
+            Err(Error::InvalidDelegate),
+        );
+    });
+}
+
+/// Test that the delegated stake has to be at least the minimum voting stake.
+#[test]
+fn delegate_vote_insufficient_stake() {
+    build_test_externalities().execute_with(|| {
+        Mocks::delegate_vote(
+            OriginType::Signed(USER_REGULAR_2),
+            USER_REGULAR_2,
+            USER_REGULAR,
+            <Runtime as Config>::MinimumStake::get() - 1,
+            Err(Error::InsufficientStake),
+        );
+    });
+}
+
+/// Test that the delegation can't be created during the revealing stage, but it can be revoked.
+#[test]
+fn delegate_vote_locked_during_revealing() {
+    build_test_externalities().execute_with(|| {
+        let voting_stage_duration = <Runtime as Config>::VoteStageDuration::get();
+        let stake = <Runtime as Config>::MinimumStake::get();
+        let cycle_id = 1;
+
+        Mocks::delegate_vote(
+            OriginType::Signed(USER_REGULAR_2),
+            USER_REGULAR_2,
+            USER_REGULAR,
+            stake,
+            Ok(()),
+        );
+
+        Mocks::start_referendum_extrinsic(OriginType::Signed(USER_ADMIN), 1, cycle_id, Ok(()));
+        MockUtils::move_to_block(voting_stage_duration + 1);
+        Mocks::check_voting_finished(1, cycle_id);
+
+        Mocks::delegate_vote(
+            OriginType::Signed(USER_REGULAR_3),
+            USER_REGULAR_3,
+            USER_REGULAR,
+            stake,
+            Err(Error::DelegationLockedDuringRevealing),
+        );
+        Mocks::revoke_vote_delegation(OriginType::Signed(USER_REGULAR_2), USER_REGULAR_2, Ok(()));
+    });
+}
+
+/// Test that the number of accounts delegating to a single delegate is limited.
+#[test]
+fn delegate_vote_max_delegators_reached() {
+    build_test_externalities().execute_with(|| {
+        let stake = <Runtime as Config>::MinimumStake::get();
+
+        for delegator in vec![USER_REGULAR_2, USER_REGULAR_3, USER_REGULAR_4] {
+            Mocks::delegate_vote(
+                OriginType::Signed(delegator),
+                delegator,
+                USER_REGULAR,
+                stake,
+                Ok(()),
+            );
+        }
+
+        Mocks::delegate_vote(
+            OriginType::Signed(USER_REGULAR_5),
+            USER_REGULAR_5,
+            USER_REGULAR,
+            stake,
+            Err(Error::MaxDelegatorsReached),
+        );
+
+        // existing delegators can still change their stake
+        Mocks::delegate_vote(
+            OriginType::Signed(USER_REGULAR_2),
+            USER_REGULAR_2,
+            USER_REGULAR,
+            2 * stake,
+            Ok(()),
+        );
+    });
+}
+
+/// Test that the delegation can be revoked releasing the delegated stake.
+#[test]
+fn revoke_vote_delegation() {
+    build_test_externalities().execute_with(|| {
+        Mocks::delegate_vote(
+            OriginType::Signed(USER_REGULAR_2),
+            USER_REGULAR_2,
+            USER_REGULAR,
+            <Runtime as Config>::MinimumStake::get(),
+            Ok(()),
+        );
+
+        Mocks::revoke_vote_delegation(OriginType::Signed(USER_REGULAR_2), USER_REGULAR_2, Ok(()));
+        assert_eq!(Module::<Runtime>::delegator_count(USER_REGULAR), 0);
+        assert_eq!(locked_stake(USER_REGULAR_2), 0);
+
+        Mocks::revoke_vote_delegation(
+            OriginType::Signed(USER_REGULAR_2),
+            USER_REGULAR_2,
+            Err(Error::DelegationNotExisting),
+        );
+    });
+}
+
+/// Test that the delegate's vote carries the delegated stake.
+#[test]
+fn delegated_stake_counted_in_delegate_vote() {
+    build_test_externalities().execute_with(|| {
+        let stake = <Runtime as Config>::MinimumStake::get();
+
+        Mocks::delegate_vote(
+            OriginType::Signed(USER_REGULAR_2),
+            USER_REGULAR_2,
+            USER_REGULAR,
+            stake,
+            Ok(()),
+        );
+
+        let winners = run_referendum_with_vote_power_strategy(
+            VotePowerStrategy::Stake,
+            vec![
+                (USER_REGULAR, stake, 0, 2),
+                (USER_REGULAR_3, stake * 3 / 2, 1, 2),
+            ],
+        );
+
+        assert_eq!(
+            winners,
+            vec![OptionResult {
+                option_id: 0,
+                vote_power: 2 * stake,
+            }]
+        );
+    });
+}
+
+/// Test that the delegator voting in the cycle overrides the delegation.
+#[test]
+fn delegator_vote_overrides_delegation() {
+    build_test_externalities().execute_with(|| {
+        let stake = <Runtime as Config>::MinimumStake::get();
+
+        Mocks::delegate_vote(
+            OriginType::Signed(USER_REGULAR_2),
+            USER_REGULAR_2,
+            USER_REGULAR,
+            stake,
+            Ok(()),
+        );
+
+        let winners = run_referendum_with_vote_power_strategy(
+            VotePowerStrategy::Stake,
+            vec![
+                (USER_REGULAR, stake, 0, 2),
+                (USER_REGULAR_2, stake, 1, 2),
+                (USER_REGULAR_3, stake * 3 / 2, 1, 2),
+            ],
+        );
+
+        assert_eq!(
+            winners,
+            vec![OptionResult {
+                option_id: 1,
+                vote_power: stake * 5 / 2,
+            }]
+        );
+    });
+}
+
+/// Test that the stake lock covers both the vote stake and the delegated stake.
+#[test]
+fn vote_stake_lock_covers_delegated_stake() {
+    build_test_externalities().execute_with(|| {
+        let voting_stage_duration = <Runtime as Config>::VoteStageDuration::get();
+        let reveal_stage_duration = <Runtime as Config>::RevealStageDuration::get();
+        let account_id = USER_REGULAR_2;
+        let origin = OriginType::Signed(account_id);
+        let stake = <Runtime as Config>::MinimumStake::get();
+        let cycle_id = 1;
+
+        Mocks::delegate_vote(origin.clone(), account_id, USER_REGULAR, stake, Ok(()));
+
+        Mocks::start_referendum_extrinsic(OriginType::Signed(USER_ADMIN), 1, cycle_id, Ok(()));
+        let (commitment, _) = MockUtils::calculate_commitment(&account_id, &0, &cycle_id);
+        Mocks::vote(
+            origin.clone(),
+            account_id,
+            commitment,
+            2 * stake,
+            cycle_id,
+            Ok(()),
+        );
+        assert_eq!(locked_stake(account_id), 2 * stake);
+
+        MockUtils::move_to_block(voting_stage_duration + reveal_stage_duration + 1);
+        Mocks::release_stake(origin.clone(), account_id, Ok(()));
+        assert_eq!(locked_stake(account_id), stake);
+
+        Mocks::revoke_vote_delegation(origin, account_id, Ok(()));
+        assert_eq!(locked_stake(account_id), 0);
+    });
+}
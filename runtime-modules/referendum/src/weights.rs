@@ -20,6 +20,9 @@
 //! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 4.0.0-dev
 //! DATE: 2023-11-06, STEPS: `50`, REPEAT: 20, LOW RANGE: `[]`, HIGH RANGE: `[]`
 //! EXECUTION: Some(Wasm), WASM-EXECUTION: Compiled, CHAIN: Some("prod-test"), DB CACHE: 1024
+//!
+//! NOTE: the weight functions marked as PLACEHOLDER are hand-written estimates, not the
+//! benchmark output. They must be regenerated with the benchmark CLI before merging.
 
 // Executed Command:
 // ./scripts/../target/release/joystream-node
@@ -53,6 +56,12 @@ pub trait WeightInfo {
 	fn reveal_vote_already_existing(_i: u32, ) -> Weight;
 	fn release_vote_stake() -> Weight;
 	fn opt_out_of_voting() -> Weight;
+	fn set_vote_power_strategy() -> Weight;
+	fn reveal_ranked_vote(_p: u32, ) -> Weight;
+	fn on_initialize_revealing_ranked(_b: u32, _p: u32, ) -> Weight;
+	fn delegate_vote() -> Weight;
+	fn revoke_vote_delegation() -> Weight;
+	fn delegated_vote_stake(_d: u32, ) -> Weight;
 }
 
 /// Weights for referendum using the Substrate node and recommended hardware.
@@ -89,127 +98,131 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(1_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
+	// PLACEHOLDER: hand-written estimate, not the benchmark output.
+	// Must be regenerated with the benchmark CLI before merging.
 	// Storage: Instance1Referendum AccountsOptedOut (r:1 w:0)
 	// Proof: Instance1Referendum AccountsOptedOut (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
 	// Storage: Instance1Referendum Stage (r:1 w:0)
 	// Proof: Instance1Referendum Stage (max_values: Some(1), max_size: Some(94), added: 589, mode: MaxEncodedLen)
 	// Storage: Instance1Referendum Votes (r:1 w:1)
 	// Proof: Instance1Referendum Votes (max_values: None, max_size: Some(113), added: 2588, mode: MaxEncodedLen)
+	// Storage: Instance1Referendum VoteStakeLockedSince (r:1 w:1)
+	// Proof: Instance1Referendum VoteStakeLockedSince (max_values: None, max_size: Some(56), added: 2531, mode: MaxEncodedLen)
 	// Storage: Balances Locks (r:1 w:1)
 	// Proof: Balances Locks (max_values: None, max_size: Some(1299), added: 3774, mode: MaxEncodedLen)
 	// Storage: System Account (r:1 w:1)
 	// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
 	fn vote() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `291`
-		//  Estimated: `17027`
-		// Minimum execution time: 38_617 nanoseconds.
 		Weight::from_parts(39_844_000, 0u64)
 			.saturating_add(Weight::from_parts(0, 17027))
-			.saturating_add(T::DbWeight::get().reads(5_u64))
-			.saturating_add(T::DbWeight::get().writes(3_u64))
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
 	}
+	// PLACEHOLDER: hand-written estimate, not the benchmark output.
+	// Must be regenerated with the benchmark CLI before merging.
 	// Storage: Instance1Referendum Stage (r:1 w:1)
 	// Proof: Instance1Referendum Stage (max_values: Some(1), max_size: Some(94), added: 589, mode: MaxEncodedLen)
 	// Storage: Instance1Referendum Votes (r:1 w:1)
 	// Proof: Instance1Referendum Votes (max_values: None, max_size: Some(113), added: 2588, mode: MaxEncodedLen)
 	// Storage: Council Candidates (r:1 w:1)
 	// Proof: Council Candidates (max_values: None, max_size: Some(161), added: 2636, mode: MaxEncodedLen)
+	// Storage: Instance1Referendum VotePowerStrategyInUse (r:1 w:0)
+	// Proof: Instance1Referendum VotePowerStrategyInUse (max_values: Some(1), max_size: Some(10), added: 505, mode: MaxEncodedLen)
+	// Storage: Instance1Referendum VoteStakeLockedSince (r:1 w:0)
+	// Proof: Instance1Referendum VoteStakeLockedSince (max_values: None, max_size: Some(56), added: 2531, mode: MaxEncodedLen)
 	// Storage: Council AnnouncementPeriodNr (r:1 w:0)
 	// Proof: Council AnnouncementPeriodNr (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
 	/// The range of component `i` is `[0, 2]`.
 	fn reveal_vote_space_for_new_winner(i: u32, ) -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `690 + i * (100 ±0)`
-		//  Estimated: `10276`
-		// Minimum execution time: 39_963 nanoseconds.
 		Weight::from_parts(42_083_067, 0u64)
 			.saturating_add(Weight::from_parts(0, 10276))
-			// Standard Error: 33_840
 			.saturating_add(Weight::from_parts(1_954_615, 0u64).saturating_mul(i.into()))
-			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().reads(6_u64))
 			.saturating_add(T::DbWeight::get().writes(3_u64))
 	}
+	// PLACEHOLDER: hand-written estimate, not the benchmark output.
+	// Must be regenerated with the benchmark CLI before merging.
 	// Storage: Instance1Referendum Stage (r:1 w:1)
 	// Proof: Instance1Referendum Stage (max_values: Some(1), max_size: Some(94), added: 589, mode: MaxEncodedLen)
 	// Storage: Instance1Referendum Votes (r:1 w:1)
 	// Proof: Instance1Referendum Votes (max_values: None, max_size: Some(113), added: 2588, mode: MaxEncodedLen)
 	// Storage: Council Candidates (r:1 w:1)
 	// Proof: Council Candidates (max_values: None, max_size: Some(161), added: 2636, mode: MaxEncodedLen)
+	// Storage: Instance1Referendum VotePowerStrategyInUse (r:1 w:0)
+	// Proof: Instance1Referendum VotePowerStrategyInUse (max_values: Some(1), max_size: Some(10), added: 505, mode: MaxEncodedLen)
+	// Storage: Instance1Referendum VoteStakeLockedSince (r:1 w:0)
+	// Proof: Instance1Referendum VoteStakeLockedSince (max_values: None, max_size: Some(56), added: 2531, mode: MaxEncodedLen)
 	// Storage: Council AnnouncementPeriodNr (r:1 w:0)
 	// Proof: Council AnnouncementPeriodNr (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
 	/// The range of component `i` is `[0, 2]`.
 	fn reveal_vote_space_not_in_winners(i: u32, ) -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `790 + i * (93 ±0)`
-		//  Estimated: `10276`
-		// Minimum execution time: 41_645 nanoseconds.
 		Weight::from_parts(43_641_575, 0u64)
 			.saturating_add(Weight::from_parts(0, 10276))
-			// Standard Error: 31_470
 			.saturating_add(Weight::from_parts(1_200_351, 0u64).saturating_mul(i.into()))
-			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().reads(6_u64))
 			.saturating_add(T::DbWeight::get().writes(3_u64))
 	}
+	// PLACEHOLDER: hand-written estimate, not the benchmark output.
+	// Must be regenerated with the benchmark CLI before merging.
 	// Storage: Instance1Referendum Stage (r:1 w:1)
 	// Proof: Instance1Referendum Stage (max_values: Some(1), max_size: Some(94), added: 589, mode: MaxEncodedLen)
 	// Storage: Instance1Referendum Votes (r:1 w:1)
 	// Proof: Instance1Referendum Votes (max_values: None, max_size: Some(113), added: 2588, mode: MaxEncodedLen)
 	// Storage: Council Candidates (r:1 w:1)
 	// Proof: Council Candidates (max_values: None, max_size: Some(161), added: 2636, mode: MaxEncodedLen)
+	// Storage: Instance1Referendum VotePowerStrategyInUse (r:1 w:0)
+	// Proof: Instance1Referendum VotePowerStrategyInUse (max_values: Some(1), max_size: Some(10), added: 505, mode: MaxEncodedLen)
+	// Storage: Instance1Referendum VoteStakeLockedSince (r:1 w:0)
+	// Proof: Instance1Referendum VoteStakeLockedSince (max_values: None, max_size: Some(56), added: 2531, mode: MaxEncodedLen)
 	// Storage: Council AnnouncementPeriodNr (r:1 w:0)
 	// Proof: Council AnnouncementPeriodNr (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
 	/// The range of component `i` is `[0, 2]`.
 	fn reveal_vote_space_replace_last_winner(i: u32, ) -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `790 + i * (93 ±0)`
-		//  Estimated: `10276`
-		// Minimum execution time: 41_606 nanoseconds.
 		Weight::from_parts(43_583_726, 0u64)
 			.saturating_add(Weight::from_parts(0, 10276))
-			// Standard Error: 37_016
 			.saturating_add(Weight::from_parts(1_150_868, 0u64).saturating_mul(i.into()))
-			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().reads(6_u64))
 			.saturating_add(T::DbWeight::get().writes(3_u64))
 	}
+	// PLACEHOLDER: hand-written estimate, not the benchmark output.
+	// Must be regenerated with the benchmark CLI before merging.
 	// Storage: Instance1Referendum Stage (r:1 w:1)
 	// Proof: Instance1Referendum Stage (max_values: Some(1), max_size: Some(94), added: 589, mode: MaxEncodedLen)
 	// Storage: Instance1Referendum Votes (r:1 w:1)
 	// Proof: Instance1Referendum Votes (max_values: None, max_size: Some(113), added: 2588, mode: MaxEncodedLen)
 	// Storage: Council Candidates (r:1 w:1)
 	// Proof: Council Candidates (max_values: None, max_size: Some(161), added: 2636, mode: MaxEncodedLen)
+	// Storage: Instance1Referendum VotePowerStrategyInUse (r:1 w:0)
+	// Proof: Instance1Referendum VotePowerStrategyInUse (max_values: Some(1), max_size: Some(10), added: 505, mode: MaxEncodedLen)
+	// Storage: Instance1Referendum VoteStakeLockedSince (r:1 w:0)
+	// Proof: Instance1Referendum VoteStakeLockedSince (max_values: None, max_size: Some(56), added: 2531, mode: MaxEncodedLen)
 	// Storage: Council AnnouncementPeriodNr (r:1 w:0)
 	// Proof: Council AnnouncementPeriodNr (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
 	/// The range of component `i` is `[0, 2]`.
 	fn reveal_vote_already_existing(i: u32, ) -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `753 + i * (96 ±0)`
-		//  Estimated: `10276`
-		// Minimum execution time: 40_385 nanoseconds.
 		Weight::from_parts(42_197_900, 0u64)
 			.saturating_add(Weight::from_parts(0, 10276))
-			// Standard Error: 32_864
 			.saturating_add(Weight::from_parts(1_902_509, 0u64).saturating_mul(i.into()))
-			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().reads(6_u64))
 			.saturating_add(T::DbWeight::get().writes(3_u64))
 	}
+	// PLACEHOLDER: hand-written estimate, not the benchmark output.
+	// Must be regenerated with the benchmark CLI before merging.
 	// Storage: Instance1Referendum Votes (r:1 w:1)
 	// Proof: Instance1Referendum Votes (max_values: None, max_size: Some(113), added: 2588, mode: MaxEncodedLen)
 	// Storage: Council AnnouncementPeriodNr (r:1 w:0)
 	// Proof: Council AnnouncementPeriodNr (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	// Storage: Instance1Referendum VoteStakeLockedSince (r:0 w:1)
+	// Proof: Instance1Referendum VoteStakeLockedSince (max_values: None, max_size: Some(56), added: 2531, mode: MaxEncodedLen)
 	// Storage: Balances Locks (r:1 w:1)
 	// Proof: Balances Locks (max_values: None, max_size: Some(1299), added: 3774, mode: MaxEncodedLen)
 	// Storage: System Account (r:1 w:1)
 	// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
 	fn release_vote_stake() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `783`
-		//  Estimated: `13428`
-		// Minimum execution time: 41_013 nanoseconds.
 		Weight::from_parts(42_434_000, 0u64)
 			.saturating_add(Weight::from_parts(0, 13428))
 			.saturating_add(T::DbWeight::get().reads(4_u64))
-			.saturating_add(T::DbWeight::get().writes(3_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
 	}
 	// Storage: Instance1Referendum AccountsOptedOut (r:1 w:1)
 	// Proof: Instance1Referendum AccountsOptedOut (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
@@ -223,6 +236,133 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(1_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
+	// PLACEHOLDER: hand-written estimate, not the benchmark output.
+	// Must be regenerated with the benchmark CLI before merging.
+	// Storage: Instance1Referendum NextVotePowerStrategy (r:0 w:1)
+	// Proof: Instance1Referendum NextVotePowerStrategy (max_values: Some(1), max_size: Some(10), added: 505, mode: MaxEncodedLen)
+	fn set_vote_power_strategy() -> Weight {
+		Weight::from_parts(9_784_000, 0u64)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	// PLACEHOLDER: hand-written estimate, not the benchmark output.
+	// Must be regenerated with the benchmark CLI before merging.
+	// Storage: Instance1Referendum Stage (r:1 w:0)
+	// Proof: Instance1Referendum Stage (max_values: Some(1), max_size: Some(94), added: 589, mode: MaxEncodedLen)
+	// Storage: Instance1Referendum Votes (r:1 w:1)
+	// Proof: Instance1Referendum Votes (max_values: None, max_size: Some(113), added: 2588, mode: MaxEncodedLen)
+	// Storage: Council Candidates (r:5 w:0)
+	// Proof: Council Candidates (max_values: None, max_size: Some(161), added: 2636, mode: MaxEncodedLen)
+	// Storage: Instance1Referendum RankedBallotCount (r:1 w:1)
+	// Proof: Instance1Referendum RankedBallotCount (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	// Storage: Instance1Referendum VotePowerStrategyInUse (r:1 w:0)
+	// Proof: Instance1Referendum VotePowerStrategyInUse (max_values: Some(1), max_size: Some(10), added: 505, mode: MaxEncodedLen)
+	// Storage: Instance1Referendum VoteStakeLockedSince (r:1 w:0)
+	// Proof: Instance1Referendum VoteStakeLockedSince (max_values: None, max_size: Some(56), added: 2531, mode: MaxEncodedLen)
+	// Storage: Instance1Referendum RankedBallots (r:0 w:1)
+	// Proof: Instance1Referendum RankedBallots (max_values: None, max_size: Some(105), added: 2580, mode: MaxEncodedLen)
+	/// The range of component `p` is `[1, 5]`.
+	fn reveal_ranked_vote(p: u32, ) -> Weight {
+		Weight::from_parts(38_514_322, 0u64)
+			.saturating_add(Weight::from_parts(0, 11396))
+			.saturating_add(Weight::from_parts(4_388_905, 0u64).saturating_mul(p.into()))
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(p.into())))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+			.saturating_add(Weight::from_parts(0, 2636).saturating_mul(p.into()))
+	}
+	// PLACEHOLDER: hand-written estimate, not the benchmark output.
+	// Must be regenerated with the benchmark CLI before merging.
+	// Storage: Instance1Referendum Stage (r:1 w:1)
+	// Proof: Instance1Referendum Stage (max_values: Some(1), max_size: Some(94), added: 589, mode: MaxEncodedLen)
+	// Storage: Instance1Referendum RankedBallotCount (r:1 w:1)
+	// Proof: Instance1Referendum RankedBallotCount (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	// Storage: Instance1Referendum RankedBallots (r:50 w:50)
+	// Proof: Instance1Referendum RankedBallots (max_values: None, max_size: Some(105), added: 2580, mode: MaxEncodedLen)
+	// Storage: Council Candidates (r:5 w:0)
+	// Proof: Council Candidates (max_values: None, max_size: Some(161), added: 2636, mode: MaxEncodedLen)
+	// Storage: Council AnnouncementPeriodNr (r:1 w:1)
+	// Proof: Council AnnouncementPeriodNr (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	// Storage: Council Stage (r:0 w:1)
+	// Proof: Council Stage (max_values: Some(1), max_size: Some(13), added: 508, mode: MaxEncodedLen)
+	/// The range of component `b` is `[1, 50]`.
+	/// The range of component `p` is `[1, 5]`.
+	fn on_initialize_revealing_ranked(b: u32, p: u32, ) -> Weight {
+		Weight::from_parts(24_817_449, 0u64)
+			.saturating_add(Weight::from_parts(0, 5093))
+			.saturating_add(Weight::from_parts(3_906_118, 0u64).saturating_mul(b.into()))
+			.saturating_add(Weight::from_parts(2_214_583, 0u64).saturating_mul(p.into()))
+			.saturating_add(Weight::from_parts(412_037, 0u64).saturating_mul(b.saturating_mul(p).into()))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(b.into())))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(p.into())))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(b.into())))
+			.saturating_add(Weight::from_parts(0, 2580).saturating_mul(b.into()))
+			.saturating_add(Weight::from_parts(0, 2636).saturating_mul(p.into()))
+	}
+	// PLACEHOLDER: hand-written estimate, not the benchmark output.
+	// Must be regenerated with the benchmark CLI before merging.
+	// Storage: Instance1Referendum AccountsOptedOut (r:1 w:0)
+	// Proof: Instance1Referendum AccountsOptedOut (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	// Storage: Instance1Referendum Stage (r:1 w:0)
+	// Proof: Instance1Referendum Stage (max_values: Some(1), max_size: Some(94), added: 589, mode: MaxEncodedLen)
+	// Storage: Balances Locks (r:1 w:1)
+	// Proof: Balances Locks (max_values: None, max_size: Some(1299), added: 3774, mode: MaxEncodedLen)
+	// Storage: System Account (r:1 w:1)
+	// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	// Storage: Instance1Referendum Delegators (r:1 w:2)
+	// Proof: Instance1Referendum Delegators (max_values: None, max_size: Some(96), added: 2571, mode: MaxEncodedLen)
+	// Storage: Instance1Referendum DelegatorCount (r:2 w:2)
+	// Proof: Instance1Referendum DelegatorCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	// Storage: Instance1Referendum Delegations (r:1 w:1)
+	// Proof: Instance1Referendum Delegations (max_values: None, max_size: Some(104), added: 2579, mode: MaxEncodedLen)
+	// Storage: Instance1Referendum Votes (r:1 w:0)
+	// Proof: Instance1Referendum Votes (max_values: None, max_size: Some(113), added: 2588, mode: MaxEncodedLen)
+	fn delegate_vote() -> Weight {
+		Weight::from_parts(50_381_000, 0u64)
+			.saturating_add(Weight::from_parts(0, 30201))
+			.saturating_add(T::DbWeight::get().reads(9_u64))
+			.saturating_add(T::DbWeight::get().writes(7_u64))
+	}
+	// PLACEHOLDER: hand-written estimate, not the benchmark output.
+	// Must be regenerated with the benchmark CLI before merging.
+	// Storage: Instance1Referendum Delegations (r:1 w:1)
+	// Proof: Instance1Referendum Delegations (max_values: None, max_size: Some(104), added: 2579, mode: MaxEncodedLen)
+	// Storage: Instance1Referendum DelegatorCount (r:1 w:1)
+	// Proof: Instance1Referendum DelegatorCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	// Storage: Instance1Referendum Votes (r:1 w:0)
+	// Proof: Instance1Referendum Votes (max_values: None, max_size: Some(113), added: 2588, mode: MaxEncodedLen)
+	// Storage: Balances Locks (r:1 w:1)
+	// Proof: Balances Locks (max_values: None, max_size: Some(1299), added: 3774, mode: MaxEncodedLen)
+	// Storage: System Account (r:1 w:1)
+	// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	// Storage: Instance1Referendum Delegators (r:0 w:1)
+	// Proof: Instance1Referendum Delegators (max_values: None, max_size: Some(96), added: 2571, mode: MaxEncodedLen)
+	fn revoke_vote_delegation() -> Weight {
+		Weight::from_parts(39_115_000, 0u64)
+			.saturating_add(Weight::from_parts(0, 19051))
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
+	}
+	// PLACEHOLDER: hand-written estimate, not the benchmark output.
+	// Must be regenerated with the benchmark CLI before merging.
+	// Storage: Instance1Referendum Delegators (r:101 w:0)
+	// Proof: Instance1Referendum Delegators (max_values: None, max_size: Some(96), added: 2571, mode: MaxEncodedLen)
+	// Storage: Instance1Referendum Votes (r:100 w:0)
+	// Proof: Instance1Referendum Votes (max_values: None, max_size: Some(113), added: 2588, mode: MaxEncodedLen)
+	// Storage: Instance1Referendum Delegations (r:100 w:0)
+	// Proof: Instance1Referendum Delegations (max_values: None, max_size: Some(104), added: 2579, mode: MaxEncodedLen)
+	// Storage: Instance1Referendum VotePowerStrategyInUse (r:1 w:0)
+	// Proof: Instance1Referendum VotePowerStrategyInUse (max_values: Some(1), max_size: Some(10), added: 505, mode: MaxEncodedLen)
+	/// The range of component `d` is `[1, 100]`.
+	fn delegated_vote_stake(d: u32, ) -> Weight {
+		Weight::from_parts(12_904_117, 0u64)
+			.saturating_add(Weight::from_parts(0, 3066))
+			.saturating_add(Weight::from_parts(9_318_752, 0u64).saturating_mul(d.into()))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().reads((3_u64).saturating_mul(d.into())))
+			.saturating_add(Weight::from_parts(0, 7738).saturating_mul(d.into()))
+	}
 }
 
 // Default implementation for tests
@@ -254,4 +394,22 @@ impl WeightInfo for () {
 	fn opt_out_of_voting() -> Weight {
 		Weight::from_parts(0, 0)
 	}
+	fn set_vote_power_strategy() -> Weight {
+		Weight::from_parts(0, 0)
+	}
+	fn reveal_ranked_vote(p: u32, ) -> Weight {
+		Weight::from_parts(0, 0)
+	}
+	fn on_initialize_revealing_ranked(b: u32, p: u32, ) -> Weight {
+		Weight::from_parts(0, 0)
+	}
+	fn delegate_vote() -> Weight {
+		Weight::from_parts(0, 0)
+	}
+	fn revoke_vote_delegation() -> Weight {
+		Weight::from_parts(0, 0)
+	}
+	fn delegated_vote_stake(d: u32, ) -> Weight {
+		Weight::from_parts(0, 0)
+	}
 }
@@ -76,6 +76,14 @@ pub trait StakingHandler<AccountId, Balance, MemberId, LockIdentifier> {
 
     /// Returns the current stake on the account.
     fn current_stake(account_id: &AccountId) -> Balance;
+
+    /// Verifies that the stake locked with the previous lock identifier can be moved to the
+    /// lock identifier of this handler: no other stakes conflict with the new lock.
+    fn can_replace_lock(account_id: &AccountId, previous_lock_id: &LockIdentifier) -> bool;
+
+    /// Moves the stake locked with the previous lock identifier to the lock identifier of this
+    /// handler. The stake remains locked during the operation. Returns the moved stake.
+    fn replace_lock(account_id: &AccountId, previous_lock_id: &LockIdentifier) -> Balance;
 }
 
 /// Implementation of the StakingHandler.
@@ -207,4 +215,38 @@ impl<
 
         existing_lock.map_or(Zero::zero(), |lock| lock.amount)
     }
+
+    fn can_replace_lock(
+        account_id: &<T as frame_system::Config>::AccountId,
+        previous_lock_id: &LockIdentifier,
+    ) -> bool {
+        let locks = <pallet_balances::Pallet<T>>::locks(account_id);
+        let lock_ids: Vec<LockIdentifier> = locks
+            .iter()
+            .map(|balance_lock| balance_lock.id)
+            .filter(|lock_id| lock_id != previous_lock_id)
+            .collect();
+
+        !T::are_locks_conflicting(&LockId::get(), lock_ids.as_slice())
+    }
+
+    fn replace_lock(
+        account_id: &<T as frame_system::Config>::AccountId,
+        previous_lock_id: &LockIdentifier,
+    ) -> <T as pallet_balances::Config>::Balance {
+        let locks = <pallet_balances::Pallet<T>>::locks(account_id);
+
+        let previous_lock = locks.iter().find(|lock| lock.id == *previous_lock_id);
+
+        let mut replaced_stake = Zero::zero();
+        if let Some(previous_lock) = previous_lock {
+            // Lock first: the stake must not become withdrawable in between.
+            Self::lock(account_id, previous_lock.amount);
+            <pallet_balances::Pallet<T>>::remove_lock(*previous_lock_id, account_id);
+
+            replaced_stake = previous_lock.amount;
+        }
+
+        replaced_stake
+    }
 }
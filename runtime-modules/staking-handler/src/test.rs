@@ -234,3 +234,51 @@ fn set_stake_succeeds_with_two_stakes() {
         assert!(TestStakingManager2::set_stake(&account_id, stake2).is_ok());
     });
 }
+
+#[test]
+fn can_replace_lock_succeeds() {
+    build_test_externalities().execute_with(|| {
+        let account_id = 1;
+        let total_amount = 300;
+        let stake = 100;
+
+        increase_total_balance_issuance_using_account_id(account_id, total_amount);
+
+        TestStakingManager::lock(&account_id, stake);
+        TestStakingManager2::lock(&account_id, stake);
+
+        assert!(!TestStakingManager2::is_account_free_of_conflicting_stakes(
+            &account_id
+        ));
+        assert!(TestStakingManager2::can_replace_lock(
+            &account_id,
+            &LockId2::get()
+        ));
+        assert!(!TestStakingManager::can_replace_lock(
+            &account_id,
+            &LockId2::get()
+        ));
+    });
+}
+
+#[test]
+fn replace_lock_succeeds() {
+    build_test_externalities().execute_with(|| {
+        let account_id = 1;
+        let total_amount = 300;
+        let stake = 100;
+
+        increase_total_balance_issuance_using_account_id(account_id, total_amount);
+
+        TestStakingManager::lock(&account_id, stake);
+
+        assert_eq!(
+            TestStakingManager2::replace_lock(&account_id, &LockId::get()),
+            stake
+        );
+
+        assert_eq!(TestStakingManager::current_stake(&account_id), 0);
+        assert_eq!(TestStakingManager2::current_stake(&account_id), stake);
+        assert_eq!(Balances::usable_balance(&account_id), total_amount - stake);
+    });
+}
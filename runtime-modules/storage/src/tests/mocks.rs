@@ -211,8 +211,10 @@ impl working_group::Config<StorageWorkingGroupInstance> for Test {
     type MemberOriginValidator = ();
     type MinUnstakingPeriodLimit = ();
     type RewardPeriod = ();
+    type MaxRewardPayoutsPerBlock = ();
     type WeightInfo = ();
     type MinimumApplicationStake = MinimumApplicationStake;
+    type MaxApplicationEndorsements = ConstU32<10>;
     type LeaderOpeningStake = LeaderOpeningStake;
     type VestingBalanceToBalance = ();
 }
@@ -226,8 +228,10 @@ impl working_group::Config<DistributionWorkingGroupInstance> for Test {
     type MemberOriginValidator = ();
     type MinUnstakingPeriodLimit = ();
     type RewardPeriod = ();
+    type MaxRewardPayoutsPerBlock = ();
     type WeightInfo = ();
     type MinimumApplicationStake = MinimumApplicationStake;
+    type MaxApplicationEndorsements = ConstU32<10>;
     type LeaderOpeningStake = LeaderOpeningStake;
     type VestingBalanceToBalance = ();
 }
@@ -350,6 +354,10 @@ impl membership::Config for Test {
         staking_handler::StakingManager<Self, BoundStakingAccountLockId>;
     type CandidateStake = CandidateStake;
     type DefaultMemberInvitesCount = DefaultMemberInvitesCount;
+    type HandleReclaimCooldown = HandleReclaimCooldown;
+    type MaxRecoveryGuardians = MaxRecoveryGuardians;
+    type RecoveryDelay = RecoveryDelay;
+    type RecoveryCooldown = RecoveryCooldown;
 }
 
 parameter_types! {
@@ -359,6 +367,10 @@ parameter_types! {
     pub const DefaultMembershipPrice: u64 = 100;
     pub const DefaultInitialInvitationBalance: u64 = 100;
     pub const DefaultMemberInvitesCount: u32 = 2;
+    pub const HandleReclaimCooldown: u64 = 1000;
+    pub const MaxRecoveryGuardians: u32 = 5;
+    pub const RecoveryDelay: u64 = 100;
+    pub const RecoveryCooldown: u64 = 50;
 }
 
 pub fn build_test_externalities() -> sp_io::TestExternalities {
@@ -2,6 +2,7 @@
 
 use super::*;
 use frame_benchmarking::v1::{account, benchmarks};
+use frame_support::traits::{LockableCurrency, WithdrawReasons};
 use frame_system::Pallet as System;
 use frame_system::{EventRecord, RawOrigin};
 use sp_runtime::traits::One;
@@ -59,6 +60,36 @@ fn assert_new_budgets<T: Config>(
     );
 }
 
+// Adds the staked worker to the working group. Returns the worker id and the staking account.
+fn add_worker<T: Config>(group: WorkingGroup) -> (ActorId<T>, T::AccountId) {
+    let account_id = account::<T::AccountId>("worker", 0, 0);
+    let stake: BalanceOf<T> = 100_000u32.into();
+    let initial_lock_id = *b"transfer";
+
+    let _ = Balances::<T>::make_free_balance_be(
+        &account_id,
+        <T as balances::Config>::ExistentialDeposit::get() + stake,
+    );
+    Balances::<T>::set_lock(initial_lock_id, &account_id, stake, WithdrawReasons::all());
+
+    let worker_id = T::transfer_working_group_worker_in(
+        group,
+        TransferredWorker {
+            member_id: Default::default(),
+            role_account_id: account_id.clone(),
+            staking_account_id: account_id.clone(),
+            reward_account_id: account_id.clone(),
+            job_unstaking_period: One::one(),
+            reward_per_block: Some(One::one()),
+            created_at: Zero::zero(),
+            lock_id: initial_lock_id,
+        },
+        WorkingGroup::Forum,
+    );
+
+    (worker_id, account_id)
+}
+
 const MAX_KILOBYTES_METADATA: u32 = 100;
 
 benchmarks! {
@@ -77,10 +108,35 @@ benchmarks! {
         RawOrigin::Root,
         WorkingGroup::Forum,
         One::one(),
-        BalanceKind::Positive
+        BalanceKind::Positive,
+        None
+    )
+    verify {
+        assert_new_budgets::<T>(99, 101, WorkingGroup::Forum, 1, BalanceKind::Positive);
+    }
+
+    update_working_group_budget_positive_with_allocation {
+        set_wg_and_council_budget::<T>(100, WorkingGroup::Forum); // All groups are similar
+        Council::<T>::create_budget_allocation(
+            RawOrigin::Root.into(),
+            Vec::new(),
+            One::one(),
+            frame_system::Pallet::<T>::block_number() + One::one(),
+        ).unwrap();
+    }: update_working_group_budget(
+        RawOrigin::Root,
+        WorkingGroup::Forum,
+        One::one(),
+        BalanceKind::Positive,
+        Some(0)
     )
     verify {
         assert_new_budgets::<T>(99, 101, WorkingGroup::Forum, 1, BalanceKind::Positive);
+        assert_eq!(
+            Council::<T>::budget_allocations(0).map(|allocation| allocation.spent),
+            Some(One::one()),
+            "Allocation not drawn"
+        );
     }
 
     update_working_group_budget_negative {
@@ -89,7 +145,8 @@ benchmarks! {
         RawOrigin::Root,
         WorkingGroup::Forum,
         One::one(),
-        BalanceKind::Negative
+        BalanceKind::Negative,
+        None
     )
     verify{
         assert_new_budgets::<T>(101, 99, WorkingGroup::Forum, 1, BalanceKind::Negative);
@@ -110,6 +167,18 @@ benchmarks! {
         assert_eq!(Balances::<T>::total_issuance(),  initial_issuance);
         assert_last_event::<T>(RawEvent::TokensBurned(account_id, initial_balance).into());
     }
+
+    transfer_worker {
+        // All groups are similar
+        let (worker_id, account_id) = add_worker::<T>(WorkingGroup::Storage);
+    }: _ (RawOrigin::Root, WorkingGroup::Storage, worker_id, WorkingGroup::Distribution)
+    verify {
+        assert!(
+            T::ensure_working_group_worker_can_be_transferred(WorkingGroup::Storage, &worker_id)
+                .is_err()
+        );
+        assert_eq!(Balances::<T>::locks(&account_id).len(), 1);
+    }
 }
 
 #[cfg(test)]
@@ -132,6 +201,15 @@ mod tests {
         });
     }
 
+    #[test]
+    fn test_update_working_group_budget_positive_with_allocation() {
+        initial_test_ext().execute_with(|| {
+            assert_ok!(
+                Utility::test_benchmark_update_working_group_budget_positive_with_allocation()
+            );
+        });
+    }
+
     #[test]
     fn test_update_working_group_budget_negative() {
         initial_test_ext().execute_with(|| {
@@ -145,4 +223,11 @@ mod tests {
             assert_ok!(Utility::test_benchmark_burn_account_tokens());
         });
     }
+
+    #[test]
+    fn test_transfer_worker() {
+        initial_test_ext().execute_with(|| {
+            assert_ok!(Utility::test_benchmark_transfer_worker());
+        });
+    }
 }
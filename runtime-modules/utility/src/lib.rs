@@ -9,12 +9,15 @@
 //! runtime code
 //! - [execute_signal_proposal](./struct.Module.html#method.execute_signal_proposal) - prints the proposal to the log
 //! - [update_working_group_budget](./struct.Module.html#method.update_working_group_budget) - Move funds between
-//! council and working group
+//! council and working group, optionally drawing against the earmarked council budget allocation
 //! - [burn_account_tokens](./struct.Module.html#method.burn_account_tokens) - Burns token from account
+//! - [transfer_worker](./struct.Module.html#method.transfer_worker) - Transfers the worker between
+//! working groups without unstaking
 //!
 //! ## Dependencies
 //! - [council](../substrate_council_module/index.html)
 //! - [common](../substrate_common_module/index.html)
+//! - [working_group](../working_group/index.html) (implements the worker transfer in the runtime)
 
 // Ensure we're `no_std` when compiling for Wasm.
 #![cfg_attr(not(feature = "std"), no_std)]
@@ -43,10 +46,14 @@ mod benchmarking;
 pub mod weights;
 pub use weights::WeightInfo;
 
+use common::council::{BudgetAllocationId, CouncilBudgetAllocations};
 use common::to_kb;
-use common::{working_group::WorkingGroup, BalanceKind};
+use common::working_group::{TransferredWorker, WorkingGroup};
+use common::{ActorId, BalanceKind, MemberId};
 use council::Module as Council;
-use frame_support::dispatch::{DispatchClass, DispatchResultWithPostInfo};
+use frame_support::dispatch::{
+    DispatchClass, DispatchError, DispatchResult, DispatchResultWithPostInfo,
+};
 use frame_support::traits::Get;
 use frame_support::traits::{Currency, Imbalance};
 use frame_support::weights::Weight;
@@ -61,6 +68,14 @@ use sp_std::{vec, vec::Vec};
 type BalanceOf<T> = <T as balances::Config>::Balance;
 type Balances<T> = balances::Pallet<T>;
 
+/// Alias for the worker transferred between the working groups.
+pub type TransferredWorkerOf<T> = TransferredWorker<
+    <T as frame_system::Config>::AccountId,
+    MemberId<T>,
+    <T as frame_system::Config>::BlockNumber,
+    BalanceOf<T>,
+>;
+
 pub trait Config: frame_system::Config + balances::Config + council::Config {
     type RuntimeEvent: From<Event<Self>> + Into<<Self as frame_system::Config>::RuntimeEvent>;
 
@@ -70,6 +85,46 @@ pub trait Config: frame_system::Config + balances::Config + council::Config {
     /// Sets the budget for the given WorkingGroup
     fn set_working_group_budget(working_group: WorkingGroup, budget: BalanceOf<Self>);
 
+    /// Verifies that the account belongs to the leader of the given WorkingGroup
+    fn is_working_group_leader_account_id(
+        working_group: WorkingGroup,
+        account_id: &Self::AccountId,
+    ) -> bool;
+
+    /// Verifies that the leader of the given WorkingGroup approved the worker transfer
+    /// to the destination WorkingGroup
+    fn is_working_group_worker_transfer_approved(
+        working_group: WorkingGroup,
+        worker_id: &ActorId<Self>,
+        destination: WorkingGroup,
+    ) -> bool;
+
+    /// Verifies that the worker can be transferred from the given WorkingGroup
+    fn ensure_working_group_worker_can_be_transferred(
+        working_group: WorkingGroup,
+        worker_id: &ActorId<Self>,
+    ) -> Result<TransferredWorkerOf<Self>, DispatchError>;
+
+    /// Verifies that the given WorkingGroup can accept the transferred worker
+    fn ensure_working_group_can_accept_worker(
+        working_group: WorkingGroup,
+        worker: &TransferredWorkerOf<Self>,
+    ) -> DispatchResult;
+
+    /// Removes the worker transferred to the destination WorkingGroup from the given WorkingGroup
+    fn transfer_working_group_worker_out(
+        working_group: WorkingGroup,
+        worker_id: &ActorId<Self>,
+        destination: WorkingGroup,
+    );
+
+    /// Adds the worker transferred from the source WorkingGroup to the given WorkingGroup
+    fn transfer_working_group_worker_in(
+        working_group: WorkingGroup,
+        worker: TransferredWorkerOf<Self>,
+        source: WorkingGroup,
+    ) -> ActorId<Self>;
+
     /// Weight information for extrinsics in this pallet.
     type WeightInfo: WeightInfo;
 }
@@ -87,6 +142,18 @@ decl_error! {
 
         /// Insufficient funds for burning
         InsufficientFundsForBurn,
+
+        /// Worker cannot be transferred within the same working group
+        CannotTransferWorkerWithinWorkingGroup,
+
+        /// Origin is not the leader of the destination working group
+        OriginIsNotDestinationWorkingGroupLead,
+
+        /// Worker transfer was not approved by the source working group leader
+        WorkerTransferNotApproved,
+
+        /// Only the funds moved from the council budget can be drawn against the budget allocation
+        BudgetAllocationForNegativeBudgetUpdate,
     }
 }
 
@@ -95,6 +162,7 @@ decl_event!(
     where
         Balance = BalanceOf<T>,
         AccountId = <T as frame_system::Config>::AccountId,
+        ActorId = ActorId<T>,
     {
         /// A signal proposal was executed
         /// Params:
@@ -119,6 +187,14 @@ decl_event!(
         /// - Account Id of the burning tokens
         /// - Balance burned from that account
         TokensBurned(AccountId, Balance),
+
+        /// A worker was transferred between the working groups
+        /// Params:
+        /// - Source working group
+        /// - Worker id in the source working group
+        /// - Destination working group
+        /// - Worker id in the destination working group
+        WorkerTransferred(WorkingGroup, ActorId, WorkingGroup, ActorId),
     }
 );
 
@@ -182,7 +258,8 @@ decl_module! {
             Ok(post_info)
         }
 
-        /// Update working group budget
+        /// Update working group budget. Funds moved from the council budget can be drawn against
+        /// the earmarked council budget allocation.
         /// <weight>
         ///
         /// ## Weight
@@ -190,12 +267,13 @@ decl_module! {
         /// - DB:
         ///    - O(1) doesn't depend on the state or parameters
         /// # </weight>
-        #[weight = Module::<T>::get_update_working_group_budget_weight(balance_kind)]
+        #[weight = Module::<T>::get_update_working_group_budget_weight(balance_kind, allocation_id)]
         pub fn update_working_group_budget(
             origin,
             working_group: WorkingGroup,
             amount: BalanceOf<T>,
             balance_kind: BalanceKind,
+            allocation_id: Option<BudgetAllocationId>,
         ) {
             ensure_root(origin.clone())?;
 
@@ -204,12 +282,31 @@ decl_module! {
 
             match balance_kind {
                 BalanceKind::Positive => {
-                    ensure!(amount<=current_budget, Error::<T>::InsufficientFundsForBudgetUpdate);
+                    // Only the update drawn against the allocation can spend the earmarked budget.
+                    let spendable_budget = if allocation_id.is_some() {
+                        current_budget
+                    } else {
+                        Council::<T>::unearmarked_budget()
+                    };
+
+                    ensure!(amount<=spendable_budget, Error::<T>::InsufficientFundsForBudgetUpdate);
+
+                    if let Some(allocation_id) = allocation_id {
+                        Council::<T>::ensure_can_draw_from_allocation(allocation_id, amount)?;
+                    }
 
                     T::set_working_group_budget(working_group, wg_budget.saturating_add(amount));
                     Council::<T>::set_budget(origin, current_budget - amount)?;
+
+                    if let Some(allocation_id) = allocation_id {
+                        Council::<T>::draw_from_allocation(allocation_id, amount);
+                    }
                 },
                 BalanceKind::Negative => {
+                    ensure!(
+                        allocation_id.is_none(),
+                        Error::<T>::BudgetAllocationForNegativeBudgetUpdate
+                    );
                     ensure!(amount <= wg_budget, Error::<T>::InsufficientFundsForBudgetUpdate);
 
                     T::set_working_group_budget(working_group, wg_budget - amount);
@@ -247,13 +344,73 @@ decl_module! {
             Self::deposit_event(RawEvent::TokensBurned(account_id, actually_burned.peek()));
         }
 
+        /// Transfers the worker between the working groups without unstaking: the stake lock of
+        /// the source group is replaced with the lock of the destination group and the worker
+        /// tenure is kept.
+        /// Require root origin (council proposal) or the signed origin of the destination group
+        /// leader with the transfer approved by the source group leader.
+        /// <weight>
+        ///
+        /// ## Weight
+        /// `O (1)` Doesn't depend on the state or parameters
+        /// - DB:
+        ///    - O(1) doesn't depend on the state or parameters
+        /// # </weight>
+        #[weight = WeightInfoUtilities::<T>::transfer_worker()]
+        pub fn transfer_worker(
+            origin,
+            source: WorkingGroup,
+            worker_id: ActorId<T>,
+            destination: WorkingGroup,
+        ) {
+            if ensure_root(origin.clone()).is_err() {
+                let account_id = ensure_signed(origin)?;
+
+                ensure!(
+                    T::is_working_group_leader_account_id(destination, &account_id),
+                    Error::<T>::OriginIsNotDestinationWorkingGroupLead
+                );
+
+                ensure!(
+                    T::is_working_group_worker_transfer_approved(source, &worker_id, destination),
+                    Error::<T>::WorkerTransferNotApproved
+                );
+            }
+
+            ensure!(source != destination, Error::<T>::CannotTransferWorkerWithinWorkingGroup);
+
+            let worker = T::ensure_working_group_worker_can_be_transferred(source, &worker_id)?;
+
+            T::ensure_working_group_can_accept_worker(destination, &worker)?;
+
+            // == Mutation Safe == //
+
+            T::transfer_working_group_worker_out(source, &worker_id, destination);
+
+            let new_worker_id = T::transfer_working_group_worker_in(destination, worker, source);
+
+            Self::deposit_event(RawEvent::WorkerTransferred(
+                source,
+                worker_id,
+                destination,
+                new_worker_id,
+            ));
+        }
+
     }
 }
 
 impl<T: Config> Module<T> {
     // Returns the weigt for update_working_group_budget extrinsic according to parameters
-    fn get_update_working_group_budget_weight(balance_kind: &BalanceKind) -> Weight {
+    fn get_update_working_group_budget_weight(
+        balance_kind: &BalanceKind,
+        allocation_id: &Option<BudgetAllocationId>,
+    ) -> Weight {
         match balance_kind {
+            BalanceKind::Positive if allocation_id.is_some() => {
+                WeightInfoUtilities::<T>::update_working_group_budget_positive_with_allocation()
+            }
+
             BalanceKind::Positive => {
                 WeightInfoUtilities::<T>::update_working_group_budget_positive()
             }
@@ -3,7 +3,10 @@
 use crate as utility;
 pub(crate) use crate::Module as Utilities;
 use crate::*;
-use common::working_group::{WorkingGroup, WorkingGroupBudgetHandler};
+use common::working_group::{
+    WorkingGroup, WorkingGroupAuthenticator, WorkingGroupBudgetHandler,
+    WorkingGroupWorkerTransferHandler,
+};
 use frame_support::{
     dispatch::DispatchError,
     parameter_types,
@@ -11,6 +14,7 @@ use frame_support::{
         ConstU16, ConstU32, ConstU64, EitherOfDiverse, LockIdentifier, OnFinalize, OnInitialize,
         WithdrawReasons,
     },
+    StorageMap, StorageValue,
 };
 use frame_system::{EnsureRoot, EnsureSigned, EventRecord, RawOrigin};
 use sp_core::H256;
@@ -21,7 +25,7 @@ use sp_runtime::{
 };
 use sp_std::convert::{TryFrom, TryInto};
 
-use staking_handler::{LockComparator, StakingManager};
+use staking_handler::{LockComparator, StakingHandler, StakingManager};
 
 pub(crate) fn assert_last_event(generic_event: <Test as Config>::RuntimeEvent) {
     let events = System::events();
@@ -37,30 +41,33 @@ pub(crate) fn assert_last_event(generic_event: <Test as Config>::RuntimeEvent) {
 
 macro_rules! call_wg {
     ($working_group:ident<$T:ty>, $function:ident $(,$x:expr)*) => {{
+        call_wg!($working_group<$T>, WorkingGroupBudgetHandler<u64, u64>, $function $(,$x)*)
+    }};
+    ($working_group:ident<$T:ty>, $handler:path, $function:ident $(,$x:expr)*) => {{
         match $working_group {
             WorkingGroup::Content =>
-                <working_group::Module::<$T, ContentWorkingGroupInstance> as WorkingGroupBudgetHandler<u64, u64>>::$function($($x,)*),
+                <working_group::Module::<$T, ContentWorkingGroupInstance> as $handler>::$function($($x,)*),
 
             WorkingGroup::Storage =>
-                <working_group::Module::<$T, StorageWorkingGroupInstance> as WorkingGroupBudgetHandler<u64, u64>>::$function($($x,)*),
+                <working_group::Module::<$T, StorageWorkingGroupInstance> as $handler>::$function($($x,)*),
 
             WorkingGroup::Forum =>
-                <working_group::Module::<$T, ForumWorkingGroupInstance> as WorkingGroupBudgetHandler<u64, u64>>::$function($($x,)*),
+                <working_group::Module::<$T, ForumWorkingGroupInstance> as $handler>::$function($($x,)*),
 
             WorkingGroup::Membership =>
-                <working_group::Module::<$T, MembershipWorkingGroupInstance> as WorkingGroupBudgetHandler<u64, u64>>::$function($($x,)*),
+                <working_group::Module::<$T, MembershipWorkingGroupInstance> as $handler>::$function($($x,)*),
 
             WorkingGroup::App =>
-                <working_group::Module::<$T, AppWorkingGroupInstance> as WorkingGroupBudgetHandler<u64, u64>>::$function($($x,)*),
+                <working_group::Module::<$T, AppWorkingGroupInstance> as $handler>::$function($($x,)*),
 
             WorkingGroup::Distribution =>
-                <working_group::Module::<$T, DistributionWorkingGroupInstance> as WorkingGroupBudgetHandler<u64, u64>>::$function($($x,)*),
+                <working_group::Module::<$T, DistributionWorkingGroupInstance> as $handler>::$function($($x,)*),
             WorkingGroup::OperationsAlpha =>
-                <working_group::Module::<$T, OperationsWorkingGroupInstanceAlpha> as WorkingGroupBudgetHandler<u64, u64>>::$function($($x,)*),
+                <working_group::Module::<$T, OperationsWorkingGroupInstanceAlpha> as $handler>::$function($($x,)*),
             WorkingGroup::OperationsBeta =>
-                <working_group::Module::<$T, OperationsWorkingGroupInstanceAlpha> as WorkingGroupBudgetHandler<u64, u64>>::$function($($x,)*),
+                <working_group::Module::<$T, OperationsWorkingGroupInstanceBeta> as $handler>::$function($($x,)*),
             WorkingGroup::OperationsGamma =>
-                <working_group::Module::<$T, OperationsWorkingGroupInstanceAlpha> as WorkingGroupBudgetHandler<u64, u64>>::$function($($x,)*),
+                <working_group::Module::<$T, OperationsWorkingGroupInstanceGamma> as $handler>::$function($($x,)*),
         }
     }};
 }
@@ -191,11 +198,90 @@ impl Config for Test {
     fn set_working_group_budget(working_group: WorkingGroup, budget: BalanceOf<Test>) {
         call_wg!(working_group<Test>, set_budget, budget)
     }
+
+    fn is_working_group_leader_account_id(working_group: WorkingGroup, account_id: &u64) -> bool {
+        call_wg!(
+            working_group<Test>,
+            WorkingGroupAuthenticator<Test>,
+            is_leader_account_id,
+            account_id
+        )
+    }
+
+    fn is_working_group_worker_transfer_approved(
+        working_group: WorkingGroup,
+        worker_id: &u64,
+        destination: WorkingGroup,
+    ) -> bool {
+        call_wg!(
+            working_group<Test>,
+            WorkingGroupWorkerTransferHandler<u64, u64, u64, u64, u64>,
+            is_worker_transfer_approved,
+            worker_id,
+            destination
+        )
+    }
+
+    fn ensure_working_group_worker_can_be_transferred(
+        working_group: WorkingGroup,
+        worker_id: &u64,
+    ) -> Result<TransferredWorkerOf<Test>, DispatchError> {
+        call_wg!(
+            working_group<Test>,
+            WorkingGroupWorkerTransferHandler<u64, u64, u64, u64, u64>,
+            ensure_worker_can_be_transferred,
+            worker_id
+        )
+    }
+
+    fn ensure_working_group_can_accept_worker(
+        working_group: WorkingGroup,
+        worker: &TransferredWorkerOf<Test>,
+    ) -> DispatchResult {
+        call_wg!(
+            working_group<Test>,
+            WorkingGroupWorkerTransferHandler<u64, u64, u64, u64, u64>,
+            ensure_worker_can_be_accepted,
+            worker
+        )
+    }
+
+    fn transfer_working_group_worker_out(
+        working_group: WorkingGroup,
+        worker_id: &u64,
+        destination: WorkingGroup,
+    ) {
+        call_wg!(
+            working_group<Test>,
+            WorkingGroupWorkerTransferHandler<u64, u64, u64, u64, u64>,
+            transfer_worker_out,
+            worker_id,
+            destination
+        )
+    }
+
+    fn transfer_working_group_worker_in(
+        working_group: WorkingGroup,
+        worker: TransferredWorkerOf<Test>,
+        source: WorkingGroup,
+    ) -> u64 {
+        call_wg!(
+            working_group<Test>,
+            WorkingGroupWorkerTransferHandler<u64, u64, u64, u64, u64>,
+            transfer_worker_in,
+            worker,
+            source
+        )
+    }
 }
 
 parameter_types! {
     pub const DefaultMembershipPrice: u64 = 100;
     pub const DefaultMemberInvitesCount: u32 = 2;
+    pub const HandleReclaimCooldown: u64 = 1000;
+    pub const MaxRecoveryGuardians: u32 = 5;
+    pub const RecoveryDelay: u64 = 100;
+    pub const RecoveryCooldown: u64 = 50;
     pub const InvitedMemberLockId: [u8; 8] = [2; 8];
     pub const ReferralCutMaximumPercent: u8 = 50;
     pub const StakingCandidateLockId: [u8; 8] = [3; 8];
@@ -214,6 +300,10 @@ impl membership::Config for Test {
         staking_handler::StakingManager<Self, StakingCandidateLockId>;
     type CandidateStake = CandidateStake;
     type DefaultMemberInvitesCount = DefaultMemberInvitesCount;
+    type HandleReclaimCooldown = HandleReclaimCooldown;
+    type MaxRecoveryGuardians = MaxRecoveryGuardians;
+    type RecoveryDelay = RecoveryDelay;
+    type RecoveryCooldown = RecoveryCooldown;
 }
 
 pub struct Wg;
@@ -328,8 +418,10 @@ impl working_group::Config<ForumWorkingGroupInstance> for Test {
     type MemberOriginValidator = ();
     type MinUnstakingPeriodLimit = ();
     type RewardPeriod = ();
+    type MaxRewardPayoutsPerBlock = ();
     type WeightInfo = ();
     type MinimumApplicationStake = MinimumApplicationStake;
+    type MaxApplicationEndorsements = ConstU32<10>;
     type LeaderOpeningStake = LeaderOpeningStake;
     type VestingBalanceToBalance = VestingBalanceToBalance;
 }
@@ -342,8 +434,10 @@ impl working_group::Config<StorageWorkingGroupInstance> for Test {
     type MemberOriginValidator = ();
     type MinUnstakingPeriodLimit = ();
     type RewardPeriod = ();
+    type MaxRewardPayoutsPerBlock = ();
     type WeightInfo = ();
     type MinimumApplicationStake = MinimumApplicationStake;
+    type MaxApplicationEndorsements = ConstU32<10>;
     type LeaderOpeningStake = LeaderOpeningStake;
     type VestingBalanceToBalance = VestingBalanceToBalance;
 }
@@ -356,8 +450,10 @@ impl working_group::Config<ContentWorkingGroupInstance> for Test {
     type MemberOriginValidator = ();
     type MinUnstakingPeriodLimit = ();
     type RewardPeriod = ();
+    type MaxRewardPayoutsPerBlock = ();
     type WeightInfo = ();
     type MinimumApplicationStake = MinimumApplicationStake;
+    type MaxApplicationEndorsements = ConstU32<10>;
     type LeaderOpeningStake = LeaderOpeningStake;
     type VestingBalanceToBalance = VestingBalanceToBalance;
 }
@@ -370,8 +466,10 @@ impl working_group::Config<OperationsWorkingGroupInstanceAlpha> for Test {
     type MemberOriginValidator = ();
     type MinUnstakingPeriodLimit = ();
     type RewardPeriod = ();
+    type MaxRewardPayoutsPerBlock = ();
     type WeightInfo = ();
     type MinimumApplicationStake = MinimumApplicationStake;
+    type MaxApplicationEndorsements = ConstU32<10>;
     type LeaderOpeningStake = LeaderOpeningStake;
     type VestingBalanceToBalance = VestingBalanceToBalance;
 }
@@ -384,8 +482,10 @@ impl working_group::Config<AppWorkingGroupInstance> for Test {
     type MemberOriginValidator = ();
     type MinUnstakingPeriodLimit = ();
     type RewardPeriod = ();
+    type MaxRewardPayoutsPerBlock = ();
     type WeightInfo = ();
     type MinimumApplicationStake = MinimumApplicationStake;
+    type MaxApplicationEndorsements = ConstU32<10>;
     type LeaderOpeningStake = LeaderOpeningStake;
     type VestingBalanceToBalance = VestingBalanceToBalance;
 }
@@ -398,8 +498,10 @@ impl working_group::Config<MembershipWorkingGroupInstance> for Test {
     type MemberOriginValidator = ();
     type MinUnstakingPeriodLimit = ();
     type RewardPeriod = ();
+    type MaxRewardPayoutsPerBlock = ();
     type WeightInfo = ();
     type MinimumApplicationStake = MinimumApplicationStake;
+    type MaxApplicationEndorsements = ConstU32<10>;
     type LeaderOpeningStake = LeaderOpeningStake;
     type VestingBalanceToBalance = VestingBalanceToBalance;
 }
@@ -412,8 +514,10 @@ impl working_group::Config<OperationsWorkingGroupInstanceBeta> for Test {
     type MemberOriginValidator = ();
     type MinUnstakingPeriodLimit = ();
     type RewardPeriod = ();
+    type MaxRewardPayoutsPerBlock = ();
     type WeightInfo = ();
     type MinimumApplicationStake = MinimumApplicationStake;
+    type MaxApplicationEndorsements = ConstU32<10>;
     type LeaderOpeningStake = LeaderOpeningStake;
     type VestingBalanceToBalance = VestingBalanceToBalance;
 }
@@ -426,8 +530,10 @@ impl working_group::Config<OperationsWorkingGroupInstanceGamma> for Test {
     type MemberOriginValidator = ();
     type MinUnstakingPeriodLimit = ();
     type RewardPeriod = ();
+    type MaxRewardPayoutsPerBlock = ();
     type WeightInfo = ();
     type MinimumApplicationStake = MinimumApplicationStake;
+    type MaxApplicationEndorsements = ConstU32<10>;
     type LeaderOpeningStake = LeaderOpeningStake;
     type VestingBalanceToBalance = VestingBalanceToBalance;
 }
@@ -440,8 +546,10 @@ impl working_group::Config<DistributionWorkingGroupInstance> for Test {
     type MemberOriginValidator = ();
     type MinUnstakingPeriodLimit = ();
     type RewardPeriod = ();
+    type MaxRewardPayoutsPerBlock = ();
     type WeightInfo = ();
     type MinimumApplicationStake = MinimumApplicationStake;
+    type MaxApplicationEndorsements = ConstU32<10>;
     type LeaderOpeningStake = LeaderOpeningStake;
     type VestingBalanceToBalance = VestingBalanceToBalance;
 }
@@ -463,8 +571,10 @@ impl working_group::Config<DistributionWorkingGroupInstance> for Test {
 //     type MemberOriginValidator = ();
 //     type MinUnstakingPeriodLimit = ();
 //     type RewardPeriod = ();
+//     type MaxRewardPayoutsPerBlock = ();
 //     type WeightInfo = ();
 //     type MinimumApplicationStake = MinimumApplicationStake;
+//     type MaxApplicationEndorsements = ConstU32<10>;
 //     type LeaderOpeningStake = LeaderOpeningStake;
 // }
 
@@ -476,8 +586,10 @@ impl working_group::Config<DistributionWorkingGroupInstance> for Test {
 //     type MemberOriginValidator = ();
 //     type MinUnstakingPeriodLimit = ();
 //     type RewardPeriod = ();
+//     type MaxRewardPayoutsPerBlock = ();
 //     type WeightInfo = ();
 //     type MinimumApplicationStake = MinimumApplicationStake;
+//     type MaxApplicationEndorsements = ConstU32<10>;
 //     type LeaderOpeningStake = LeaderOpeningStake;
 // }
 
@@ -489,8 +601,10 @@ impl working_group::Config<DistributionWorkingGroupInstance> for Test {
 //     type MemberOriginValidator = ();
 //     type MinUnstakingPeriodLimit = ();
 //     type RewardPeriod = ();
+//     type MaxRewardPayoutsPerBlock = ();
 //     type WeightInfo = ();
 //     type MinimumApplicationStake = MinimumApplicationStake;
+//     type MaxApplicationEndorsements = ConstU32<10>;
 //     type LeaderOpeningStake = LeaderOpeningStake;
 // }
 
@@ -502,8 +616,10 @@ impl working_group::Config<DistributionWorkingGroupInstance> for Test {
 //     type MemberOriginValidator = ();
 //     type MinUnstakingPeriodLimit = ();
 //     type RewardPeriod = ();
+//     type MaxRewardPayoutsPerBlock = ();
 //     type WeightInfo = ();
 //     type MinimumApplicationStake = MinimumApplicationStake;
+//     type MaxApplicationEndorsements = ConstU32<10>;
 //     type LeaderOpeningStake = LeaderOpeningStake;
 // }
 
@@ -515,8 +631,10 @@ impl working_group::Config<DistributionWorkingGroupInstance> for Test {
 //     type MemberOriginValidator = ();
 //     type MinUnstakingPeriodLimit = ();
 //     type RewardPeriod = ();
+//     type MaxRewardPayoutsPerBlock = ();
 //     type WeightInfo = ();
 //     type MinimumApplicationStake = MinimumApplicationStake;
+//     type MaxApplicationEndorsements = ConstU32<10>;
 //     type LeaderOpeningStake = LeaderOpeningStake;
 // }
 
@@ -528,8 +646,10 @@ impl working_group::Config<DistributionWorkingGroupInstance> for Test {
 //     type MemberOriginValidator = ();
 //     type MinUnstakingPeriodLimit = ();
 //     type RewardPeriod = ();
+//     type MaxRewardPayoutsPerBlock = ();
 //     type WeightInfo = ();
 //     type MinimumApplicationStake = MinimumApplicationStake;
+//     type MaxApplicationEndorsements = ConstU32<10>;
 //     type LeaderOpeningStake = LeaderOpeningStake;
 // }
 
@@ -541,8 +661,10 @@ impl working_group::Config<DistributionWorkingGroupInstance> for Test {
 //     type MemberOriginValidator = ();
 //     type MinUnstakingPeriodLimit = ();
 //     type RewardPeriod = ();
+//     type MaxRewardPayoutsPerBlock = ();
 //     type WeightInfo = ();
 //     type MinimumApplicationStake = MinimumApplicationStake;
+//     type MaxApplicationEndorsements = ConstU32<10>;
 //     type LeaderOpeningStake = LeaderOpeningStake;
 // }
 
@@ -554,8 +676,10 @@ impl working_group::Config<DistributionWorkingGroupInstance> for Test {
 //     type MemberOriginValidator = ();
 //     type MinUnstakingPeriodLimit = ();
 //     type RewardPeriod = ();
+//     type MaxRewardPayoutsPerBlock = ();
 //     type WeightInfo = ();
 //     type MinimumApplicationStake = MinimumApplicationStake;
+//     type MaxApplicationEndorsements = ConstU32<10>;
 //     type LeaderOpeningStake = LeaderOpeningStake;
 // }
 
@@ -567,8 +691,10 @@ impl working_group::Config<DistributionWorkingGroupInstance> for Test {
 //     type MemberOriginValidator = ();
 //     type MinUnstakingPeriodLimit = ();
 //     type RewardPeriod = ();
+//     type MaxRewardPayoutsPerBlock = ();
 //     type WeightInfo = ();
 //     type MinimumApplicationStake = MinimumApplicationStake;
+//     type MaxApplicationEndorsements = ConstU32<10>;
 //     type LeaderOpeningStake = LeaderOpeningStake;
 // }
 
@@ -609,6 +735,11 @@ impl referendum::Config<ReferendumInstance> for Test {
     type MinimumStake = MinimumVotingStake;
     type WeightInfo = ();
     type MaxWinnerTargetCount = MaxWinnerTargetCount;
+    type DefaultVotePowerStrategy = ();
+    type MaxRankedBallotPreferences = ConstU32<5>;
+    type MaxRankedBallots = ConstU32<10>;
+    type MaxVotedOptions = ConstU32<100>;
+    type MaxDelegatorsPerDelegate = ConstU32<10>;
 
     fn calculate_vote_power(
         _: &<Self as frame_system::Config>::AccountId,
@@ -722,6 +853,122 @@ impl BurnTokensFixture {
     }
 }
 
+// Adds the staked worker to the working group instance. Returns the worker id.
+pub(crate) fn add_worker<I: working_group::Instance>(account_id: u64, stake: u64) -> u64
+where
+    Test: working_group::Config<I>,
+{
+    let worker_id = working_group::Module::<Test, I>::next_worker_id();
+
+    let _ = Balances::deposit_creating(&account_id, stake);
+    <Test as working_group::Config<I>>::StakingHandler::lock(&account_id, stake);
+
+    working_group::WorkerById::<Test, I>::insert(
+        worker_id,
+        working_group::Worker::<Test>::new(
+            &account_id,
+            &account_id,
+            &account_id,
+            &account_id,
+            0,
+            None,
+            System::block_number(),
+        ),
+    );
+    working_group::NextWorkerId::<Test, I>::put(worker_id + 1);
+    working_group::ActiveWorkerCount::<I>::mutate(|count| *count += 1);
+
+    worker_id
+}
+
+// Adds the staked lead to the working group instance. Returns the lead account id.
+pub(crate) fn add_lead<I: working_group::Instance>(account_id: u64) -> u64
+where
+    Test: working_group::Config<I>,
+{
+    let lead_id = add_worker::<I>(account_id, 100);
+
+    working_group::CurrentLead::<Test, I>::put(lead_id);
+
+    account_id
+}
+
+pub struct TransferWorkerFixture {
+    origin: RawOrigin<u64>,
+    source: WorkingGroup,
+    worker_id: u64,
+    destination: WorkingGroup,
+}
+
+impl TransferWorkerFixture {
+    pub fn default_for_worker_id(worker_id: u64) -> Self {
+        Self {
+            origin: RawOrigin::Root,
+            source: WorkingGroup::Forum,
+            worker_id,
+            destination: WorkingGroup::Storage,
+        }
+    }
+
+    pub fn with_origin(self, origin: RawOrigin<u64>) -> Self {
+        Self { origin, ..self }
+    }
+
+    pub fn with_destination(self, destination: WorkingGroup) -> Self {
+        Self {
+            destination,
+            ..self
+        }
+    }
+
+    pub fn call_and_assert(&self, expected_result: DispatchResult) {
+        let worker = ForumWorkingGroup::worker_by_id(self.worker_id);
+        let new_worker_id = StorageWorkingGroup::next_worker_id();
+
+        let actual_result = Utilities::<Test>::transfer_worker(
+            self.origin.clone().into(),
+            self.source,
+            self.worker_id,
+            self.destination,
+        );
+
+        assert_eq!(actual_result, expected_result);
+
+        if actual_result.is_ok() {
+            let worker = worker.expect("Worker Must Exist");
+            let new_worker =
+                StorageWorkingGroup::worker_by_id(new_worker_id).expect("Worker Must Exist");
+
+            assert!(ForumWorkingGroup::worker_by_id(self.worker_id).is_none());
+            assert_eq!(new_worker.member_id, worker.member_id);
+            assert_eq!(new_worker.staking_account_id, worker.staking_account_id);
+            assert_eq!(new_worker.created_at, worker.created_at);
+            assert_eq!(
+                StorageWorkingGroup::worker_transferred_at(new_worker_id),
+                Some(System::block_number())
+            );
+
+            assert_eq!(
+                StakingManager::<Test, LockId1>::current_stake(&worker.staking_account_id),
+                0
+            );
+            assert!(StakingManager::<Test, LockId2>::current_stake(&worker.staking_account_id) > 0);
+
+            assert_last_event(
+                RawEvent::WorkerTransferred(
+                    self.source,
+                    self.worker_id,
+                    self.destination,
+                    new_worker_id,
+                )
+                .into(),
+            );
+        } else {
+            assert_eq!(ForumWorkingGroup::worker_by_id(self.worker_id), worker);
+        }
+    }
+}
+
 impl council::Config for Test {
     type RuntimeEvent = RuntimeEvent;
     type Referendum = referendum::Module<Test, ReferendumInstance>;
@@ -3,8 +3,12 @@
 pub(crate) mod mocks;
 
 use crate::*;
+use frame_support::{assert_noop, assert_ok};
 use frame_system::RawOrigin;
-use mocks::{assert_last_event, initial_test_ext, BurnTokensFixture, Test, Utilities};
+use mocks::{
+    add_lead, add_worker, assert_last_event, initial_test_ext, BurnTokensFixture,
+    ForumWorkingGroupInstance, StorageWorkingGroupInstance, Test, TransferWorkerFixture, Utilities,
+};
 use sp_arithmetic::traits::{One, Zero};
 use sp_runtime::DispatchError;
 use strum::IntoEnumIterator;
@@ -59,7 +63,8 @@ fn run_update_working_group_budget_fails_permissions(wg: WorkingGroup) {
                 RawOrigin::Signed(0).into(),
                 wg,
                 Zero::zero(),
-                BalanceKind::Positive
+                BalanceKind::Positive,
+                None
             ),
             Err(DispatchError::BadOrigin)
         );
@@ -81,7 +86,8 @@ fn run_update_working_group_budget_fails_positive(wg: WorkingGroup) {
                 RawOrigin::Root.into(),
                 wg,
                 One::one(),
-                BalanceKind::Positive
+                BalanceKind::Positive,
+                None
             ),
             Err(Error::<Test>::InsufficientFundsForBudgetUpdate.into())
         );
@@ -103,7 +109,8 @@ fn run_update_working_group_budget_fails_negative(wg: WorkingGroup) {
                 RawOrigin::Root.into(),
                 wg,
                 One::one(),
-                BalanceKind::Negative
+                BalanceKind::Negative,
+                None
             ),
             Err(Error::<Test>::InsufficientFundsForBudgetUpdate.into())
         );
@@ -129,7 +136,8 @@ fn run_update_working_group_budget_succeeds_positive(wg: WorkingGroup) {
                 RawOrigin::Root.into(),
                 wg,
                 funding_amount,
-                BalanceKind::Positive
+                BalanceKind::Positive,
+                None
             ),
             Ok(())
         );
@@ -166,6 +174,7 @@ fn run_update_working_group_budget_succeeds_negative(wg: WorkingGroup) {
                 wg,
                 funding_amount,
                 BalanceKind::Negative,
+                None
             ),
             Ok(())
         );
@@ -181,6 +190,129 @@ fn run_update_working_group_budget_succeeds_negative(wg: WorkingGroup) {
     });
 }
 
+#[test]
+fn update_working_group_budget_succeeds_with_budget_allocation() {
+    initial_test_ext().execute_with(|| {
+        let wg = WorkingGroup::Forum;
+        let budget = 100000;
+        let funding_amount = 100;
+        council::Module::<Test>::set_budget(RawOrigin::Root.into(), budget).unwrap();
+        council::Module::<Test>::create_budget_allocation(
+            RawOrigin::Root.into(),
+            b"forum Q3".to_vec(),
+            funding_amount,
+            frame_system::Pallet::<Test>::block_number() + 10,
+        )
+        .unwrap();
+
+        assert_eq!(
+            Utilities::<Test>::update_working_group_budget(
+                RawOrigin::Root.into(),
+                wg,
+                funding_amount,
+                BalanceKind::Positive,
+                Some(0)
+            ),
+            Ok(())
+        );
+
+        assert_eq!(council::Module::<Test>::budget(), budget - funding_amount);
+        assert_eq!(
+            <Test as Config>::get_working_group_budget(wg),
+            funding_amount
+        );
+        assert_eq!(
+            council::Module::<Test>::budget_allocations(0).map(|allocation| allocation.spent),
+            Some(funding_amount)
+        );
+        assert_last_event(
+            RawEvent::UpdatedWorkingGroupBudget(wg, funding_amount, BalanceKind::Positive).into(),
+        );
+    });
+}
+
+#[test]
+fn update_working_group_budget_fails_with_exceeded_budget_allocation_cap() {
+    initial_test_ext().execute_with(|| {
+        let wg = WorkingGroup::Forum;
+        let funding_amount = 100;
+        council::Module::<Test>::set_budget(RawOrigin::Root.into(), 100000).unwrap();
+        council::Module::<Test>::create_budget_allocation(
+            RawOrigin::Root.into(),
+            b"forum Q3".to_vec(),
+            funding_amount - 1,
+            frame_system::Pallet::<Test>::block_number() + 10,
+        )
+        .unwrap();
+
+        assert_noop!(
+            Utilities::<Test>::update_working_group_budget(
+                RawOrigin::Root.into(),
+                wg,
+                funding_amount,
+                BalanceKind::Positive,
+                Some(0)
+            ),
+            council::Error::<Test>::BudgetAllocationCapExceeded
+        );
+    });
+}
+
+#[test]
+fn update_working_group_budget_fails_to_spend_earmarked_budget_without_allocation() {
+    initial_test_ext().execute_with(|| {
+        let wg = WorkingGroup::Forum;
+        let budget = 1000;
+        let earmarked = 600;
+        council::Module::<Test>::set_budget(RawOrigin::Root.into(), budget).unwrap();
+        council::Module::<Test>::create_budget_allocation(
+            RawOrigin::Root.into(),
+            b"forum Q3".to_vec(),
+            earmarked,
+            frame_system::Pallet::<Test>::block_number() + 10,
+        )
+        .unwrap();
+
+        assert_noop!(
+            Utilities::<Test>::update_working_group_budget(
+                RawOrigin::Root.into(),
+                wg,
+                budget - earmarked + 1,
+                BalanceKind::Positive,
+                None
+            ),
+            Error::<Test>::InsufficientFundsForBudgetUpdate
+        );
+
+        assert_ok!(Utilities::<Test>::update_working_group_budget(
+            RawOrigin::Root.into(),
+            wg,
+            budget - earmarked,
+            BalanceKind::Positive,
+            None
+        ));
+    });
+}
+
+#[test]
+fn update_working_group_budget_fails_with_budget_allocation_for_negative_update() {
+    initial_test_ext().execute_with(|| {
+        let wg = WorkingGroup::Forum;
+        <Test as Config>::set_working_group_budget(wg, 100000);
+
+        assert_noop!(
+            Utilities::<Test>::update_working_group_budget(
+                RawOrigin::Root.into(),
+                wg,
+                One::one(),
+                BalanceKind::Negative,
+                Some(0)
+            ),
+            Error::<Test>::BudgetAllocationForNegativeBudgetUpdate
+        );
+    });
+}
+
 #[test]
 fn burn_account_tokens_succeeds() {
     initial_test_ext().execute_with(|| {
@@ -205,3 +337,85 @@ fn burn_account_tokens_fails_insufficient() {
             .execute_and_assert(Err(Error::<Test>::InsufficientFundsForBurn.into()));
     });
 }
+
+#[test]
+fn transfer_worker_succeeds_by_council() {
+    initial_test_ext().execute_with(|| {
+        let worker_id = add_worker::<ForumWorkingGroupInstance>(1, 100);
+
+        TransferWorkerFixture::default_for_worker_id(worker_id).call_and_assert(Ok(()));
+    });
+}
+
+#[test]
+fn transfer_worker_succeeds_with_leads_approval() {
+    initial_test_ext().execute_with(|| {
+        let source_lead_account_id = add_lead::<ForumWorkingGroupInstance>(10);
+        let destination_lead_account_id = add_lead::<StorageWorkingGroupInstance>(11);
+        let worker_id = add_worker::<ForumWorkingGroupInstance>(1, 100);
+
+        assert_ok!(
+            working_group::Module::<Test, ForumWorkingGroupInstance>::set_worker_transfer_approval(
+                RawOrigin::Signed(source_lead_account_id).into(),
+                worker_id,
+                Some(WorkingGroup::Storage),
+            )
+        );
+
+        TransferWorkerFixture::default_for_worker_id(worker_id)
+            .with_origin(RawOrigin::Signed(destination_lead_account_id))
+            .call_and_assert(Ok(()));
+    });
+}
+
+#[test]
+fn transfer_worker_fails_without_source_lead_approval() {
+    initial_test_ext().execute_with(|| {
+        let destination_lead_account_id = add_lead::<StorageWorkingGroupInstance>(11);
+        let worker_id = add_worker::<ForumWorkingGroupInstance>(1, 100);
+
+        TransferWorkerFixture::default_for_worker_id(worker_id)
+            .with_origin(RawOrigin::Signed(destination_lead_account_id))
+            .call_and_assert(Err(Error::<Test>::WorkerTransferNotApproved.into()));
+    });
+}
+
+#[test]
+fn transfer_worker_fails_with_invalid_origin() {
+    initial_test_ext().execute_with(|| {
+        let source_lead_account_id = add_lead::<ForumWorkingGroupInstance>(10);
+        let worker_id = add_worker::<ForumWorkingGroupInstance>(1, 100);
+
+        TransferWorkerFixture::default_for_worker_id(worker_id)
+            .with_origin(RawOrigin::Signed(source_lead_account_id))
+            .call_and_assert(Err(
+                Error::<Test>::OriginIsNotDestinationWorkingGroupLead.into()
+            ));
+    });
+}
+
+#[test]
+fn transfer_worker_fails_within_working_group() {
+    initial_test_ext().execute_with(|| {
+        let worker_id = add_worker::<ForumWorkingGroupInstance>(1, 100);
+
+        TransferWorkerFixture::default_for_worker_id(worker_id)
+            .with_destination(WorkingGroup::Forum)
+            .call_and_assert(Err(
+                Error::<Test>::CannotTransferWorkerWithinWorkingGroup.into()
+            ));
+    });
+}
+
+#[test]
+fn transfer_worker_fails_with_lead() {
+    initial_test_ext().execute_with(|| {
+        add_lead::<ForumWorkingGroupInstance>(10);
+        let lead_id = working_group::Module::<Test, ForumWorkingGroupInstance>::current_lead()
+            .expect("Lead Must Exist");
+
+        TransferWorkerFixture::default_for_worker_id(lead_id).call_and_assert(Err(
+            working_group::Error::<Test, ForumWorkingGroupInstance>::CannotTransferLead.into(),
+        ));
+    });
+}
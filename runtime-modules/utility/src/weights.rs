@@ -20,6 +20,9 @@
 //! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 4.0.0-dev
 //! DATE: 2023-11-07, STEPS: `50`, REPEAT: 20, LOW RANGE: `[]`, HIGH RANGE: `[]`
 //! EXECUTION: Some(Wasm), WASM-EXECUTION: Compiled, CHAIN: Some("prod-test"), DB CACHE: 1024
+//!
+//! NOTE: the weight functions marked as PLACEHOLDER are hand-written estimates, not the
+//! benchmark output. They must be regenerated with the benchmark CLI before merging.
 
 // Executed Command:
 // ./scripts/../target/release/joystream-node
@@ -46,8 +49,10 @@ use sp_std::marker::PhantomData;
 pub trait WeightInfo {
 	fn execute_signal_proposal(_i: u32, ) -> Weight;
 	fn update_working_group_budget_positive() -> Weight;
+	fn update_working_group_budget_positive_with_allocation() -> Weight;
 	fn update_working_group_budget_negative() -> Weight;
 	fn burn_account_tokens() -> Weight;
+	fn transfer_worker() -> Weight;
 }
 
 /// Weights for joystream_utility using the Substrate node and recommended hardware.
@@ -64,20 +69,38 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			// Standard Error: 1_464
 			.saturating_add(Weight::from_parts(1_035_477, 0u64).saturating_mul(i.into()))
 	}
+	// PLACEHOLDER: hand-written estimate, not the benchmark output.
+	// Must be regenerated with the benchmark CLI before merging.
 	// Storage: Instance1WorkingGroup Budget (r:1 w:1)
 	// Proof: Instance1WorkingGroup Budget (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
 	// Storage: Council Budget (r:1 w:1)
 	// Proof: Council Budget (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	// Storage: Council EarmarkedBudget (r:1 w:0)
+	// Proof: Council EarmarkedBudget (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
 	fn update_working_group_budget_positive() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `291`
-		//  Estimated: `3002`
-		// Minimum execution time: 25_485 nanoseconds.
-		Weight::from_parts(26_531_000, 0u64)
-			.saturating_add(Weight::from_parts(0, 3002))
-			.saturating_add(T::DbWeight::get().reads(2_u64))
+		Weight::from_parts(28_406_000, 0u64)
+			.saturating_add(Weight::from_parts(0, 4008))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
 			.saturating_add(T::DbWeight::get().writes(2_u64))
 	}
+	// PLACEHOLDER: hand-written estimate, not the benchmark output.
+	// Must be regenerated with the benchmark CLI before merging.
+	// Storage: Instance1WorkingGroup Budget (r:1 w:1)
+	// Proof: Instance1WorkingGroup Budget (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	// Storage: Council Budget (r:1 w:1)
+	// Proof: Council Budget (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	// Storage: Council BudgetAllocations (r:1 w:1)
+	// Proof: Council BudgetAllocations (max_values: None, max_size: Some(100), added: 2575, mode: MaxEncodedLen)
+	// Storage: Council EarmarkedBudget (r:1 w:1)
+	// Proof: Council EarmarkedBudget (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	// Storage: Council BudgetAllocationSpendings (r:0 w:1)
+	// Proof: Council BudgetAllocationSpendings (max_values: None, max_size: Some(64), added: 2539, mode: MaxEncodedLen)
+	fn update_working_group_budget_positive_with_allocation() -> Weight {
+		Weight::from_parts(36_215_000, 0u64)
+			.saturating_add(Weight::from_parts(0, 6583))
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
 	// Storage: Instance1WorkingGroup Budget (r:1 w:1)
 	// Proof: Instance1WorkingGroup Budget (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
 	// Storage: Council Budget (r:1 w:1)
@@ -92,17 +115,42 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(2_u64))
 			.saturating_add(T::DbWeight::get().writes(2_u64))
 	}
+	// PLACEHOLDER: hand-written estimate, not the benchmark output.
+	// Must be regenerated with the benchmark CLI before merging.
 	// Storage: System Account (r:1 w:1)
 	// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
 	fn burn_account_tokens() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `101`
-		//  Estimated: `3593`
-		// Minimum execution time: 31_689 nanoseconds.
 		Weight::from_parts(32_799_000, 0u64)
 			.saturating_add(Weight::from_parts(0, 3593))
 			.saturating_add(T::DbWeight::get().reads(1_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}	// Storage: Instance1WorkingGroup CurrentLead (r:1 w:0)
+	// Proof: Instance1WorkingGroup CurrentLead (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	// Storage: Instance1WorkingGroup WorkerById (r:1 w:1)
+	// Proof: Instance1WorkingGroup WorkerById (max_values: None, max_size: Some(180), added: 2655, mode: MaxEncodedLen)
+	// Storage: Instance2WorkingGroup ActiveWorkerCount (r:1 w:1)
+	// Proof: Instance2WorkingGroup ActiveWorkerCount (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	// Storage: Balances Locks (r:1 w:1)
+	// Proof: Balances Locks (max_values: None, max_size: Some(1299), added: 3774, mode: MaxEncodedLen)
+	// Storage: Instance1WorkingGroup DeputyById (r:1 w:0)
+	// Proof: Instance1WorkingGroup DeputyById (max_values: None, max_size: Some(61), added: 2536, mode: MaxEncodedLen)
+	// Storage: Instance1WorkingGroup ActiveWorkerCount (r:1 w:1)
+	// Proof: Instance1WorkingGroup ActiveWorkerCount (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	// Storage: Instance2WorkingGroup NextWorkerId (r:1 w:1)
+	// Proof: Instance2WorkingGroup NextWorkerId (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	// Storage: System Account (r:1 w:1)
+	// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	// Storage: Instance1WorkingGroup PendingRewardAdjustment (r:0 w:1)
+	// Proof: Instance1WorkingGroup PendingRewardAdjustment (max_values: None, max_size: Some(24), added: 2499, mode: MaxEncodedLen)
+	// Storage: Instance1WorkingGroup WorkerTransferApprovalById (r:0 w:1)
+	// Proof: Instance1WorkingGroup WorkerTransferApprovalById (max_values: None, max_size: Some(25), added: 2500, mode: MaxEncodedLen)
+	// Storage: Instance2WorkingGroup WorkerById (r:0 w:1)
+	// Proof: Instance2WorkingGroup WorkerById (max_values: None, max_size: Some(180), added: 2655, mode: MaxEncodedLen)
+	fn transfer_worker() -> Weight {
+		Weight::from_parts(62_817_000, 0u64)
+			.saturating_add(Weight::from_parts(0, 22518))
+			.saturating_add(T::DbWeight::get().reads(8_u64))
+			.saturating_add(T::DbWeight::get().writes(9_u64))
 	}
 }
 
@@ -114,10 +162,16 @@ impl WeightInfo for () {
 	fn update_working_group_budget_positive() -> Weight {
 		Weight::from_parts(0, 0)
 	}
+	fn update_working_group_budget_positive_with_allocation() -> Weight {
+		Weight::from_parts(0, 0)
+	}
 	fn update_working_group_budget_negative() -> Weight {
 		Weight::from_parts(0, 0)
 	}
 	fn burn_account_tokens() -> Weight {
 		Weight::from_parts(0, 0)
 	}
+	fn transfer_worker() -> Weight {
+		Weight::from_parts(0, 0)
+	}
 }
@@ -6,6 +6,7 @@ use frame_support::traits::OnInitialize;
 use frame_system::EventRecord;
 use frame_system::Pallet as System;
 use frame_system::RawOrigin;
+use sp_arithmetic::Percent;
 use sp_runtime::traits::Bounded;
 use sp_std::prelude::*;
 use sp_std::vec;
@@ -122,6 +123,58 @@ fn add_opening_and_apply_with_multiple_ids<T: Config<I> + membership::Config, I:
     (opening_id, successful_application_ids, account_ids)
 }
 
+// Adds the regular opening with the applications of the members with provided ids and
+// shortlists all of them.
+fn add_opening_and_shortlist_applications<T: Config<I> + membership::Config, I: Instance>(
+    ids: &[u32],
+    lead_id: &T::AccountId,
+    endorsement_period: Option<T::BlockNumber>,
+) -> (OpeningId, BTreeSet<ApplicationId>) {
+    let (opening_id, application_ids, _) = add_opening_and_apply_with_multiple_ids::<T, I>(
+        ids,
+        &T::RuntimeOrigin::from(RawOrigin::Signed(lead_id.clone())),
+        &OpeningType::Regular,
+    );
+
+    WorkingGroup::<T, I>::shortlist_applications(
+        RawOrigin::Signed(lead_id.clone()).into(),
+        opening_id,
+        application_ids.clone(),
+        endorsement_period,
+        ids.len().try_into().unwrap(),
+    )
+    .unwrap();
+
+    (opening_id, application_ids)
+}
+
+// Endorses the applications spreading the endorsements evenly. The endorser member ids start
+// from the provided id.
+fn endorse_applications<T: Config<I> + membership::Config, I: Instance>(
+    application_ids: &BTreeSet<ApplicationId>,
+    endorsement_count: u32,
+    first_endorser_id: u32,
+) {
+    let stake = T::MinimumApplicationStake::get();
+
+    for (endorser_id, application_id) in (first_endorser_id..)
+        .zip(application_ids.iter().cycle())
+        .take(endorsement_count.try_into().unwrap())
+    {
+        let (endorser_account_id, endorser_member_id) =
+            member_funded_account::<T, I>("endorser", endorser_id);
+
+        WorkingGroup::<T, I>::endorse_application(
+            RawOrigin::Signed(endorser_account_id.clone()).into(),
+            endorser_member_id,
+            *application_id,
+            endorser_account_id,
+            stake,
+        )
+        .unwrap();
+    }
+}
+
 fn add_and_apply_opening<T: Config<I>, I: Instance>(
     id: u32,
     add_opening_origin: &T::RuntimeOrigin,
@@ -197,6 +250,16 @@ fn force_missed_reward<T: Config<I>, I: Instance>() {
     System::<T>::set_block_number(curr_block_number);
     WorkingGroup::<T, _>::set_budget(RawOrigin::Root.into(), Zero::zero()).unwrap();
     WorkingGroup::<T, _>::on_initialize(curr_block_number);
+    complete_reward_payout_round::<T, I>();
+}
+
+// Reward payouts of the big groups are spread over several blocks.
+fn complete_reward_payout_round<T: Config<I>, I: Instance>() {
+    while WorkingGroup::<T, I>::current_reward_payout_round().is_some() {
+        let curr_block_number = System::<T>::block_number().saturating_add(One::one());
+        System::<T>::set_block_number(curr_block_number);
+        WorkingGroup::<T, _>::on_initialize(curr_block_number);
+    }
 }
 
 pub fn insert_a_worker<T: Config<I> + membership::Config, I: Instance>(
@@ -345,7 +408,7 @@ benchmarks_instance! {
 
 
     on_initialize_rewarding_with_missing_reward {
-        let i in 2 .. T::MaxWorkerNumberLimit::get();
+        let i in 2 .. T::MaxRewardPayoutsPerBlock::get().min(T::MaxWorkerNumberLimit::get());
 
         let (lead_id, _) = insert_a_worker::<T, I>(
             OpeningType::Leader,
@@ -405,7 +468,7 @@ benchmarks_instance! {
     }
 
     on_initialize_rewarding_with_missing_reward_cant_pay {
-        let i in 2 .. T::MaxWorkerNumberLimit::get();
+        let i in 2 .. T::MaxRewardPayoutsPerBlock::get().min(T::MaxWorkerNumberLimit::get());
 
         let (lead_id, _) = insert_a_worker::<T, I>(
             OpeningType::Leader,
@@ -454,7 +517,7 @@ benchmarks_instance! {
     }
 
     on_initialize_rewarding_without_missing_reward {
-        let i in 2 .. T::MaxWorkerNumberLimit::get();
+        let i in 2 .. T::MaxRewardPayoutsPerBlock::get().min(T::MaxWorkerNumberLimit::get());
 
         let (lead_id, _) = insert_a_worker::<T, I>(
             OpeningType::Leader,
@@ -983,6 +1046,505 @@ benchmarks_instance! {
         verify {
             assert_last_event::<T, I>(RawEvent::WorkerRemarked(worker_id, msg).into());
     }
+
+    file_worker_review {
+        let i in 0 .. MAX_KILOBYTES_METADATA;
+
+        let (lead_id, _) =
+            insert_a_worker::<T, I>(OpeningType::Leader, 0, None);
+        let (_, worker_id) = insert_a_worker::<T, I>(
+            OpeningType::Regular,
+            1,
+            Some(lead_id.clone())
+        );
+        let metadata = vec![0u8; (i * 1000).try_into().unwrap()];
+        let reward_adjustment = Some(RewardAdjustment::Bonus(Percent::from_percent(10)));
+    }: _ (
+        RawOrigin::Signed(lead_id),
+        worker_id,
+        MAX_WORKER_REVIEW_RATING,
+        metadata.clone(),
+        reward_adjustment
+    )
+    verify {
+        assert_eq!(
+            WorkingGroup::<T, I>::pending_reward_adjustment(worker_id),
+            reward_adjustment.map(|adjustment| (0, adjustment))
+        );
+        assert_last_event::<T, I>(
+            RawEvent::WorkerReviewFiled(
+                worker_id,
+                0,
+                MAX_WORKER_REVIEW_RATING,
+                metadata,
+                reward_adjustment
+            ).into()
+        );
+    }
+
+    contest_worker_review {
+        let i in 0 .. MAX_KILOBYTES_METADATA;
+
+        let (lead_id, _) =
+            insert_a_worker::<T, I>(OpeningType::Leader, 0, None);
+        let (caller_id, worker_id) = insert_a_worker::<T, I>(
+            OpeningType::Regular,
+            1,
+            Some(lead_id.clone())
+        );
+        WorkingGroup::<T, I>::file_worker_review(
+            RawOrigin::Signed(lead_id).into(),
+            worker_id,
+            0,
+            vec![],
+            Some(RewardAdjustment::Penalty(Percent::from_percent(10)))
+        ).unwrap();
+        let rationale = vec![0u8; (i * 1000).try_into().unwrap()];
+    }: _ (RawOrigin::Signed(caller_id), worker_id, 0, rationale.clone())
+    verify {
+        assert_eq!(
+            WorkingGroup::<T, I>::worker_review_by_id(0).unwrap().status,
+            WorkerReviewStatus::Contested
+        );
+        assert_last_event::<T, I>(
+            RawEvent::WorkerReviewContested(worker_id, 0, rationale).into()
+        );
+    }
+
+    resolve_worker_review_contest {
+        let (lead_id, _) =
+            insert_a_worker::<T, I>(OpeningType::Leader, 0, None);
+        let (caller_id, worker_id) = insert_a_worker::<T, I>(
+            OpeningType::Regular,
+            1,
+            Some(lead_id.clone())
+        );
+        WorkingGroup::<T, I>::file_worker_review(
+            RawOrigin::Signed(lead_id).into(),
+            worker_id,
+            0,
+            vec![],
+            Some(RewardAdjustment::Penalty(Percent::from_percent(10)))
+        ).unwrap();
+        WorkingGroup::<T, I>::contest_worker_review(
+            RawOrigin::Signed(caller_id).into(),
+            worker_id,
+            0,
+            vec![]
+        ).unwrap();
+        // Worst case: the withheld reward is refunded on overturn.
+        WorkerReviewById::<T, I>::mutate(0, |review| {
+            if let Some(review) = review {
+                review.withheld_reward = One::one();
+            }
+        });
+        let current_budget = BalanceOf::<T>::max_value();
+        WorkingGroup::<T, _>::set_budget(RawOrigin::Root.into(), current_budget).unwrap();
+    }: _ (RawOrigin::Root, worker_id, 0, false)
+    verify {
+        assert!(WorkingGroup::<T, I>::pending_reward_adjustment(worker_id).is_none());
+        assert!(!WorkerReviewById::<T, I>::contains_key(0));
+        assert_last_event::<T, I>(
+            RawEvent::WorkerReviewContestResolved(worker_id, 0, false).into()
+        );
+    }
+
+    appoint_deputy {
+        let (lead_id, _) =
+            insert_a_worker::<T, I>(OpeningType::Leader, 0, None);
+        let (_, worker_id) = insert_a_worker::<T, I>(
+            OpeningType::Regular,
+            1,
+            Some(lead_id.clone())
+        );
+        let permissions: DeputyPermissions = [
+            LeadActionPermission::FillOpenings,
+            LeadActionPermission::SpendFromBudget,
+            LeadActionPermission::SlashWorkerStake,
+            LeadActionPermission::TerminateWorkers,
+            LeadActionPermission::DecreaseWorkerStake,
+            LeadActionPermission::UpdateWorkerReward,
+            LeadActionPermission::ReviewWorkers,
+        ].iter().copied().collect();
+        let spending_cap: BalanceOf<T> = One::one();
+    }: _ (RawOrigin::Signed(lead_id), worker_id, permissions.clone(), spending_cap)
+    verify {
+        assert!(WorkingGroup::<T, I>::deputy_by_id(worker_id).is_some());
+        assert_last_event::<T, I>(
+            RawEvent::DeputyAppointed(worker_id, permissions, spending_cap).into()
+        );
+    }
+
+    revoke_deputy {
+        let (lead_id, _) =
+            insert_a_worker::<T, I>(OpeningType::Leader, 0, None);
+        let (_, worker_id) = insert_a_worker::<T, I>(
+            OpeningType::Regular,
+            1,
+            Some(lead_id.clone())
+        );
+        WorkingGroup::<T, I>::appoint_deputy(
+            RawOrigin::Signed(lead_id).into(),
+            worker_id,
+            [LeadActionPermission::FillOpenings].iter().copied().collect(),
+            One::one(),
+        ).unwrap();
+    }: _ (RawOrigin::Root, worker_id)
+    verify {
+        assert!(WorkingGroup::<T, I>::deputy_by_id(worker_id).is_none());
+        assert_last_event::<T, I>(RawEvent::DeputyRevoked(worker_id).into());
+    }
+    create_recurring_payment {
+        let i in 0 .. MAX_KILOBYTES_METADATA;
+
+        let (lead_id, _) =
+            insert_a_worker::<T, I>(OpeningType::Leader, 0, None);
+        let amount_per_period: BalanceOf<T> = One::one();
+        let start_block = System::<T>::block_number();
+        let end_block = start_block.saturating_add(T::RewardPeriod::get().into());
+        let total_cap: Option<BalanceOf<T>> = Some(One::one());
+        let rationale = Some(vec![0u8; (i * 1000) as usize]);
+    }: _ (
+        RawOrigin::Signed(lead_id.clone()),
+        lead_id.clone(),
+        amount_per_period,
+        start_block,
+        end_block,
+        total_cap,
+        rationale.clone()
+    )
+    verify {
+        let recurring_payment = RecurringPayment {
+            recipient: lead_id,
+            amount_per_period,
+            start_block,
+            end_block,
+            total_cap,
+            accrued_amount: Zero::zero(),
+            missed_payment: None,
+        };
+        assert_eq!(
+            WorkingGroup::<T, I>::recurring_payment_by_id(0),
+            Some(recurring_payment.clone())
+        );
+        assert_last_event::<T, I>(
+            RawEvent::RecurringPaymentCreated(0, recurring_payment, rationale).into()
+        );
+    }
+
+    cancel_recurring_payment {
+        let (lead_id, _) =
+            insert_a_worker::<T, I>(OpeningType::Leader, 0, None);
+        let start_block = System::<T>::block_number();
+        WorkingGroup::<T, I>::create_recurring_payment(
+            RawOrigin::Signed(lead_id.clone()).into(),
+            lead_id,
+            One::one(),
+            start_block,
+            start_block.saturating_add(T::RewardPeriod::get().into()),
+            None,
+            None,
+        ).unwrap();
+    }: _ (RawOrigin::Root, 0)
+    verify {
+        assert!(WorkingGroup::<T, I>::recurring_payment_by_id(0).is_none());
+        assert_last_event::<T, I>(RawEvent::RecurringPaymentCanceled(0).into());
+    }
+
+    on_initialize_paying_recurring_payments {
+        let i in 2 .. T::MaxRewardPayoutsPerBlock::get();
+
+        let (lead_id, _) = insert_a_worker::<T, I>(
+            OpeningType::Leader,
+            0,
+            None
+        );
+
+        // The lead is rewarded first and uses one payout of the batch.
+        let amount_per_period = BalanceOf::<T>::from(T::RewardPeriod::get());
+        let end_block = System::<T>::block_number()
+            .saturating_add(T::RewardPeriod::get().saturating_mul(10).into());
+        for _ in 1..i {
+            WorkingGroup::<T, I>::create_recurring_payment(
+                RawOrigin::Signed(lead_id.clone()).into(),
+                lead_id.clone(),
+                amount_per_period,
+                Zero::zero(),
+                end_block,
+                None,
+                None,
+            ).unwrap();
+        }
+
+        // Sets periods so that we can reward
+        let curr_block_number =
+            System::<T>::block_number().saturating_add(T::RewardPeriod::get().into());
+        System::<T>::set_block_number(curr_block_number);
+
+        // Sets budget so that we can pay it
+        WorkingGroup::<T, _>::set_budget(
+            RawOrigin::Root.into(), BalanceOf::<T>::max_value()
+        ).unwrap();
+    }: { WorkingGroup::<T, _>::on_initialize(curr_block_number) }
+    verify {
+        for recurring_payment_id in 0..(i - 1) {
+            assert_eq!(
+                WorkingGroup::<T, I>::recurring_payment_by_id(recurring_payment_id as u64)
+                    .map(|payment| payment.accrued_amount),
+                Some(amount_per_period),
+                "Not all recurring payments paid"
+            );
+        }
+        assert!(WorkingGroup::<T, I>::current_reward_payout_round().is_none());
+    }
+
+    fill_screened_opening {
+        let i in 2 .. T::MaxWorkerNumberLimit::get();
+        let j in 0 .. T::MaxWorkerNumberLimit::get()
+            .saturating_mul(T::MaxApplicationEndorsements::get());
+
+        let (lead_id, _) = insert_a_worker::<T, I>(
+            OpeningType::Leader,
+            0,
+            None
+        );
+
+        let (opening_id, application_ids) = add_opening_and_shortlist_applications::<T, I>(
+            &(1..i.saturating_add(1)).collect::<Vec<_>>(),
+            &lead_id,
+            Some(One::one()),
+        );
+
+        endorse_applications::<T, I>(&application_ids, j, i.saturating_add(1));
+
+        let curr_block_number = System::<T>::block_number().saturating_add(One::one());
+        System::<T>::set_block_number(curr_block_number);
+        WorkingGroup::<T, I>::start_application_selection(
+            RawOrigin::Signed(lead_id.clone()).into(),
+            opening_id
+        ).unwrap();
+
+        let successful_application_ids: BTreeSet<ApplicationId> =
+            application_ids.iter().take(1).copied().collect();
+        let dropped_application_ids: BTreeSet<ApplicationId> =
+            application_ids.iter().skip(1).copied().collect();
+    }: fill_opening(
+            RawOrigin::Signed(lead_id.clone()),
+            opening_id,
+            successful_application_ids
+        )
+    verify {
+        assert!(!OpeningById::<T, I>::contains_key(opening_id), "Opening still not filled");
+        assert!(WorkingGroup::<T, I>::application_screening_stage(opening_id).is_none());
+
+        for application_id in application_ids.iter() {
+            assert!(WorkingGroup::<T, I>::application_by_id(application_id).is_none());
+            assert_eq!(WorkingGroup::<T, I>::application_endorsement_count(application_id), 0);
+        }
+
+        assert_last_event::<T, I>(
+            RawEvent::ApplicationsDropped(opening_id, dropped_application_ids).into()
+        );
+    }
+
+    cancel_screened_opening {
+        let i in 0 .. T::MaxWorkerNumberLimit::get()
+            .saturating_mul(T::MaxApplicationEndorsements::get());
+
+        let (lead_id, _) = insert_a_worker::<T, I>(
+            OpeningType::Leader,
+            0,
+            None
+        );
+
+        let application_count = T::MaxWorkerNumberLimit::get();
+        let (opening_id, application_ids) = add_opening_and_shortlist_applications::<T, I>(
+            &(1..application_count.saturating_add(1)).collect::<Vec<_>>(),
+            &lead_id,
+            Some(One::one()),
+        );
+
+        endorse_applications::<T, I>(&application_ids, i, application_count.saturating_add(1));
+    }: cancel_opening(RawOrigin::Signed(lead_id.clone()), opening_id)
+    verify {
+        assert!(!OpeningById::<T, I>::contains_key(opening_id), "Opening not removed");
+        assert!(WorkingGroup::<T, I>::application_screening_stage(opening_id).is_none());
+
+        for application_id in application_ids.iter() {
+            assert_eq!(WorkingGroup::<T, I>::application_endorsement_count(application_id), 0);
+        }
+
+        assert_last_event::<T, I>(RawEvent::OpeningCanceled(opening_id).into());
+    }
+
+    shortlist_applications {
+        let i in 1 .. T::MaxWorkerNumberLimit::get();
+        let j in 1 .. T::MaxWorkerNumberLimit::get();
+
+        let (lead_id, _) = insert_a_worker::<T, I>(
+            OpeningType::Leader,
+            0,
+            None
+        );
+
+        let (opening_id, application_ids, _) = add_opening_and_apply_with_multiple_ids::<T, I>(
+            &(1..i.saturating_add(j).saturating_add(1)).collect::<Vec<_>>(),
+            &T::RuntimeOrigin::from(RawOrigin::Signed(lead_id.clone())),
+            &OpeningType::Regular
+        );
+
+        let shortlisted_application_ids: BTreeSet<ApplicationId> =
+            application_ids.iter().take(i as usize).copied().collect();
+        let dropped_application_ids: BTreeSet<ApplicationId> =
+            application_ids.iter().skip(i as usize).copied().collect();
+        let endorsement_period: Option<T::BlockNumber> = Some(One::one());
+    }: _ (
+        RawOrigin::Signed(lead_id),
+        opening_id,
+        shortlisted_application_ids,
+        endorsement_period,
+        i.saturating_add(j)
+    )
+    verify {
+        assert!(WorkingGroup::<T, I>::application_screening_stage(opening_id).is_some());
+
+        for application_id in dropped_application_ids.iter() {
+            assert!(WorkingGroup::<T, I>::application_by_id(application_id).is_none());
+        }
+
+        assert_last_event::<T, I>(
+            RawEvent::ApplicationsDropped(opening_id, dropped_application_ids).into()
+        );
+    }
+
+    endorse_application {
+        let (lead_id, _) = insert_a_worker::<T, I>(
+            OpeningType::Leader,
+            0,
+            None
+        );
+
+        let (_, application_ids) = add_opening_and_shortlist_applications::<T, I>(
+            &[1],
+            &lead_id,
+            Some(One::one()),
+        );
+        let application_id = *application_ids.iter().next().unwrap();
+
+        let (endorser_account_id, endorser_member_id) =
+            member_funded_account::<T, I>("endorser", 2);
+        let stake = T::MinimumApplicationStake::get();
+    }: _ (
+        RawOrigin::Signed(endorser_account_id.clone()),
+        endorser_member_id,
+        application_id,
+        endorser_account_id.clone(),
+        stake
+    )
+    verify {
+        assert_eq!(WorkingGroup::<T, I>::application_endorsement_stake(application_id), stake);
+        assert_last_event::<T, I>(
+            RawEvent::ApplicationEndorsed(
+                application_id,
+                endorser_member_id,
+                endorser_account_id,
+                stake
+            ).into()
+        );
+    }
+
+    withdraw_application_endorsement {
+        let (lead_id, _) = insert_a_worker::<T, I>(
+            OpeningType::Leader,
+            0,
+            None
+        );
+
+        let (_, application_ids) = add_opening_and_shortlist_applications::<T, I>(
+            &[1],
+            &lead_id,
+            Some(One::one()),
+        );
+        let application_id = *application_ids.iter().next().unwrap();
+
+        let (endorser_account_id, endorser_member_id) =
+            member_funded_account::<T, I>("endorser", 2);
+        WorkingGroup::<T, I>::endorse_application(
+            RawOrigin::Signed(endorser_account_id.clone()).into(),
+            endorser_member_id,
+            application_id,
+            endorser_account_id.clone(),
+            T::MinimumApplicationStake::get(),
+        ).unwrap();
+    }: _ (RawOrigin::Signed(endorser_account_id), endorser_member_id, application_id)
+    verify {
+        assert!(
+            WorkingGroup::<T, I>::application_endorsement_by_id(
+                application_id,
+                endorser_member_id
+            ).is_none()
+        );
+        assert_eq!(
+            WorkingGroup::<T, I>::application_endorsement_stake(application_id),
+            Zero::zero()
+        );
+        assert_last_event::<T, I>(
+            RawEvent::ApplicationEndorsementWithdrawn(application_id, endorser_member_id).into()
+        );
+    }
+
+    start_application_selection {
+        let i in 1 .. T::MaxWorkerNumberLimit::get();
+
+        let (lead_id, _) = insert_a_worker::<T, I>(
+            OpeningType::Leader,
+            0,
+            None
+        );
+
+        let (opening_id, application_ids) = add_opening_and_shortlist_applications::<T, I>(
+            &(1..i.saturating_add(1)).collect::<Vec<_>>(),
+            &lead_id,
+            Some(One::one()),
+        );
+
+        let curr_block_number = System::<T>::block_number().saturating_add(One::one());
+        System::<T>::set_block_number(curr_block_number);
+    }: _ (RawOrigin::Signed(lead_id), opening_id)
+    verify {
+        assert_eq!(
+            WorkingGroup::<T, I>::application_screening_stage(opening_id),
+            Some(ApplicationScreeningStage::Selection)
+        );
+
+        let ranking = application_ids
+            .iter()
+            .map(|application_id| (*application_id, Zero::zero()))
+            .collect::<Vec<_>>();
+        assert_last_event::<T, I>(
+            RawEvent::ApplicationSelectionStarted(opening_id, ranking).into()
+        );
+    }
+
+    set_worker_transfer_approval {
+        let (lead_id, _) =
+            insert_a_worker::<T, I>(OpeningType::Leader, 0, None);
+        let (_, worker_id) = insert_a_worker::<T, I>(
+            OpeningType::Regular,
+            1,
+            Some(lead_id.clone())
+        );
+        let destination = Some(common::working_group::WorkingGroup::Storage);
+    }: _ (RawOrigin::Signed(lead_id), worker_id, destination)
+    verify {
+        assert_eq!(
+            WorkingGroup::<T, I>::worker_transfer_approval_by_id(worker_id),
+            destination
+        );
+        assert_last_event::<T, I>(
+            RawEvent::WorkerTransferApprovalSet(worker_id, destination).into()
+        );
+    }
 }
 
 #[cfg(test)]
@@ -1179,4 +1741,111 @@ mod tests {
             assert_ok!(WorkingGroup::<Test>::test_benchmark_worker_remark());
         });
     }
+
+    #[test]
+    fn test_file_worker_review() {
+        build_test_externalities().execute_with(|| {
+            assert_ok!(WorkingGroup::<Test>::test_benchmark_file_worker_review());
+        });
+    }
+
+    #[test]
+    fn test_contest_worker_review() {
+        build_test_externalities().execute_with(|| {
+            assert_ok!(WorkingGroup::<Test>::test_benchmark_contest_worker_review());
+        });
+    }
+
+    #[test]
+    fn test_resolve_worker_review_contest() {
+        build_test_externalities().execute_with(|| {
+            assert_ok!(WorkingGroup::<Test>::test_benchmark_resolve_worker_review_contest());
+        });
+    }
+
+    #[test]
+    fn test_appoint_deputy() {
+        build_test_externalities().execute_with(|| {
+            assert_ok!(WorkingGroup::<Test>::test_benchmark_appoint_deputy());
+        });
+    }
+
+    #[test]
+    fn test_revoke_deputy() {
+        build_test_externalities().execute_with(|| {
+            assert_ok!(WorkingGroup::<Test>::test_benchmark_revoke_deputy());
+        });
+    }
+
+    #[test]
+    fn test_create_recurring_payment() {
+        build_test_externalities().execute_with(|| {
+            assert_ok!(WorkingGroup::<Test>::test_benchmark_create_recurring_payment());
+        });
+    }
+
+    #[test]
+    fn test_cancel_recurring_payment() {
+        build_test_externalities().execute_with(|| {
+            assert_ok!(WorkingGroup::<Test>::test_benchmark_cancel_recurring_payment());
+        });
+    }
+
+    #[test]
+    fn test_on_initialize_paying_recurring_payments() {
+        build_test_externalities().execute_with(|| {
+            assert_ok!(
+                WorkingGroup::<Test>::test_benchmark_on_initialize_paying_recurring_payments()
+            );
+        });
+    }
+
+    #[test]
+    fn test_fill_screened_opening() {
+        build_test_externalities().execute_with(|| {
+            assert_ok!(WorkingGroup::<Test>::test_benchmark_fill_screened_opening());
+        });
+    }
+
+    #[test]
+    fn test_cancel_screened_opening() {
+        build_test_externalities().execute_with(|| {
+            assert_ok!(WorkingGroup::<Test>::test_benchmark_cancel_screened_opening());
+        });
+    }
+
+    #[test]
+    fn test_shortlist_applications() {
+        build_test_externalities().execute_with(|| {
+            assert_ok!(WorkingGroup::<Test>::test_benchmark_shortlist_applications());
+        });
+    }
+
+    #[test]
+    fn test_endorse_application() {
+        build_test_externalities().execute_with(|| {
+            assert_ok!(WorkingGroup::<Test>::test_benchmark_endorse_application());
+        });
+    }
+
+    #[test]
+    fn test_withdraw_application_endorsement() {
+        build_test_externalities().execute_with(|| {
+            assert_ok!(WorkingGroup::<Test>::test_benchmark_withdraw_application_endorsement());
+        });
+    }
+
+    #[test]
+    fn test_start_application_selection() {
+        build_test_externalities().execute_with(|| {
+            assert_ok!(WorkingGroup::<Test>::test_benchmark_start_application_selection());
+        });
+    }
+
+    #[test]
+    fn test_set_worker_transfer_approval() {
+        build_test_externalities().execute_with(|| {
+            assert_ok!(WorkingGroup::<Test>::test_benchmark_set_worker_transfer_approval());
+        });
+    }
 }
@@ -1,12 +1,14 @@
 use crate::{
-    ApplicationId, BalanceOf, Config, Instance, OpeningId, OpeningOf, OpeningType, StakePolicy,
-    Worker, WorkerId,
+    ApplicationEndorsementOf, ApplicationId, ApplicationScreeningStage, BalanceOf, Config,
+    DeputyOf, Instance, LeadActionPermission, OpeningId, OpeningOf, OpeningType,
+    RecurringPaymentId, RecurringPaymentOf, StakePolicy, Worker, WorkerId, WorkerReviewId,
+    WorkerReviewOf,
 };
 
 use super::Error;
 use frame_support::dispatch::{DispatchError, DispatchResult};
 use frame_support::traits::Get;
-use frame_support::{ensure, StorageMap, StorageValue};
+use frame_support::{ensure, StorageDoubleMap, StorageMap, StorageValue};
 use frame_system::{ensure_root, ensure_signed};
 use sp_arithmetic::traits::Zero;
 use sp_std::collections::btree_set::BTreeSet;
@@ -15,6 +17,7 @@ use sp_std::vec::Vec;
 use staking_handler::StakingHandler;
 
 use crate::types::{ApplicationInfo, StakeParameters};
+use common::MemberId;
 
 // Check opening: verifies origin and opening type compatibility.
 pub(crate) fn ensure_origin_for_opening_type<T: Config<I>, I: Instance>(
@@ -164,6 +167,53 @@ pub(crate) fn ensure_worker_exists<T: Config<I>, I: Instance>(
     <crate::WorkerById<T, I>>::get(worker_id).ok_or(Error::<T, I>::WorkerDoesNotExist)
 }
 
+// Check worker review: ensures the review exists and belongs to the worker.
+pub(crate) fn ensure_worker_review_exists<T: Config<I>, I: Instance>(
+    worker_id: &WorkerId<T>,
+    review_id: &WorkerReviewId,
+) -> Result<WorkerReviewOf<T>, Error<T, I>> {
+    let review = <crate::WorkerReviewById<T, I>>::get(review_id)
+        .ok_or(Error::<T, I>::WorkerReviewDoesNotExist)?;
+
+    ensure!(
+        review.worker_id == *worker_id,
+        Error::<T, I>::WorkerReviewDoesNotExist
+    );
+
+    Ok(review)
+}
+
+// Check application screening: ensures the opening is at the active endorsement stage.
+pub(crate) fn ensure_application_endorsement_stage_is_active<T: Config<I>, I: Instance>(
+    opening_id: OpeningId,
+) -> DispatchResult {
+    match crate::Module::<T, I>::application_screening_stage(opening_id) {
+        Some(ApplicationScreeningStage::Endorsement { ends_at })
+            if crate::Module::<T, I>::current_block() < ends_at =>
+        {
+            Ok(())
+        }
+        _ => Err(Error::<T, I>::ApplicationEndorsementStageIsNotActive.into()),
+    }
+}
+
+// Check application endorsement: returns the endorsement if it exists.
+pub(crate) fn ensure_application_endorsement_exists<T: Config<I>, I: Instance>(
+    application_id: ApplicationId,
+    member_id: MemberId<T>,
+) -> Result<ApplicationEndorsementOf<T>, Error<T, I>> {
+    <crate::ApplicationEndorsementById<T, I>>::get(application_id, member_id)
+        .ok_or(Error::<T, I>::ApplicationEndorsementDoesNotExist)
+}
+
+// Check recurring payment: returns the recurring payment by id if it exists.
+pub(crate) fn ensure_recurring_payment_exists<T: Config<I>, I: Instance>(
+    recurring_payment_id: &RecurringPaymentId,
+) -> Result<RecurringPaymentOf<T>, Error<T, I>> {
+    <crate::RecurringPaymentById<T, I>>::get(recurring_payment_id)
+        .ok_or(Error::<T, I>::RecurringPaymentDoesNotExist)
+}
+
 // Check worker: ensures the origin contains signed account that belongs to existing worker.
 pub(crate) fn ensure_worker_signed<T: Config<I>, I: Instance>(
     origin: T::RuntimeOrigin,
@@ -202,6 +252,120 @@ pub(crate) fn ensure_origin_for_worker_operation<T: Config<I>, I: Instance>(
     Ok(is_sudo)
 }
 
+// Check deputy: ensures the worker was appointed as the deputy.
+pub(crate) fn ensure_deputy_exists<T: Config<I>, I: Instance>(
+    worker_id: &WorkerId<T>,
+) -> Result<DeputyOf<T>, Error<T, I>> {
+    <crate::DeputyById<T, I>>::get(worker_id).ok_or(Error::<T, I>::WorkerIsNotDeputy)
+}
+
+// Check leader or deputy: ensures origin is signed by the leader or by the deputy with the
+// provided permission. Returns the deputy worker id in the latter case.
+pub(crate) fn ensure_origin_is_active_leader_or_deputy<T: Config<I>, I: Instance>(
+    origin: T::RuntimeOrigin,
+    permission: LeadActionPermission,
+) -> Result<Option<WorkerId<T>>, DispatchError> {
+    // Ensure is signed
+    let signer = ensure_signed(origin)?;
+
+    // Deputies act on behalf of the leader only while the leader is set.
+    ensure_lead_is_set::<T, I>()?;
+
+    if let Some(deputy_id) = <crate::DeputyIdByAccountId<T, I>>::get(&signer) {
+        // The leader privileges take precedence over the deputy ones.
+        if ensure_is_lead_account::<T, I>(signer.clone()).is_err() {
+            let deputy = ensure_deputy_exists::<T, I>(&deputy_id)?;
+
+            ensure!(
+                deputy.permissions.contains(&permission),
+                Error::<T, I>::DeputyLacksPermission
+            );
+
+            return Ok(Some(deputy_id));
+        }
+    }
+
+    ensure_is_lead_account::<T, I>(signer)?;
+
+    Ok(None)
+}
+
+// Check opening: verifies origin and opening type compatibility allowing the deputy with the
+// provided permission to act for the regular openings. Returns the deputy worker id if any.
+pub(crate) fn ensure_origin_for_opening_type_or_deputy<T: Config<I>, I: Instance>(
+    origin: T::RuntimeOrigin,
+    opening_type: OpeningType,
+    permission: LeadActionPermission,
+) -> Result<Option<WorkerId<T>>, DispatchError> {
+    match opening_type {
+        OpeningType::Regular => {
+            // Ensure lead or the deputy is set and is origin signer.
+            ensure_origin_is_active_leader_or_deputy::<T, I>(origin, permission)
+        }
+        OpeningType::Leader => {
+            // Council proposal.
+            ensure_root(origin)?;
+
+            Ok(None)
+        }
+    }
+}
+
+// Check worker: verifies proper origin for the worker operation allowing the deputy with the
+// provided permission to act on other regular workers. Returns whether the origin is sudo and
+// the deputy worker id if any.
+pub(crate) fn ensure_origin_for_worker_operation_or_deputy<T: Config<I>, I: Instance>(
+    origin: T::RuntimeOrigin,
+    worker_id: WorkerId<T>,
+    permission: LeadActionPermission,
+) -> Result<(bool, Option<WorkerId<T>>), DispatchError> {
+    let leader_worker_id = ensure_lead_is_set::<T, I>()?;
+
+    if leader_worker_id == worker_id {
+        // Council proposal.
+        ensure_root(origin)?;
+
+        return Ok((true, None));
+    }
+
+    let deputy_id = ensure_origin_is_active_leader_or_deputy::<T, I>(origin, permission)?;
+
+    ensure!(
+        deputy_id != Some(worker_id),
+        Error::<T, I>::DeputyCannotActOnSelf
+    );
+
+    Ok((false, deputy_id))
+}
+
+// Check deputy: verifies that the spending doesn't exceed the deputy spending cap.
+pub(crate) fn ensure_within_deputy_spending_cap<T: Config<I>, I: Instance>(
+    deputy_id: &Option<WorkerId<T>>,
+    amount: BalanceOf<T>,
+) -> DispatchResult {
+    if let Some(deputy_id) = deputy_id {
+        let deputy = ensure_deputy_exists::<T, I>(deputy_id)?;
+
+        ensure!(
+            amount <= deputy.spending_cap,
+            Error::<T, I>::DeputySpendingCapExceeded
+        );
+    }
+
+    Ok(())
+}
+
+// Check leader or council: ensures origin is signed by the leader or it is the root (council proposal).
+pub(crate) fn ensure_origin_is_active_leader_or_root<T: Config<I>, I: Instance>(
+    origin: T::RuntimeOrigin,
+) -> DispatchResult {
+    if ensure_root(origin.clone()).is_ok() {
+        return Ok(());
+    }
+
+    ensure_origin_is_active_leader::<T, I>(origin)
+}
+
 // Check opening: verifies stake policy for the opening.
 pub(crate) fn ensure_valid_stake_policy<T: Config<I>, I: Instance>(
     stake_policy: &StakePolicy<T::BlockNumber, BalanceOf<T>>,
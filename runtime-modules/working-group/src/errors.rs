@@ -103,5 +103,80 @@ decl_error! {
 
         /// Cannot withdraw: insufficient budget balance.
         InsufficientBalanceForTransfer,
+
+        /// Worker performance review doesn't exist.
+        WorkerReviewDoesNotExist,
+
+        /// Worker performance review rating exceeds the max rating.
+        InvalidWorkerReviewRating,
+
+        /// Only the filed (not contested or resolved) review can be contested.
+        WorkerReviewCannotBeContested,
+
+        /// Worker performance review is not contested.
+        WorkerReviewIsNotContested,
+
+        /// The lead cannot be appointed as the deputy.
+        CannotAppointLeadAsDeputy,
+
+        /// Worker is not the deputy.
+        WorkerIsNotDeputy,
+
+        /// Deputy lacks the permission for the lead action.
+        DeputyLacksPermission,
+
+        /// Deputy cannot perform the lead action on itself.
+        DeputyCannotActOnSelf,
+
+        /// Spending exceeds the deputy spending cap.
+        DeputySpendingCapExceeded,
+
+        /// Role account is already used by another deputy.
+        DeputyRoleAccountAlreadyUsed,
+
+        /// Too many deputy permissions provided.
+        MaxNumberOfDeputyPermissionsExceeded,
+
+        /// Recurring payment doesn't exist.
+        RecurringPaymentDoesNotExist,
+
+        /// Recurring payment should end after it starts and after the current block.
+        InvalidRecurringPaymentPeriod,
+
+        /// Application screening of the opening has already started.
+        ApplicationScreeningAlreadyStarted,
+
+        /// Number of the shortlisted applications exceeds the maximum worker number.
+        MaxShortlistedApplicationsExceeded,
+
+        /// Provided application count witness doesn't match the opening applications.
+        InvalidApplicationCountWitness,
+
+        /// Endorsement period cannot be zero.
+        InvalidEndorsementPeriod,
+
+        /// Application is not in the active endorsement stage.
+        ApplicationEndorsementStageIsNotActive,
+
+        /// Endorsement stage of the opening has not ended yet.
+        ApplicationEndorsementStageNotEnded,
+
+        /// Selection stage of the opening application screening has not started yet.
+        ApplicationSelectionNotStarted,
+
+        /// Member has already endorsed the application.
+        ApplicationAlreadyEndorsed,
+
+        /// Application endorsement doesn't exist.
+        ApplicationEndorsementDoesNotExist,
+
+        /// Endorsement stake cannot be zero.
+        CannotEndorseWithZeroStake,
+
+        /// Application has reached the maximum number of endorsements.
+        MaxApplicationEndorsementsExceeded,
+
+        /// Cannot transfer the leader to another working group.
+        CannotTransferLead,
     }
 }
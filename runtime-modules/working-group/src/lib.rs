@@ -23,6 +23,18 @@
 //! - [set_status_text](./struct.Module.html#method.set_status_text) - Sets the working group status.
 //! - [spend_from_budget](./struct.Module.html#method.spend_from_budget) - Spend tokens from the group budget.
 //! - [fund_working_group_budget](./struct.Module.html#method.fund_working_group_budget) - Fund the group budget by a member.
+//! - [file_worker_review](./struct.Module.html#method.file_worker_review) - File a performance review for the regular worker/lead.
+//! - [contest_worker_review](./struct.Module.html#method.contest_worker_review) - Contest the performance review by the reviewed worker.
+//! - [resolve_worker_review_contest](./struct.Module.html#method.resolve_worker_review_contest) - Resolve the contested performance review by the council.
+//! - [appoint_deputy](./struct.Module.html#method.appoint_deputy) - Appoint the regular worker as the deputy performing the delegated lead actions.
+//! - [revoke_deputy](./struct.Module.html#method.revoke_deputy) - Revoke the deputy by the lead or the council.
+//! - [create_recurring_payment](./struct.Module.html#method.create_recurring_payment) - Create a recurring payment from the group budget.
+//! - [cancel_recurring_payment](./struct.Module.html#method.cancel_recurring_payment) - Cancel the recurring payment by the lead or the council.
+//! - [shortlist_applications](./struct.Module.html#method.shortlist_applications) - Shortlist the opening applications and drop the rest of them.
+//! - [endorse_application](./struct.Module.html#method.endorse_application) - Endorse the shortlisted application with the member stake.
+//! - [withdraw_application_endorsement](./struct.Module.html#method.withdraw_application_endorsement) - Withdraw the application endorsement and release its stake.
+//! - [start_application_selection](./struct.Module.html#method.start_application_selection) - End the endorsement stage and rank the shortlisted applications.
+//! - [set_worker_transfer_approval](./struct.Module.html#method.set_worker_transfer_approval) - Approve the regular worker transfer to another working group.
 
 // Ensure we're `no_std` when compiling for Wasm.
 #![cfg_attr(not(feature = "std"), no_std)]
@@ -56,6 +68,7 @@ pub mod benchmarking;
 
 mod checks;
 mod errors;
+pub mod migrations;
 #[cfg(test)]
 mod tests;
 mod types;
@@ -64,28 +77,32 @@ pub use weights::WeightInfo;
 
 use common::{costs::burn_from_usable, StakingAccountValidator};
 use frame_support::dispatch::RawOrigin;
-use frame_support::traits::{Currency, Get, LockIdentifier};
+use frame_support::traits::{Currency, Get, LockIdentifier, StorageVersion};
 use frame_support::weights::Weight;
-use frame_support::IterableStorageMap;
 use frame_support::{decl_event, decl_module, decl_storage, ensure, StorageValue};
+use frame_support::{IterableStorageDoubleMap, IterableStorageMap};
 use frame_system::{ensure_root, ensure_signed};
 use sp_runtime::traits::Convert;
 use sp_runtime::traits::{Hash, One, SaturatedConversion, Saturating, StaticLookup, Zero};
 use sp_std::borrow::ToOwned;
 use sp_std::collections::{btree_map::BTreeMap, btree_set::BTreeSet};
+use sp_std::convert::TryInto;
 use sp_std::{vec, vec::Vec};
 
 pub use errors::Error;
+use types::ApplicationInfo;
 pub use types::*;
-use types::{ApplicationInfo, WorkerInfo};
 
 use common::membership::MemberOriginValidator;
 use common::to_kb;
+use common::working_group::{TransferredWorker, WorkingGroup, WorkingGroupWorkerTransferHandler};
 use common::MemberId;
-use frame_support::dispatch::DispatchResult;
+use frame_support::dispatch::{DispatchError, DispatchResult, DispatchResultWithPostInfo};
 use staking_handler::StakingHandler;
 type Balances<T> = balances::Pallet<T>;
 
+const CURRENT_STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
 type WeightInfoWorkingGroup<T, I> = <T as Config<I>>::WeightInfo;
 type VestingInfoOf<T> =
     vesting::VestingInfo<VestingBalanceOf<T>, <T as frame_system::Config>::BlockNumber>;
@@ -127,6 +144,10 @@ pub trait Config<I: Instance = DefaultInstance>:
     /// Defines the period every worker gets paid in blocks.
     type RewardPeriod: Get<u32>;
 
+    /// Defines max number of workers rewarded in a single block. Reward payouts of the bigger
+    /// groups are spread over several blocks.
+    type MaxRewardPayoutsPerBlock: Get<u32>;
+
     /// Weight information for extrinsics in this pallet.
     type WeightInfo: WeightInfo;
 
@@ -135,6 +156,9 @@ pub trait Config<I: Instance = DefaultInstance>:
 
     /// Stake needed to create an opening
     type LeaderOpeningStake: Get<Self::Balance>;
+
+    /// Maximum number of the member endorsements of a shortlisted application.
+    type MaxApplicationEndorsements: Get<u32>;
 }
 
 decl_event!(
@@ -152,7 +176,12 @@ decl_event!(
        ApplyOnOpeningParameters = ApplyOnOpeningParameters<T>,
        MemberId = MemberId<T>,
        Hash = <T as frame_system::Config>::Hash,
-       VestingInfo = VestingInfoOf<T>
+       VestingInfo = VestingInfoOf<T>,
+       WorkerReviewId = WorkerReviewId,
+       DeputyPermissions = DeputyPermissions,
+       RecurringPaymentId = RecurringPaymentId,
+       RecurringPayment = RecurringPaymentOf<T>,
+       BlockNumber = <T as frame_system::Config>::BlockNumber,
     {
         /// Emits on adding new job opening.
         /// Params:
@@ -314,6 +343,135 @@ decl_event!(
         /// - worker
         /// - message
         WorkerRemarked(WorkerId, Vec<u8>),
+
+        /// Emits on filing the worker performance review.
+        /// Params:
+        /// - Worker id.
+        /// - Review id.
+        /// - Rating.
+        /// - Review metadata.
+        /// - Reward adjustment for the next reward payout.
+        WorkerReviewFiled(WorkerId, WorkerReviewId, u8, Vec<u8>, Option<RewardAdjustment>),
+
+        /// Emits on contesting the worker performance review.
+        /// Params:
+        /// - Worker id.
+        /// - Review id.
+        /// - Rationale.
+        WorkerReviewContested(WorkerId, WorkerReviewId, Vec<u8>),
+
+        /// Emits on resolving the contested worker performance review.
+        /// Params:
+        /// - Worker id.
+        /// - Review id.
+        /// - Whether the review was upheld.
+        WorkerReviewContestResolved(WorkerId, WorkerReviewId, bool),
+
+        /// Emits on appointing the deputy (or updating the deputy permissions).
+        /// Params:
+        /// - Deputy worker id.
+        /// - Delegated lead actions.
+        /// - Deputy spending cap.
+        DeputyAppointed(WorkerId, DeputyPermissions, Balance),
+
+        /// Emits on revoking the deputy.
+        /// Params:
+        /// - Deputy worker id.
+        DeputyRevoked(WorkerId),
+
+        /// Emits on performing the lead action by the deputy.
+        /// Params:
+        /// - Deputy worker id.
+        /// - Performed lead action.
+        DeputyActionPerformed(WorkerId, LeadActionPermission),
+
+        /// Emits on creating the recurring payment.
+        /// Params:
+        /// - Recurring payment id.
+        /// - Recurring payment.
+        /// - Rationale.
+        RecurringPaymentCreated(RecurringPaymentId, RecurringPayment, Option<Vec<u8>>),
+
+        /// Emits on paying the recurring payment.
+        /// Params:
+        /// - Recurring payment id.
+        /// - Recipient account id.
+        /// - Paid amount.
+        /// - Payment type (missed or regular).
+        RecurringPaymentPaid(RecurringPaymentId, AccountId, Balance, RewardPaymentType),
+
+        /// Emits on reaching new missed amount of the recurring payment.
+        /// Params:
+        /// - Recurring payment id.
+        /// - Missed amount (optional).
+        NewRecurringPaymentMissedAmountReached(RecurringPaymentId, Option<Balance>),
+
+        /// Emits on paying out the recurring payment completely.
+        /// Params:
+        /// - Recurring payment id.
+        RecurringPaymentCompleted(RecurringPaymentId),
+
+        /// Emits on canceling the recurring payment.
+        /// Params:
+        /// - Recurring payment id.
+        RecurringPaymentCanceled(RecurringPaymentId),
+
+        /// Emits on shortlisting the opening applications.
+        /// Params:
+        /// - Opening id.
+        /// - Shortlisted application ids.
+        /// - Block the endorsement stage ends at (optional).
+        ApplicationsShortlisted(OpeningId, BTreeSet<ApplicationId>, Option<BlockNumber>),
+
+        /// Emits on dropping the opening applications by the application screening.
+        /// The application stakes are released.
+        /// Params:
+        /// - Opening id.
+        /// - Dropped application ids.
+        ApplicationsDropped(OpeningId, BTreeSet<ApplicationId>),
+
+        /// Emits on endorsing the shortlisted application.
+        /// Params:
+        /// - Application id.
+        /// - Endorsing member id.
+        /// - Endorsement staking account id.
+        /// - Endorsement stake.
+        ApplicationEndorsed(ApplicationId, MemberId, AccountId, Balance),
+
+        /// Emits on withdrawing the application endorsement.
+        /// Params:
+        /// - Application id.
+        /// - Endorsing member id.
+        ApplicationEndorsementWithdrawn(ApplicationId, MemberId),
+
+        /// Emits on starting the selection stage of the application screening.
+        /// Params:
+        /// - Opening id.
+        /// - Shortlisted application ids with their endorsement stakes ranked by the stake.
+        ApplicationSelectionStarted(OpeningId, Vec<(ApplicationId, Balance)>),
+
+        /// Emits on updating the leader approval of the worker transfer.
+        /// Params:
+        /// - Worker id.
+        /// - Destination working group (none if the approval was revoked).
+        WorkerTransferApprovalSet(WorkerId, Option<WorkingGroup>),
+
+        /// Emits on transferring the worker to another working group. The stake stays locked.
+        /// Params:
+        /// - Worker id.
+        /// - Destination working group.
+        WorkerTransferredOut(WorkerId, WorkingGroup),
+
+        /// Emits on accepting the worker transferred from another working group.
+        /// Params:
+        /// - New worker id.
+        /// - Source working group.
+        WorkerTransferredIn(WorkerId, WorkingGroup),
+
+        /// Emits on delaying the reward payout round until the round in progress completes.
+        /// Params:
+        /// - Reward block of the delayed round.
+        RewardPayoutRoundDelayed(BlockNumber),
     }
 );
 
@@ -351,6 +509,83 @@ decl_storage! { generate_storage_info
 
         /// Status text hash.
         pub StatusTextHash get(fn status_text_hash) : T::Hash;
+
+        /// Worker reward payout round in progress.
+        pub CurrentRewardPayoutRound get(fn current_reward_payout_round) :
+            Option<RewardPayoutRoundOf<T>>;
+
+        /// Block the last worker reward payout round was started at.
+        pub LastRewardBlock get(fn last_reward_block) : Option<T::BlockNumber>;
+
+        /// Leaving workers indexed by the block their unstaking period ends at.
+        pub LeavingWorkers get(fn leaving_workers) : double_map
+            hasher(blake2_128_concat) T::BlockNumber,
+            hasher(blake2_128_concat) WorkerId<T> => ();
+
+        /// Next identifier for a new worker performance review.
+        pub NextWorkerReviewId get(fn next_worker_review_id) : WorkerReviewId;
+
+        /// Maps identifier to the worker performance review.
+        pub WorkerReviewById get(fn worker_review_by_id) : map hasher(blake2_128_concat)
+            WorkerReviewId => Option<WorkerReviewOf<T>>;
+
+        /// Reward adjustment of the latest worker performance review for the next reward payout.
+        pub PendingRewardAdjustment get(fn pending_reward_adjustment) : map
+            hasher(blake2_128_concat) WorkerId<T> => Option<(WorkerReviewId, RewardAdjustment)>;
+
+        /// Maps worker identifier to the deputy appointed by the lead.
+        pub DeputyById get(fn deputy_by_id) : map hasher(blake2_128_concat)
+            WorkerId<T> => Option<DeputyOf<T>>;
+
+        /// Maps the deputy role account to the deputy worker identifier.
+        pub DeputyIdByAccountId get(fn deputy_id_by_account_id) : map hasher(blake2_128_concat)
+            T::AccountId => Option<WorkerId<T>>;
+
+        /// Next identifier for a new recurring payment.
+        pub NextRecurringPaymentId get(fn next_recurring_payment_id) : RecurringPaymentId;
+
+        /// Maps identifier to the recurring payment.
+        pub RecurringPaymentById get(fn recurring_payment_by_id) : map hasher(blake2_128_concat)
+            RecurringPaymentId => Option<RecurringPaymentOf<T>>;
+
+        /// Applications of the opening. Used to drop the applications by the application
+        /// screening.
+        pub ApplicationIdsByOpeningId get(fn application_ids_by_opening_id) : double_map
+            hasher(blake2_128_concat) OpeningId,
+            hasher(blake2_128_concat) ApplicationId => ();
+
+        /// Application screening stage of the opening.
+        pub ApplicationScreeningStageByOpeningId get(fn application_screening_stage) :
+            map hasher(blake2_128_concat)
+            OpeningId => Option<ApplicationScreeningStage<T::BlockNumber>>;
+
+        /// Total endorsement stake of the shortlisted application.
+        pub ApplicationEndorsementStake get(fn application_endorsement_stake) :
+            map hasher(blake2_128_concat) ApplicationId => BalanceOf<T>;
+
+        /// Endorsements of the shortlisted applications by the members.
+        pub ApplicationEndorsementById get(fn application_endorsement_by_id) : double_map
+            hasher(blake2_128_concat) ApplicationId,
+            hasher(blake2_128_concat) MemberId<T> => Option<ApplicationEndorsementOf<T>>;
+
+        /// Number of the member endorsements of the shortlisted application.
+        pub ApplicationEndorsementCount get(fn application_endorsement_count) :
+            map hasher(blake2_128_concat) ApplicationId => u32;
+
+        /// Destination working group of the worker transfer approved by the leader.
+        pub WorkerTransferApprovalById get(fn worker_transfer_approval_by_id) :
+            map hasher(blake2_128_concat) WorkerId<T> => Option<WorkingGroup>;
+
+        /// Block the worker was transferred in from another working group at.
+        pub WorkerTransferredAt get(fn worker_transferred_at) :
+            map hasher(blake2_128_concat) WorkerId<T> => Option<T::BlockNumber>;
+
+        /// Reward block of the last reward payout round that rewarded the worker.
+        pub WorkerRewardedAt get(fn worker_rewarded_at) :
+            map hasher(blake2_128_concat) WorkerId<T> => Option<T::BlockNumber>;
+
+        /// Reward block of the reward payout round delayed by the round still in progress.
+        pub DelayedRewardBlock get(fn delayed_reward_block) : Option<T::BlockNumber>;
     }
 }
 
@@ -380,6 +615,12 @@ decl_module! {
         /// Defines the period every worker gets paid in blocks.
         const RewardPeriod: u32 = T::RewardPeriod::get();
 
+        /// Max number of workers rewarded in a single block.
+        const MaxRewardPayoutsPerBlock: u32 = T::MaxRewardPayoutsPerBlock::get();
+
+        /// Max number of the member endorsements of a shortlisted application.
+        const MaxApplicationEndorsements: u32 = T::MaxApplicationEndorsements::get();
+
         /// Staking handler lock id.
         const StakingHandlerLockId: LockIdentifier = T::StakingHandler::lock_id();
 
@@ -387,35 +628,22 @@ decl_module! {
         ///
         /// ## Weight
         /// `O (W)` where:
-        /// - `W` is the number of workers currently present in the `working_group`
+        /// - `W` is the number of processed workers: the workers with the unstaking period ending
+        ///   at the current block or the workers and recurring payments paid in the current block
+        ///   (limited by `MaxRewardPayoutsPerBlock`), whichever is bigger
         /// - DB:
         ///    - O(W)
         /// # </weight>
         fn on_initialize() -> Weight {
-            let leaving_workers = Self::get_workers_with_finished_unstaking_period();
-            let mut biggest_number_of_processed_workers = leaving_workers.len();
-
-            leaving_workers.iter().for_each(|wi| {
-                Self::remove_worker(
-                    &wi.worker_id,
-                    &wi.worker,
-                    RawEvent::WorkerExited(wi.worker_id)
-                );
-            });
+            let exited_workers = Self::remove_workers_with_finished_unstaking_period();
 
             if Self::is_reward_block() {
-                // We count the number of workers that will be processed to not be so pessimistic
-                // when calculating the weight for this function
-                let mut count_number_of_workers = 0;
-                WorkerById::<T, I>::iter().for_each(|(worker_id, worker)| {
-                    Self::reward_worker(&worker_id, &worker);
-                    count_number_of_workers = count_number_of_workers.saturating_add(1);
-                });
-
-                biggest_number_of_processed_workers = biggest_number_of_processed_workers.max(count_number_of_workers);
+                Self::start_reward_payout_round();
             }
 
-            Self::calculate_weight_on_initialize(biggest_number_of_processed_workers.saturated_into())
+            let payouts = Self::continue_reward_payout_round();
+
+            Self::calculate_weight_on_initialize(exited_workers.max(payouts))
         }
 
         /// Add a job opening for a regular worker/lead role.
@@ -514,6 +742,12 @@ decl_module! {
             // Ensure job opening exists.
             let opening = checks::ensure_opening_exists::<T, I>(p.opening_id)?;
 
+            // Applications are not accepted after the application screening start.
+            ensure!(
+                !<ApplicationScreeningStageByOpeningId<T, I>>::contains_key(p.opening_id),
+                Error::<T, I>::ApplicationScreeningAlreadyStarted
+            );
+
             // Ensure that proposed stake is enough for the opening.
             checks::ensure_application_stake_match_opening::<T, I>(&opening, &p.stake_parameters)?;
 
@@ -568,6 +802,7 @@ decl_module! {
 
             // Store an application.
             ApplicationById::<T, I>::insert(new_application_id, application);
+            ApplicationIdsByOpeningId::<I>::insert(p.opening_id, new_application_id, ());
 
             // Update the next application identifier value.
             NextApplicationId::<I>::mutate(|id| *id = updated_next_application_id);
@@ -578,29 +813,41 @@ decl_module! {
 
         /// Fill opening for the regular/lead position.
         /// Require signed leader origin or the root (to fill opening for the leader position).
+        /// The opening with the application screening can be filled only at the selection stage:
+        /// the shortlisted applications that weren't selected are dropped and the endorsement
+        /// stakes of all the shortlisted applications are released.
         /// # <weight>
         ///
         /// ## Weight
-        /// `O (A)` where:
-        /// - `A` is the length of `successful_application_ids`
+        /// `O (A + E)` where:
+        /// - `A` is the length of `successful_application_ids` or the number of the shortlisted
+        ///   applications for the opening with the application screening
+        /// - `E` is the number of the endorsements of the shortlisted applications
         /// - DB:
-        ///    - O(A)
+        ///    - O(A + E)
+        /// The weight of the screened opening with the maximum number of the endorsements is
+        /// charged upfront and the unused weight is refunded.
         /// # </weight>
         #[weight =
             WeightInfoWorkingGroup::<T, I>::fill_opening_worker(
                 successful_application_ids.len().saturated_into()
             )
             .max(WeightInfoWorkingGroup::<T, I>::fill_opening_lead())
+            .max(Module::<T, I>::max_fill_screened_opening_weight())
         ]
         pub fn fill_opening(
             origin,
             opening_id: OpeningId,
             successful_application_ids: BTreeSet<ApplicationId>,
-        ) {
+        ) -> DispatchResultWithPostInfo {
             // Ensure job opening exists.
             let opening = checks::ensure_opening_exists::<T, I>(opening_id)?;
 
-            checks::ensure_origin_for_opening_type::<T, I>(origin, opening.opening_type)?;
+            let deputy_id = checks::ensure_origin_for_opening_type_or_deputy::<T, I>(
+                origin,
+                opening.opening_type,
+                LeadActionPermission::FillOpenings,
+            )?;
 
             // Ensure we're not exceeding the maximum worker number.
             let potential_worker_number = Self::active_worker_count()
@@ -639,6 +886,31 @@ decl_module! {
                 );
             }
 
+            // Screened opening can be filled only at the selection stage.
+            let screening_stage = Self::application_screening_stage(opening_id);
+            if let Some(stage) = screening_stage {
+                ensure!(
+                    stage == ApplicationScreeningStage::Selection,
+                    Error::<T, I>::ApplicationSelectionNotStarted
+                );
+            }
+
+            let actual_weight = if screening_stage.is_some() {
+                let (application_count, endorsement_count) =
+                    Self::count_opening_applications_and_endorsements(opening_id);
+
+                WeightInfoWorkingGroup::<T, I>::fill_screened_opening(
+                    application_count,
+                    endorsement_count,
+                )
+            } else if opening.opening_type == OpeningType::Leader {
+                WeightInfoWorkingGroup::<T, I>::fill_opening_lead()
+            } else {
+                WeightInfoWorkingGroup::<T, I>::fill_opening_worker(
+                    successful_application_ids.len().saturated_into()
+                )
+            };
+
             //
             // == MUTATION SAFE ==
             //
@@ -669,6 +941,17 @@ decl_module! {
                     application_id_to_worker_id,
                     successful_application_ids
                 ));
+
+            // Release the stakes of the shortlisted applications that weren't selected.
+            if screening_stage.is_some() {
+                <ApplicationScreeningStageByOpeningId<T, I>>::remove(opening_id);
+
+                Self::drop_opening_applications(opening_id, &BTreeSet::new());
+            }
+
+            Self::deposit_deputy_action_event(deputy_id, LeadActionPermission::FillOpenings);
+
+            Ok(Some(actual_weight).into())
         }
 
         /// Update the associated role account of the active regular worker/lead.
@@ -695,10 +978,26 @@ decl_module! {
             // Ensure the worker is active.
             ensure!(!worker.is_leaving(), Error::<T, I>::WorkerIsLeaving);
 
+            let is_deputy = DeputyById::<T, I>::contains_key(worker_id);
+
+            // Ensure the deputy role account stays unique.
+            if is_deputy {
+                ensure!(
+                    Self::deputy_id_by_account_id(&new_role_account_id)
+                        .map_or(true, |deputy_id| deputy_id == worker_id),
+                    Error::<T, I>::DeputyRoleAccountAlreadyUsed
+                );
+            }
+
             //
             // == MUTATION SAFE ==
             //
 
+            if is_deputy {
+                DeputyIdByAccountId::<T, I>::remove(&worker.role_account_id);
+                DeputyIdByAccountId::<T, I>::insert(&new_role_account_id, worker_id);
+            }
+
             // Update role account
             WorkerById::<T, I>::insert(worker_id, Worker::<T> {
                 role_account_id: new_role_account_id.clone(),
@@ -734,8 +1033,19 @@ decl_module! {
             // == MUTATION SAFE ==
             //
 
+            let current_block = Self::current_block();
+
+            // Leaving worker cannot act as the deputy.
+            Self::remove_deputy(&worker_id, &worker.role_account_id);
+
+            LeavingWorkers::<T, I>::insert(
+                Self::unstaking_period_end(current_block, worker.job_unstaking_period),
+                worker_id,
+                ()
+            );
+
             WorkerById::<T, I>::insert(worker_id, Worker::<T> {
-                started_leaving_at: Some(Self::current_block()),
+                started_leaving_at: Some(current_block),
                 ..worker
             });
 
@@ -760,8 +1070,12 @@ decl_module! {
             penalty: Option<BalanceOf<T>>,
             rationale: Option<Vec<u8>>,
         ) {
-            // Ensure lead is set or it is the council terminating the leader.
-            let is_sudo = checks::ensure_origin_for_worker_operation::<T,I>(origin, worker_id)?;
+            // Ensure lead (or the deputy) is set or it is the council terminating the leader.
+            let (is_sudo, deputy_id) = checks::ensure_origin_for_worker_operation_or_deputy::<T,I>(
+                origin,
+                worker_id,
+                LeadActionPermission::TerminateWorkers,
+            )?;
 
             // Ensuring worker actually exists.
             let worker = checks::ensure_worker_exists::<T,I>(&worker_id)?;
@@ -782,6 +1096,8 @@ decl_module! {
             };
 
             Self::remove_worker(&worker_id, &worker, event);
+
+            Self::deposit_deputy_action_event(deputy_id, LeadActionPermission::TerminateWorkers);
         }
 
         /// Slashes the regular worker stake, demands a leader origin. No limits, no actions on zero stake.
@@ -802,8 +1118,12 @@ decl_module! {
             penalty: BalanceOf<T>,
             rationale: Option<Vec<u8>>
         ) {
-            // Ensure lead is set or it is the council slashing the leader.
-            checks::ensure_origin_for_worker_operation::<T,I>(origin, worker_id)?;
+            // Ensure lead (or the deputy) is set or it is the council slashing the leader.
+            let (_, deputy_id) = checks::ensure_origin_for_worker_operation_or_deputy::<T,I>(
+                origin,
+                worker_id,
+                LeadActionPermission::SlashWorkerStake,
+            )?;
 
             // Ensuring worker actually exists.
             let worker = checks::ensure_worker_exists::<T,I>(&worker_id)?;
@@ -817,7 +1137,9 @@ decl_module! {
             // == MUTATION SAFE ==
             //
 
-            Self::slash(worker_id, &worker.staking_account_id, penalty, rationale)
+            Self::slash(worker_id, &worker.staking_account_id, penalty, rationale);
+
+            Self::deposit_deputy_action_event(deputy_id, LeadActionPermission::SlashWorkerStake);
         }
 
         /// Decreases the regular worker/lead stake and returns the remainder to the
@@ -834,8 +1156,12 @@ decl_module! {
         /// # </weight>
         #[weight = WeightInfoWorkingGroup::<T, I>::decrease_stake()]
         pub fn decrease_stake(origin, worker_id: WorkerId<T>, stake_balance_delta: BalanceOf<T>) {
-            // Ensure lead is set or it is the council decreasing the leader's stake.
-            checks::ensure_origin_for_worker_operation::<T,I>(origin, worker_id)?;
+            // Ensure lead (or the deputy) is set or it is the council decreasing the leader's stake.
+            let (_, deputy_id) = checks::ensure_origin_for_worker_operation_or_deputy::<T,I>(
+                origin,
+                worker_id,
+                LeadActionPermission::DecreaseWorkerStake,
+            )?;
 
             let worker = checks::ensure_worker_exists::<T,I>(&worker_id)?;
 
@@ -869,6 +1195,8 @@ decl_module! {
             T::StakingHandler::set_stake(&worker.staking_account_id, new_stake)?;
 
             Self::deposit_event(RawEvent::StakeDecreased(worker_id, stake_balance_delta));
+
+            Self::deposit_deputy_action_event(deputy_id, LeadActionPermission::DecreaseWorkerStake);
         }
 
         /// Increases the regular worker/lead stake, demands a worker origin.
@@ -949,7 +1277,10 @@ decl_module! {
             T::StakingHandler::unlock(&application_info.application.staking_account_id);
 
             // Remove an application.
-            <ApplicationById<T, I>>::remove(application_info.application_id);
+            Self::remove_application(
+                application_info.application.opening_id,
+                application_info.application_id
+            );
 
             // Trigger event
             Self::deposit_event(RawEvent::ApplicationWithdrawn(application_id));
@@ -957,24 +1288,40 @@ decl_module! {
 
         /// Cancel an opening for the regular worker/lead position.
         /// Require signed leader origin or the root (to cancel opening for the leader position).
+        /// The endorsement stakes of the shortlisted applications are released.
         ///
         /// # <weight>
         ///
         /// ## Weight
-        /// `O (1)`
+        /// `O (E)` where:
+        /// - `E` is the number of the endorsements of the shortlisted applications
         /// - DB:
-        ///    - O(1) doesn't depend on the state or parameters
+        ///    - O(E)
+        /// The weight of the screened opening with the maximum number of the endorsements is
+        /// charged upfront and the unused weight is refunded.
         /// # </weight>
-        #[weight = WeightInfoWorkingGroup::<T, I>::cancel_opening()]
+        #[weight = WeightInfoWorkingGroup::<T, I>::cancel_opening()
+            .max(Module::<T, I>::max_cancel_screened_opening_weight())]
         pub fn cancel_opening(
             origin,
             opening_id: OpeningId,
-        ) {
+        ) -> DispatchResultWithPostInfo {
             // Ensure job opening exists.
             let opening = checks::ensure_opening_exists::<T, I>(opening_id)?;
 
             checks::ensure_origin_for_opening_type::<T, I>(origin, opening.opening_type)?;
 
+            let screening_stage = Self::application_screening_stage(opening_id);
+
+            let actual_weight = if screening_stage.is_some() {
+                let (_, endorsement_count) =
+                    Self::count_opening_applications_and_endorsements(opening_id);
+
+                WeightInfoWorkingGroup::<T, I>::cancel_screened_opening(endorsement_count)
+            } else {
+                WeightInfoWorkingGroup::<T, I>::cancel_opening()
+            };
+
             //
             // == MUTATION SAFE ==
             //
@@ -994,8 +1341,20 @@ decl_module! {
             // Remove the opening.
             <OpeningById::<T, I>>::remove(opening_id);
 
+            // Release the endorsement stakes of the shortlisted applications. The applications
+            // are kept to be withdrawn by the applicants.
+            if screening_stage.is_some() {
+                <ApplicationScreeningStageByOpeningId<T, I>>::remove(opening_id);
+
+                for (application_id, _) in ApplicationIdsByOpeningId::<I>::iter_prefix(opening_id) {
+                    Self::release_application_endorsements(application_id);
+                }
+            }
+
             // Trigger event
             Self::deposit_event(RawEvent::OpeningCanceled(opening_id));
+
+            Ok(Some(actual_weight).into())
         }
 
         /// Sets a new budget for the working group.
@@ -1077,8 +1436,12 @@ decl_module! {
             worker_id: WorkerId<T>,
             reward_per_block: Option<BalanceOf<T>>
         ) {
-            // Ensure lead is set or it is the council setting the leader's reward.
-            checks::ensure_origin_for_worker_operation::<T,I>(origin, worker_id)?;
+            // Ensure lead (or the deputy) is set or it is the council setting the leader's reward.
+            let (_, deputy_id) = checks::ensure_origin_for_worker_operation_or_deputy::<T,I>(
+                origin,
+                worker_id,
+                LeadActionPermission::UpdateWorkerReward,
+            )?;
 
             // Ensuring worker actually exists
             let worker = checks::ensure_worker_exists::<T,I>(&worker_id)?;
@@ -1095,6 +1458,8 @@ decl_module! {
 
             // Trigger event
             Self::deposit_event(RawEvent::WorkerRewardAmountUpdated(worker_id, reward_per_block));
+
+            Self::deposit_deputy_action_event(deputy_id, LeadActionPermission::UpdateWorkerReward);
         }
 
         /// Sets a new status text for the working group.
@@ -1146,11 +1511,16 @@ decl_module! {
             amount: BalanceOf<T>,
             rationale: Option<Vec<u8>>,
         ) {
-            // Ensure group leader privilege.
-            checks::ensure_origin_is_active_leader::<T,I>(origin)?;
+            // Ensure group leader (or the deputy) privilege.
+            let deputy_id = checks::ensure_origin_is_active_leader_or_deputy::<T,I>(
+                origin,
+                LeadActionPermission::SpendFromBudget,
+            )?;
 
             ensure!(amount > Zero::zero(), Error::<T, I>::CannotSpendZero);
 
+            checks::ensure_within_deputy_spending_cap::<T, I>(&deputy_id, amount)?;
+
             // Ensures that the budget is sufficient for the spending of specified amount
             let (_, potential_missed_payment) = Self::calculate_possible_payment(amount);
             ensure!(
@@ -1164,8 +1534,12 @@ decl_module! {
 
             Self::pay_from_budget(&account_id, amount);
 
+            Self::decrease_deputy_spending_cap(&deputy_id, amount);
+
             // Trigger event
             Self::deposit_event(RawEvent::BudgetSpending(account_id, amount, rationale));
+
+            Self::deposit_deputy_action_event(deputy_id, LeadActionPermission::SpendFromBudget);
         }
 
         /// Transfers specified amount to any account.
@@ -1187,11 +1561,16 @@ decl_module! {
         ) {
             let amount = T::VestingBalanceToBalance::convert(vesting_schedule.locked());
 
-            // Ensure group leader privilege.
-            checks::ensure_origin_is_active_leader::<T,I>(origin)?;
+            // Ensure group leader (or the deputy) privilege.
+            let deputy_id = checks::ensure_origin_is_active_leader_or_deputy::<T,I>(
+                origin,
+                LeadActionPermission::SpendFromBudget,
+            )?;
 
             ensure!(amount > Zero::zero(), Error::<T, I>::CannotSpendZero);
 
+            checks::ensure_within_deputy_spending_cap::<T, I>(&deputy_id, amount)?;
+
             // Ensures that the budget is sufficient for the spending of specified amount
             let (_, potential_missed_payment) = Self::calculate_possible_payment(amount);
             ensure!(
@@ -1210,8 +1589,13 @@ decl_module! {
                 <<T as frame_system::Config>::Lookup as StaticLookup>::unlookup(account_id.clone()),
                 vesting_schedule,
             )?;
+
+            Self::decrease_deputy_spending_cap(&deputy_id, amount);
+
             // Trigger event
             Self::deposit_event(RawEvent::VestedBudgetSpending(account_id, vesting_schedule, rationale));
+
+            Self::deposit_deputy_action_event(deputy_id, LeadActionPermission::SpendFromBudget);
         }
 
 
@@ -1299,105 +1683,790 @@ decl_module! {
             Self::deposit_event(RawEvent::WorkerRemarked(worker_id, msg));
         }
 
-    }
-}
-
-impl<T: Config<I>, I: Instance> Module<T, I> {
-    // Calculate weight for on_initialize
-    // We assume worst case scenario in a safe manner
-    // We take the most number of workers that will be processed and use it as input of the most costly function
-    fn calculate_weight_on_initialize(workers: u32) -> Weight {
-        WeightInfoWorkingGroup::<T, I>::on_initialize_rewarding_without_missing_reward(
-            workers,
-        )
-        .max(
-            WeightInfoWorkingGroup::<T, I>::on_initialize_rewarding_with_missing_reward_cant_pay(
-                workers,
-            ),
-        )
-        .max(
-            WeightInfoWorkingGroup::<T, I>::on_initialize_rewarding_with_missing_reward(
-                workers,
-            ),
-        )
-        .max(WeightInfoWorkingGroup::<T, I>::on_initialize_leaving(
-            workers,
-        ))
-    }
-
-    // Calculate weight for `leave_role`
-    fn leave_role_weight(rationale: &Option<Vec<u8>>) -> Weight {
-        WeightInfoWorkingGroup::<T, I>::leave_role(to_kb(
-            rationale
-                .as_ref()
-                .map(|rationale| rationale.len().saturated_into())
-                .unwrap_or_default(),
-        ))
-    }
+        /// Files a performance review for the worker. The optional reward adjustment applies to
+        /// the next reward payout of the worker and replaces the adjustment of the previous review.
+        /// Require signed leader origin or the root (to review the leader).
+        ///
+        /// # <weight>
+        ///
+        /// ## Weight
+        /// `O (M)` where:
+        /// - `M` is the size of `metadata` in kilobytes
+        /// - DB:
+        ///    - O(1) doesn't depend on the state or parameters
+        /// # </weight>
+        #[weight = WeightInfoWorkingGroup::<T, I>::file_worker_review(
+            to_kb(metadata.len().saturated_into())
+        )]
+        pub fn file_worker_review(
+            origin,
+            worker_id: WorkerId<T>,
+            rating: u8,
+            metadata: Vec<u8>,
+            reward_adjustment: Option<RewardAdjustment>,
+        ) {
+            // Ensure lead (or the deputy) is set or it is the council reviewing the leader.
+            let (_, deputy_id) = checks::ensure_origin_for_worker_operation_or_deputy::<T, I>(
+                origin,
+                worker_id,
+                LeadActionPermission::ReviewWorkers,
+            )?;
 
-    // Calculate weights for terminate_role
-    fn terminate_role_weight(penalty: &Option<Vec<u8>>) -> Weight {
-        WeightInfoWorkingGroup::<T, I>::terminate_role_lead(to_kb(
-            penalty
-                .as_ref()
-                .map(|penalty| penalty.len().saturated_into())
-                .unwrap_or_default(),
-        ))
-        .max(WeightInfoWorkingGroup::<T, I>::terminate_role_worker(
-            to_kb(
-                penalty
-                    .as_ref()
-                    .map(|penalty| penalty.len().saturated_into())
-                    .unwrap_or_default(),
-            ),
-        ))
-    }
+            checks::ensure_worker_exists::<T, I>(&worker_id)?;
 
-    // Calculates slash_stake weight
-    fn slash_stake_weight(rationale: &Option<Vec<u8>>) -> Weight {
-        WeightInfoWorkingGroup::<T, I>::slash_stake(to_kb(
-            rationale
-                .as_ref()
-                .map(|text| text.len().saturated_into())
-                .unwrap_or_default(),
-        ))
-    }
+            ensure!(
+                rating <= MAX_WORKER_REVIEW_RATING,
+                Error::<T, I>::InvalidWorkerReviewRating
+            );
 
-    // Wrapper-function over frame_system::block_number()
-    fn current_block() -> T::BlockNumber {
-        <frame_system::Pallet<T>>::block_number()
-    }
+            //
+            // == MUTATION SAFE ==
+            //
 
-    // Increases active worker counter (saturating).
-    fn increase_active_worker_counter() {
-        let next_active_worker_count_value = Self::active_worker_count().saturating_add(1);
-        <ActiveWorkerCount<I>>::put(next_active_worker_count_value);
-    }
+            let review_id = Self::next_worker_review_id();
 
-    // Decreases active worker counter (saturating).
-    fn decrease_active_worker_counter() {
-        let next_active_worker_count_value = Self::active_worker_count().saturating_sub(1);
-        <ActiveWorkerCount<I>>::put(next_active_worker_count_value);
-    }
+            WorkerReviewById::<T, I>::insert(review_id, WorkerReview {
+                worker_id,
+                filed_at: Self::current_block(),
+                rating,
+                metadata_hash: T::Hashing::hash(&metadata),
+                reward_adjustment,
+                status: WorkerReviewStatus::Filed,
+                withheld_reward: Zero::zero(),
+            });
 
-    // Processes successful application during the fill_opening().
-    fn fulfill_successful_applications(
-        opening: &OpeningOf<T>,
-        successful_applications_info: Vec<ApplicationInfo<T, I>>,
-    ) -> BTreeMap<ApplicationId, WorkerId<T>> {
-        let mut application_id_to_worker_id = BTreeMap::new();
+            NextWorkerReviewId::<I>::put(review_id.saturating_add(1));
 
-        successful_applications_info
-            .iter()
-            .for_each(|application_info| {
-                let new_worker_id = Self::create_worker_by_application(opening, application_info);
+            // The latest review defines the reward adjustment for the next reward payout.
+            if let Some(reward_adjustment) = reward_adjustment {
+                PendingRewardAdjustment::<T, I>::insert(worker_id, (review_id, reward_adjustment));
+            } else {
+                PendingRewardAdjustment::<T, I>::remove(worker_id);
+            }
 
-                application_id_to_worker_id.insert(application_info.application_id, new_worker_id);
+            // Trigger event
+            Self::deposit_event(RawEvent::WorkerReviewFiled(
+                worker_id,
+                review_id,
+                rating,
+                metadata,
+                reward_adjustment
+            ));
+
+            Self::deposit_deputy_action_event(deputy_id, LeadActionPermission::ReviewWorkers);
+        }
 
-                // Sets a leader on successful opening when opening is for leader.
-                if matches!(opening.opening_type, OpeningType::Leader) {
-                    Self::set_lead(new_worker_id);
-                }
+        /// Contests the performance review. The reward adjustment of the contested review
+        /// is suspended until the council resolves the contest.
+        /// Require signed worker origin.
+        ///
+        /// # <weight>
+        ///
+        /// ## Weight
+        /// `O (R)` where:
+        /// - `R` is the size of `rationale` in kilobytes
+        /// - DB:
+        ///    - O(1) doesn't depend on the state or parameters
+        /// # </weight>
+        #[weight = WeightInfoWorkingGroup::<T, I>::contest_worker_review(
+            to_kb(rationale.len().saturated_into())
+        )]
+        pub fn contest_worker_review(
+            origin,
+            worker_id: WorkerId<T>,
+            review_id: WorkerReviewId,
+            rationale: Vec<u8>,
+        ) {
+            checks::ensure_worker_signed::<T, I>(origin, &worker_id)?;
+
+            let review = checks::ensure_worker_review_exists::<T, I>(&worker_id, &review_id)?;
+
+            ensure!(
+                review.status == WorkerReviewStatus::Filed,
+                Error::<T, I>::WorkerReviewCannotBeContested
+            );
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            WorkerReviewById::<T, I>::insert(review_id, WorkerReview {
+                status: WorkerReviewStatus::Contested,
+                ..review
+            });
+
+            // Trigger event
+            Self::deposit_event(RawEvent::WorkerReviewContested(worker_id, review_id, rationale));
+        }
+
+        /// Resolves the contested performance review and removes it. The reward adjustment of
+        /// the overturned review is discarded and the reward already withheld by it is refunded
+        /// from the working group budget.
+        /// Requires root origin (the council).
+        ///
+        /// # <weight>
+        ///
+        /// ## Weight
+        /// `O (1)`
+        /// - DB:
+        ///    - O(1) doesn't depend on the state or parameters
+        /// # </weight>
+        #[weight = WeightInfoWorkingGroup::<T, I>::resolve_worker_review_contest()]
+        pub fn resolve_worker_review_contest(
+            origin,
+            worker_id: WorkerId<T>,
+            review_id: WorkerReviewId,
+            upheld: bool,
+        ) {
+            ensure_root(origin)?;
+
+            let review = checks::ensure_worker_review_exists::<T, I>(&worker_id, &review_id)?;
+
+            ensure!(
+                review.status == WorkerReviewStatus::Contested,
+                Error::<T, I>::WorkerReviewIsNotContested
+            );
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            WorkerReviewById::<T, I>::remove(review_id);
+
+            // The adjustment of the upheld review stays pending and applies to the next payout.
+            if !upheld {
+                if let Some((pending_review_id, _)) = Self::pending_reward_adjustment(worker_id) {
+                    if pending_review_id == review_id {
+                        PendingRewardAdjustment::<T, I>::remove(worker_id);
+                    }
+                }
+
+                Self::refund_withheld_reward(&worker_id, review.withheld_reward);
+            }
+
+            // Trigger event
+            Self::deposit_event(RawEvent::WorkerReviewContestResolved(
+                worker_id,
+                review_id,
+                upheld
+            ));
+        }
+
+        /// Appoints the regular worker as the deputy performing the provided lead actions
+        /// (or updates the permissions of the existing deputy). The deputy can spend the group
+        /// budget up to the provided spending cap.
+        /// Require signed leader origin.
+        ///
+        /// # <weight>
+        ///
+        /// ## Weight
+        /// `O (1)`
+        /// - DB:
+        ///    - O(1) doesn't depend on the state or parameters
+        /// # </weight>
+        #[weight = WeightInfoWorkingGroup::<T, I>::appoint_deputy()]
+        pub fn appoint_deputy(
+            origin,
+            worker_id: WorkerId<T>,
+            permissions: DeputyPermissions,
+            spending_cap: BalanceOf<T>,
+        ) {
+            // Ensure group leader privilege.
+            checks::ensure_origin_is_active_leader::<T, I>(origin)?;
+
+            ensure!(
+                Self::current_lead() != Some(worker_id),
+                Error::<T, I>::CannotAppointLeadAsDeputy
+            );
+
+            let worker = checks::ensure_worker_exists::<T, I>(&worker_id)?;
+
+            // Ensure the worker is active.
+            ensure!(!worker.is_leaving(), Error::<T, I>::WorkerIsLeaving);
+
+            // Ensure the deputy role account is not used by another deputy.
+            if let Some(deputy_id) = Self::deputy_id_by_account_id(&worker.role_account_id) {
+                ensure!(deputy_id == worker_id, Error::<T, I>::DeputyRoleAccountAlreadyUsed);
+            }
+
+            let stored_permissions: StoredDeputyPermissions = permissions
+                .clone()
+                .try_into()
+                .map_err(|_| Error::<T, I>::MaxNumberOfDeputyPermissionsExceeded)?;
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            DeputyById::<T, I>::insert(worker_id, Deputy {
+                permissions: stored_permissions,
+                spending_cap,
+            });
+
+            DeputyIdByAccountId::<T, I>::insert(&worker.role_account_id, worker_id);
+
+            // Trigger event
+            Self::deposit_event(RawEvent::DeputyAppointed(worker_id, permissions, spending_cap));
+        }
+
+        /// Revokes the deputy.
+        /// Require signed leader origin or the root (council proposal).
+        ///
+        /// # <weight>
+        ///
+        /// ## Weight
+        /// `O (1)`
+        /// - DB:
+        ///    - O(1) doesn't depend on the state or parameters
+        /// # </weight>
+        #[weight = WeightInfoWorkingGroup::<T, I>::revoke_deputy()]
+        pub fn revoke_deputy(origin, worker_id: WorkerId<T>) {
+            checks::ensure_origin_is_active_leader_or_root::<T, I>(origin)?;
+
+            checks::ensure_deputy_exists::<T, I>(&worker_id)?;
+
+            let worker = checks::ensure_worker_exists::<T, I>(&worker_id)?;
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            Self::remove_deputy(&worker_id, &worker.role_account_id);
+        }
+
+        /// Creates the recurring payment from the group budget. The payment is paid out in the
+        /// worker reward payout rounds: `amount_per_period` per reward period between
+        /// `start_block` and `end_block` but no more than the optional `total_cap` in total.
+        /// Require signed leader origin.
+        ///
+        /// # <weight>
+        ///
+        /// ## Weight
+        /// `O (R)` where:
+        /// - `R` is the size of `rationale` in kilobytes
+        /// - DB:
+        ///    - O(1) doesn't depend on the state or parameters
+        /// # </weight>
+        #[weight = WeightInfoWorkingGroup::<T, I>::create_recurring_payment(
+            to_kb(rationale.as_ref().map_or(0, |text| text.len()).saturated_into())
+        )]
+        pub fn create_recurring_payment(
+            origin,
+            recipient: T::AccountId,
+            amount_per_period: BalanceOf<T>,
+            start_block: T::BlockNumber,
+            end_block: T::BlockNumber,
+            total_cap: Option<BalanceOf<T>>,
+            rationale: Option<Vec<u8>>,
+        ) {
+            // Ensure group leader privilege.
+            checks::ensure_origin_is_active_leader::<T, I>(origin)?;
+
+            ensure!(amount_per_period > Zero::zero(), Error::<T, I>::CannotSpendZero);
+
+            if let Some(total_cap) = total_cap {
+                ensure!(total_cap > Zero::zero(), Error::<T, I>::CannotSpendZero);
+            }
+
+            ensure!(
+                end_block > start_block && end_block > Self::current_block(),
+                Error::<T, I>::InvalidRecurringPaymentPeriod
+            );
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            let recurring_payment_id = Self::next_recurring_payment_id();
+
+            let recurring_payment = RecurringPayment {
+                recipient,
+                amount_per_period,
+                start_block,
+                end_block,
+                total_cap,
+                accrued_amount: Zero::zero(),
+                missed_payment: None,
+            };
+
+            RecurringPaymentById::<T, I>::insert(recurring_payment_id, recurring_payment.clone());
+
+            NextRecurringPaymentId::<I>::put(recurring_payment_id.saturating_add(1));
+
+            // Trigger event
+            Self::deposit_event(RawEvent::RecurringPaymentCreated(
+                recurring_payment_id,
+                recurring_payment,
+                rationale
+            ));
+        }
+
+        /// Cancels the recurring payment. The missed payment is not paid out.
+        /// Require signed leader origin or the root (council proposal).
+        ///
+        /// # <weight>
+        ///
+        /// ## Weight
+        /// `O (1)`
+        /// - DB:
+        ///    - O(1) doesn't depend on the state or parameters
+        /// # </weight>
+        #[weight = WeightInfoWorkingGroup::<T, I>::cancel_recurring_payment()]
+        pub fn cancel_recurring_payment(origin, recurring_payment_id: RecurringPaymentId) {
+            checks::ensure_origin_is_active_leader_or_root::<T, I>(origin)?;
+
+            checks::ensure_recurring_payment_exists::<T, I>(&recurring_payment_id)?;
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            RecurringPaymentById::<T, I>::remove(recurring_payment_id);
+
+            // Trigger event
+            Self::deposit_event(RawEvent::RecurringPaymentCanceled(recurring_payment_id));
+        }
+
+        /// Shortlists the opening applications and starts the application screening: new
+        /// applications are not accepted and the applications that weren't shortlisted are
+        /// dropped releasing their stakes. The shortlisted applications can be endorsed by the
+        /// members for the optional `endorsement_period`, otherwise the selection stage starts
+        /// immediately.
+        /// Require signed leader origin or the root (to screen the applications for the leader
+        /// position).
+        ///
+        /// # <weight>
+        ///
+        /// ## Weight
+        /// `O (S + A)` where:
+        /// - `S` is the length of `shortlisted_application_ids`
+        /// - `A` is `application_count_witness` - the number of the opening applications
+        /// - DB:
+        ///    - O(S + A)
+        /// # </weight>
+        #[weight = WeightInfoWorkingGroup::<T, I>::shortlist_applications(
+            shortlisted_application_ids.len().saturated_into(),
+            *application_count_witness,
+        )]
+        pub fn shortlist_applications(
+            origin,
+            opening_id: OpeningId,
+            shortlisted_application_ids: BTreeSet<ApplicationId>,
+            endorsement_period: Option<T::BlockNumber>,
+            application_count_witness: u32,
+        ) {
+            // Ensure job opening exists.
+            let opening = checks::ensure_opening_exists::<T, I>(opening_id)?;
+
+            let deputy_id = checks::ensure_origin_for_opening_type_or_deputy::<T, I>(
+                origin,
+                opening.opening_type,
+                LeadActionPermission::FillOpenings,
+            )?;
+
+            ensure!(
+                !<ApplicationScreeningStageByOpeningId<T, I>>::contains_key(opening_id),
+                Error::<T, I>::ApplicationScreeningAlreadyStarted
+            );
+
+            ensure!(
+                shortlisted_application_ids.len() <=
+                    T::MaxWorkerNumberLimit::get().saturated_into::<usize>(),
+                Error::<T, I>::MaxShortlistedApplicationsExceeded
+            );
+
+            let shortlisted_applications_info =
+                checks::ensure_succesful_applications_exist::<T, I>(&shortlisted_application_ids)?;
+
+            // Check that all applications are for the intended opening
+            ensure!(
+                shortlisted_applications_info.iter()
+                .all(|info| info.application.opening_id == opening_id),
+                Error::<T, I>::ApplicationsNotForOpening
+            );
+
+            // Ensure the witness covers all the opening applications.
+            let application_count = ApplicationIdsByOpeningId::<I>::iter_prefix(opening_id)
+                .take(application_count_witness.saturating_add(1).saturated_into())
+                .count();
+
+            ensure!(
+                application_count <= application_count_witness.saturated_into::<usize>(),
+                Error::<T, I>::InvalidApplicationCountWitness
+            );
+
+            let endorsement_ends_at = match endorsement_period {
+                Some(endorsement_period) => {
+                    ensure!(
+                        !endorsement_period.is_zero(),
+                        Error::<T, I>::InvalidEndorsementPeriod
+                    );
+
+                    Some(Self::current_block().saturating_add(endorsement_period))
+                }
+                None => None,
+            };
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            for application_id in shortlisted_application_ids.iter() {
+                ApplicationIdsByOpeningId::<I>::insert(opening_id, application_id, ());
+            }
+
+            let stage = endorsement_ends_at.map_or(
+                ApplicationScreeningStage::Selection,
+                |ends_at| ApplicationScreeningStage::Endorsement { ends_at }
+            );
+
+            <ApplicationScreeningStageByOpeningId<T, I>>::insert(opening_id, stage);
+
+            // Trigger event
+            Self::deposit_event(RawEvent::ApplicationsShortlisted(
+                opening_id,
+                shortlisted_application_ids.clone(),
+                endorsement_ends_at,
+            ));
+
+            Self::drop_opening_applications(opening_id, &shortlisted_application_ids);
+
+            if stage == ApplicationScreeningStage::Selection {
+                Self::deposit_event(RawEvent::ApplicationSelectionStarted(
+                    opening_id,
+                    Self::rank_opening_applications(opening_id),
+                ));
+            }
+
+            Self::deposit_deputy_action_event(deputy_id, LeadActionPermission::FillOpenings);
+        }
+
+        /// Endorses the shortlisted application with the member stake during the endorsement
+        /// stage of the application screening. The stake is locked until the endorsement is
+        /// withdrawn.
+        ///
+        /// # <weight>
+        ///
+        /// ## Weight
+        /// `O (1)`
+        /// - DB:
+        ///    - O(1) doesn't depend on the state or parameters
+        /// # </weight>
+        #[weight = WeightInfoWorkingGroup::<T, I>::endorse_application()]
+        pub fn endorse_application(
+            origin,
+            member_id: MemberId<T>,
+            application_id: ApplicationId,
+            staking_account_id: T::AccountId,
+            stake: BalanceOf<T>,
+        ) {
+            // Ensure the origin of a member with given id.
+            T::MemberOriginValidator::ensure_member_controller_account_origin(origin, member_id)?;
+
+            let application_info = checks::ensure_application_exists::<T, I>(&application_id)?;
+
+            checks::ensure_application_endorsement_stage_is_active::<T, I>(
+                application_info.application.opening_id
+            )?;
+
+            ensure!(
+                !<ApplicationEndorsementById<T, I>>::contains_key(application_id, member_id),
+                Error::<T, I>::ApplicationAlreadyEndorsed
+            );
+
+            ensure!(stake > Zero::zero(), Error::<T, I>::CannotEndorseWithZeroStake);
+
+            ensure!(
+                Self::application_endorsement_count(application_id) <
+                    T::MaxApplicationEndorsements::get(),
+                Error::<T, I>::MaxApplicationEndorsementsExceeded
+            );
+
+            // Checks external conditions for staking.
+            ensure!(
+                T::StakingAccountValidator::is_member_staking_account(
+                    &member_id,
+                    &staking_account_id
+                ),
+                Error::<T, I>::InvalidStakingAccountForMember
+            );
+
+            ensure!(
+                T::StakingHandler::is_account_free_of_conflicting_stakes(&staking_account_id),
+                Error::<T, I>::ConflictStakesOnAccount
+            );
+
+            ensure!(
+                T::StakingHandler::is_enough_balance_for_stake(&staking_account_id, stake),
+                Error::<T, I>::InsufficientBalanceToCoverStake
+            );
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            T::StakingHandler::lock(&staking_account_id, stake);
+
+            <ApplicationEndorsementById<T, I>>::insert(
+                application_id,
+                member_id,
+                ApplicationEndorsement {
+                    staking_account_id: staking_account_id.clone(),
+                    stake,
+                }
+            );
+
+            <ApplicationEndorsementStake<T, I>>::mutate(application_id, |total_stake| {
+                *total_stake = total_stake.saturating_add(stake)
+            });
+
+            <ApplicationEndorsementCount<I>>::mutate(application_id, |count| {
+                *count = count.saturating_add(1)
+            });
+
+            // Trigger event
+            Self::deposit_event(RawEvent::ApplicationEndorsed(
+                application_id,
+                member_id,
+                staking_account_id,
+                stake,
+            ));
+        }
+
+        /// Withdraws the application endorsement and releases its stake. The endorsement can be
+        /// withdrawn at any stage.
+        ///
+        /// # <weight>
+        ///
+        /// ## Weight
+        /// `O (1)`
+        /// - DB:
+        ///    - O(1) doesn't depend on the state or parameters
+        /// # </weight>
+        #[weight = WeightInfoWorkingGroup::<T, I>::withdraw_application_endorsement()]
+        pub fn withdraw_application_endorsement(
+            origin,
+            member_id: MemberId<T>,
+            application_id: ApplicationId,
+        ) {
+            // Ensure the origin of a member with given id.
+            T::MemberOriginValidator::ensure_member_controller_account_origin(origin, member_id)?;
+
+            let endorsement =
+                checks::ensure_application_endorsement_exists::<T, I>(application_id, member_id)?;
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            T::StakingHandler::unlock(&endorsement.staking_account_id);
+
+            <ApplicationEndorsementById<T, I>>::remove(application_id, member_id);
+
+            <ApplicationEndorsementStake<T, I>>::mutate(application_id, |total_stake| {
+                *total_stake = total_stake.saturating_sub(endorsement.stake)
+            });
+
+            <ApplicationEndorsementCount<I>>::mutate(application_id, |count| {
+                *count = count.saturating_sub(1)
+            });
+
+            // Trigger event
+            Self::deposit_event(RawEvent::ApplicationEndorsementWithdrawn(
+                application_id,
+                member_id,
+            ));
+        }
+
+        /// Ends the endorsement stage of the application screening and starts the selection
+        /// stage. Emits the shortlisted applications ranked by their endorsement stakes.
+        /// Require signed leader origin or the root (for the leader position opening).
+        ///
+        /// # <weight>
+        ///
+        /// ## Weight
+        /// `O (S)` where:
+        /// - `S` is the number of the shortlisted applications (limited by the maximum worker
+        ///   number)
+        /// - DB:
+        ///    - O(S)
+        /// # </weight>
+        #[weight = WeightInfoWorkingGroup::<T, I>::start_application_selection(
+            T::MaxWorkerNumberLimit::get()
+        )]
+        pub fn start_application_selection(origin, opening_id: OpeningId) {
+            // Ensure job opening exists.
+            let opening = checks::ensure_opening_exists::<T, I>(opening_id)?;
+
+            let deputy_id = checks::ensure_origin_for_opening_type_or_deputy::<T, I>(
+                origin,
+                opening.opening_type,
+                LeadActionPermission::FillOpenings,
+            )?;
+
+            match Self::application_screening_stage(opening_id) {
+                Some(ApplicationScreeningStage::Endorsement { ends_at }) => ensure!(
+                    Self::current_block() >= ends_at,
+                    Error::<T, I>::ApplicationEndorsementStageNotEnded
+                ),
+                _ => return Err(Error::<T, I>::ApplicationEndorsementStageIsNotActive.into()),
+            }
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            <ApplicationScreeningStageByOpeningId<T, I>>::insert(
+                opening_id,
+                ApplicationScreeningStage::Selection
+            );
+
+            // Trigger event
+            Self::deposit_event(RawEvent::ApplicationSelectionStarted(
+                opening_id,
+                Self::rank_opening_applications(opening_id),
+            ));
+
+            Self::deposit_deputy_action_event(deputy_id, LeadActionPermission::FillOpenings);
+        }
+
+        /// Approves the regular worker transfer to the destination working group (or revokes the
+        /// approval). The transfer is completed by the destination group leader.
+        /// Require signed leader origin.
+        ///
+        /// # <weight>
+        ///
+        /// ## Weight
+        /// `O (1)`
+        /// - DB:
+        ///    - O(1) doesn't depend on the state or parameters
+        /// # </weight>
+        #[weight = WeightInfoWorkingGroup::<T, I>::set_worker_transfer_approval()]
+        pub fn set_worker_transfer_approval(
+            origin,
+            worker_id: WorkerId<T>,
+            destination: Option<WorkingGroup>,
+        ) {
+            // Ensure group leader privilege.
+            checks::ensure_origin_is_active_leader::<T, I>(origin)?;
+
+            if destination.is_some() {
+                Self::ensure_worker_can_be_transferred(&worker_id)?;
+            } else {
+                checks::ensure_worker_exists::<T, I>(&worker_id)?;
+            }
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            WorkerTransferApprovalById::<T, I>::set(worker_id, destination);
+
+            // Trigger event
+            Self::deposit_event(RawEvent::WorkerTransferApprovalSet(worker_id, destination));
+        }
+
+        type StorageVersion = CURRENT_STORAGE_VERSION;
+    }
+}
+
+impl<T: Config<I>, I: Instance> Module<T, I> {
+    // Calculate weight for on_initialize
+    // We assume worst case scenario in a safe manner
+    // We take the most number of workers that will be processed and use it as input of the most costly function
+    fn calculate_weight_on_initialize(workers: u32) -> Weight {
+        WeightInfoWorkingGroup::<T, I>::on_initialize_rewarding_without_missing_reward(
+            workers,
+        )
+        .max(
+            WeightInfoWorkingGroup::<T, I>::on_initialize_rewarding_with_missing_reward_cant_pay(
+                workers,
+            ),
+        )
+        .max(
+            WeightInfoWorkingGroup::<T, I>::on_initialize_rewarding_with_missing_reward(
+                workers,
+            ),
+        )
+        .max(WeightInfoWorkingGroup::<T, I>::on_initialize_leaving(
+            workers,
+        ))
+        .max(WeightInfoWorkingGroup::<T, I>::on_initialize_paying_recurring_payments(workers))
+    }
+
+    // Calculate weight for `leave_role`
+    fn leave_role_weight(rationale: &Option<Vec<u8>>) -> Weight {
+        WeightInfoWorkingGroup::<T, I>::leave_role(to_kb(
+            rationale
+                .as_ref()
+                .map(|rationale| rationale.len().saturated_into())
+                .unwrap_or_default(),
+        ))
+    }
+
+    // Calculate weights for terminate_role
+    fn terminate_role_weight(penalty: &Option<Vec<u8>>) -> Weight {
+        WeightInfoWorkingGroup::<T, I>::terminate_role_lead(to_kb(
+            penalty
+                .as_ref()
+                .map(|penalty| penalty.len().saturated_into())
+                .unwrap_or_default(),
+        ))
+        .max(WeightInfoWorkingGroup::<T, I>::terminate_role_worker(
+            to_kb(
+                penalty
+                    .as_ref()
+                    .map(|penalty| penalty.len().saturated_into())
+                    .unwrap_or_default(),
+            ),
+        ))
+    }
+
+    // Calculates slash_stake weight
+    fn slash_stake_weight(rationale: &Option<Vec<u8>>) -> Weight {
+        WeightInfoWorkingGroup::<T, I>::slash_stake(to_kb(
+            rationale
+                .as_ref()
+                .map(|text| text.len().saturated_into())
+                .unwrap_or_default(),
+        ))
+    }
+
+    // Wrapper-function over frame_system::block_number()
+    fn current_block() -> T::BlockNumber {
+        <frame_system::Pallet<T>>::block_number()
+    }
+
+    // Increases active worker counter (saturating).
+    fn increase_active_worker_counter() {
+        let next_active_worker_count_value = Self::active_worker_count().saturating_add(1);
+        <ActiveWorkerCount<I>>::put(next_active_worker_count_value);
+    }
+
+    // Decreases active worker counter (saturating).
+    fn decrease_active_worker_counter() {
+        let next_active_worker_count_value = Self::active_worker_count().saturating_sub(1);
+        <ActiveWorkerCount<I>>::put(next_active_worker_count_value);
+    }
+
+    // Processes successful application during the fill_opening().
+    fn fulfill_successful_applications(
+        opening: &OpeningOf<T>,
+        successful_applications_info: Vec<ApplicationInfo<T, I>>,
+    ) -> BTreeMap<ApplicationId, WorkerId<T>> {
+        let mut application_id_to_worker_id = BTreeMap::new();
+
+        successful_applications_info
+            .iter()
+            .for_each(|application_info| {
+                let new_worker_id = Self::create_worker_by_application(opening, application_info);
+
+                application_id_to_worker_id.insert(application_info.application_id, new_worker_id);
+
+                // Sets a leader on successful opening when opening is for leader.
+                if matches!(opening.opening_type, OpeningType::Leader) {
+                    Self::set_lead(new_worker_id);
+                }
             });
 
         application_id_to_worker_id
@@ -1430,11 +2499,111 @@ impl<T: Config<I>, I: Instance> Module<T, I> {
         <NextWorkerId<T, I>>::mutate(|id| *id += <WorkerId<T> as One>::one());
 
         // Remove an application.
-        <ApplicationById<T, I>>::remove(application_info.application_id);
+        Self::remove_application(
+            application_info.application.opening_id,
+            application_info.application_id,
+        );
 
         new_worker_id
     }
 
+    // Removes the application with its index and endorsements releasing the endorsement stakes.
+    fn remove_application(opening_id: OpeningId, application_id: ApplicationId) {
+        <ApplicationById<T, I>>::remove(application_id);
+        ApplicationIdsByOpeningId::<I>::remove(opening_id, application_id);
+        Self::release_application_endorsements(application_id);
+    }
+
+    // Releases the endorsement stakes of the application and removes its endorsements.
+    fn release_application_endorsements(application_id: ApplicationId) {
+        for (member_id, endorsement) in
+            <ApplicationEndorsementById<T, I>>::drain_prefix(application_id)
+        {
+            T::StakingHandler::unlock(&endorsement.staking_account_id);
+
+            Self::deposit_event(RawEvent::ApplicationEndorsementWithdrawn(
+                application_id,
+                member_id,
+            ));
+        }
+
+        <ApplicationEndorsementStake<T, I>>::remove(application_id);
+        <ApplicationEndorsementCount<I>>::remove(application_id);
+    }
+
+    // Counts the applications of the opening and their endorsements.
+    fn count_opening_applications_and_endorsements(opening_id: OpeningId) -> (u32, u32) {
+        ApplicationIdsByOpeningId::<I>::iter_prefix(opening_id).fold(
+            (0u32, 0u32),
+            |(application_count, endorsement_count), (application_id, _)| {
+                (
+                    application_count.saturating_add(1),
+                    endorsement_count
+                        .saturating_add(Self::application_endorsement_count(application_id)),
+                )
+            },
+        )
+    }
+
+    // Weight of filling the opening with the maximum number of the shortlisted applications
+    // and endorsements.
+    fn max_fill_screened_opening_weight() -> Weight {
+        let max_applications = T::MaxWorkerNumberLimit::get();
+
+        WeightInfoWorkingGroup::<T, I>::fill_screened_opening(
+            max_applications,
+            max_applications.saturating_mul(T::MaxApplicationEndorsements::get()),
+        )
+    }
+
+    // Weight of cancelling the opening with the maximum number of the shortlisted applications
+    // and endorsements.
+    fn max_cancel_screened_opening_weight() -> Weight {
+        WeightInfoWorkingGroup::<T, I>::cancel_screened_opening(
+            T::MaxWorkerNumberLimit::get().saturating_mul(T::MaxApplicationEndorsements::get()),
+        )
+    }
+
+    // Drops the opening applications except the kept ones releasing their stakes.
+    fn drop_opening_applications(
+        opening_id: OpeningId,
+        kept_application_ids: &BTreeSet<ApplicationId>,
+    ) {
+        let dropped_application_ids = ApplicationIdsByOpeningId::<I>::iter_prefix(opening_id)
+            .map(|(application_id, _)| application_id)
+            .filter(|application_id| !kept_application_ids.contains(application_id))
+            .collect::<BTreeSet<_>>();
+
+        for application_id in dropped_application_ids.iter() {
+            if let Some(application) = Self::application_by_id(application_id) {
+                T::StakingHandler::unlock(&application.staking_account_id);
+            }
+
+            Self::remove_application(opening_id, *application_id);
+        }
+
+        Self::deposit_event(RawEvent::ApplicationsDropped(
+            opening_id,
+            dropped_application_ids,
+        ));
+    }
+
+    // Ranks the opening applications by their endorsement stakes (descending).
+    fn rank_opening_applications(opening_id: OpeningId) -> Vec<(ApplicationId, BalanceOf<T>)> {
+        let mut ranking = ApplicationIdsByOpeningId::<I>::iter_prefix(opening_id)
+            .map(|(application_id, _)| {
+                (
+                    application_id,
+                    Self::application_endorsement_stake(application_id),
+                )
+            })
+            .collect::<Vec<_>>();
+
+        ranking.sort_by(|(id1, stake1), (id2, stake2)| stake2.cmp(stake1).then(id1.cmp(id2)));
+
+        ranking
+    }
+
     // Set worker id as a leader id.
     pub(crate) fn set_lead(worker_id: WorkerId<T>) {
         // Update current lead
@@ -1465,10 +2634,25 @@ impl<T: Config<I>, I: Instance> Module<T, I> {
             }
         }
 
-        Self::try_to_pay_missed_reward(worker_id, worker);
+        Self::reward_leaving_worker(worker_id, worker);
+
+        // Remove the worker from the exit queue.
+        if let Some(started_leaving_at) = worker.started_leaving_at {
+            LeavingWorkers::<T, I>::remove(
+                Self::unstaking_period_end(started_leaving_at, worker.job_unstaking_period),
+                worker_id,
+            );
+        }
+
+        PendingRewardAdjustment::<T, I>::remove(worker_id);
+        WorkerTransferApprovalById::<T, I>::remove(worker_id);
+
+        Self::remove_deputy(worker_id, &worker.role_account_id);
 
         // Remove the worker from the storage.
         WorkerById::<T, I>::remove(worker_id);
+        WorkerTransferredAt::<T, I>::remove(worker_id);
+        WorkerRewardedAt::<T, I>::remove(worker_id);
         Self::decrease_active_worker_counter();
 
         T::StakingHandler::unlock(&worker.staking_account_id);
@@ -1492,23 +2676,22 @@ impl<T: Config<I>, I: Instance> Module<T, I> {
         ));
     }
 
-    // Reward a worker using reward presets and working group budget.
-    fn reward_worker(worker_id: &WorkerId<T>, worker: &Worker<T>) {
-        // If reward period is not set.
-        let mut rewarding_period: u32 = T::RewardPeriod::get();
-        if rewarding_period == 0u32 {
-            rewarding_period = One::one();
-        }
+    // Reward a worker for the payout round period using reward presets and working group budget.
+    fn reward_worker(worker_id: &WorkerId<T>, worker: &Worker<T>, round: &RewardPayoutRoundOf<T>) {
+        WorkerRewardedAt::<T, I>::insert(worker_id, round.reward_block);
 
-        // Modify rewarding period for new workers.
-        let block_from_worker_creation: u128 =
-            (Self::current_block() - worker.created_at).saturated_into();
-        if block_from_worker_creation < rewarding_period.into() {
-            rewarding_period = block_from_worker_creation.saturated_into();
-        }
+        // Modify rewarding period for new and transferred workers.
+        let employed_since = Self::employed_since(worker_id, worker);
+        let rewarding_period: u32 = round
+            .reward_block
+            .saturating_sub(round.rewarded_since.max(employed_since))
+            .saturated_into();
 
         if let Some(reward_per_block) = worker.reward_per_block {
-            let reward = reward_per_block * rewarding_period.into();
+            let reward = Self::apply_pending_reward_adjustment(
+                worker_id,
+                reward_per_block.saturating_mul(rewarding_period.into()),
+            );
 
             let (actual_reward, missed_reward) = Self::calculate_possible_payment(reward);
 
@@ -1531,6 +2714,375 @@ impl<T: Config<I>, I: Instance> Module<T, I> {
         }
     }
 
+    // Block the worker is employed in the group since: hired or transferred in.
+    fn employed_since(worker_id: &WorkerId<T>, worker: &Worker<T>) -> T::BlockNumber {
+        Self::worker_transferred_at(worker_id).unwrap_or(worker.created_at)
+    }
+
+    // Defines whether the reward payout round owes the worker a reward: the worker was employed
+    // before the round reward block and wasn't rewarded by the round yet.
+    fn is_due_round_reward(
+        worker_id: &WorkerId<T>,
+        worker: &Worker<T>,
+        round: &RewardPayoutRoundOf<T>,
+    ) -> bool {
+        Self::employed_since(worker_id, worker) < round.reward_block
+            && Self::worker_rewarded_at(worker_id) != Some(round.reward_block)
+    }
+
+    // Pays the worker leaving the group the reward owed by the reward payout round in progress
+    // along with the missed reward.
+    fn reward_leaving_worker(worker_id: &WorkerId<T>, worker: &Worker<T>) {
+        if let Some(round) = Self::current_reward_payout_round() {
+            if worker.reward_per_block.is_some()
+                && Self::is_due_round_reward(worker_id, worker, &round)
+            {
+                // Pays the missed reward as well unless the budget is insufficient.
+                Self::reward_worker(worker_id, worker, &round);
+                return;
+            }
+        }
+
+        Self::try_to_pay_missed_reward(worker_id, worker);
+    }
+
+    // Pays the worker transferred out of the group the reward accrued since the last reward
+    // payout round block (or the employment block) up to the current block. The part the group
+    // budget can't cover is lost along with the worker record.
+    fn reward_transferred_worker(worker_id: &WorkerId<T>, worker: &Worker<T>) {
+        if let Some(reward_per_block) = worker.reward_per_block {
+            let employed_since = Self::employed_since(worker_id, worker);
+            let rewarded_since = Self::last_reward_block()
+                .map_or(employed_since, |last_reward_block| {
+                    last_reward_block.max(employed_since)
+                });
+            let rewarding_period: u32 = Self::current_block()
+                .saturating_sub(rewarded_since)
+                .saturated_into();
+
+            let reward = Self::apply_pending_reward_adjustment(
+                worker_id,
+                reward_per_block.saturating_mul(rewarding_period.into()),
+            );
+
+            let (actual_reward, _) = Self::calculate_possible_payment(reward);
+
+            if actual_reward > Zero::zero() {
+                Self::pay_reward(
+                    worker_id,
+                    &worker.reward_account_id,
+                    actual_reward,
+                    RewardPaymentType::RegularReward,
+                );
+            }
+        }
+    }
+
+    // Revokes the deputy if the worker was appointed as the one. Deposits an event.
+    fn remove_deputy(worker_id: &WorkerId<T>, role_account_id: &T::AccountId) {
+        if DeputyById::<T, I>::take(worker_id).is_some() {
+            DeputyIdByAccountId::<T, I>::remove(role_account_id);
+
+            Self::deposit_event(RawEvent::DeputyRevoked(*worker_id));
+        }
+    }
+
+    // Decreases the deputy spending cap by the spent amount.
+    fn decrease_deputy_spending_cap(deputy_id: &Option<WorkerId<T>>, amount: BalanceOf<T>) {
+        if let Some(deputy_id) = deputy_id {
+            DeputyById::<T, I>::mutate(deputy_id, |deputy| {
+                if let Some(deputy) = deputy {
+                    deputy.spending_cap = deputy.spending_cap.saturating_sub(amount);
+                }
+            });
+        }
+    }
+
+    // Attributes the lead action to the deputy that performed it.
+    fn deposit_deputy_action_event(
+        deputy_id: Option<WorkerId<T>>,
+        permission: LeadActionPermission,
+    ) {
+        if let Some(deputy_id) = deputy_id {
+            Self::deposit_event(RawEvent::DeputyActionPerformed(deputy_id, permission));
+        }
+    }
+
+    // Applies the reward adjustment of the latest worker performance review to the reward.
+    // The adjustment of the contested review stays pending until the contest is resolved.
+    // The reward withheld by the penalty is saved to the review for the possible refund.
+    fn apply_pending_reward_adjustment(
+        worker_id: &WorkerId<T>,
+        reward: BalanceOf<T>,
+    ) -> BalanceOf<T> {
+        let (review_id, adjustment) = match Self::pending_reward_adjustment(worker_id) {
+            Some(pending) => pending,
+            None => return reward,
+        };
+
+        let review = Self::worker_review_by_id(review_id);
+
+        if let Some(WorkerReview {
+            status: WorkerReviewStatus::Contested,
+            ..
+        }) = review
+        {
+            return reward;
+        }
+
+        PendingRewardAdjustment::<T, I>::remove(worker_id);
+
+        let adjusted_reward = adjustment.apply(reward);
+
+        if review.is_some() && adjusted_reward < reward {
+            WorkerReviewById::<T, I>::mutate(review_id, |review| {
+                if let Some(review) = review {
+                    review.withheld_reward = review
+                        .withheld_reward
+                        .saturating_add(reward.saturating_sub(adjusted_reward));
+                }
+            });
+        }
+
+        adjusted_reward
+    }
+
+    // Refunds the reward withheld by the overturned performance review from the group budget.
+    // Saves the refund as the missed reward when the budget is insufficient.
+    fn refund_withheld_reward(worker_id: &WorkerId<T>, withheld_reward: BalanceOf<T>) {
+        if withheld_reward.is_zero() {
+            return;
+        }
+
+        if let Some(worker) = Self::worker_by_id(worker_id) {
+            let (refund, missed_refund) = Self::calculate_possible_payment(withheld_reward);
+
+            if refund > Zero::zero() {
+                Self::pay_reward(
+                    worker_id,
+                    &worker.reward_account_id,
+                    refund,
+                    RewardPaymentType::MissedReward,
+                );
+            }
+
+            if missed_refund > Zero::zero() {
+                Self::save_missed_reward(worker_id, &worker, missed_refund);
+            }
+        }
+    }
+
+    // Starts a new reward payout round. The round is delayed until the previous one completes
+    // when the previous one is still in progress.
+    fn start_reward_payout_round() {
+        let current_block = Self::current_block();
+
+        if <CurrentRewardPayoutRound<T, I>>::exists() {
+            <DelayedRewardBlock<T, I>>::put(current_block);
+
+            Self::deposit_event(RawEvent::RewardPayoutRoundDelayed(current_block));
+
+            return;
+        }
+
+        Self::start_reward_payout_round_at(current_block);
+    }
+
+    // Starts the reward payout round for the reward block. The rewarded period of the round starts
+    // at the previous reward payout round block.
+    fn start_reward_payout_round_at(reward_block: T::BlockNumber) {
+        let rewarded_since = Self::last_reward_block()
+            .unwrap_or_else(|| reward_block.saturating_sub(Self::reward_period().into()));
+
+        <LastRewardBlock<T, I>>::put(reward_block);
+        <CurrentRewardPayoutRound<T, I>>::put(RewardPayoutRound {
+            reward_block,
+            rewarded_since,
+            last_rewarded_worker_id: None,
+            workers_rewarded: false,
+            last_paid_recurring_payment_id: None,
+        });
+    }
+
+    // Rewards the next batch of workers (followed by the recurring payments) of the reward payout
+    // round in progress. Returns the number of payouts.
+    fn continue_reward_payout_round() -> u32 {
+        let mut round = match Self::current_reward_payout_round() {
+            Some(round) => round,
+            None => return 0,
+        };
+
+        let max_payouts = T::MaxRewardPayoutsPerBlock::get().max(One::one());
+        let mut payouts = 0u32;
+
+        if !round.workers_rewarded {
+            let mut workers = match round.last_rewarded_worker_id {
+                Some(last_rewarded_worker_id) => WorkerById::<T, I>::iter_from(
+                    WorkerById::<T, I>::hashed_key_for(last_rewarded_worker_id),
+                ),
+                None => WorkerById::<T, I>::iter(),
+            };
+
+            while payouts < max_payouts {
+                match workers.next() {
+                    Some((worker_id, worker)) => {
+                        // Skips the workers hired or transferred in during the round and the
+                        // workers already rewarded on leaving the group.
+                        if Self::is_due_round_reward(&worker_id, &worker, &round) {
+                            Self::reward_worker(&worker_id, &worker, &round);
+                        }
+                        round.last_rewarded_worker_id = Some(worker_id);
+                        payouts = payouts.saturating_add(1);
+                    }
+                    None => {
+                        round.workers_rewarded = true;
+                        break;
+                    }
+                }
+            }
+        }
+
+        if round.workers_rewarded {
+            let mut recurring_payments = match round.last_paid_recurring_payment_id {
+                Some(last_paid_recurring_payment_id) => RecurringPaymentById::<T, I>::iter_from(
+                    RecurringPaymentById::<T, I>::hashed_key_for(last_paid_recurring_payment_id),
+                ),
+                None => RecurringPaymentById::<T, I>::iter(),
+            };
+
+            while payouts < max_payouts {
+                match recurring_payments.next() {
+                    Some((recurring_payment_id, recurring_payment)) => {
+                        Self::pay_recurring_payment(
+                            recurring_payment_id,
+                            recurring_payment,
+                            &round,
+                        );
+                        round.last_paid_recurring_payment_id = Some(recurring_payment_id);
+                        payouts = payouts.saturating_add(1);
+                    }
+                    None => {
+                        <CurrentRewardPayoutRound<T, I>>::kill();
+
+                        // The delayed round continues the payouts on the next block.
+                        if let Some(delayed_reward_block) = <DelayedRewardBlock<T, I>>::take() {
+                            Self::start_reward_payout_round_at(delayed_reward_block);
+                        }
+
+                        return payouts;
+                    }
+                }
+            }
+        }
+
+        <CurrentRewardPayoutRound<T, I>>::put(round);
+
+        payouts
+    }
+
+    // Pays the recurring payment for the payout round period using the working group budget.
+    // Removes the payment when it's paid out completely.
+    fn pay_recurring_payment(
+        recurring_payment_id: RecurringPaymentId,
+        mut recurring_payment: RecurringPaymentOf<T>,
+        round: &RewardPayoutRoundOf<T>,
+    ) {
+        // Only the part of the payout round period between the start and end blocks is paid.
+        let paid_since = round.rewarded_since.max(recurring_payment.start_block);
+        let paid_until = round.reward_block.min(recurring_payment.end_block);
+        let paid_period: u32 = paid_until.saturating_sub(paid_since).saturated_into();
+
+        let mut payment = recurring_payment
+            .amount_per_period
+            .saturating_mul(paid_period.into())
+            .checked_div(&Self::reward_period().into())
+            .unwrap_or_else(Zero::zero);
+
+        if let Some(total_cap) = recurring_payment.total_cap {
+            payment = payment.min(total_cap.saturating_sub(recurring_payment.accrued_amount));
+        }
+
+        recurring_payment.accrued_amount = recurring_payment.accrued_amount.saturating_add(payment);
+
+        let (actual_payment, missed_payment) = Self::calculate_possible_payment(payment);
+
+        // Check whether the budget is not zero.
+        if actual_payment > Zero::zero() {
+            Self::pay_recurring_payment_amount(
+                recurring_payment_id,
+                &recurring_payment.recipient,
+                actual_payment,
+                RewardPaymentType::RegularReward,
+            );
+        }
+
+        // Check whether the budget is insufficient.
+        if missed_payment > Zero::zero() {
+            let new_missed_payment = recurring_payment
+                .missed_payment
+                .map_or(missed_payment, |val| val.saturating_add(missed_payment));
+
+            recurring_payment.missed_payment = Some(new_missed_payment);
+
+            Self::deposit_event(RawEvent::NewRecurringPaymentMissedAmountReached(
+                recurring_payment_id,
+                recurring_payment.missed_payment,
+            ));
+        } else if let Some(missed_payment) = recurring_payment.missed_payment {
+            let (could_be_paid, insufficient_amount) =
+                Self::calculate_possible_payment(missed_payment);
+
+            // Checks if the budget allows any payment.
+            if could_be_paid > Zero::zero() {
+                Self::pay_recurring_payment_amount(
+                    recurring_payment_id,
+                    &recurring_payment.recipient,
+                    could_be_paid,
+                    RewardPaymentType::MissedReward,
+                );
+
+                recurring_payment.missed_payment = if insufficient_amount > Zero::zero() {
+                    Some(insufficient_amount)
+                } else {
+                    None
+                };
+
+                Self::deposit_event(RawEvent::NewRecurringPaymentMissedAmountReached(
+                    recurring_payment_id,
+                    recurring_payment.missed_payment,
+                ));
+            }
+        }
+
+        let is_paid_out = (round.reward_block >= recurring_payment.end_block
+            || recurring_payment.is_cap_reached())
+            && recurring_payment.missed_payment.is_none();
+
+        if is_paid_out {
+            RecurringPaymentById::<T, I>::remove(recurring_payment_id);
+
+            Self::deposit_event(RawEvent::RecurringPaymentCompleted(recurring_payment_id));
+        } else {
+            RecurringPaymentById::<T, I>::insert(recurring_payment_id, recurring_payment);
+        }
+    }
+
+    // Helper-function joining the recurring payment with the event.
+    fn pay_recurring_payment_amount(
+        recurring_payment_id: RecurringPaymentId,
+        account_id: &T::AccountId,
+        amount: BalanceOf<T>,
+        payment_type: RewardPaymentType,
+    ) {
+        Self::pay_from_budget(account_id, amount);
+        Self::deposit_event(RawEvent::RecurringPaymentPaid(
+            recurring_payment_id,
+            account_id.clone(),
+            amount,
+            payment_type,
+        ));
+    }
+
     // Transfers the tokens if budget is sufficient. Infallible!
     // Should be accompanied with previous budget check.
     fn pay_from_budget(account_id: &T::AccountId, amount: BalanceOf<T>) {
@@ -1625,19 +3177,33 @@ impl<T: Config<I>, I: Instance> Module<T, I> {
         }
     }
 
-    // Returns a collection of workers with finished unstaking period.
-    fn get_workers_with_finished_unstaking_period() -> Vec<WorkerInfo<T>> {
-        WorkerById::<T, I>::iter()
-            .filter_map(|(worker_id, worker)| {
-                if let Some(started_leaving_at) = worker.started_leaving_at {
-                    if started_leaving_at + worker.job_unstaking_period <= Self::current_block() {
-                        return Some((worker_id, worker).into());
-                    }
-                }
+    // Removes the workers with the unstaking period ending at the current block.
+    // Returns the number of removed workers.
+    fn remove_workers_with_finished_unstaking_period() -> u32 {
+        let mut exited_workers = 0u32;
 
-                None
-            })
-            .collect::<Vec<_>>()
+        for (worker_id, _) in LeavingWorkers::<T, I>::drain_prefix(Self::current_block()) {
+            if let Some(worker) = Self::worker_by_id(worker_id) {
+                Self::remove_worker(&worker_id, &worker, RawEvent::WorkerExited(worker_id));
+                exited_workers = exited_workers.saturating_add(1);
+            }
+        }
+
+        exited_workers
+    }
+
+    // Returns the block the unstaking period of a worker leaving at the provided block ends at.
+    // Workers leave at the earliest on the next block.
+    fn unstaking_period_end(
+        started_leaving_at: T::BlockNumber,
+        job_unstaking_period: T::BlockNumber,
+    ) -> T::BlockNumber {
+        started_leaving_at.saturating_add(job_unstaking_period.max(One::one()))
+    }
+
+    // Reward period in blocks. Not set reward period is treated as reward_period == 1.
+    fn reward_period() -> u32 {
+        T::RewardPeriod::get().max(One::one())
     }
 
     // Defines whether the current block is a reward block.
@@ -1649,15 +3215,8 @@ impl<T: Config<I>, I: Instance> Module<T, I> {
             return false;
         }
 
-        let reward_period: u32 = T::RewardPeriod::get();
-
-        // Special case for not set reward_period. Treats as reward_period == 1.
-        if reward_period == 0u32 {
-            return true;
-        }
-
         // Check whether current block is a reward block.
-        current_block % reward_period.into() == Zero::zero()
+        current_block % Self::reward_period().into() == Zero::zero()
     }
 
     // Sets the working group budget.
@@ -1747,6 +3306,105 @@ impl<T: Config<I>, I: Instance>
     }
 }
 
+impl<T: Config<I>, I: Instance>
+    WorkingGroupWorkerTransferHandler<
+        T::AccountId,
+        WorkerId<T>,
+        MemberId<T>,
+        T::BlockNumber,
+        BalanceOf<T>,
+    > for Module<T, I>
+{
+    fn is_worker_transfer_approved(worker_id: &WorkerId<T>, destination: WorkingGroup) -> bool {
+        Self::worker_transfer_approval_by_id(worker_id) == Some(destination)
+    }
+
+    fn ensure_worker_can_be_transferred(
+        worker_id: &WorkerId<T>,
+    ) -> Result<TransferredWorkerOf<T>, DispatchError> {
+        ensure!(
+            Self::current_lead() != Some(*worker_id),
+            Error::<T, I>::CannotTransferLead
+        );
+
+        let worker = checks::ensure_worker_exists::<T, I>(worker_id)?;
+
+        // Ensure the worker is active.
+        ensure!(!worker.is_leaving(), Error::<T, I>::WorkerIsLeaving);
+
+        Ok(TransferredWorker {
+            member_id: worker.member_id,
+            role_account_id: worker.role_account_id,
+            staking_account_id: worker.staking_account_id,
+            reward_account_id: worker.reward_account_id,
+            job_unstaking_period: worker.job_unstaking_period,
+            reward_per_block: worker.reward_per_block,
+            created_at: worker.created_at,
+            lock_id: T::StakingHandler::lock_id(),
+        })
+    }
+
+    fn ensure_worker_can_be_accepted(worker: &TransferredWorkerOf<T>) -> DispatchResult {
+        ensure!(
+            Self::active_worker_count() < T::MaxWorkerNumberLimit::get(),
+            Error::<T, I>::MaxActiveWorkerNumberExceeded
+        );
+
+        ensure!(
+            T::StakingHandler::can_replace_lock(&worker.staking_account_id, &worker.lock_id),
+            Error::<T, I>::ConflictStakesOnAccount
+        );
+
+        Ok(())
+    }
+
+    fn transfer_worker_out(worker_id: &WorkerId<T>, destination: WorkingGroup) {
+        if let Some(worker) = Self::worker_by_id(worker_id) {
+            Self::reward_leaving_worker(worker_id, &worker);
+            Self::reward_transferred_worker(worker_id, &worker);
+
+            PendingRewardAdjustment::<T, I>::remove(worker_id);
+            WorkerTransferApprovalById::<T, I>::remove(worker_id);
+
+            Self::remove_deputy(worker_id, &worker.role_account_id);
+
+            // Remove the worker from the storage keeping the stake locked.
+            WorkerById::<T, I>::remove(worker_id);
+            WorkerTransferredAt::<T, I>::remove(worker_id);
+            WorkerRewardedAt::<T, I>::remove(worker_id);
+            Self::decrease_active_worker_counter();
+
+            Self::deposit_event(RawEvent::WorkerTransferredOut(*worker_id, destination));
+        }
+    }
+
+    fn transfer_worker_in(worker: TransferredWorkerOf<T>, source: WorkingGroup) -> WorkerId<T> {
+        let new_worker_id = <NextWorkerId<T, I>>::get();
+
+        T::StakingHandler::replace_lock(&worker.staking_account_id, &worker.lock_id);
+
+        let new_worker = Worker::<T>::new(
+            &worker.member_id,
+            &worker.role_account_id,
+            &worker.reward_account_id,
+            &worker.staking_account_id,
+            worker.job_unstaking_period,
+            worker.reward_per_block,
+            worker.created_at,
+        );
+
+        <WorkerById<T, I>>::insert(new_worker_id, new_worker);
+        WorkerTransferredAt::<T, I>::insert(new_worker_id, Self::current_block());
+        Self::increase_active_worker_counter();
+
+        <NextWorkerId<T, I>>::mutate(|id| *id += <WorkerId<T> as One>::one());
+
+        Self::deposit_event(RawEvent::WorkerTransferredIn(new_worker_id, source));
+
+        new_worker_id
+    }
+}
+
 impl<T: Config<I>, I: Instance> frame_support::traits::Hooks<T::BlockNumber> for Pallet<T, I> {
     #[cfg(feature = "try-runtime")]
     fn try_state(_: T::BlockNumber) -> Result<(), &'static str> {
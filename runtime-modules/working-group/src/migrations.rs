@@ -0,0 +1,56 @@
+// Migrations for Working Group Pallet
+
+use super::*;
+use frame_support::traits::{GetStorageVersion, OnRuntimeUpgrade};
+use sp_std::marker::PhantomData;
+
+pub mod v1 {
+    use super::*;
+
+    /// Queues the workers that started leaving before the upgrade in `LeavingWorkers`,
+    /// so they exit once their unstaking period ends, and indexes the applications submitted
+    /// before the upgrade in `ApplicationIdsByOpeningId`, so they can be dropped by the
+    /// application screening.
+    pub struct MigrateToV1<T, I>(PhantomData<(T, I)>);
+
+    impl<T: Config<I>, I: Instance> OnRuntimeUpgrade for MigrateToV1<T, I> {
+        fn on_runtime_upgrade() -> Weight {
+            let onchain = Pallet::<T, I>::on_chain_storage_version();
+            let current = Pallet::<T, I>::current_storage_version();
+
+            if onchain > 0 {
+                return T::DbWeight::get().reads(1);
+            }
+
+            let current_block = Pallet::<T, I>::current_block();
+            let mut reads = 1u64;
+            let mut writes = 1u64;
+
+            for (worker_id, worker) in WorkerById::<T, I>::iter() {
+                reads = reads.saturating_add(1);
+
+                if let Some(started_leaving_at) = worker.started_leaving_at {
+                    // Workers with the unstaking period already over exit on the next block.
+                    let unstaking_period_end = Pallet::<T, I>::unstaking_period_end(
+                        started_leaving_at,
+                        worker.job_unstaking_period,
+                    )
+                    .max(current_block.saturating_add(One::one()));
+
+                    LeavingWorkers::<T, I>::insert(unstaking_period_end, worker_id, ());
+                    writes = writes.saturating_add(1);
+                }
+            }
+
+            for (application_id, application) in ApplicationById::<T, I>::iter() {
+                ApplicationIdsByOpeningId::<I>::insert(application.opening_id, application_id, ());
+                reads = reads.saturating_add(1);
+                writes = writes.saturating_add(1);
+            }
+
+            current.put::<Pallet<T, I>>();
+
+            T::DbWeight::get().reads_writes(reads, writes)
+        }
+    }
+}
@@ -1,19 +1,25 @@
 #![cfg(test)]
 use frame_support::dispatch::{DispatchError, DispatchResult};
 use frame_support::traits::{Currency, WithdrawReasons};
-use frame_support::StorageMap;
+use frame_support::{IterableStorageDoubleMap, StorageMap};
 use frame_system::{EventRecord, Phase, RawOrigin};
 use sp_runtime::traits::Hash;
 use sp_std::collections::{btree_map::BTreeMap, btree_set::BTreeSet};
 
 use super::hiring_workflow::HiringWorkflow;
-use super::mock::{Balances, LockId, RuntimeEvent, System, Test, TestWorkingGroup};
+use super::mock::{
+    Balances, LockId, RuntimeEvent, System, Test, TestWorkingGroup, DEFAULT_WORKER_ACCOUNT_ID,
+};
 use crate::tests::mock::BlockNumberToBalance;
 use crate::types::StakeParameters;
 use crate::{
-    Application, ApplyOnOpeningParameters, BalanceOf, Config, DefaultInstance, Opening,
-    OpeningType, RawEvent, StakePolicy, VestingInfoOf, Worker,
+    Application, ApplicationEndorsement, ApplicationScreeningStage, ApplyOnOpeningParameters,
+    BalanceOf, Config, DefaultInstance, DeputyPermissions, LeadActionPermission, Opening,
+    OpeningType, RawEvent, RecurringPayment, RecurringPaymentId, RecurringPaymentOf,
+    RewardAdjustment, StakePolicy, VestingInfoOf, Worker, WorkerReview, WorkerReviewId,
+    WorkerReviewStatus,
 };
+use common::working_group::{WorkingGroup, WorkingGroupWorkerTransferHandler};
 use staking_handler::StakingHandler;
 
 pub fn set_invitation_lock(
@@ -43,6 +49,11 @@ impl EventFixture {
             u64,
             <Test as frame_system::Config>::Hash,
             VestingInfoOf<Test>,
+            WorkerReviewId,
+            DeputyPermissions,
+            RecurringPaymentId,
+            RecurringPaymentOf<Test>,
+            u64,
             DefaultInstance,
         >,
     ) {
@@ -75,6 +86,11 @@ impl EventFixture {
             u64,
             <Test as frame_system::Config>::Hash,
             VestingInfoOf<Test>,
+            WorkerReviewId,
+            DeputyPermissions,
+            RecurringPaymentId,
+            RecurringPaymentOf<Test>,
+            u64,
             DefaultInstance,
         >,
     ) {
@@ -389,7 +405,8 @@ impl FillOpeningFixture {
             self.origin.clone().into(),
             self.opening_id,
             self.successful_application_ids.clone(),
-        )?;
+        )
+        .map_err(|err| err.error)?;
 
         Ok(saved_worker_next_id)
     }
@@ -963,6 +980,8 @@ impl CancelOpeningFixture {
 
     pub fn call(&self) -> DispatchResult {
         TestWorkingGroup::cancel_opening(self.origin.clone().into(), self.opening_id)
+            .map(|_| ())
+            .map_err(|err| err.error)
     }
 
     pub fn call_and_assert(&self, expected_result: DispatchResult) {
@@ -1351,3 +1370,676 @@ impl FundWorkingGroupBudgetFixture {
         }
     }
 }
+
+pub struct FileWorkerReviewFixture {
+    origin: RawOrigin<u64>,
+    worker_id: u64,
+    rating: u8,
+    metadata: Vec<u8>,
+    reward_adjustment: Option<RewardAdjustment>,
+}
+
+impl FileWorkerReviewFixture {
+    pub fn default_for_worker_id(worker_id: u64) -> Self {
+        let lead_account_id = get_current_lead_account_id();
+
+        Self {
+            origin: RawOrigin::Signed(lead_account_id),
+            worker_id,
+            rating: 5,
+            metadata: b"review".to_vec(),
+            reward_adjustment: None,
+        }
+    }
+
+    pub fn with_origin(self, origin: RawOrigin<u64>) -> Self {
+        Self { origin, ..self }
+    }
+
+    pub fn with_rating(self, rating: u8) -> Self {
+        Self { rating, ..self }
+    }
+
+    pub fn with_reward_adjustment(self, reward_adjustment: Option<RewardAdjustment>) -> Self {
+        Self {
+            reward_adjustment,
+            ..self
+        }
+    }
+
+    pub fn call_and_assert(&self, expected_result: DispatchResult) -> Option<WorkerReviewId> {
+        let review_id = TestWorkingGroup::next_worker_review_id();
+
+        let actual_result = TestWorkingGroup::file_worker_review(
+            self.origin.clone().into(),
+            self.worker_id,
+            self.rating,
+            self.metadata.clone(),
+            self.reward_adjustment,
+        );
+
+        assert_eq!(actual_result.clone(), expected_result);
+
+        if actual_result.is_ok() {
+            assert_eq!(
+                TestWorkingGroup::worker_review_by_id(review_id),
+                Some(WorkerReview {
+                    worker_id: self.worker_id,
+                    filed_at: System::block_number(),
+                    rating: self.rating,
+                    metadata_hash: <Test as frame_system::Config>::Hashing::hash(&self.metadata),
+                    reward_adjustment: self.reward_adjustment,
+                    status: WorkerReviewStatus::Filed,
+                    withheld_reward: 0,
+                })
+            );
+
+            Some(review_id)
+        } else {
+            assert!(!<crate::WorkerReviewById<Test, DefaultInstance>>::contains_key(review_id));
+
+            None
+        }
+    }
+}
+
+pub struct ContestWorkerReviewFixture {
+    origin: RawOrigin<u64>,
+    worker_id: u64,
+    review_id: WorkerReviewId,
+    rationale: Vec<u8>,
+}
+
+impl ContestWorkerReviewFixture {
+    pub fn default_for_ids(worker_id: u64, review_id: WorkerReviewId) -> Self {
+        Self {
+            origin: RawOrigin::Signed(DEFAULT_WORKER_ACCOUNT_ID),
+            worker_id,
+            review_id,
+            rationale: b"rationale".to_vec(),
+        }
+    }
+
+    pub fn with_origin(self, origin: RawOrigin<u64>) -> Self {
+        Self { origin, ..self }
+    }
+
+    pub fn call_and_assert(&self, expected_result: DispatchResult) {
+        let actual_result = TestWorkingGroup::contest_worker_review(
+            self.origin.clone().into(),
+            self.worker_id,
+            self.review_id,
+            self.rationale.clone(),
+        );
+
+        assert_eq!(actual_result.clone(), expected_result);
+
+        if actual_result.is_ok() {
+            let review =
+                TestWorkingGroup::worker_review_by_id(self.review_id).expect("Review Must Exist");
+
+            assert_eq!(review.status, WorkerReviewStatus::Contested);
+        }
+    }
+}
+
+pub struct ResolveWorkerReviewContestFixture {
+    origin: RawOrigin<u64>,
+    worker_id: u64,
+    review_id: WorkerReviewId,
+    upheld: bool,
+}
+
+impl ResolveWorkerReviewContestFixture {
+    pub fn default_for_ids(worker_id: u64, review_id: WorkerReviewId) -> Self {
+        Self {
+            origin: RawOrigin::Root,
+            worker_id,
+            review_id,
+            upheld: true,
+        }
+    }
+
+    pub fn with_origin(self, origin: RawOrigin<u64>) -> Self {
+        Self { origin, ..self }
+    }
+
+    pub fn with_upheld(self, upheld: bool) -> Self {
+        Self { upheld, ..self }
+    }
+
+    pub fn call_and_assert(&self, expected_result: DispatchResult) {
+        let actual_result = TestWorkingGroup::resolve_worker_review_contest(
+            self.origin.clone().into(),
+            self.worker_id,
+            self.review_id,
+            self.upheld,
+        );
+
+        assert_eq!(actual_result.clone(), expected_result);
+
+        if actual_result.is_ok() {
+            assert!(
+                !<crate::WorkerReviewById<Test, DefaultInstance>>::contains_key(self.review_id)
+            );
+        }
+    }
+}
+
+pub struct AppointDeputyFixture {
+    origin: RawOrigin<u64>,
+    worker_id: u64,
+    permissions: DeputyPermissions,
+    spending_cap: u64,
+}
+
+impl AppointDeputyFixture {
+    pub fn default_for_worker_id(worker_id: u64) -> Self {
+        let lead_account_id = get_current_lead_account_id();
+
+        Self {
+            origin: RawOrigin::Signed(lead_account_id),
+            worker_id,
+            permissions: BTreeSet::new(),
+            spending_cap: 0,
+        }
+    }
+
+    pub fn with_origin(self, origin: RawOrigin<u64>) -> Self {
+        Self { origin, ..self }
+    }
+
+    pub fn with_permissions(self, permissions: Vec<LeadActionPermission>) -> Self {
+        Self {
+            permissions: permissions.into_iter().collect(),
+            ..self
+        }
+    }
+
+    pub fn with_spending_cap(self, spending_cap: u64) -> Self {
+        Self {
+            spending_cap,
+            ..self
+        }
+    }
+
+    pub fn call_and_assert(&self, expected_result: DispatchResult) {
+        let old_deputy = TestWorkingGroup::deputy_by_id(self.worker_id);
+
+        let actual_result = TestWorkingGroup::appoint_deputy(
+            self.origin.clone().into(),
+            self.worker_id,
+            self.permissions.clone(),
+            self.spending_cap,
+        );
+
+        assert_eq!(actual_result.clone(), expected_result);
+
+        let new_deputy = TestWorkingGroup::deputy_by_id(self.worker_id);
+
+        if actual_result.is_ok() {
+            let deputy = new_deputy.expect("Deputy Must Exist");
+            let worker = TestWorkingGroup::worker_by_id(self.worker_id).expect("Worker Must Exist");
+
+            assert_eq!(deputy.permissions.into_inner(), self.permissions);
+            assert_eq!(deputy.spending_cap, self.spending_cap);
+            assert_eq!(
+                TestWorkingGroup::deputy_id_by_account_id(worker.role_account_id),
+                Some(self.worker_id)
+            );
+        } else {
+            assert_eq!(new_deputy, old_deputy);
+        }
+    }
+}
+
+pub struct RevokeDeputyFixture {
+    origin: RawOrigin<u64>,
+    worker_id: u64,
+}
+
+impl RevokeDeputyFixture {
+    pub fn default_for_worker_id(worker_id: u64) -> Self {
+        let lead_account_id = get_current_lead_account_id();
+
+        Self {
+            origin: RawOrigin::Signed(lead_account_id),
+            worker_id,
+        }
+    }
+
+    pub fn with_origin(self, origin: RawOrigin<u64>) -> Self {
+        Self { origin, ..self }
+    }
+
+    pub fn call_and_assert(&self, expected_result: DispatchResult) {
+        let actual_result =
+            TestWorkingGroup::revoke_deputy(self.origin.clone().into(), self.worker_id);
+
+        assert_eq!(actual_result.clone(), expected_result);
+
+        if actual_result.is_ok() {
+            let worker = TestWorkingGroup::worker_by_id(self.worker_id).expect("Worker Must Exist");
+
+            assert!(TestWorkingGroup::deputy_by_id(self.worker_id).is_none());
+            assert!(TestWorkingGroup::deputy_id_by_account_id(worker.role_account_id).is_none());
+        }
+    }
+}
+
+pub struct CreateRecurringPaymentFixture {
+    origin: RawOrigin<u64>,
+    recipient: u64,
+    amount_per_period: u64,
+    start_block: u64,
+    end_block: u64,
+    total_cap: Option<u64>,
+    rationale: Option<Vec<u8>>,
+}
+
+impl Default for CreateRecurringPaymentFixture {
+    fn default() -> Self {
+        let lead_account_id = get_current_lead_account_id();
+
+        Self {
+            origin: RawOrigin::Signed(lead_account_id),
+            recipient: DEFAULT_WORKER_ACCOUNT_ID,
+            amount_per_period: 100,
+            start_block: 0,
+            end_block: 100,
+            total_cap: None,
+            rationale: None,
+        }
+    }
+}
+
+impl CreateRecurringPaymentFixture {
+    pub fn with_origin(self, origin: RawOrigin<u64>) -> Self {
+        Self { origin, ..self }
+    }
+
+    pub fn with_amount_per_period(self, amount_per_period: u64) -> Self {
+        Self {
+            amount_per_period,
+            ..self
+        }
+    }
+
+    pub fn with_period(self, start_block: u64, end_block: u64) -> Self {
+        Self {
+            start_block,
+            end_block,
+            ..self
+        }
+    }
+
+    pub fn with_total_cap(self, total_cap: Option<u64>) -> Self {
+        Self { total_cap, ..self }
+    }
+
+    pub fn call_and_assert(&self, expected_result: DispatchResult) -> Option<RecurringPaymentId> {
+        let next_recurring_payment_id = TestWorkingGroup::next_recurring_payment_id();
+
+        let actual_result = TestWorkingGroup::create_recurring_payment(
+            self.origin.clone().into(),
+            self.recipient,
+            self.amount_per_period,
+            self.start_block,
+            self.end_block,
+            self.total_cap,
+            self.rationale.clone(),
+        );
+
+        assert_eq!(actual_result.clone(), expected_result);
+
+        if actual_result.is_ok() {
+            assert_eq!(
+                TestWorkingGroup::next_recurring_payment_id(),
+                next_recurring_payment_id + 1
+            );
+
+            let expected_recurring_payment = RecurringPayment {
+                recipient: self.recipient,
+                amount_per_period: self.amount_per_period,
+                start_block: self.start_block,
+                end_block: self.end_block,
+                total_cap: self.total_cap,
+                accrued_amount: 0,
+                missed_payment: None,
+            };
+
+            assert_eq!(
+                TestWorkingGroup::recurring_payment_by_id(next_recurring_payment_id),
+                Some(expected_recurring_payment)
+            );
+
+            Some(next_recurring_payment_id)
+        } else {
+            assert_eq!(
+                TestWorkingGroup::next_recurring_payment_id(),
+                next_recurring_payment_id
+            );
+
+            None
+        }
+    }
+}
+
+pub struct CancelRecurringPaymentFixture {
+    origin: RawOrigin<u64>,
+    recurring_payment_id: RecurringPaymentId,
+}
+
+impl CancelRecurringPaymentFixture {
+    pub fn default_for_recurring_payment_id(recurring_payment_id: RecurringPaymentId) -> Self {
+        let lead_account_id = get_current_lead_account_id();
+
+        Self {
+            origin: RawOrigin::Signed(lead_account_id),
+            recurring_payment_id,
+        }
+    }
+
+    pub fn with_origin(self, origin: RawOrigin<u64>) -> Self {
+        Self { origin, ..self }
+    }
+
+    pub fn call_and_assert(&self, expected_result: DispatchResult) {
+        let actual_result = TestWorkingGroup::cancel_recurring_payment(
+            self.origin.clone().into(),
+            self.recurring_payment_id,
+        );
+
+        assert_eq!(actual_result.clone(), expected_result);
+
+        if actual_result.is_ok() {
+            assert!(TestWorkingGroup::recurring_payment_by_id(self.recurring_payment_id).is_none());
+        }
+    }
+}
+
+pub struct ShortlistApplicationsFixture {
+    origin: RawOrigin<u64>,
+    opening_id: u64,
+    shortlisted_application_ids: BTreeSet<u64>,
+    endorsement_period: Option<u64>,
+    application_count_witness: u32,
+}
+
+impl ShortlistApplicationsFixture {
+    pub fn default_for_ids(opening_id: u64, application_ids: Vec<u64>) -> Self {
+        let lead_account_id = get_current_lead_account_id();
+
+        Self {
+            origin: RawOrigin::Signed(lead_account_id),
+            opening_id,
+            application_count_witness: application_ids.len() as u32,
+            shortlisted_application_ids: application_ids.iter().copied().collect(),
+            endorsement_period: None,
+        }
+    }
+
+    pub fn with_origin(self, origin: RawOrigin<u64>) -> Self {
+        Self { origin, ..self }
+    }
+
+    pub fn with_endorsement_period(self, endorsement_period: Option<u64>) -> Self {
+        Self {
+            endorsement_period,
+            ..self
+        }
+    }
+
+    pub fn with_application_count_witness(self, application_count_witness: u32) -> Self {
+        Self {
+            application_count_witness,
+            ..self
+        }
+    }
+
+    pub fn call_and_assert(&self, expected_result: DispatchResult) {
+        let old_stage = TestWorkingGroup::application_screening_stage(self.opening_id);
+
+        let actual_result = TestWorkingGroup::shortlist_applications(
+            self.origin.clone().into(),
+            self.opening_id,
+            self.shortlisted_application_ids.clone(),
+            self.endorsement_period,
+            self.application_count_witness,
+        );
+
+        assert_eq!(actual_result.clone(), expected_result);
+
+        let new_stage = TestWorkingGroup::application_screening_stage(self.opening_id);
+
+        if actual_result.is_ok() {
+            let expected_stage = match self.endorsement_period {
+                Some(endorsement_period) => ApplicationScreeningStage::Endorsement {
+                    ends_at: System::block_number() + endorsement_period,
+                },
+                None => ApplicationScreeningStage::Selection,
+            };
+
+            assert_eq!(new_stage, Some(expected_stage));
+
+            let application_ids =
+                <crate::ApplicationIdsByOpeningId<DefaultInstance>>::iter_prefix(self.opening_id)
+                    .map(|(application_id, _)| application_id)
+                    .collect::<BTreeSet<_>>();
+
+            assert_eq!(application_ids, self.shortlisted_application_ids);
+        } else {
+            assert_eq!(new_stage, old_stage);
+        }
+    }
+}
+
+pub struct EndorseApplicationFixture {
+    origin: RawOrigin<u64>,
+    member_id: u64,
+    application_id: u64,
+    staking_account_id: u64,
+    stake: u64,
+    initial_balance: u64,
+}
+
+impl EndorseApplicationFixture {
+    pub fn default_for_application_id(application_id: u64) -> Self {
+        Self {
+            origin: RawOrigin::Signed(6),
+            member_id: 6,
+            application_id,
+            staking_account_id: 6,
+            stake: 100,
+            initial_balance: 100,
+        }
+    }
+
+    pub fn with_member(self, member_id: u64) -> Self {
+        Self {
+            origin: RawOrigin::Signed(member_id),
+            member_id,
+            staking_account_id: member_id,
+            ..self
+        }
+    }
+
+    pub fn with_stake(self, stake: u64) -> Self {
+        Self {
+            stake,
+            initial_balance: stake,
+            ..self
+        }
+    }
+
+    pub fn call_and_assert(&self, expected_result: DispatchResult) {
+        balances::Pallet::<Test>::make_free_balance_be(
+            &self.staking_account_id,
+            self.initial_balance,
+        );
+
+        let old_total_stake = TestWorkingGroup::application_endorsement_stake(self.application_id);
+
+        let actual_result = TestWorkingGroup::endorse_application(
+            self.origin.clone().into(),
+            self.member_id,
+            self.application_id,
+            self.staking_account_id,
+            self.stake,
+        );
+
+        assert_eq!(actual_result.clone(), expected_result);
+
+        let new_total_stake = TestWorkingGroup::application_endorsement_stake(self.application_id);
+
+        if actual_result.is_ok() {
+            assert_eq!(
+                TestWorkingGroup::application_endorsement_by_id(
+                    self.application_id,
+                    self.member_id
+                ),
+                Some(ApplicationEndorsement {
+                    staking_account_id: self.staking_account_id,
+                    stake: self.stake,
+                })
+            );
+            assert_eq!(
+                Balances::usable_balance(&self.staking_account_id),
+                self.initial_balance - self.stake
+            );
+            assert_eq!(new_total_stake, old_total_stake + self.stake);
+        } else {
+            assert_eq!(new_total_stake, old_total_stake);
+        }
+    }
+}
+
+pub struct WithdrawApplicationEndorsementFixture {
+    origin: RawOrigin<u64>,
+    member_id: u64,
+    application_id: u64,
+}
+
+impl WithdrawApplicationEndorsementFixture {
+    pub fn default_for_application_id(application_id: u64) -> Self {
+        Self {
+            origin: RawOrigin::Signed(6),
+            member_id: 6,
+            application_id,
+        }
+    }
+
+    pub fn with_origin(self, origin: RawOrigin<u64>) -> Self {
+        Self { origin, ..self }
+    }
+
+    pub fn call_and_assert(&self, expected_result: DispatchResult) {
+        let actual_result = TestWorkingGroup::withdraw_application_endorsement(
+            self.origin.clone().into(),
+            self.member_id,
+            self.application_id,
+        );
+
+        assert_eq!(actual_result.clone(), expected_result);
+
+        if actual_result.is_ok() {
+            assert!(TestWorkingGroup::application_endorsement_by_id(
+                self.application_id,
+                self.member_id
+            )
+            .is_none());
+        }
+    }
+}
+
+pub struct StartApplicationSelectionFixture {
+    origin: RawOrigin<u64>,
+    opening_id: u64,
+}
+
+impl StartApplicationSelectionFixture {
+    pub fn default_for_opening_id(opening_id: u64) -> Self {
+        let lead_account_id = get_current_lead_account_id();
+
+        Self {
+            origin: RawOrigin::Signed(lead_account_id),
+            opening_id,
+        }
+    }
+
+    pub fn with_origin(self, origin: RawOrigin<u64>) -> Self {
+        Self { origin, ..self }
+    }
+
+    pub fn call_and_assert(&self, expected_result: DispatchResult) {
+        let actual_result = TestWorkingGroup::start_application_selection(
+            self.origin.clone().into(),
+            self.opening_id,
+        );
+
+        assert_eq!(actual_result.clone(), expected_result);
+
+        if actual_result.is_ok() {
+            assert_eq!(
+                TestWorkingGroup::application_screening_stage(self.opening_id),
+                Some(ApplicationScreeningStage::Selection)
+            );
+        }
+    }
+}
+
+pub struct SetWorkerTransferApprovalFixture {
+    origin: RawOrigin<u64>,
+    worker_id: u64,
+    destination: Option<WorkingGroup>,
+}
+
+impl SetWorkerTransferApprovalFixture {
+    pub fn default_for_worker_id(worker_id: u64) -> Self {
+        let lead_account_id = get_current_lead_account_id();
+
+        Self {
+            origin: RawOrigin::Signed(lead_account_id),
+            worker_id,
+            destination: Some(WorkingGroup::Storage),
+        }
+    }
+
+    pub fn with_origin(self, origin: RawOrigin<u64>) -> Self {
+        Self { origin, ..self }
+    }
+
+    pub fn with_destination(self, destination: Option<WorkingGroup>) -> Self {
+        Self {
+            destination,
+            ..self
+        }
+    }
+
+    pub fn call_and_assert(&self, expected_result: DispatchResult) {
+        let old_approval = TestWorkingGroup::worker_transfer_approval_by_id(self.worker_id);
+
+        let actual_result = TestWorkingGroup::set_worker_transfer_approval(
+            self.origin.clone().into(),
+            self.worker_id,
+            self.destination,
+        );
+
+        assert_eq!(actual_result.clone(), expected_result);
+
+        let new_approval = TestWorkingGroup::worker_transfer_approval_by_id(self.worker_id);
+
+        if actual_result.is_ok() {
+            assert_eq!(new_approval, self.destination);
+
+            if let Some(destination) = self.destination {
+                assert!(TestWorkingGroup::is_worker_transfer_approved(
+                    &self.worker_id,
+                    destination
+                ));
+            }
+        } else {
+            assert_eq!(new_approval, old_approval);
+        }
+    }
+}
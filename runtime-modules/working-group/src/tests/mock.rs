@@ -1,4 +1,4 @@
-use frame_support::traits::{ConstU16, ConstU32, ConstU64, LockIdentifier, WithdrawReasons};
+use frame_support::traits::{ConstU16, ConstU32, ConstU64, Get, LockIdentifier, WithdrawReasons};
 use frame_support::traits::{OnFinalize, OnInitialize};
 use frame_support::{parameter_types, PalletId};
 
@@ -9,6 +9,7 @@ use sp_runtime::{
     traits::{BlakeTwo256, IdentityLookup},
 };
 use staking_handler::LockComparator;
+use std::cell::RefCell;
 use std::convert::{TryFrom, TryInto};
 
 use crate::{self as working_group, BalanceOf, VestingBalanceOf};
@@ -22,6 +23,10 @@ parameter_types! {
     pub const DefaultMembershipPrice: u64 = 0;
     pub const DefaultInitialInvitationBalance: u64 = 100;
     pub const DefaultMemberInvitesCount: u32 = 2;
+    pub const HandleReclaimCooldown: u64 = 1000;
+    pub const MaxRecoveryGuardians: u32 = 5;
+    pub const RecoveryDelay: u64 = 100;
+    pub const RecoveryCooldown: u64 = 50;
     pub const InvitedMemberLockId: [u8; 8] = [2; 8];
     pub const ReferralCutMaximumPercent: u8 = 50;
     pub const StakingCandidateLockId: [u8; 8] = [3; 8];
@@ -125,6 +130,10 @@ impl membership::Config for Test {
         staking_handler::StakingManager<Self, StakingCandidateLockId>;
     type CandidateStake = CandidateStake;
     type DefaultMemberInvitesCount = DefaultMemberInvitesCount;
+    type HandleReclaimCooldown = HandleReclaimCooldown;
+    type MaxRecoveryGuardians = MaxRecoveryGuardians;
+    type RecoveryDelay = RecoveryDelay;
+    type RecoveryCooldown = RecoveryCooldown;
 }
 
 impl LockComparator<<Test as balances::Config>::Balance> for Test {
@@ -140,6 +149,7 @@ parameter_types! {
     pub const MinimumApplicationStake: u64 = 50;
     pub const LockId: [u8; 8] = [1; 8];
     pub const LeaderOpeningStake: u64 = 20;
+    pub const MaxApplicationEndorsements: u32 = 3;
     pub const WorkingGroupModuleId: PalletId = PalletId(*b"mworking"); // module storage
 }
 
@@ -152,9 +162,28 @@ impl Config for Test {
     type MemberOriginValidator = ();
     type MinUnstakingPeriodLimit = MinUnstakingPeriodLimit;
     type RewardPeriod = RewardPeriod;
+    type MaxRewardPayoutsPerBlock = MaxRewardPayoutsPerBlock;
     type WeightInfo = ();
     type MinimumApplicationStake = MinimumApplicationStake;
     type LeaderOpeningStake = LeaderOpeningStake;
+    type MaxApplicationEndorsements = MaxApplicationEndorsements;
+}
+
+thread_local! {
+    pub static MAX_REWARD_PAYOUTS_PER_BLOCK: RefCell<u32> = RefCell::new(3);
+}
+
+pub struct MaxRewardPayoutsPerBlock;
+impl MaxRewardPayoutsPerBlock {
+    pub fn set(max_payouts: u32) {
+        MAX_REWARD_PAYOUTS_PER_BLOCK.with(|value| *value.borrow_mut() = max_payouts);
+    }
+}
+
+impl Get<u32> for MaxRewardPayoutsPerBlock {
+    fn get() -> u32 {
+        MAX_REWARD_PAYOUTS_PER_BLOCK.with(|value| *value.borrow())
+    }
 }
 
 pub struct BlockNumberToBalance();
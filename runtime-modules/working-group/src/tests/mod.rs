@@ -6,13 +6,19 @@ pub use mock::{build_test_externalities, Test, DEFAULT_WORKER_ACCOUNT_ID};
 
 use frame_system::RawOrigin;
 use sp_arithmetic::traits::Zero;
+use sp_arithmetic::Percent;
 use vesting::VestingInfo;
 
+use crate::migrations::v1::MigrateToV1;
 use crate::tests::fixtures::{
-    get_current_lead_account_id, set_invitation_lock, CancelOpeningFixture,
-    DecreaseWorkerStakeFixture, FundWorkingGroupBudgetFixture, IncreaseWorkerStakeFixture,
-    SetBudgetFixture, SetStatusTextFixture, SlashWorkerStakeFixture, SpendFromBudgetFixture,
-    UpdateRewardAccountFixture, UpdateRewardAmountFixture, VestedSpendFromBudgetFixture,
+    get_current_lead_account_id, set_invitation_lock, AppointDeputyFixture, CancelOpeningFixture,
+    CancelRecurringPaymentFixture, ContestWorkerReviewFixture, CreateRecurringPaymentFixture,
+    DecreaseWorkerStakeFixture, EndorseApplicationFixture, FileWorkerReviewFixture,
+    FundWorkingGroupBudgetFixture, IncreaseWorkerStakeFixture, ResolveWorkerReviewContestFixture,
+    RevokeDeputyFixture, SetBudgetFixture, SetStatusTextFixture, SetWorkerTransferApprovalFixture,
+    ShortlistApplicationsFixture, SlashWorkerStakeFixture, SpendFromBudgetFixture,
+    StartApplicationSelectionFixture, UpdateRewardAccountFixture, UpdateRewardAmountFixture,
+    VestedSpendFromBudgetFixture, WithdrawApplicationEndorsementFixture,
     WithdrawApplicationFixture,
 };
 use crate::tests::hiring_workflow::HiringWorkflow;
@@ -22,22 +28,28 @@ use crate::tests::mock::{
 };
 use crate::types::StakeParameters;
 use crate::{
-    Config, DefaultInstance, Error, OpeningType, RawEvent, RewardPaymentType, StakePolicy,
-    VestingInfoOf, Worker,
+    Config, DefaultInstance, Error, LeadActionPermission, OpeningType, RawEvent, RewardAdjustment,
+    RewardPaymentType, StakePolicy, VestingInfoOf, Worker, MAX_WORKER_REVIEW_RATING,
+};
+use common::working_group::{
+    TransferredWorker, WorkingGroup, WorkingGroupAuthenticator, WorkingGroupWorkerTransferHandler,
 };
-use common::working_group::WorkingGroupAuthenticator;
 use fixtures::{
     increase_total_balance_issuance_using_account_id, AddOpeningFixture, ApplyOnOpeningFixture,
     EventFixture, FillOpeningFixture, HireLeadFixture, HireRegularWorkerFixture,
     LeaveWorkerRoleFixture, TerminateWorkerRoleFixture, UpdateWorkerRoleAccountFixture,
 };
 use frame_support::dispatch::DispatchError;
-use frame_support::traits::Currency;
-use frame_support::StorageMap;
+use frame_support::traits::{Currency, OnRuntimeUpgrade, StorageVersion};
 use frame_support::{assert_noop, assert_ok};
-use mock::{run_to_block, Balances, RewardPeriod, TestWorkingGroup, ACTOR_ORIGIN_ERROR};
+use frame_support::{StorageDoubleMap, StorageMap};
+use mock::{
+    run_to_block, Balances, InvitedMemberLockId, LockId, MaxRewardPayoutsPerBlock, RewardPeriod,
+    System, TestWorkingGroup, ACTOR_ORIGIN_ERROR,
+};
 use sp_runtime::traits::Hash;
-use sp_std::collections::btree_map::BTreeMap;
+use sp_std::collections::{btree_map::BTreeMap, btree_set::BTreeSet};
+use staking_handler::StakingHandler;
 
 #[test]
 fn add_opening_succeeded() {
@@ -709,6 +721,61 @@ fn leave_worker_role_succeeds_with_correct_unstaking_period() {
     });
 }
 
+#[test]
+fn leaving_worker_migration_queues_workers_started_leaving_before_upgrade() {
+    build_test_externalities().execute_with(|| {
+        let starting_block = 10;
+        run_to_block(starting_block);
+
+        let worker_id = HireRegularWorkerFixture::default().hire();
+        let finished_worker_id = HireRegularWorkerFixture::default().hire();
+
+        let unstaking_period = TestWorkingGroup::worker_by_id(worker_id)
+            .expect("Worker Must Exist")
+            .job_unstaking_period;
+
+        // Simulate workers that started leaving before the exit queue was introduced.
+        <crate::WorkerById<Test, DefaultInstance>>::mutate(worker_id, |worker| {
+            if let Some(worker) = worker {
+                worker.started_leaving_at = Some(starting_block);
+            }
+        });
+        <crate::WorkerById<Test, DefaultInstance>>::mutate(finished_worker_id, |worker| {
+            if let Some(worker) = worker {
+                worker.started_leaving_at = Some(0);
+            }
+        });
+        StorageVersion::new(0).put::<TestWorkingGroup>();
+
+        MigrateToV1::<Test, DefaultInstance>::on_runtime_upgrade();
+
+        assert!(
+            <crate::LeavingWorkers<Test, DefaultInstance>>::contains_key(
+                starting_block + unstaking_period,
+                worker_id
+            )
+        );
+        assert!(
+            <crate::LeavingWorkers<Test, DefaultInstance>>::contains_key(
+                starting_block + 1,
+                finished_worker_id
+            )
+        );
+
+        run_to_block(starting_block + 1);
+
+        assert!(!<crate::WorkerById<Test, DefaultInstance>>::contains_key(
+            finished_worker_id
+        ));
+
+        run_to_block(starting_block + unstaking_period);
+
+        assert!(!<crate::WorkerById<Test, DefaultInstance>>::contains_key(
+            worker_id
+        ));
+    });
+}
+
 #[test]
 fn leave_worker_role_succeeds_with_partial_payment_of_missed_reward() {
     build_test_externalities().execute_with(|| {
@@ -2063,6 +2130,227 @@ fn rewards_payments_with_starting_block() {
     });
 }
 
+#[test]
+fn rewards_payments_are_spread_over_several_blocks() {
+    build_test_externalities().execute_with(|| {
+        MaxRewardPayoutsPerBlock::set(1);
+
+        let reward_per_block = 10;
+
+        let worker_id = HireRegularWorkerFixture::default()
+            .with_reward_per_block(Some(reward_per_block))
+            .hire();
+
+        let worker = TestWorkingGroup::worker_by_id(worker_id).expect("Worker Must Exist");
+
+        let account_id = worker.reward_account_id;
+
+        SetBudgetFixture::default().execute();
+
+        let reward_block: u64 = RewardPeriod::get().into();
+        run_to_block(reward_block);
+
+        // The lead and the worker can't be rewarded in a single block.
+        assert!(TestWorkingGroup::current_reward_payout_round().is_some());
+
+        run_to_block(reward_block + 1);
+
+        assert!(TestWorkingGroup::current_reward_payout_round().is_none());
+        assert_eq!(
+            Balances::usable_balance(&account_id),
+            reward_block * reward_per_block
+        );
+
+        // Delayed payouts still cover the whole reward period.
+        let block_number = 10;
+        run_to_block(block_number + 1);
+
+        assert_eq!(
+            Balances::usable_balance(&account_id),
+            block_number * reward_per_block
+        );
+    });
+}
+
+#[test]
+fn rewards_are_paid_to_workers_removed_and_not_to_workers_hired_during_payout_round() {
+    build_test_externalities().execute_with(|| {
+        MaxRewardPayoutsPerBlock::set(1);
+
+        let reward_per_block = 10;
+
+        let removed_worker_id = HireRegularWorkerFixture::default()
+            .with_reward_per_block(Some(reward_per_block))
+            .hire();
+        let removed_worker_account_id = TestWorkingGroup::worker_by_id(removed_worker_id)
+            .expect("Worker Must Exist")
+            .reward_account_id;
+        let removed_worker_initial_balance = Balances::free_balance(&removed_worker_account_id);
+
+        SetBudgetFixture::default().execute();
+
+        let reward_block: u64 = RewardPeriod::get().into();
+        run_to_block(reward_block);
+
+        // The lead and the worker can't be rewarded in a single block.
+        assert!(TestWorkingGroup::current_reward_payout_round().is_some());
+
+        TerminateWorkerRoleFixture::default_for_worker_id(removed_worker_id)
+            .call_and_assert(Ok(()));
+
+        // The removed worker is paid for the whole period of the round in progress.
+        assert_eq!(
+            Balances::free_balance(&removed_worker_account_id),
+            removed_worker_initial_balance + reward_block * reward_per_block
+        );
+
+        let hired_worker_id = HiringWorkflow::default()
+            .with_setup_environment(false)
+            .with_reward_per_block(Some(reward_per_block))
+            .add_application_full(b"worker2".to_vec(), RawOrigin::Signed(3), 3, 3)
+            .execute()
+            .unwrap();
+        let hired_worker_account_id = TestWorkingGroup::worker_by_id(hired_worker_id)
+            .expect("Worker Must Exist")
+            .reward_account_id;
+        let hired_worker_initial_balance = Balances::free_balance(&hired_worker_account_id);
+
+        while TestWorkingGroup::current_reward_payout_round()
+            .map_or(false, |round| round.reward_block == reward_block)
+        {
+            run_to_block(System::block_number() + 1);
+        }
+
+        // The round in progress doesn't reward the worker hired after its reward block.
+        assert_eq!(TestWorkingGroup::worker_rewarded_at(hired_worker_id), None);
+        assert_eq!(
+            Balances::free_balance(&hired_worker_account_id),
+            hired_worker_initial_balance
+        );
+        assert_eq!(
+            Balances::free_balance(&removed_worker_account_id),
+            removed_worker_initial_balance + reward_block * reward_per_block
+        );
+
+        let next_reward_block = 2 * reward_block;
+        while TestWorkingGroup::worker_rewarded_at(hired_worker_id) != Some(next_reward_block) {
+            run_to_block(System::block_number() + 1);
+        }
+
+        // The next round rewards the hired worker since the hiring block.
+        assert_eq!(
+            Balances::free_balance(&hired_worker_account_id),
+            hired_worker_initial_balance + (next_reward_block - reward_block) * reward_per_block
+        );
+    });
+}
+
+#[test]
+fn reward_payout_round_is_delayed_until_previous_round_completes() {
+    build_test_externalities().execute_with(|| {
+        MaxRewardPayoutsPerBlock::set(1);
+
+        let reward_per_block = 10;
+
+        let worker_id = HireRegularWorkerFixture::default()
+            .with_reward_per_block(Some(reward_per_block))
+            .hire();
+        let account_id = TestWorkingGroup::worker_by_id(worker_id)
+            .expect("Worker Must Exist")
+            .reward_account_id;
+
+        HiringWorkflow::default()
+            .with_setup_environment(false)
+            .with_reward_per_block(Some(reward_per_block))
+            .add_application_full(b"worker2".to_vec(), RawOrigin::Signed(3), 3, 3)
+            .execute()
+            .unwrap();
+
+        let initial_balance = Balances::free_balance(&account_id);
+
+        SetBudgetFixture::default().execute();
+
+        // The lead and two workers are rewarded within three blocks.
+        let reward_block: u64 = RewardPeriod::get().into();
+        let next_reward_block = 2 * reward_block;
+        run_to_block(next_reward_block);
+
+        EventFixture::contains_crate_event(RawEvent::RewardPayoutRoundDelayed(next_reward_block));
+        assert_eq!(
+            TestWorkingGroup::delayed_reward_block(),
+            Some(next_reward_block)
+        );
+
+        run_to_block(next_reward_block + 1);
+
+        // The delayed round covers the period since the previous round reward block.
+        let round = TestWorkingGroup::current_reward_payout_round().unwrap();
+        assert_eq!(round.reward_block, next_reward_block);
+        assert_eq!(round.rewarded_since, reward_block);
+        assert_eq!(TestWorkingGroup::delayed_reward_block(), None);
+
+        while TestWorkingGroup::worker_rewarded_at(worker_id) != Some(next_reward_block) {
+            run_to_block(System::block_number() + 1);
+        }
+
+        assert_eq!(
+            Balances::free_balance(&account_id),
+            initial_balance + next_reward_block * reward_per_block
+        );
+    });
+}
+
+#[test]
+fn leaving_workers_are_queued_by_unstaking_period_end() {
+    build_test_externalities().execute_with(|| {
+        run_to_block(1);
+
+        let worker_id = HireRegularWorkerFixture::default().hire();
+
+        let leave_worker_role_fixture = LeaveWorkerRoleFixture::default_for_worker_id(worker_id);
+        leave_worker_role_fixture.call_and_assert(Ok(()));
+
+        let worker = TestWorkingGroup::worker_by_id(worker_id).expect("Worker Must Exist");
+        let exit_block = 1 + worker.job_unstaking_period;
+
+        assert!(
+            <crate::LeavingWorkers<Test, DefaultInstance>>::contains_key(exit_block, worker_id)
+        );
+
+        run_to_block(exit_block);
+
+        assert!(
+            !<crate::LeavingWorkers<Test, DefaultInstance>>::contains_key(exit_block, worker_id)
+        );
+        assert!(!<crate::WorkerById<Test, DefaultInstance>>::contains_key(
+            worker_id
+        ));
+    });
+}
+
+#[test]
+fn terminate_leaving_worker_removes_it_from_leaving_queue() {
+    build_test_externalities().execute_with(|| {
+        run_to_block(1);
+
+        let worker_id = HireRegularWorkerFixture::default().hire();
+
+        let leave_worker_role_fixture = LeaveWorkerRoleFixture::default_for_worker_id(worker_id);
+        leave_worker_role_fixture.call_and_assert(Ok(()));
+
+        let worker = TestWorkingGroup::worker_by_id(worker_id).expect("Worker Must Exist");
+        let exit_block = 1 + worker.job_unstaking_period;
+
+        let terminate_worker_role_fixture =
+            TerminateWorkerRoleFixture::default_for_worker_id(worker_id);
+        terminate_worker_role_fixture.call_and_assert(Ok(()));
+
+        assert!(
+            !<crate::LeavingWorkers<Test, DefaultInstance>>::contains_key(exit_block, worker_id)
+        );
+    });
+}
+
 #[test]
 fn set_budget_succeeded() {
     build_test_externalities().execute_with(|| {
@@ -2297,90 +2585,1495 @@ fn update_reward_amount_fails_with_invalid_worker_id() {
 }
 
 #[test]
-fn set_status_text_succeeded() {
+fn file_worker_review_succeeds() {
     build_test_externalities().execute_with(|| {
-        HireLeadFixture::default().hire_lead();
-
         run_to_block(1);
 
-        let status_text = b"some".to_vec();
-        SetStatusTextFixture::default()
-            .with_status_text(Some(status_text.clone()))
-            .call_and_assert(Ok(()));
+        let worker_id = HireRegularWorkerFixture::default().hire();
 
-        let expected_hash = <Test as frame_system::Config>::Hashing::hash(&status_text);
-        EventFixture::assert_last_crate_event(RawEvent::StatusTextChanged(
-            expected_hash,
-            Some(status_text),
+        let reward_adjustment = Some(RewardAdjustment::Bonus(Percent::from_percent(20)));
+        let file_review_fixture = FileWorkerReviewFixture::default_for_worker_id(worker_id)
+            .with_reward_adjustment(reward_adjustment);
+
+        let review_id = file_review_fixture.call_and_assert(Ok(())).unwrap();
+
+        assert_eq!(
+            TestWorkingGroup::pending_reward_adjustment(worker_id),
+            reward_adjustment.map(|adjustment| (review_id, adjustment))
+        );
+
+        EventFixture::assert_last_crate_event(RawEvent::WorkerReviewFiled(
+            worker_id,
+            review_id,
+            5,
+            b"review".to_vec(),
+            reward_adjustment,
         ));
     });
 }
 
 #[test]
-fn set_status_text_fails_with_bad_origin() {
+fn file_worker_review_succeeds_for_leader() {
     build_test_externalities().execute_with(|| {
-        HireLeadFixture::default().hire_lead();
-        let leader_account_id = 10;
+        let worker_id = HireLeadFixture::default().hire_lead();
 
-        SetStatusTextFixture::default()
-            .with_origin(RawOrigin::Signed(leader_account_id))
-            .call_and_assert(Err(Error::<Test, DefaultInstance>::IsNotLeadAccount.into()));
+        let file_review_fixture =
+            FileWorkerReviewFixture::default_for_worker_id(worker_id).with_origin(RawOrigin::Root);
+
+        file_review_fixture.call_and_assert(Ok(()));
     });
 }
 
 #[test]
-fn spend_from_budget_succeeded() {
+fn file_worker_review_without_adjustment_discards_pending_adjustment() {
     build_test_externalities().execute_with(|| {
-        let account_id = 2;
-        let amount = 100;
-        HireLeadFixture::default().hire_lead();
+        let worker_id = HireRegularWorkerFixture::default().hire();
 
-        run_to_block(1);
+        FileWorkerReviewFixture::default_for_worker_id(worker_id)
+            .with_reward_adjustment(Some(RewardAdjustment::Penalty(Percent::from_percent(20))))
+            .call_and_assert(Ok(()));
 
-        let set_budget_fixture = SetBudgetFixture::default().with_budget(1000);
-        assert_eq!(set_budget_fixture.call(), Ok(()));
+        FileWorkerReviewFixture::default_for_worker_id(worker_id).call_and_assert(Ok(()));
 
-        SpendFromBudgetFixture::default()
-            .with_account_id(account_id)
-            .with_amount(amount)
-            .call_and_assert(Ok(()));
+        assert!(TestWorkingGroup::pending_reward_adjustment(worker_id).is_none());
+    });
+}
 
-        EventFixture::assert_last_crate_event(RawEvent::BudgetSpending(account_id, amount, None));
+#[test]
+fn file_worker_review_fails_with_invalid_origin_signed_account() {
+    build_test_externalities().execute_with(|| {
+        let worker_id = HireRegularWorkerFixture::default().hire();
+
+        let file_review_fixture = FileWorkerReviewFixture::default_for_worker_id(worker_id)
+            .with_origin(RawOrigin::Signed(2));
+
+        file_review_fixture
+            .call_and_assert(Err(Error::<Test, DefaultInstance>::IsNotLeadAccount.into()));
     });
 }
 
 #[test]
-fn vested_spend_from_budget_succeeded_with_vesting_correctly_added() {
-    let get_vesting = |account_id: u64| -> VestingInfo<u64, u64> {
-        let v = Vesting::vesting(&account_id).unwrap();
-        v.last().copied().unwrap()
-    };
-    let account_id = 2;
-    let amount = 100;
-    let vesting_period = 10;
-    let per_block = amount / vesting_period;
-    let mut block_number = 1;
-    let vested_amount = |block_number: u64| -> u64 { per_block * (block_number - 1) };
+fn file_worker_review_fails_with_invalid_worker_id() {
     build_test_externalities().execute_with(|| {
-        HireLeadFixture::default().hire_lead();
+        HireRegularWorkerFixture::default().hire();
 
-        run_to_block(block_number);
+        let invalid_worker_id = 12;
+        let file_review_fixture = FileWorkerReviewFixture::default_for_worker_id(invalid_worker_id);
 
-        let set_budget_fixture = SetBudgetFixture::default().with_budget(1000);
-        assert_eq!(set_budget_fixture.call(), Ok(()));
+        file_review_fixture.call_and_assert(Err(
+            Error::<Test, DefaultInstance>::WorkerDoesNotExist.into(),
+        ));
+    });
+}
 
-        assert_ok!(VestedSpendFromBudgetFixture::default()
-            .with_account_id(account_id)
-            .with_vesting_schedule(amount, per_block)
-            .call());
+#[test]
+fn file_worker_review_fails_with_invalid_rating() {
+    build_test_externalities().execute_with(|| {
+        let worker_id = HireRegularWorkerFixture::default().hire();
 
-        // amount at vesting block should be amount / 10
-        assert_eq!(
-            get_vesting(account_id).locked_at::<BlockNumberToBalance>(block_number),
-            amount - vested_amount(block_number)
-        );
+        let file_review_fixture = FileWorkerReviewFixture::default_for_worker_id(worker_id)
+            .with_rating(MAX_WORKER_REVIEW_RATING + 1);
 
-        // halfway through the vesting period amount vested should be exactly half
+        file_review_fixture.call_and_assert(Err(
+            Error::<Test, DefaultInstance>::InvalidWorkerReviewRating.into(),
+        ));
+    });
+}
+
+#[test]
+fn worker_review_adjustment_applies_to_next_reward_payout() {
+    build_test_externalities().execute_with(|| {
+        let reward_per_block = 10;
+
+        let worker_id = HireRegularWorkerFixture::default()
+            .with_reward_per_block(Some(reward_per_block))
+            .hire();
+
+        let worker = TestWorkingGroup::worker_by_id(worker_id).expect("Worker Must Exist");
+        let account_id = worker.reward_account_id;
+
+        SetBudgetFixture::default().execute();
+
+        FileWorkerReviewFixture::default_for_worker_id(worker_id)
+            .with_reward_adjustment(Some(RewardAdjustment::Bonus(Percent::from_percent(50))))
+            .call_and_assert(Ok(()));
+
+        let reward_period: u64 = RewardPeriod::get().into();
+        let period_reward = reward_period * reward_per_block;
+
+        run_to_block(reward_period);
+
+        assert_eq!(
+            Balances::usable_balance(&account_id),
+            period_reward + period_reward / 2
+        );
+        assert!(TestWorkingGroup::pending_reward_adjustment(worker_id).is_none());
+
+        // The adjustment applies to a single reward payout.
+        run_to_block(reward_period * 2);
+
+        assert_eq!(
+            Balances::usable_balance(&account_id),
+            2 * period_reward + period_reward / 2
+        );
+    });
+}
+
+#[test]
+fn contest_worker_review_succeeds() {
+    build_test_externalities().execute_with(|| {
+        run_to_block(1);
+
+        let worker_id = HireRegularWorkerFixture::default().hire();
+
+        let review_id = FileWorkerReviewFixture::default_for_worker_id(worker_id)
+            .call_and_assert(Ok(()))
+            .unwrap();
+
+        ContestWorkerReviewFixture::default_for_ids(worker_id, review_id).call_and_assert(Ok(()));
+
+        EventFixture::assert_last_crate_event(RawEvent::WorkerReviewContested(
+            worker_id,
+            review_id,
+            b"rationale".to_vec(),
+        ));
+    });
+}
+
+#[test]
+fn contest_worker_review_fails_with_invalid_origin_signed_account() {
+    build_test_externalities().execute_with(|| {
+        let worker_id = HireRegularWorkerFixture::default().hire();
+
+        let review_id = FileWorkerReviewFixture::default_for_worker_id(worker_id)
+            .call_and_assert(Ok(()))
+            .unwrap();
+
+        let lead_account_id = get_current_lead_account_id();
+        ContestWorkerReviewFixture::default_for_ids(worker_id, review_id)
+            .with_origin(RawOrigin::Signed(lead_account_id))
+            .call_and_assert(Err(
+                Error::<Test, DefaultInstance>::SignerIsNotWorkerRoleAccount.into(),
+            ));
+    });
+}
+
+#[test]
+fn contest_worker_review_fails_with_invalid_review_id() {
+    build_test_externalities().execute_with(|| {
+        let worker_id = HireRegularWorkerFixture::default().hire();
+
+        let invalid_review_id = 12;
+        ContestWorkerReviewFixture::default_for_ids(worker_id, invalid_review_id).call_and_assert(
+            Err(Error::<Test, DefaultInstance>::WorkerReviewDoesNotExist.into()),
+        );
+    });
+}
+
+#[test]
+fn contest_worker_review_fails_with_contested_review() {
+    build_test_externalities().execute_with(|| {
+        let worker_id = HireRegularWorkerFixture::default().hire();
+
+        let review_id = FileWorkerReviewFixture::default_for_worker_id(worker_id)
+            .call_and_assert(Ok(()))
+            .unwrap();
+
+        let contest_review_fixture =
+            ContestWorkerReviewFixture::default_for_ids(worker_id, review_id);
+        contest_review_fixture.call_and_assert(Ok(()));
+
+        contest_review_fixture.call_and_assert(Err(
+            Error::<Test, DefaultInstance>::WorkerReviewCannotBeContested.into(),
+        ));
+    });
+}
+
+#[test]
+fn contested_worker_review_adjustment_is_suspended_until_upheld() {
+    build_test_externalities().execute_with(|| {
+        let reward_per_block = 10;
+
+        let worker_id = HireRegularWorkerFixture::default()
+            .with_reward_per_block(Some(reward_per_block))
+            .hire();
+
+        let worker = TestWorkingGroup::worker_by_id(worker_id).expect("Worker Must Exist");
+        let account_id = worker.reward_account_id;
+
+        SetBudgetFixture::default().execute();
+
+        let reward_adjustment = RewardAdjustment::Penalty(Percent::from_percent(50));
+        let review_id = FileWorkerReviewFixture::default_for_worker_id(worker_id)
+            .with_reward_adjustment(Some(reward_adjustment))
+            .call_and_assert(Ok(()))
+            .unwrap();
+
+        ContestWorkerReviewFixture::default_for_ids(worker_id, review_id).call_and_assert(Ok(()));
+
+        let reward_period: u64 = RewardPeriod::get().into();
+        let period_reward = reward_period * reward_per_block;
+
+        run_to_block(reward_period);
+
+        assert_eq!(Balances::usable_balance(&account_id), period_reward);
+        assert_eq!(
+            TestWorkingGroup::pending_reward_adjustment(worker_id),
+            Some((review_id, reward_adjustment))
+        );
+
+        ResolveWorkerReviewContestFixture::default_for_ids(worker_id, review_id)
+            .call_and_assert(Ok(()));
+
+        EventFixture::assert_last_crate_event(RawEvent::WorkerReviewContestResolved(
+            worker_id, review_id, true,
+        ));
+
+        run_to_block(reward_period * 2);
+
+        assert_eq!(
+            Balances::usable_balance(&account_id),
+            period_reward + period_reward / 2
+        );
+    });
+}
+
+#[test]
+fn overturned_worker_review_adjustment_is_discarded() {
+    build_test_externalities().execute_with(|| {
+        let reward_per_block = 10;
+
+        let worker_id = HireRegularWorkerFixture::default()
+            .with_reward_per_block(Some(reward_per_block))
+            .hire();
+
+        let worker = TestWorkingGroup::worker_by_id(worker_id).expect("Worker Must Exist");
+        let account_id = worker.reward_account_id;
+
+        SetBudgetFixture::default().execute();
+
+        let review_id = FileWorkerReviewFixture::default_for_worker_id(worker_id)
+            .with_reward_adjustment(Some(RewardAdjustment::Penalty(Percent::from_percent(50))))
+            .call_and_assert(Ok(()))
+            .unwrap();
+
+        ContestWorkerReviewFixture::default_for_ids(worker_id, review_id).call_and_assert(Ok(()));
+
+        ResolveWorkerReviewContestFixture::default_for_ids(worker_id, review_id)
+            .with_upheld(false)
+            .call_and_assert(Ok(()));
+
+        assert!(TestWorkingGroup::pending_reward_adjustment(worker_id).is_none());
+
+        let reward_period: u64 = RewardPeriod::get().into();
+        run_to_block(reward_period);
+
+        assert_eq!(
+            Balances::usable_balance(&account_id),
+            reward_period * reward_per_block
+        );
+    });
+}
+
+#[test]
+fn overturned_worker_review_refunds_withheld_reward() {
+    build_test_externalities().execute_with(|| {
+        let reward_per_block = 10;
+
+        let worker_id = HireRegularWorkerFixture::default()
+            .with_reward_per_block(Some(reward_per_block))
+            .hire();
+
+        let worker = TestWorkingGroup::worker_by_id(worker_id).expect("Worker Must Exist");
+        let account_id = worker.reward_account_id;
+
+        SetBudgetFixture::default().execute();
+
+        let review_id = FileWorkerReviewFixture::default_for_worker_id(worker_id)
+            .with_reward_adjustment(Some(RewardAdjustment::Penalty(Percent::from_percent(50))))
+            .call_and_assert(Ok(()))
+            .unwrap();
+
+        let reward_period: u64 = RewardPeriod::get().into();
+        let period_reward = reward_period * reward_per_block;
+
+        run_to_block(reward_period);
+
+        assert_eq!(Balances::usable_balance(&account_id), period_reward / 2);
+        assert_eq!(
+            TestWorkingGroup::worker_review_by_id(review_id)
+                .expect("Review Must Exist")
+                .withheld_reward,
+            period_reward / 2
+        );
+
+        ContestWorkerReviewFixture::default_for_ids(worker_id, review_id).call_and_assert(Ok(()));
+
+        ResolveWorkerReviewContestFixture::default_for_ids(worker_id, review_id)
+            .with_upheld(false)
+            .call_and_assert(Ok(()));
+
+        assert_eq!(Balances::usable_balance(&account_id), period_reward);
+
+        EventFixture::assert_last_crate_event(RawEvent::WorkerReviewContestResolved(
+            worker_id, review_id, false,
+        ));
+    });
+}
+
+#[test]
+fn upheld_worker_review_keeps_withheld_reward() {
+    build_test_externalities().execute_with(|| {
+        let reward_per_block = 10;
+
+        let worker_id = HireRegularWorkerFixture::default()
+            .with_reward_per_block(Some(reward_per_block))
+            .hire();
+
+        let worker = TestWorkingGroup::worker_by_id(worker_id).expect("Worker Must Exist");
+        let account_id = worker.reward_account_id;
+
+        SetBudgetFixture::default().execute();
+
+        let review_id = FileWorkerReviewFixture::default_for_worker_id(worker_id)
+            .with_reward_adjustment(Some(RewardAdjustment::Penalty(Percent::from_percent(50))))
+            .call_and_assert(Ok(()))
+            .unwrap();
+
+        let reward_period: u64 = RewardPeriod::get().into();
+        let period_reward = reward_period * reward_per_block;
+
+        run_to_block(reward_period);
+
+        ContestWorkerReviewFixture::default_for_ids(worker_id, review_id).call_and_assert(Ok(()));
+
+        ResolveWorkerReviewContestFixture::default_for_ids(worker_id, review_id)
+            .call_and_assert(Ok(()));
+
+        assert_eq!(Balances::usable_balance(&account_id), period_reward / 2);
+    });
+}
+
+#[test]
+fn resolve_worker_review_contest_fails_with_invalid_origin() {
+    build_test_externalities().execute_with(|| {
+        let worker_id = HireRegularWorkerFixture::default().hire();
+
+        let review_id = FileWorkerReviewFixture::default_for_worker_id(worker_id)
+            .call_and_assert(Ok(()))
+            .unwrap();
+
+        ContestWorkerReviewFixture::default_for_ids(worker_id, review_id).call_and_assert(Ok(()));
+
+        let lead_account_id = get_current_lead_account_id();
+        ResolveWorkerReviewContestFixture::default_for_ids(worker_id, review_id)
+            .with_origin(RawOrigin::Signed(lead_account_id))
+            .call_and_assert(Err(DispatchError::BadOrigin));
+    });
+}
+
+#[test]
+fn resolve_worker_review_contest_fails_with_not_contested_review() {
+    build_test_externalities().execute_with(|| {
+        let worker_id = HireRegularWorkerFixture::default().hire();
+
+        let review_id = FileWorkerReviewFixture::default_for_worker_id(worker_id)
+            .call_and_assert(Ok(()))
+            .unwrap();
+
+        ResolveWorkerReviewContestFixture::default_for_ids(worker_id, review_id).call_and_assert(
+            Err(Error::<Test, DefaultInstance>::WorkerReviewIsNotContested.into()),
+        );
+    });
+}
+
+// Hires the lead and two regular workers. Returns the ids of the workers.
+fn hire_deputy_and_regular_worker() -> (u64, u64) {
+    let deputy_id = HireRegularWorkerFixture::default().hire();
+
+    let worker_id = HiringWorkflow::default()
+        .with_setup_environment(false)
+        .add_application_full(b"worker2".to_vec(), RawOrigin::Signed(3), 3, 3)
+        .execute()
+        .unwrap();
+
+    (deputy_id, worker_id)
+}
+
+#[test]
+fn appoint_deputy_succeeded() {
+    build_test_externalities().execute_with(|| {
+        run_to_block(1);
+
+        let worker_id = HireRegularWorkerFixture::default().hire();
+
+        let permissions = vec![
+            LeadActionPermission::FillOpenings,
+            LeadActionPermission::SpendFromBudget,
+        ];
+        let spending_cap = 100;
+
+        AppointDeputyFixture::default_for_worker_id(worker_id)
+            .with_permissions(permissions.clone())
+            .with_spending_cap(spending_cap)
+            .call_and_assert(Ok(()));
+
+        EventFixture::assert_last_crate_event(RawEvent::DeputyAppointed(
+            worker_id,
+            permissions.into_iter().collect(),
+            spending_cap,
+        ));
+    });
+}
+
+#[test]
+fn appoint_deputy_fails_with_invalid_origin() {
+    build_test_externalities().execute_with(|| {
+        let worker_id = HireRegularWorkerFixture::default().hire();
+
+        AppointDeputyFixture::default_for_worker_id(worker_id)
+            .with_origin(RawOrigin::Signed(DEFAULT_WORKER_ACCOUNT_ID))
+            .call_and_assert(Err(Error::<Test, DefaultInstance>::IsNotLeadAccount.into()));
+
+        AppointDeputyFixture::default_for_worker_id(worker_id)
+            .with_origin(RawOrigin::Root)
+            .call_and_assert(Err(DispatchError::BadOrigin));
+    });
+}
+
+#[test]
+fn appoint_deputy_fails_for_the_lead() {
+    build_test_externalities().execute_with(|| {
+        let lead_id = HireLeadFixture::default().hire_lead();
+
+        AppointDeputyFixture::default_for_worker_id(lead_id).call_and_assert(Err(
+            Error::<Test, DefaultInstance>::CannotAppointLeadAsDeputy.into(),
+        ));
+    });
+}
+
+#[test]
+fn appoint_deputy_fails_for_leaving_worker() {
+    build_test_externalities().execute_with(|| {
+        let worker_id = HireRegularWorkerFixture::default().hire();
+
+        LeaveWorkerRoleFixture::default_for_worker_id(worker_id).call_and_assert(Ok(()));
+
+        AppointDeputyFixture::default_for_worker_id(worker_id)
+            .call_and_assert(Err(Error::<Test, DefaultInstance>::WorkerIsLeaving.into()));
+    });
+}
+
+#[test]
+fn deputy_slashes_worker_stake_on_behalf_of_the_lead() {
+    build_test_externalities().execute_with(|| {
+        run_to_block(1);
+
+        let (deputy_id, worker_id) = hire_deputy_and_regular_worker();
+
+        AppointDeputyFixture::default_for_worker_id(deputy_id)
+            .with_permissions(vec![LeadActionPermission::SlashWorkerStake])
+            .call_and_assert(Ok(()));
+
+        SlashWorkerStakeFixture::default_for_worker_id(worker_id)
+            .with_origin(RawOrigin::Signed(DEFAULT_WORKER_ACCOUNT_ID))
+            .with_account_id(3)
+            .call_and_assert(Ok(()));
+
+        EventFixture::assert_last_crate_event(RawEvent::DeputyActionPerformed(
+            deputy_id,
+            LeadActionPermission::SlashWorkerStake,
+        ));
+    });
+}
+
+#[test]
+fn deputy_action_fails_without_permission() {
+    build_test_externalities().execute_with(|| {
+        let (deputy_id, worker_id) = hire_deputy_and_regular_worker();
+
+        AppointDeputyFixture::default_for_worker_id(deputy_id)
+            .with_permissions(vec![LeadActionPermission::ReviewWorkers])
+            .call_and_assert(Ok(()));
+
+        SlashWorkerStakeFixture::default_for_worker_id(worker_id)
+            .with_origin(RawOrigin::Signed(DEFAULT_WORKER_ACCOUNT_ID))
+            .with_account_id(3)
+            .call_and_assert(Err(
+                Error::<Test, DefaultInstance>::DeputyLacksPermission.into()
+            ));
+    });
+}
+
+#[test]
+fn deputy_cannot_act_on_self_or_the_lead() {
+    build_test_externalities().execute_with(|| {
+        let deputy_id = HireRegularWorkerFixture::default().hire();
+        let lead_id = TestWorkingGroup::current_lead().unwrap();
+
+        AppointDeputyFixture::default_for_worker_id(deputy_id)
+            .with_permissions(vec![LeadActionPermission::UpdateWorkerReward])
+            .call_and_assert(Ok(()));
+
+        UpdateRewardAmountFixture::default_for_worker_id(deputy_id)
+            .with_origin(RawOrigin::Signed(DEFAULT_WORKER_ACCOUNT_ID))
+            .call_and_assert(Err(
+                Error::<Test, DefaultInstance>::DeputyCannotActOnSelf.into()
+            ));
+
+        UpdateRewardAmountFixture::default_for_worker_id(lead_id)
+            .with_origin(RawOrigin::Signed(DEFAULT_WORKER_ACCOUNT_ID))
+            .call_and_assert(Err(DispatchError::BadOrigin));
+    });
+}
+
+#[test]
+fn deputy_spending_is_limited_by_spending_cap() {
+    build_test_externalities().execute_with(|| {
+        run_to_block(1);
+
+        let account_id = 5;
+        let spending_cap = 100;
+
+        let deputy_id = HireRegularWorkerFixture::default().hire();
+
+        SetBudgetFixture::default().with_budget(1000).execute();
+
+        AppointDeputyFixture::default_for_worker_id(deputy_id)
+            .with_permissions(vec![LeadActionPermission::SpendFromBudget])
+            .with_spending_cap(spending_cap)
+            .call_and_assert(Ok(()));
+
+        let deputy_spend_fixture = SpendFromBudgetFixture::default()
+            .with_origin(RawOrigin::Signed(DEFAULT_WORKER_ACCOUNT_ID))
+            .with_account_id(account_id)
+            .with_amount(spending_cap / 2 + 1);
+
+        deputy_spend_fixture.call_and_assert(Ok(()));
+
+        EventFixture::assert_last_crate_event(RawEvent::DeputyActionPerformed(
+            deputy_id,
+            LeadActionPermission::SpendFromBudget,
+        ));
+
+        assert_eq!(
+            TestWorkingGroup::deputy_by_id(deputy_id)
+                .unwrap()
+                .spending_cap,
+            spending_cap / 2 - 1
+        );
+
+        deputy_spend_fixture.call_and_assert(Err(
+            Error::<Test, DefaultInstance>::DeputySpendingCapExceeded.into(),
+        ));
+
+        // The lead spending is not limited by the deputy spending cap.
+        SpendFromBudgetFixture::default()
+            .with_account_id(account_id)
+            .with_amount(spending_cap)
+            .call_and_assert(Ok(()));
+    });
+}
+
+#[test]
+fn revoke_deputy_succeeded() {
+    build_test_externalities().execute_with(|| {
+        run_to_block(1);
+
+        let (deputy_id, worker_id) = hire_deputy_and_regular_worker();
+
+        AppointDeputyFixture::default_for_worker_id(deputy_id)
+            .with_permissions(vec![LeadActionPermission::UpdateWorkerReward])
+            .call_and_assert(Ok(()));
+
+        RevokeDeputyFixture::default_for_worker_id(deputy_id).call_and_assert(Ok(()));
+
+        EventFixture::assert_last_crate_event(RawEvent::DeputyRevoked(deputy_id));
+
+        UpdateRewardAmountFixture::default_for_worker_id(worker_id)
+            .with_origin(RawOrigin::Signed(DEFAULT_WORKER_ACCOUNT_ID))
+            .call_and_assert(Err(Error::<Test, DefaultInstance>::IsNotLeadAccount.into()));
+    });
+}
+
+#[test]
+fn revoke_deputy_succeeded_by_council() {
+    build_test_externalities().execute_with(|| {
+        let deputy_id = HireRegularWorkerFixture::default().hire();
+
+        AppointDeputyFixture::default_for_worker_id(deputy_id).call_and_assert(Ok(()));
+
+        RevokeDeputyFixture::default_for_worker_id(deputy_id)
+            .with_origin(RawOrigin::Root)
+            .call_and_assert(Ok(()));
+    });
+}
+
+#[test]
+fn revoke_deputy_fails_with_invalid_origin() {
+    build_test_externalities().execute_with(|| {
+        let deputy_id = HireRegularWorkerFixture::default().hire();
+
+        AppointDeputyFixture::default_for_worker_id(deputy_id).call_and_assert(Ok(()));
+
+        RevokeDeputyFixture::default_for_worker_id(deputy_id)
+            .with_origin(RawOrigin::Signed(DEFAULT_WORKER_ACCOUNT_ID))
+            .call_and_assert(Err(Error::<Test, DefaultInstance>::IsNotLeadAccount.into()));
+    });
+}
+
+#[test]
+fn revoke_deputy_fails_with_not_deputy() {
+    build_test_externalities().execute_with(|| {
+        let worker_id = HireRegularWorkerFixture::default().hire();
+
+        RevokeDeputyFixture::default_for_worker_id(worker_id)
+            .call_and_assert(Err(Error::<Test, DefaultInstance>::WorkerIsNotDeputy.into()));
+    });
+}
+
+#[test]
+fn leaving_deputy_is_revoked() {
+    build_test_externalities().execute_with(|| {
+        let deputy_id = HireRegularWorkerFixture::default().hire();
+
+        AppointDeputyFixture::default_for_worker_id(deputy_id).call_and_assert(Ok(()));
+
+        LeaveWorkerRoleFixture::default_for_worker_id(deputy_id).call_and_assert(Ok(()));
+
+        assert!(TestWorkingGroup::deputy_by_id(deputy_id).is_none());
+        assert!(TestWorkingGroup::deputy_id_by_account_id(DEFAULT_WORKER_ACCOUNT_ID).is_none());
+    });
+}
+
+#[test]
+fn update_role_account_moves_deputy_role_account() {
+    build_test_externalities().execute_with(|| {
+        let new_role_account_id = 42;
+
+        let deputy_id = HireRegularWorkerFixture::default().hire();
+
+        AppointDeputyFixture::default_for_worker_id(deputy_id).call_and_assert(Ok(()));
+
+        UpdateWorkerRoleAccountFixture::default_with_ids(deputy_id, new_role_account_id)
+            .call_and_assert(Ok(()));
+
+        assert!(TestWorkingGroup::deputy_id_by_account_id(DEFAULT_WORKER_ACCOUNT_ID).is_none());
+        assert_eq!(
+            TestWorkingGroup::deputy_id_by_account_id(new_role_account_id),
+            Some(deputy_id)
+        );
+    });
+}
+
+#[test]
+fn create_recurring_payment_succeeded() {
+    build_test_externalities().execute_with(|| {
+        HireLeadFixture::default().hire_lead();
+
+        run_to_block(1);
+
+        let recurring_payment_id = CreateRecurringPaymentFixture::default()
+            .with_total_cap(Some(1000))
+            .call_and_assert(Ok(()))
+            .unwrap();
+
+        let recurring_payment = TestWorkingGroup::recurring_payment_by_id(recurring_payment_id)
+            .expect("Recurring payment must exist");
+
+        EventFixture::assert_last_crate_event(RawEvent::RecurringPaymentCreated(
+            recurring_payment_id,
+            recurring_payment,
+            None,
+        ));
+    });
+}
+
+#[test]
+fn create_recurring_payment_fails_with_invalid_origin() {
+    build_test_externalities().execute_with(|| {
+        HireLeadFixture::default().hire_lead();
+
+        CreateRecurringPaymentFixture::default()
+            .with_origin(RawOrigin::Signed(DEFAULT_WORKER_ACCOUNT_ID))
+            .call_and_assert(Err(Error::<Test, DefaultInstance>::IsNotLeadAccount.into()));
+
+        CreateRecurringPaymentFixture::default()
+            .with_origin(RawOrigin::Root)
+            .call_and_assert(Err(DispatchError::BadOrigin));
+    });
+}
+
+#[test]
+fn create_recurring_payment_fails_with_zero_amount() {
+    build_test_externalities().execute_with(|| {
+        HireLeadFixture::default().hire_lead();
+
+        CreateRecurringPaymentFixture::default()
+            .with_amount_per_period(0)
+            .call_and_assert(Err(Error::<Test, DefaultInstance>::CannotSpendZero.into()));
+
+        CreateRecurringPaymentFixture::default()
+            .with_total_cap(Some(0))
+            .call_and_assert(Err(Error::<Test, DefaultInstance>::CannotSpendZero.into()));
+    });
+}
+
+#[test]
+fn create_recurring_payment_fails_with_invalid_period() {
+    build_test_externalities().execute_with(|| {
+        HireLeadFixture::default().hire_lead();
+
+        CreateRecurringPaymentFixture::default()
+            .with_period(10, 10)
+            .call_and_assert(Err(
+                Error::<Test, DefaultInstance>::InvalidRecurringPaymentPeriod.into(),
+            ));
+
+        run_to_block(10);
+
+        CreateRecurringPaymentFixture::default()
+            .with_period(0, 10)
+            .call_and_assert(Err(
+                Error::<Test, DefaultInstance>::InvalidRecurringPaymentPeriod.into(),
+            ));
+    });
+}
+
+#[test]
+fn recurring_payments_are_paid_with_rewards() {
+    build_test_externalities().execute_with(|| {
+        HireLeadFixture::default().hire_lead();
+
+        SetBudgetFixture::default().execute();
+
+        let amount_per_period = 100;
+        let end_block = 10;
+        let recurring_payment_id = CreateRecurringPaymentFixture::default()
+            .with_amount_per_period(amount_per_period)
+            .with_period(0, end_block)
+            .call_and_assert(Ok(()))
+            .unwrap();
+
+        let reward_period: u64 = RewardPeriod::get().into();
+        run_to_block(reward_period);
+
+        assert_eq!(
+            Balances::usable_balance(&DEFAULT_WORKER_ACCOUNT_ID),
+            amount_per_period
+        );
+        EventFixture::assert_last_crate_event(RawEvent::RecurringPaymentPaid(
+            recurring_payment_id,
+            DEFAULT_WORKER_ACCOUNT_ID,
+            amount_per_period,
+            RewardPaymentType::RegularReward,
+        ));
+
+        run_to_block(end_block + reward_period);
+
+        assert_eq!(
+            Balances::usable_balance(&DEFAULT_WORKER_ACCOUNT_ID),
+            amount_per_period * end_block / reward_period
+        );
+        assert!(TestWorkingGroup::recurring_payment_by_id(recurring_payment_id).is_none());
+        EventFixture::contains_crate_event(RawEvent::RecurringPaymentCompleted(
+            recurring_payment_id,
+        ));
+    });
+}
+
+#[test]
+fn recurring_payments_are_limited_by_total_cap() {
+    build_test_externalities().execute_with(|| {
+        HireLeadFixture::default().hire_lead();
+
+        SetBudgetFixture::default().execute();
+
+        let total_cap = 150;
+        let recurring_payment_id = CreateRecurringPaymentFixture::default()
+            .with_amount_per_period(100)
+            .with_total_cap(Some(total_cap))
+            .call_and_assert(Ok(()))
+            .unwrap();
+
+        run_to_block(10);
+
+        assert_eq!(
+            Balances::usable_balance(&DEFAULT_WORKER_ACCOUNT_ID),
+            total_cap
+        );
+        assert!(TestWorkingGroup::recurring_payment_by_id(recurring_payment_id).is_none());
+        EventFixture::contains_crate_event(RawEvent::RecurringPaymentCompleted(
+            recurring_payment_id,
+        ));
+    });
+}
+
+#[test]
+fn recurring_payments_with_insufficient_budget_and_restored_budget() {
+    build_test_externalities().execute_with(|| {
+        HireLeadFixture::default().hire_lead();
+
+        let amount_per_period = 100;
+        let recurring_payment_id = CreateRecurringPaymentFixture::default()
+            .with_amount_per_period(amount_per_period)
+            .call_and_assert(Ok(()))
+            .unwrap();
+
+        run_to_block(4);
+
+        assert_eq!(Balances::usable_balance(&DEFAULT_WORKER_ACCOUNT_ID), 0);
+
+        let recurring_payment = TestWorkingGroup::recurring_payment_by_id(recurring_payment_id)
+            .expect("Recurring payment must exist");
+        assert_eq!(
+            recurring_payment.missed_payment,
+            Some(2 * amount_per_period)
+        );
+
+        SetBudgetFixture::default().execute();
+
+        run_to_block(6);
+
+        assert_eq!(
+            Balances::usable_balance(&DEFAULT_WORKER_ACCOUNT_ID),
+            3 * amount_per_period
+        );
+
+        let recurring_payment = TestWorkingGroup::recurring_payment_by_id(recurring_payment_id)
+            .expect("Recurring payment must exist");
+        assert_eq!(recurring_payment.missed_payment, None);
+        EventFixture::assert_last_crate_event(RawEvent::NewRecurringPaymentMissedAmountReached(
+            recurring_payment_id,
+            None,
+        ));
+    });
+}
+
+#[test]
+fn cancel_recurring_payment_succeeded() {
+    build_test_externalities().execute_with(|| {
+        HireLeadFixture::default().hire_lead();
+
+        let recurring_payment_id = CreateRecurringPaymentFixture::default()
+            .call_and_assert(Ok(()))
+            .unwrap();
+
+        run_to_block(1);
+
+        CancelRecurringPaymentFixture::default_for_recurring_payment_id(recurring_payment_id)
+            .call_and_assert(Ok(()));
+
+        EventFixture::assert_last_crate_event(RawEvent::RecurringPaymentCanceled(
+            recurring_payment_id,
+        ));
+
+        SetBudgetFixture::default().execute();
+
+        run_to_block(10);
+
+        assert_eq!(Balances::usable_balance(&DEFAULT_WORKER_ACCOUNT_ID), 0);
+    });
+}
+
+#[test]
+fn cancel_recurring_payment_succeeded_by_council() {
+    build_test_externalities().execute_with(|| {
+        HireLeadFixture::default().hire_lead();
+
+        let recurring_payment_id = CreateRecurringPaymentFixture::default()
+            .call_and_assert(Ok(()))
+            .unwrap();
+
+        CancelRecurringPaymentFixture::default_for_recurring_payment_id(recurring_payment_id)
+            .with_origin(RawOrigin::Root)
+            .call_and_assert(Ok(()));
+    });
+}
+
+#[test]
+fn cancel_recurring_payment_fails_with_invalid_origin() {
+    build_test_externalities().execute_with(|| {
+        HireLeadFixture::default().hire_lead();
+
+        let recurring_payment_id = CreateRecurringPaymentFixture::default()
+            .call_and_assert(Ok(()))
+            .unwrap();
+
+        CancelRecurringPaymentFixture::default_for_recurring_payment_id(recurring_payment_id)
+            .with_origin(RawOrigin::Signed(DEFAULT_WORKER_ACCOUNT_ID))
+            .call_and_assert(Err(Error::<Test, DefaultInstance>::IsNotLeadAccount.into()));
+    });
+}
+
+#[test]
+fn cancel_recurring_payment_fails_with_invalid_recurring_payment_id() {
+    build_test_externalities().execute_with(|| {
+        HireLeadFixture::default().hire_lead();
+
+        CancelRecurringPaymentFixture::default_for_recurring_payment_id(1).call_and_assert(Err(
+            Error::<Test, DefaultInstance>::RecurringPaymentDoesNotExist.into(),
+        ));
+    });
+}
+
+// Hires the lead and adds the regular opening with the applications of the provided members.
+fn add_opening_with_applications(member_ids: &[u64]) -> (u64, Vec<u64>) {
+    run_to_block(1);
+
+    HireLeadFixture::default().hire_lead();
+
+    let opening_id = AddOpeningFixture::default()
+        .with_starting_block(1)
+        .call_and_assert(Ok(()));
+
+    let application_ids = member_ids
+        .iter()
+        .map(|member_id| {
+            ApplyOnOpeningFixture::default_for_opening_id(opening_id)
+                .with_origin(RawOrigin::Signed(*member_id), *member_id)
+                .call_and_assert(Ok(()))
+        })
+        .collect();
+
+    (opening_id, application_ids)
+}
+
+#[test]
+fn shortlist_applications_succeeded() {
+    build_test_externalities().execute_with(|| {
+        let (opening_id, application_ids) = add_opening_with_applications(&[2, 3, 4]);
+
+        ShortlistApplicationsFixture::default_for_ids(opening_id, application_ids[..2].to_vec())
+            .with_application_count_witness(3)
+            .call_and_assert(Ok(()));
+
+        let shortlisted_application_ids: BTreeSet<u64> =
+            application_ids[..2].iter().copied().collect();
+        let dropped_application_ids: BTreeSet<u64> = application_ids[2..].iter().copied().collect();
+
+        EventFixture::contains_crate_event(RawEvent::ApplicationsShortlisted(
+            opening_id,
+            shortlisted_application_ids,
+            None,
+        ));
+        EventFixture::contains_crate_event(RawEvent::ApplicationsDropped(
+            opening_id,
+            dropped_application_ids,
+        ));
+        EventFixture::assert_last_crate_event(RawEvent::ApplicationSelectionStarted(
+            opening_id,
+            vec![(application_ids[0], 0), (application_ids[1], 0)],
+        ));
+
+        // Dropped application stake is released.
+        assert!(TestWorkingGroup::application_by_id(application_ids[2]).is_none());
+        assert_eq!(
+            Balances::usable_balance(&4),
+            <Test as Config>::MinimumApplicationStake::get()
+        );
+    });
+}
+
+#[test]
+fn shortlist_applications_fails_with_invalid_origin() {
+    build_test_externalities().execute_with(|| {
+        let (opening_id, application_ids) = add_opening_with_applications(&[2]);
+
+        ShortlistApplicationsFixture::default_for_ids(opening_id, application_ids)
+            .with_origin(RawOrigin::Signed(2))
+            .call_and_assert(Err(Error::<Test, DefaultInstance>::IsNotLeadAccount.into()));
+    });
+}
+
+#[test]
+fn shortlist_applications_fails_with_invalid_application_count_witness() {
+    build_test_externalities().execute_with(|| {
+        let (opening_id, application_ids) = add_opening_with_applications(&[2, 3]);
+
+        ShortlistApplicationsFixture::default_for_ids(opening_id, application_ids[..1].to_vec())
+            .call_and_assert(Err(
+                Error::<Test, DefaultInstance>::InvalidApplicationCountWitness.into(),
+            ));
+    });
+}
+
+#[test]
+fn shortlist_applications_fails_with_exceeding_max_worker_number() {
+    build_test_externalities().execute_with(|| {
+        let (opening_id, application_ids) = add_opening_with_applications(&[2, 3, 4, 5]);
+
+        ShortlistApplicationsFixture::default_for_ids(opening_id, application_ids).call_and_assert(
+            Err(Error::<Test, DefaultInstance>::MaxShortlistedApplicationsExceeded.into()),
+        );
+    });
+}
+
+#[test]
+fn shortlist_applications_fails_with_zero_endorsement_period() {
+    build_test_externalities().execute_with(|| {
+        let (opening_id, application_ids) = add_opening_with_applications(&[2]);
+
+        ShortlistApplicationsFixture::default_for_ids(opening_id, application_ids)
+            .with_endorsement_period(Some(0))
+            .call_and_assert(Err(
+                Error::<Test, DefaultInstance>::InvalidEndorsementPeriod.into(),
+            ));
+    });
+}
+
+#[test]
+fn shortlist_applications_fails_when_screening_already_started() {
+    build_test_externalities().execute_with(|| {
+        let (opening_id, application_ids) = add_opening_with_applications(&[2]);
+
+        let shortlist_applications_fixture =
+            ShortlistApplicationsFixture::default_for_ids(opening_id, application_ids);
+        shortlist_applications_fixture.call_and_assert(Ok(()));
+        shortlist_applications_fixture.call_and_assert(Err(
+            Error::<Test, DefaultInstance>::ApplicationScreeningAlreadyStarted.into(),
+        ));
+
+        ApplyOnOpeningFixture::default_for_opening_id(opening_id)
+            .with_origin(RawOrigin::Signed(3), 3)
+            .call_and_assert(Err(
+                Error::<Test, DefaultInstance>::ApplicationScreeningAlreadyStarted.into(),
+            ));
+    });
+}
+
+#[test]
+fn fill_opening_fails_at_endorsement_stage() {
+    build_test_externalities().execute_with(|| {
+        let (opening_id, application_ids) = add_opening_with_applications(&[2]);
+
+        ShortlistApplicationsFixture::default_for_ids(opening_id, application_ids.clone())
+            .with_endorsement_period(Some(10))
+            .call_and_assert(Ok(()));
+
+        FillOpeningFixture::default_for_ids(opening_id, application_ids).call_and_assert(Err(
+            Error::<Test, DefaultInstance>::ApplicationSelectionNotStarted.into(),
+        ));
+    });
+}
+
+#[test]
+fn fill_screened_opening_drops_not_selected_applications() {
+    build_test_externalities().execute_with(|| {
+        let (opening_id, application_ids) = add_opening_with_applications(&[2, 3]);
+
+        ShortlistApplicationsFixture::default_for_ids(opening_id, application_ids.clone())
+            .call_and_assert(Ok(()));
+
+        FillOpeningFixture::default_for_ids(opening_id, vec![application_ids[0]])
+            .with_created_at(1)
+            .call_and_assert(Ok(()));
+
+        EventFixture::assert_last_crate_event(RawEvent::ApplicationsDropped(
+            opening_id,
+            vec![application_ids[1]].into_iter().collect(),
+        ));
+
+        assert!(TestWorkingGroup::application_screening_stage(opening_id).is_none());
+        assert!(TestWorkingGroup::application_by_id(application_ids[1]).is_none());
+        assert_eq!(
+            Balances::usable_balance(&3),
+            <Test as Config>::MinimumApplicationStake::get()
+        );
+    });
+}
+
+#[test]
+fn endorse_application_succeeded() {
+    build_test_externalities().execute_with(|| {
+        let (opening_id, application_ids) = add_opening_with_applications(&[2]);
+
+        ShortlistApplicationsFixture::default_for_ids(opening_id, application_ids.clone())
+            .with_endorsement_period(Some(10))
+            .call_and_assert(Ok(()));
+
+        let stake = 100;
+        EndorseApplicationFixture::default_for_application_id(application_ids[0])
+            .with_stake(stake)
+            .call_and_assert(Ok(()));
+
+        EventFixture::assert_last_crate_event(RawEvent::ApplicationEndorsed(
+            application_ids[0],
+            6,
+            6,
+            stake,
+        ));
+    });
+}
+
+#[test]
+fn endorse_application_fails_with_inactive_endorsement_stage() {
+    build_test_externalities().execute_with(|| {
+        let (opening_id, application_ids) = add_opening_with_applications(&[2, 3]);
+
+        // No screening.
+        EndorseApplicationFixture::default_for_application_id(application_ids[0]).call_and_assert(
+            Err(Error::<Test, DefaultInstance>::ApplicationEndorsementStageIsNotActive.into()),
+        );
+
+        ShortlistApplicationsFixture::default_for_ids(opening_id, application_ids.clone())
+            .with_endorsement_period(Some(10))
+            .call_and_assert(Ok(()));
+
+        run_to_block(11);
+
+        // Endorsement stage ended.
+        EndorseApplicationFixture::default_for_application_id(application_ids[0]).call_and_assert(
+            Err(Error::<Test, DefaultInstance>::ApplicationEndorsementStageIsNotActive.into()),
+        );
+    });
+}
+
+#[test]
+fn endorse_application_fails_with_zero_stake() {
+    build_test_externalities().execute_with(|| {
+        let (opening_id, application_ids) = add_opening_with_applications(&[2]);
+
+        ShortlistApplicationsFixture::default_for_ids(opening_id, application_ids.clone())
+            .with_endorsement_period(Some(10))
+            .call_and_assert(Ok(()));
+
+        EndorseApplicationFixture::default_for_application_id(application_ids[0])
+            .with_stake(0)
+            .call_and_assert(Err(
+                Error::<Test, DefaultInstance>::CannotEndorseWithZeroStake.into(),
+            ));
+    });
+}
+
+#[test]
+fn endorse_application_fails_when_already_endorsed() {
+    build_test_externalities().execute_with(|| {
+        let (opening_id, application_ids) = add_opening_with_applications(&[2]);
+
+        ShortlistApplicationsFixture::default_for_ids(opening_id, application_ids.clone())
+            .with_endorsement_period(Some(10))
+            .call_and_assert(Ok(()));
+
+        let endorse_application_fixture =
+            EndorseApplicationFixture::default_for_application_id(application_ids[0]);
+        endorse_application_fixture.call_and_assert(Ok(()));
+        endorse_application_fixture.call_and_assert(Err(
+            Error::<Test, DefaultInstance>::ApplicationAlreadyEndorsed.into(),
+        ));
+    });
+}
+
+#[test]
+fn withdraw_application_endorsement_succeeded() {
+    build_test_externalities().execute_with(|| {
+        let (opening_id, application_ids) = add_opening_with_applications(&[2]);
+
+        ShortlistApplicationsFixture::default_for_ids(opening_id, application_ids.clone())
+            .with_endorsement_period(Some(10))
+            .call_and_assert(Ok(()));
+
+        let stake = 100;
+        EndorseApplicationFixture::default_for_application_id(application_ids[0])
+            .with_stake(stake)
+            .call_and_assert(Ok(()));
+
+        WithdrawApplicationEndorsementFixture::default_for_application_id(application_ids[0])
+            .call_and_assert(Ok(()));
+
+        EventFixture::assert_last_crate_event(RawEvent::ApplicationEndorsementWithdrawn(
+            application_ids[0],
+            6,
+        ));
+
+        assert_eq!(Balances::usable_balance(&6), stake);
+        assert_eq!(
+            TestWorkingGroup::application_endorsement_stake(application_ids[0]),
+            0
+        );
+    });
+}
+
+#[test]
+fn withdraw_application_endorsement_fails_with_invalid_endorsement() {
+    build_test_externalities().execute_with(|| {
+        let (_, application_ids) = add_opening_with_applications(&[2]);
+
+        WithdrawApplicationEndorsementFixture::default_for_application_id(application_ids[0])
+            .call_and_assert(Err(
+                Error::<Test, DefaultInstance>::ApplicationEndorsementDoesNotExist.into(),
+            ));
+    });
+}
+
+#[test]
+fn start_application_selection_ranks_applications_by_endorsement_stake() {
+    build_test_externalities().execute_with(|| {
+        let (opening_id, application_ids) = add_opening_with_applications(&[2, 3]);
+
+        let endorsement_period = 10;
+        ShortlistApplicationsFixture::default_for_ids(opening_id, application_ids.clone())
+            .with_endorsement_period(Some(endorsement_period))
+            .call_and_assert(Ok(()));
+
+        EndorseApplicationFixture::default_for_application_id(application_ids[0])
+            .with_stake(100)
+            .call_and_assert(Ok(()));
+        EndorseApplicationFixture::default_for_application_id(application_ids[1])
+            .with_member(7)
+            .with_stake(200)
+            .call_and_assert(Ok(()));
+        EndorseApplicationFixture::default_for_application_id(application_ids[1])
+            .with_member(8)
+            .with_stake(50)
+            .call_and_assert(Ok(()));
+
+        let start_application_selection_fixture =
+            StartApplicationSelectionFixture::default_for_opening_id(opening_id);
+
+        start_application_selection_fixture.call_and_assert(Err(
+            Error::<Test, DefaultInstance>::ApplicationEndorsementStageNotEnded.into(),
+        ));
+
+        run_to_block(1 + endorsement_period);
+
+        start_application_selection_fixture.call_and_assert(Ok(()));
+
+        EventFixture::assert_last_crate_event(RawEvent::ApplicationSelectionStarted(
+            opening_id,
+            vec![(application_ids[1], 250), (application_ids[0], 100)],
+        ));
+
+        start_application_selection_fixture.call_and_assert(Err(
+            Error::<Test, DefaultInstance>::ApplicationEndorsementStageIsNotActive.into(),
+        ));
+    });
+}
+
+#[test]
+fn application_index_migration_lets_screening_drop_applications_submitted_before_upgrade() {
+    build_test_externalities().execute_with(|| {
+        let (opening_id, application_ids) = add_opening_with_applications(&[2, 3]);
+
+        // Simulate applications submitted before the application index was introduced.
+        for application_id in application_ids.iter() {
+            <crate::ApplicationIdsByOpeningId<DefaultInstance>>::remove(opening_id, application_id);
+        }
+        StorageVersion::new(0).put::<TestWorkingGroup>();
+
+        MigrateToV1::<Test, DefaultInstance>::on_runtime_upgrade();
+
+        for application_id in application_ids.iter() {
+            assert!(
+                <crate::ApplicationIdsByOpeningId<DefaultInstance>>::contains_key(
+                    opening_id,
+                    application_id
+                )
+            );
+        }
+
+        ShortlistApplicationsFixture::default_for_ids(opening_id, vec![application_ids[0]])
+            .with_application_count_witness(2)
+            .call_and_assert(Ok(()));
+
+        assert!(TestWorkingGroup::application_by_id(application_ids[1]).is_none());
+        assert_eq!(
+            Balances::usable_balance(&3),
+            <Test as Config>::MinimumApplicationStake::get()
+        );
+    });
+}
+
+#[test]
+fn endorse_application_fails_with_max_endorsements_exceeded() {
+    build_test_externalities().execute_with(|| {
+        let (opening_id, application_ids) = add_opening_with_applications(&[2]);
+
+        ShortlistApplicationsFixture::default_for_ids(opening_id, application_ids.clone())
+            .with_endorsement_period(Some(10))
+            .call_and_assert(Ok(()));
+
+        let max_endorsements: u64 = <Test as Config>::MaxApplicationEndorsements::get().into();
+        for member_id in 0..max_endorsements {
+            EndorseApplicationFixture::default_for_application_id(application_ids[0])
+                .with_member(5 + member_id)
+                .call_and_assert(Ok(()));
+        }
+
+        EndorseApplicationFixture::default_for_application_id(application_ids[0])
+            .with_member(5 + max_endorsements)
+            .call_and_assert(Err(
+                Error::<Test, DefaultInstance>::MaxApplicationEndorsementsExceeded.into(),
+            ));
+    });
+}
+
+#[test]
+fn fill_screened_opening_releases_endorsement_stakes() {
+    build_test_externalities().execute_with(|| {
+        let (opening_id, application_ids) = add_opening_with_applications(&[2, 3]);
+
+        let endorsement_period = 10;
+        ShortlistApplicationsFixture::default_for_ids(opening_id, application_ids.clone())
+            .with_endorsement_period(Some(endorsement_period))
+            .call_and_assert(Ok(()));
+
+        EndorseApplicationFixture::default_for_application_id(application_ids[0])
+            .with_stake(100)
+            .call_and_assert(Ok(()));
+        EndorseApplicationFixture::default_for_application_id(application_ids[1])
+            .with_member(7)
+            .with_stake(200)
+            .call_and_assert(Ok(()));
+
+        run_to_block(1 + endorsement_period);
+
+        StartApplicationSelectionFixture::default_for_opening_id(opening_id)
+            .call_and_assert(Ok(()));
+
+        FillOpeningFixture::default_for_ids(opening_id, vec![application_ids[0]])
+            .with_created_at(1 + endorsement_period)
+            .call_and_assert(Ok(()));
+
+        assert_eq!(Balances::usable_balance(&6), 100);
+        assert_eq!(Balances::usable_balance(&7), 200);
+
+        for (application_id, member_id) in application_ids.iter().zip([6u64, 7u64].iter()) {
+            assert!(
+                TestWorkingGroup::application_endorsement_by_id(application_id, member_id)
+                    .is_none()
+            );
+            assert_eq!(
+                TestWorkingGroup::application_endorsement_count(application_id),
+                0
+            );
+            assert_eq!(
+                TestWorkingGroup::application_endorsement_stake(application_id),
+                0
+            );
+        }
+    });
+}
+
+#[test]
+fn cancel_screened_opening_releases_endorsement_stakes() {
+    build_test_externalities().execute_with(|| {
+        let (opening_id, application_ids) = add_opening_with_applications(&[2]);
+
+        ShortlistApplicationsFixture::default_for_ids(opening_id, application_ids.clone())
+            .with_endorsement_period(Some(10))
+            .call_and_assert(Ok(()));
+
+        let stake = 100;
+        EndorseApplicationFixture::default_for_application_id(application_ids[0])
+            .with_stake(stake)
+            .call_and_assert(Ok(()));
+
+        CancelOpeningFixture::default_for_opening_id(opening_id).call_and_assert(Ok(()));
+
+        EventFixture::contains_crate_event(RawEvent::ApplicationEndorsementWithdrawn(
+            application_ids[0],
+            6,
+        ));
+
+        assert_eq!(Balances::usable_balance(&6), stake);
+        assert!(TestWorkingGroup::application_screening_stage(opening_id).is_none());
+        assert!(TestWorkingGroup::application_endorsement_by_id(application_ids[0], 6).is_none());
+        assert_eq!(
+            TestWorkingGroup::application_endorsement_count(application_ids[0]),
+            0
+        );
+
+        // The application is kept to be withdrawn by the applicant.
+        assert!(TestWorkingGroup::application_by_id(application_ids[0]).is_some());
+    });
+}
+
+#[test]
+fn set_status_text_succeeded() {
+    build_test_externalities().execute_with(|| {
+        HireLeadFixture::default().hire_lead();
+
+        run_to_block(1);
+
+        let status_text = b"some".to_vec();
+        SetStatusTextFixture::default()
+            .with_status_text(Some(status_text.clone()))
+            .call_and_assert(Ok(()));
+
+        let expected_hash = <Test as frame_system::Config>::Hashing::hash(&status_text);
+        EventFixture::assert_last_crate_event(RawEvent::StatusTextChanged(
+            expected_hash,
+            Some(status_text),
+        ));
+    });
+}
+
+#[test]
+fn set_status_text_fails_with_bad_origin() {
+    build_test_externalities().execute_with(|| {
+        HireLeadFixture::default().hire_lead();
+        let leader_account_id = 10;
+
+        SetStatusTextFixture::default()
+            .with_origin(RawOrigin::Signed(leader_account_id))
+            .call_and_assert(Err(Error::<Test, DefaultInstance>::IsNotLeadAccount.into()));
+    });
+}
+
+#[test]
+fn spend_from_budget_succeeded() {
+    build_test_externalities().execute_with(|| {
+        let account_id = 2;
+        let amount = 100;
+        HireLeadFixture::default().hire_lead();
+
+        run_to_block(1);
+
+        let set_budget_fixture = SetBudgetFixture::default().with_budget(1000);
+        assert_eq!(set_budget_fixture.call(), Ok(()));
+
+        SpendFromBudgetFixture::default()
+            .with_account_id(account_id)
+            .with_amount(amount)
+            .call_and_assert(Ok(()));
+
+        EventFixture::assert_last_crate_event(RawEvent::BudgetSpending(account_id, amount, None));
+    });
+}
+
+#[test]
+fn vested_spend_from_budget_succeeded_with_vesting_correctly_added() {
+    let get_vesting = |account_id: u64| -> VestingInfo<u64, u64> {
+        let v = Vesting::vesting(&account_id).unwrap();
+        v.last().copied().unwrap()
+    };
+    let account_id = 2;
+    let amount = 100;
+    let vesting_period = 10;
+    let per_block = amount / vesting_period;
+    let mut block_number = 1;
+    let vested_amount = |block_number: u64| -> u64 { per_block * (block_number - 1) };
+    build_test_externalities().execute_with(|| {
+        HireLeadFixture::default().hire_lead();
+
+        run_to_block(block_number);
+
+        let set_budget_fixture = SetBudgetFixture::default().with_budget(1000);
+        assert_eq!(set_budget_fixture.call(), Ok(()));
+
+        assert_ok!(VestedSpendFromBudgetFixture::default()
+            .with_account_id(account_id)
+            .with_vesting_schedule(amount, per_block)
+            .call());
+
+        // amount at vesting block should be amount / 10
+        assert_eq!(
+            get_vesting(account_id).locked_at::<BlockNumberToBalance>(block_number),
+            amount - vested_amount(block_number)
+        );
+
+        // halfway through the vesting period amount vested should be exactly half
         block_number += vesting_period / 2; // current block = 4
         run_to_block(block_number);
         assert_eq!(
@@ -2882,3 +4575,288 @@ fn worker_remark_ok() {
         EventFixture::assert_last_crate_event(RawEvent::WorkerRemarked(worker_id, Vec::new()));
     });
 }
+
+#[test]
+fn set_worker_transfer_approval_succeeded() {
+    build_test_externalities().execute_with(|| {
+        run_to_block(1);
+
+        let worker_id = HireRegularWorkerFixture::default().hire();
+
+        SetWorkerTransferApprovalFixture::default_for_worker_id(worker_id).call_and_assert(Ok(()));
+
+        EventFixture::assert_last_crate_event(RawEvent::WorkerTransferApprovalSet(
+            worker_id,
+            Some(WorkingGroup::Storage),
+        ));
+
+        assert!(!TestWorkingGroup::is_worker_transfer_approved(
+            &worker_id,
+            WorkingGroup::Distribution
+        ));
+    });
+}
+
+#[test]
+fn set_worker_transfer_approval_succeeded_with_revoked_approval() {
+    build_test_externalities().execute_with(|| {
+        run_to_block(1);
+
+        let worker_id = HireRegularWorkerFixture::default().hire();
+
+        SetWorkerTransferApprovalFixture::default_for_worker_id(worker_id).call_and_assert(Ok(()));
+
+        SetWorkerTransferApprovalFixture::default_for_worker_id(worker_id)
+            .with_destination(None)
+            .call_and_assert(Ok(()));
+
+        EventFixture::assert_last_crate_event(RawEvent::WorkerTransferApprovalSet(worker_id, None));
+
+        assert!(!TestWorkingGroup::is_worker_transfer_approved(
+            &worker_id,
+            WorkingGroup::Storage
+        ));
+    });
+}
+
+#[test]
+fn set_worker_transfer_approval_fails_with_invalid_origin() {
+    build_test_externalities().execute_with(|| {
+        let worker_id = HireRegularWorkerFixture::default().hire();
+
+        SetWorkerTransferApprovalFixture::default_for_worker_id(worker_id)
+            .with_origin(RawOrigin::Signed(DEFAULT_WORKER_ACCOUNT_ID))
+            .call_and_assert(Err(Error::<Test, DefaultInstance>::IsNotLeadAccount.into()));
+    });
+}
+
+#[test]
+fn set_worker_transfer_approval_fails_with_invalid_worker_id() {
+    build_test_externalities().execute_with(|| {
+        HireLeadFixture::default().hire_lead();
+
+        let invalid_worker_id = 11;
+
+        SetWorkerTransferApprovalFixture::default_for_worker_id(invalid_worker_id).call_and_assert(
+            Err(Error::<Test, DefaultInstance>::WorkerDoesNotExist.into()),
+        );
+    });
+}
+
+#[test]
+fn set_worker_transfer_approval_fails_with_lead() {
+    build_test_externalities().execute_with(|| {
+        let lead_id = HireLeadFixture::default().hire_lead();
+
+        SetWorkerTransferApprovalFixture::default_for_worker_id(lead_id).call_and_assert(Err(
+            Error::<Test, DefaultInstance>::CannotTransferLead.into(),
+        ));
+    });
+}
+
+#[test]
+fn set_worker_transfer_approval_fails_with_leaving_worker() {
+    build_test_externalities().execute_with(|| {
+        let worker_id = HireRegularWorkerFixture::default().hire();
+
+        LeaveWorkerRoleFixture::default_for_worker_id(worker_id).call_and_assert(Ok(()));
+
+        SetWorkerTransferApprovalFixture::default_for_worker_id(worker_id)
+            .call_and_assert(Err(Error::<Test, DefaultInstance>::WorkerIsLeaving.into()));
+    });
+}
+
+#[test]
+fn transfer_worker_out_keeps_stake_locked() {
+    build_test_externalities().execute_with(|| {
+        run_to_block(1);
+
+        let worker_id = HireRegularWorkerFixture::default().hire();
+
+        AppointDeputyFixture::default_for_worker_id(worker_id).call_and_assert(Ok(()));
+        SetWorkerTransferApprovalFixture::default_for_worker_id(worker_id).call_and_assert(Ok(()));
+
+        let worker_count = TestWorkingGroup::active_worker_count();
+        let transferred_worker =
+            TestWorkingGroup::ensure_worker_can_be_transferred(&worker_id).unwrap();
+
+        assert_eq!(transferred_worker.lock_id, LockId::get());
+
+        TestWorkingGroup::transfer_worker_out(&worker_id, WorkingGroup::Storage);
+
+        EventFixture::assert_last_crate_event(RawEvent::WorkerTransferredOut(
+            worker_id,
+            WorkingGroup::Storage,
+        ));
+
+        assert!(TestWorkingGroup::worker_by_id(worker_id).is_none());
+        assert!(TestWorkingGroup::deputy_by_id(worker_id).is_none());
+        assert!(TestWorkingGroup::worker_transfer_approval_by_id(worker_id).is_none());
+        assert_eq!(TestWorkingGroup::active_worker_count(), worker_count - 1);
+        assert_eq!(
+            <Test as Config>::StakingHandler::current_stake(&DEFAULT_WORKER_ACCOUNT_ID),
+            <Test as Config>::MinimumApplicationStake::get()
+        );
+    });
+}
+
+#[test]
+fn transfer_worker_out_pays_reward_accrued_since_last_reward_block() {
+    build_test_externalities().execute_with(|| {
+        let reward_per_block = 10;
+
+        let worker_id = HireRegularWorkerFixture::default()
+            .with_reward_per_block(Some(reward_per_block))
+            .hire();
+        let account_id = TestWorkingGroup::worker_by_id(worker_id)
+            .expect("Worker Must Exist")
+            .reward_account_id;
+        let initial_balance = Balances::free_balance(&account_id);
+
+        SetBudgetFixture::default().execute();
+
+        let reward_block: u64 = RewardPeriod::get().into();
+        let transfer_block = reward_block + 1;
+        run_to_block(transfer_block);
+
+        assert_eq!(
+            Balances::free_balance(&account_id),
+            initial_balance + reward_block * reward_per_block
+        );
+
+        SetWorkerTransferApprovalFixture::default_for_worker_id(worker_id).call_and_assert(Ok(()));
+
+        TestWorkingGroup::transfer_worker_out(&worker_id, WorkingGroup::Storage);
+
+        // The blocks since the last reward block are paid on the transfer.
+        EventFixture::contains_crate_event(RawEvent::RewardPaid(
+            worker_id,
+            account_id,
+            (transfer_block - reward_block) * reward_per_block,
+            RewardPaymentType::RegularReward,
+        ));
+        assert_eq!(
+            Balances::free_balance(&account_id),
+            initial_balance + transfer_block * reward_per_block
+        );
+    });
+}
+
+#[test]
+fn transfer_worker_in_replaces_stake_lock_and_keeps_tenure() {
+    build_test_externalities().execute_with(|| {
+        run_to_block(1);
+
+        let account_id = DEFAULT_WORKER_ACCOUNT_ID;
+        let stake = 100;
+        let created_at = 0;
+
+        increase_total_balance_issuance_using_account_id(account_id, stake);
+        set_invitation_lock(&account_id, stake);
+
+        let transferred_worker = TransferredWorker {
+            member_id: 2,
+            role_account_id: account_id,
+            staking_account_id: account_id,
+            reward_account_id: account_id,
+            job_unstaking_period: <Test as Config>::MinUnstakingPeriodLimit::get(),
+            reward_per_block: None,
+            created_at,
+            lock_id: InvitedMemberLockId::get(),
+        };
+
+        assert_ok!(TestWorkingGroup::ensure_worker_can_be_accepted(
+            &transferred_worker
+        ));
+
+        let worker_id =
+            TestWorkingGroup::transfer_worker_in(transferred_worker, WorkingGroup::Storage);
+
+        EventFixture::assert_last_crate_event(RawEvent::WorkerTransferredIn(
+            worker_id,
+            WorkingGroup::Storage,
+        ));
+
+        let worker = TestWorkingGroup::worker_by_id(worker_id).expect("Worker Must Exist");
+
+        assert_eq!(worker.created_at, created_at);
+        assert_eq!(TestWorkingGroup::worker_transferred_at(worker_id), Some(1));
+        assert_eq!(TestWorkingGroup::active_worker_count(), 1);
+        assert_eq!(
+            <Test as Config>::StakingHandler::current_stake(&account_id),
+            stake
+        );
+        assert_eq!(
+            <Test as membership::Config>::InvitedMemberStakingHandler::current_stake(&account_id),
+            0
+        );
+    });
+}
+
+#[test]
+fn transfer_worker_in_fails_with_conflicting_stakes() {
+    build_test_externalities().execute_with(|| {
+        HireRegularWorkerFixture::default().hire();
+
+        let account_id = DEFAULT_WORKER_ACCOUNT_ID;
+        let stake = 100;
+
+        increase_total_balance_issuance_using_account_id(account_id, stake);
+        set_invitation_lock(&account_id, stake);
+
+        let transferred_worker = TransferredWorker {
+            member_id: 2,
+            role_account_id: account_id,
+            staking_account_id: account_id,
+            reward_account_id: account_id,
+            job_unstaking_period: <Test as Config>::MinUnstakingPeriodLimit::get(),
+            reward_per_block: None,
+            created_at: 0,
+            lock_id: InvitedMemberLockId::get(),
+        };
+
+        assert_eq!(
+            TestWorkingGroup::ensure_worker_can_be_accepted(&transferred_worker),
+            Err(Error::<Test, DefaultInstance>::ConflictStakesOnAccount.into())
+        );
+    });
+}
+
+#[test]
+fn transferred_worker_is_rewarded_since_transfer() {
+    build_test_externalities().execute_with(|| {
+        run_to_block(1);
+
+        let account_id = DEFAULT_WORKER_ACCOUNT_ID;
+        let reward_account_id = 3;
+        let stake = 100;
+        let reward_per_block = 10;
+
+        increase_total_balance_issuance_using_account_id(account_id, stake);
+        set_invitation_lock(&account_id, stake);
+
+        SetBudgetFixture::default().with_budget(100000).execute();
+
+        TestWorkingGroup::transfer_worker_in(
+            TransferredWorker {
+                member_id: 2,
+                role_account_id: account_id,
+                staking_account_id: account_id,
+                reward_account_id,
+                job_unstaking_period: <Test as Config>::MinUnstakingPeriodLimit::get(),
+                reward_per_block: Some(reward_per_block),
+                created_at: 0,
+                lock_id: InvitedMemberLockId::get(),
+            },
+            WorkingGroup::Storage,
+        );
+
+        run_to_block(2);
+
+        // Only the block after the transfer is rewarded.
+        assert_eq!(
+            Balances::usable_balance(&reward_account_id),
+            reward_per_block
+        );
+    });
+}
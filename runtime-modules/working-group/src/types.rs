@@ -1,9 +1,15 @@
 #![warn(missing_docs)]
 
 use codec::{Decode, Encode, MaxEncodedLen};
+use frame_support::parameter_types;
+use frame_support::storage::bounded_btree_set::BoundedBTreeSet;
 use frame_support::traits::Currency;
 use scale_info::TypeInfo;
+use sp_arithmetic::traits::AtLeast32BitUnsigned;
+use sp_arithmetic::{PerThing, Percent};
+use sp_std::collections::btree_set::BTreeSet;
 use sp_std::vec::Vec;
+use varaint_count::VariantCount;
 
 #[cfg(feature = "std")]
 use serde::{Deserialize, Serialize};
@@ -27,6 +33,15 @@ pub type ApplicationId = u64;
 /// Type for an opening id.
 pub type OpeningId = u64;
 
+/// Type for a worker performance review id.
+pub type WorkerReviewId = u64;
+
+/// Type for a recurring payment id.
+pub type RecurringPaymentId = u64;
+
+/// Max rating of the worker performance review.
+pub const MAX_WORKER_REVIEW_RATING: u8 = 10;
+
 // ApplicationId - Application - helper struct.
 pub(crate) struct ApplicationInfo<T: crate::Config<I>, I: crate::Instance> {
     pub application_id: ApplicationId,
@@ -34,22 +49,6 @@ pub(crate) struct ApplicationInfo<T: crate::Config<I>, I: crate::Instance> {
     pub marker: PhantomData<I>,
 }
 
-// WorkerId - Worker - helper struct.
-pub(crate) struct WorkerInfo<
-    T: common::membership::MembershipTypes + frame_system::Config + balances::Config,
-> {
-    pub worker_id: WorkerId<T>,
-    pub worker: Worker<T>,
-}
-
-impl<T: common::membership::MembershipTypes + frame_system::Config + balances::Config>
-    From<(WorkerId<T>, Worker<T>)> for WorkerInfo<T>
-{
-    fn from((worker_id, worker): (WorkerId<T>, Worker<T>)) -> Self {
-        WorkerInfo { worker_id, worker }
-    }
-}
-
 /// Group worker type alias.
 pub type Worker<T> = GroupWorker<
     <T as frame_system::Config>::AccountId,
@@ -58,6 +57,14 @@ pub type Worker<T> = GroupWorker<
     BalanceOf<T>,
 >;
 
+/// Transferred worker type alias.
+pub type TransferredWorkerOf<T> = common::working_group::TransferredWorker<
+    <T as frame_system::Config>::AccountId,
+    MemberId<T>,
+    <T as frame_system::Config>::BlockNumber,
+    BalanceOf<T>,
+>;
+
 /// Balance alias for `balances` module.
 pub type BalanceOf<T> = <T as balances::Config>::Balance;
 
@@ -225,6 +232,33 @@ impl<AccountId: Clone, MemberId: Clone, BlockNumber, Balance>
     }
 }
 
+/// Progress of the worker reward payout round.
+///
+/// A round starts on a reward block and pays a limited number of workers per block until all the
+/// workers are rewarded. The recurring payments are paid out after the workers.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Debug, Clone, Default, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+pub struct RewardPayoutRound<BlockNumber, WorkerId> {
+    /// Reward block the round was started at.
+    pub reward_block: BlockNumber,
+
+    /// Beginning of the rewarded period: previous reward block.
+    pub rewarded_since: BlockNumber,
+
+    /// Last rewarded worker. The next block continues the payouts after this worker.
+    pub last_rewarded_worker_id: Option<WorkerId>,
+
+    /// Defines whether all the workers were rewarded in the round.
+    pub workers_rewarded: bool,
+
+    /// Last paid recurring payment. The next block continues the payouts after this payment.
+    pub last_paid_recurring_payment_id: Option<RecurringPaymentId>,
+}
+
+/// RewardPayoutRound type alias.
+pub type RewardPayoutRoundOf<T> =
+    RewardPayoutRound<<T as frame_system::Config>::BlockNumber, WorkerId<T>>;
+
 /// Stake policy for the job opening.
 #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 #[derive(Encode, Decode, Debug, Clone, Default, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
@@ -288,3 +322,205 @@ pub enum RewardPaymentType {
     /// The reward was paid in time.
     RegularReward,
 }
+
+/// Worker reward adjustment defined by the performance review.
+/// Applies to the next reward payout of the worker.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Debug, Clone, PartialEq, Eq, Copy, TypeInfo, MaxEncodedLen)]
+pub enum RewardAdjustment {
+    /// Increases the reward by the provided percentage.
+    Bonus(Percent),
+
+    /// Decreases the reward by the provided percentage.
+    Penalty(Percent),
+}
+
+impl RewardAdjustment {
+    /// Applies the adjustment to the reward.
+    pub fn apply<Balance: AtLeast32BitUnsigned + Copy>(&self, reward: Balance) -> Balance {
+        match self {
+            RewardAdjustment::Bonus(percent) => reward.saturating_add(percent.mul_floor(reward)),
+            RewardAdjustment::Penalty(percent) => reward.saturating_sub(percent.mul_floor(reward)),
+        }
+    }
+}
+
+/// Worker performance review status.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Debug, Clone, PartialEq, Eq, Copy, TypeInfo, MaxEncodedLen)]
+pub enum WorkerReviewStatus {
+    /// The review was filed.
+    Filed,
+
+    /// The worker contested the review, the council decision is pending.
+    /// The review is removed once the council resolves the contest.
+    Contested,
+}
+
+/// Worker performance review filed by the lead (or the council for the lead).
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Debug, Clone, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+pub struct WorkerReview<WorkerId, BlockNumber, Hash, Balance> {
+    /// Reviewed worker id.
+    pub worker_id: WorkerId,
+
+    /// Block the review was filed at.
+    pub filed_at: BlockNumber,
+
+    /// Worker performance rating.
+    pub rating: u8,
+
+    /// Hash of the review metadata.
+    pub metadata_hash: Hash,
+
+    /// Optional reward adjustment for the next reward payout.
+    pub reward_adjustment: Option<RewardAdjustment>,
+
+    /// Review status.
+    pub status: WorkerReviewStatus,
+
+    /// Reward withheld from the worker by the applied penalty. Refunded when the council
+    /// overturns the review.
+    pub withheld_reward: Balance,
+}
+
+/// WorkerReview type alias.
+pub type WorkerReviewOf<T> = WorkerReview<
+    WorkerId<T>,
+    <T as frame_system::Config>::BlockNumber,
+    <T as frame_system::Config>::Hash,
+    BalanceOf<T>,
+>;
+
+/// Lead action that can be delegated to the deputy.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(
+    Encode,
+    Decode,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    Debug,
+    PartialOrd,
+    Ord,
+    TypeInfo,
+    VariantCount,
+    MaxEncodedLen,
+)]
+pub enum LeadActionPermission {
+    /// Allows filling the regular worker openings through `fill_opening` tx (including the
+    /// application screening through `shortlist_applications` and `start_application_selection`
+    /// txs).
+    FillOpenings,
+    /// Allows spending the group budget (up to the deputy spending cap) through
+    /// `spend_from_budget` and `vested_spend_from_budget` txs.
+    SpendFromBudget,
+    /// Allows slashing the regular worker stake through `slash_stake` tx.
+    SlashWorkerStake,
+    /// Allows terminating the regular worker through `terminate_role` tx.
+    TerminateWorkers,
+    /// Allows decreasing the regular worker stake through `decrease_stake` tx.
+    DecreaseWorkerStake,
+    /// Allows updating the regular worker reward through `update_reward_amount` tx.
+    UpdateWorkerReward,
+    /// Allows filing the regular worker performance reviews through `file_worker_review` tx.
+    ReviewWorkers,
+}
+
+parameter_types! {
+    pub const DeputyPermissionsMaxSize: u32 = LeadActionPermission::VARIANT_COUNT as u32;
+}
+
+/// Set of the lead actions delegated to the deputy.
+pub type DeputyPermissions = BTreeSet<LeadActionPermission>;
+
+/// Bounded set of the lead actions delegated to the deputy.
+pub type StoredDeputyPermissions = BoundedBTreeSet<LeadActionPermission, DeputyPermissionsMaxSize>;
+
+/// Worker appointed by the lead to perform some of the lead actions.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Debug, Clone, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+pub struct Deputy<Balance> {
+    /// Lead actions delegated to the deputy.
+    pub permissions: StoredDeputyPermissions,
+
+    /// Amount of the group budget the deputy is still allowed to spend.
+    pub spending_cap: Balance,
+}
+
+/// Deputy type alias.
+pub type DeputyOf<T> = Deputy<BalanceOf<T>>;
+
+/// Recurring payment from the group budget to the account that is not a worker (eg.: a grant).
+/// The payment is paid out in the worker reward payout rounds.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Debug, Clone, Default, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+pub struct RecurringPayment<AccountId, Balance, BlockNumber> {
+    /// Account receiving the payments.
+    pub recipient: AccountId,
+
+    /// Amount paid per reward period.
+    pub amount_per_period: Balance,
+
+    /// Block the payments start at.
+    pub start_block: BlockNumber,
+
+    /// Block the payments end at.
+    pub end_block: BlockNumber,
+
+    /// Optional cap of the total paid amount.
+    pub total_cap: Option<Balance>,
+
+    /// Total amount accrued so far (including the missed payment).
+    pub accrued_amount: Balance,
+
+    /// Amount not paid because of the insufficient group budget.
+    pub missed_payment: Option<Balance>,
+}
+
+impl<AccountId, Balance: AtLeast32BitUnsigned + Copy, BlockNumber>
+    RecurringPayment<AccountId, Balance, BlockNumber>
+{
+    /// Defines whether the total cap of the payment was reached.
+    pub fn is_cap_reached(&self) -> bool {
+        self.total_cap
+            .map_or(false, |total_cap| self.accrued_amount >= total_cap)
+    }
+}
+
+/// RecurringPayment type alias.
+pub type RecurringPaymentOf<T> = RecurringPayment<
+    <T as frame_system::Config>::AccountId,
+    BalanceOf<T>,
+    <T as frame_system::Config>::BlockNumber,
+>;
+
+/// Application screening stage of the opening with the shortlisted applications.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Debug, Clone, Copy, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+pub enum ApplicationScreeningStage<BlockNumber> {
+    /// Members endorse the shortlisted applications with their stakes until the provided block.
+    Endorsement {
+        /// Block the endorsement stage ends at.
+        ends_at: BlockNumber,
+    },
+
+    /// The opening is filled with the shortlisted applications.
+    Selection,
+}
+
+/// Endorsement of the shortlisted application by the member.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Debug, Clone, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+pub struct ApplicationEndorsement<AccountId, Balance> {
+    /// Account used to stake the endorsement.
+    pub staking_account_id: AccountId,
+
+    /// Endorsement stake.
+    pub stake: Balance,
+}
+
+/// ApplicationEndorsement type alias.
+pub type ApplicationEndorsementOf<T> =
+    ApplicationEndorsement<<T as frame_system::Config>::AccountId, BalanceOf<T>>;
@@ -20,6 +20,9 @@
 //! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 4.0.0-dev
 //! DATE: 2024-04-08, STEPS: `50`, REPEAT: 20, LOW RANGE: `[]`, HIGH RANGE: `[]`
 //! EXECUTION: Some(Wasm), WASM-EXECUTION: Compiled, CHAIN: Some("prod-test"), DB CACHE: 1024
+//!
+//! NOTE: the weight functions marked as PLACEHOLDER are hand-written estimates, not the
+//! benchmark output. They must be regenerated with the benchmark CLI before merging.
 
 // Executed Command:
 // ./scripts/../target/release/joystream-node
@@ -70,6 +73,21 @@ pub trait WeightInfo {
 	fn leave_role(_i: u32, ) -> Weight;
 	fn lead_remark(_i: u32, ) -> Weight;
 	fn worker_remark(_i: u32, ) -> Weight;
+	fn file_worker_review(_i: u32, ) -> Weight;
+	fn contest_worker_review(_i: u32, ) -> Weight;
+	fn resolve_worker_review_contest() -> Weight;
+	fn appoint_deputy() -> Weight;
+	fn revoke_deputy() -> Weight;
+	fn create_recurring_payment(_i: u32, ) -> Weight;
+	fn cancel_recurring_payment() -> Weight;
+	fn on_initialize_paying_recurring_payments(_i: u32, ) -> Weight;
+	fn fill_screened_opening(_i: u32, _j: u32, ) -> Weight;
+	fn cancel_screened_opening(_i: u32, ) -> Weight;
+	fn shortlist_applications(_i: u32, _j: u32, ) -> Weight;
+	fn endorse_application() -> Weight;
+	fn withdraw_application_endorsement() -> Weight;
+	fn start_application_selection(_i: u32, ) -> Weight;
+	fn set_worker_transfer_approval() -> Weight;
 }
 
 /// Weights for working_group using the Substrate node and recommended hardware.
@@ -584,6 +602,340 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(Weight::from_parts(1_062_627, 0u64).saturating_mul(i.into()))
 			.saturating_add(T::DbWeight::get().reads(1_u64))
 	}
+	// PLACEHOLDER: hand-written estimate, not the benchmark output.
+	// Must be regenerated with the benchmark CLI before merging.
+	// Storage: Instance3WorkingGroup CurrentLead (r:1 w:0)
+	// Proof: Instance3WorkingGroup CurrentLead (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	// Storage: Instance3WorkingGroup WorkerById (r:2 w:0)
+	// Proof: Instance3WorkingGroup WorkerById (max_values: None, max_size: Some(175), added: 2650, mode: MaxEncodedLen)
+	// Storage: Instance3WorkingGroup NextWorkerReviewId (r:1 w:1)
+	// Proof: Instance3WorkingGroup NextWorkerReviewId (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	// Storage: Instance3WorkingGroup WorkerReviewById (r:0 w:1)
+	// Proof: Instance3WorkingGroup WorkerReviewById (max_values: None, max_size: Some(77), added: 2552, mode: MaxEncodedLen)
+	// Storage: Instance3WorkingGroup PendingRewardAdjustment (r:0 w:1)
+	// Proof: Instance3WorkingGroup PendingRewardAdjustment (max_values: None, max_size: Some(32), added: 2507, mode: MaxEncodedLen)
+	/// The range of component `i` is `[0, 100]`.
+	fn file_worker_review(i: u32, ) -> Weight {
+		Weight::from_parts(32_611_508, 0u64)
+			.saturating_add(Weight::from_parts(0, 9276))
+			.saturating_add(Weight::from_parts(1_742_094, 0u64).saturating_mul(i.into()))
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	// PLACEHOLDER: hand-written estimate, not the benchmark output.
+	// Must be regenerated with the benchmark CLI before merging.
+	// Storage: Instance3WorkingGroup WorkerById (r:1 w:0)
+	// Proof: Instance3WorkingGroup WorkerById (max_values: None, max_size: Some(175), added: 2650, mode: MaxEncodedLen)
+	// Storage: Instance3WorkingGroup WorkerReviewById (r:1 w:1)
+	// Proof: Instance3WorkingGroup WorkerReviewById (max_values: None, max_size: Some(77), added: 2552, mode: MaxEncodedLen)
+	/// The range of component `i` is `[0, 100]`.
+	fn contest_worker_review(i: u32, ) -> Weight {
+		Weight::from_parts(26_980_115, 0u64)
+			.saturating_add(Weight::from_parts(0, 6192))
+			.saturating_add(Weight::from_parts(1_064_302, 0u64).saturating_mul(i.into()))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	// PLACEHOLDER: hand-written estimate, not the benchmark output.
+	// Must be regenerated with the benchmark CLI before merging.
+	// Storage: Instance3WorkingGroup WorkerReviewById (r:1 w:1)
+	// Proof: Instance3WorkingGroup WorkerReviewById (max_values: None, max_size: Some(93), added: 2568, mode: MaxEncodedLen)
+	// Storage: Instance3WorkingGroup PendingRewardAdjustment (r:1 w:1)
+	// Proof: Instance3WorkingGroup PendingRewardAdjustment (max_values: None, max_size: Some(42), added: 2517, mode: MaxEncodedLen)
+	// Storage: Instance3WorkingGroup WorkerById (r:1 w:0)
+	// Proof: Instance3WorkingGroup WorkerById (max_values: None, max_size: Some(175), added: 2650, mode: MaxEncodedLen)
+	// Storage: Instance3WorkingGroup Budget (r:1 w:1)
+	// Proof: Instance3WorkingGroup Budget (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	// Storage: System Account (r:1 w:1)
+	// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn resolve_worker_review_contest() -> Weight {
+		Weight::from_parts(37_502_000, 0u64)
+			.saturating_add(Weight::from_parts(0, 10849))
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	// PLACEHOLDER: hand-written estimate, not the benchmark output.
+	// Must be regenerated with the benchmark CLI before merging.
+	// Storage: Instance3WorkingGroup CurrentLead (r:1 w:0)
+	// Proof: Instance3WorkingGroup CurrentLead (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	// Storage: Instance3WorkingGroup WorkerById (r:2 w:0)
+	// Proof: Instance3WorkingGroup WorkerById (max_values: None, max_size: Some(175), added: 2650, mode: MaxEncodedLen)
+	// Storage: Instance3WorkingGroup DeputyIdByAccountId (r:1 w:1)
+	// Proof: Instance3WorkingGroup DeputyIdByAccountId (max_values: None, max_size: Some(56), added: 2531, mode: MaxEncodedLen)
+	// Storage: Instance3WorkingGroup DeputyById (r:0 w:1)
+	// Proof: Instance3WorkingGroup DeputyById (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	fn appoint_deputy() -> Weight {
+		Weight::from_parts(22_051_000, 0u64)
+			.saturating_add(Weight::from_parts(0, 8334))
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	// PLACEHOLDER: hand-written estimate, not the benchmark output.
+	// Must be regenerated with the benchmark CLI before merging.
+	// Storage: Instance3WorkingGroup CurrentLead (r:1 w:0)
+	// Proof: Instance3WorkingGroup CurrentLead (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	// Storage: Instance3WorkingGroup WorkerById (r:2 w:0)
+	// Proof: Instance3WorkingGroup WorkerById (max_values: None, max_size: Some(175), added: 2650, mode: MaxEncodedLen)
+	// Storage: Instance3WorkingGroup DeputyById (r:1 w:1)
+	// Proof: Instance3WorkingGroup DeputyById (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	// Storage: Instance3WorkingGroup DeputyIdByAccountId (r:0 w:1)
+	// Proof: Instance3WorkingGroup DeputyIdByAccountId (max_values: None, max_size: Some(56), added: 2531, mode: MaxEncodedLen)
+	fn revoke_deputy() -> Weight {
+		Weight::from_parts(20_804_000, 0u64)
+			.saturating_add(Weight::from_parts(0, 8326))
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	// PLACEHOLDER: hand-written estimate, not the benchmark output.
+	// Must be regenerated with the benchmark CLI before merging.
+	// Storage: Instance3WorkingGroup CurrentLead (r:1 w:0)
+	// Proof: Instance3WorkingGroup CurrentLead (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	// Storage: Instance3WorkingGroup WorkerById (r:1 w:0)
+	// Proof: Instance3WorkingGroup WorkerById (max_values: None, max_size: Some(175), added: 2650, mode: MaxEncodedLen)
+	// Storage: Instance3WorkingGroup NextRecurringPaymentId (r:1 w:1)
+	// Proof: Instance3WorkingGroup NextRecurringPaymentId (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	// Storage: Instance3WorkingGroup RecurringPaymentById (r:0 w:1)
+	// Proof: Instance3WorkingGroup RecurringPaymentById (max_values: None, max_size: Some(120), added: 2595, mode: MaxEncodedLen)
+	/// The range of component `i` is `[0, 100]`.
+	fn create_recurring_payment(i: u32, ) -> Weight {
+		Weight::from_parts(22_602_000, 0u64)
+			.saturating_add(Weight::from_parts(0, 5126))
+			.saturating_add(Weight::from_parts(685, 0u64).saturating_mul(i.into()))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	// PLACEHOLDER: hand-written estimate, not the benchmark output.
+	// Must be regenerated with the benchmark CLI before merging.
+	// Storage: Instance3WorkingGroup CurrentLead (r:1 w:0)
+	// Proof: Instance3WorkingGroup CurrentLead (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	// Storage: Instance3WorkingGroup WorkerById (r:1 w:0)
+	// Proof: Instance3WorkingGroup WorkerById (max_values: None, max_size: Some(175), added: 2650, mode: MaxEncodedLen)
+	// Storage: Instance3WorkingGroup RecurringPaymentById (r:1 w:1)
+	// Proof: Instance3WorkingGroup RecurringPaymentById (max_values: None, max_size: Some(120), added: 2595, mode: MaxEncodedLen)
+	fn cancel_recurring_payment() -> Weight {
+		Weight::from_parts(20_356_000, 0u64)
+			.saturating_add(Weight::from_parts(0, 7728))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	// PLACEHOLDER: hand-written estimate, not the benchmark output.
+	// Must be regenerated with the benchmark CLI before merging.
+	// Storage: Instance3WorkingGroup CurrentRewardPayoutRound (r:1 w:1)
+	// Proof: Instance3WorkingGroup CurrentRewardPayoutRound (max_values: Some(1), max_size: Some(35), added: 530, mode: MaxEncodedLen)
+	// Storage: Instance3WorkingGroup RecurringPaymentById (r:31 w:30)
+	// Proof: Instance3WorkingGroup RecurringPaymentById (max_values: None, max_size: Some(120), added: 2595, mode: MaxEncodedLen)
+	// Storage: Instance3WorkingGroup Budget (r:1 w:1)
+	// Proof: Instance3WorkingGroup Budget (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	// Storage: System Account (r:30 w:30)
+	// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// The range of component `i` is `[2, 30]`.
+	fn on_initialize_paying_recurring_payments(i: u32, ) -> Weight {
+		Weight::from_parts(23_914_402, 0u64)
+			.saturating_add(Weight::from_parts(0, 6140))
+			.saturating_add(Weight::from_parts(38_106_517, 0u64).saturating_mul(i.into()))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(i.into())))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(i.into())))
+			.saturating_add(Weight::from_parts(0, 5198).saturating_mul(i.into()))
+	}
+	// PLACEHOLDER: hand-written estimate, not the benchmark output.
+	// Must be regenerated with the benchmark CLI before merging.
+	// Storage: Instance3WorkingGroup OpeningById (r:1 w:1)
+	// Proof: Instance3WorkingGroup OpeningById (max_values: None, max_size: Some(114), added: 2589, mode: MaxEncodedLen)
+	// Storage: Instance3WorkingGroup CurrentLead (r:1 w:0)
+	// Proof: Instance3WorkingGroup CurrentLead (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	// Storage: Instance3WorkingGroup WorkerById (r:1 w:1)
+	// Proof: Instance3WorkingGroup WorkerById (max_values: None, max_size: Some(175), added: 2650, mode: MaxEncodedLen)
+	// Storage: Instance3WorkingGroup ActiveWorkerCount (r:1 w:1)
+	// Proof: Instance3WorkingGroup ActiveWorkerCount (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	// Storage: Instance3WorkingGroup ApplicationScreeningStageByOpeningId (r:1 w:1)
+	// Proof: Instance3WorkingGroup ApplicationScreeningStageByOpeningId (max_values: None, max_size: Some(33), added: 2508, mode: MaxEncodedLen)
+	// Storage: Instance3WorkingGroup ApplicationById (r:30 w:30)
+	// Proof: Instance3WorkingGroup ApplicationById (max_values: None, max_size: Some(168), added: 2643, mode: MaxEncodedLen)
+	// Storage: Instance3WorkingGroup ApplicationIdsByOpeningId (r:31 w:30)
+	// Proof: Instance3WorkingGroup ApplicationIdsByOpeningId (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	// Storage: Instance3WorkingGroup ApplicationEndorsementById (r:600 w:600)
+	// Proof: Instance3WorkingGroup ApplicationEndorsementById (max_values: None, max_size: Some(96), added: 2571, mode: MaxEncodedLen)
+	// Storage: Instance3WorkingGroup ApplicationEndorsementStake (r:0 w:30)
+	// Proof: Instance3WorkingGroup ApplicationEndorsementStake (max_values: None, max_size: Some(40), added: 2515, mode: MaxEncodedLen)
+	// Storage: Instance3WorkingGroup ApplicationEndorsementCount (r:30 w:30)
+	// Proof: Instance3WorkingGroup ApplicationEndorsementCount (max_values: None, max_size: Some(28), added: 2503, mode: MaxEncodedLen)
+	// Storage: Balances Locks (r:630 w:630)
+	// Proof: Balances Locks (max_values: None, max_size: Some(1299), added: 3774, mode: MaxEncodedLen)
+	// Storage: System Account (r:630 w:630)
+	// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	// Storage: Instance3WorkingGroup NextWorkerId (r:1 w:1)
+	// Proof: Instance3WorkingGroup NextWorkerId (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// The range of component `i` is `[2, 30]`.
+	/// The range of component `j` is `[0, 600]`.
+	fn fill_screened_opening(i: u32, j: u32, ) -> Weight {
+		Weight::from_parts(58_412_706, 0u64)
+			.saturating_add(Weight::from_parts(0, 25307))
+			.saturating_add(Weight::from_parts(28_946_113, 0u64).saturating_mul(i.into()))
+			.saturating_add(Weight::from_parts(21_304_870, 0u64).saturating_mul(j.into()))
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().reads((5_u64).saturating_mul(i.into())))
+			.saturating_add(T::DbWeight::get().reads((3_u64).saturating_mul(j.into())))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+			.saturating_add(T::DbWeight::get().writes((6_u64).saturating_mul(i.into())))
+			.saturating_add(T::DbWeight::get().writes((3_u64).saturating_mul(j.into())))
+			.saturating_add(Weight::from_parts(0, 14046).saturating_mul(i.into()))
+			.saturating_add(Weight::from_parts(0, 8948).saturating_mul(j.into()))
+	}
+	// PLACEHOLDER: hand-written estimate, not the benchmark output.
+	// Must be regenerated with the benchmark CLI before merging.
+	// Storage: Instance3WorkingGroup OpeningById (r:1 w:1)
+	// Proof: Instance3WorkingGroup OpeningById (max_values: None, max_size: Some(114), added: 2589, mode: MaxEncodedLen)
+	// Storage: Instance3WorkingGroup CurrentLead (r:1 w:0)
+	// Proof: Instance3WorkingGroup CurrentLead (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	// Storage: Instance3WorkingGroup WorkerById (r:1 w:0)
+	// Proof: Instance3WorkingGroup WorkerById (max_values: None, max_size: Some(175), added: 2650, mode: MaxEncodedLen)
+	// Storage: Instance3WorkingGroup ApplicationScreeningStageByOpeningId (r:1 w:1)
+	// Proof: Instance3WorkingGroup ApplicationScreeningStageByOpeningId (max_values: None, max_size: Some(33), added: 2508, mode: MaxEncodedLen)
+	// Storage: Instance3WorkingGroup ApplicationIdsByOpeningId (r:31 w:0)
+	// Proof: Instance3WorkingGroup ApplicationIdsByOpeningId (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	// Storage: Instance3WorkingGroup ApplicationEndorsementById (r:600 w:600)
+	// Proof: Instance3WorkingGroup ApplicationEndorsementById (max_values: None, max_size: Some(96), added: 2571, mode: MaxEncodedLen)
+	// Storage: Instance3WorkingGroup ApplicationEndorsementStake (r:0 w:30)
+	// Proof: Instance3WorkingGroup ApplicationEndorsementStake (max_values: None, max_size: Some(40), added: 2515, mode: MaxEncodedLen)
+	// Storage: Instance3WorkingGroup ApplicationEndorsementCount (r:30 w:30)
+	// Proof: Instance3WorkingGroup ApplicationEndorsementCount (max_values: None, max_size: Some(28), added: 2503, mode: MaxEncodedLen)
+	// Storage: Balances Locks (r:601 w:601)
+	// Proof: Balances Locks (max_values: None, max_size: Some(1299), added: 3774, mode: MaxEncodedLen)
+	// Storage: System Account (r:601 w:601)
+	// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// The range of component `i` is `[0, 600]`.
+	fn cancel_screened_opening(i: u32, ) -> Weight {
+		Weight::from_parts(63_481_000, 0u64)
+			.saturating_add(Weight::from_parts(0, 19577))
+			.saturating_add(Weight::from_parts(21_118_402, 0u64).saturating_mul(i.into()))
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().reads((3_u64).saturating_mul(i.into())))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+			.saturating_add(T::DbWeight::get().writes((3_u64).saturating_mul(i.into())))
+			.saturating_add(Weight::from_parts(0, 8948).saturating_mul(i.into()))
+	}
+	// PLACEHOLDER: hand-written estimate, not the benchmark output.
+	// Must be regenerated with the benchmark CLI before merging.
+	// Storage: Instance3WorkingGroup OpeningById (r:1 w:0)
+	// Proof: Instance3WorkingGroup OpeningById (max_values: None, max_size: Some(114), added: 2589, mode: MaxEncodedLen)
+	// Storage: Instance3WorkingGroup CurrentLead (r:1 w:0)
+	// Proof: Instance3WorkingGroup CurrentLead (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	// Storage: Instance3WorkingGroup WorkerById (r:1 w:0)
+	// Proof: Instance3WorkingGroup WorkerById (max_values: None, max_size: Some(175), added: 2650, mode: MaxEncodedLen)
+	// Storage: Instance3WorkingGroup ApplicationScreeningStageByOpeningId (r:1 w:1)
+	// Proof: Instance3WorkingGroup ApplicationScreeningStageByOpeningId (max_values: None, max_size: Some(33), added: 2508, mode: MaxEncodedLen)
+	// Storage: Instance3WorkingGroup ApplicationById (r:60 w:30)
+	// Proof: Instance3WorkingGroup ApplicationById (max_values: None, max_size: Some(168), added: 2643, mode: MaxEncodedLen)
+	// Storage: Instance3WorkingGroup ApplicationIdsByOpeningId (r:61 w:60)
+	// Proof: Instance3WorkingGroup ApplicationIdsByOpeningId (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	// Storage: Instance3WorkingGroup ApplicationEndorsementStake (r:30 w:30)
+	// Proof: Instance3WorkingGroup ApplicationEndorsementStake (max_values: None, max_size: Some(40), added: 2515, mode: MaxEncodedLen)
+	// Storage: Balances Locks (r:30 w:30)
+	// Proof: Balances Locks (max_values: None, max_size: Some(1299), added: 3774, mode: MaxEncodedLen)
+	// Storage: System Account (r:30 w:30)
+	// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// The range of component `i` is `[1, 30]`.
+	/// The range of component `j` is `[1, 30]`.
+	fn shortlist_applications(i: u32, j: u32, ) -> Weight {
+		Weight::from_parts(21_377_502, 0u64)
+			.saturating_add(Weight::from_parts(0, 12431))
+			.saturating_add(Weight::from_parts(7_512_604, 0u64).saturating_mul(i.into()))
+			.saturating_add(Weight::from_parts(27_403_981, 0u64).saturating_mul(j.into()))
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(i.into())))
+			.saturating_add(T::DbWeight::get().reads((4_u64).saturating_mul(j.into())))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(i.into())))
+			.saturating_add(T::DbWeight::get().writes((5_u64).saturating_mul(j.into())))
+			.saturating_add(Weight::from_parts(0, 5166).saturating_mul(i.into()))
+			.saturating_add(Weight::from_parts(0, 11543).saturating_mul(j.into()))
+	}
+	// PLACEHOLDER: hand-written estimate, not the benchmark output.
+	// Must be regenerated with the benchmark CLI before merging.
+	// Storage: Membership MembershipById (r:1 w:0)
+	// Proof: Membership MembershipById (max_values: None, max_size: Some(125), added: 2600, mode: MaxEncodedLen)
+	// Storage: Instance3WorkingGroup ApplicationById (r:1 w:0)
+	// Proof: Instance3WorkingGroup ApplicationById (max_values: None, max_size: Some(168), added: 2643, mode: MaxEncodedLen)
+	// Storage: Instance3WorkingGroup ApplicationScreeningStageByOpeningId (r:1 w:0)
+	// Proof: Instance3WorkingGroup ApplicationScreeningStageByOpeningId (max_values: None, max_size: Some(33), added: 2508, mode: MaxEncodedLen)
+	// Storage: Instance3WorkingGroup ApplicationEndorsementById (r:1 w:1)
+	// Proof: Instance3WorkingGroup ApplicationEndorsementById (max_values: None, max_size: Some(96), added: 2571, mode: MaxEncodedLen)
+	// Storage: Membership StakingAccountIdMemberStatus (r:1 w:0)
+	// Proof: Membership StakingAccountIdMemberStatus (max_values: None, max_size: Some(65), added: 2540, mode: MaxEncodedLen)
+	// Storage: Balances Locks (r:1 w:1)
+	// Proof: Balances Locks (max_values: None, max_size: Some(1299), added: 3774, mode: MaxEncodedLen)
+	// Storage: System Account (r:1 w:1)
+	// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	// Storage: Instance3WorkingGroup ApplicationEndorsementStake (r:1 w:1)
+	// Proof: Instance3WorkingGroup ApplicationEndorsementStake (max_values: None, max_size: Some(40), added: 2515, mode: MaxEncodedLen)
+	// Storage: Instance3WorkingGroup ApplicationEndorsementCount (r:1 w:1)
+	// Proof: Instance3WorkingGroup ApplicationEndorsementCount (max_values: None, max_size: Some(28), added: 2503, mode: MaxEncodedLen)
+	fn endorse_application() -> Weight {
+		Weight::from_parts(48_369_000, 0u64)
+			.saturating_add(Weight::from_parts(0, 26507))
+			.saturating_add(T::DbWeight::get().reads(9_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+	// PLACEHOLDER: hand-written estimate, not the benchmark output.
+	// Must be regenerated with the benchmark CLI before merging.
+	// Storage: Membership MembershipById (r:1 w:0)
+	// Proof: Membership MembershipById (max_values: None, max_size: Some(125), added: 2600, mode: MaxEncodedLen)
+	// Storage: Instance3WorkingGroup ApplicationEndorsementById (r:1 w:1)
+	// Proof: Instance3WorkingGroup ApplicationEndorsementById (max_values: None, max_size: Some(96), added: 2571, mode: MaxEncodedLen)
+	// Storage: Balances Locks (r:1 w:1)
+	// Proof: Balances Locks (max_values: None, max_size: Some(1299), added: 3774, mode: MaxEncodedLen)
+	// Storage: System Account (r:1 w:1)
+	// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	// Storage: Instance3WorkingGroup ApplicationEndorsementStake (r:1 w:1)
+	// Proof: Instance3WorkingGroup ApplicationEndorsementStake (max_values: None, max_size: Some(40), added: 2515, mode: MaxEncodedLen)
+	// Storage: Instance3WorkingGroup ApplicationEndorsementCount (r:1 w:1)
+	// Proof: Instance3WorkingGroup ApplicationEndorsementCount (max_values: None, max_size: Some(28), added: 2503, mode: MaxEncodedLen)
+	fn withdraw_application_endorsement() -> Weight {
+		Weight::from_parts(37_618_000, 0u64)
+			.saturating_add(Weight::from_parts(0, 18888))
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+	// PLACEHOLDER: hand-written estimate, not the benchmark output.
+	// Must be regenerated with the benchmark CLI before merging.
+	// Storage: Instance3WorkingGroup OpeningById (r:1 w:0)
+	// Proof: Instance3WorkingGroup OpeningById (max_values: None, max_size: Some(114), added: 2589, mode: MaxEncodedLen)
+	// Storage: Instance3WorkingGroup CurrentLead (r:1 w:0)
+	// Proof: Instance3WorkingGroup CurrentLead (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	// Storage: Instance3WorkingGroup WorkerById (r:1 w:0)
+	// Proof: Instance3WorkingGroup WorkerById (max_values: None, max_size: Some(175), added: 2650, mode: MaxEncodedLen)
+	// Storage: Instance3WorkingGroup ApplicationScreeningStageByOpeningId (r:1 w:1)
+	// Proof: Instance3WorkingGroup ApplicationScreeningStageByOpeningId (max_values: None, max_size: Some(33), added: 2508, mode: MaxEncodedLen)
+	// Storage: Instance3WorkingGroup ApplicationIdsByOpeningId (r:31 w:0)
+	// Proof: Instance3WorkingGroup ApplicationIdsByOpeningId (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	// Storage: Instance3WorkingGroup ApplicationEndorsementStake (r:30 w:0)
+	// Proof: Instance3WorkingGroup ApplicationEndorsementStake (max_values: None, max_size: Some(40), added: 2515, mode: MaxEncodedLen)
+	/// The range of component `i` is `[1, 30]`.
+	fn start_application_selection(i: u32, ) -> Weight {
+		Weight::from_parts(27_105_336, 0u64)
+			.saturating_add(Weight::from_parts(0, 10627))
+			.saturating_add(Weight::from_parts(5_284_519, 0u64).saturating_mul(i.into()))
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(i.into())))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(Weight::from_parts(0, 5038).saturating_mul(i.into()))
+	}
+	// PLACEHOLDER: hand-written estimate, not the benchmark output.
+	// Must be regenerated with the benchmark CLI before merging.
+	// Storage: Instance3WorkingGroup CurrentLead (r:1 w:0)
+	// Proof: Instance3WorkingGroup CurrentLead (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	// Storage: Instance3WorkingGroup WorkerById (r:2 w:0)
+	// Proof: Instance3WorkingGroup WorkerById (max_values: None, max_size: Some(180), added: 2655, mode: MaxEncodedLen)
+	// Storage: Instance3WorkingGroup WorkerTransferApprovalById (r:0 w:1)
+	// Proof: Instance3WorkingGroup WorkerTransferApprovalById (max_values: None, max_size: Some(25), added: 2500, mode: MaxEncodedLen)
+	fn set_worker_transfer_approval() -> Weight {
+		Weight::from_parts(20_317_000, 0u64)
+			.saturating_add(Weight::from_parts(0, 6813))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
 }
 
 // Default implementation for tests
@@ -666,4 +1018,49 @@ impl WeightInfo for () {
 	fn worker_remark(i: u32, ) -> Weight {
 		Weight::from_parts(0, 0)
 	}
+	fn file_worker_review(i: u32, ) -> Weight {
+		Weight::from_parts(0, 0)
+	}
+	fn contest_worker_review(i: u32, ) -> Weight {
+		Weight::from_parts(0, 0)
+	}
+	fn resolve_worker_review_contest() -> Weight {
+		Weight::from_parts(0, 0)
+	}
+	fn appoint_deputy() -> Weight {
+		Weight::from_parts(0, 0)
+	}
+	fn revoke_deputy() -> Weight {
+		Weight::from_parts(0, 0)
+	}
+	fn create_recurring_payment(i: u32, ) -> Weight {
+		Weight::from_parts(0, 0)
+	}
+	fn cancel_recurring_payment() -> Weight {
+		Weight::from_parts(0, 0)
+	}
+	fn on_initialize_paying_recurring_payments(i: u32, ) -> Weight {
+		Weight::from_parts(0, 0)
+	}
+	fn fill_screened_opening(i: u32, j: u32, ) -> Weight {
+		Weight::from_parts(0, 0)
+	}
+	fn cancel_screened_opening(i: u32, ) -> Weight {
+		Weight::from_parts(0, 0)
+	}
+	fn shortlist_applications(i: u32, j: u32, ) -> Weight {
+		Weight::from_parts(0, 0)
+	}
+	fn endorse_application() -> Weight {
+		Weight::from_parts(0, 0)
+	}
+	fn withdraw_application_endorsement() -> Weight {
+		Weight::from_parts(0, 0)
+	}
+	fn start_application_selection(i: u32, ) -> Weight {
+		Weight::from_parts(0, 0)
+	}
+	fn set_worker_transfer_approval() -> Weight {
+		Weight::from_parts(0, 0)
+	}
 }
@@ -1,6 +1,6 @@
 #![warn(missing_docs)]
 
-use sp_runtime::SaturatedConversion;
+use frame_support::traits::Get;
 use sp_std::marker::PhantomData;
 
 use proposals_engine::VotersParameters;
@@ -12,10 +12,9 @@ pub struct CouncilManager<T> {
 }
 
 impl<T: council::Config> VotersParameters for CouncilManager<T> {
-    /// Implement total_voters_count() as council size
+    /// Implement total_voters_count() as council size. The configured size is used instead of
+    /// the current members count, so vacated seats count as missing votes.
     fn total_voters_count() -> u32 {
-        council::Module::<T>::council_members()
-            .len()
-            .saturated_into()
+        T::CouncilSize::get()
     }
 }
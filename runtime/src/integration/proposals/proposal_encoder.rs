@@ -1,6 +1,6 @@
 use crate::{Runtime, RuntimeCall};
 use common::working_group::WorkingGroup;
-use common::FreezablePallet;
+use common::{BalanceKind, FreezablePallet};
 use proposals_codex::{ProposalDetails, ProposalDetailsOf, ProposalEncoder};
 use working_group::OpeningType;
 
@@ -56,7 +56,10 @@ impl ProposalEncoder<Runtime> for ExtrinsicProposalEncoder {
                 })
             }
             ProposalDetails::FundingRequest(funding_requests) => {
-                RuntimeCall::Council(council::Call::funding_request { funding_requests })
+                RuntimeCall::Council(council::Call::funding_request {
+                    funding_requests,
+                    allocation_id: None,
+                })
             }
             ProposalDetails::SetMaxValidatorCount(new) => {
                 RuntimeCall::Staking(pallet_staking::Call::set_validator_count { new })
@@ -82,6 +85,7 @@ impl ProposalEncoder<Runtime> for ExtrinsicProposalEncoder {
                         working_group,
                         amount,
                         balance_kind,
+                        allocation_id: None,
                     },
                 )
             }
@@ -185,6 +189,62 @@ impl ProposalEncoder<Runtime> for ExtrinsicProposalEncoder {
             ProposalDetails::DecreaseCouncilBudget(reduction_amount) => {
                 RuntimeCall::Council(council::Call::decrease_council_budget { reduction_amount })
             }
+            ProposalDetails::OverturnBountyJudgment(bounty_id) => {
+                RuntimeCall::Bounty(bounty::Call::overturn_oracle_judgment { bounty_id })
+            }
+            ProposalDetails::RevokeWorkingGroupDeputy(worker_id, working_group) => {
+                wrap_working_group_call!(working_group, Wg::revoke_deputy_call(worker_id))
+            }
+            ProposalDetails::TransferWorkingGroupWorker(source, worker_id, destination) => {
+                RuntimeCall::JoystreamUtility(joystream_utility::Call::transfer_worker {
+                    source,
+                    worker_id,
+                    destination,
+                })
+            }
+            ProposalDetails::SetReferendumVotePowerStrategy(strategy) => {
+                RuntimeCall::Referendum(referendum::Call::set_vote_power_strategy { strategy })
+            }
+            ProposalDetails::RecallCouncilor(membership_id, slashing_percentage) => {
+                RuntimeCall::Council(council::Call::recall_councilor {
+                    membership_id,
+                    slashing_percentage,
+                })
+            }
+            ProposalDetails::ResolveWorkerReviewContest(
+                worker_id,
+                review_id,
+                upheld,
+                working_group,
+            ) => wrap_working_group_call!(
+                working_group,
+                Wg::resolve_worker_review_contest_call(worker_id, review_id, upheld)
+            ),
+            ProposalDetails::CreateBudgetAllocation(name, cap, expires_at) => {
+                RuntimeCall::Council(council::Call::create_budget_allocation {
+                    name,
+                    cap,
+                    expires_at,
+                })
+            }
+            ProposalDetails::FundingRequestFromAllocation(funding_requests, allocation_id) => {
+                RuntimeCall::Council(council::Call::funding_request {
+                    funding_requests,
+                    allocation_id: Some(allocation_id),
+                })
+            }
+            ProposalDetails::UpdateWorkingGroupBudgetFromAllocation(
+                amount,
+                working_group,
+                allocation_id,
+            ) => RuntimeCall::JoystreamUtility(
+                joystream_utility::Call::update_working_group_budget {
+                    working_group,
+                    amount,
+                    balance_kind: BalanceKind::Positive,
+                    allocation_id: Some(allocation_id),
+                },
+            ),
         };
 
         call.encode()
@@ -283,4 +343,22 @@ where
     ) -> working_group::Call<T, I> {
         working_group::Call::<T, I>::cancel_opening { opening_id }
     }
+
+    // Generic call constructor for the working group 'revoke deputy'.
+    fn revoke_deputy_call(worker_id: working_group::WorkerId<T>) -> working_group::Call<T, I> {
+        working_group::Call::<T, I>::revoke_deputy { worker_id }
+    }
+
+    // Generic call constructor for the working group 'resolve worker review contest'.
+    fn resolve_worker_review_contest_call(
+        worker_id: working_group::WorkerId<T>,
+        review_id: working_group::WorkerReviewId,
+        upheld: bool,
+    ) -> working_group::Call<T, I> {
+        working_group::Call::<T, I>::resolve_worker_review_contest {
+            worker_id,
+            review_id,
+            upheld,
+        }
+    }
 }
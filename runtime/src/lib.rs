@@ -86,7 +86,7 @@ use sp_runtime::{
     curve::PiecewiseLinear,
     generic, impl_opaque_keys,
     traits::{BlakeTwo256, ConvertInto, IdentityLookup, OpaqueKeys, Zero},
-    Perbill,
+    DispatchError, DispatchResult, Perbill,
 };
 
 use sp_std::boxed::Box;
@@ -119,7 +119,11 @@ pub use runtime_api::*;
 
 use integration::proposals::{CouncilManager, ExtrinsicProposalEncoder};
 
-use common::working_group::{WorkingGroup, WorkingGroupBudgetHandler};
+use common::referendum::VotePowerStrategy;
+use common::working_group::{
+    WorkingGroup, WorkingGroupAuthenticator, WorkingGroupBudgetHandler,
+    WorkingGroupWorkerTransferHandler,
+};
 use council::ReferendumConnection;
 use pallet_staking::EraPayout;
 use referendum::{CastVote, OptionResult};
@@ -939,14 +943,18 @@ impl project_token::Config for Runtime {
 
 parameter_types! {
     pub const MaxPauserAccounts: u32 = 10;
+    pub const MaxAttesterAccounts: u32 = 10;
     pub const DefaultBridgingFee: Balance = dollars!(1);
 }
 
 impl argo_bridge::Config for Runtime {
     type RuntimeEvent = RuntimeEvent;
     type MaxPauserAccounts = MaxPauserAccounts;
+    type MaxAttesterAccounts = MaxAttesterAccounts;
     type WeightInfo = argo_bridge::weights::SubstrateWeight<Runtime>;
     type DefaultBridgingFee = DefaultBridgingFee;
+    type ProjectToken = ProjectToken;
+    type CouncilBudgetManager = Council;
 }
 
 // The referendum instance alias.
@@ -1051,6 +1059,14 @@ parameter_types! {
     pub const BudgetRefillPeriod: BlockNumber = 6;
 }
 
+parameter_types! {
+    pub const DefaultVotePowerStrategy: VotePowerStrategy<BlockNumber> = VotePowerStrategy::Stake;
+    pub const MaxRankedBallotPreferences: u32 = CouncilSize::get();
+    pub const MaxRankedBallots: u32 = 50;
+    pub const MaxVotedOptions: u32 = 100;
+    pub const MaxDelegatorsPerDelegate: u32 = 100;
+}
+
 impl referendum::Config<ReferendumInstance> for Runtime {
     type RuntimeEvent = RuntimeEvent;
     type MaxSaltLength = MaxSaltLength;
@@ -1063,6 +1079,11 @@ impl referendum::Config<ReferendumInstance> for Runtime {
     type MinimumStake = MinimumVotingStake;
     type WeightInfo = referendum::weights::SubstrateWeight<Runtime>;
     type MaxWinnerTargetCount = MaxWinnerTargetCount;
+    type DefaultVotePowerStrategy = DefaultVotePowerStrategy;
+    type MaxRankedBallotPreferences = MaxRankedBallotPreferences;
+    type MaxRankedBallots = MaxRankedBallots;
+    type MaxVotedOptions = MaxVotedOptions;
+    type MaxDelegatorsPerDelegate = MaxDelegatorsPerDelegate;
 
     fn calculate_vote_power(
         _account_id: &<Self as frame_system::Config>::AccountId,
@@ -1232,6 +1253,10 @@ parameter_types! {
     pub const ReferralCutMaximumPercent: u8 = 50;
     pub const DefaultInitialInvitationBalance: Balance = cents!(50);
     pub const DefaultMemberInvitesCount: u32 = 2;
+    pub const HandleReclaimCooldown: BlockNumber = days!(365);
+    pub const MaxRecoveryGuardians: u32 = 10;
+    pub const RecoveryDelay: BlockNumber = days!(7);
+    pub const RecoveryCooldown: BlockNumber = days!(1);
     // Candidate stake related:
     pub StakingAccountCleanupTxFee: Balance = compute_fee(
         RuntimeCall::Members(membership::Call::<Runtime>::remove_staking_account { member_id: 0 })
@@ -1253,6 +1278,10 @@ impl membership::Config for Runtime {
     type ReferralCutMaximumPercent = ReferralCutMaximumPercent;
     type CandidateStake = CandidateStake;
     type DefaultMemberInvitesCount = DefaultMemberInvitesCount;
+    type HandleReclaimCooldown = HandleReclaimCooldown;
+    type MaxRecoveryGuardians = MaxRecoveryGuardians;
+    type RecoveryDelay = RecoveryDelay;
+    type RecoveryCooldown = RecoveryCooldown;
 }
 
 parameter_types! {
@@ -1374,10 +1403,14 @@ parameter_types! {
     pub const OperationsBetaRewardPeriod: u32 = days!(1) + 70;
     pub const OperationsGammaRewardPeriod: u32 = days!(1) + 80;
     pub const DistributionRewardPeriod: u32 = days!(1) + 90;
+    // Rewards of the bigger groups are paid over several blocks
+    pub const WorkingGroupMaxRewardPayoutsPerBlock: u32 = 20;
     // This should be more costly than `apply_on_opening` fee
     pub const MinimumApplicationStake: Balance = dollars!(20);
     // This should be more costly than `add_opening` fee
     pub const LeaderOpeningStake: Balance = dollars!(100);
+    // Bounds the endorsement stakes released when a screened opening is filled or canceled
+    pub const WorkingGroupMaxApplicationEndorsements: u32 = 10;
 }
 
 // Make sure that one cannot leave before a slashing proposal for lead can go through.
@@ -1448,9 +1481,11 @@ impl working_group::Config<ForumWorkingGroupInstance> for Runtime {
     type MemberOriginValidator = Members;
     type MinUnstakingPeriodLimit = MinUnstakingPeriodLimit;
     type RewardPeriod = ForumWorkingGroupRewardPeriod;
+    type MaxRewardPayoutsPerBlock = WorkingGroupMaxRewardPayoutsPerBlock;
     type WeightInfo = working_group::weights::SubstrateWeight<Runtime>;
     type MinimumApplicationStake = MinimumApplicationStake;
     type LeaderOpeningStake = LeaderOpeningStake;
+    type MaxApplicationEndorsements = WorkingGroupMaxApplicationEndorsements;
     type VestingBalanceToBalance = BalanceConverter;
 }
 
@@ -1462,9 +1497,11 @@ impl working_group::Config<StorageWorkingGroupInstance> for Runtime {
     type MemberOriginValidator = Members;
     type MinUnstakingPeriodLimit = MinUnstakingPeriodLimit;
     type RewardPeriod = StorageWorkingGroupRewardPeriod;
+    type MaxRewardPayoutsPerBlock = WorkingGroupMaxRewardPayoutsPerBlock;
     type WeightInfo = working_group::weights::SubstrateWeight<Runtime>;
     type MinimumApplicationStake = MinimumApplicationStake;
     type LeaderOpeningStake = LeaderOpeningStake;
+    type MaxApplicationEndorsements = WorkingGroupMaxApplicationEndorsements;
     type VestingBalanceToBalance = BalanceConverter;
 }
 
@@ -1476,9 +1513,11 @@ impl working_group::Config<ContentWorkingGroupInstance> for Runtime {
     type MemberOriginValidator = Members;
     type MinUnstakingPeriodLimit = MinUnstakingPeriodLimit;
     type RewardPeriod = ContentWorkingGroupRewardPeriod;
+    type MaxRewardPayoutsPerBlock = WorkingGroupMaxRewardPayoutsPerBlock;
     type WeightInfo = working_group::weights::SubstrateWeight<Runtime>;
     type MinimumApplicationStake = MinimumApplicationStake;
     type LeaderOpeningStake = LeaderOpeningStake;
+    type MaxApplicationEndorsements = WorkingGroupMaxApplicationEndorsements;
     type VestingBalanceToBalance = BalanceConverter;
 }
 
@@ -1490,9 +1529,11 @@ impl working_group::Config<MembershipWorkingGroupInstance> for Runtime {
     type MemberOriginValidator = Members;
     type MinUnstakingPeriodLimit = MinUnstakingPeriodLimit;
     type RewardPeriod = MembershipRewardPeriod;
+    type MaxRewardPayoutsPerBlock = WorkingGroupMaxRewardPayoutsPerBlock;
     type WeightInfo = working_group::weights::SubstrateWeight<Runtime>;
     type MinimumApplicationStake = MinimumApplicationStake;
     type LeaderOpeningStake = LeaderOpeningStake;
+    type MaxApplicationEndorsements = WorkingGroupMaxApplicationEndorsements;
     type VestingBalanceToBalance = BalanceConverter;
 }
 
@@ -1504,9 +1545,11 @@ impl working_group::Config<OperationsWorkingGroupInstanceAlpha> for Runtime {
     type MemberOriginValidator = Members;
     type MinUnstakingPeriodLimit = MinUnstakingPeriodLimit;
     type RewardPeriod = OperationsAlphaRewardPeriod;
+    type MaxRewardPayoutsPerBlock = WorkingGroupMaxRewardPayoutsPerBlock;
     type WeightInfo = working_group::weights::SubstrateWeight<Runtime>;
     type MinimumApplicationStake = MinimumApplicationStake;
     type LeaderOpeningStake = LeaderOpeningStake;
+    type MaxApplicationEndorsements = WorkingGroupMaxApplicationEndorsements;
     type VestingBalanceToBalance = BalanceConverter;
 }
 
@@ -1518,9 +1561,11 @@ impl working_group::Config<AppWorkingGroupInstance> for Runtime {
     type MemberOriginValidator = Members;
     type MinUnstakingPeriodLimit = MinUnstakingPeriodLimit;
     type RewardPeriod = AppRewardPeriod;
+    type MaxRewardPayoutsPerBlock = WorkingGroupMaxRewardPayoutsPerBlock;
     type WeightInfo = working_group::weights::SubstrateWeight<Runtime>;
     type MinimumApplicationStake = MinimumApplicationStake;
     type LeaderOpeningStake = LeaderOpeningStake;
+    type MaxApplicationEndorsements = WorkingGroupMaxApplicationEndorsements;
     type VestingBalanceToBalance = BalanceConverter;
 }
 
@@ -1532,9 +1577,11 @@ impl working_group::Config<OperationsWorkingGroupInstanceBeta> for Runtime {
     type MemberOriginValidator = Members;
     type MinUnstakingPeriodLimit = MinUnstakingPeriodLimit;
     type RewardPeriod = OperationsBetaRewardPeriod;
+    type MaxRewardPayoutsPerBlock = WorkingGroupMaxRewardPayoutsPerBlock;
     type WeightInfo = working_group::weights::SubstrateWeight<Runtime>;
     type MinimumApplicationStake = MinimumApplicationStake;
     type LeaderOpeningStake = LeaderOpeningStake;
+    type MaxApplicationEndorsements = WorkingGroupMaxApplicationEndorsements;
     type VestingBalanceToBalance = BalanceConverter;
 }
 
@@ -1546,9 +1593,11 @@ impl working_group::Config<OperationsWorkingGroupInstanceGamma> for Runtime {
     type MemberOriginValidator = Members;
     type MinUnstakingPeriodLimit = MinUnstakingPeriodLimit;
     type RewardPeriod = OperationsGammaRewardPeriod;
+    type MaxRewardPayoutsPerBlock = WorkingGroupMaxRewardPayoutsPerBlock;
     type WeightInfo = working_group::weights::SubstrateWeight<Runtime>;
     type MinimumApplicationStake = MinimumApplicationStake;
     type LeaderOpeningStake = LeaderOpeningStake;
+    type MaxApplicationEndorsements = WorkingGroupMaxApplicationEndorsements;
     type VestingBalanceToBalance = BalanceConverter;
 }
 
@@ -1560,9 +1609,11 @@ impl working_group::Config<DistributionWorkingGroupInstance> for Runtime {
     type MemberOriginValidator = Members;
     type MinUnstakingPeriodLimit = MinUnstakingPeriodLimit;
     type RewardPeriod = DistributionRewardPeriod;
+    type MaxRewardPayoutsPerBlock = WorkingGroupMaxRewardPayoutsPerBlock;
     type WeightInfo = working_group::weights::SubstrateWeight<Runtime>;
     type MinimumApplicationStake = MinimumApplicationStake;
     type LeaderOpeningStake = LeaderOpeningStake;
+    type MaxApplicationEndorsements = WorkingGroupMaxApplicationEndorsements;
     type VestingBalanceToBalance = BalanceConverter;
 }
 
@@ -1603,6 +1654,7 @@ impl proposals_engine::Config for Runtime {
     type WeightInfo = proposals_engine::weights::SubstrateWeight<Runtime>;
     type StakingAccountValidator = Members;
     type DispatchableCallCodeMaxLen = DispatchableCallCodeMaxLen;
+    type CouncilParticipationObserver = Council;
 }
 
 impl Default for RuntimeCall {
@@ -1637,16 +1689,19 @@ parameter_types! {
 
 macro_rules! call_wg {
     ($working_group:ident, $function:ident $(,$x:expr)*) => {{
+        call_wg!($working_group, WorkingGroupBudgetHandler<AccountId, Balance>, $function $(,$x)*)
+    }};
+    ($working_group:ident, $handler:path, $function:ident $(,$x:expr)*) => {{
         match $working_group {
-            WorkingGroup::Content => <ContentWorkingGroup as WorkingGroupBudgetHandler<AccountId, Balance>>::$function($($x,)*),
-            WorkingGroup::Storage => <StorageWorkingGroup as WorkingGroupBudgetHandler<AccountId, Balance>>::$function($($x,)*),
-            WorkingGroup::Forum => <ForumWorkingGroup as WorkingGroupBudgetHandler<AccountId, Balance>>::$function($($x,)*),
-            WorkingGroup::Membership => <MembershipWorkingGroup as WorkingGroupBudgetHandler<AccountId, Balance>>::$function($($x,)*),
-            WorkingGroup::App => <AppWorkingGroup as WorkingGroupBudgetHandler<AccountId, Balance>>::$function($($x,)*),
-            WorkingGroup::Distribution => <DistributionWorkingGroup as WorkingGroupBudgetHandler<AccountId, Balance>>::$function($($x,)*),
-            WorkingGroup::OperationsAlpha => <OperationsWorkingGroupAlpha as WorkingGroupBudgetHandler<AccountId, Balance>>::$function($($x,)*),
-            WorkingGroup::OperationsBeta => <OperationsWorkingGroupBeta as WorkingGroupBudgetHandler<AccountId, Balance>>::$function($($x,)*),
-            WorkingGroup::OperationsGamma => <OperationsWorkingGroupGamma as WorkingGroupBudgetHandler<AccountId, Balance>>::$function($($x,)*),
+            WorkingGroup::Content => <ContentWorkingGroup as $handler>::$function($($x,)*),
+            WorkingGroup::Storage => <StorageWorkingGroup as $handler>::$function($($x,)*),
+            WorkingGroup::Forum => <ForumWorkingGroup as $handler>::$function($($x,)*),
+            WorkingGroup::Membership => <MembershipWorkingGroup as $handler>::$function($($x,)*),
+            WorkingGroup::App => <AppWorkingGroup as $handler>::$function($($x,)*),
+            WorkingGroup::Distribution => <DistributionWorkingGroup as $handler>::$function($($x,)*),
+            WorkingGroup::OperationsAlpha => <OperationsWorkingGroupAlpha as $handler>::$function($($x,)*),
+            WorkingGroup::OperationsBeta => <OperationsWorkingGroupBeta as $handler>::$function($($x,)*),
+            WorkingGroup::OperationsGamma => <OperationsWorkingGroupGamma as $handler>::$function($($x,)*),
         }
     }};
 }
@@ -1676,6 +1731,84 @@ impl joystream_utility::Config for Runtime {
     fn set_working_group_budget(working_group: WorkingGroup, budget: Balance) {
         call_wg!(working_group, set_budget, budget)
     }
+
+    fn is_working_group_leader_account_id(
+        working_group: WorkingGroup,
+        account_id: &AccountId,
+    ) -> bool {
+        call_wg!(
+            working_group,
+            WorkingGroupAuthenticator<Runtime>,
+            is_leader_account_id,
+            account_id
+        )
+    }
+
+    fn is_working_group_worker_transfer_approved(
+        working_group: WorkingGroup,
+        worker_id: &ActorId,
+        destination: WorkingGroup,
+    ) -> bool {
+        call_wg!(
+            working_group,
+            WorkingGroupWorkerTransferHandler<AccountId, ActorId, MemberId, BlockNumber, Balance>,
+            is_worker_transfer_approved,
+            worker_id,
+            destination
+        )
+    }
+
+    fn ensure_working_group_worker_can_be_transferred(
+        working_group: WorkingGroup,
+        worker_id: &ActorId,
+    ) -> Result<joystream_utility::TransferredWorkerOf<Runtime>, DispatchError> {
+        call_wg!(
+            working_group,
+            WorkingGroupWorkerTransferHandler<AccountId, ActorId, MemberId, BlockNumber, Balance>,
+            ensure_worker_can_be_transferred,
+            worker_id
+        )
+    }
+
+    fn ensure_working_group_can_accept_worker(
+        working_group: WorkingGroup,
+        worker: &joystream_utility::TransferredWorkerOf<Runtime>,
+    ) -> DispatchResult {
+        call_wg!(
+            working_group,
+            WorkingGroupWorkerTransferHandler<AccountId, ActorId, MemberId, BlockNumber, Balance>,
+            ensure_worker_can_be_accepted,
+            worker
+        )
+    }
+
+    fn transfer_working_group_worker_out(
+        working_group: WorkingGroup,
+        worker_id: &ActorId,
+        destination: WorkingGroup,
+    ) {
+        call_wg!(
+            working_group,
+            WorkingGroupWorkerTransferHandler<AccountId, ActorId, MemberId, BlockNumber, Balance>,
+            transfer_worker_out,
+            worker_id,
+            destination
+        )
+    }
+
+    fn transfer_working_group_worker_in(
+        working_group: WorkingGroup,
+        worker: joystream_utility::TransferredWorkerOf<Runtime>,
+        source: WorkingGroup,
+    ) -> ActorId {
+        call_wg!(
+            working_group,
+            WorkingGroupWorkerTransferHandler<AccountId, ActorId, MemberId, BlockNumber, Balance>,
+            transfer_worker_in,
+            worker,
+            source
+        )
+    }
 }
 
 parameter_types! {
@@ -1727,6 +1860,20 @@ impl proposals_codex::Config for Runtime {
     type UpdateGlobalNftLimitProposalParameters = UpdateGlobalNftLimitProposalParameters;
     type UpdateChannelPayoutsProposalParameters = UpdateChannelPayoutsProposalParameters;
     type DecreaseCouncilBudgetProposalParameters = DecreaseCouncilBudgetProposalParameters;
+    type OverturnBountyJudgmentProposalParameters = OverturnBountyJudgmentProposalParameters;
+    type RevokeWorkingGroupDeputyProposalParameters = RevokeWorkingGroupDeputyProposalParameters;
+    type TransferWorkingGroupWorkerProposalParameters =
+        TransferWorkingGroupWorkerProposalParameters;
+    type SetReferendumVotePowerStrategyProposalParameters =
+        SetReferendumVotePowerStrategyProposalParameters;
+    type RecallCouncilorProposalParameters = RecallCouncilorProposalParameters;
+    type ResolveWorkerReviewContestProposalParameters =
+        ResolveWorkerReviewContestProposalParameters;
+    type CreateBudgetAllocationProposalParameters = CreateBudgetAllocationProposalParameters;
+    type FundingRequestFromAllocationProposalParameters =
+        FundingRequestFromAllocationProposalParameters;
+    type UpdateWorkingGroupBudgetFromAllocationProposalParameters =
+        UpdateWorkingGroupBudgetFromAllocationProposalParameters;
     type FundingRequestProposalMaxTotalAmount = FundingRequestProposalMaxTotalAmount;
     type FundingRequestProposalMaxAccounts = FundingRequestProposalMaxAccounts;
     type SetMaxValidatorCountProposalMaxValidators = SetMaxValidatorCountProposalMaxValidators;
@@ -1745,6 +1892,10 @@ impl pallet_constitution::Config for Runtime {
 parameter_types! {
     pub const BountyModuleId: PalletId = PalletId(*b"m:bounty"); // module : bounty
     pub const ClosedContractSizeLimit: u32 = 50;
+    pub const MaxDeliveryMilestones: u32 = 10;
+    pub const MaxOraclePanelSize: u32 = 10;
+    pub const JudgmentDisputeStake: Balance = dollars!(100);
+    pub const JudgmentDisputePeriod: BlockNumber = days!(14);
 
     // Bounty work entry stake related:
     pub BountyWorkEntryCleanupTxFee: Balance = compute_fee(
@@ -1797,12 +1948,18 @@ impl bounty::Config for Runtime {
     type Membership = Members;
     type WeightInfo = bounty::weights::SubstrateWeight<Runtime>;
     type CouncilBudgetManager = Council;
+    type CouncilMembers = Council;
     type StakingHandler = staking_handler::StakingManager<Self, BountyLockId>;
     type EntryId = u64;
     type ClosedContractSizeLimit = ClosedContractSizeLimit;
     type MinWorkEntrantStake = MinWorkEntrantStake;
     type FunderStateBloatBondAmount = FunderStateBloatBondAmount;
     type CreatorStateBloatBondAmount = CreatorStateBloatBondAmount;
+    type MaxDeliveryMilestones = MaxDeliveryMilestones;
+    type MaxOraclePanelSize = MaxOraclePanelSize;
+    type JudgmentDisputeStake = JudgmentDisputeStake;
+    type JudgmentDisputePeriod = JudgmentDisputePeriod;
+    type ProjectToken = ProjectToken;
 }
 
 parameter_types! {
@@ -1903,7 +2060,10 @@ impl InstanceFilter<RuntimeCall> for ProxyType {
                 c,
                 RuntimeCall::Referendum(referendum::Call::vote { .. })
                     | RuntimeCall::Referendum(referendum::Call::reveal_vote { .. })
+                    | RuntimeCall::Referendum(referendum::Call::reveal_ranked_vote { .. })
                     | RuntimeCall::Referendum(referendum::Call::release_vote_stake { .. })
+                    | RuntimeCall::Referendum(referendum::Call::delegate_vote { .. })
+                    | RuntimeCall::Referendum(referendum::Call::revoke_vote_delegation { .. })
             ),
             ProxyType::Staking => matches!(c, RuntimeCall::Staking(..)),
             ProxyType::StorageTransactor => matches!(
@@ -121,4 +121,31 @@ parameter_types! {
 
     pub DecreaseCouncilBudgetProposalParameters: ProposalParameters<BlockNumber, Balance> =
         decrease_council_budget();
+
+    pub OverturnBountyJudgmentProposalParameters: ProposalParameters<BlockNumber, Balance> =
+        overturn_bounty_judgment();
+
+    pub RevokeWorkingGroupDeputyProposalParameters: ProposalParameters<BlockNumber, Balance> =
+        revoke_working_group_deputy();
+
+    pub TransferWorkingGroupWorkerProposalParameters: ProposalParameters<BlockNumber, Balance> =
+        transfer_working_group_worker();
+
+    pub SetReferendumVotePowerStrategyProposalParameters: ProposalParameters<BlockNumber, Balance> =
+        set_referendum_vote_power_strategy();
+
+    pub RecallCouncilorProposalParameters: ProposalParameters<BlockNumber, Balance> =
+        recall_councilor();
+
+    pub ResolveWorkerReviewContestProposalParameters: ProposalParameters<BlockNumber, Balance> =
+        resolve_worker_review_contest();
+
+    pub CreateBudgetAllocationProposalParameters: ProposalParameters<BlockNumber, Balance> =
+        create_budget_allocation();
+
+    pub FundingRequestFromAllocationProposalParameters: ProposalParameters<BlockNumber, Balance> =
+        funding_request_from_allocation();
+
+    pub UpdateWorkingGroupBudgetFromAllocationProposalParameters: ProposalParameters<BlockNumber, Balance> =
+        update_working_group_budget_from_allocation();
 }
@@ -401,3 +401,127 @@ pub(crate) fn decrease_council_budget() -> ProposalParameters<BlockNumber, Balan
         constitutionality: 1,
     }
 }
+
+// Proposal parameters for the 'Overturn Bounty Judgment' proposal
+pub(crate) fn overturn_bounty_judgment() -> ProposalParameters<BlockNumber, Balance> {
+    ProposalParameters {
+        voting_period: 200,
+        grace_period: 0,
+        approval_quorum_percentage: 60,
+        approval_threshold_percentage: 75,
+        slashing_quorum_percentage: 60,
+        slashing_threshold_percentage: 80,
+        required_stake: Some(dollars!(50)),
+        constitutionality: 1,
+    }
+}
+
+// Proposal parameters for the 'Revoke Working Group Deputy' proposal
+pub(crate) fn revoke_working_group_deputy() -> ProposalParameters<BlockNumber, Balance> {
+    ProposalParameters {
+        voting_period: 200,
+        grace_period: 0,
+        approval_quorum_percentage: 60,
+        approval_threshold_percentage: 75,
+        slashing_quorum_percentage: 60,
+        slashing_threshold_percentage: 80,
+        required_stake: Some(dollars!(50)),
+        constitutionality: 1,
+    }
+}
+
+pub(crate) fn transfer_working_group_worker() -> ProposalParameters<BlockNumber, Balance> {
+    ProposalParameters {
+        voting_period: 200,
+        grace_period: 0,
+        approval_quorum_percentage: 60,
+        approval_threshold_percentage: 75,
+        slashing_quorum_percentage: 60,
+        slashing_threshold_percentage: 80,
+        required_stake: Some(dollars!(50)),
+        constitutionality: 1,
+    }
+}
+
+pub(crate) fn set_referendum_vote_power_strategy() -> ProposalParameters<BlockNumber, Balance> {
+    ProposalParameters {
+        voting_period: 200,
+        grace_period: 100,
+        approval_quorum_percentage: 66,
+        approval_threshold_percentage: 80,
+        slashing_quorum_percentage: 60,
+        slashing_threshold_percentage: 80,
+        required_stake: Some(dollars!(200)),
+        constitutionality: 1,
+    }
+}
+
+pub(crate) fn recall_councilor() -> ProposalParameters<BlockNumber, Balance> {
+    ProposalParameters {
+        voting_period: 200,
+        grace_period: 100,
+        approval_quorum_percentage: 66,
+        approval_threshold_percentage: 80,
+        slashing_quorum_percentage: 60,
+        slashing_threshold_percentage: 80,
+        required_stake: Some(dollars!(200)),
+        constitutionality: 1,
+    }
+}
+
+// Proposal parameters for the 'Resolve Worker Review Contest' proposal
+pub(crate) fn resolve_worker_review_contest() -> ProposalParameters<BlockNumber, Balance> {
+    ProposalParameters {
+        voting_period: 200,
+        grace_period: 0,
+        approval_quorum_percentage: 60,
+        approval_threshold_percentage: 75,
+        slashing_quorum_percentage: 60,
+        slashing_threshold_percentage: 80,
+        required_stake: Some(dollars!(50)),
+        constitutionality: 1,
+    }
+}
+
+// Proposal parameters for the 'Create Budget Allocation' proposal
+pub(crate) fn create_budget_allocation() -> ProposalParameters<BlockNumber, Balance> {
+    ProposalParameters {
+        voting_period: 200,
+        grace_period: 100,
+        approval_quorum_percentage: 60,
+        approval_threshold_percentage: 80,
+        slashing_quorum_percentage: 60,
+        slashing_threshold_percentage: 80,
+        required_stake: Some(dollars!(25)),
+        constitutionality: 1,
+    }
+}
+
+// Proposal parameters for the 'Funding Request From Allocation' proposal
+pub(crate) fn funding_request_from_allocation() -> ProposalParameters<BlockNumber, Balance> {
+    ProposalParameters {
+        voting_period: 200,
+        grace_period: 100,
+        approval_quorum_percentage: 60,
+        approval_threshold_percentage: 80,
+        slashing_quorum_percentage: 60,
+        slashing_threshold_percentage: 80,
+        required_stake: Some(dollars!(25)),
+        constitutionality: 1,
+    }
+}
+
+// Proposal parameters for the 'Update Working Group Budget From Allocation' proposal
+pub(crate) fn update_working_group_budget_from_allocation(
+) -> ProposalParameters<BlockNumber, Balance> {
+    ProposalParameters {
+        voting_period: 200,
+        grace_period: 0,
+        approval_quorum_percentage: 60,
+        approval_threshold_percentage: 75,
+        slashing_quorum_percentage: 60,
+        slashing_threshold_percentage: 80,
+        required_stake: Some(dollars!(50)),
+        constitutionality: 1,
+    }
+}
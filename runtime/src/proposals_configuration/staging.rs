@@ -412,3 +412,127 @@ pub(crate) fn decrease_council_budget() -> ProposalParameters<BlockNumber, Balan
         constitutionality: 1,
     }
 }
+
+// Proposal parameters for the 'Overturn Bounty Judgment' proposal
+pub(crate) fn overturn_bounty_judgment() -> ProposalParameters<BlockNumber, Balance> {
+    ProposalParameters {
+        voting_period: minutes!(20),
+        grace_period: 0,
+        approval_quorum_percentage: TWO_OUT_OF_THREE,
+        approval_threshold_percentage: TWO_OUT_OF_THREE,
+        slashing_quorum_percentage: ALL,
+        slashing_threshold_percentage: ALL,
+        required_stake: Some(dollars!(50)),
+        constitutionality: 1,
+    }
+}
+
+// Proposal parameters for the 'Revoke Working Group Deputy' proposal
+pub(crate) fn revoke_working_group_deputy() -> ProposalParameters<BlockNumber, Balance> {
+    ProposalParameters {
+        voting_period: minutes!(20),
+        grace_period: minutes!(5),
+        approval_quorum_percentage: TWO_OUT_OF_THREE,
+        approval_threshold_percentage: TWO_OUT_OF_THREE,
+        slashing_quorum_percentage: ALL,
+        slashing_threshold_percentage: ALL,
+        required_stake: Some(dollars!(50)),
+        constitutionality: 1,
+    }
+}
+
+pub(crate) fn transfer_working_group_worker() -> ProposalParameters<BlockNumber, Balance> {
+    ProposalParameters {
+        voting_period: minutes!(20),
+        grace_period: minutes!(5),
+        approval_quorum_percentage: TWO_OUT_OF_THREE,
+        approval_threshold_percentage: TWO_OUT_OF_THREE,
+        slashing_quorum_percentage: ALL,
+        slashing_threshold_percentage: ALL,
+        required_stake: Some(dollars!(50)),
+        constitutionality: 1,
+    }
+}
+
+pub(crate) fn set_referendum_vote_power_strategy() -> ProposalParameters<BlockNumber, Balance> {
+    ProposalParameters {
+        voting_period: minutes!(20),
+        grace_period: minutes!(5),
+        approval_quorum_percentage: ALL,
+        approval_threshold_percentage: ALL,
+        slashing_quorum_percentage: ALL,
+        slashing_threshold_percentage: ALL,
+        required_stake: Some(dollars!(2_000)),
+        constitutionality: 2,
+    }
+}
+
+pub(crate) fn recall_councilor() -> ProposalParameters<BlockNumber, Balance> {
+    ProposalParameters {
+        voting_period: minutes!(20),
+        grace_period: minutes!(5),
+        approval_quorum_percentage: TWO_OUT_OF_THREE,
+        approval_threshold_percentage: TWO_OUT_OF_THREE,
+        slashing_quorum_percentage: ALL,
+        slashing_threshold_percentage: ALL,
+        required_stake: Some(dollars!(2_000)),
+        constitutionality: 2,
+    }
+}
+
+// Proposal parameters for the 'Resolve Worker Review Contest' proposal
+pub(crate) fn resolve_worker_review_contest() -> ProposalParameters<BlockNumber, Balance> {
+    ProposalParameters {
+        voting_period: minutes!(20),
+        grace_period: minutes!(5),
+        approval_quorum_percentage: TWO_OUT_OF_THREE,
+        approval_threshold_percentage: TWO_OUT_OF_THREE,
+        slashing_quorum_percentage: ALL,
+        slashing_threshold_percentage: ALL,
+        required_stake: Some(dollars!(50)),
+        constitutionality: 1,
+    }
+}
+
+// Proposal parameters for the 'Create Budget Allocation' proposal
+pub(crate) fn create_budget_allocation() -> ProposalParameters<BlockNumber, Balance> {
+    ProposalParameters {
+        voting_period: minutes!(20),
+        grace_period: minutes!(5),
+        approval_quorum_percentage: TWO_OUT_OF_THREE,
+        approval_threshold_percentage: TWO_OUT_OF_THREE,
+        slashing_quorum_percentage: ALL,
+        slashing_threshold_percentage: ALL,
+        required_stake: Some(dollars!(10)),
+        constitutionality: 1,
+    }
+}
+
+// Proposal parameters for the 'Funding Request From Allocation' proposal
+pub(crate) fn funding_request_from_allocation() -> ProposalParameters<BlockNumber, Balance> {
+    ProposalParameters {
+        voting_period: minutes!(20),
+        grace_period: minutes!(5),
+        approval_quorum_percentage: TWO_OUT_OF_THREE,
+        approval_threshold_percentage: TWO_OUT_OF_THREE,
+        slashing_quorum_percentage: ALL,
+        slashing_threshold_percentage: ALL,
+        required_stake: Some(dollars!(10)),
+        constitutionality: 1,
+    }
+}
+
+// Proposal parameters for the 'Update Working Group Budget From Allocation' proposal
+pub(crate) fn update_working_group_budget_from_allocation(
+) -> ProposalParameters<BlockNumber, Balance> {
+    ProposalParameters {
+        voting_period: minutes!(20),
+        grace_period: minutes!(5),
+        approval_quorum_percentage: TWO_OUT_OF_THREE,
+        approval_threshold_percentage: TWO_OUT_OF_THREE,
+        slashing_quorum_percentage: ALL,
+        slashing_threshold_percentage: ALL,
+        required_stake: Some(dollars!(50)),
+        constitutionality: 1,
+    }
+}